@@ -5,6 +5,7 @@ use std::cmp::Ordering;
 use std::collections::{BTreeMap, BinaryHeap};
 use std::fmt::{Debug, Display, Formatter};
 use std::ops;
+use std::sync::Arc;
 use tracing::trace;
 
 mod categories;
@@ -12,6 +13,7 @@ pub mod context;
 mod diagnostics;
 mod matcher;
 pub mod options;
+mod plugin;
 mod query;
 mod registry;
 mod rule;
@@ -28,8 +30,11 @@ pub use crate::categories::{
 };
 pub use crate::diagnostics::AnalyzerDiagnostic;
 pub use crate::diagnostics::SuppressionDiagnostic;
-pub use crate::matcher::{InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry};
+pub use crate::matcher::{
+    InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry, SignalRuleKey,
+};
 pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
+pub use crate::plugin::{AnalyzerPlugin, PluginSignal};
 pub use crate::query::{AddVisitor, QueryKey, QueryMatch, Queryable};
 pub use crate::registry::{
     LanguageRoot, MetadataRegistry, Phase, Phases, RegistryRuleMetadata, RegistryVisitor,
@@ -46,14 +51,16 @@ pub use crate::signals::{
 pub use crate::syntax::{Ast, SyntaxVisitor};
 pub use crate::visitor::{NodeVisitor, Visitor, VisitorContext, VisitorFinishContext};
 
+use crate::plugin::PluginVisitor;
 use biome_console::markup;
 use biome_diagnostics::{
     category, Applicability, Diagnostic, DiagnosticExt, DiagnosticTags, Severity,
 };
 use biome_rowan::{
-    AstNode, BatchMutation, Direction, Language, SyntaxElement, SyntaxToken, TextLen, TextRange,
-    TextSize, TokenAtOffset, TriviaPiece, TriviaPieceKind, WalkEvent,
+    AstNode, BatchMutation, Direction, Language, SyntaxElement, SyntaxToken, SyntaxTriviaPiece,
+    TextLen, TextRange, TextSize, TokenAtOffset, TriviaPiece, TriviaPieceKind, WalkEvent,
 };
+use biome_suppression::{parse_suppression_comment, SuppressionScope};
 
 /// The analyzer is the main entry point into the `biome_analyze` infrastructure.
 /// Its role is to run a collection of [Visitor]s over a syntax tree, with each
@@ -117,6 +124,15 @@ where
         self.phases.entry(phase).or_default().push(visitor);
     }
 
+    /// Registers an [AnalyzerPlugin] whose signals are emitted alongside the
+    /// signals of the statically known lint rules
+    pub fn add_plugin(&mut self, plugin: Arc<dyn AnalyzerPlugin<L>>)
+    where
+        L: 'static,
+    {
+        self.add_visitor(Phases::Syntax, Box::new(PluginVisitor::new(plugin)));
+    }
+
     pub fn run(self, mut ctx: AnalyzerContext<L>) -> Option<Break> {
         let Self {
             phases,
@@ -129,6 +145,7 @@ where
 
         let mut line_index = 0;
         let mut line_suppressions = Vec::new();
+        let mut range_suppressions = Vec::new();
 
         for (index, (phase, mut visitors)) in phases.into_iter().enumerate() {
             let runner = PhaseRunner {
@@ -140,6 +157,7 @@ where
                 parse_suppression_comment,
                 line_index: &mut line_index,
                 line_suppressions: &mut line_suppressions,
+                range_suppressions: &mut range_suppressions,
                 emit_signal: &mut emit_signal,
                 root: &ctx.root,
                 services: &ctx.services,
@@ -172,22 +190,14 @@ where
             }
         }
 
-        for suppression in line_suppressions {
-            if suppression.did_suppress_signal {
-                continue;
-            }
-
-            let signal = DiagnosticSignal::new(|| {
-                SuppressionDiagnostic::new(
-                    category!("suppressions/unused"),
-                    suppression.comment_span,
-                    "Suppression comment is not being used",
-                )
-            });
-
-            if let ControlFlow::Break(br) = (emit_signal)(&signal) {
-                return Some(br);
-            }
+        let suppressions = line_suppressions
+            .into_iter()
+            .map(SuppressionComment::from)
+            .chain(range_suppressions.into_iter().map(SuppressionComment::from));
+        if let ControlFlow::Break(br) =
+            emit_unused_suppressions(suppressions, &ctx, &query_matcher, &mut emit_signal)
+        {
+            return Some(br);
         }
 
         None
@@ -214,6 +224,8 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break, Diag> {
     line_index: &'phase mut usize,
     /// Track active suppression comments per-line, ordered by line index
     line_suppressions: &'phase mut Vec<LineSuppression>,
+    /// Track the range suppression comments, ordered by starting position
+    range_suppressions: &'phase mut Vec<RangeSuppression>,
     /// Handles analyzer signals emitted by individual rules
     emit_signal: &'phase mut SignalHandler<'analyzer, L, Break>,
     /// Root node of the file being analyzed
@@ -233,6 +245,9 @@ struct LineSuppression {
     line_index: usize,
     /// Range of source text covered by the suppression comment
     comment_span: TextRange,
+    /// Ranges of all the comments merged in this suppression, removed
+    /// together when the suppression is unused
+    comment_ranges: Vec<TextRange>,
     /// Range of source text this comment is suppressing lint rules for
     text_range: TextRange,
     /// Set to true if this comment has set the `suppress_all` flag to true
@@ -241,11 +256,103 @@ struct LineSuppression {
     /// List of all the rules this comment has started suppressing (must be
     /// removed from the suppressed set on expiration)
     suppressed_rules: Vec<RuleFilter<'static>>,
+    /// Set to true if this comment suppresses the signals of all the plugins
+    suppress_all_plugins: bool,
+    /// List of the names of the plugins this comment is suppressing
+    suppressed_plugins: Vec<Box<str>>,
+    /// Set to `true` when a signal matching this suppression was emitted and
+    /// suppressed
+    did_suppress_signal: bool,
+}
+
+/// Single entry for a `biome-ignore-start` or `biome-ignore-all` suppression
+/// comment in the `range_suppressions` buffer
+#[derive(Debug)]
+struct RangeSuppression {
+    /// Range of source text covered by the comment starting the suppression
+    comment_span: TextRange,
+    /// Ranges of the comments starting and ending the suppression
+    comment_ranges: Vec<TextRange>,
+    /// Range of source text this comment is suppressing lint rules for, until
+    /// the end of the file if the range isn't closed
+    text_range: TextRange,
+    /// Set to true once the `biome-ignore-end` comment closing this range is found
+    is_closed: bool,
+    /// Set to true if this suppression is a `biome-ignore-all` comment
+    is_file: bool,
+    /// Set to true if this comment suppresses all the lint rules
+    suppress_all: bool,
+    /// List of all the rules this comment is suppressing
+    suppressed_rules: Vec<RuleFilter<'static>>,
+    /// Set to true if this comment suppresses the signals of all the plugins
+    suppress_all_plugins: bool,
+    /// List of the names of the plugins this comment is suppressing
+    suppressed_plugins: Vec<Box<str>>,
     /// Set to `true` when a signal matching this suppression was emitted and
     /// suppressed
     did_suppress_signal: bool,
 }
 
+/// The parts of a line or range suppression comment needed to report it when
+/// it's unused
+struct SuppressionComment {
+    comment_span: TextRange,
+    comment_ranges: Vec<TextRange>,
+    suppress_all: bool,
+    suppressed_rules: Vec<RuleFilter<'static>>,
+    suppress_all_plugins: bool,
+    suppressed_plugins: Vec<Box<str>>,
+    did_suppress_signal: bool,
+}
+
+impl From<LineSuppression> for SuppressionComment {
+    fn from(suppression: LineSuppression) -> Self {
+        Self {
+            comment_span: suppression.comment_span,
+            comment_ranges: suppression.comment_ranges,
+            suppress_all: suppression.suppress_all,
+            suppressed_rules: suppression.suppressed_rules,
+            suppress_all_plugins: suppression.suppress_all_plugins,
+            suppressed_plugins: suppression.suppressed_plugins,
+            did_suppress_signal: suppression.did_suppress_signal,
+        }
+    }
+}
+
+impl From<RangeSuppression> for SuppressionComment {
+    fn from(suppression: RangeSuppression) -> Self {
+        Self {
+            comment_span: suppression.comment_span,
+            comment_ranges: suppression.comment_ranges,
+            suppress_all: suppression.suppress_all,
+            suppressed_rules: suppression.suppressed_rules,
+            suppress_all_plugins: suppression.suppress_all_plugins,
+            suppressed_plugins: suppression.suppressed_plugins,
+            did_suppress_signal: suppression.did_suppress_signal,
+        }
+    }
+}
+
+impl RangeSuppression {
+    /// Returns `true` if this suppression covers the signal of `rule` emitted at `range`
+    fn suppresses(&self, rule: &SignalRuleKey, range: TextRange) -> bool {
+        if !self.text_range.contains_range(range) {
+            return false;
+        }
+
+        match rule {
+            SignalRuleKey::Rule(rule) => {
+                self.suppress_all || self.suppressed_rules.iter().any(|filter| filter == rule)
+            }
+            SignalRuleKey::Plugin(name) => {
+                self.suppress_all
+                    || self.suppress_all_plugins
+                    || self.suppressed_plugins.contains(name)
+            }
+        }
+    }
+}
+
 impl<'a, 'phase, L, Matcher, Break, Diag> PhaseRunner<'a, 'phase, L, Matcher, Break, Diag>
 where
     L: Language,
@@ -256,6 +363,8 @@ where
     /// suppression comments
     fn run_first_phase(mut self) -> ControlFlow<Break> {
         trace!("Running first analyzer phase");
+        self.handle_file_suppressions()?;
+
         let iter = self.root.syntax().preorder_with_tokens(Direction::Next);
         for event in iter {
             let node_event = match event {
@@ -322,6 +431,48 @@ where
         ControlFlow::Continue(())
     }
 
+    /// Parses the `biome-ignore-all` comments before the traversal starts:
+    /// they suppress the signals of the whole file, including the ones
+    /// emitted for the code preceding the comment
+    fn handle_file_suppressions(&mut self) -> ControlFlow<Break> {
+        for token in self.root.syntax().descendants_tokens(Direction::Next) {
+            let trivia = [
+                (true, token.leading_trivia()),
+                (false, token.trailing_trivia()),
+            ];
+            for (is_leading, trivia) in trivia {
+                for (index, piece) in trivia.pieces().enumerate() {
+                    let Some(comment) = piece.as_comments() else {
+                        continue;
+                    };
+
+                    let is_file_suppression = (self.parse_suppression_comment)(comment.text())
+                        .iter()
+                        .any(|result| matches!(result, Ok(SuppressionKind::File)));
+                    if is_file_suppression {
+                        self.handle_comment(
+                            &token,
+                            is_leading,
+                            index,
+                            comment.text(),
+                            piece.text_range(),
+                        )?;
+                    }
+                }
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    /// Returns `true` if the comment at `range` is a `biome-ignore-all`
+    /// comment, already handled by [Self::handle_file_suppressions]
+    fn is_file_suppression(&self, range: TextRange) -> bool {
+        self.range_suppressions
+            .iter()
+            .any(|suppression| suppression.is_file && suppression.comment_span == range)
+    }
+
     /// Process the text for a single token, parsing suppression comments and
     /// handling line breaks, then flush all pending query signals in the queue
     /// whose position is less then the end of the token within the file
@@ -338,7 +489,9 @@ where
             }
 
             if let Some(comment) = piece.as_comments() {
-                self.handle_comment(&token, true, index, comment.text(), piece.text_range())?;
+                if !self.is_file_suppression(piece.text_range()) {
+                    self.handle_comment(&token, true, index, comment.text(), piece.text_range())?;
+                }
             }
         }
 
@@ -355,7 +508,9 @@ where
             }
 
             if let Some(comment) = piece.as_comments() {
-                self.handle_comment(&token, false, index, comment.text(), piece.text_range())?;
+                if !self.is_file_suppression(piece.text_range()) {
+                    self.handle_comment(&token, false, index, comment.text(), piece.text_range())?;
+                }
             }
         }
 
@@ -407,16 +562,34 @@ where
                     return true;
                 }
 
-                suppression
-                    .suppressed_rules
-                    .iter()
-                    .any(|filter| *filter == entry.rule)
+                match &entry.rule {
+                    SignalRuleKey::Rule(rule) => suppression
+                        .suppressed_rules
+                        .iter()
+                        .any(|filter| filter == rule),
+                    SignalRuleKey::Plugin(name) => {
+                        suppression.suppress_all_plugins
+                            || suppression.suppressed_plugins.contains(name)
+                    }
+                }
             });
 
+            // Fallback to the innermost range suppression covering the signal
+            let range_suppression = if suppression.is_none() {
+                self.range_suppressions
+                    .iter_mut()
+                    .rev()
+                    .find(|suppression| suppression.suppresses(&entry.rule, entry.text_range))
+            } else {
+                None
+            };
+
             // If the signal is being suppressed mark the line suppression as
             // hit, otherwise emit the signal
             if let Some(suppression) = suppression {
                 suppression.did_suppress_signal = true;
+            } else if let Some(suppression) = range_suppression {
+                suppression.did_suppress_signal = true;
             } else if range_match(self.range, entry.text_range) {
                 (self.emit_signal)(&*entry.signal)?;
             }
@@ -441,7 +614,11 @@ where
     ) -> ControlFlow<Break> {
         let mut suppress_all = false;
         let mut suppressions = Vec::new();
+        let mut suppress_all_plugins = false;
+        let mut plugins = Vec::new();
         let mut has_legacy = false;
+        let mut unknown_rules = Vec::new();
+        let mut scope = None;
 
         for result in (self.parse_suppression_comment)(text) {
             let kind = match result {
@@ -479,6 +656,18 @@ where
                 SuppressionKind::Rule(rule) => Some(rule),
                 SuppressionKind::MaybeLegacy(rule) => Some(rule),
                 SuppressionKind::Deprecated => None,
+                SuppressionKind::Plugin(Some(name)) => {
+                    plugins.push(Box::from(name));
+                    continue;
+                }
+                SuppressionKind::Plugin(None) => {
+                    suppress_all_plugins = true;
+                    continue;
+                }
+                SuppressionKind::RangeStart | SuppressionKind::RangeEnd | SuppressionKind::File => {
+                    scope = Some(kind);
+                    continue;
+                }
             };
 
             if let Some(rule) = rule {
@@ -498,25 +687,11 @@ where
                     suppressions.push(key);
                     has_legacy |= matches!(kind, SuppressionKind::MaybeLegacy(_));
                 } else if range_match(self.range, range) {
-                    // Emit a warning for the unknown rule
-                    let signal = DiagnosticSignal::new(move || match group_rule {
-                        Some((group, rule)) => SuppressionDiagnostic::new(
-                            category!("suppressions/unknownRule"),
-                            range,
-                            format_args!("Unknown lint rule {group}/{rule} in suppression comment"),
-                        ),
-
-                        None => SuppressionDiagnostic::new(
-                            category!("suppressions/unknownGroup"),
-                            range,
-                            format_args!("Unknown lint rule group {rule} in suppression comment"),
-                        ),
-                    });
-
-                    (self.emit_signal)(&signal)?;
+                    unknown_rules.push((rule, group_rule));
                 }
             } else {
                 suppressions.clear();
+                plugins.clear();
                 suppress_all = true;
                 // If this if a "suppress all lints" comment, no need to
                 // parse anything else
@@ -524,6 +699,36 @@ where
             }
         }
 
+        // Emit a warning for the unknown rules. The comment can be removed
+        // if it doesn't suppress anything else
+        let root = self.root;
+        let suppresses_nothing =
+            !suppress_all && !suppress_all_plugins && suppressions.is_empty() && plugins.is_empty();
+        for (rule, group_rule) in unknown_rules {
+            let signal = DiagnosticSignal::new(move || match group_rule {
+                Some((group, rule)) => SuppressionDiagnostic::new(
+                    category!("suppressions/unknownRule"),
+                    range,
+                    format_args!("Unknown lint rule {group}/{rule} in suppression comment"),
+                ),
+
+                None => SuppressionDiagnostic::new(
+                    category!("suppressions/unknownGroup"),
+                    range,
+                    format_args!("Unknown lint rule group {rule} in suppression comment"),
+                ),
+            })
+            .with_action(move || {
+                if suppresses_nothing {
+                    remove_suppression_comments(root, &[range], Applicability::Always)
+                } else {
+                    None
+                }
+            });
+
+            (self.emit_signal)(&signal)?;
+        }
+
         // Emit a warning for legacy suppression syntax
         if has_legacy && range_match(self.range, range) {
             let signal = DiagnosticSignal::new(move || {
@@ -541,10 +746,46 @@ where
             (self.emit_signal)(&signal)?;
         }
 
-        if !suppress_all && suppressions.is_empty() {
+        if !suppress_all && !suppress_all_plugins && suppressions.is_empty() && plugins.is_empty() {
+            // A bare `biome-ignore-end` closes the last range
+            if matches!(scope, Some(SuppressionKind::RangeEnd)) {
+                self.close_range_suppression(range);
+            }
             return ControlFlow::Continue(());
         }
 
+        match scope {
+            Some(SuppressionKind::RangeStart | SuppressionKind::File) => {
+                let is_file = matches!(scope, Some(SuppressionKind::File));
+                // A file suppression covers the whole file, even the code
+                // preceding the comment
+                let root_range = self.root.syntax().text_range();
+                let start = if is_file {
+                    root_range.start()
+                } else {
+                    range.start()
+                };
+                self.range_suppressions.push(RangeSuppression {
+                    comment_span: range,
+                    comment_ranges: vec![range],
+                    text_range: TextRange::new(start, root_range.end()),
+                    is_closed: is_file,
+                    is_file,
+                    suppress_all,
+                    suppressed_rules: suppressions,
+                    suppress_all_plugins,
+                    suppressed_plugins: plugins,
+                    did_suppress_signal: false,
+                });
+                return ControlFlow::Continue(());
+            }
+            Some(SuppressionKind::RangeEnd) => {
+                self.close_range_suppression(range);
+                return ControlFlow::Continue(());
+            }
+            _ => {}
+        }
+
         // Suppression comments apply to the next line
         let line_index = *self.line_index + 1;
 
@@ -556,12 +797,19 @@ where
             {
                 last_suppression.line_index = line_index;
                 last_suppression.text_range = last_suppression.text_range.cover(range);
+                last_suppression.comment_ranges.push(range);
                 last_suppression.suppress_all |= suppress_all;
+                last_suppression.suppress_all_plugins |= suppress_all_plugins;
                 if !last_suppression.suppress_all {
                     last_suppression.suppressed_rules.extend(suppressions);
                 } else {
                     last_suppression.suppressed_rules.clear();
                 }
+                if !last_suppression.suppress_all && !last_suppression.suppress_all_plugins {
+                    last_suppression.suppressed_plugins.extend(plugins);
+                } else {
+                    last_suppression.suppressed_plugins.clear();
+                }
                 return ControlFlow::Continue(());
             }
         }
@@ -569,9 +817,12 @@ where
         let entry = LineSuppression {
             line_index,
             comment_span: range,
+            comment_ranges: vec![range],
             text_range: range,
             suppress_all,
             suppressed_rules: suppressions,
+            suppress_all_plugins,
+            suppressed_plugins: plugins,
             did_suppress_signal: false,
         };
 
@@ -580,6 +831,21 @@ where
         ControlFlow::Continue(())
     }
 
+    /// Closes the innermost range suppression that is still open with the
+    /// `biome-ignore-end` comment at `range`
+    fn close_range_suppression(&mut self, range: TextRange) {
+        if let Some(suppression) = self
+            .range_suppressions
+            .iter_mut()
+            .rev()
+            .find(|suppression| !suppression.is_closed)
+        {
+            suppression.is_closed = true;
+            suppression.text_range = TextRange::new(suppression.text_range.start(), range.end());
+            suppression.comment_ranges.push(range);
+        }
+    }
+
     /// Check a piece of source text (token or trivia) for line breaks and
     /// increment the line index accordingly, extending the range of the
     /// current suppression as required
@@ -677,6 +943,9 @@ fn range_match(filter: Option<TextRange>, range: TextRange) -> bool {
 /// - `// rome-ignore lint/style/useWhile lint/nursery/noUnreachable` -> `vec![Rule("style/useWhile"), Rule("nursery/noUnreachable")]`
 /// - `// rome-ignore lint(style/useWhile)` -> `vec![MaybeLegacy("style/useWhile")]`
 /// - `// rome-ignore lint(style/useWhile) lint(nursery/noUnreachable)` -> `vec![MaybeLegacy("style/useWhile"), MaybeLegacy("nursery/noUnreachable")]`
+/// - `// biome-ignore plugin/noConsoleLog` -> `vec![Plugin(Some("noConsoleLog"))]`
+/// - `// biome-ignore-start lint/style/useWhile` -> `vec![RangeStart, Rule("style/useWhile")]`
+/// - `// biome-ignore-end` -> `vec![RangeEnd]`
 type SuppressionParser<D> = fn(&str) -> Vec<Result<SuppressionKind, D>>;
 
 /// This enum is used to categorize what is disabled by a suppression comment and with what syntax
@@ -689,6 +958,65 @@ pub enum SuppressionKind<'a> {
     MaybeLegacy(&'a str),
     /// `rome-ignore` is legacy
     Deprecated,
+    /// A suppression disabling a plugin eg. `// biome-ignore plugin/noConsoleLog`, or all
+    /// the plugins eg. `// biome-ignore plugin`
+    Plugin(Option<&'a str>),
+    /// The suppressions of the comment apply until the matching [SuppressionKind::RangeEnd]
+    /// eg. `// biome-ignore-start lint/style/useWhile`
+    RangeStart,
+    /// The comment closes the last range suppression eg. `// biome-ignore-end`
+    RangeEnd,
+    /// The suppressions of the comment apply until the end of the file
+    /// eg. `// biome-ignore-all lint/style/useWhile`
+    File,
+}
+
+/// The [SuppressionParser] of the languages whose suppression comments are parsed by
+/// [biome_suppression], eg. CSS and JSON
+pub fn parse_linter_suppression_comment(
+    text: &str,
+) -> Vec<Result<SuppressionKind, biome_suppression::SuppressionDiagnostic>> {
+    let mut result = Vec::new();
+
+    for comment in parse_suppression_comment(text) {
+        let comment = match comment {
+            Ok(comment) => comment,
+            Err(err) => {
+                result.push(Err(err));
+                continue;
+            }
+        };
+
+        match comment.scope {
+            SuppressionScope::Line => {}
+            SuppressionScope::RangeStart => result.push(Ok(SuppressionKind::RangeStart)),
+            SuppressionScope::File => result.push(Ok(SuppressionKind::File)),
+            SuppressionScope::RangeEnd => {
+                // Ignore the end of ranges unrelated to the linter, eg. `biome-ignore-end format: <reason>`
+                let is_lint_end = comment.categories.is_empty()
+                    || comment.categories.iter().any(|(key, _)| {
+                        *key == category!("lint") || key.name().starts_with("lint/")
+                    });
+                if is_lint_end {
+                    result.push(Ok(SuppressionKind::RangeEnd));
+                }
+            }
+        }
+
+        for (key, value) in comment.categories {
+            if key == category!("lint") {
+                if let Some(value) = value {
+                    result.push(Ok(SuppressionKind::MaybeLegacy(value)));
+                } else {
+                    result.push(Ok(SuppressionKind::Everything));
+                }
+            } else if let Some(rule) = key.name().strip_prefix("lint/") {
+                result.push(Ok(SuppressionKind::Rule(rule)));
+            }
+        }
+    }
+
+    result
 }
 
 fn update_suppression<L: Language>(
@@ -746,6 +1074,145 @@ fn update_suppression<L: Language>(
     })
 }
 
+/// Emits a `suppressions/unused` diagnostic, with a fix removing the comment,
+/// for each of the `suppressions` in the analyzed range that didn't suppress any signal
+fn emit_unused_suppressions<L: Language, Break>(
+    suppressions: impl Iterator<Item = SuppressionComment>,
+    ctx: &AnalyzerContext<L>,
+    query_matcher: &impl QueryMatcher<L>,
+    emit_signal: &mut SignalHandler<L, Break>,
+) -> ControlFlow<Break> {
+    for suppression in suppressions {
+        if suppression.did_suppress_signal || !range_match(ctx.range, suppression.comment_span) {
+            continue;
+        }
+
+        let signal = DiagnosticSignal::new(|| {
+            SuppressionDiagnostic::new(
+                category!("suppressions/unused"),
+                suppression.comment_span,
+                "Suppression comment is not being used",
+            )
+        })
+        .with_action(|| {
+            let applicability = unused_suppression_applicability(
+                query_matcher,
+                suppression.suppress_all,
+                &suppression.suppressed_rules,
+                suppression.suppress_all_plugins,
+                &suppression.suppressed_plugins,
+            );
+            remove_suppression_comments(&ctx.root, &suppression.comment_ranges, applicability)
+        });
+
+        (emit_signal)(&signal)?;
+    }
+
+    ControlFlow::Continue(())
+}
+
+/// Returns the applicability of the removal of an unused suppression comment.
+///
+/// The removal is only safe if all the rules the comment suppresses were run:
+/// a comment suppressing a disabled rule, a group, all the rules or plugins
+/// may still be needed when the analyzer runs with another configuration.
+fn unused_suppression_applicability<L: Language>(
+    query_matcher: &impl QueryMatcher<L>,
+    suppress_all: bool,
+    suppressed_rules: &[RuleFilter<'static>],
+    suppress_all_plugins: bool,
+    suppressed_plugins: &[Box<str>],
+) -> Applicability {
+    let did_run_all_rules = !suppress_all
+        && !suppress_all_plugins
+        && suppressed_plugins.is_empty()
+        && suppressed_rules.iter().all(|filter| match filter {
+            RuleFilter::Rule(group, rule) => query_matcher.runs_rule(group, rule),
+            RuleFilter::Group(_) => false,
+        });
+
+    if did_run_all_rules {
+        Applicability::Always
+    } else {
+        Applicability::MaybeIncorrect
+    }
+}
+
+/// Creates a code action that removes the suppression comments found at
+/// `comment_ranges`, along with their indentation and the line break ending them
+fn remove_suppression_comments<L: Language>(
+    root: &L::Root,
+    comment_ranges: &[TextRange],
+    applicability: Applicability,
+) -> Option<AnalyzerAction<L>> {
+    let mut tokens: Vec<SyntaxToken<L>> = Vec::new();
+    for range in comment_ranges {
+        let token = root.syntax().covering_element(*range).into_token()?;
+        if !tokens.contains(&token) {
+            tokens.push(token);
+        }
+    }
+
+    let mut mutation = BatchMutation::new(root.syntax().clone());
+    for token in tokens {
+        let leading_trivia: Vec<_> = token.leading_trivia().pieces().collect();
+        let trailing_trivia: Vec<_> = token.trailing_trivia().pieces().collect();
+        let new_token = token
+            .clone()
+            .detach()
+            .with_leading_trivia(without_comments(&leading_trivia, comment_ranges))
+            .with_trailing_trivia(without_comments(&trailing_trivia, comment_ranges));
+
+        mutation.replace_token_discard_trivia(token, new_token);
+    }
+
+    Some(AnalyzerAction {
+        rule_name: None,
+        category: ActionCategory::QuickFix,
+        applicability,
+        message: markup! {
+            "Remove the suppression comment"
+        }
+        .to_owned(),
+        mutation,
+    })
+}
+
+/// Returns the trivia `pieces` without the comments found at `comment_ranges`
+fn without_comments<'a, L: Language>(
+    pieces: &'a [SyntaxTriviaPiece<L>],
+    comment_ranges: &[TextRange],
+) -> Vec<(TriviaPieceKind, &'a str)> {
+    let mut removed = vec![false; pieces.len()];
+    for (index, piece) in pieces.iter().enumerate() {
+        if !comment_ranges.contains(&piece.text_range()) {
+            continue;
+        }
+
+        removed[index] = true;
+        // Remove the indentation of the comment
+        let mut start = index;
+        while start > 0 && pieces[start - 1].kind() == TriviaPieceKind::Whitespace {
+            start -= 1;
+            removed[start] = true;
+        }
+        // Remove the line break following the comment
+        if pieces
+            .get(index + 1)
+            .is_some_and(|piece| piece.kind() == TriviaPieceKind::Newline)
+        {
+            removed[index + 1] = true;
+        }
+    }
+
+    pieces
+        .iter()
+        .zip(removed)
+        .filter(|(_, removed)| !removed)
+        .map(|(piece, _)| (piece.kind(), piece.text()))
+        .collect()
+}
+
 /// Payload received by the function responsible to mark a suppression comment
 pub struct SuppressionCommentEmitterPayload<'a, L: Language> {
     /// The possible offset found in the [TextRange] of the emitted diagnostic
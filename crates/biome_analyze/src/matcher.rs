@@ -16,6 +16,12 @@ use std::{
 pub trait QueryMatcher<L: Language> {
     /// Execute a single query match
     fn match_query(&mut self, params: MatchQueryParams<L>);
+
+    /// Returns `true` if the rule `group/rule` is run by this matcher. The
+    /// suppression comments of rules that don't run can't be safely removed
+    fn runs_rule(&self, _group: &str, _rule: &str) -> bool {
+        false
+    }
 }
 
 /// Parameters provided to [QueryMatcher::match_query] and require to run lint rules
@@ -131,12 +137,25 @@ impl PartialEq<RuleKey> for RuleFilter<'static> {
     }
 }
 
+/// Identifier of what emitted a signal: a rule known statically by the analyzer, or a plugin
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignalRuleKey {
+    Rule(RuleKey),
+    Plugin(Box<str>),
+}
+
+impl From<RuleKey> for SignalRuleKey {
+    fn from(key: RuleKey) -> Self {
+        Self::Rule(key)
+    }
+}
+
 /// Entry for a pending signal in the `signal_queue`
 pub struct SignalEntry<'phase, L: Language> {
     /// Boxed analyzer signal to be emitted
     pub signal: Box<dyn AnalyzerSignal<L> + 'phase>,
     /// Unique identifier for the rule that emitted this signal
-    pub rule: RuleKey,
+    pub rule: SignalRuleKey,
     /// Text range in the document this signal covers
     pub text_range: TextRange,
 }
@@ -192,6 +211,10 @@ where
         (self.func)(&params);
         self.inner.match_query(params);
     }
+
+    fn runs_rule(&self, group: &str, rule: &str) -> bool {
+        self.inner.runs_rule(group, rule)
+    }
 }
 
 #[cfg(test)]
@@ -232,7 +255,7 @@ mod tests {
             let span = node.text_trimmed_range();
             params.signal_queue.push(SignalEntry {
                 signal: Box::new(DiagnosticSignal::new(move || TestDiagnostic { span })),
-                rule: RuleKey::new("group", "rule"),
+                rule: RuleKey::new("group", "rule").into(),
                 text_range: span,
             });
         }
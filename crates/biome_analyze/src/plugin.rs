@@ -0,0 +1,137 @@
+use crate::matcher::{SignalEntry, SignalRuleKey};
+use crate::signals::{AnalyzerActionIter, AnalyzerTransformationIter};
+use crate::{
+    AnalyzerAction, AnalyzerDiagnostic, AnalyzerSignal, RuleDiagnostic, Visitor, VisitorContext,
+};
+use biome_console::{markup, MarkupBuf};
+use biome_diagnostics::{category, Category, DiagnosticExt, Severity};
+use biome_rowan::{Language, SyntaxNode, TextRange, WalkEvent};
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// A lint rule that isn't known statically by the analyzer, for example a rule
+/// defined by the user in the configuration.
+///
+/// Unlike a [Rule](crate::Rule), a plugin doesn't query the syntax tree: it
+/// receives the root of the file and returns all its signals at once.
+pub trait AnalyzerPlugin<L: Language>: Debug + Send + Sync {
+    /// The name of the plugin. Its diagnostics use the category `plugin/<name>`,
+    /// which is also used by suppression comments: `// biome-ignore plugin/<name>: <explanation>`
+    ///
+    /// The category must be registered with [Category::register_plugin] when the
+    /// plugin is loaded, otherwise the diagnostics use the category `plugin`.
+    fn name(&self) -> &str;
+
+    /// Evaluates the plugin against the root of a file
+    fn evaluate(&self, root: &SyntaxNode<L>) -> Vec<PluginSignal<L>>;
+}
+
+/// A diagnostic emitted by an [AnalyzerPlugin], with an optional code action
+#[derive(Debug, Clone)]
+pub struct PluginSignal<L: Language> {
+    range: TextRange,
+    message: MarkupBuf,
+    severity: Severity,
+    action: Option<AnalyzerAction<L>>,
+}
+
+impl<L: Language> PluginSignal<L> {
+    /// Creates a signal with [Severity::Error]
+    pub fn new(range: TextRange, message: MarkupBuf) -> Self {
+        Self {
+            range,
+            message,
+            severity: Severity::Error,
+            action: None,
+        }
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_action(mut self, action: AnalyzerAction<L>) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    pub fn action(&self) -> Option<&AnalyzerAction<L>> {
+        self.action.as_ref()
+    }
+}
+
+/// Adapter that emits the signals of a plugin in the signal queue of the analyzer
+pub(crate) struct PluginVisitor<L: Language> {
+    plugin: Arc<dyn AnalyzerPlugin<L>>,
+    category: &'static Category,
+}
+
+impl<L: Language> PluginVisitor<L> {
+    pub(crate) fn new(plugin: Arc<dyn AnalyzerPlugin<L>>) -> Self {
+        let category = Category::plugin(plugin.name()).unwrap_or(category!("plugin"));
+        Self { plugin, category }
+    }
+}
+
+impl<L: Language + 'static> Visitor for PluginVisitor<L> {
+    type Language = L;
+
+    fn visit(&mut self, event: &WalkEvent<SyntaxNode<L>>, ctx: VisitorContext<L>) {
+        // The plugin inspects the whole file at once, when the root is entered
+        let WalkEvent::Enter(node) = event else {
+            return;
+        };
+        if node.parent().is_some() {
+            return;
+        }
+
+        let name: Box<str> = self.plugin.name().into();
+        for signal in self.plugin.evaluate(node) {
+            ctx.signal_queue.push(SignalEntry {
+                text_range: signal.range,
+                rule: SignalRuleKey::Plugin(name.clone()),
+                signal: Box::new(PluginAnalyzerSignal {
+                    plugin: name.clone(),
+                    category: self.category,
+                    signal,
+                }),
+            });
+        }
+    }
+}
+
+/// A [PluginSignal] paired with the name of the plugin that emitted it
+struct PluginAnalyzerSignal<L: Language> {
+    plugin: Box<str>,
+    category: &'static Category,
+    signal: PluginSignal<L>,
+}
+
+impl<L: Language + 'static> AnalyzerSignal<L> for PluginAnalyzerSignal<L> {
+    fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
+        let plugin = &*self.plugin;
+        let diagnostic =
+            RuleDiagnostic::new(self.category, self.signal.range, &self.signal.message).note(
+                markup! {
+                    "This diagnostic was emitted by the plugin "<Emphasis>{plugin}</Emphasis>"."
+                },
+            );
+
+        Some(AnalyzerDiagnostic::from_error(
+            diagnostic.with_severity(self.signal.severity),
+        ))
+    }
+
+    fn actions(&self) -> AnalyzerActionIter<L> {
+        AnalyzerActionIter::new(self.signal.action.clone())
+    }
+
+    fn transformations(&self) -> AnalyzerTransformationIter<L> {
+        AnalyzerTransformationIter::new(vec![])
+    }
+}
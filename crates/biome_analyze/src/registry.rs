@@ -103,6 +103,8 @@ impl<L: Language> RegistryVisitor<L> for MetadataRegistry {
 pub struct RuleRegistry<L: Language> {
     /// Holds a collection of rules for each phase.
     phase_rules: [PhaseRules<L>; 2],
+    /// Holds the group and name of all the rules in the registry
+    rule_names: FxHashSet<(&'static str, &'static str)>,
 }
 
 impl<L: Language + Default> RuleRegistry<L> {
@@ -115,6 +117,7 @@ impl<L: Language + Default> RuleRegistry<L> {
             root,
             registry: RuleRegistry {
                 phase_rules: Default::default(),
+                rule_names: FxHashSet::default(),
             },
             visitors: BTreeMap::default(),
             services: ServiceBag::default(),
@@ -174,6 +177,10 @@ impl<L: Language + Default + 'static> RegistryVisitor<L> for RuleRegistryBuilder
             return;
         }
 
+        self.registry
+            .rule_names
+            .insert((<R::Group as RuleGroup>::NAME, R::METADATA.name));
+
         let phase = R::phase() as usize;
         let phase = &mut self.registry.phase_rules[phase];
 
@@ -290,6 +297,10 @@ impl<L: Language + 'static> QueryMatcher<L> for RuleRegistry<L> {
             let _ = (rule.run)(&mut params, state);
         }
     }
+
+    fn runs_rule(&self, group: &str, rule: &str) -> bool {
+        self.rule_names.contains(&(group, rule))
+    }
 }
 
 /// [SyntaxKindRules] holds a collection of [Rule]s that match a specific [SyntaxKind] value
@@ -441,7 +452,7 @@ impl<L: Language + Default> RegistryRule<L> {
 
                 params.signal_queue.push(SignalEntry {
                     signal,
-                    rule: RuleKey::rule::<R>(),
+                    rule: RuleKey::rule::<R>().into(),
                     text_range,
                 });
             }
@@ -1,14 +1,16 @@
 use crate::changed::get_changed_files;
 use crate::cli_options::CliOptions;
-use crate::commands::{get_stdin, resolve_manifest, validate_configuration_diagnostics};
+use crate::commands::{
+    enabled_tools_configuration, get_stdin, resolve_manifest, validate_configuration_diagnostics,
+};
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
 use biome_deserialize::Merge;
 use biome_service::configuration::organize_imports::PartialOrganizeImports;
 use biome_service::configuration::{
-    load_configuration, LoadedConfiguration, PartialFormatterConfiguration,
-    PartialLinterConfiguration,
+    load_configuration, load_json_schemas, load_plugins, LoadedConfiguration,
+    PartialFormatterConfiguration, PartialLinterConfiguration,
 };
 use biome_service::workspace::{FixFileMode, UpdateSettingsParams};
 use biome_service::PartialConfiguration;
@@ -26,6 +28,7 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) organize_imports_enabled: Option<bool>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) watch: bool,
 }
 
 /// Handler for the "check" command of the Biome CLI
@@ -45,6 +48,7 @@ pub(crate) fn check(
         formatter_enabled,
         since,
         changed,
+        watch,
     } = payload;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
@@ -62,7 +66,7 @@ pub(crate) fn check(
     };
 
     let loaded_configuration =
-        load_configuration(&session.app.fs, cli_options.as_configuration_base_path())?;
+        load_configuration(&*session.app.fs, cli_options.as_configuration_base_path())?;
     validate_configuration_diagnostics(
         &loaded_configuration,
         session.app.console,
@@ -100,6 +104,8 @@ pub(crate) fn check(
         organize_imports.enabled = organize_imports_enabled;
     }
 
+    let mut cli_configuration =
+        enabled_tools_configuration(formatter_enabled, linter_enabled, organize_imports_enabled);
     if let Some(mut configuration) = configuration {
         if let Some(linter) = configuration.linter.as_mut() {
             // Don't overwrite rules from the CLI configuration.
@@ -108,20 +114,37 @@ pub(crate) fn check(
             // configuration.
             linter.rules = None;
         }
-        fs_configuration.merge_with(configuration);
+        fs_configuration.merge_with(configuration.clone());
+        cli_configuration.merge_with(configuration);
     }
 
+    let plugins = load_plugins(&*session.app.fs, fs_configuration.plugins.as_ref())?;
+    let json_schemas = load_json_schemas(
+        &*session.app.fs,
+        fs_configuration
+            .json
+            .as_ref()
+            .and_then(|json| json.schemas.as_ref()),
+    )?;
+
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
     let (vcs_base_path, gitignore_matches) =
         fs_configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
 
-    let stdin = get_stdin(stdin_file_path, &mut *session.app.console, "check")?;
-
     if since.is_some() && !changed {
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
+    if watch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
+    }
+
+    let stdin = get_stdin(stdin_file_path, &mut *session.app.console, "check")?;
+
     if changed {
         paths = get_changed_files(&session.app.fs, &fs_configuration, since)?;
     }
@@ -133,13 +156,18 @@ pub(crate) fn check(
             configuration: fs_configuration,
             vcs_base_path,
             gitignore_matches,
+            plugins,
+            json_schemas,
+            project_folder: None,
         })?;
 
     execute_mode(
         Execution::new(TraversalMode::Check {
             fix_file_mode,
             stdin,
-        }),
+        })
+        .with_watch(watch)
+        .with_cli_configuration(cli_configuration),
         session,
         &cli_options,
         paths,
@@ -1,12 +1,12 @@
 use crate::changed::get_changed_files;
 use crate::cli_options::CliOptions;
-use crate::commands::validate_configuration_diagnostics;
+use crate::commands::{enabled_tools_configuration, validate_configuration_diagnostics};
 use crate::{execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution};
 use biome_deserialize::Merge;
 use biome_service::configuration::organize_imports::PartialOrganizeImports;
 use biome_service::configuration::{
-    load_configuration, LoadedConfiguration, PartialFormatterConfiguration,
-    PartialLinterConfiguration,
+    load_configuration, load_json_schemas, load_plugins, LoadedConfiguration,
+    PartialFormatterConfiguration, PartialLinterConfiguration,
 };
 use biome_service::workspace::UpdateSettingsParams;
 use biome_service::PartialConfiguration;
@@ -38,7 +38,7 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
     let loaded_configuration =
-        load_configuration(&session.app.fs, cli_options.as_configuration_base_path())?;
+        load_configuration(&*session.app.fs, cli_options.as_configuration_base_path())?;
 
     validate_configuration_diagnostics(
         &loaded_configuration,
@@ -83,6 +83,8 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
         return Err(CliDiagnostic::incompatible_end_configuration("Formatter, linter and organize imports are disabled, can't perform the command. This is probably and error."));
     }
 
+    let mut cli_configuration =
+        enabled_tools_configuration(formatter_enabled, linter_enabled, organize_imports_enabled);
     if let Some(mut configuration) = configuration {
         if let Some(linter) = configuration.linter.as_mut() {
             // Don't overwrite rules from the CLI configuration.
@@ -91,9 +93,19 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
             // configuration.
             linter.rules = None;
         }
-        fs_configuration.merge_with(configuration);
+        fs_configuration.merge_with(configuration.clone());
+        cli_configuration.merge_with(configuration);
     }
 
+    let plugins = load_plugins(&*session.app.fs, fs_configuration.plugins.as_ref())?;
+    let json_schemas = load_json_schemas(
+        &*session.app.fs,
+        fs_configuration
+            .json
+            .as_ref()
+            .and_then(|json| json.schemas.as_ref()),
+    )?;
+
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
     let (vcs_base_path, gitignore_matches) =
@@ -115,7 +127,15 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
             working_directory: session.app.fs.working_directory(),
             vcs_base_path,
             gitignore_matches,
+            plugins,
+            json_schemas,
+            project_folder: None,
         })?;
 
-    execute_mode(Execution::new_ci(), session, &cli_options, paths)
+    execute_mode(
+        Execution::new_ci().with_cli_configuration(cli_configuration),
+        session,
+        &cli_options,
+        paths,
+    )
 }
@@ -11,10 +11,12 @@ use biome_deserialize::Merge;
 use biome_diagnostics::PrintDiagnostic;
 use biome_service::configuration::vcs::PartialVcsConfiguration;
 use biome_service::configuration::{
-    load_configuration, LoadedConfiguration, PartialCssFormatter, PartialFilesConfiguration,
-    PartialFormatterConfiguration, PartialJavascriptFormatter, PartialJsonFormatter,
+    load_configuration, LoadedConfiguration, PartialCssConfiguration, PartialCssFormatter,
+    PartialFilesConfiguration, PartialFormatterConfiguration, PartialJavascriptConfiguration,
+    PartialJavascriptFormatter, PartialJsonConfiguration, PartialJsonFormatter,
 };
 use biome_service::workspace::UpdateSettingsParams;
+use biome_service::PartialConfiguration;
 use std::ffi::OsString;
 
 pub(crate) struct FormatCommandPayload {
@@ -30,6 +32,7 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) paths: Vec<OsString>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) watch: bool,
 }
 
 /// Handler for the "format" command of the Biome CLI
@@ -49,12 +52,13 @@ pub(crate) fn format(
         mut json_formatter,
         mut css_formatter,
         since,
+        watch,
         changed,
     } = payload;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
     let loaded_configuration =
-        load_configuration(&session.app.fs, cli_options.as_configuration_base_path())?;
+        load_configuration(&*session.app.fs, cli_options.as_configuration_base_path())?;
     validate_configuration_diagnostics(
         &loaded_configuration,
         session.app.console,
@@ -120,6 +124,31 @@ pub(crate) fn format(
         }
     }
 
+    let cli_configuration = PartialConfiguration {
+        files: files_configuration.clone(),
+        formatter: formatter_configuration.clone(),
+        javascript: javascript_formatter
+            .clone()
+            .map(|formatter| PartialJavascriptConfiguration {
+                formatter: Some(formatter),
+                ..Default::default()
+            }),
+        json: json_formatter
+            .clone()
+            .map(|formatter| PartialJsonConfiguration {
+                formatter: Some(formatter),
+                ..Default::default()
+            }),
+        css: css_formatter
+            .clone()
+            .map(|formatter| PartialCssConfiguration {
+                formatter: Some(formatter),
+                ..Default::default()
+            }),
+        vcs: vcs_configuration.clone(),
+        ..Default::default()
+    };
+
     if css_formatter.is_some() {
         let css = configuration.css.get_or_insert_with(Default::default);
         css.formatter.merge_with(css_formatter);
@@ -158,6 +187,13 @@ pub(crate) fn format(
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
+    if watch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
+    }
+
     if changed {
         paths = get_changed_files(&session.app.fs, &configuration, since)?;
     }
@@ -170,6 +206,9 @@ pub(crate) fn format(
             configuration,
             vcs_base_path,
             gitignore_matches,
+            plugins: Vec::new(),
+            json_schemas: Vec::new(),
+            project_folder: None,
         })?;
 
     let stdin = get_stdin(stdin_file_path, console, "format")?;
@@ -191,5 +230,12 @@ pub(crate) fn format(
         })
     };
 
-    execute_mode(execution, session, &cli_options, paths)
+    execute_mode(
+        execution
+            .with_watch(watch)
+            .with_cli_configuration(cli_configuration),
+        session,
+        &cli_options,
+        paths,
+    )
 }
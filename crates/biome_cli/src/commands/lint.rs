@@ -1,17 +1,21 @@
 use crate::changed::get_changed_files;
 use crate::cli_options::CliOptions;
 use crate::commands::{get_stdin, resolve_manifest, validate_configuration_diagnostics};
+use crate::reports::baseline::{Baseline, BaselineMode, BaselineRoot, DEFAULT_BASELINE_PATH};
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
 use biome_deserialize::Merge;
+use biome_fs::OpenOptions;
 use biome_service::configuration::vcs::PartialVcsConfiguration;
 use biome_service::configuration::{
-    load_configuration, LoadedConfiguration, PartialFilesConfiguration, PartialLinterConfiguration,
+    load_configuration, load_json_schemas, load_plugins, LoadedConfiguration,
+    PartialFilesConfiguration, PartialLinterConfiguration,
 };
 use biome_service::workspace::{FixFileMode, UpdateSettingsParams};
-use biome_service::PartialConfiguration;
+use biome_service::{PartialConfiguration, WorkspaceError};
 use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 
 pub(crate) struct LintCommandPayload {
     pub(crate) apply: bool,
@@ -24,6 +28,10 @@ pub(crate) struct LintCommandPayload {
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) write_baseline: bool,
+    pub(crate) baseline: bool,
+    pub(crate) baseline_path: Option<PathBuf>,
+    pub(crate) watch: bool,
 }
 
 /// Handler for the "lint" command of the Biome CLI
@@ -39,6 +47,10 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         files_configuration,
         changed,
         since,
+        write_baseline,
+        baseline,
+        baseline_path,
+        watch,
     } = payload;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
@@ -55,8 +67,15 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         Some(FixFileMode::SafeAndUnsafeFixes)
     };
 
+    if write_baseline && baseline {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--write-baseline",
+            "--baseline",
+        ));
+    }
+
     let loaded_configuration =
-        load_configuration(&session.app.fs, cli_options.as_configuration_base_path())?;
+        load_configuration(&*session.app.fs, cli_options.as_configuration_base_path())?;
     validate_configuration_diagnostics(
         &loaded_configuration,
         session.app.console,
@@ -69,7 +88,31 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         directory_path: configuration_path,
         ..
     } = loaded_configuration;
-    fs_configuration.merge_with(PartialConfiguration {
+
+    // The baseline lives next to the configuration file by default, and its paths are
+    // relative to the configuration file, so it doesn't depend on where Biome is run from
+    let baseline_path = baseline_path.unwrap_or_else(|| match &configuration_path {
+        Some(configuration_path) => configuration_path.join(DEFAULT_BASELINE_PATH),
+        None => PathBuf::from(DEFAULT_BASELINE_PATH),
+    });
+    let baseline_root = BaselineRoot::new(
+        session.app.fs.working_directory(),
+        configuration_path.clone(),
+    );
+    let baseline = if write_baseline {
+        Some(BaselineMode::Write {
+            path: baseline_path,
+            root: baseline_root,
+        })
+    } else if baseline {
+        Some(BaselineMode::Filter {
+            baseline: read_baseline(&session, &baseline_path)?,
+            root: baseline_root,
+        })
+    } else {
+        None
+    };
+    let cli_configuration = PartialConfiguration {
         linter: if fs_configuration
             .linter
             .as_ref()
@@ -86,7 +129,17 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         files: files_configuration,
         vcs: vcs_configuration,
         ..Default::default()
-    });
+    };
+    fs_configuration.merge_with(cli_configuration.clone());
+
+    let plugins = load_plugins(&*session.app.fs, fs_configuration.plugins.as_ref())?;
+    let json_schemas = load_json_schemas(
+        &*session.app.fs,
+        fs_configuration
+            .json
+            .as_ref()
+            .and_then(|json| json.schemas.as_ref()),
+    )?;
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
@@ -97,6 +150,20 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
+    if watch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
+    }
+
+    if watch && write_baseline {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--write-baseline",
+        ));
+    }
+
     if changed {
         paths = get_changed_files(&session.app.fs, &fs_configuration, since)?;
     }
@@ -111,15 +178,37 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
             configuration: fs_configuration,
             vcs_base_path,
             gitignore_matches,
+            plugins,
+            json_schemas,
+            project_folder: None,
         })?;
 
     execute_mode(
         Execution::new(TraversalMode::Lint {
             fix_file_mode,
             stdin,
-        }),
+            baseline,
+        })
+        .with_watch(watch)
+        .with_cli_configuration(cli_configuration),
         session,
         &cli_options,
         paths,
     )
 }
+
+/// Reads the baseline file written by a previous run with `--write-baseline`
+fn read_baseline(session: &CliSession, path: &Path) -> Result<Baseline, CliDiagnostic> {
+    let mut file = session
+        .app
+        .fs
+        .open_with_options(path, OpenOptions::default().read(true))
+        .map_err(|_| WorkspaceError::cant_read_file(path.display().to_string()))?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .map_err(|_| WorkspaceError::cant_read_file(path.display().to_string()))?;
+
+    Baseline::from_json(&content).map_err(|error| {
+        CliDiagnostic::invalid_baseline(path.display().to_string(), error.to_string())
+    })
+}
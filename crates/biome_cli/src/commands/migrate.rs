@@ -22,7 +22,7 @@ pub(crate) fn migrate(
         diagnostics: _,
         directory_path,
         file_path,
-    } = load_configuration(&session.app.fs, base_path)?;
+    } = load_configuration(&*session.app.fs, base_path)?;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
     if let (Some(path), Some(directory_path)) = (file_path, directory_path) {
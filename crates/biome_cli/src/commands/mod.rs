@@ -6,6 +6,7 @@ use crate::{CliDiagnostic, CliSession, LoggingLevel, VERSION};
 use biome_console::{markup, Console, ConsoleExt};
 use biome_diagnostics::{Diagnostic, PrintDiagnostic};
 use biome_fs::BiomePath;
+use biome_service::configuration::organize_imports::PartialOrganizeImports;
 use biome_service::configuration::vcs::PartialVcsConfiguration;
 use biome_service::configuration::{
     css::partial_css_formatter, javascript::partial_javascript_formatter,
@@ -33,6 +34,7 @@ pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod migrate;
 pub(crate) mod rage;
+pub(crate) mod search;
 pub(crate) mod version;
 
 #[derive(Debug, Clone, Bpaf)]
@@ -119,6 +121,10 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Keep running, and run the command again on the files that change
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -158,6 +164,19 @@ pub enum BiomeCommand {
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+        /// Writes the current diagnostics to the baseline file instead of reporting them.
+        /// The following runs with `--baseline` only report new diagnostics.
+        #[bpaf(long("write-baseline"), switch)]
+        write_baseline: bool,
+        /// Reports only the diagnostics that aren't recorded in the baseline file
+        #[bpaf(long("baseline"), switch)]
+        baseline: bool,
+        /// The path of the baseline file. Defaults to `biome-baseline.json`.
+        #[bpaf(long("baseline-path"), argument("PATH"))]
+        baseline_path: Option<PathBuf>,
+        /// Keep running, and run the command again on the files that change
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -207,6 +226,10 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Keep running, and run the command again on the files that change
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -246,6 +269,43 @@ pub enum BiomeCommand {
         paths: Vec<OsString>,
     },
 
+    /// Searches the code that matches a pattern, in a set of files.
+    ///
+    /// Identifiers that start with `$` are metavariables: `$name` matches any single node,
+    /// and `$$$name` matches any sequence of nodes, e.g. the arguments of a call.
+    ///
+    /// ## Examples
+    ///
+    /// ```shell
+    /// biome search 'console.log($message)' ./src
+    /// ```
+    #[bpaf(command)]
+    Search {
+        #[bpaf(external(partial_vcs_configuration), optional, hide_usage)]
+        vcs_configuration: Option<PartialVcsConfiguration>,
+
+        #[bpaf(external(partial_files_configuration), optional, hide_usage)]
+        files_configuration: Option<PartialFilesConfiguration>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+        /// Use this option when you want to search the code piped from `stdin`.
+        ///
+        /// The file doesn't need to exist on disk, what matters is the extension of the file. Based on the extension, Biome knows how to parse the code.
+        ///
+        /// Example: `echo 'console.log(a);' | biome search 'console.log($msg)' --stdin-file-path=file.js`
+        #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
+        stdin_file_path: Option<String>,
+
+        /// The pattern to search, e.g. `console.log($message)`
+        #[bpaf(positional("PATTERN"))]
+        pattern: String,
+
+        /// Single file, single path or list of paths
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
+
     /// Bootstraps a new biome project. Creates a configuration file with some defaults.
     #[bpaf(command)]
     Init(
@@ -326,6 +386,7 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
             | BiomeCommand::Migrate { cli_options, .. } => cli_options.colors.as_ref(),
             BiomeCommand::LspProxy(_)
             | BiomeCommand::Start(_)
@@ -345,6 +406,7 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
             | BiomeCommand::Migrate { cli_options, .. } => cli_options.use_server,
             BiomeCommand::Init(_)
             | BiomeCommand::Start(_)
@@ -366,6 +428,7 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
             | BiomeCommand::Migrate { cli_options, .. } => cli_options.verbose,
             BiomeCommand::Version(_)
             | BiomeCommand::Rage(..)
@@ -385,6 +448,7 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
             | BiomeCommand::Migrate { cli_options, .. } => cli_options.log_level.clone(),
             BiomeCommand::Version(_)
             | BiomeCommand::LspProxy(_)
@@ -403,6 +467,7 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
             | BiomeCommand::Migrate { cli_options, .. } => cli_options.log_kind.clone(),
             BiomeCommand::Version(_)
             | BiomeCommand::Rage(..)
@@ -507,3 +572,27 @@ pub(crate) fn get_stdin(
 
     Ok(stdin)
 }
+
+/// Returns the configuration enabling or disabling the tools via the arguments
+/// `--formatter-enabled`, `--linter-enabled` and `--organize-imports-enabled`
+pub(crate) fn enabled_tools_configuration(
+    formatter_enabled: Option<bool>,
+    linter_enabled: Option<bool>,
+    organize_imports_enabled: Option<bool>,
+) -> PartialConfiguration {
+    PartialConfiguration {
+        formatter: formatter_enabled.map(|enabled| PartialFormatterConfiguration {
+            enabled: Some(enabled),
+            ..Default::default()
+        }),
+        linter: linter_enabled.map(|enabled| PartialLinterConfiguration {
+            enabled: Some(enabled),
+            ..Default::default()
+        }),
+        organize_imports: organize_imports_enabled.map(|enabled| PartialOrganizeImports {
+            enabled: Some(enabled),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,91 @@
+use crate::cli_options::CliOptions;
+use crate::commands::{get_stdin, resolve_manifest, validate_configuration_diagnostics};
+use crate::{
+    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+};
+use biome_deserialize::Merge;
+use biome_service::configuration::vcs::PartialVcsConfiguration;
+use biome_service::configuration::{
+    load_configuration, LoadedConfiguration, PartialFilesConfiguration,
+};
+use biome_service::workspace::{ParsePatternParams, UpdateSettingsParams};
+use biome_service::PartialConfiguration;
+use std::ffi::OsString;
+
+pub(crate) struct SearchCommandPayload {
+    pub(crate) cli_options: CliOptions,
+    pub(crate) vcs_configuration: Option<PartialVcsConfiguration>,
+    pub(crate) files_configuration: Option<PartialFilesConfiguration>,
+    pub(crate) stdin_file_path: Option<String>,
+    pub(crate) pattern: String,
+    pub(crate) paths: Vec<OsString>,
+}
+
+/// Handler for the "search" command of the Biome CLI
+pub(crate) fn search(
+    session: CliSession,
+    payload: SearchCommandPayload,
+) -> Result<(), CliDiagnostic> {
+    let SearchCommandPayload {
+        cli_options,
+        vcs_configuration,
+        files_configuration,
+        stdin_file_path,
+        pattern,
+        paths,
+    } = payload;
+    setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
+
+    let loaded_configuration =
+        load_configuration(&*session.app.fs, cli_options.as_configuration_base_path())?;
+    validate_configuration_diagnostics(
+        &loaded_configuration,
+        session.app.console,
+        cli_options.verbose,
+    )?;
+    resolve_manifest(&session)?;
+
+    let LoadedConfiguration {
+        configuration: mut fs_configuration,
+        directory_path: configuration_path,
+        ..
+    } = loaded_configuration;
+    fs_configuration.merge_with(PartialConfiguration {
+        files: files_configuration,
+        vcs: vcs_configuration,
+        ..Default::default()
+    });
+
+    // check if support of git ignore files is enabled
+    let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
+    let (vcs_base_path, gitignore_matches) =
+        fs_configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
+
+    let stdin = get_stdin(stdin_file_path, &mut *session.app.console, "search")?;
+
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            working_directory: session.app.fs.working_directory(),
+            configuration: fs_configuration,
+            vcs_base_path,
+            gitignore_matches,
+            plugins: Vec::new(),
+            json_schemas: Vec::new(),
+            project_folder: None,
+        })?;
+
+    let pattern = session
+        .app
+        .workspace
+        .parse_pattern(ParsePatternParams { pattern })?
+        .pattern_id;
+
+    execute_mode(
+        Execution::new(TraversalMode::Search { pattern, stdin }),
+        session,
+        &cli_options,
+        paths,
+    )
+}
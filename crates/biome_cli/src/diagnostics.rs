@@ -55,6 +55,8 @@ pub enum CliDiagnostic {
     NoFilesWereProcessed(NoFilesWereProcessed),
     /// Errors thrown when running the `biome migrate` command
     MigrateError(MigrationDiagnostic),
+    /// Returned when the baseline file can't be read
+    InvalidBaseline(InvalidBaseline),
 }
 
 #[derive(Debug, Diagnostic)]
@@ -244,6 +246,20 @@ pub struct MigrationDiagnostic {
     pub reason: String,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "internalError/fs",
+    severity = Error,
+    message(
+        description = "The baseline file {path} is invalid: {reason}",
+        message("The baseline file "<Emphasis>{self.path}</Emphasis>" is invalid: "{{&self.reason}})
+    )
+)]
+pub struct InvalidBaseline {
+    path: String,
+    reason: String,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
     category = "internalError/fs",
@@ -431,6 +447,14 @@ impl CliDiagnostic {
         })
     }
 
+    /// Returned when the baseline file can't be deserialized
+    pub fn invalid_baseline(path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidBaseline(InvalidBaseline {
+            path: path.into(),
+            reason: reason.into(),
+        })
+    }
+
     /// Return by the help command when it is called with a subcommand it doesn't know
     pub fn new_unknown_help(command: impl Into<String>) -> Self {
         Self::UnknownCommandHelp(UnknownCommandHelp {
@@ -458,6 +482,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::NoFilesWereProcessed(diagnostic) => diagnostic.category(),
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.category(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.category(),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.category(),
         }
     }
 
@@ -479,6 +504,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::NoFilesWereProcessed(diagnostic) => diagnostic.tags(),
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.tags(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.tags(),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.tags(),
         }
     }
 
@@ -500,6 +526,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::NoFilesWereProcessed(diagnostic) => diagnostic.severity(),
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.severity(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.severity(),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.severity(),
         }
     }
 
@@ -521,6 +548,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::NoFilesWereProcessed(diagnostic) => diagnostic.location(),
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.location(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.location(),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.location(),
         }
     }
 
@@ -542,6 +570,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::NoFilesWereProcessed(diagnostic) => diagnostic.message(fmt),
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.message(fmt),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.message(fmt),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.message(fmt),
         }
     }
 
@@ -563,6 +592,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::NoFilesWereProcessed(diagnostic) => diagnostic.description(fmt),
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.description(fmt),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.description(fmt),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.description(fmt),
         }
     }
 
@@ -584,6 +614,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::NoFilesWereProcessed(diagnostic) => diagnostic.advices(visitor),
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.advices(visitor),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.advices(visitor),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.advices(visitor),
         }
     }
 
@@ -609,6 +640,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::NoFilesWereProcessed(diagnostic) => diagnostic.verbose_advices(visitor),
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.verbose_advices(visitor),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.verbose_advices(visitor),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.verbose_advices(visitor),
         }
     }
 
@@ -630,6 +662,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::NoFilesWereProcessed(diagnostic) => diagnostic.source(),
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.source(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.source(),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.source(),
         }
     }
 }
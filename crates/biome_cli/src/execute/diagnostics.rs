@@ -3,6 +3,7 @@ use biome_diagnostics::{
     Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, Severity, Visit,
 };
 use biome_text_edit::TextEdit;
+use biome_text_size::TextRange;
 use std::io;
 
 #[derive(Debug, Diagnostic)]
@@ -68,6 +69,17 @@ pub(crate) struct MigrateDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+	category = "search",
+	severity = Information,
+	message = "Found a match of the pattern."
+)]
+pub(crate) struct SearchDiagnostic {
+    #[location(span)]
+    pub(crate) span: TextRange,
+}
+
 #[derive(Debug)]
 pub(crate) struct ContentDiffAdvice {
     pub(crate) old: String,
@@ -3,14 +3,17 @@ mod migrate;
 mod process_file;
 mod std_in;
 mod traverse;
+mod watch;
 
 use crate::cli_options::CliOptions;
 use crate::execute::migrate::MigratePayload;
 use crate::execute::traverse::traverse;
+use crate::reports::baseline::BaselineMode;
 use crate::{CliDiagnostic, CliSession};
 use biome_diagnostics::{category, Category};
 use biome_fs::BiomePath;
-use biome_service::workspace::{FeatureName, FixFileMode};
+use biome_service::workspace::{FeatureName, FixFileMode, PatternId};
+use biome_service::PartialConfiguration;
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
@@ -25,12 +28,20 @@ pub(crate) struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u16,
+
+    /// Whether the command keeps running and processes again the files that change
+    watch: bool,
+
+    /// The configuration passed via CLI arguments, merged over the nested configuration
+    /// files so that the arguments always take precedence
+    cli_configuration: PartialConfiguration,
 }
 
 impl Execution {
     pub(crate) fn as_feature_name(&self) -> FeatureName {
         match self.traversal_mode {
             TraversalMode::Format { .. } => FeatureName::Format,
+            TraversalMode::Search { .. } => FeatureName::Search,
             _ => FeatureName::Lint,
         }
     }
@@ -91,6 +102,8 @@ pub(crate) enum TraversalMode {
         /// 1. The virtual path to the file
         /// 2. The content of the file
         stdin: Option<Stdin>,
+        /// Whether the diagnostics should be recorded to a baseline, or filtered by it
+        baseline: Option<BaselineMode>,
     },
     /// This mode is enabled when running the command `biome ci`
     CI {
@@ -119,6 +132,15 @@ pub(crate) enum TraversalMode {
         /// Migrate from prettier
         prettier: bool,
     },
+    /// This mode is enabled when running the command `biome search`
+    Search {
+        /// The pattern to search, compiled by the workspace
+        pattern: PatternId,
+        /// An optional tuple.
+        /// 1. The virtual path to the file
+        /// 2. The content of the file
+        stdin: Option<Stdin>,
+    },
 }
 
 impl Display for TraversalMode {
@@ -129,6 +151,7 @@ impl Display for TraversalMode {
             TraversalMode::Format { .. } => write!(f, "format"),
             TraversalMode::Migrate { .. } => write!(f, "migrate"),
             TraversalMode::Lint { .. } => write!(f, "lint"),
+            TraversalMode::Search { .. } => write!(f, "search"),
         }
     }
 }
@@ -149,6 +172,8 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            watch: false,
+            cli_configuration: PartialConfiguration::default(),
         }
     }
 
@@ -169,6 +194,8 @@ impl Execution {
                 },
             },
             max_diagnostics: 20,
+            watch: false,
+            cli_configuration: PartialConfiguration::default(),
         }
     }
 
@@ -178,9 +205,34 @@ impl Execution {
             traversal_mode,
             report_mode,
             max_diagnostics: 20,
+            watch: false,
+            cli_configuration: PartialConfiguration::default(),
         }
     }
 
+    /// Keeps the command running after the first traversal, see [watch::run]
+    pub(crate) fn with_watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
+        self
+    }
+
+    /// Sets the configuration passed via CLI arguments
+    pub(crate) fn with_cli_configuration(
+        mut self,
+        cli_configuration: PartialConfiguration,
+    ) -> Self {
+        self.cli_configuration = cli_configuration;
+        self
+    }
+
+    pub(crate) fn cli_configuration(&self) -> &PartialConfiguration {
+        &self.cli_configuration
+    }
+
+    pub(crate) const fn is_watch(&self) -> bool {
+        self.watch
+    }
+
     /// Tells if the reporting is happening straight to terminal
     pub(crate) fn should_report_to_terminal(&self) -> bool {
         matches!(self.report_mode, ReportMode::Terminal)
@@ -201,7 +253,19 @@ impl Execution {
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.as_ref(),
             TraversalMode::Format { .. }
             | TraversalMode::CI { .. }
-            | TraversalMode::Migrate { .. } => None,
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. } => None,
+        }
+    }
+
+    pub(crate) fn as_baseline_mode(&self) -> Option<&BaselineMode> {
+        match &self.traversal_mode {
+            TraversalMode::Lint { baseline, .. } => baseline.as_ref(),
+            TraversalMode::Check { .. }
+            | TraversalMode::Format { .. }
+            | TraversalMode::CI { .. }
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. } => None,
         }
     }
 
@@ -212,6 +276,7 @@ impl Execution {
             TraversalMode::CI { .. } => category!("ci"),
             TraversalMode::Format { .. } => category!("format"),
             TraversalMode::Migrate { .. } => category!("migrate"),
+            TraversalMode::Search { .. } => category!("search"),
         }
     }
 
@@ -264,7 +329,7 @@ impl Execution {
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
-            TraversalMode::CI { .. } => false,
+            TraversalMode::CI { .. } | TraversalMode::Search { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write: dry_run, .. } => dry_run,
         }
@@ -274,7 +339,8 @@ impl Execution {
         match &self.traversal_mode {
             TraversalMode::Format { stdin, .. }
             | TraversalMode::Lint { stdin, .. }
-            | TraversalMode::Check { stdin, .. } => stdin.as_ref(),
+            | TraversalMode::Check { stdin, .. }
+            | TraversalMode::Search { stdin, .. } => stdin.as_ref(),
             TraversalMode::CI { .. } | TraversalMode::Migrate { .. } => None,
         }
     }
@@ -284,7 +350,7 @@ impl Execution {
 /// or handles the stdin file.
 pub(crate) fn execute_mode(
     mut mode: Execution,
-    session: CliSession,
+    mut session: CliSession,
     cli_options: &CliOptions,
     paths: Vec<OsString>,
 ) -> Result<(), CliDiagnostic> {
@@ -316,7 +382,9 @@ pub(crate) fn execute_mode(
             prettier,
         };
         migrate::run(payload)
+    } else if mode.is_watch() {
+        watch::run(mode, session, cli_options, paths)
     } else {
-        traverse(mode, session, cli_options, paths)
+        traverse(&mode, &mut session, cli_options, paths)
     }
 }
@@ -2,12 +2,14 @@ mod check;
 mod format;
 mod lint;
 mod organize_imports;
+mod search;
 pub(crate) mod workspace_file;
 
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::process_file::check::check_file;
 use crate::execute::process_file::format::format;
 use crate::execute::process_file::lint::lint;
+use crate::execute::process_file::search::search;
 use crate::execute::traverse::TraversalOptions;
 use crate::execute::TraversalMode;
 use biome_diagnostics::{category, DiagnosticExt, DiagnosticTags, Error};
@@ -136,6 +138,7 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
                     .with_formatter()
                     .with_linter()
                     .with_organize_imports()
+                    .with_search()
                     .build(),
             })
             .with_file_path_and_code_and_tags(
@@ -219,6 +222,7 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
                 ),
             TraversalMode::Format { .. } => file_features.support_kind_for(&FeatureName::Format),
             TraversalMode::Lint { .. } => file_features.support_kind_for(&FeatureName::Lint),
+            TraversalMode::Search { .. } => file_features.support_kind_for(&FeatureName::Search),
             TraversalMode::Migrate { .. } => None,
         };
 
@@ -253,6 +257,10 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
             TraversalMode::Check { .. } | TraversalMode::CI { .. } => {
                 check_file(shared_context, path, &file_features)
             }
+            TraversalMode::Search { ref pattern, .. } => {
+                // the unsupported case should be handled already at this point
+                search(shared_context, path, pattern)
+            }
             TraversalMode::Migrate { .. } => {
                 unreachable!("The migration should not be called for this file")
             }
@@ -58,12 +58,17 @@ pub(crate) fn lint_with_guard<'ctx>(
                 errors = fix_result.errors;
             }
 
-            let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
+            // The baseline needs all the diagnostics of the file, the printer applies the limit
+            let max_diagnostics = if ctx.execution.as_baseline_mode().is_some() {
+                u32::MAX
+            } else {
+                ctx.remaining_diagnostics.load(Ordering::Relaxed).into()
+            };
             let pull_diagnostics_result = workspace_file
                 .guard()
                 .pull_diagnostics(
                     RuleCategories::LINT | RuleCategories::SYNTAX,
-                    max_diagnostics.into(),
+                    max_diagnostics,
                 )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
@@ -94,7 +99,8 @@ pub(crate) fn lint_with_guard<'ctx>(
                 });
             }
 
-            if errors > 0 {
+            // With a baseline, the printer counts the errors that aren't recorded in the baseline
+            if errors > 0 && ctx.execution.as_baseline_mode().is_none() {
                 Ok(FileStatus::Message(Message::Failure))
             } else if changed {
                 Ok(FileStatus::Changed)
@@ -0,0 +1,52 @@
+use crate::execute::diagnostics::{ResultExt, SearchDiagnostic};
+use crate::execute::process_file::workspace_file::WorkspaceFile;
+use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use biome_diagnostics::{category, Error};
+use biome_service::workspace::PatternId;
+use std::path::Path;
+
+/// Searches the occurrences of a pattern inside a single file and returns a [FileResult]
+pub(crate) fn search<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    path: &Path,
+    pattern: &PatternId,
+) -> FileResult {
+    let workspace_file = WorkspaceFile::new(ctx, path)?;
+    search_with_guard(ctx, &workspace_file, pattern)
+}
+
+pub(crate) fn search_with_guard<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    workspace_file: &WorkspaceFile,
+    pattern: &PatternId,
+) -> FileResult {
+    tracing::info_span!("Processes searching", path =? workspace_file.path.display()).in_scope(
+        move || {
+            let result = workspace_file
+                .guard()
+                .search_pattern(pattern)
+                .with_file_path_and_code(
+                    workspace_file.path.display().to_string(),
+                    category!("search"),
+                )?;
+
+            if result.matches.is_empty() {
+                return Ok(FileStatus::Unchanged);
+            }
+
+            let input = workspace_file.input()?;
+            ctx.push_message(Message::Diagnostics {
+                name: workspace_file.path.display().to_string(),
+                content: input,
+                diagnostics: result
+                    .matches
+                    .into_iter()
+                    .map(|span| Error::from(SearchDiagnostic { span }))
+                    .collect(),
+                skipped_diagnostics: 0,
+            });
+
+            Ok(FileStatus::Unchanged)
+        },
+    )
+}
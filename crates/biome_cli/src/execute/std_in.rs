@@ -1,15 +1,16 @@
 //! In here, there are the operations that run via standard input
 //!
-use crate::execute::diagnostics::{ContentDiffAdvice, FormatDiffDiagnostic};
-use crate::execute::Execution;
+use crate::execute::diagnostics::{ContentDiffAdvice, FormatDiffDiagnostic, SearchDiagnostic};
+use crate::execute::{Execution, TraversalMode};
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{markup, ConsoleExt};
-use biome_diagnostics::Diagnostic;
 use biome_diagnostics::PrintDiagnostic;
+use biome_diagnostics::{Diagnostic, DiagnosticExt};
 use biome_fs::BiomePath;
 use biome_service::workspace::{
     ChangeFileParams, FeaturesBuilder, FixFileParams, FormatFileParams, OpenFileParams,
-    OrganizeImportsParams, PullDiagnosticsParams, RuleCategories, SupportsFeatureParams,
+    OrganizeImportsParams, PullDiagnosticsParams, RuleCategories, SearchPatternParams,
+    SupportsFeatureParams,
 };
 use biome_service::WorkspaceError;
 use std::borrow::Cow;
@@ -103,6 +104,7 @@ pub(crate) fn run<'a>(
                     fix_file_mode: *fix_file_mode,
                     path: biome_path.clone(),
                     should_format: mode.is_check() && file_features.supports_format(),
+                    only_rule: None,
                 })?;
                 if fix_file_result.code != new_content {
                     version += 1;
@@ -136,6 +138,7 @@ pub(crate) fn run<'a>(
                 categories: RuleCategories::LINT | RuleCategories::SYNTAX,
                 path: biome_path.clone(),
                 max_diagnostics: mode.max_diagnostics.into(),
+                content: None,
             })?;
             diagnostics.extend(result.diagnostics);
         }
@@ -179,6 +182,37 @@ pub(crate) fn run<'a>(
                 })
             }
         }
+    } else if let TraversalMode::Search { pattern, .. } = mode.traversal_mode() {
+        let file_features = workspace.file_features(SupportsFeatureParams {
+            path: biome_path.clone(),
+            feature: FeaturesBuilder::new().with_search().build(),
+        })?;
+        if !file_features.supports_search() {
+            console.error(markup! {
+                <Warn>"The content was not searched because the language of the file isn't supported."</Warn>
+            });
+            return Ok(());
+        }
+
+        workspace.open_file(OpenFileParams {
+            path: biome_path.clone(),
+            version: 0,
+            content: content.into(),
+            document_file_source: None,
+        })?;
+        let result = workspace.search_pattern(SearchPatternParams {
+            path: biome_path.clone(),
+            pattern: *pattern,
+        })?;
+
+        for span in result.matches {
+            let diagnostic = SearchDiagnostic { span }
+                .with_file_path(biome_path.display().to_string())
+                .with_file_source_code(content);
+            console.error(markup! {
+                {if verbose { PrintDiagnostic::verbose(&diagnostic) } else { PrintDiagnostic::simple(&diagnostic) }}
+            })
+        }
     } else {
         console.append(markup! {{content}});
     }
@@ -5,18 +5,24 @@ use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice,
     FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic,
 };
+use crate::reports::baseline::{Baseline, BaselineMode};
 use crate::{CliDiagnostic, CliSession, Execution, FormatterReportSummary, Report, TraversalMode};
 use biome_console::fmt::Formatter;
 use biome_console::{fmt, markup, Console, ConsoleExt};
+use biome_deserialize::Merge;
 use biome_diagnostics::DiagnosticTags;
 use biome_diagnostics::PrintGitHubDiagnostic;
 use biome_diagnostics::{category, DiagnosticExt, Error, PrintDiagnostic, Resource, Severity};
-use biome_fs::{BiomePath, FileSystem, PathInterner};
+use biome_fs::{BiomePath, ConfigName, FileSystem, OpenOptions, PathInterner};
 use biome_fs::{TraversalContext, TraversalScope};
-use biome_service::workspace::{FeaturesBuilder, IsPathIgnoredParams};
+use biome_service::configuration::{
+    load_json_schemas, load_nested_configuration, load_plugins, LoadedConfiguration,
+};
+use biome_service::workspace::{FeaturesBuilder, IsPathIgnoredParams, UpdateSettingsParams};
 use biome_service::{extension_error, workspace::SupportsFeatureParams, Workspace, WorkspaceError};
 use crossbeam::channel::{unbounded, Receiver, Sender};
 use rustc_hash::FxHashSet;
+use std::collections::BTreeSet;
 use std::sync::atomic::AtomicU32;
 use std::{
     ffi::OsString,
@@ -25,7 +31,7 @@ use std::{
     path::{Path, PathBuf},
     sync::{
         atomic::{AtomicU16, AtomicUsize, Ordering},
-        Once,
+        Mutex, Once,
     },
     thread,
     time::{Duration, Instant},
@@ -80,6 +86,9 @@ impl<'a> fmt::Display for SummaryTotal<'a> {
                     "Checked "{files}" in "{self.2}
                 })
             }
+            TraversalMode::Search { .. } => fmt.write_markup(markup! {
+                "Searched "{files}" in "{self.2}
+            }),
             TraversalMode::Format { write, .. } => {
                 if *write {
                     fmt.write_markup(markup! {
@@ -114,8 +123,12 @@ impl<'a> fmt::Display for SummaryResult<'a> {
             self.changed + self.unchanged,
             &self.duration,
         );
-        let detail = SummaryDetail(self.changed);
-        fmt.write_markup(markup!(<Info>{summary}{detail}</Info>))?;
+        if matches!(self.traversal, TraversalMode::Search { .. }) {
+            fmt.write_markup(markup!(<Info>{summary}"."</Info>))?;
+        } else {
+            let detail = SummaryDetail(self.changed);
+            fmt.write_markup(markup!(<Info>{summary}{detail}</Info>))?;
+        }
 
         if self.errors > 0 {
             if self.errors == 1 {
@@ -137,8 +150,8 @@ impl<'a> fmt::Display for SummaryResult<'a> {
 
 ///
 pub(crate) fn traverse(
-    execution: Execution,
-    session: CliSession,
+    execution: &Execution,
+    session: &mut CliSession,
     cli_options: &CliOptions,
     inputs: Vec<OsString>,
 ) -> Result<(), CliDiagnostic> {
@@ -169,7 +182,7 @@ pub(crate) fn traverse(
 
     let mut report = Report::default();
 
-    let printer = DiagnosticsPrinter::new(&execution)
+    let printer = DiagnosticsPrinter::new(execution)
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
         .with_max_diagnostics(max_diagnostics);
@@ -190,7 +203,7 @@ pub(crate) fn traverse(
             &TraversalOptions {
                 fs,
                 workspace,
-                execution: &execution,
+                execution,
                 interner,
                 changed: &changed,
                 unchanged: &unchanged,
@@ -205,6 +218,14 @@ pub(crate) fn traverse(
         elapsed
     });
 
+    if let Some(BaselineMode::Write {
+        path: baseline_path,
+        ..
+    }) = execution.as_baseline_mode()
+    {
+        write_baseline(fs, baseline_path, &printer.take_baseline(), console)?;
+    }
+
     let errors = printer.errors();
     let warnings = printer.warnings();
     let changed = changed.load(Ordering::Relaxed);
@@ -275,6 +296,37 @@ pub(crate) fn traverse(
     }
 }
 
+/// Writes the diagnostics recorded by `--write-baseline` to `path`
+fn write_baseline(
+    fs: &dyn FileSystem,
+    path: &Path,
+    baseline: &Baseline,
+    console: &mut dyn Console,
+) -> Result<(), CliDiagnostic> {
+    let content = baseline.to_json().map_err(|error| {
+        CliDiagnostic::invalid_baseline(path.display().to_string(), error.to_string())
+    })?;
+    let mut file = fs
+        .open_with_options(
+            path,
+            OpenOptions::default()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )
+        .map_err(CliDiagnostic::io_error)?;
+    file.set_content(content.as_bytes())
+        .map_err(CliDiagnostic::io_error)?;
+
+    let count = baseline.len();
+    let path = path.display().to_string();
+    console.log(markup! {
+        <Info>"Wrote "{count}" diagnostics to the baseline "<Emphasis>{path}</Emphasis>"."</Info>
+    });
+
+    Ok(())
+}
+
 /// This function will setup the global Rayon thread pool the first time it's called
 ///
 /// This is currently only used to assign friendly debug names to the threads of the pool
@@ -292,6 +344,9 @@ fn init_thread_pool() {
 /// run it to completion, returning the duration of the process
 fn traverse_inputs(fs: &dyn FileSystem, inputs: Vec<OsString>, ctx: &TraversalOptions) -> Duration {
     let start = Instant::now();
+
+    load_nested_configurations(fs, &inputs, ctx);
+
     fs.traversal(Box::new(move |scope: &dyn TraversalScope| {
         for input in inputs {
             scope.spawn(ctx, PathBuf::from(input));
@@ -301,6 +356,96 @@ fn traverse_inputs(fs: &dyn FileSystem, inputs: Vec<OsString>, ctx: &TraversalOp
     start.elapsed()
 }
 
+/// Loads the nested configuration files (`"root": false`) of the directories containing
+/// the inputs and of the directories inside them, before any file is processed.
+///
+/// Each directory is resolved once, from the outermost to the innermost, so that a
+/// nested configuration is merged over the configuration of its parent directories.
+fn load_nested_configurations(fs: &dyn FileSystem, inputs: &[OsString], ctx: &TraversalOptions) {
+    let (interner, _) = PathInterner::new();
+    let collector = NestedConfigurationsCollector {
+        options: ctx,
+        interner,
+        directories: Mutex::new(BTreeSet::new()),
+    };
+
+    for input in inputs {
+        let ancestors = Path::new(input)
+            .ancestors()
+            .skip(1)
+            .filter(|ancestor| !ancestor.as_os_str().is_empty());
+        collector.add_directory(ancestors.map(Path::to_path_buf));
+    }
+
+    fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+        for input in inputs {
+            scope.spawn(&collector, PathBuf::from(input));
+        }
+    }));
+
+    // Parents are sorted before their subdirectories
+    let directories = collector.directories.into_inner().unwrap();
+    for directory in directories {
+        ctx.load_nested_configuration(&directory);
+    }
+}
+
+/// Traversal context collecting the directories that contain a configuration file,
+/// without processing any file
+struct NestedConfigurationsCollector<'ctx, 'app> {
+    options: &'ctx TraversalOptions<'ctx, 'app>,
+    interner: PathInterner,
+    directories: Mutex<BTreeSet<PathBuf>>,
+}
+
+impl NestedConfigurationsCollector<'_, '_> {
+    fn add_directory(&self, directories: impl IntoIterator<Item = PathBuf>) {
+        self.directories.lock().unwrap().extend(directories);
+    }
+
+    fn is_ignored(&self, biome_path: BiomePath) -> bool {
+        self.options
+            .workspace
+            .is_path_ignored(IsPathIgnoredParams {
+                biome_path,
+                feature: self.options.execution.as_feature_name(),
+            })
+            .unwrap_or(true)
+    }
+}
+
+impl TraversalContext for NestedConfigurationsCollector<'_, '_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _error: Error) {
+        // The errors of the traversal are reported by the traversal processing the files
+    }
+
+    fn can_handle(&self, biome_path: &BiomePath) -> bool {
+        let path = biome_path.as_path();
+        if !self.options.fs.path_is_file(path) {
+            return !self.is_ignored(biome_path.clone());
+        }
+
+        let is_configuration_file = path.file_name().is_some_and(|file_name| {
+            ConfigName::file_names()
+                .iter()
+                .any(|config_name| file_name == *config_name)
+        });
+        if let Some(directory) = path.parent().filter(|_| is_configuration_file) {
+            if !directory.as_os_str().is_empty() && !self.is_ignored(BiomePath::new(directory)) {
+                self.add_directory([directory.to_path_buf()]);
+            }
+        }
+
+        false
+    }
+
+    fn handle_file(&self, _path: &Path) {}
+}
+
 // struct DiagnosticsReporter<'ctx> {}
 
 struct DiagnosticsPrinter<'ctx> {
@@ -324,6 +469,11 @@ struct DiagnosticsPrinter<'ctx> {
 
     not_printed_diagnostics: AtomicU32,
     printed_diagnostics: AtomicU32,
+
+    /// The baseline filled by `--write-baseline`, or consumed by `--baseline`
+    baseline: Mutex<Baseline>,
+    /// The number of diagnostics ignored because they are recorded in the baseline
+    baselined_diagnostics: AtomicU32,
 }
 
 impl<'ctx> DiagnosticsPrinter<'ctx> {
@@ -338,6 +488,11 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
             max_diagnostics: 20,
             not_printed_diagnostics: AtomicU32::new(0),
             printed_diagnostics: AtomicU32::new(0),
+            baseline: Mutex::new(match execution.as_baseline_mode() {
+                Some(BaselineMode::Filter { baseline, .. }) => baseline.clone(),
+                Some(BaselineMode::Write { .. }) | None => Baseline::default(),
+            }),
+            baselined_diagnostics: AtomicU32::new(0),
         }
     }
 
@@ -364,6 +519,40 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self.warnings.load(Ordering::Relaxed)
     }
 
+    /// Takes the diagnostics recorded with `--write-baseline`
+    fn take_baseline(&self) -> Baseline {
+        std::mem::take(&mut *self.baseline.lock().unwrap())
+    }
+
+    /// Records the diagnostic in the baseline, or looks it up, depending on the
+    /// baseline mode. Returns `true` if the diagnostic shouldn't be reported.
+    fn apply_baseline(&self, path: &str, content: &str, diagnostic: &Error) -> bool {
+        let Some(mode) = self.execution.as_baseline_mode() else {
+            return false;
+        };
+        let path = mode.root().key(path);
+        match mode {
+            BaselineMode::Write { .. } => {
+                self.baseline
+                    .lock()
+                    .unwrap()
+                    .record(&path, content, diagnostic);
+                true
+            }
+            BaselineMode::Filter { .. } => {
+                let recorded = self
+                    .baseline
+                    .lock()
+                    .unwrap()
+                    .take(&path, content, diagnostic);
+                if recorded {
+                    self.baselined_diagnostics.fetch_add(1, Ordering::Relaxed);
+                }
+                recorded
+            }
+        }
+    }
+
     /// Checks if the diagnostic we received from the thread should be considered or not. Logic:
     /// - it should not be considered if its severity level is lower than the one provided via CLI;
     /// - it should not be considered if it's a verbose diagnostic and the CLI **didn't** request a `--verbose` option.
@@ -495,6 +684,9 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                             if self.should_skip_diagnostic(severity, diag.tags()) {
                                 continue;
                             }
+                            if self.apply_baseline(&name, &content, &diag) {
+                                continue;
+                            }
                             if severity == Severity::Error {
                                 self.errors.fetch_add(1, Ordering::Relaxed);
                             }
@@ -627,6 +819,13 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                 <Info>"Diagnostics not shown: "</Info><Emphasis>{not_printed_diagnostics}</Emphasis><Info>"."</Info>
             })
         }
+
+        let baselined_diagnostics = self.baselined_diagnostics.load(Ordering::Relaxed);
+        if baselined_diagnostics > 0 {
+            console.log(markup! {
+                <Info>"Ignored "{baselined_diagnostics}" diagnostics recorded in the baseline."</Info>
+            })
+        }
     }
 }
 
@@ -679,6 +878,52 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
             WorkspaceError::protected_file(biome_path.display().to_string()).into(),
         )
     }
+
+    /// Registers the nested configuration file (`"root": false`) placed inside `directory`,
+    /// if there's one, so that the files of the directory use its settings
+    fn load_nested_configuration(&self, directory: &Path) {
+        let loaded_configuration = match load_nested_configuration(self.fs, directory) {
+            Ok(Some(loaded_configuration)) => loaded_configuration,
+            Ok(None) => return,
+            Err(err) => {
+                self.push_diagnostic(err.into());
+                return;
+            }
+        };
+
+        if loaded_configuration.has_errors() {
+            for diagnostic in loaded_configuration.diagnostics {
+                self.push_diagnostic(diagnostic);
+            }
+            return;
+        }
+
+        let LoadedConfiguration {
+            mut configuration, ..
+        } = loaded_configuration;
+        configuration.merge_with(self.execution.cli_configuration().clone());
+        let result = load_plugins(self.fs, configuration.plugins.as_ref()).and_then(|plugins| {
+            let json_schemas = load_json_schemas(
+                self.fs,
+                configuration
+                    .json
+                    .as_ref()
+                    .and_then(|json| json.schemas.as_ref()),
+            )?;
+            self.workspace.update_settings(UpdateSettingsParams {
+                working_directory: Some(directory.to_path_buf()),
+                configuration,
+                vcs_base_path: None,
+                gitignore_matches: vec![],
+                plugins,
+                json_schemas,
+                project_folder: Some(directory.to_path_buf()),
+            })
+        });
+        if let Err(err) = result {
+            self.push_diagnostic(err.into());
+        }
+    }
 }
 
 impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
@@ -717,6 +962,7 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
                 .with_linter()
                 .with_formatter()
                 .with_organize_imports()
+                .with_search()
                 .build(),
         });
 
@@ -748,6 +994,7 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
             }
             TraversalMode::Format { .. } => file_features.supports_format(),
             TraversalMode::Lint { .. } => file_features.supports_lint(),
+            TraversalMode::Search { .. } => file_features.supports_search(),
             // Imagine if Biome can't handle its own configuration file...
             TraversalMode::Migrate { .. } => true,
         }
@@ -0,0 +1,207 @@
+use crate::cli_options::CliOptions;
+use crate::execute::traverse::traverse;
+use crate::{CliDiagnostic, CliSession, Execution};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::PrintDiagnostic;
+use biome_fs::BiomePath;
+use biome_service::workspace::{FeaturesBuilder, SupportsFeatureParams};
+use crossbeam::channel::{unbounded, Receiver, RecvTimeoutError};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The time to wait for other events after a file changed. Editors and formatters
+/// usually emit several events when they save a file, they're processed in one run.
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(100);
+
+/// Runs the traversal on `inputs`, then keeps watching them and runs the traversal
+/// again on the files that changed.
+///
+/// The same workspace is used for every run, so the settings are loaded only once.
+/// The diagnostics of a run are printed, but they don't stop the command.
+pub(crate) fn run(
+    execution: Execution,
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    inputs: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    let result = traverse(&execution, &mut session, cli_options, inputs.clone());
+    print_result(&mut session, cli_options, result);
+
+    let (sender, receiver) = unbounded();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        // The receiver is dropped only when the command stops
+        let _ = sender.send(event);
+    })
+    .map_err(watch_error)?;
+    for input in &inputs {
+        watcher
+            .watch(Path::new(input), RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+    }
+
+    session.app.console.log(markup! {
+        <Info>"Watching for file changes..."</Info>
+    });
+
+    // The watcher reports absolute paths, they're made relative to the working directory
+    // like the paths of the inputs, so that they match the ignore patterns
+    let working_directory = session.app.fs.working_directory();
+    while let Some(changed_paths) = next_changed_paths(&receiver, working_directory.as_deref()) {
+        let paths = files_to_process(&session, changed_paths);
+        if paths.is_empty() {
+            continue;
+        }
+
+        let result = traverse(&execution, &mut session, cli_options, paths);
+        print_result(&mut session, cli_options, result);
+    }
+
+    Ok(())
+}
+
+/// Waits for the next file events, and returns the paths that were created or modified,
+/// relative to `working_directory`.
+///
+/// Returns [None] when the watcher stopped.
+fn next_changed_paths(
+    receiver: &Receiver<notify::Result<Event>>,
+    working_directory: Option<&Path>,
+) -> Option<BTreeSet<PathBuf>> {
+    let mut changed_paths = BTreeSet::new();
+    collect_paths(receiver.recv().ok()?, working_directory, &mut changed_paths);
+
+    loop {
+        match receiver.recv_timeout(DEBOUNCE_DURATION) {
+            Ok(event) => collect_paths(event, working_directory, &mut changed_paths),
+            Err(RecvTimeoutError::Timeout) => return Some(changed_paths),
+            Err(RecvTimeoutError::Disconnected) => return None,
+        }
+    }
+}
+
+fn collect_paths(
+    event: notify::Result<Event>,
+    working_directory: Option<&Path>,
+    changed_paths: &mut BTreeSet<PathBuf>,
+) {
+    match event {
+        Ok(event) => {
+            if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                changed_paths.extend(
+                    event
+                        .paths
+                        .into_iter()
+                        .map(|path| relative_path(path, working_directory)),
+                );
+            }
+        }
+        Err(error) => {
+            tracing::error!("Failed to watch the files: {error}");
+        }
+    }
+}
+
+/// Returns `path` relative to `working_directory`, or `path` itself if it's outside of it
+fn relative_path(path: PathBuf, working_directory: Option<&Path>) -> PathBuf {
+    working_directory
+        .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+        .map_or_else(|| path.clone(), Path::to_path_buf)
+}
+
+/// Keeps the changed files that still exist, and that the command can handle.
+/// The files ignored by `files.ignore` or by the VCS are dropped.
+fn files_to_process(session: &CliSession, changed_paths: BTreeSet<PathBuf>) -> Vec<OsString> {
+    changed_paths
+        .into_iter()
+        .filter(|path| session.app.fs.path_is_file(path))
+        .filter(|path| {
+            let file_features = session.app.workspace.file_features(SupportsFeatureParams {
+                path: BiomePath::new(path),
+                feature: FeaturesBuilder::new()
+                    .with_linter()
+                    .with_formatter()
+                    .with_organize_imports()
+                    .with_search()
+                    .build(),
+            });
+            file_features.is_ok_and(|file_features| {
+                !file_features.is_ignored()
+                    && !file_features.is_protected()
+                    && !file_features.is_not_supported()
+            })
+        })
+        .map(PathBuf::into_os_string)
+        .collect()
+}
+
+fn watch_error(error: notify::Error) -> CliDiagnostic {
+    CliDiagnostic::io_error(io::Error::new(io::ErrorKind::Other, error))
+}
+
+fn print_result(
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    result: Result<(), CliDiagnostic>,
+) {
+    if let Err(error) = result {
+        if cli_options.verbose {
+            session
+                .app
+                .console
+                .error(markup! {{PrintDiagnostic::verbose(&error)}});
+        } else {
+            session
+                .app
+                .console
+                .error(markup! {{PrintDiagnostic::simple(&error)}});
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::collect_paths;
+    use notify::event::{CreateKind, ModifyKind, RemoveKind};
+    use notify::{Event, EventKind};
+    use std::collections::BTreeSet;
+    use std::path::{Path, PathBuf};
+
+    fn changed_paths(events: Vec<Event>, working_directory: Option<&Path>) -> Vec<PathBuf> {
+        let mut changed_paths = BTreeSet::new();
+        for event in events {
+            collect_paths(Ok(event), working_directory, &mut changed_paths);
+        }
+        changed_paths.into_iter().collect()
+    }
+
+    #[test]
+    fn paths_are_relative_to_the_working_directory() {
+        let events = vec![
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path("/project/src/a.js".into()),
+            Event::new(EventKind::Create(CreateKind::File)).add_path("/other/b.js".into()),
+        ];
+
+        assert_eq!(
+            changed_paths(events, Some(Path::new("/project"))),
+            [PathBuf::from("/other/b.js"), PathBuf::from("src/a.js")]
+        );
+    }
+
+    #[test]
+    fn removed_paths_are_skipped() {
+        let events = vec![
+            Event::new(EventKind::Remove(RemoveKind::File)).add_path("/project/a.js".into()),
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path("/project/b.js".into()),
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path("/project/b.js".into()),
+        ];
+
+        assert_eq!(
+            changed_paths(events, Some(Path::new("/project"))),
+            [PathBuf::from("b.js")]
+        );
+    }
+}
@@ -27,6 +27,7 @@ use crate::commands::check::CheckCommandPayload;
 use crate::commands::ci::CiCommandPayload;
 use crate::commands::format::FormatCommandPayload;
 use crate::commands::lint::LintCommandPayload;
+use crate::commands::search::SearchCommandPayload;
 pub use crate::commands::{biome_command, BiomeCommand};
 pub use crate::logging::{setup_cli_subscriber, LoggingLevel};
 pub use diagnostics::CliDiagnostic;
@@ -92,6 +93,7 @@ impl<'app> CliSession<'app> {
                 formatter_enabled,
                 changed,
                 since,
+                watch,
             } => commands::check::check(
                 self,
                 CheckCommandPayload {
@@ -106,6 +108,7 @@ impl<'app> CliSession<'app> {
                     formatter_enabled,
                     changed,
                     since,
+                    watch,
                 },
             ),
             BiomeCommand::Lint {
@@ -119,6 +122,10 @@ impl<'app> CliSession<'app> {
                 files_configuration,
                 changed,
                 since,
+                write_baseline,
+                baseline,
+                baseline_path,
+                watch,
             } => commands::lint::lint(
                 self,
                 LintCommandPayload {
@@ -132,6 +139,28 @@ impl<'app> CliSession<'app> {
                     files_configuration,
                     changed,
                     since,
+                    write_baseline,
+                    baseline,
+                    baseline_path,
+                    watch,
+                },
+            ),
+            BiomeCommand::Search {
+                cli_options,
+                vcs_configuration,
+                files_configuration,
+                stdin_file_path,
+                pattern,
+                paths,
+            } => commands::search::search(
+                self,
+                SearchCommandPayload {
+                    cli_options,
+                    vcs_configuration,
+                    files_configuration,
+                    stdin_file_path,
+                    pattern,
+                    paths,
                 },
             ),
             BiomeCommand::Ci {
@@ -169,6 +198,7 @@ impl<'app> CliSession<'app> {
                 css_formatter,
                 changed,
                 since,
+                watch,
             } => commands::format::format(
                 self,
                 FormatCommandPayload {
@@ -184,6 +214,7 @@ impl<'app> CliSession<'app> {
                     css_formatter,
                     changed,
                     since,
+                    watch,
                 },
             ),
             BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
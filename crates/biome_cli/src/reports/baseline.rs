@@ -0,0 +1,243 @@
+use biome_diagnostics::Error;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+/// The file used by `--baseline` and `--write-baseline` when `--baseline-path` isn't provided
+pub(crate) const DEFAULT_BASELINE_PATH: &str = "biome-baseline.json";
+
+/// How the diagnostics emitted by a traversal interact with a baseline
+#[derive(Debug)]
+pub(crate) enum BaselineMode {
+    /// Record all the diagnostics, and write them to the given file
+    Write { path: PathBuf, root: BaselineRoot },
+    /// Report only the diagnostics that aren't recorded in the baseline
+    Filter {
+        baseline: Baseline,
+        root: BaselineRoot,
+    },
+}
+
+impl BaselineMode {
+    pub(crate) fn root(&self) -> &BaselineRoot {
+        match self {
+            BaselineMode::Write { root, .. } | BaselineMode::Filter { root, .. } => root,
+        }
+    }
+}
+
+/// The directory the files of the baseline are relative to: the directory of
+/// the configuration file, or the working directory when there's no configuration.
+///
+/// The paths recorded in the baseline don't depend on the directory Biome is
+/// run from, and use `/` as separator on every platform.
+#[derive(Debug, Default)]
+pub(crate) struct BaselineRoot {
+    working_directory: Option<PathBuf>,
+    root: Option<PathBuf>,
+}
+
+impl BaselineRoot {
+    pub(crate) fn new(working_directory: Option<PathBuf>, root: Option<PathBuf>) -> Self {
+        let root = match (&working_directory, root) {
+            (Some(working_directory), Some(root)) => Some(working_directory.join(root)),
+            (_, root) => root.or_else(|| working_directory.clone()),
+        };
+        Self {
+            working_directory,
+            root: root.map(|root| normalize(&root)),
+        }
+    }
+
+    /// Returns the key of the file at `path`, relative to the working directory
+    pub(crate) fn key(&self, path: &str) -> String {
+        let path = match &self.working_directory {
+            Some(working_directory) => working_directory.join(path),
+            None => PathBuf::from(path),
+        };
+        let path = normalize(&path);
+        let path = self
+            .root
+            .as_deref()
+            .and_then(|root| path.strip_prefix(root).ok())
+            .unwrap_or(&path);
+
+        path.components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(name.to_string_lossy()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+/// Removes the `.` and `..` components of `path`
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// The diagnostics that existed when the baseline was written, grouped by file.
+///
+/// A diagnostic is identified by its category and by a fingerprint of the code it
+/// points to, so that moving code around a file doesn't invalidate the baseline.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub(crate) struct Baseline {
+    files: BTreeMap<String, Vec<BaselineEntry>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct BaselineEntry {
+    /// The category of the diagnostic, e.g. `lint/suspicious/noDebugger`
+    rule: String,
+    /// Hash of the code the diagnostic points to, whitespaces excluded
+    fingerprint: String,
+    /// How many diagnostics of this file share the same rule and fingerprint
+    count: u32,
+}
+
+impl Baseline {
+    pub(crate) fn from_json(content: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(content)
+    }
+
+    pub(crate) fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// The number of diagnostics recorded in the baseline
+    pub(crate) fn len(&self) -> u32 {
+        self.files.values().flatten().map(|entry| entry.count).sum()
+    }
+
+    /// Records a diagnostic of the file `path`, whose source code is `content`.
+    ///
+    /// Diagnostics without a category can't be recorded.
+    pub(crate) fn record(&mut self, path: &str, content: &str, diagnostic: &Error) {
+        let Some((rule, fingerprint)) = identify(content, diagnostic) else {
+            return;
+        };
+
+        let entries = self.files.entry(path.to_string()).or_default();
+        match entries
+            .iter_mut()
+            .find(|entry| entry.rule == rule && entry.fingerprint == fingerprint)
+        {
+            Some(entry) => entry.count += 1,
+            None => entries.push(BaselineEntry {
+                rule,
+                fingerprint,
+                count: 1,
+            }),
+        }
+    }
+
+    /// Returns `true` if the diagnostic is recorded in the baseline. Each recorded
+    /// diagnostic can only match once, so a new occurrence of an existing violation
+    /// is still reported.
+    pub(crate) fn take(&mut self, path: &str, content: &str, diagnostic: &Error) -> bool {
+        let Some((rule, fingerprint)) = identify(content, diagnostic) else {
+            return false;
+        };
+        let Some(entries) = self.files.get_mut(path) else {
+            return false;
+        };
+
+        match entries
+            .iter_mut()
+            .find(|entry| entry.count > 0 && entry.rule == rule && entry.fingerprint == fingerprint)
+        {
+            Some(entry) => {
+                entry.count -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Computes the rule and the fingerprint of a diagnostic
+fn identify(content: &str, diagnostic: &Error) -> Option<(String, String)> {
+    let rule = diagnostic.category()?.name().to_string();
+    let code = diagnostic
+        .location()
+        .span
+        .and_then(|span| content.get(usize::from(span.start())..usize::from(span.end())))
+        .unwrap_or_default();
+
+    Some((rule, fingerprint(code)))
+}
+
+/// FNV-1a hash of the words of `code`, so the fingerprint doesn't depend on
+/// the formatting or the position of the code
+fn fingerprint(code: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for word in code.split_whitespace() {
+        for byte in word.bytes().chain([b' ']) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_diagnostics::{Diagnostic, DiagnosticExt, Severity};
+    use biome_rowan::{TextRange, TextSize};
+
+    #[derive(Debug, Diagnostic)]
+    #[diagnostic(category = "lint/suspicious/noDebugger")]
+    struct TestDiagnostic {
+        #[location(span)]
+        span: TextRange,
+    }
+
+    fn diagnostic(content: &str, code: &str) -> Error {
+        let start = content.find(code).unwrap();
+        let span = TextRange::at(
+            TextSize::from(start as u32),
+            TextSize::from(code.len() as u32),
+        );
+        TestDiagnostic { span }.with_severity(Severity::Error)
+    }
+
+    #[test]
+    fn matches_moved_code() {
+        let before = "debugger;";
+        let after = "\n\nfoo();\n  debugger;";
+
+        let mut baseline = Baseline::default();
+        baseline.record("file.js", before, &diagnostic(before, "debugger"));
+        assert_eq!(baseline.len(), 1);
+
+        let mut baseline = Baseline::from_json(&baseline.to_json().unwrap()).unwrap();
+        assert!(baseline.take("file.js", after, &diagnostic(after, "debugger")));
+        assert!(!baseline.take("file.js", after, &diagnostic(after, "debugger")));
+        assert!(!baseline.take("other.js", after, &diagnostic(after, "debugger")));
+    }
+
+    #[test]
+    fn keys_are_relative_to_the_root() {
+        let root = BaselineRoot::new(
+            Some(PathBuf::from("/project/packages")),
+            Some(PathBuf::from("..")),
+        );
+        assert_eq!(root.key("app/./src/file.js"), "packages/app/src/file.js");
+        assert_eq!(root.key("/project/lib/file.js"), "lib/file.js");
+
+        let root = BaselineRoot::new(None, None);
+        assert_eq!(root.key("./src/file.js"), "src/file.js");
+    }
+}
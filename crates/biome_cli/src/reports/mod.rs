@@ -1,3 +1,4 @@
+pub(crate) mod baseline;
 pub mod formatter;
 
 use crate::reports::formatter::{FormatterReportFileDetail, FormatterReportSummary};
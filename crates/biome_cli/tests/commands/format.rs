@@ -3361,3 +3361,96 @@ fn format_empty_svelte_ts_files_write() {
         result,
     ));
 }
+
+#[test]
+fn format_with_nested_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), r#"{}"#.as_bytes());
+    fs.insert(
+        Path::new("packages/biome.json").into(),
+        r#"{ "root": false, "formatter": { "indentStyle": "space" } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("file.js").into(),
+        "function f() {return 1}\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/file.js").into(),
+        "function f() {return 1}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("file.js"), ("packages")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        Path::new("file.js"),
+        "function f() {\n\treturn 1;\n}\n",
+    );
+    assert_file_contents(
+        &fs,
+        Path::new("packages/file.js"),
+        "function f() {\n  return 1;\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_with_nested_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_arguments_override_nested_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), r#"{}"#.as_bytes());
+    fs.insert(
+        Path::new("packages/biome.json").into(),
+        r#"{ "root": false, "formatter": { "indentStyle": "space" } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/file.js").into(),
+        "function f() {return 1}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                ("--indent-style=tab"),
+                ("packages"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        Path::new("packages/file.js"),
+        "function f() {\n\treturn 1;\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_arguments_override_nested_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -16,6 +16,7 @@ use crate::configs::{
 };
 use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
 use crate::{assert_cli_snapshot, run_cli, FORMATTED, LINT_ERROR, PARSE_ERROR};
+use biome_cli::CliDiagnostic;
 use biome_console::{markup, BufferConsole, LogLevel, MarkupBuf};
 use biome_fs::{ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
 use biome_service::DynRef;
@@ -2796,3 +2797,371 @@ import "lodash";
         result,
     ));
 }
+
+#[test]
+fn write_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/check.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\nclass A { #foo; #foo }\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--write-baseline"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+/// Writes a baseline of `content`, then lints `new_content` with the baseline
+fn lint_with_baseline(
+    fs: &mut MemoryFileSystem,
+    console: &mut BufferConsole,
+    content: &str,
+    new_content: &str,
+) -> Result<(), CliDiagnostic> {
+    let file_path = Path::new("src/check.js");
+    fs.insert(file_path.into(), content.as_bytes());
+
+    // The path isn't normalized, the baseline must still match the file
+    let result = run_cli(
+        DynRef::Borrowed(fs),
+        console,
+        Args::from([("lint"), ("--write-baseline"), ("./src/check.js")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.insert(file_path.into(), new_content.as_bytes());
+    run_cli(
+        DynRef::Borrowed(fs),
+        console,
+        Args::from([("lint"), ("--baseline"), ("src/check.js")].as_slice()),
+    )
+}
+
+#[test]
+fn baseline_ignores_moved_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = lint_with_baseline(
+        &mut fs,
+        &mut console,
+        "debugger;\nclass A { #foo; #foo; }\n",
+        "let a;\n\ndebugger;\n\nclass A {\n  #foo;\n  #foo;\n}\n",
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_ignores_moved_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_reports_new_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = lint_with_baseline(
+        &mut fs,
+        &mut console,
+        "debugger;\n",
+        "debugger;\ndebugger;\n",
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_reports_new_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_and_write_baseline_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--write-baseline"),
+                ("--baseline"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_and_write_baseline_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn apply_removes_unused_suppression_comments() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(
+        file_path.into(),
+        "// biome-ignore lint/suspicious/noDebugger: not needed anymore\nfoo();\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_removes_unused_suppression_comments",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn apply_removes_suppression_comments_of_unknown_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(
+        file_path.into(),
+        "// biome-ignore lint/suspicious/noSuchRule: the rule doesn't exist\nfoo();\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_removes_suppression_comments_of_unknown_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_with_plugins_from_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["./plugins/noConsoleLog.json"] }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("plugins/noConsoleLog.json").into(),
+        r#"{
+    "name": "noConsoleLog",
+    "pattern": "console.log($msg)",
+    "message": "Use the logger instead of console.log.",
+    "level": "error"
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "console.log(\"hello\");\n// biome-ignore plugin/noConsoleLog: explained\nconsole.log(\"world\");\n"
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_with_plugins_from_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_with_suppression_of_unloaded_plugin() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["./plugins/noConsoleLog.json"] }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("plugins/noConsoleLog.json").into(),
+        r#"{
+    "name": "noConsoleLog",
+    "pattern": "console.log($msg)",
+    "message": "Use the logger instead of console.log.",
+    "level": "error"
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "// biome-ignore plugin/notLoaded: explained\nconsole.log(\"hello\");\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_with_suppression_of_unloaded_plugin",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_with_missing_plugin_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["./plugins/missing.json"] }"#.as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "console.log(\"hello\");\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_with_missing_plugin_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn apply_keeps_suppression_comments_of_disabled_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#.as_bytes(),
+    );
+
+    let file_path = Path::new("fix.js");
+    fs.insert(
+        file_path.into(),
+        "// biome-ignore lint/suspicious/noDebugger: kept when the rule is enabled again\nfoo();\n"
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_keeps_suppression_comments_of_disabled_rules",
+        fs,
+        console,
+        result,
+    ));
+}
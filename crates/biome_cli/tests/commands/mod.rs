@@ -7,4 +7,5 @@ mod lint;
 mod lsp_proxy;
 mod migrate;
 mod rage;
+mod search;
 mod version;
@@ -340,6 +340,194 @@ mod configuration {
             result,
         ));
     }
+
+    #[test]
+    fn override_environments() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        fs.insert(
+            Path::new("biome.json").into(),
+            r#"{
+                "linter": {
+                    "enabled": true,
+                    "rules": {
+                        "correctness": {
+                            "noUndeclaredVariables": "error"
+                        }
+                    }
+                },
+                "overrides": [{
+                    "include": ["tests"],
+                    "javascript": {
+                        "environments": ["jest"]
+                    }
+                }]
+            }"#
+            .as_bytes(),
+        );
+        fs.insert(
+            Path::new("tests/test.js").into(),
+            r#"describe("environments", () => {
+    it("knows the globals of Jest", () => {
+        expect(true).toBe(true);
+    });
+});"#
+                .as_bytes(),
+        );
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(&["lint", "tests/test.js"]),
+        );
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "override_environments",
+            fs,
+            console,
+            result,
+        ));
+    }
+
+    #[test]
+    fn json_schemas() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        fs.insert(
+            Path::new("biome.json").into(),
+            r#"{
+                "json": {
+                    "schemas": [
+                        {
+                            "include": ["services/*.json"],
+                            "path": "schemas/service.json"
+                        }
+                    ]
+                },
+                "linter": {
+                    "rules": {
+                        "nursery": {
+                            "noJsonSchemaViolations": "error"
+                        }
+                    }
+                }
+            }"#
+            .as_bytes(),
+        );
+        fs.insert(
+            Path::new("schemas/service.json").into(),
+            r#"{
+    "type": "object",
+    "properties": {
+        "name": { "type": "string" },
+        "port": { "type": "integer" }
+    }
+}"#
+            .as_bytes(),
+        );
+        fs.insert(
+            Path::new("services/api.json").into(),
+            r#"{ "name": "api", "port": "8080" }"#.as_bytes(),
+        );
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(&["lint", "services/api.json"]),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "json_schemas",
+            fs,
+            console,
+            result,
+        ));
+    }
+
+    #[test]
+    fn nested_configuration_resolves_parent_paths() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        fs.insert(
+            Path::new("biome.json").into(),
+            r#"{
+                "plugins": ["./plugins/noConsoleLog.json"],
+                "json": {
+                    "schemas": [
+                        {
+                            "include": ["**/services/*.json"],
+                            "path": "./schemas/service.json"
+                        }
+                    ]
+                },
+                "linter": {
+                    "rules": {
+                        "nursery": {
+                            "noJsonSchemaViolations": "error"
+                        }
+                    }
+                }
+            }"#
+            .as_bytes(),
+        );
+        fs.insert(
+            Path::new("plugins/noConsoleLog.json").into(),
+            r#"{
+    "name": "noConsoleLog",
+    "pattern": "console.log($msg)",
+    "message": "Use the logger instead of console.log.",
+    "level": "error"
+}"#
+            .as_bytes(),
+        );
+        fs.insert(
+            Path::new("schemas/service.json").into(),
+            r#"{
+    "type": "object",
+    "properties": {
+        "port": { "type": "integer" }
+    }
+}"#
+            .as_bytes(),
+        );
+        fs.insert(
+            Path::new("packages/app/biome.json").into(),
+            r#"{ "root": false, "formatter": { "indentStyle": "space" } }"#.as_bytes(),
+        );
+        fs.insert(
+            Path::new("packages/app/index.js").into(),
+            "console.log(\"hello\");\n".as_bytes(),
+        );
+        fs.insert(
+            Path::new("packages/app/services/api.json").into(),
+            r#"{ "port": "8080" }"#.as_bytes(),
+        );
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(&["lint", "--config-path=packages/app", "packages/app"]),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "nested_configuration_resolves_parent_paths",
+            fs,
+            console,
+            result,
+        ));
+    }
 }
 
 /// Create an [App] instance using the provided [FileSystem] and [Console]
@@ -2,12 +2,20 @@
 
 use biome_analyze::declare_group;
 
+pub mod no_undefined_custom_properties;
+pub mod no_unknown_property_value;
+pub mod no_unused_custom_properties;
+pub mod no_useless_var_fallback;
 pub mod noop;
 
 declare_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_undefined_custom_properties :: NoUndefinedCustomProperties ,
+            self :: no_unknown_property_value :: NoUnknownPropertyValue ,
+            self :: no_unused_custom_properties :: NoUnusedCustomProperties ,
+            self :: no_useless_var_fallback :: NoUselessVarFallback ,
             self :: noop :: Noop ,
         ]
      }
@@ -0,0 +1,82 @@
+use crate::semantic_services::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{CssFunction, TextRange};
+
+declare_rule! {
+    /// Disallow the use of custom properties that are never declared.
+    ///
+    /// `var()` substitutes the value of a custom property. When the property isn't declared,
+    /// the declaration that uses `var()` is invalid at computed-value time, which usually
+    /// comes from a typo in the name of the property.
+    ///
+    /// A custom property is declared when a stylesheet of the project declares it in a rule,
+    /// e.g. `--primary: blue`, or registers it with `@property`.
+    /// The references with a fallback value, e.g. `var(--primary, blue)`, are ignored,
+    /// because the fallback is used on purpose when the property isn't set.
+    ///
+    /// The rule doesn't know about the custom properties set from JavaScript or in inline styles.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// :root {
+    ///   --primary: blue;
+    /// }
+    ///
+    /// a {
+    ///   color: var(--primray);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// :root {
+    ///   --primary: blue;
+    /// }
+    ///
+    /// a {
+    ///   color: var(--primary);
+    ///   background: var(--background, white);
+    /// }
+    /// ```
+    ///
+    pub NoUndefinedCustomProperties {
+        version: "next",
+        name: "noUndefinedCustomProperties",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUndefinedCustomProperties {
+    type Query = Semantic<CssFunction>;
+    type State = (String, TextRange);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let reference = ctx.model().as_reference(ctx.query())?;
+        if reference.fallback().is_some() || ctx.is_declared(reference.name()) {
+            return None;
+        }
+        Some((reference.name().to_string(), reference.range()))
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, (name, range): &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The custom property "<Emphasis>{name}</Emphasis>" is never declared."
+                },
+            )
+            .note(markup! {
+                "Check the name of the property, or declare it in one of the stylesheets of the project."
+            }),
+        )
+    }
+}
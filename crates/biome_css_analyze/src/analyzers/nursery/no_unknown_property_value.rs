@@ -0,0 +1,87 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::property::{
+    validate_property_value, CssPropertyValue, InvalidPropertyValue, InvalidPropertyValueKind,
+};
+use biome_css_syntax::CssGenericProperty;
+
+declare_rule! {
+    /// Disallow values that aren't valid for their property.
+    ///
+    /// The values of the well-known properties are checked against the grammars
+    /// of the CSS specifications, which catches misspelled keywords, missing units
+    /// and values that are simply not accepted by the property.
+    ///
+    /// Custom properties, vendor-prefixed properties and values that use `var()`
+    /// or a vendor-prefixed keyword are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   display: flx;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   width: 100;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   display: inline flex;
+    ///   width: calc(100% - 2rem);
+    /// }
+    /// ```
+    ///
+    pub NoUnknownPropertyValue {
+        version: "next",
+        name: "noUnknownPropertyValue",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnknownPropertyValue {
+    type Query = Ast<CssGenericProperty>;
+    type State = InvalidPropertyValue;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        match validate_property_value(ctx.query()) {
+            CssPropertyValue::Invalid(invalid) => Some(invalid),
+            CssPropertyValue::Valid(_) | CssPropertyValue::Unchecked => None,
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let definition = state.definition();
+        let name = definition.name;
+        let syntax = definition.syntax;
+        let diagnostic = match state.kind() {
+            InvalidPropertyValueKind::Unexpected => RuleDiagnostic::new(
+                rule_category!(),
+                state.range(),
+                markup! {
+                    "Unexpected value for the property "<Emphasis>{name}</Emphasis>"."
+                },
+            ),
+            InvalidPropertyValueKind::Incomplete => RuleDiagnostic::new(
+                rule_category!(),
+                state.range(),
+                markup! {
+                    "The value of the property "<Emphasis>{name}</Emphasis>" is incomplete."
+                },
+            ),
+        };
+        Some(diagnostic.note(markup! {
+            "The values of "<Emphasis>{name}</Emphasis>" follow this syntax: "<Emphasis>{syntax}</Emphasis>
+        }))
+    }
+}
@@ -0,0 +1,80 @@
+use crate::semantic_services::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{AnyCssDeclarationName, CssGenericProperty, CssSyntaxToken};
+
+declare_rule! {
+    /// Disallow custom properties that are never used.
+    ///
+    /// A custom property that no stylesheet of the project reads with `var()` is dead code,
+    /// that usually remains after a refactoring.
+    ///
+    /// Custom properties are often read from JavaScript, with `getPropertyValue()`, or are
+    /// part of the public API of a design system. This rule doesn't know about these usages,
+    /// so it's better suited for applications than for libraries.
+    /// The registrations of `@property` aren't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// :root {
+    ///   --primary: blue;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// :root {
+    ///   --primary: blue;
+    /// }
+    ///
+    /// a {
+    ///   color: var(--primary);
+    /// }
+    /// ```
+    ///
+    pub NoUnusedCustomProperties {
+        version: "next",
+        name: "noUnusedCustomProperties",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnusedCustomProperties {
+    type Query = Semantic<CssGenericProperty>;
+    type State = CssSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        // The parser keeps the name of custom properties as a plain identifier
+        let token = match ctx.query().name().ok()? {
+            AnyCssDeclarationName::CssDashedIdentifier(name) => name.value_token().ok()?,
+            AnyCssDeclarationName::CssIdentifier(name) => name.value_token().ok()?,
+        };
+        let name = token.text_trimmed();
+        if !name.starts_with("--") || ctx.is_referenced(name) {
+            return None;
+        }
+        Some(token)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, token: &Self::State) -> Option<RuleDiagnostic> {
+        let name_text = token.text_trimmed();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                token.text_trimmed_range(),
+                markup! {
+                    "The custom property "<Emphasis>{name_text}</Emphasis>" is never used."
+                },
+            )
+            .note(markup! {
+                "Remove the declaration, or read the property with "<Emphasis>"var()"</Emphasis>"."
+            }),
+        )
+    }
+}
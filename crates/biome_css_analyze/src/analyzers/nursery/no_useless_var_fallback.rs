@@ -0,0 +1,84 @@
+use crate::semantic_services::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{CssFunction, TextRange};
+
+declare_rule! {
+    /// Disallow `var()` fallbacks that can never apply.
+    ///
+    /// The fallback of `var()` is used when the custom property is missing or invalid.
+    /// A custom property registered with `@property` and an `initial-value` descriptor always
+    /// has a valid value, so the fallback of its references is dead code, and misleads
+    /// the readers about the value that applies.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// @property --angle {
+    ///   syntax: "<angle>";
+    ///   inherits: false;
+    ///   initial-value: 0deg;
+    /// }
+    ///
+    /// a {
+    ///   rotate: var(--angle, 45deg);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// @property --angle {
+    ///   syntax: "<angle>";
+    ///   inherits: false;
+    ///   initial-value: 0deg;
+    /// }
+    ///
+    /// a {
+    ///   rotate: var(--angle);
+    ///   color: var(--color, red);
+    /// }
+    /// ```
+    ///
+    pub NoUselessVarFallback {
+        version: "next",
+        name: "noUselessVarFallback",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUselessVarFallback {
+    type Query = Semantic<CssFunction>;
+    type State = (String, TextRange);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let reference = ctx.model().as_reference(ctx.query())?;
+        let fallback = reference.fallback()?;
+        if !ctx.has_initial_value(reference.name()) {
+            return None;
+        }
+        Some((reference.name().to_string(), fallback))
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, (name, range): &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This fallback can never apply."
+                },
+            )
+            .note(markup! {
+                "The custom property "<Emphasis>{name}</Emphasis>" is registered with an initial value, so it always has a valid value."
+            })
+            .note(markup! {
+                "Remove the fallback, or set the value with "<Emphasis>"initial-value"</Emphasis>"."
+            }),
+        )
+    }
+}
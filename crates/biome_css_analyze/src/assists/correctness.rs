@@ -0,0 +1,14 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_group;
+
+pub mod sort_declarations;
+
+declare_group! {
+    pub Correctness {
+        name : "correctness" ,
+        rules : [
+            self :: sort_declarations :: SortDeclarations ,
+        ]
+     }
+}
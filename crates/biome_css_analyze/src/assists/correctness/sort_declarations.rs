@@ -0,0 +1,592 @@
+use std::{borrow::Cow, mem::take};
+
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, SourceActionKind,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssProperty, CssDeclarationWithSemicolon, CssLanguage, CssRoot,
+    CssSyntaxKind, CssSyntaxNode, CssSyntaxToken, T,
+};
+use biome_diagnostics::Applicability;
+use biome_rowan::{syntax::SyntaxTrivia, AstNode, BatchMutationExt};
+
+use crate::CssRuleAction;
+
+declare_rule! {
+    /// Provides a whole-source code action to sort the declarations of the blocks in the file.
+    ///
+    /// The declarations are sorted with one of the following orders:
+    /// - `grouped` (default): the declarations are grouped by concern (positioning, box model,
+    ///   typography, visual, animation), with the shorthands before their longhands;
+    /// - `alphabetical`: the declarations are sorted by name;
+    /// - `custom`: the declarations follow the given list of properties.
+    ///
+    /// The properties that aren't part of the order are placed after the others, sorted by name.
+    /// Custom properties always come first, in their original order, and vendor prefixes
+    /// are ignored when comparing names.
+    ///
+    /// Declarations separated by an empty line, a nested rule or an at-rule are sorted separately,
+    /// and the comments attached to a declaration are moved with it.
+    /// Declarations are left untouched when sorting would swap a shorthand property and one of
+    /// its longhands, because it would change the computed style.
+    ///
+    /// ## Examples
+    ///
+    /// ```css
+    /// a {
+    ///   color: red;
+    ///   /* Always visible */
+    ///   display: block;
+    ///   position: absolute;
+    ///   margin: 0;
+    /// }
+    /// ```
+    pub SortDeclarations {
+        version: "next",
+        name: "sortDeclarations",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for SortDeclarations {
+    type Query = Ast<CssRoot>;
+    type State = Vec<DeclarationGroup>;
+    type Signals = Option<Self::State>;
+    type Options = SortDeclarationsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let root = ctx.query();
+        let order = &ctx.options().order;
+
+        let groups: Vec<_> = root
+            .syntax()
+            .descendants()
+            .filter(|node| {
+                matches!(
+                    node.kind(),
+                    CssSyntaxKind::CSS_DECLARATION_LIST
+                        | CssSyntaxKind::CSS_DECLARATION_OR_RULE_LIST
+                        | CssSyntaxKind::CSS_DECLARATION_OR_AT_RULE_LIST
+                )
+            })
+            .flat_map(|list| collect_groups(&list))
+            .filter_map(|declarations| DeclarationGroup::sort(declarations, order))
+            .collect();
+
+        (!groups.is_empty()).then_some(groups)
+    }
+
+    fn action(ctx: &RuleContext<Self>, groups: &Self::State) -> Option<CssRuleAction> {
+        let mut mutation = ctx.root().begin();
+
+        for group in groups {
+            let first = &group.declarations[0].node;
+            let last = group.declarations.len() - 1;
+            // The empty line that separates the group from the previous declarations stays in place
+            let separator = group.declarations[0]
+                .has_empty_line_before()
+                .then(|| first.syntax().first_leading_trivia()?.pieces().next())
+                .flatten()
+                .filter(|piece| piece.is_newline());
+
+            for (index, (prev, next)) in group.declarations.iter().zip(group.sorted()).enumerate() {
+                if prev.node == next.node {
+                    continue;
+                }
+                let mut node = next.node.clone();
+                // A declaration moved away from the end of the block needs a semicolon
+                if index != last {
+                    node = with_semicolon(&node)?;
+                }
+                if let Some(separator) = &separator {
+                    if index == 0 {
+                        node = node.prepend_trivia_pieces([separator.clone()])?;
+                    } else if &next.node == first {
+                        let leading_trivia = node.syntax().first_leading_trivia()?;
+                        node = node.with_leading_trivia_pieces(leading_trivia.pieces().skip(1))?;
+                    }
+                }
+                mutation.replace_node_discard_trivia(prev.node.clone(), node);
+            }
+        }
+
+        Some(CssRuleAction {
+            category: ActionCategory::Source(SourceActionKind::Other(Cow::Borrowed(
+                "sortDeclarations",
+            ))),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Sort Declarations (Biome)" }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Options for the rule `sortDeclarations`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SortDeclarationsOptions {
+    /// The order of the sorted declarations
+    pub order: DeclarationsOrder,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum DeclarationsOrder {
+    /// Sorts the declarations by name
+    Alphabetical,
+    /// Groups the declarations by concern
+    #[default]
+    Grouped,
+    /// Sorts the declarations with the given list of properties
+    Custom(Vec<String>),
+}
+
+impl DeclarationsOrder {
+    /// Returns the position of `property` in the order, if it's part of it
+    fn position(&self, property: &str) -> Option<usize> {
+        match self {
+            Self::Alphabetical => None,
+            Self::Grouped => GROUPED_ORDER
+                .iter()
+                .flat_map(|group| group.iter())
+                .position(|name| *name == property),
+            Self::Custom(properties) => properties.iter().position(|name| name == property),
+        }
+    }
+}
+
+/// A list of contiguous declarations that are sorted together
+#[derive(Debug)]
+pub struct DeclarationGroup {
+    /// The declarations, in source order
+    declarations: Vec<Declaration>,
+    /// The indices of the declarations, in sorted order
+    order: Vec<usize>,
+}
+
+impl DeclarationGroup {
+    fn sort(declarations: Vec<Declaration>, order: &DeclarationsOrder) -> Option<Self> {
+        let keys: Vec<_> = declarations
+            .iter()
+            .map(|declaration| declaration.sort_key(order))
+            .collect();
+        let mut indices: Vec<_> = (0..declarations.len()).collect();
+        // The sort is stable, so duplicated properties keep their relative order
+        indices.sort_by(|a, b| keys[*a].cmp(&keys[*b]));
+
+        if indices
+            .iter()
+            .enumerate()
+            .all(|(index, sorted)| index == *sorted)
+        {
+            return None;
+        }
+
+        // Bail if a shorthand and one of its longhands would be swapped, the last one wins
+        for (position, index) in indices.iter().enumerate() {
+            let name = declarations[*index].unprefixed_name();
+            let swapped = indices[position + 1..]
+                .iter()
+                .filter(|other| *other < index)
+                .map(|other| declarations[*other].unprefixed_name());
+            for other in swapped {
+                if is_shorthand_of(name, other) || is_shorthand_of(other, name) {
+                    return None;
+                }
+            }
+        }
+
+        Some(Self {
+            declarations,
+            order: indices,
+        })
+    }
+
+    fn sorted(&self) -> impl Iterator<Item = &Declaration> {
+        self.order.iter().map(|index| &self.declarations[*index])
+    }
+}
+
+#[derive(Debug)]
+struct Declaration {
+    node: CssDeclarationWithSemicolon,
+    /// The name of the property, lowercased unless it's a custom property
+    name: String,
+    is_custom_property: bool,
+}
+
+impl Declaration {
+    fn new(node: CssDeclarationWithSemicolon) -> Option<Self> {
+        let AnyCssProperty::CssGenericProperty(property) =
+            node.declaration().ok()?.property().ok()?
+        else {
+            return None;
+        };
+        // The parser keeps the name of custom properties as a plain identifier
+        let token = match property.name().ok()? {
+            AnyCssDeclarationName::CssIdentifier(name) => name.value_token().ok()?,
+            AnyCssDeclarationName::CssDashedIdentifier(name) => name.value_token().ok()?,
+        };
+        let name = token.text_trimmed();
+        let is_custom_property = name.starts_with("--");
+        let name = if is_custom_property {
+            name.to_string()
+        } else {
+            name.to_ascii_lowercase()
+        };
+        Some(Self {
+            node,
+            name,
+            is_custom_property,
+        })
+    }
+
+    /// Returns the name of the property without its vendor prefix
+    fn unprefixed_name(&self) -> &str {
+        if self.is_custom_property {
+            return &self.name;
+        }
+        ["-webkit-", "-moz-", "-ms-", "-o-"]
+            .iter()
+            .find_map(|prefix| self.name.strip_prefix(prefix))
+            .unwrap_or(&self.name)
+    }
+
+    /// Custom properties come first, then the properties of the order, then the other
+    /// properties by name
+    fn sort_key(&self, order: &DeclarationsOrder) -> (usize, &str) {
+        if self.is_custom_property {
+            return (0, "");
+        }
+        let name = self.unprefixed_name();
+        match order.position(name) {
+            Some(position) => (position + 1, ""),
+            None => (usize::MAX, name),
+        }
+    }
+
+    fn has_empty_line_before(&self) -> bool {
+        self.node
+            .syntax()
+            .first_token()
+            .is_some_and(|token| has_empty_line(&token.leading_trivia()))
+    }
+}
+
+/// Splits the items of a block into groups of declarations, separated by the items that
+/// aren't declarations and by empty lines
+fn collect_groups(list: &CssSyntaxNode) -> Vec<Vec<Declaration>> {
+    let mut groups = Vec::new();
+    let mut group = Vec::new();
+
+    for item in list.children() {
+        let Some(declaration) = CssDeclarationWithSemicolon::cast(item).and_then(Declaration::new)
+        else {
+            groups.push(take(&mut group));
+            continue;
+        };
+        if declaration.has_empty_line_before() {
+            groups.push(take(&mut group));
+        }
+        group.push(declaration);
+    }
+    groups.push(group);
+
+    groups.retain(|group| group.len() > 1);
+    groups
+}
+
+fn has_empty_line(trivia: &SyntaxTrivia<CssLanguage>) -> bool {
+    let mut was_newline = false;
+    trivia
+        .pieces()
+        .filter(|piece| !piece.is_whitespace())
+        .any(|piece| {
+            let prev_newline = was_newline;
+            was_newline = piece.is_newline();
+            prev_newline && was_newline
+        })
+}
+
+/// Adds a semicolon to a declaration that doesn't have one, moving the trailing trivia
+/// of the declaration after the semicolon
+fn with_semicolon(node: &CssDeclarationWithSemicolon) -> Option<CssDeclarationWithSemicolon> {
+    if node.semicolon_token().is_some() {
+        return Some(node.clone());
+    }
+    let declaration = node.declaration().ok()?;
+    let trailing_trivia = declaration.syntax().last_trailing_trivia()?;
+    let semicolon = CssSyntaxToken::new_detached(T![;], ";", [], [])
+        .with_trailing_trivia_pieces(trailing_trivia.pieces());
+    Some(
+        make::css_declaration_with_semicolon(declaration.with_trailing_trivia_pieces([])?)
+            .with_semicolon_token(semicolon)
+            .build(),
+    )
+}
+
+/// Whether setting `shorthand` resets `longhand`
+fn is_shorthand_of(shorthand: &str, longhand: &str) -> bool {
+    if shorthand == longhand {
+        return false;
+    }
+    shorthand == "all"
+        || longhand
+            .strip_prefix(shorthand)
+            .is_some_and(|rest| rest.starts_with('-'))
+        || SHORTHANDS.iter().any(|(name, longhands)| {
+            *name == shorthand && longhands.iter().any(|name| *name == longhand)
+        })
+}
+
+/// Shorthand properties whose longhands don't start with the name of the shorthand
+const SHORTHANDS: &[(&str, &[&str])] = &[
+    (
+        "border-color",
+        &[
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+    ),
+    (
+        "border-radius",
+        &[
+            "border-top-left-radius",
+            "border-top-right-radius",
+            "border-bottom-right-radius",
+            "border-bottom-left-radius",
+        ],
+    ),
+    (
+        "border-style",
+        &[
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+        ],
+    ),
+    (
+        "border-width",
+        &[
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+    ),
+    ("columns", &["column-width", "column-count"]),
+    ("flex-flow", &["flex-direction", "flex-wrap"]),
+    ("font", &["line-height"]),
+    ("gap", &["row-gap", "column-gap"]),
+    (
+        "grid",
+        &[
+            "row-gap",
+            "column-gap",
+            "grid-auto-columns",
+            "grid-auto-flow",
+            "grid-auto-rows",
+        ],
+    ),
+    (
+        "grid-area",
+        &[
+            "grid-row-start",
+            "grid-row-end",
+            "grid-column-start",
+            "grid-column-end",
+        ],
+    ),
+    (
+        "grid-template",
+        &[
+            "grid-template-areas",
+            "grid-template-columns",
+            "grid-template-rows",
+        ],
+    ),
+    ("inset", &["top", "right", "bottom", "left"]),
+    ("place-content", &["align-content", "justify-content"]),
+    ("place-items", &["align-items", "justify-items"]),
+    ("place-self", &["align-self", "justify-self"]),
+];
+
+/// The properties of the `grouped` order, by concern
+const GROUPED_ORDER: &[&[&str]] = &[
+    // Positioning
+    &[
+        "position",
+        "inset",
+        "inset-block",
+        "inset-block-start",
+        "inset-block-end",
+        "inset-inline",
+        "inset-inline-start",
+        "inset-inline-end",
+        "top",
+        "right",
+        "bottom",
+        "left",
+        "z-index",
+    ],
+    // Display and box model
+    &[
+        "display",
+        "box-sizing",
+        "flex",
+        "flex-grow",
+        "flex-shrink",
+        "flex-basis",
+        "flex-flow",
+        "flex-direction",
+        "flex-wrap",
+        "grid",
+        "grid-area",
+        "grid-template",
+        "grid-template-areas",
+        "grid-template-rows",
+        "grid-template-columns",
+        "grid-row",
+        "grid-row-start",
+        "grid-row-end",
+        "grid-column",
+        "grid-column-start",
+        "grid-column-end",
+        "grid-auto-rows",
+        "grid-auto-columns",
+        "grid-auto-flow",
+        "gap",
+        "row-gap",
+        "column-gap",
+        "place-content",
+        "place-items",
+        "place-self",
+        "align-content",
+        "align-items",
+        "align-self",
+        "justify-content",
+        "justify-items",
+        "justify-self",
+        "order",
+        "float",
+        "clear",
+        "width",
+        "min-width",
+        "max-width",
+        "height",
+        "min-height",
+        "max-height",
+        "aspect-ratio",
+        "margin",
+        "margin-top",
+        "margin-right",
+        "margin-bottom",
+        "margin-left",
+        "margin-block",
+        "margin-block-start",
+        "margin-block-end",
+        "margin-inline",
+        "margin-inline-start",
+        "margin-inline-end",
+        "padding",
+        "padding-top",
+        "padding-right",
+        "padding-bottom",
+        "padding-left",
+        "padding-block",
+        "padding-block-start",
+        "padding-block-end",
+        "padding-inline",
+        "padding-inline-start",
+        "padding-inline-end",
+        "overflow",
+        "overflow-x",
+        "overflow-y",
+    ],
+    // Typography
+    &[
+        "font",
+        "font-family",
+        "font-size",
+        "font-style",
+        "font-weight",
+        "font-variant",
+        "line-height",
+        "letter-spacing",
+        "word-spacing",
+        "color",
+        "text-align",
+        "text-decoration",
+        "text-indent",
+        "text-overflow",
+        "text-transform",
+        "text-shadow",
+        "white-space",
+        "word-break",
+        "overflow-wrap",
+        "vertical-align",
+        "list-style",
+        "list-style-type",
+        "list-style-position",
+        "list-style-image",
+    ],
+    // Visual
+    &[
+        "visibility",
+        "opacity",
+        "background",
+        "background-color",
+        "background-image",
+        "background-repeat",
+        "background-position",
+        "background-size",
+        "background-clip",
+        "border",
+        "border-width",
+        "border-style",
+        "border-color",
+        "border-top",
+        "border-right",
+        "border-bottom",
+        "border-left",
+        "border-radius",
+        "outline",
+        "outline-width",
+        "outline-style",
+        "outline-color",
+        "outline-offset",
+        "box-shadow",
+        "filter",
+        "backdrop-filter",
+        "mix-blend-mode",
+        "cursor",
+        "pointer-events",
+        "user-select",
+    ],
+    // Animation
+    &[
+        "transform",
+        "transform-origin",
+        "transition",
+        "transition-property",
+        "transition-duration",
+        "transition-timing-function",
+        "transition-delay",
+        "animation",
+        "animation-name",
+        "animation-duration",
+        "animation-timing-function",
+        "animation-delay",
+        "animation-iteration-count",
+        "animation-direction",
+        "animation-fill-mode",
+        "animation-play-state",
+        "will-change",
+    ],
+    // Miscellaneous
+    &["content", "quotes", "appearance", "resize"],
+];
@@ -1,13 +1,22 @@
 mod analyzers;
+mod assists;
 mod registry;
+mod semantic_services;
 
+pub use crate::assists::correctness::sort_declarations::{
+    DeclarationsOrder, SortDeclarationsOptions,
+};
 pub use crate::registry::visit_registry;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
+    parse_linter_suppression_comment, AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow,
+    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
 };
+use biome_css_semantic::ProjectCustomProperties;
 use biome_css_syntax::CssLanguage;
 use biome_diagnostics::Error;
+use std::sync::Arc;
+
+pub(crate) type CssRuleAction = RuleAction<CssLanguage>;
 
 /// Return the static [MetadataRegistry] for the JSON analyzer rules
 pub fn metadata() -> &'static MetadataRegistry {
@@ -24,18 +33,27 @@ pub fn metadata() -> &'static MetadataRegistry {
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
-/// then call `emit_signal` when an analysis rule emits a diagnostic or action
+/// then call `emit_signal` when an analysis rule emits a diagnostic or action.
+/// `custom_properties` are the custom properties of the other stylesheets of the project
 pub fn analyze<'a, F, B>(
     root: &LanguageRoot<CssLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    custom_properties: ProjectCustomProperties,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<CssLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        custom_properties,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -49,6 +67,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    custom_properties: ProjectCustomProperties,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -56,15 +75,10 @@ where
     F: FnMut(&dyn AnalyzerSignal<CssLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    fn parse_linter_suppression_comment(
-        _text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
-        vec![]
-    }
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
@@ -83,6 +97,8 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    services.insert_service(Arc::new(custom_properties));
+
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -105,7 +121,7 @@ mod tests {
     use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic, Severity};
     use std::slice;
 
-    use crate::{analyze, AnalysisFilter, ControlFlow};
+    use crate::{analyze, AnalysisFilter, ControlFlow, ProjectCustomProperties};
 
     #[ignore]
     #[test]
@@ -134,6 +150,7 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            ProjectCustomProperties::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -0,0 +1,128 @@
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor, Visitor, VisitorContext, VisitorFinishContext,
+};
+use biome_css_semantic::{
+    ProjectCustomProperties, SemanticEventExtractor, SemanticModel, SemanticModelBuilder,
+};
+use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxNode, WalkEvent};
+use biome_rowan::{AstNode, SyntaxNode};
+use std::sync::Arc;
+
+pub struct SemanticServices {
+    model: SemanticModel,
+    project: Arc<ProjectCustomProperties>,
+}
+
+impl SemanticServices {
+    pub fn model(&self) -> &SemanticModel {
+        &self.model
+    }
+
+    /// Returns `true` if the file or another stylesheet of the project declares
+    /// or registers the custom property `name`
+    pub fn is_declared(&self, name: &str) -> bool {
+        self.model.is_declared(name) || self.project.is_declared(name)
+    }
+
+    /// Returns `true` if the file or another stylesheet of the project reads
+    /// the custom property `name`
+    pub fn is_referenced(&self, name: &str) -> bool {
+        self.model.is_referenced(name) || self.project.is_referenced(name)
+    }
+
+    /// Returns `true` if the file or another stylesheet of the project registers
+    /// the custom property `name` with an initial value
+    pub fn has_initial_value(&self, name: &str) -> bool {
+        self.model.has_initial_value(name) || self.project.has_initial_value(name)
+    }
+}
+
+impl FromServices for SemanticServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> Result<Self, MissingServicesDiagnostic> {
+        let model: &SemanticModel = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["SemanticModel"])
+        })?;
+        let project: &Arc<ProjectCustomProperties> = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["ProjectCustomProperties"])
+        })?;
+        Ok(Self {
+            model: model.clone(),
+            project: project.clone(),
+        })
+    }
+}
+
+impl Phase for SemanticServices {
+    fn phase() -> Phases {
+        Phases::Semantic
+    }
+}
+
+/// Query type usable by lint rules **that uses the semantic model** to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct Semantic<N>(pub N);
+
+impl<N> Queryable for Semantic<N>
+where
+    N: AstNode<Language = CssLanguage> + 'static,
+{
+    type Input = CssSyntaxNode;
+    type Output = N;
+
+    type Language = CssLanguage;
+    type Services = SemanticServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<CssLanguage>, root: &CssRoot) {
+        analyzer.add_visitor(Phases::Syntax, || SemanticModelBuilderVisitor::new(root));
+        analyzer.add_visitor(Phases::Semantic, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
+
+pub struct SemanticModelBuilderVisitor {
+    extractor: SemanticEventExtractor,
+    builder: SemanticModelBuilder,
+}
+
+impl SemanticModelBuilderVisitor {
+    pub(crate) fn new(root: &CssRoot) -> Self {
+        Self {
+            extractor: SemanticEventExtractor::default(),
+            builder: SemanticModelBuilder::new(root.clone()),
+        }
+    }
+}
+
+impl Visitor for SemanticModelBuilderVisitor {
+    type Language = CssLanguage;
+
+    fn visit(
+        &mut self,
+        event: &WalkEvent<SyntaxNode<CssLanguage>>,
+        _ctx: VisitorContext<CssLanguage>,
+    ) {
+        if let WalkEvent::Enter(node) = event {
+            self.extractor.enter(node);
+        }
+
+        while let Some(e) = self.extractor.pop() {
+            self.builder.push_event(e);
+        }
+    }
+
+    fn finish(self: Box<Self>, ctx: VisitorFinishContext<CssLanguage>) {
+        let model = self.builder.build();
+        ctx.services.insert_service(model);
+    }
+}
@@ -1,5 +1,6 @@
 use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
 use biome_css_parser::{parse_css, CssParserOptions};
+use biome_css_semantic::{semantic_model, ProjectCustomProperties};
 use biome_css_syntax::{CssFileSource, CssLanguage};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
@@ -105,8 +106,9 @@ pub(crate) fn analyze_and_snap(
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
+    let project = load_custom_properties(input_file, &parser_options);
 
-    let (_, errors) = biome_css_analyze::analyze(&root, filter, &options, |event| {
+    let (_, errors) = biome_css_analyze::analyze(&root, filter, &options, project, |event| {
         if let Some(mut diag) = event.diagnostic() {
             for action in event.actions() {
                 if check_action_type.is_suppression() {
@@ -179,6 +181,34 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
+/// Loads the custom properties of the other stylesheets in the directory of the
+/// test file, as if they were part of the same project
+fn load_custom_properties(
+    input_file: &Path,
+    parser_options: &CssParserOptions,
+) -> ProjectCustomProperties {
+    let mut custom_properties = ProjectCustomProperties::default();
+    let Some(entries) = input_file
+        .parent()
+        .and_then(|directory| std::fs::read_dir(directory).ok())
+    else {
+        return custom_properties;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.extension() != Some(OsStr::new("css")) || path.file_name() == input_file.file_name()
+        {
+            continue;
+        }
+        if let Ok(content) = read_to_string(&path) {
+            let parse = parse_css(&content, parser_options.clone());
+            custom_properties.add_model(&semantic_model(&parse.tree()));
+        }
+    }
+
+    custom_properties
+}
+
 fn check_code_action(
     path: &Path,
     source: &str,
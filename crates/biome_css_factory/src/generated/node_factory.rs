@@ -189,6 +189,60 @@ pub fn css_complex_selector(
         ],
     ))
 }
+pub fn css_composes_import_specifier(
+    from_token: SyntaxToken,
+    source: AnyCssComposesImportSource,
+) -> CssComposesImportSpecifier {
+    CssComposesImportSpecifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COMPOSES_IMPORT_SPECIFIER,
+        [
+            Some(SyntaxElement::Token(from_token)),
+            Some(SyntaxElement::Node(source.into_syntax())),
+        ],
+    ))
+}
+pub fn css_composes_property(
+    name: CssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssComposesPropertyValue,
+) -> CssComposesProperty {
+    CssComposesProperty::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COMPOSES_PROPERTY,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn css_composes_property_value(
+    classes: CssComposesClassList,
+) -> CssComposesPropertyValueBuilder {
+    CssComposesPropertyValueBuilder {
+        classes,
+        specifier: None,
+    }
+}
+pub struct CssComposesPropertyValueBuilder {
+    classes: CssComposesClassList,
+    specifier: Option<CssComposesImportSpecifier>,
+}
+impl CssComposesPropertyValueBuilder {
+    pub fn with_specifier(mut self, specifier: CssComposesImportSpecifier) -> Self {
+        self.specifier = Some(specifier);
+        self
+    }
+    pub fn build(self) -> CssComposesPropertyValue {
+        CssComposesPropertyValue::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_COMPOSES_PROPERTY_VALUE,
+            [
+                Some(SyntaxElement::Node(self.classes.into_syntax())),
+                self.specifier
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
 pub fn css_compound_selector(sub_selectors: CssSubSelectorList) -> CssCompoundSelectorBuilder {
     CssCompoundSelectorBuilder {
         sub_selectors,
@@ -2024,162 +2078,1241 @@ pub fn css_url_value_raw(value_token: SyntaxToken) -> CssUrlValueRaw {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn css_component_value_list<I>(items: I) -> CssComponentValueList
-where
-    I: IntoIterator<Item = AnyCssValue>,
-    I::IntoIter: ExactSizeIterator,
-{
-    CssComponentValueList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_COMPONENT_VALUE_LIST,
-        items
-            .into_iter()
-            .map(|item| Some(item.into_syntax().into())),
+pub fn css_value_at_rule(
+    value_token: SyntaxToken,
+    clause: AnyCssValueAtRuleClause,
+    semicolon_token: SyntaxToken,
+) -> CssValueAtRule {
+    CssValueAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_VALUE_AT_RULE,
+        [
+            Some(SyntaxElement::Token(value_token)),
+            Some(SyntaxElement::Node(clause.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
     ))
 }
-pub fn css_compound_selector_list<I, S>(items: I, separators: S) -> CssCompoundSelectorList
-where
-    I: IntoIterator<Item = AnyCssCompoundSelector>,
-    I::IntoIter: ExactSizeIterator,
-    S: IntoIterator<Item = CssSyntaxToken>,
-    S::IntoIter: ExactSizeIterator,
-{
-    let mut items = items.into_iter();
-    let mut separators = separators.into_iter();
-    let length = items.len() + separators.len();
-    CssCompoundSelectorList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_COMPOUND_SELECTOR_LIST,
-        (0..length).map(|index| {
-            if index % 2 == 0 {
-                Some(items.next()?.into_syntax().into())
-            } else {
-                Some(separators.next()?.into())
-            }
-        }),
+pub fn css_value_at_rule_declaration_clause(
+    name: CssCustomIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> CssValueAtRuleDeclarationClause {
+    CssValueAtRuleDeclarationClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_VALUE_AT_RULE_DECLARATION_CLAUSE,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
     ))
 }
-pub fn css_declaration_list<I>(items: I) -> CssDeclarationList
-where
-    I: IntoIterator<Item = CssDeclarationWithSemicolon>,
-    I::IntoIter: ExactSizeIterator,
-{
-    CssDeclarationList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_DECLARATION_LIST,
-        items
-            .into_iter()
-            .map(|item| Some(item.into_syntax().into())),
+pub fn css_value_at_rule_import_clause(
+    specifiers: CssValueAtRuleImportSpecifierList,
+    from_token: SyntaxToken,
+    source: AnyCssValueAtRuleImportSource,
+) -> CssValueAtRuleImportClause {
+    CssValueAtRuleImportClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_VALUE_AT_RULE_IMPORT_CLAUSE,
+        [
+            Some(SyntaxElement::Node(specifiers.into_syntax())),
+            Some(SyntaxElement::Token(from_token)),
+            Some(SyntaxElement::Node(source.into_syntax())),
+        ],
     ))
 }
-pub fn css_declaration_or_at_rule_list<I>(items: I) -> CssDeclarationOrAtRuleList
-where
-    I: IntoIterator<Item = AnyCssDeclarationOrAtRule>,
-    I::IntoIter: ExactSizeIterator,
-{
-    CssDeclarationOrAtRuleList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_DECLARATION_OR_AT_RULE_LIST,
-        items
-            .into_iter()
-            .map(|item| Some(item.into_syntax().into())),
+pub fn css_value_at_rule_import_specifier(
+    name: CssCustomIdentifier,
+) -> CssValueAtRuleImportSpecifier {
+    CssValueAtRuleImportSpecifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_VALUE_AT_RULE_IMPORT_SPECIFIER,
+        [Some(SyntaxElement::Node(name.into_syntax()))],
     ))
 }
-pub fn css_declaration_or_rule_list<I>(items: I) -> CssDeclarationOrRuleList
-where
-    I: IntoIterator<Item = AnyCssDeclarationOrRule>,
-    I::IntoIter: ExactSizeIterator,
-{
-    CssDeclarationOrRuleList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_DECLARATION_OR_RULE_LIST,
-        items
-            .into_iter()
-            .map(|item| Some(item.into_syntax().into())),
+pub fn css_value_at_rule_named_import_specifier(
+    name: CssCustomIdentifier,
+    as_token: SyntaxToken,
+    local_name: CssCustomIdentifier,
+) -> CssValueAtRuleNamedImportSpecifier {
+    CssValueAtRuleNamedImportSpecifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_VALUE_AT_RULE_NAMED_IMPORT_SPECIFIER,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(as_token)),
+            Some(SyntaxElement::Node(local_name.into_syntax())),
+        ],
     ))
 }
-pub fn css_document_matcher_list<I, S>(items: I, separators: S) -> CssDocumentMatcherList
-where
-    I: IntoIterator<Item = AnyCssDocumentMatcher>,
-    I::IntoIter: ExactSizeIterator,
-    S: IntoIterator<Item = CssSyntaxToken>,
-    S::IntoIter: ExactSizeIterator,
-{
-    let mut items = items.into_iter();
-    let mut separators = separators.into_iter();
-    let length = items.len() + separators.len();
-    CssDocumentMatcherList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_DOCUMENT_MATCHER_LIST,
-        (0..length).map(|index| {
-            if index % 2 == 0 {
-                Some(items.next()?.into_syntax().into())
-            } else {
-                Some(separators.next()?.into())
-            }
-        }),
-    ))
+pub fn less_declaration(
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> LessDeclarationBuilder {
+    LessDeclarationBuilder {
+        name,
+        colon_token,
+        value,
+        semicolon_token: None,
+    }
 }
-pub fn css_font_feature_values_item_list<I>(items: I) -> CssFontFeatureValuesItemList
-where
-    I: IntoIterator<Item = AnyCssFontFeatureValuesItem>,
-    I::IntoIter: ExactSizeIterator,
-{
-    CssFontFeatureValuesItemList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_FONT_FEATURE_VALUES_ITEM_LIST,
-        items
-            .into_iter()
-            .map(|item| Some(item.into_syntax().into())),
+pub struct LessDeclarationBuilder {
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessDeclaration {
+        LessDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_escaped_string(bitwise_not_token: SyntaxToken, value: CssString) -> LessEscapedString {
+    LessEscapedString::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_ESCAPED_STRING,
+        [
+            Some(SyntaxElement::Token(bitwise_not_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
     ))
 }
-pub fn css_generic_component_value_list<I>(items: I) -> CssGenericComponentValueList
-where
-    I: IntoIterator<Item = AnyCssGenericComponentValue>,
-    I::IntoIter: ExactSizeIterator,
-{
-    CssGenericComponentValueList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_GENERIC_COMPONENT_VALUE_LIST,
-        items
-            .into_iter()
-            .map(|item| Some(item.into_syntax().into())),
+pub fn less_extend_declaration(
+    amp_token: SyntaxToken,
+    colon_token: SyntaxToken,
+    extend: LessPseudoClassExtend,
+) -> LessExtendDeclarationBuilder {
+    LessExtendDeclarationBuilder {
+        amp_token,
+        colon_token,
+        extend,
+        semicolon_token: None,
+    }
+}
+pub struct LessExtendDeclarationBuilder {
+    amp_token: SyntaxToken,
+    colon_token: SyntaxToken,
+    extend: LessPseudoClassExtend,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessExtendDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessExtendDeclaration {
+        LessExtendDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_EXTEND_DECLARATION,
+            [
+                Some(SyntaxElement::Token(self.amp_token)),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.extend.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_extend_target(selector: AnyCssSelector) -> LessExtendTargetBuilder {
+    LessExtendTargetBuilder {
+        selector,
+        all_token: None,
+    }
+}
+pub struct LessExtendTargetBuilder {
+    selector: AnyCssSelector,
+    all_token: Option<SyntaxToken>,
+}
+impl LessExtendTargetBuilder {
+    pub fn with_all_token(mut self, all_token: SyntaxToken) -> Self {
+        self.all_token = Some(all_token);
+        self
+    }
+    pub fn build(self) -> LessExtendTarget {
+        LessExtendTarget::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_EXTEND_TARGET,
+            [
+                Some(SyntaxElement::Node(self.selector.into_syntax())),
+                self.all_token.map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_identifier(at_token: SyntaxToken, name: CssCustomIdentifier) -> LessIdentifier {
+    LessIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_IDENTIFIER,
+        [
+            Some(SyntaxElement::Token(at_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
     ))
 }
-pub fn css_keyframes_item_list<I>(items: I) -> CssKeyframesItemList
-where
-    I: IntoIterator<Item = AnyCssKeyframesItem>,
-    I::IntoIter: ExactSizeIterator,
-{
-    CssKeyframesItemList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_KEYFRAMES_ITEM_LIST,
-        items
-            .into_iter()
-            .map(|item| Some(item.into_syntax().into())),
+pub fn less_import_at_rule(
+    import_token: SyntaxToken,
+    options: LessImportOptions,
+    url: AnyCssImportUrl,
+    media: CssMediaQueryList,
+    semicolon_token: SyntaxToken,
+) -> LessImportAtRule {
+    LessImportAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_IMPORT_AT_RULE,
+        [
+            Some(SyntaxElement::Token(import_token)),
+            Some(SyntaxElement::Node(options.into_syntax())),
+            Some(SyntaxElement::Node(url.into_syntax())),
+            Some(SyntaxElement::Node(media.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
     ))
 }
-pub fn css_keyframes_selector_list<I, S>(items: I, separators: S) -> CssKeyframesSelectorList
-where
-    I: IntoIterator<Item = AnyCssKeyframesSelector>,
-    I::IntoIter: ExactSizeIterator,
-    S: IntoIterator<Item = CssSyntaxToken>,
-    S::IntoIter: ExactSizeIterator,
-{
-    let mut items = items.into_iter();
-    let mut separators = separators.into_iter();
-    let length = items.len() + separators.len();
-    CssKeyframesSelectorList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_KEYFRAMES_SELECTOR_LIST,
-        (0..length).map(|index| {
-            if index % 2 == 0 {
-                Some(items.next()?.into_syntax().into())
-            } else {
-                Some(separators.next()?.into())
-            }
-        }),
+pub fn less_import_options(
+    l_paren_token: SyntaxToken,
+    options: LessImportOptionList,
+    r_paren_token: SyntaxToken,
+) -> LessImportOptions {
+    LessImportOptions::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_IMPORT_OPTIONS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(options.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
     ))
 }
-pub fn css_layer_name_list<I, S>(items: I, separators: S) -> CssLayerNameList
-where
-    I: IntoIterator<Item = CssIdentifier>,
-    I::IntoIter: ExactSizeIterator,
-    S: IntoIterator<Item = CssSyntaxToken>,
-    S::IntoIter: ExactSizeIterator,
-{
-    let mut items = items.into_iter();
-    let mut separators = separators.into_iter();
+pub fn less_mixin_call(dot_token: SyntaxToken, name: CssCustomIdentifier) -> LessMixinCallBuilder {
+    LessMixinCallBuilder {
+        dot_token,
+        name,
+        arguments: None,
+        important: None,
+        semicolon_token: None,
+    }
+}
+pub struct LessMixinCallBuilder {
+    dot_token: SyntaxToken,
+    name: CssCustomIdentifier,
+    arguments: Option<AnyLessMixinParameters>,
+    important: Option<CssDeclarationImportant>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessMixinCallBuilder {
+    pub fn with_arguments(mut self, arguments: AnyLessMixinParameters) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_important(mut self, important: CssDeclarationImportant) -> Self {
+        self.important = Some(important);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessMixinCall {
+        LessMixinCall::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_CALL,
+            [
+                Some(SyntaxElement::Token(self.dot_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.important
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_declaration(
+    dot_token: SyntaxToken,
+    name: CssCustomIdentifier,
+    parameters: AnyLessMixinParameters,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> LessMixinDeclarationBuilder {
+    LessMixinDeclarationBuilder {
+        dot_token,
+        name,
+        parameters,
+        block,
+        guard: None,
+    }
+}
+pub struct LessMixinDeclarationBuilder {
+    dot_token: SyntaxToken,
+    name: CssCustomIdentifier,
+    parameters: AnyLessMixinParameters,
+    block: AnyCssDeclarationOrRuleBlock,
+    guard: Option<LessMixinGuard>,
+}
+impl LessMixinDeclarationBuilder {
+    pub fn with_guard(mut self, guard: LessMixinGuard) -> Self {
+        self.guard = Some(guard);
+        self
+    }
+    pub fn build(self) -> LessMixinDeclaration {
+        LessMixinDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_DECLARATION,
+            [
+                Some(SyntaxElement::Token(self.dot_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Node(self.parameters.into_syntax())),
+                self.guard
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_guard(
+    when_token: SyntaxToken,
+    conditions: CssGenericComponentValueList,
+) -> LessMixinGuard {
+    LessMixinGuard::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_GUARD,
+        [
+            Some(SyntaxElement::Token(when_token)),
+            Some(SyntaxElement::Node(conditions.into_syntax())),
+        ],
+    ))
+}
+pub fn less_mixin_parameter(value: CssGenericComponentValueList) -> LessMixinParameterBuilder {
+    LessMixinParameterBuilder { value, name: None }
+}
+pub struct LessMixinParameterBuilder {
+    value: CssGenericComponentValueList,
+    name: Option<LessMixinParameterName>,
+}
+impl LessMixinParameterBuilder {
+    pub fn with_name(mut self, name: LessMixinParameterName) -> Self {
+        self.name = Some(name);
+        self
+    }
+    pub fn build(self) -> LessMixinParameter {
+        LessMixinParameter::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_PARAMETER,
+            [
+                self.name
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_parameter_name(
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+) -> LessMixinParameterName {
+    LessMixinParameterName::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PARAMETER_NAME,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+        ],
+    ))
+}
+pub fn less_mixin_parameters(
+    l_paren_token: SyntaxToken,
+    items: LessMixinParameterList,
+    r_paren_token: SyntaxToken,
+) -> LessMixinParameters {
+    LessMixinParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_mixin_semicolon_parameters(
+    l_paren_token: SyntaxToken,
+    items: LessMixinSemicolonParameterList,
+    r_paren_token: SyntaxToken,
+) -> LessMixinSemicolonParameters {
+    LessMixinSemicolonParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_SEMICOLON_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_operator(value_token: SyntaxToken) -> LessOperator {
+    LessOperator::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_OPERATOR,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn less_parenthesized_expression(
+    l_paren_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    r_paren_token: SyntaxToken,
+) -> LessParenthesizedExpression {
+    LessParenthesizedExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_PARENTHESIZED_EXPRESSION,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_plugin_at_rule(
+    plugin_token: SyntaxToken,
+    path: CssString,
+    semicolon_token: SyntaxToken,
+) -> LessPluginAtRule {
+    LessPluginAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_PLUGIN_AT_RULE,
+        [
+            Some(SyntaxElement::Token(plugin_token)),
+            Some(SyntaxElement::Node(path.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
+    ))
+}
+pub fn less_pseudo_class_extend(
+    extend_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    targets: LessExtendTargetList,
+    r_paren_token: SyntaxToken,
+) -> LessPseudoClassExtend {
+    LessPseudoClassExtend::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_PSEUDO_CLASS_EXTEND,
+        [
+            Some(SyntaxElement::Token(extend_token)),
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(targets.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_argument(value: CssGenericComponentValueList) -> ScssArgumentBuilder {
+    ScssArgumentBuilder { value, name: None }
+}
+pub struct ScssArgumentBuilder {
+    value: CssGenericComponentValueList,
+    name: Option<ScssArgumentName>,
+}
+impl ScssArgumentBuilder {
+    pub fn with_name(mut self, name: ScssArgumentName) -> Self {
+        self.name = Some(name);
+        self
+    }
+    pub fn build(self) -> ScssArgument {
+        ScssArgument::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_ARGUMENT,
+            [
+                self.name
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_argument_name(name: ScssIdentifier, colon_token: SyntaxToken) -> ScssArgumentName {
+    ScssArgumentName::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ARGUMENT_NAME,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+        ],
+    ))
+}
+pub fn scss_content_at_rule(
+    content_token: SyntaxToken,
+    semicolon_token: SyntaxToken,
+) -> ScssContentAtRule {
+    ScssContentAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_CONTENT_AT_RULE,
+        [
+            Some(SyntaxElement::Token(content_token)),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
+    ))
+}
+pub fn scss_declaration(
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    modifiers: ScssVariableModifierList,
+) -> ScssDeclarationBuilder {
+    ScssDeclarationBuilder {
+        name,
+        colon_token,
+        value,
+        modifiers,
+        semicolon_token: None,
+    }
+}
+pub struct ScssDeclarationBuilder {
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    modifiers: ScssVariableModifierList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssDeclaration {
+        ScssDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                Some(SyntaxElement::Node(self.modifiers.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_each_at_rule(
+    each_token: SyntaxToken,
+    bindings: ScssEachBindingList,
+    in_token: SyntaxToken,
+    iterable: CssGenericComponentValueList,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssEachAtRule {
+    ScssEachAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EACH_AT_RULE,
+        [
+            Some(SyntaxElement::Token(each_token)),
+            Some(SyntaxElement::Node(bindings.into_syntax())),
+            Some(SyntaxElement::Token(in_token)),
+            Some(SyntaxElement::Node(iterable.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_else_clause(
+    at_token: SyntaxToken,
+    else_token: SyntaxToken,
+    body: AnyScssElseClauseBody,
+) -> ScssElseClause {
+    ScssElseClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ELSE_CLAUSE,
+        [
+            Some(SyntaxElement::Token(at_token)),
+            Some(SyntaxElement::Token(else_token)),
+            Some(SyntaxElement::Node(body.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_extend_at_rule(
+    extend_token: SyntaxToken,
+    selectors: CssSelectorList,
+    semicolon_token: SyntaxToken,
+) -> ScssExtendAtRuleBuilder {
+    ScssExtendAtRuleBuilder {
+        extend_token,
+        selectors,
+        semicolon_token,
+        optional: None,
+    }
+}
+pub struct ScssExtendAtRuleBuilder {
+    extend_token: SyntaxToken,
+    selectors: CssSelectorList,
+    semicolon_token: SyntaxToken,
+    optional: Option<ScssExtendOptional>,
+}
+impl ScssExtendAtRuleBuilder {
+    pub fn with_optional(mut self, optional: ScssExtendOptional) -> Self {
+        self.optional = Some(optional);
+        self
+    }
+    pub fn build(self) -> ScssExtendAtRule {
+        ScssExtendAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_EXTEND_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.extend_token)),
+                Some(SyntaxElement::Node(self.selectors.into_syntax())),
+                self.optional
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.semicolon_token)),
+            ],
+        ))
+    }
+}
+pub fn scss_extend_optional(
+    excl_token: SyntaxToken,
+    optional_token: SyntaxToken,
+) -> ScssExtendOptional {
+    ScssExtendOptional::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EXTEND_OPTIONAL,
+        [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(optional_token)),
+        ],
+    ))
+}
+pub fn scss_for_at_rule(
+    for_token: SyntaxToken,
+    variable: ScssIdentifier,
+    from_token: SyntaxToken,
+    start: AnyCssValue,
+    operator_token: SyntaxToken,
+    end: AnyCssValue,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssForAtRule {
+    ScssForAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FOR_AT_RULE,
+        [
+            Some(SyntaxElement::Token(for_token)),
+            Some(SyntaxElement::Node(variable.into_syntax())),
+            Some(SyntaxElement::Token(from_token)),
+            Some(SyntaxElement::Node(start.into_syntax())),
+            Some(SyntaxElement::Token(operator_token)),
+            Some(SyntaxElement::Node(end.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_forward_as(
+    as_token: SyntaxToken,
+    prefix: CssCustomIdentifier,
+    star_token: SyntaxToken,
+) -> ScssForwardAs {
+    ScssForwardAs::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_AS,
+        [
+            Some(SyntaxElement::Token(as_token)),
+            Some(SyntaxElement::Node(prefix.into_syntax())),
+            Some(SyntaxElement::Token(star_token)),
+        ],
+    ))
+}
+pub fn scss_forward_at_rule(
+    forward_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+) -> ScssForwardAtRuleBuilder {
+    ScssForwardAtRuleBuilder {
+        forward_token,
+        url,
+        semicolon_token,
+        prefix: None,
+        visibility: None,
+        configuration: None,
+    }
+}
+pub struct ScssForwardAtRuleBuilder {
+    forward_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+    prefix: Option<ScssForwardAs>,
+    visibility: Option<ScssForwardVisibility>,
+    configuration: Option<ScssModuleConfiguration>,
+}
+impl ScssForwardAtRuleBuilder {
+    pub fn with_prefix(mut self, prefix: ScssForwardAs) -> Self {
+        self.prefix = Some(prefix);
+        self
+    }
+    pub fn with_visibility(mut self, visibility: ScssForwardVisibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+    pub fn with_configuration(mut self, configuration: ScssModuleConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+    pub fn build(self) -> ScssForwardAtRule {
+        ScssForwardAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_FORWARD_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.forward_token)),
+                Some(SyntaxElement::Node(self.url.into_syntax())),
+                self.prefix
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.visibility
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.configuration
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.semicolon_token)),
+            ],
+        ))
+    }
+}
+pub fn scss_forward_visibility(
+    modifier_token: SyntaxToken,
+    members: ScssForwardMemberList,
+) -> ScssForwardVisibility {
+    ScssForwardVisibility::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_VISIBILITY,
+        [
+            Some(SyntaxElement::Token(modifier_token)),
+            Some(SyntaxElement::Node(members.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_identifier(dollar_token: SyntaxToken, name: CssCustomIdentifier) -> ScssIdentifier {
+    ScssIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_IDENTIFIER,
+        [
+            Some(SyntaxElement::Token(dollar_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_if_at_rule(
+    if_token: SyntaxToken,
+    condition: CssGenericComponentValueList,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssIfAtRuleBuilder {
+    ScssIfAtRuleBuilder {
+        if_token,
+        condition,
+        block,
+        else_clause: None,
+    }
+}
+pub struct ScssIfAtRuleBuilder {
+    if_token: SyntaxToken,
+    condition: CssGenericComponentValueList,
+    block: AnyCssDeclarationOrRuleBlock,
+    else_clause: Option<ScssElseClause>,
+}
+impl ScssIfAtRuleBuilder {
+    pub fn with_else_clause(mut self, else_clause: ScssElseClause) -> Self {
+        self.else_clause = Some(else_clause);
+        self
+    }
+    pub fn build(self) -> ScssIfAtRule {
+        ScssIfAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_IF_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.if_token)),
+                Some(SyntaxElement::Node(self.condition.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+                self.else_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_include_arguments(
+    l_paren_token: SyntaxToken,
+    items: ScssArgumentList,
+    r_paren_token: SyntaxToken,
+) -> ScssIncludeArguments {
+    ScssIncludeArguments::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_INCLUDE_ARGUMENTS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_include_at_rule(
+    include_token: SyntaxToken,
+    name: AnyScssIncludeName,
+) -> ScssIncludeAtRuleBuilder {
+    ScssIncludeAtRuleBuilder {
+        include_token,
+        name,
+        arguments: None,
+        block: None,
+        semicolon_token: None,
+    }
+}
+pub struct ScssIncludeAtRuleBuilder {
+    include_token: SyntaxToken,
+    name: AnyScssIncludeName,
+    arguments: Option<ScssIncludeArguments>,
+    block: Option<AnyCssDeclarationOrRuleBlock>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssIncludeAtRuleBuilder {
+    pub fn with_arguments(mut self, arguments: ScssIncludeArguments) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_block(mut self, block: AnyCssDeclarationOrRuleBlock) -> Self {
+        self.block = Some(block);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssIncludeAtRule {
+        ScssIncludeAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_INCLUDE_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.include_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.block
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_interpolation(
+    hash_token: SyntaxToken,
+    l_curly_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    r_curly_token: SyntaxToken,
+) -> ScssInterpolation {
+    ScssInterpolation::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_INTERPOLATION,
+        [
+            Some(SyntaxElement::Token(hash_token)),
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
+pub fn scss_map_expression(
+    l_paren_token: SyntaxToken,
+    pairs: ScssMapExpressionPairList,
+    r_paren_token: SyntaxToken,
+) -> ScssMapExpression {
+    ScssMapExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(pairs.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_map_expression_pair(
+    key: AnyCssValue,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> ScssMapExpressionPair {
+    ScssMapExpressionPair::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR,
+        [
+            Some(SyntaxElement::Node(key.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_mixin_at_rule(
+    mixin_token: SyntaxToken,
+    name: CssCustomIdentifier,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssMixinAtRuleBuilder {
+    ScssMixinAtRuleBuilder {
+        mixin_token,
+        name,
+        block,
+        parameters: None,
+    }
+}
+pub struct ScssMixinAtRuleBuilder {
+    mixin_token: SyntaxToken,
+    name: CssCustomIdentifier,
+    block: AnyCssDeclarationOrRuleBlock,
+    parameters: Option<ScssParameters>,
+}
+impl ScssMixinAtRuleBuilder {
+    pub fn with_parameters(mut self, parameters: ScssParameters) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+    pub fn build(self) -> ScssMixinAtRule {
+        ScssMixinAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_MIXIN_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.mixin_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.parameters
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_module_configuration(
+    with_token: SyntaxToken,
+    value: ScssMapExpression,
+) -> ScssModuleConfiguration {
+    ScssModuleConfiguration::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MODULE_CONFIGURATION,
+        [
+            Some(SyntaxElement::Token(with_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_operator(value_token: SyntaxToken) -> ScssOperator {
+    ScssOperator::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_OPERATOR,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn scss_parameter(name: ScssIdentifier) -> ScssParameterBuilder {
+    ScssParameterBuilder {
+        name,
+        default_value: None,
+    }
+}
+pub struct ScssParameterBuilder {
+    name: ScssIdentifier,
+    default_value: Option<ScssParameterDefault>,
+}
+impl ScssParameterBuilder {
+    pub fn with_default_value(mut self, default_value: ScssParameterDefault) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+    pub fn build(self) -> ScssParameter {
+        ScssParameter::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_PARAMETER,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.default_value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_parameter_default(
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> ScssParameterDefault {
+    ScssParameterDefault::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETER_DEFAULT,
+        [
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_parameters(
+    l_paren_token: SyntaxToken,
+    items: ScssParameterList,
+    r_paren_token: SyntaxToken,
+) -> ScssParameters {
+    ScssParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_parenthesized_expression(
+    l_paren_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    r_paren_token: SyntaxToken,
+) -> ScssParenthesizedExpression {
+    ScssParenthesizedExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARENTHESIZED_EXPRESSION,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_placeholder_selector(
+    percent_token: SyntaxToken,
+    name: CssCustomIdentifier,
+) -> ScssPlaceholderSelector {
+    ScssPlaceholderSelector::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PLACEHOLDER_SELECTOR,
+        [
+            Some(SyntaxElement::Token(percent_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_qualified_name(
+    module: CssCustomIdentifier,
+    dot_token: SyntaxToken,
+    member: AnyScssModuleMember,
+) -> ScssQualifiedName {
+    ScssQualifiedName::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_QUALIFIED_NAME,
+        [
+            Some(SyntaxElement::Node(module.into_syntax())),
+            Some(SyntaxElement::Token(dot_token)),
+            Some(SyntaxElement::Node(member.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_use_all_namespace(star_token: SyntaxToken) -> ScssUseAllNamespace {
+    ScssUseAllNamespace::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_USE_ALL_NAMESPACE,
+        [Some(SyntaxElement::Token(star_token))],
+    ))
+}
+pub fn scss_use_as(as_token: SyntaxToken, namespace: AnyScssUseNamespace) -> ScssUseAs {
+    ScssUseAs::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_USE_AS,
+        [
+            Some(SyntaxElement::Token(as_token)),
+            Some(SyntaxElement::Node(namespace.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_use_at_rule(
+    use_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+) -> ScssUseAtRuleBuilder {
+    ScssUseAtRuleBuilder {
+        use_token,
+        url,
+        semicolon_token,
+        namespace: None,
+        configuration: None,
+    }
+}
+pub struct ScssUseAtRuleBuilder {
+    use_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+    namespace: Option<ScssUseAs>,
+    configuration: Option<ScssModuleConfiguration>,
+}
+impl ScssUseAtRuleBuilder {
+    pub fn with_namespace(mut self, namespace: ScssUseAs) -> Self {
+        self.namespace = Some(namespace);
+        self
+    }
+    pub fn with_configuration(mut self, configuration: ScssModuleConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+    pub fn build(self) -> ScssUseAtRule {
+        ScssUseAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_USE_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.use_token)),
+                Some(SyntaxElement::Node(self.url.into_syntax())),
+                self.namespace
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.configuration
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.semicolon_token)),
+            ],
+        ))
+    }
+}
+pub fn scss_variable_modifier(
+    excl_token: SyntaxToken,
+    value_token: SyntaxToken,
+) -> ScssVariableModifier {
+    ScssVariableModifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER,
+        [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(value_token)),
+        ],
+    ))
+}
+pub fn scss_while_at_rule(
+    while_token: SyntaxToken,
+    condition: CssGenericComponentValueList,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssWhileAtRule {
+    ScssWhileAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_WHILE_AT_RULE,
+        [
+            Some(SyntaxElement::Token(while_token)),
+            Some(SyntaxElement::Node(condition.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn css_component_value_list<I>(items: I) -> CssComponentValueList
+where
+    I: IntoIterator<Item = AnyCssValue>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssComponentValueList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COMPONENT_VALUE_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn css_composes_class_list<I>(items: I) -> CssComposesClassList
+where
+    I: IntoIterator<Item = CssCustomIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssComposesClassList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COMPOSES_CLASS_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn css_compound_selector_list<I, S>(items: I, separators: S) -> CssCompoundSelectorList
+where
+    I: IntoIterator<Item = AnyCssCompoundSelector>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    CssCompoundSelectorList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COMPOUND_SELECTOR_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn css_declaration_list<I>(items: I) -> CssDeclarationList
+where
+    I: IntoIterator<Item = CssDeclarationWithSemicolon>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssDeclarationList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_DECLARATION_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn css_declaration_or_at_rule_list<I>(items: I) -> CssDeclarationOrAtRuleList
+where
+    I: IntoIterator<Item = AnyCssDeclarationOrAtRule>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssDeclarationOrAtRuleList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_DECLARATION_OR_AT_RULE_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn css_declaration_or_rule_list<I>(items: I) -> CssDeclarationOrRuleList
+where
+    I: IntoIterator<Item = AnyCssDeclarationOrRule>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssDeclarationOrRuleList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_DECLARATION_OR_RULE_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn css_document_matcher_list<I, S>(items: I, separators: S) -> CssDocumentMatcherList
+where
+    I: IntoIterator<Item = AnyCssDocumentMatcher>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    CssDocumentMatcherList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_DOCUMENT_MATCHER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn css_font_feature_values_item_list<I>(items: I) -> CssFontFeatureValuesItemList
+where
+    I: IntoIterator<Item = AnyCssFontFeatureValuesItem>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssFontFeatureValuesItemList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_FONT_FEATURE_VALUES_ITEM_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn css_generic_component_value_list<I>(items: I) -> CssGenericComponentValueList
+where
+    I: IntoIterator<Item = AnyCssGenericComponentValue>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssGenericComponentValueList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_GENERIC_COMPONENT_VALUE_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn css_keyframes_item_list<I>(items: I) -> CssKeyframesItemList
+where
+    I: IntoIterator<Item = AnyCssKeyframesItem>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssKeyframesItemList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_KEYFRAMES_ITEM_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn css_keyframes_selector_list<I, S>(items: I, separators: S) -> CssKeyframesSelectorList
+where
+    I: IntoIterator<Item = AnyCssKeyframesSelector>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    CssKeyframesSelectorList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_KEYFRAMES_SELECTOR_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn css_layer_name_list<I, S>(items: I, separators: S) -> CssLayerNameList
+where
+    I: IntoIterator<Item = CssIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
     let length = items.len() + separators.len();
     CssLayerNameList::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_LAYER_NAME_LIST,
@@ -2399,6 +3532,234 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn css_value_at_rule_import_specifier_list<I, S>(
+    items: I,
+    separators: S,
+) -> CssValueAtRuleImportSpecifierList
+where
+    I: IntoIterator<Item = AnyCssValueAtRuleImportSpecifier>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    CssValueAtRuleImportSpecifierList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_VALUE_AT_RULE_IMPORT_SPECIFIER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn less_extend_target_list<I, S>(items: I, separators: S) -> LessExtendTargetList
+where
+    I: IntoIterator<Item = LessExtendTarget>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    LessExtendTargetList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_EXTEND_TARGET_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn less_import_option_list<I, S>(items: I, separators: S) -> LessImportOptionList
+where
+    I: IntoIterator<Item = CssIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    LessImportOptionList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_IMPORT_OPTION_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn less_mixin_parameter_list<I, S>(items: I, separators: S) -> LessMixinParameterList
+where
+    I: IntoIterator<Item = LessMixinParameter>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    LessMixinParameterList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PARAMETER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn less_mixin_semicolon_parameter_list<I, S>(
+    items: I,
+    separators: S,
+) -> LessMixinSemicolonParameterList
+where
+    I: IntoIterator<Item = LessMixinParameter>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    LessMixinSemicolonParameterList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_SEMICOLON_PARAMETER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_argument_list<I, S>(items: I, separators: S) -> ScssArgumentList
+where
+    I: IntoIterator<Item = ScssArgument>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssArgumentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ARGUMENT_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_each_binding_list<I, S>(items: I, separators: S) -> ScssEachBindingList
+where
+    I: IntoIterator<Item = ScssIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssEachBindingList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EACH_BINDING_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_forward_member_list<I, S>(items: I, separators: S) -> ScssForwardMemberList
+where
+    I: IntoIterator<Item = AnyScssModuleMember>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssForwardMemberList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_MEMBER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_map_expression_pair_list<I, S>(items: I, separators: S) -> ScssMapExpressionPairList
+where
+    I: IntoIterator<Item = ScssMapExpressionPair>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssMapExpressionPairList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_parameter_list<I, S>(items: I, separators: S) -> ScssParameterList
+where
+    I: IntoIterator<Item = ScssParameter>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssParameterList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_variable_modifier_list<I>(items: I) -> ScssVariableModifierList
+where
+    I: IntoIterator<Item = ScssVariableModifier>,
+    I::IntoIter: ExactSizeIterator,
+{
+    ScssVariableModifierList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn css_bogus<I>(slots: I) -> CssBogus
 where
     I: IntoIterator<Item = Option<SyntaxElement>>,
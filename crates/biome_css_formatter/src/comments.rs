@@ -2,13 +2,13 @@ use crate::prelude::*;
 use biome_css_syntax::{AnyCssDeclarationName, CssFunction, CssLanguage, TextLen};
 use biome_diagnostics::category;
 use biome_formatter::comments::{
-    is_doc_comment, CommentKind, CommentPlacement, CommentStyle, CommentTextPosition, Comments,
-    DecoratedComment, SourceComment,
+    find_suppression_range_kind, is_doc_comment, CommentKind, CommentPlacement, CommentStyle,
+    CommentTextPosition, Comments, DecoratedComment, SourceComment, SuppressionRangeKind,
 };
 use biome_formatter::formatter::Formatter;
 use biome_formatter::{write, FormatResult, FormatRule};
 use biome_rowan::SyntaxTriviaPieceComments;
-use biome_suppression::parse_suppression_comment;
+use biome_suppression::{parse_suppression_comment, SuppressionScope};
 
 pub type CssComments = Comments<CssLanguage>;
 
@@ -68,10 +68,24 @@ impl CommentStyle for CssCommentStyle {
     fn is_suppression(text: &str) -> bool {
         parse_suppression_comment(text)
             .filter_map(Result::ok)
+            .filter(|suppression| suppression.scope == SuppressionScope::Line)
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
     }
 
+    fn get_suppression_range_kind(text: &str) -> Option<SuppressionRangeKind> {
+        find_suppression_range_kind(parse_suppression_comment(text).filter_map(|suppression| {
+            let suppression = suppression.ok()?;
+            let kind = match suppression.scope {
+                SuppressionScope::Line => return None,
+                SuppressionScope::RangeStart => SuppressionRangeKind::Start,
+                SuppressionScope::RangeEnd => SuppressionRangeKind::End,
+                SuppressionScope::File => SuppressionRangeKind::File,
+            };
+            Some((kind, suppression.categories))
+        }))
+    }
+
     fn get_comment_kind(comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
         if comment.text().starts_with("/*") {
             if comment.has_newline() {
@@ -27,6 +27,19 @@ impl FormatRule<AnyCssAtRule> for FormatAnyCssAtRule {
             AnyCssAtRule::CssDocumentAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssBogusAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssPropertyAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssValueAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssUseAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssForwardAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssMixinAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssIncludeAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssContentAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssExtendAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssIfAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssEachAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssForAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssWhileAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::LessPluginAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::LessImportAtRule(node) => node.format().fmt(f),
         }
     }
 }
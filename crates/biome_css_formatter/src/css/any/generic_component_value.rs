@@ -10,6 +10,8 @@ impl FormatRule<AnyCssGenericComponentValue> for FormatAnyCssGenericComponentVal
         match node {
             AnyCssGenericComponentValue::AnyCssValue(node) => node.format().fmt(f),
             AnyCssGenericComponentValue::CssGenericDelimiter(node) => node.format().fmt(f),
+            AnyCssGenericComponentValue::ScssOperator(node) => node.format().fmt(f),
+            AnyCssGenericComponentValue::LessOperator(node) => node.format().fmt(f),
         }
     }
 }
@@ -2,6 +2,7 @@
 
 pub(crate) mod at_rule;
 pub(crate) mod attribute_matcher_value;
+pub(crate) mod composes_import_source;
 pub(crate) mod compound_selector;
 pub(crate) mod container_and_combinable_query;
 pub(crate) mod container_or_combinable_query;
@@ -69,3 +70,6 @@ pub(crate) mod supports_or_combinable_condition;
 pub(crate) mod url_modifier;
 pub(crate) mod url_value;
 pub(crate) mod value;
+pub(crate) mod value_at_rule_clause;
+pub(crate) mod value_at_rule_import_source;
+pub(crate) mod value_at_rule_import_specifier;
@@ -21,6 +21,7 @@ impl FormatRule<AnyCssPseudoClass> for FormatAnyCssPseudoClass {
             }
             AnyCssPseudoClass::CssPseudoClassFunctionValueList(node) => node.format().fmt(f),
             AnyCssPseudoClass::CssPseudoClassFunctionNth(node) => node.format().fmt(f),
+            AnyCssPseudoClass::LessPseudoClassExtend(node) => node.format().fmt(f),
             AnyCssPseudoClass::CssBogusPseudoClass(node) => node.format().fmt(f),
         }
     }
@@ -11,6 +11,11 @@ impl FormatRule<AnyCssRule> for FormatAnyCssRule {
             AnyCssRule::CssQualifiedRule(node) => node.format().fmt(f),
             AnyCssRule::CssNestedQualifiedRule(node) => node.format().fmt(f),
             AnyCssRule::CssAtRule(node) => node.format().fmt(f),
+            AnyCssRule::ScssDeclaration(node) => node.format().fmt(f),
+            AnyCssRule::LessDeclaration(node) => node.format().fmt(f),
+            AnyCssRule::LessMixinDeclaration(node) => node.format().fmt(f),
+            AnyCssRule::LessMixinCall(node) => node.format().fmt(f),
+            AnyCssRule::LessExtendDeclaration(node) => node.format().fmt(f),
             AnyCssRule::CssBogusRule(node) => node.format().fmt(f),
         }
     }
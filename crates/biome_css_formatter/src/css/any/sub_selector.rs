@@ -13,6 +13,7 @@ impl FormatRule<AnyCssSubSelector> for FormatAnyCssSubSelector {
             AnyCssSubSelector::CssAttributeSelector(node) => node.format().fmt(f),
             AnyCssSubSelector::CssPseudoClassSelector(node) => node.format().fmt(f),
             AnyCssSubSelector::CssPseudoElementSelector(node) => node.format().fmt(f),
+            AnyCssSubSelector::ScssPlaceholderSelector(node) => node.format().fmt(f),
             AnyCssSubSelector::CssBogusSubSelector(node) => node.format().fmt(f),
         }
     }
@@ -17,6 +17,14 @@ impl FormatRule<AnyCssValue> for FormatAnyCssValue {
             AnyCssValue::CssRatio(node) => node.format().fmt(f),
             AnyCssValue::AnyCssFunction(node) => node.format().fmt(f),
             AnyCssValue::CssColor(node) => node.format().fmt(f),
+            AnyCssValue::ScssIdentifier(node) => node.format().fmt(f),
+            AnyCssValue::ScssQualifiedName(node) => node.format().fmt(f),
+            AnyCssValue::ScssInterpolation(node) => node.format().fmt(f),
+            AnyCssValue::ScssParenthesizedExpression(node) => node.format().fmt(f),
+            AnyCssValue::ScssMapExpression(node) => node.format().fmt(f),
+            AnyCssValue::LessIdentifier(node) => node.format().fmt(f),
+            AnyCssValue::LessParenthesizedExpression(node) => node.format().fmt(f),
+            AnyCssValue::LessEscapedString(node) => node.format().fmt(f),
         }
     }
 }
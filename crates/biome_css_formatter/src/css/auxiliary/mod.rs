@@ -4,6 +4,8 @@ pub(crate) mod attribute_matcher;
 pub(crate) mod attribute_matcher_value;
 pub(crate) mod attribute_name;
 pub(crate) mod binary_expression;
+pub(crate) mod composes_import_specifier;
+pub(crate) mod composes_property_value;
 pub(crate) mod container_and_query;
 pub(crate) mod container_not_query;
 pub(crate) mod container_or_query;
@@ -69,3 +71,7 @@ pub(crate) mod supports_not_condition;
 pub(crate) mod supports_or_condition;
 pub(crate) mod universal_namespace_prefix;
 pub(crate) mod url_function;
+pub(crate) mod value_at_rule_declaration_clause;
+pub(crate) mod value_at_rule_import_clause;
+pub(crate) mod value_at_rule_import_specifier;
+pub(crate) mod value_at_rule_named_import_specifier;
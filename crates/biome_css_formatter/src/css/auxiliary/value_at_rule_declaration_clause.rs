@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssValueAtRuleDeclarationClause, CssValueAtRuleDeclarationClauseFields};
+use biome_formatter::write;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssValueAtRuleDeclarationClause;
+impl FormatNodeRule<CssValueAtRuleDeclarationClause> for FormatCssValueAtRuleDeclarationClause {
+    fn fmt_fields(
+        &self,
+        node: &CssValueAtRuleDeclarationClause,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssValueAtRuleDeclarationClauseFields {
+            name,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [name.format(), colon_token.format(), space(), value.format()]
+        )
+    }
+}
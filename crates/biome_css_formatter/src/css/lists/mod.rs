@@ -1,6 +1,7 @@
 //! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
 
 pub(crate) mod component_value_list;
+pub(crate) mod composes_class_list;
 pub(crate) mod compound_selector_list;
 pub(crate) mod declaration_list;
 pub(crate) mod declaration_or_at_rule_list;
@@ -23,3 +24,4 @@ pub(crate) mod rule_list;
 pub(crate) mod selector_list;
 pub(crate) mod sub_selector_list;
 pub(crate) mod url_modifier_list;
+pub(crate) mod value_at_rule_import_specifier_list;
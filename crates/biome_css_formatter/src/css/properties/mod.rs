@@ -1,3 +1,4 @@
 //! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
 
+pub(crate) mod composes_property;
 pub(crate) mod generic_property;
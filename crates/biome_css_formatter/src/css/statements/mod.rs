@@ -20,3 +20,4 @@ pub(crate) mod property_at_rule;
 pub(crate) mod scope_at_rule;
 pub(crate) mod starting_style_at_rule;
 pub(crate) mod supports_at_rule;
+pub(crate) mod value_at_rule;
@@ -144,6 +144,206 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssAtRule {
         )
     }
 }
+impl FormatRule<biome_css_syntax::ScssDeclaration>
+    for crate::scss::auxiliary::declaration::FormatScssDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssDeclaration,
+        crate::scss::auxiliary::declaration::FormatScssDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::declaration::FormatScssDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssDeclaration,
+        crate::scss::auxiliary::declaration::FormatScssDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::declaration::FormatScssDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessDeclaration>
+    for crate::less::auxiliary::declaration::FormatLessDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessDeclaration,
+        crate::less::auxiliary::declaration::FormatLessDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::declaration::FormatLessDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessDeclaration,
+        crate::less::auxiliary::declaration::FormatLessDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::declaration::FormatLessDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinDeclaration>
+    for crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinDeclaration,
+        crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinDeclaration,
+        crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinCall>
+    for crate::less::auxiliary::mixin_call::FormatLessMixinCall
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinCall,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinCall>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinCall {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinCall,
+        crate::less::auxiliary::mixin_call::FormatLessMixinCall,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_call::FormatLessMixinCall::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinCall {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinCall,
+        crate::less::auxiliary::mixin_call::FormatLessMixinCall,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_call::FormatLessMixinCall::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessExtendDeclaration>
+    for crate::less::auxiliary::extend_declaration::FormatLessExtendDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessExtendDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessExtendDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessExtendDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessExtendDeclaration,
+        crate::less::auxiliary::extend_declaration::FormatLessExtendDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::extend_declaration::FormatLessExtendDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessExtendDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessExtendDeclaration,
+        crate::less::auxiliary::extend_declaration::FormatLessExtendDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::extend_declaration::FormatLessExtendDeclaration::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssComplexSelector>
     for crate::css::selectors::complex_selector::FormatCssComplexSelector
 {
@@ -506,6 +706,46 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementSelector
         )
     }
 }
+impl FormatRule<biome_css_syntax::ScssPlaceholderSelector>
+    for crate::scss::selectors::placeholder_selector::FormatScssPlaceholderSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssPlaceholderSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssPlaceholderSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssPlaceholderSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssPlaceholderSelector,
+        crate::scss::selectors::placeholder_selector::FormatScssPlaceholderSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::selectors::placeholder_selector::FormatScssPlaceholderSelector::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssPlaceholderSelector {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssPlaceholderSelector,
+        crate::scss::selectors::placeholder_selector::FormatScssPlaceholderSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::selectors::placeholder_selector::FormatScssPlaceholderSelector::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssNamespace>
     for crate::css::auxiliary::namespace::FormatCssNamespace
 {
@@ -1083,6 +1323,46 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionNt
         )
     }
 }
+impl FormatRule<biome_css_syntax::LessPseudoClassExtend>
+    for crate::less::pseudo::pseudo_class_extend::FormatLessPseudoClassExtend
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessPseudoClassExtend,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessPseudoClassExtend>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessPseudoClassExtend {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessPseudoClassExtend,
+        crate::less::pseudo::pseudo_class_extend::FormatLessPseudoClassExtend,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::pseudo::pseudo_class_extend::FormatLessPseudoClassExtend::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessPseudoClassExtend {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessPseudoClassExtend,
+        crate::less::pseudo::pseudo_class_extend::FormatLessPseudoClassExtend,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::pseudo::pseudo_class_extend::FormatLessPseudoClassExtend::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssRelativeSelector>
     for crate::css::selectors::relative_selector::FormatCssRelativeSelector
 {
@@ -1735,150 +2015,338 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssGenericProperty {
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssGenericDelimiter>
-    for crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter
+impl FormatRule<biome_css_syntax::CssComposesProperty>
+    for crate::css::properties::composes_property::FormatCssComposesProperty
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssGenericDelimiter,
+        node: &biome_css_syntax::CssComposesProperty,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssGenericDelimiter>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssComposesProperty>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssGenericDelimiter {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssComposesProperty {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssGenericDelimiter,
-        crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter,
+        biome_css_syntax::CssComposesProperty,
+        crate::css::properties::composes_property::FormatCssComposesProperty,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter::default(),
+            crate::css::properties::composes_property::FormatCssComposesProperty::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssGenericDelimiter {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssComposesProperty {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssGenericDelimiter,
-        crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter,
+        biome_css_syntax::CssComposesProperty,
+        crate::css::properties::composes_property::FormatCssComposesProperty,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter::default(),
+            crate::css::properties::composes_property::FormatCssComposesProperty::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssDashedIdentifier>
-    for crate::css::value::dashed_identifier::FormatCssDashedIdentifier
+impl FormatRule<biome_css_syntax::CssComposesPropertyValue>
+    for crate::css::auxiliary::composes_property_value::FormatCssComposesPropertyValue
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssDashedIdentifier,
+        node: &biome_css_syntax::CssComposesPropertyValue,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssDashedIdentifier>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssComposesPropertyValue>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDashedIdentifier {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssComposesPropertyValue {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDashedIdentifier,
-        crate::css::value::dashed_identifier::FormatCssDashedIdentifier,
+        biome_css_syntax::CssComposesPropertyValue,
+        crate::css::auxiliary::composes_property_value::FormatCssComposesPropertyValue,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::value::dashed_identifier::FormatCssDashedIdentifier::default(),
+            crate::css::auxiliary::composes_property_value::FormatCssComposesPropertyValue::default(
+            ),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDashedIdentifier {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssComposesPropertyValue {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDashedIdentifier,
-        crate::css::value::dashed_identifier::FormatCssDashedIdentifier,
+        biome_css_syntax::CssComposesPropertyValue,
+        crate::css::auxiliary::composes_property_value::FormatCssComposesPropertyValue,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::value::dashed_identifier::FormatCssDashedIdentifier::default(),
+            crate::css::auxiliary::composes_property_value::FormatCssComposesPropertyValue::default(
+            ),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssCharsetAtRule>
-    for crate::css::statements::charset_at_rule::FormatCssCharsetAtRule
+impl FormatRule<biome_css_syntax::CssComposesImportSpecifier>
+    for crate::css::auxiliary::composes_import_specifier::FormatCssComposesImportSpecifier
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssCharsetAtRule,
+        node: &biome_css_syntax::CssComposesImportSpecifier,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssCharsetAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssComposesImportSpecifier>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssCharsetAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssComposesImportSpecifier {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssCharsetAtRule,
-        crate::css::statements::charset_at_rule::FormatCssCharsetAtRule,
+        biome_css_syntax::CssComposesImportSpecifier,
+        crate::css::auxiliary::composes_import_specifier::FormatCssComposesImportSpecifier,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::statements::charset_at_rule::FormatCssCharsetAtRule::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: composes_import_specifier :: FormatCssComposesImportSpecifier :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCharsetAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssComposesImportSpecifier {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssCharsetAtRule,
-        crate::css::statements::charset_at_rule::FormatCssCharsetAtRule,
+        biome_css_syntax::CssComposesImportSpecifier,
+        crate::css::auxiliary::composes_import_specifier::FormatCssComposesImportSpecifier,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::statements::charset_at_rule::FormatCssCharsetAtRule::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: composes_import_specifier :: FormatCssComposesImportSpecifier :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssColorProfileAtRule>
-    for crate::css::statements::color_profile_at_rule::FormatCssColorProfileAtRule
+impl FormatRule<biome_css_syntax::CssGenericDelimiter>
+    for crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssColorProfileAtRule,
+        node: &biome_css_syntax::CssGenericDelimiter,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssColorProfileAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssGenericDelimiter>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssColorProfileAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssGenericDelimiter {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssColorProfileAtRule,
-        crate::css::statements::color_profile_at_rule::FormatCssColorProfileAtRule,
+        biome_css_syntax::CssGenericDelimiter,
+        crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::color_profile_at_rule::FormatCssColorProfileAtRule::default(),
+            crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssGenericDelimiter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssGenericDelimiter,
+        crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssOperator>
+    for crate::scss::auxiliary::operator::FormatScssOperator
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::ScssOperator, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssOperator>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssOperator {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssOperator,
+        crate::scss::auxiliary::operator::FormatScssOperator,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::operator::FormatScssOperator::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssOperator {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssOperator,
+        crate::scss::auxiliary::operator::FormatScssOperator,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::operator::FormatScssOperator::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessOperator>
+    for crate::less::auxiliary::operator::FormatLessOperator
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::LessOperator, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessOperator>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessOperator {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessOperator,
+        crate::less::auxiliary::operator::FormatLessOperator,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::operator::FormatLessOperator::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessOperator {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessOperator,
+        crate::less::auxiliary::operator::FormatLessOperator,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::operator::FormatLessOperator::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssDashedIdentifier>
+    for crate::css::value::dashed_identifier::FormatCssDashedIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssDashedIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssDashedIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDashedIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssDashedIdentifier,
+        crate::css::value::dashed_identifier::FormatCssDashedIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::dashed_identifier::FormatCssDashedIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDashedIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssDashedIdentifier,
+        crate::css::value::dashed_identifier::FormatCssDashedIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::dashed_identifier::FormatCssDashedIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssCharsetAtRule>
+    for crate::css::statements::charset_at_rule::FormatCssCharsetAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssCharsetAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssCharsetAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCharsetAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssCharsetAtRule,
+        crate::css::statements::charset_at_rule::FormatCssCharsetAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::charset_at_rule::FormatCssCharsetAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCharsetAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssCharsetAtRule,
+        crate::css::statements::charset_at_rule::FormatCssCharsetAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::charset_at_rule::FormatCssCharsetAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssColorProfileAtRule>
+    for crate::css::statements::color_profile_at_rule::FormatCssColorProfileAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssColorProfileAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssColorProfileAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssColorProfileAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssColorProfileAtRule,
+        crate::css::statements::color_profile_at_rule::FormatCssColorProfileAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::color_profile_at_rule::FormatCssColorProfileAtRule::default(),
         )
     }
 }
@@ -2523,2917 +2991,5010 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPropertyAtRule {
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssFontFeatureValuesBlock>
-    for crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock
+impl FormatRule<biome_css_syntax::CssValueAtRule>
+    for crate::css::statements::value_at_rule::FormatCssValueAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssFontFeatureValuesBlock,
+        node: &biome_css_syntax::CssValueAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssFontFeatureValuesBlock>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssValueAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesBlock {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssValueAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssFontFeatureValuesBlock,
-        crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock,
+        biome_css_syntax::CssValueAtRule,
+        crate::css::statements::value_at_rule::FormatCssValueAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_block :: FormatCssFontFeatureValuesBlock :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::value_at_rule::FormatCssValueAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesBlock {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssFontFeatureValuesBlock,
-        crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock,
+        biome_css_syntax::CssValueAtRule,
+        crate::css::statements::value_at_rule::FormatCssValueAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_block :: FormatCssFontFeatureValuesBlock :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::value_at_rule::FormatCssValueAtRule::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssFontFeatureValuesItem>
-    for crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem
+impl FormatRule<biome_css_syntax::ScssUseAtRule>
+    for crate::scss::statements::use_at_rule::FormatScssUseAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssFontFeatureValuesItem,
+        node: &biome_css_syntax::ScssUseAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssFontFeatureValuesItem>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::ScssUseAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItem {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssUseAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssFontFeatureValuesItem,
-        crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem,
+        biome_css_syntax::ScssUseAtRule,
+        crate::scss::statements::use_at_rule::FormatScssUseAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_item :: FormatCssFontFeatureValuesItem :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::use_at_rule::FormatScssUseAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItem {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssUseAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssFontFeatureValuesItem,
-        crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem,
+        biome_css_syntax::ScssUseAtRule,
+        crate::scss::statements::use_at_rule::FormatScssUseAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_item :: FormatCssFontFeatureValuesItem :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::use_at_rule::FormatScssUseAtRule::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssContainerNotQuery>
-    for crate::css::auxiliary::container_not_query::FormatCssContainerNotQuery
+impl FormatRule<biome_css_syntax::ScssForwardAtRule>
+    for crate::scss::statements::forward_at_rule::FormatScssForwardAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssContainerNotQuery,
+        node: &biome_css_syntax::ScssForwardAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssContainerNotQuery>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::ScssForwardAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerNotQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssForwardAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssContainerNotQuery,
-        crate::css::auxiliary::container_not_query::FormatCssContainerNotQuery,
+        biome_css_syntax::ScssForwardAtRule,
+        crate::scss::statements::forward_at_rule::FormatScssForwardAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::container_not_query::FormatCssContainerNotQuery::default(),
+            crate::scss::statements::forward_at_rule::FormatScssForwardAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerNotQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssForwardAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssContainerNotQuery,
-        crate::css::auxiliary::container_not_query::FormatCssContainerNotQuery,
+        biome_css_syntax::ScssForwardAtRule,
+        crate::scss::statements::forward_at_rule::FormatScssForwardAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::container_not_query::FormatCssContainerNotQuery::default(),
+            crate::scss::statements::forward_at_rule::FormatScssForwardAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssContainerOrQuery>
-    for crate::css::auxiliary::container_or_query::FormatCssContainerOrQuery
+impl FormatRule<biome_css_syntax::ScssMixinAtRule>
+    for crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssContainerOrQuery,
+        node: &biome_css_syntax::ScssMixinAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssContainerOrQuery>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::ScssMixinAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerOrQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssMixinAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssContainerOrQuery,
-        crate::css::auxiliary::container_or_query::FormatCssContainerOrQuery,
+        biome_css_syntax::ScssMixinAtRule,
+        crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::container_or_query::FormatCssContainerOrQuery::default(),
+            crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerOrQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssMixinAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssContainerOrQuery,
-        crate::css::auxiliary::container_or_query::FormatCssContainerOrQuery,
+        biome_css_syntax::ScssMixinAtRule,
+        crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::container_or_query::FormatCssContainerOrQuery::default(),
+            crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssContainerAndQuery>
-    for crate::css::auxiliary::container_and_query::FormatCssContainerAndQuery
+impl FormatRule<biome_css_syntax::ScssIncludeAtRule>
+    for crate::scss::statements::include_at_rule::FormatScssIncludeAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssContainerAndQuery,
+        node: &biome_css_syntax::ScssIncludeAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssContainerAndQuery>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::ScssIncludeAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerAndQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssIncludeAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssContainerAndQuery,
-        crate::css::auxiliary::container_and_query::FormatCssContainerAndQuery,
+        biome_css_syntax::ScssIncludeAtRule,
+        crate::scss::statements::include_at_rule::FormatScssIncludeAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::container_and_query::FormatCssContainerAndQuery::default(),
+            crate::scss::statements::include_at_rule::FormatScssIncludeAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerAndQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssIncludeAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssContainerAndQuery,
-        crate::css::auxiliary::container_and_query::FormatCssContainerAndQuery,
+        biome_css_syntax::ScssIncludeAtRule,
+        crate::scss::statements::include_at_rule::FormatScssIncludeAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::container_and_query::FormatCssContainerAndQuery::default(),
+            crate::scss::statements::include_at_rule::FormatScssIncludeAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssContainerQueryInParens>
-    for crate::css::auxiliary::container_query_in_parens::FormatCssContainerQueryInParens
+impl FormatRule<biome_css_syntax::ScssContentAtRule>
+    for crate::scss::statements::content_at_rule::FormatScssContentAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssContainerQueryInParens,
+        node: &biome_css_syntax::ScssContentAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssContainerQueryInParens>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::ScssContentAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerQueryInParens {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssContentAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssContainerQueryInParens,
-        crate::css::auxiliary::container_query_in_parens::FormatCssContainerQueryInParens,
+        biome_css_syntax::ScssContentAtRule,
+        crate::scss::statements::content_at_rule::FormatScssContentAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_query_in_parens :: FormatCssContainerQueryInParens :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::content_at_rule::FormatScssContentAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerQueryInParens {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssContentAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssContainerQueryInParens,
-        crate::css::auxiliary::container_query_in_parens::FormatCssContainerQueryInParens,
+        biome_css_syntax::ScssContentAtRule,
+        crate::scss::statements::content_at_rule::FormatScssContentAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_query_in_parens :: FormatCssContainerQueryInParens :: default ())
-    }
-}
-impl FormatRule < biome_css_syntax :: CssContainerSizeFeatureInParens > for crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssContainerSizeFeatureInParens , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssContainerSizeFeatureInParens > :: fmt (self , node , f) } }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerSizeFeatureInParens {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssContainerSizeFeatureInParens , crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens > ;
-    fn format(&self) -> Self::Format<'_> {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens :: default ())
-    }
-}
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerSizeFeatureInParens {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssContainerSizeFeatureInParens , crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens > ;
-    fn into_format(self) -> Self::Format {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::content_at_rule::FormatScssContentAtRule::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssContainerStyleQueryInParens>
-    for crate::css::auxiliary::container_style_query_in_parens::FormatCssContainerStyleQueryInParens
+impl FormatRule<biome_css_syntax::ScssExtendAtRule>
+    for crate::scss::statements::extend_at_rule::FormatScssExtendAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssContainerStyleQueryInParens,
+        node: &biome_css_syntax::ScssExtendAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssContainerStyleQueryInParens>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::ScssExtendAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleQueryInParens {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssContainerStyleQueryInParens , crate :: css :: auxiliary :: container_style_query_in_parens :: FormatCssContainerStyleQueryInParens > ;
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssExtendAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssExtendAtRule,
+        crate::scss::statements::extend_at_rule::FormatScssExtendAtRule,
+    >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_style_query_in_parens :: FormatCssContainerStyleQueryInParens :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::extend_at_rule::FormatScssExtendAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleQueryInParens {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssContainerStyleQueryInParens , crate :: css :: auxiliary :: container_style_query_in_parens :: FormatCssContainerStyleQueryInParens > ;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssExtendAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssExtendAtRule,
+        crate::scss::statements::extend_at_rule::FormatScssExtendAtRule,
+    >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_query_in_parens :: FormatCssContainerStyleQueryInParens :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::extend_at_rule::FormatScssExtendAtRule::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssContainerStyleNotQuery>
-    for crate::css::auxiliary::container_style_not_query::FormatCssContainerStyleNotQuery
+impl FormatRule<biome_css_syntax::ScssIfAtRule>
+    for crate::scss::statements::if_at_rule::FormatScssIfAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(
-        &self,
-        node: &biome_css_syntax::CssContainerStyleNotQuery,
-        f: &mut CssFormatter,
-    ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssContainerStyleNotQuery>::fmt(self, node, f)
+    fn fmt(&self, node: &biome_css_syntax::ScssIfAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssIfAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleNotQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssIfAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssContainerStyleNotQuery,
-        crate::css::auxiliary::container_style_not_query::FormatCssContainerStyleNotQuery,
+        biome_css_syntax::ScssIfAtRule,
+        crate::scss::statements::if_at_rule::FormatScssIfAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_style_not_query :: FormatCssContainerStyleNotQuery :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::if_at_rule::FormatScssIfAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleNotQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssIfAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssContainerStyleNotQuery,
-        crate::css::auxiliary::container_style_not_query::FormatCssContainerStyleNotQuery,
+        biome_css_syntax::ScssIfAtRule,
+        crate::scss::statements::if_at_rule::FormatScssIfAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_not_query :: FormatCssContainerStyleNotQuery :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::if_at_rule::FormatScssIfAtRule::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssContainerStyleAndQuery>
-    for crate::css::auxiliary::container_style_and_query::FormatCssContainerStyleAndQuery
+impl FormatRule<biome_css_syntax::ScssEachAtRule>
+    for crate::scss::statements::each_at_rule::FormatScssEachAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssContainerStyleAndQuery,
+        node: &biome_css_syntax::ScssEachAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssContainerStyleAndQuery>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::ScssEachAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleAndQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssEachAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssContainerStyleAndQuery,
-        crate::css::auxiliary::container_style_and_query::FormatCssContainerStyleAndQuery,
+        biome_css_syntax::ScssEachAtRule,
+        crate::scss::statements::each_at_rule::FormatScssEachAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_style_and_query :: FormatCssContainerStyleAndQuery :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::each_at_rule::FormatScssEachAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleAndQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssEachAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssContainerStyleAndQuery,
-        crate::css::auxiliary::container_style_and_query::FormatCssContainerStyleAndQuery,
+        biome_css_syntax::ScssEachAtRule,
+        crate::scss::statements::each_at_rule::FormatScssEachAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_and_query :: FormatCssContainerStyleAndQuery :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::each_at_rule::FormatScssEachAtRule::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssContainerStyleOrQuery>
-    for crate::css::auxiliary::container_style_or_query::FormatCssContainerStyleOrQuery
+impl FormatRule<biome_css_syntax::ScssForAtRule>
+    for crate::scss::statements::for_at_rule::FormatScssForAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssContainerStyleOrQuery,
+        node: &biome_css_syntax::ScssForAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssContainerStyleOrQuery>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::ScssForAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleOrQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssForAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssContainerStyleOrQuery,
-        crate::css::auxiliary::container_style_or_query::FormatCssContainerStyleOrQuery,
+        biome_css_syntax::ScssForAtRule,
+        crate::scss::statements::for_at_rule::FormatScssForAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_style_or_query :: FormatCssContainerStyleOrQuery :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::for_at_rule::FormatScssForAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleOrQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssForAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssContainerStyleOrQuery,
-        crate::css::auxiliary::container_style_or_query::FormatCssContainerStyleOrQuery,
+        biome_css_syntax::ScssForAtRule,
+        crate::scss::statements::for_at_rule::FormatScssForAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_or_query :: FormatCssContainerStyleOrQuery :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::for_at_rule::FormatScssForAtRule::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssContainerStyleInParens>
-    for crate::css::auxiliary::container_style_in_parens::FormatCssContainerStyleInParens
+impl FormatRule<biome_css_syntax::ScssWhileAtRule>
+    for crate::scss::statements::while_at_rule::FormatScssWhileAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssContainerStyleInParens,
+        node: &biome_css_syntax::ScssWhileAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssContainerStyleInParens>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::ScssWhileAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleInParens {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssWhileAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssContainerStyleInParens,
-        crate::css::auxiliary::container_style_in_parens::FormatCssContainerStyleInParens,
+        biome_css_syntax::ScssWhileAtRule,
+        crate::scss::statements::while_at_rule::FormatScssWhileAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_style_in_parens :: FormatCssContainerStyleInParens :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::while_at_rule::FormatScssWhileAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleInParens {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssWhileAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssContainerStyleInParens,
-        crate::css::auxiliary::container_style_in_parens::FormatCssContainerStyleInParens,
+        biome_css_syntax::ScssWhileAtRule,
+        crate::scss::statements::while_at_rule::FormatScssWhileAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_in_parens :: FormatCssContainerStyleInParens :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::while_at_rule::FormatScssWhileAtRule::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesBlock>
-    for crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock
+impl FormatRule<biome_css_syntax::LessPluginAtRule>
+    for crate::less::statements::plugin_at_rule::FormatLessPluginAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssKeyframesBlock,
+        node: &biome_css_syntax::LessPluginAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesBlock>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::LessPluginAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesBlock {
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessPluginAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesBlock,
-        crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock,
+        biome_css_syntax::LessPluginAtRule,
+        crate::less::statements::plugin_at_rule::FormatLessPluginAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock::default(),
+            crate::less::statements::plugin_at_rule::FormatLessPluginAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesBlock {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessPluginAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesBlock,
-        crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock,
+        biome_css_syntax::LessPluginAtRule,
+        crate::less::statements::plugin_at_rule::FormatLessPluginAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock::default(),
+            crate::less::statements::plugin_at_rule::FormatLessPluginAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesItem>
-    for crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem
+impl FormatRule<biome_css_syntax::LessImportAtRule>
+    for crate::less::statements::import_at_rule::FormatLessImportAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssKeyframesItem,
+        node: &biome_css_syntax::LessImportAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesItem>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::LessImportAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItem {
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessImportAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesItem,
-        crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem,
+        biome_css_syntax::LessImportAtRule,
+        crate::less::statements::import_at_rule::FormatLessImportAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem::default(),
+            crate::less::statements::import_at_rule::FormatLessImportAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItem {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessImportAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesItem,
-        crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem,
+        biome_css_syntax::LessImportAtRule,
+        crate::less::statements::import_at_rule::FormatLessImportAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem::default(),
+            crate::less::statements::import_at_rule::FormatLessImportAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesIdentSelector>
-    for crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector
+impl FormatRule<biome_css_syntax::CssFontFeatureValuesBlock>
+    for crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssKeyframesIdentSelector,
+        node: &biome_css_syntax::CssFontFeatureValuesBlock,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesIdentSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssFontFeatureValuesBlock>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesIdentSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesBlock {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesIdentSelector,
-        crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector,
+        biome_css_syntax::CssFontFeatureValuesBlock,
+        crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: selectors :: keyframes_ident_selector :: FormatCssKeyframesIdentSelector :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_block :: FormatCssFontFeatureValuesBlock :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesIdentSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesBlock {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesIdentSelector,
-        crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector,
+        biome_css_syntax::CssFontFeatureValuesBlock,
+        crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: keyframes_ident_selector :: FormatCssKeyframesIdentSelector :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_block :: FormatCssFontFeatureValuesBlock :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesPercentageSelector>
-    for crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector
+impl FormatRule<biome_css_syntax::CssFontFeatureValuesItem>
+    for crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssKeyframesPercentageSelector,
+        node: &biome_css_syntax::CssFontFeatureValuesItem,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesPercentageSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssFontFeatureValuesItem>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesPercentageSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItem {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesPercentageSelector,
-        crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector,
+        biome_css_syntax::CssFontFeatureValuesItem,
+        crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: selectors :: keyframes_percentage_selector :: FormatCssKeyframesPercentageSelector :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_item :: FormatCssFontFeatureValuesItem :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesPercentageSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItem {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesPercentageSelector,
-        crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector,
+        biome_css_syntax::CssFontFeatureValuesItem,
+        crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: keyframes_percentage_selector :: FormatCssKeyframesPercentageSelector :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_item :: FormatCssFontFeatureValuesItem :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssPercentage>
-    for crate::css::value::percentage::FormatCssPercentage
+impl FormatRule<biome_css_syntax::CssContainerNotQuery>
+    for crate::css::auxiliary::container_not_query::FormatCssContainerNotQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPercentage,
+        node: &biome_css_syntax::CssContainerNotQuery,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPercentage>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssContainerNotQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPercentage {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerNotQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPercentage,
-        crate::css::value::percentage::FormatCssPercentage,
+        biome_css_syntax::CssContainerNotQuery,
+        crate::css::auxiliary::container_not_query::FormatCssContainerNotQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::value::percentage::FormatCssPercentage::default(),
+            crate::css::auxiliary::container_not_query::FormatCssContainerNotQuery::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPercentage {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerNotQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPercentage,
-        crate::css::value::percentage::FormatCssPercentage,
+        biome_css_syntax::CssContainerNotQuery,
+        crate::css::auxiliary::container_not_query::FormatCssContainerNotQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::value::percentage::FormatCssPercentage::default(),
+            crate::css::auxiliary::container_not_query::FormatCssContainerNotQuery::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaConditionQuery>
-    for crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery
+impl FormatRule<biome_css_syntax::CssContainerOrQuery>
+    for crate::css::auxiliary::container_or_query::FormatCssContainerOrQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaConditionQuery,
+        node: &biome_css_syntax::CssContainerOrQuery,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaConditionQuery>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssContainerOrQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerOrQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaConditionQuery,
-        crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery,
+        biome_css_syntax::CssContainerOrQuery,
+        crate::css::auxiliary::container_or_query::FormatCssContainerOrQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery::default(),
+            crate::css::auxiliary::container_or_query::FormatCssContainerOrQuery::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerOrQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaConditionQuery,
-        crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery,
+        biome_css_syntax::CssContainerOrQuery,
+        crate::css::auxiliary::container_or_query::FormatCssContainerOrQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery::default(),
+            crate::css::auxiliary::container_or_query::FormatCssContainerOrQuery::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaAndTypeQuery>
-    for crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery
+impl FormatRule<biome_css_syntax::CssContainerAndQuery>
+    for crate::css::auxiliary::container_and_query::FormatCssContainerAndQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaAndTypeQuery,
+        node: &biome_css_syntax::CssContainerAndQuery,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaAndTypeQuery>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssContainerAndQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaAndTypeQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerAndQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaAndTypeQuery,
-        crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery,
+        biome_css_syntax::CssContainerAndQuery,
+        crate::css::auxiliary::container_and_query::FormatCssContainerAndQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery::default(),
+            crate::css::auxiliary::container_and_query::FormatCssContainerAndQuery::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaAndTypeQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerAndQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaAndTypeQuery,
-        crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery,
+        biome_css_syntax::CssContainerAndQuery,
+        crate::css::auxiliary::container_and_query::FormatCssContainerAndQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery::default(),
+            crate::css::auxiliary::container_and_query::FormatCssContainerAndQuery::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaTypeQuery>
-    for crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery
+impl FormatRule<biome_css_syntax::CssContainerQueryInParens>
+    for crate::css::auxiliary::container_query_in_parens::FormatCssContainerQueryInParens
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaTypeQuery,
+        node: &biome_css_syntax::CssContainerQueryInParens,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaTypeQuery>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssContainerQueryInParens>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaTypeQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerQueryInParens {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaTypeQuery,
-        crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery,
+        biome_css_syntax::CssContainerQueryInParens,
+        crate::css::auxiliary::container_query_in_parens::FormatCssContainerQueryInParens,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_query_in_parens :: FormatCssContainerQueryInParens :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaTypeQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerQueryInParens {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaTypeQuery,
-        crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery,
+        biome_css_syntax::CssContainerQueryInParens,
+        crate::css::auxiliary::container_query_in_parens::FormatCssContainerQueryInParens,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_query_in_parens :: FormatCssContainerQueryInParens :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaType>
-    for crate::css::auxiliary::media_type::FormatCssMediaType
+impl FormatRule < biome_css_syntax :: CssContainerSizeFeatureInParens > for crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssContainerSizeFeatureInParens , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssContainerSizeFeatureInParens > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerSizeFeatureInParens {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssContainerSizeFeatureInParens , crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerSizeFeatureInParens {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssContainerSizeFeatureInParens , crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssContainerStyleQueryInParens>
+    for crate::css::auxiliary::container_style_query_in_parens::FormatCssContainerStyleQueryInParens
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssMediaType, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaType>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssContainerStyleQueryInParens,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssContainerStyleQueryInParens>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaType {
-    type Format<'a> = FormatRefWithRule<
-        'a,
-        biome_css_syntax::CssMediaType,
-        crate::css::auxiliary::media_type::FormatCssMediaType,
-    >;
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleQueryInParens {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssContainerStyleQueryInParens , crate :: css :: auxiliary :: container_style_query_in_parens :: FormatCssContainerStyleQueryInParens > ;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::media_type::FormatCssMediaType::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_style_query_in_parens :: FormatCssContainerStyleQueryInParens :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaType {
-    type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaType,
-        crate::css::auxiliary::media_type::FormatCssMediaType,
-    >;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleQueryInParens {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssContainerStyleQueryInParens , crate :: css :: auxiliary :: container_style_query_in_parens :: FormatCssContainerStyleQueryInParens > ;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::media_type::FormatCssMediaType::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_query_in_parens :: FormatCssContainerStyleQueryInParens :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaNotCondition>
-    for crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition
+impl FormatRule<biome_css_syntax::CssContainerStyleNotQuery>
+    for crate::css::auxiliary::container_style_not_query::FormatCssContainerStyleNotQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaNotCondition,
+        node: &biome_css_syntax::CssContainerStyleNotQuery,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaNotCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssContainerStyleNotQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaNotCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleNotQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaNotCondition,
-        crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition,
+        biome_css_syntax::CssContainerStyleNotQuery,
+        crate::css::auxiliary::container_style_not_query::FormatCssContainerStyleNotQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_style_not_query :: FormatCssContainerStyleNotQuery :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaNotCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleNotQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaNotCondition,
-        crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition,
+        biome_css_syntax::CssContainerStyleNotQuery,
+        crate::css::auxiliary::container_style_not_query::FormatCssContainerStyleNotQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_not_query :: FormatCssContainerStyleNotQuery :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaAndCondition>
-    for crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition
+impl FormatRule<biome_css_syntax::CssContainerStyleAndQuery>
+    for crate::css::auxiliary::container_style_and_query::FormatCssContainerStyleAndQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaAndCondition,
+        node: &biome_css_syntax::CssContainerStyleAndQuery,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaAndCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssContainerStyleAndQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaAndCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleAndQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaAndCondition,
-        crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition,
+        biome_css_syntax::CssContainerStyleAndQuery,
+        crate::css::auxiliary::container_style_and_query::FormatCssContainerStyleAndQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_style_and_query :: FormatCssContainerStyleAndQuery :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaAndCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleAndQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaAndCondition,
-        crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition,
+        biome_css_syntax::CssContainerStyleAndQuery,
+        crate::css::auxiliary::container_style_and_query::FormatCssContainerStyleAndQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_and_query :: FormatCssContainerStyleAndQuery :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaOrCondition>
-    for crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition
+impl FormatRule<biome_css_syntax::CssContainerStyleOrQuery>
+    for crate::css::auxiliary::container_style_or_query::FormatCssContainerStyleOrQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaOrCondition,
+        node: &biome_css_syntax::CssContainerStyleOrQuery,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaOrCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssContainerStyleOrQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaOrCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleOrQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaOrCondition,
-        crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition,
+        biome_css_syntax::CssContainerStyleOrQuery,
+        crate::css::auxiliary::container_style_or_query::FormatCssContainerStyleOrQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_style_or_query :: FormatCssContainerStyleOrQuery :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaOrCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleOrQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaOrCondition,
-        crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition,
+        biome_css_syntax::CssContainerStyleOrQuery,
+        crate::css::auxiliary::container_style_or_query::FormatCssContainerStyleOrQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_or_query :: FormatCssContainerStyleOrQuery :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaConditionInParens>
-    for crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens
+impl FormatRule<biome_css_syntax::CssContainerStyleInParens>
+    for crate::css::auxiliary::container_style_in_parens::FormatCssContainerStyleInParens
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaConditionInParens,
+        node: &biome_css_syntax::CssContainerStyleInParens,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaConditionInParens>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssContainerStyleInParens>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionInParens {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleInParens {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaConditionInParens,
-        crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens,
+        biome_css_syntax::CssContainerStyleInParens,
+        crate::css::auxiliary::container_style_in_parens::FormatCssContainerStyleInParens,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: media_condition_in_parens :: FormatCssMediaConditionInParens :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_style_in_parens :: FormatCssContainerStyleInParens :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionInParens {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleInParens {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaConditionInParens,
-        crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens,
+        biome_css_syntax::CssContainerStyleInParens,
+        crate::css::auxiliary::container_style_in_parens::FormatCssContainerStyleInParens,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: media_condition_in_parens :: FormatCssMediaConditionInParens :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_in_parens :: FormatCssContainerStyleInParens :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaFeatureInParens>
-    for crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens
+impl FormatRule<biome_css_syntax::CssKeyframesBlock>
+    for crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaFeatureInParens,
+        node: &biome_css_syntax::CssKeyframesBlock,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaFeatureInParens>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesBlock>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaFeatureInParens {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesBlock {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaFeatureInParens,
-        crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens,
+        biome_css_syntax::CssKeyframesBlock,
+        crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens::default(
-            ),
+            crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaFeatureInParens {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesBlock {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaFeatureInParens,
-        crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens,
+        biome_css_syntax::CssKeyframesBlock,
+        crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens::default(
-            ),
+            crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeaturePlain>
-    for crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain
+impl FormatRule<biome_css_syntax::CssKeyframesItem>
+    for crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssQueryFeaturePlain,
+        node: &biome_css_syntax::CssKeyframesItem,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeaturePlain>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesItem>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeaturePlain {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItem {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeaturePlain,
-        crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain,
+        biome_css_syntax::CssKeyframesItem,
+        crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain::default(),
+            crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeaturePlain {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItem {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeaturePlain,
-        crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain,
+        biome_css_syntax::CssKeyframesItem,
+        crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain::default(),
+            crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeatureBoolean>
-    for crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean
+impl FormatRule<biome_css_syntax::CssKeyframesIdentSelector>
+    for crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssQueryFeatureBoolean,
+        node: &biome_css_syntax::CssKeyframesIdentSelector,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeatureBoolean>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesIdentSelector>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureBoolean {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesIdentSelector {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeatureBoolean,
-        crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean,
+        biome_css_syntax::CssKeyframesIdentSelector,
+        crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: keyframes_ident_selector :: FormatCssKeyframesIdentSelector :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureBoolean {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesIdentSelector {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeatureBoolean,
-        crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean,
+        biome_css_syntax::CssKeyframesIdentSelector,
+        crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: keyframes_ident_selector :: FormatCssKeyframesIdentSelector :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeatureRange>
-    for crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange
+impl FormatRule<biome_css_syntax::CssKeyframesPercentageSelector>
+    for crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssQueryFeatureRange,
+        node: &biome_css_syntax::CssKeyframesPercentageSelector,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRange>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesPercentageSelector>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRange {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesPercentageSelector {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeatureRange,
-        crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange,
+        biome_css_syntax::CssKeyframesPercentageSelector,
+        crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: keyframes_percentage_selector :: FormatCssKeyframesPercentageSelector :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRange {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesPercentageSelector {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeatureRange,
-        crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange,
+        biome_css_syntax::CssKeyframesPercentageSelector,
+        crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: keyframes_percentage_selector :: FormatCssKeyframesPercentageSelector :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeatureReverseRange>
-    for crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange
+impl FormatRule<biome_css_syntax::CssPercentage>
+    for crate::css::value::percentage::FormatCssPercentage
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssQueryFeatureReverseRange,
+        node: &biome_css_syntax::CssPercentage,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeatureReverseRange>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssPercentage>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureReverseRange {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPercentage {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeatureReverseRange,
-        crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange,
+        biome_css_syntax::CssPercentage,
+        crate::css::value::percentage::FormatCssPercentage,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_reverse_range :: FormatCssQueryFeatureReverseRange :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::percentage::FormatCssPercentage::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureReverseRange {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPercentage {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeatureReverseRange,
-        crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange,
+        biome_css_syntax::CssPercentage,
+        crate::css::value::percentage::FormatCssPercentage,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_reverse_range :: FormatCssQueryFeatureReverseRange :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::percentage::FormatCssPercentage::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeatureRangeInterval>
-    for crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval
+impl FormatRule<biome_css_syntax::CssMediaConditionQuery>
+    for crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssQueryFeatureRangeInterval,
+        node: &biome_css_syntax::CssMediaConditionQuery,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRangeInterval>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaConditionQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeInterval {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeatureRangeInterval,
-        crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval,
+        biome_css_syntax::CssMediaConditionQuery,
+        crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_interval :: FormatCssQueryFeatureRangeInterval :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeInterval {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeatureRangeInterval,
-        crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval,
+        biome_css_syntax::CssMediaConditionQuery,
+        crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_interval :: FormatCssQueryFeatureRangeInterval :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeatureRangeComparison>
-    for crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison
+impl FormatRule<biome_css_syntax::CssMediaAndTypeQuery>
+    for crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssQueryFeatureRangeComparison,
+        node: &biome_css_syntax::CssMediaAndTypeQuery,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRangeComparison>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaAndTypeQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeComparison {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaAndTypeQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeatureRangeComparison,
-        crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison,
+        biome_css_syntax::CssMediaAndTypeQuery,
+        crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_comparison :: FormatCssQueryFeatureRangeComparison :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeComparison {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaAndTypeQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeatureRangeComparison,
-        crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison,
+        biome_css_syntax::CssMediaAndTypeQuery,
+        crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_comparison :: FormatCssQueryFeatureRangeComparison :: default ())
-    }
-}
-impl FormatRule<biome_css_syntax::CssRatio> for crate::css::value::ratio::FormatCssRatio {
-    type Context = CssFormatContext;
-    #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssRatio, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssRatio>::fmt(self, node, f)
-    }
-}
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssRatio {
-    type Format<'a> =
-        FormatRefWithRule<'a, biome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
-    fn format(&self) -> Self::Format<'_> {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
-    }
-}
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRatio {
-    type Format =
-        FormatOwnedWithRule<biome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
-    fn into_format(self) -> Self::Format {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssPageSelector>
-    for crate::css::selectors::page_selector::FormatCssPageSelector
+impl FormatRule<biome_css_syntax::CssMediaTypeQuery>
+    for crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPageSelector,
+        node: &biome_css_syntax::CssMediaTypeQuery,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPageSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaTypeQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaTypeQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPageSelector,
-        crate::css::selectors::page_selector::FormatCssPageSelector,
+        biome_css_syntax::CssMediaTypeQuery,
+        crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::selectors::page_selector::FormatCssPageSelector::default(),
+            crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaTypeQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPageSelector,
-        crate::css::selectors::page_selector::FormatCssPageSelector,
+        biome_css_syntax::CssMediaTypeQuery,
+        crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::selectors::page_selector::FormatCssPageSelector::default(),
+            crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssPageSelectorPseudo>
-    for crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo
+impl FormatRule<biome_css_syntax::CssMediaType>
+    for crate::css::auxiliary::media_type::FormatCssMediaType
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(
-        &self,
-        node: &biome_css_syntax::CssPageSelectorPseudo,
-        f: &mut CssFormatter,
-    ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPageSelectorPseudo>::fmt(self, node, f)
+    fn fmt(&self, node: &biome_css_syntax::CssMediaType, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssMediaType>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudo {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaType {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPageSelectorPseudo,
-        crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo,
+        biome_css_syntax::CssMediaType,
+        crate::css::auxiliary::media_type::FormatCssMediaType,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo::default(),
+            crate::css::auxiliary::media_type::FormatCssMediaType::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudo {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaType {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPageSelectorPseudo,
-        crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo,
+        biome_css_syntax::CssMediaType,
+        crate::css::auxiliary::media_type::FormatCssMediaType,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo::default(),
+            crate::css::auxiliary::media_type::FormatCssMediaType::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssPageAtRuleBlock>
-    for crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock
+impl FormatRule<biome_css_syntax::CssMediaNotCondition>
+    for crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPageAtRuleBlock,
+        node: &biome_css_syntax::CssMediaNotCondition,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPageAtRuleBlock>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaNotCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleBlock {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaNotCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPageAtRuleBlock,
-        crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock,
+        biome_css_syntax::CssMediaNotCondition,
+        crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock::default(),
+            crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleBlock {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaNotCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPageAtRuleBlock,
-        crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock,
+        biome_css_syntax::CssMediaNotCondition,
+        crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock::default(),
+            crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMarginAtRule>
-    for crate::css::statements::margin_at_rule::FormatCssMarginAtRule
+impl FormatRule<biome_css_syntax::CssMediaAndCondition>
+    for crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMarginAtRule,
+        node: &biome_css_syntax::CssMediaAndCondition,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMarginAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaAndCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMarginAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaAndCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMarginAtRule,
-        crate::css::statements::margin_at_rule::FormatCssMarginAtRule,
+        biome_css_syntax::CssMediaAndCondition,
+        crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::margin_at_rule::FormatCssMarginAtRule::default(),
+            crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMarginAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaAndCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMarginAtRule,
-        crate::css::statements::margin_at_rule::FormatCssMarginAtRule,
+        biome_css_syntax::CssMediaAndCondition,
+        crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::margin_at_rule::FormatCssMarginAtRule::default(),
+            crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssLayerDeclaration>
-    for crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration
+impl FormatRule<biome_css_syntax::CssMediaOrCondition>
+    for crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssLayerDeclaration,
+        node: &biome_css_syntax::CssMediaOrCondition,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssLayerDeclaration>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaOrCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerDeclaration {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaOrCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssLayerDeclaration,
-        crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration,
+        biome_css_syntax::CssMediaOrCondition,
+        crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration::default(),
+            crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerDeclaration {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaOrCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssLayerDeclaration,
-        crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration,
+        biome_css_syntax::CssMediaOrCondition,
+        crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration::default(),
+            crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssLayerReference>
-    for crate::css::auxiliary::layer_reference::FormatCssLayerReference
+impl FormatRule<biome_css_syntax::CssMediaConditionInParens>
+    for crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssLayerReference,
+        node: &biome_css_syntax::CssMediaConditionInParens,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssLayerReference>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaConditionInParens>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerReference {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionInParens {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssLayerReference,
-        crate::css::auxiliary::layer_reference::FormatCssLayerReference,
+        biome_css_syntax::CssMediaConditionInParens,
+        crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::layer_reference::FormatCssLayerReference::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: media_condition_in_parens :: FormatCssMediaConditionInParens :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerReference {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionInParens {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssLayerReference,
-        crate::css::auxiliary::layer_reference::FormatCssLayerReference,
+        biome_css_syntax::CssMediaConditionInParens,
+        crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::layer_reference::FormatCssLayerReference::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: media_condition_in_parens :: FormatCssMediaConditionInParens :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsNotCondition>
-    for crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition
+impl FormatRule<biome_css_syntax::CssMediaFeatureInParens>
+    for crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsNotCondition,
+        node: &biome_css_syntax::CssMediaFeatureInParens,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsNotCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaFeatureInParens>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsNotCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaFeatureInParens {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsNotCondition,
-        crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition,
+        biome_css_syntax::CssMediaFeatureInParens,
+        crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition::default(),
+            crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens::default(
+            ),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsNotCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaFeatureInParens {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsNotCondition,
-        crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition,
+        biome_css_syntax::CssMediaFeatureInParens,
+        crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition::default(),
+            crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens::default(
+            ),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsOrCondition>
-    for crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition
+impl FormatRule<biome_css_syntax::CssQueryFeaturePlain>
+    for crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsOrCondition,
+        node: &biome_css_syntax::CssQueryFeaturePlain,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsOrCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssQueryFeaturePlain>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsOrCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeaturePlain {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsOrCondition,
-        crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition,
+        biome_css_syntax::CssQueryFeaturePlain,
+        crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition::default(),
+            crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsOrCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeaturePlain {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsOrCondition,
-        crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition,
+        biome_css_syntax::CssQueryFeaturePlain,
+        crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition::default(),
+            crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsAndCondition>
-    for crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition
+impl FormatRule<biome_css_syntax::CssQueryFeatureBoolean>
+    for crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsAndCondition,
+        node: &biome_css_syntax::CssQueryFeatureBoolean,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsAndCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssQueryFeatureBoolean>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsAndCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureBoolean {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsAndCondition,
-        crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition,
+        biome_css_syntax::CssQueryFeatureBoolean,
+        crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition::default(),
+            crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsAndCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureBoolean {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsAndCondition,
-        crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition,
+        biome_css_syntax::CssQueryFeatureBoolean,
+        crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition::default(),
+            crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsConditionInParens>
-    for crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens
+impl FormatRule<biome_css_syntax::CssQueryFeatureRange>
+    for crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsConditionInParens,
+        node: &biome_css_syntax::CssQueryFeatureRange,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsConditionInParens>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRange>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsConditionInParens {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRange {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsConditionInParens,
-        crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens,
+        biome_css_syntax::CssQueryFeatureRange,
+        crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: supports_condition_in_parens :: FormatCssSupportsConditionInParens :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsConditionInParens {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRange {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsConditionInParens,
-        crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens,
+        biome_css_syntax::CssQueryFeatureRange,
+        crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: supports_condition_in_parens :: FormatCssSupportsConditionInParens :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsFeatureDeclaration>
-    for crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration
+impl FormatRule<biome_css_syntax::CssQueryFeatureReverseRange>
+    for crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsFeatureDeclaration,
+        node: &biome_css_syntax::CssQueryFeatureReverseRange,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsFeatureDeclaration>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssQueryFeatureReverseRange>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureDeclaration {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureReverseRange {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsFeatureDeclaration,
-        crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration,
+        biome_css_syntax::CssQueryFeatureReverseRange,
+        crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_declaration :: FormatCssSupportsFeatureDeclaration :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_reverse_range :: FormatCssQueryFeatureReverseRange :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureDeclaration {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureReverseRange {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsFeatureDeclaration,
-        crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration,
+        biome_css_syntax::CssQueryFeatureReverseRange,
+        crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_declaration :: FormatCssSupportsFeatureDeclaration :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_reverse_range :: FormatCssQueryFeatureReverseRange :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsFeatureSelector>
-    for crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector
+impl FormatRule<biome_css_syntax::CssQueryFeatureRangeInterval>
+    for crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsFeatureSelector,
+        node: &biome_css_syntax::CssQueryFeatureRangeInterval,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsFeatureSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRangeInterval>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeInterval {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsFeatureSelector,
-        crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector,
+        biome_css_syntax::CssQueryFeatureRangeInterval,
+        crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: selectors :: supports_feature_selector :: FormatCssSupportsFeatureSelector :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_interval :: FormatCssQueryFeatureRangeInterval :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeInterval {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsFeatureSelector,
-        crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector,
+        biome_css_syntax::CssQueryFeatureRangeInterval,
+        crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: supports_feature_selector :: FormatCssSupportsFeatureSelector :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_interval :: FormatCssQueryFeatureRangeInterval :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssFunction>
-    for crate::css::auxiliary::function::FormatCssFunction
+impl FormatRule<biome_css_syntax::CssQueryFeatureRangeComparison>
+    for crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssFunction, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssFunction>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssQueryFeatureRangeComparison,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRangeComparison>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssFunction {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeComparison {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssFunction,
-        crate::css::auxiliary::function::FormatCssFunction,
+        biome_css_syntax::CssQueryFeatureRangeComparison,
+        crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::function::FormatCssFunction::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_comparison :: FormatCssQueryFeatureRangeComparison :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFunction {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeComparison {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssFunction,
-        crate::css::auxiliary::function::FormatCssFunction,
+        biome_css_syntax::CssQueryFeatureRangeComparison,
+        crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::function::FormatCssFunction::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_comparison :: FormatCssQueryFeatureRangeComparison :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssScopeRangeStart>
-    for crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart
-{
+impl FormatRule<biome_css_syntax::CssRatio> for crate::css::value::ratio::FormatCssRatio {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(
-        &self,
-        node: &biome_css_syntax::CssScopeRangeStart,
+    fn fmt(&self, node: &biome_css_syntax::CssRatio, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssRatio>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRatio {
+    type Format<'a> =
+        FormatRefWithRule<'a, biome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRatio {
+    type Format =
+        FormatOwnedWithRule<biome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
+    }
+}
+impl FormatRule<biome_css_syntax::CssPageSelector>
+    for crate::css::selectors::page_selector::FormatCssPageSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPageSelector,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssScopeRangeStart>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssPageSelector>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeStart {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelector {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssScopeRangeStart,
-        crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart,
+        biome_css_syntax::CssPageSelector,
+        crate::css::selectors::page_selector::FormatCssPageSelector,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart::default(),
+            crate::css::selectors::page_selector::FormatCssPageSelector::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeStart {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelector {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssScopeRangeStart,
-        crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart,
+        biome_css_syntax::CssPageSelector,
+        crate::css::selectors::page_selector::FormatCssPageSelector,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart::default(),
+            crate::css::selectors::page_selector::FormatCssPageSelector::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssScopeRangeEnd>
-    for crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd
+impl FormatRule<biome_css_syntax::CssPageSelectorPseudo>
+    for crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssScopeRangeEnd,
+        node: &biome_css_syntax::CssPageSelectorPseudo,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssScopeRangeEnd>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssPageSelectorPseudo>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeEnd {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudo {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssScopeRangeEnd,
-        crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd,
+        biome_css_syntax::CssPageSelectorPseudo,
+        crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd::default(),
+            crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeEnd {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudo {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssScopeRangeEnd,
-        crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd,
+        biome_css_syntax::CssPageSelectorPseudo,
+        crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd::default(),
+            crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssScopeRangeInterval>
-    for crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval
+impl FormatRule<biome_css_syntax::CssPageAtRuleBlock>
+    for crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssScopeRangeInterval,
+        node: &biome_css_syntax::CssPageAtRuleBlock,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssScopeRangeInterval>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssPageAtRuleBlock>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeInterval {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleBlock {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssScopeRangeInterval,
-        crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval,
+        biome_css_syntax::CssPageAtRuleBlock,
+        crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval::default(),
+            crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeInterval {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleBlock {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssScopeRangeInterval,
-        crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval,
+        biome_css_syntax::CssPageAtRuleBlock,
+        crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval::default(),
+            crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssScopeEdge>
-    for crate::css::auxiliary::scope_edge::FormatCssScopeEdge
+impl FormatRule<biome_css_syntax::CssMarginAtRule>
+    for crate::css::statements::margin_at_rule::FormatCssMarginAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssScopeEdge, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssScopeEdge>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssMarginAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssMarginAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeEdge {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMarginAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssScopeEdge,
-        crate::css::auxiliary::scope_edge::FormatCssScopeEdge,
+        biome_css_syntax::CssMarginAtRule,
+        crate::css::statements::margin_at_rule::FormatCssMarginAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::scope_edge::FormatCssScopeEdge::default(),
+            crate::css::statements::margin_at_rule::FormatCssMarginAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeEdge {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMarginAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssScopeEdge,
-        crate::css::auxiliary::scope_edge::FormatCssScopeEdge,
+        biome_css_syntax::CssMarginAtRule,
+        crate::css::statements::margin_at_rule::FormatCssMarginAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::scope_edge::FormatCssScopeEdge::default(),
+            crate::css::statements::margin_at_rule::FormatCssMarginAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssImportSupports>
-    for crate::css::auxiliary::import_supports::FormatCssImportSupports
+impl FormatRule<biome_css_syntax::CssLayerDeclaration>
+    for crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssImportSupports,
+        node: &biome_css_syntax::CssLayerDeclaration,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssImportSupports>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssLayerDeclaration>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportSupports {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerDeclaration {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssImportSupports,
-        crate::css::auxiliary::import_supports::FormatCssImportSupports,
+        biome_css_syntax::CssLayerDeclaration,
+        crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::import_supports::FormatCssImportSupports::default(),
+            crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportSupports {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerDeclaration {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssImportSupports,
-        crate::css::auxiliary::import_supports::FormatCssImportSupports,
+        biome_css_syntax::CssLayerDeclaration,
+        crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::import_supports::FormatCssImportSupports::default(),
+            crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssUrlFunction>
-    for crate::css::auxiliary::url_function::FormatCssUrlFunction
+impl FormatRule<biome_css_syntax::CssLayerReference>
+    for crate::css::auxiliary::layer_reference::FormatCssLayerReference
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssUrlFunction,
+        node: &biome_css_syntax::CssLayerReference,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssUrlFunction>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssLayerReference>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssUrlFunction {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerReference {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssUrlFunction,
-        crate::css::auxiliary::url_function::FormatCssUrlFunction,
+        biome_css_syntax::CssLayerReference,
+        crate::css::auxiliary::layer_reference::FormatCssLayerReference,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::url_function::FormatCssUrlFunction::default(),
+            crate::css::auxiliary::layer_reference::FormatCssLayerReference::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUrlFunction {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerReference {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssUrlFunction,
-        crate::css::auxiliary::url_function::FormatCssUrlFunction,
+        biome_css_syntax::CssLayerReference,
+        crate::css::auxiliary::layer_reference::FormatCssLayerReference,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::url_function::FormatCssUrlFunction::default(),
+            crate::css::auxiliary::layer_reference::FormatCssLayerReference::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssImportAnonymousLayer>
-    for crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer
+impl FormatRule<biome_css_syntax::CssSupportsNotCondition>
+    for crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssImportAnonymousLayer,
+        node: &biome_css_syntax::CssSupportsNotCondition,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssImportAnonymousLayer>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssSupportsNotCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportAnonymousLayer {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsNotCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssImportAnonymousLayer,
-        crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer,
+        biome_css_syntax::CssSupportsNotCondition,
+        crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer::default(),
+            crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportAnonymousLayer {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsNotCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssImportAnonymousLayer,
-        crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer,
+        biome_css_syntax::CssSupportsNotCondition,
+        crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer::default(),
+            crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssImportNamedLayer>
-    for crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer
+impl FormatRule<biome_css_syntax::CssSupportsOrCondition>
+    for crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssImportNamedLayer,
+        node: &biome_css_syntax::CssSupportsOrCondition,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssImportNamedLayer>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssSupportsOrCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportNamedLayer {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsOrCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssImportNamedLayer,
-        crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer,
+        biome_css_syntax::CssSupportsOrCondition,
+        crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer::default(),
+            crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportNamedLayer {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsOrCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssImportNamedLayer,
-        crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer,
+        biome_css_syntax::CssSupportsOrCondition,
+        crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer::default(),
+            crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssDocumentCustomMatcher>
-    for crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher
+impl FormatRule<biome_css_syntax::CssSupportsAndCondition>
+    for crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssDocumentCustomMatcher,
+        node: &biome_css_syntax::CssSupportsAndCondition,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssDocumentCustomMatcher>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssSupportsAndCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDocumentCustomMatcher {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsAndCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDocumentCustomMatcher,
-        crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher,
+        biome_css_syntax::CssSupportsAndCondition,
+        crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher::default(
-            ),
+            crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDocumentCustomMatcher {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsAndCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDocumentCustomMatcher,
-        crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher,
+        biome_css_syntax::CssSupportsAndCondition,
+        crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher::default(
-            ),
+            crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssColor> for crate::css::value::color::FormatCssColor {
+impl FormatRule<biome_css_syntax::CssSupportsConditionInParens>
+    for crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens
+{
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssColor, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssColor>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssSupportsConditionInParens,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssSupportsConditionInParens>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssColor {
-    type Format<'a> =
-        FormatRefWithRule<'a, biome_css_syntax::CssColor, crate::css::value::color::FormatCssColor>;
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsConditionInParens {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssSupportsConditionInParens,
+        crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens,
+    >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(self, crate::css::value::color::FormatCssColor::default())
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: supports_condition_in_parens :: FormatCssSupportsConditionInParens :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssColor {
-    type Format =
-        FormatOwnedWithRule<biome_css_syntax::CssColor, crate::css::value::color::FormatCssColor>;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsConditionInParens {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssSupportsConditionInParens,
+        crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens,
+    >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(self, crate::css::value::color::FormatCssColor::default())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: supports_condition_in_parens :: FormatCssSupportsConditionInParens :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssRegularDimension>
-    for crate::css::value::regular_dimension::FormatCssRegularDimension
+impl FormatRule<biome_css_syntax::CssSupportsFeatureDeclaration>
+    for crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssRegularDimension,
+        node: &biome_css_syntax::CssSupportsFeatureDeclaration,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssRegularDimension>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssSupportsFeatureDeclaration>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssRegularDimension {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureDeclaration {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssRegularDimension,
-        crate::css::value::regular_dimension::FormatCssRegularDimension,
+        biome_css_syntax::CssSupportsFeatureDeclaration,
+        crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_declaration :: FormatCssSupportsFeatureDeclaration :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssSupportsFeatureDeclaration,
+        crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_declaration :: FormatCssSupportsFeatureDeclaration :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssSupportsFeatureSelector>
+    for crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssSupportsFeatureSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssSupportsFeatureSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssSupportsFeatureSelector,
+        crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: supports_feature_selector :: FormatCssSupportsFeatureSelector :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureSelector {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssSupportsFeatureSelector,
+        crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: supports_feature_selector :: FormatCssSupportsFeatureSelector :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssFunction>
+    for crate::css::auxiliary::function::FormatCssFunction
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::CssFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssFunction>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssFunction {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssFunction,
+        crate::css::auxiliary::function::FormatCssFunction,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::value::regular_dimension::FormatCssRegularDimension::default(),
+            crate::css::auxiliary::function::FormatCssFunction::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRegularDimension {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFunction {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssRegularDimension,
-        crate::css::value::regular_dimension::FormatCssRegularDimension,
+        biome_css_syntax::CssFunction,
+        crate::css::auxiliary::function::FormatCssFunction,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::value::regular_dimension::FormatCssRegularDimension::default(),
+            crate::css::auxiliary::function::FormatCssFunction::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssUnknownDimension>
-    for crate::css::value::unknown_dimension::FormatCssUnknownDimension
+impl FormatRule<biome_css_syntax::CssScopeRangeStart>
+    for crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssUnknownDimension,
+        node: &biome_css_syntax::CssScopeRangeStart,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssUnknownDimension>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssScopeRangeStart>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssUnknownDimension {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeStart {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssUnknownDimension,
-        crate::css::value::unknown_dimension::FormatCssUnknownDimension,
+        biome_css_syntax::CssScopeRangeStart,
+        crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::value::unknown_dimension::FormatCssUnknownDimension::default(),
+            crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUnknownDimension {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeStart {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssUnknownDimension,
-        crate::css::value::unknown_dimension::FormatCssUnknownDimension,
+        biome_css_syntax::CssScopeRangeStart,
+        crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::value::unknown_dimension::FormatCssUnknownDimension::default(),
+            crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssUrlValueRaw>
-    for crate::css::value::url_value_raw::FormatCssUrlValueRaw
+impl FormatRule<biome_css_syntax::CssScopeRangeEnd>
+    for crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssUrlValueRaw,
+        node: &biome_css_syntax::CssScopeRangeEnd,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssUrlValueRaw>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssScopeRangeEnd>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssUrlValueRaw {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeEnd {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssUrlValueRaw,
-        crate::css::value::url_value_raw::FormatCssUrlValueRaw,
+        biome_css_syntax::CssScopeRangeEnd,
+        crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::value::url_value_raw::FormatCssUrlValueRaw::default(),
+            crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUrlValueRaw {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeEnd {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssUrlValueRaw,
-        crate::css::value::url_value_raw::FormatCssUrlValueRaw,
+        biome_css_syntax::CssScopeRangeEnd,
+        crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::value::url_value_raw::FormatCssUrlValueRaw::default(),
+            crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssParameter>
-    for crate::css::auxiliary::parameter::FormatCssParameter
+impl FormatRule<biome_css_syntax::CssScopeRangeInterval>
+    for crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssParameter, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssParameter>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssScopeRangeInterval,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssScopeRangeInterval>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssParameter {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeInterval {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssParameter,
-        crate::css::auxiliary::parameter::FormatCssParameter,
+        biome_css_syntax::CssScopeRangeInterval,
+        crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::parameter::FormatCssParameter::default(),
+            crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssParameter {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeInterval {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssParameter,
-        crate::css::auxiliary::parameter::FormatCssParameter,
+        biome_css_syntax::CssScopeRangeInterval,
+        crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::parameter::FormatCssParameter::default(),
+            crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssBinaryExpression>
-    for crate::css::auxiliary::binary_expression::FormatCssBinaryExpression
+impl FormatRule<biome_css_syntax::CssScopeEdge>
+    for crate::css::auxiliary::scope_edge::FormatCssScopeEdge
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(
-        &self,
-        node: &biome_css_syntax::CssBinaryExpression,
-        f: &mut CssFormatter,
-    ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssBinaryExpression>::fmt(self, node, f)
+    fn fmt(&self, node: &biome_css_syntax::CssScopeEdge, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssScopeEdge>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssBinaryExpression {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeEdge {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssBinaryExpression,
-        crate::css::auxiliary::binary_expression::FormatCssBinaryExpression,
+        biome_css_syntax::CssScopeEdge,
+        crate::css::auxiliary::scope_edge::FormatCssScopeEdge,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::binary_expression::FormatCssBinaryExpression::default(),
+            crate::css::auxiliary::scope_edge::FormatCssScopeEdge::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssBinaryExpression {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeEdge {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssBinaryExpression,
-        crate::css::auxiliary::binary_expression::FormatCssBinaryExpression,
+        biome_css_syntax::CssScopeEdge,
+        crate::css::auxiliary::scope_edge::FormatCssScopeEdge,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::binary_expression::FormatCssBinaryExpression::default(),
+            crate::css::auxiliary::scope_edge::FormatCssScopeEdge::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssParenthesizedExpression>
-    for crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression
+impl FormatRule < biome_css_syntax :: CssValueAtRuleDeclarationClause > for crate :: css :: auxiliary :: value_at_rule_declaration_clause :: FormatCssValueAtRuleDeclarationClause { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssValueAtRuleDeclarationClause , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssValueAtRuleDeclarationClause > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleDeclarationClause {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssValueAtRuleDeclarationClause , crate :: css :: auxiliary :: value_at_rule_declaration_clause :: FormatCssValueAtRuleDeclarationClause > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: value_at_rule_declaration_clause :: FormatCssValueAtRuleDeclarationClause :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleDeclarationClause {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssValueAtRuleDeclarationClause , crate :: css :: auxiliary :: value_at_rule_declaration_clause :: FormatCssValueAtRuleDeclarationClause > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: value_at_rule_declaration_clause :: FormatCssValueAtRuleDeclarationClause :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssValueAtRuleImportClause>
+    for crate::css::auxiliary::value_at_rule_import_clause::FormatCssValueAtRuleImportClause
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssParenthesizedExpression,
+        node: &biome_css_syntax::CssValueAtRuleImportClause,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssParenthesizedExpression>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssValueAtRuleImportClause>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssParenthesizedExpression {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleImportClause {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssParenthesizedExpression,
-        crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression,
+        biome_css_syntax::CssValueAtRuleImportClause,
+        crate::css::auxiliary::value_at_rule_import_clause::FormatCssValueAtRuleImportClause,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: parenthesized_expression :: FormatCssParenthesizedExpression :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: value_at_rule_import_clause :: FormatCssValueAtRuleImportClause :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssParenthesizedExpression {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleImportClause {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssParenthesizedExpression,
-        crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression,
+        biome_css_syntax::CssValueAtRuleImportClause,
+        crate::css::auxiliary::value_at_rule_import_clause::FormatCssValueAtRuleImportClause,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: parenthesized_expression :: FormatCssParenthesizedExpression :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: value_at_rule_import_clause :: FormatCssValueAtRuleImportClause :: default ())
     }
 }
-impl FormatRule < biome_css_syntax :: CssListOfComponentValuesExpression > for crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssListOfComponentValuesExpression , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssListOfComponentValuesExpression > :: fmt (self , node , f) } }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssListOfComponentValuesExpression {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssListOfComponentValuesExpression , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression > ;
+impl FormatRule<biome_css_syntax::CssValueAtRuleImportSpecifier>
+    for crate::css::auxiliary::value_at_rule_import_specifier::FormatCssValueAtRuleImportSpecifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssValueAtRuleImportSpecifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssValueAtRuleImportSpecifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleImportSpecifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssValueAtRuleImportSpecifier,
+        crate::css::auxiliary::value_at_rule_import_specifier::FormatCssValueAtRuleImportSpecifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: value_at_rule_import_specifier :: FormatCssValueAtRuleImportSpecifier :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleImportSpecifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssValueAtRuleImportSpecifier,
+        crate::css::auxiliary::value_at_rule_import_specifier::FormatCssValueAtRuleImportSpecifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: value_at_rule_import_specifier :: FormatCssValueAtRuleImportSpecifier :: default ())
+    }
+}
+impl FormatRule < biome_css_syntax :: CssValueAtRuleNamedImportSpecifier > for crate :: css :: auxiliary :: value_at_rule_named_import_specifier :: FormatCssValueAtRuleNamedImportSpecifier { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssValueAtRuleNamedImportSpecifier , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssValueAtRuleNamedImportSpecifier > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleNamedImportSpecifier {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssValueAtRuleNamedImportSpecifier , crate :: css :: auxiliary :: value_at_rule_named_import_specifier :: FormatCssValueAtRuleNamedImportSpecifier > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: value_at_rule_named_import_specifier :: FormatCssValueAtRuleNamedImportSpecifier :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleNamedImportSpecifier {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssValueAtRuleNamedImportSpecifier , crate :: css :: auxiliary :: value_at_rule_named_import_specifier :: FormatCssValueAtRuleNamedImportSpecifier > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: value_at_rule_named_import_specifier :: FormatCssValueAtRuleNamedImportSpecifier :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssImportSupports>
+    for crate::css::auxiliary::import_supports::FormatCssImportSupports
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssImportSupports,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssImportSupports>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportSupports {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssImportSupports,
+        crate::css::auxiliary::import_supports::FormatCssImportSupports,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::import_supports::FormatCssImportSupports::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportSupports {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssImportSupports,
+        crate::css::auxiliary::import_supports::FormatCssImportSupports,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::import_supports::FormatCssImportSupports::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssUrlFunction>
+    for crate::css::auxiliary::url_function::FormatCssUrlFunction
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssUrlFunction,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssUrlFunction>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssUrlFunction {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssUrlFunction,
+        crate::css::auxiliary::url_function::FormatCssUrlFunction,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::url_function::FormatCssUrlFunction::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUrlFunction {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssUrlFunction,
+        crate::css::auxiliary::url_function::FormatCssUrlFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::url_function::FormatCssUrlFunction::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssImportAnonymousLayer>
+    for crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssImportAnonymousLayer,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssImportAnonymousLayer>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportAnonymousLayer {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssImportAnonymousLayer,
+        crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportAnonymousLayer {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssImportAnonymousLayer,
+        crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssImportNamedLayer>
+    for crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssImportNamedLayer,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssImportNamedLayer>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportNamedLayer {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssImportNamedLayer,
+        crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportNamedLayer {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssImportNamedLayer,
+        crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssDocumentCustomMatcher>
+    for crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssDocumentCustomMatcher,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssDocumentCustomMatcher>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDocumentCustomMatcher {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssDocumentCustomMatcher,
+        crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDocumentCustomMatcher {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssDocumentCustomMatcher,
+        crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher::default(
+            ),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssColor> for crate::css::value::color::FormatCssColor {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::CssColor, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssColor>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssColor {
+    type Format<'a> =
+        FormatRefWithRule<'a, biome_css_syntax::CssColor, crate::css::value::color::FormatCssColor>;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::css::value::color::FormatCssColor::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssColor {
+    type Format =
+        FormatOwnedWithRule<biome_css_syntax::CssColor, crate::css::value::color::FormatCssColor>;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::css::value::color::FormatCssColor::default())
+    }
+}
+impl FormatRule<biome_css_syntax::ScssIdentifier>
+    for crate::scss::value::identifier::FormatScssIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssIdentifier,
+        crate::scss::value::identifier::FormatScssIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::value::identifier::FormatScssIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssIdentifier,
+        crate::scss::value::identifier::FormatScssIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::value::identifier::FormatScssIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssQualifiedName>
+    for crate::scss::auxiliary::qualified_name::FormatScssQualifiedName
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssQualifiedName,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssQualifiedName>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssQualifiedName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssQualifiedName,
+        crate::scss::auxiliary::qualified_name::FormatScssQualifiedName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::qualified_name::FormatScssQualifiedName::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssQualifiedName {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssQualifiedName,
+        crate::scss::auxiliary::qualified_name::FormatScssQualifiedName,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::qualified_name::FormatScssQualifiedName::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssInterpolation>
+    for crate::scss::auxiliary::interpolation::FormatScssInterpolation
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssInterpolation,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssInterpolation>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssInterpolation {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssInterpolation,
+        crate::scss::auxiliary::interpolation::FormatScssInterpolation,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::interpolation::FormatScssInterpolation::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssInterpolation {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssInterpolation,
+        crate::scss::auxiliary::interpolation::FormatScssInterpolation,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::interpolation::FormatScssInterpolation::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssParenthesizedExpression>
+    for crate::scss::auxiliary::parenthesized_expression::FormatScssParenthesizedExpression
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssParenthesizedExpression,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssParenthesizedExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParenthesizedExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParenthesizedExpression,
+        crate::scss::auxiliary::parenthesized_expression::FormatScssParenthesizedExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: scss :: auxiliary :: parenthesized_expression :: FormatScssParenthesizedExpression :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParenthesizedExpression {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParenthesizedExpression,
+        crate::scss::auxiliary::parenthesized_expression::FormatScssParenthesizedExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: scss :: auxiliary :: parenthesized_expression :: FormatScssParenthesizedExpression :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::ScssMapExpression>
+    for crate::scss::auxiliary::map_expression::FormatScssMapExpression
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssMapExpression,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssMapExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssMapExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssMapExpression,
+        crate::scss::auxiliary::map_expression::FormatScssMapExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::map_expression::FormatScssMapExpression::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssMapExpression {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssMapExpression,
+        crate::scss::auxiliary::map_expression::FormatScssMapExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::map_expression::FormatScssMapExpression::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessIdentifier>
+    for crate::less::value::identifier::FormatLessIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessIdentifier,
+        crate::less::value::identifier::FormatLessIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::value::identifier::FormatLessIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessIdentifier,
+        crate::less::value::identifier::FormatLessIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::value::identifier::FormatLessIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessParenthesizedExpression>
+    for crate::less::auxiliary::parenthesized_expression::FormatLessParenthesizedExpression
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessParenthesizedExpression,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessParenthesizedExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessParenthesizedExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessParenthesizedExpression,
+        crate::less::auxiliary::parenthesized_expression::FormatLessParenthesizedExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: less :: auxiliary :: parenthesized_expression :: FormatLessParenthesizedExpression :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessParenthesizedExpression {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessParenthesizedExpression,
+        crate::less::auxiliary::parenthesized_expression::FormatLessParenthesizedExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: less :: auxiliary :: parenthesized_expression :: FormatLessParenthesizedExpression :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::LessEscapedString>
+    for crate::less::auxiliary::escaped_string::FormatLessEscapedString
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessEscapedString,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessEscapedString>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessEscapedString {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessEscapedString,
+        crate::less::auxiliary::escaped_string::FormatLessEscapedString,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::escaped_string::FormatLessEscapedString::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessEscapedString {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessEscapedString,
+        crate::less::auxiliary::escaped_string::FormatLessEscapedString,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::escaped_string::FormatLessEscapedString::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssRegularDimension>
+    for crate::css::value::regular_dimension::FormatCssRegularDimension
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssRegularDimension,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssRegularDimension>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRegularDimension {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssRegularDimension,
+        crate::css::value::regular_dimension::FormatCssRegularDimension,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::regular_dimension::FormatCssRegularDimension::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRegularDimension {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssRegularDimension,
+        crate::css::value::regular_dimension::FormatCssRegularDimension,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::regular_dimension::FormatCssRegularDimension::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssUnknownDimension>
+    for crate::css::value::unknown_dimension::FormatCssUnknownDimension
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssUnknownDimension,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssUnknownDimension>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssUnknownDimension {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssUnknownDimension,
+        crate::css::value::unknown_dimension::FormatCssUnknownDimension,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::unknown_dimension::FormatCssUnknownDimension::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUnknownDimension {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssUnknownDimension,
+        crate::css::value::unknown_dimension::FormatCssUnknownDimension,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::unknown_dimension::FormatCssUnknownDimension::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssUrlValueRaw>
+    for crate::css::value::url_value_raw::FormatCssUrlValueRaw
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssUrlValueRaw,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssUrlValueRaw>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssUrlValueRaw {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssUrlValueRaw,
+        crate::css::value::url_value_raw::FormatCssUrlValueRaw,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::url_value_raw::FormatCssUrlValueRaw::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUrlValueRaw {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssUrlValueRaw,
+        crate::css::value::url_value_raw::FormatCssUrlValueRaw,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::url_value_raw::FormatCssUrlValueRaw::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssParameter>
+    for crate::css::auxiliary::parameter::FormatCssParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::CssParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssParameter,
+        crate::css::auxiliary::parameter::FormatCssParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::parameter::FormatCssParameter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssParameter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssParameter,
+        crate::css::auxiliary::parameter::FormatCssParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::parameter::FormatCssParameter::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssBinaryExpression>
+    for crate::css::auxiliary::binary_expression::FormatCssBinaryExpression
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssBinaryExpression,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssBinaryExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssBinaryExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssBinaryExpression,
+        crate::css::auxiliary::binary_expression::FormatCssBinaryExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::binary_expression::FormatCssBinaryExpression::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssBinaryExpression {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssBinaryExpression,
+        crate::css::auxiliary::binary_expression::FormatCssBinaryExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::binary_expression::FormatCssBinaryExpression::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssParenthesizedExpression>
+    for crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssParenthesizedExpression,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssParenthesizedExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssParenthesizedExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssParenthesizedExpression,
+        crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: parenthesized_expression :: FormatCssParenthesizedExpression :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssParenthesizedExpression {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssParenthesizedExpression,
+        crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: parenthesized_expression :: FormatCssParenthesizedExpression :: default ())
+    }
+}
+impl FormatRule < biome_css_syntax :: CssListOfComponentValuesExpression > for crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssListOfComponentValuesExpression , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssListOfComponentValuesExpression > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssListOfComponentValuesExpression {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssListOfComponentValuesExpression , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssListOfComponentValuesExpression {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssListOfComponentValuesExpression , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::ScssMapExpressionPair>
+    for crate::scss::auxiliary::map_expression_pair::FormatScssMapExpressionPair
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssMapExpressionPair,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssMapExpressionPair>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssMapExpressionPair {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssMapExpressionPair,
+        crate::scss::auxiliary::map_expression_pair::FormatScssMapExpressionPair,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::map_expression_pair::FormatScssMapExpressionPair::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssMapExpressionPair {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssMapExpressionPair,
+        crate::scss::auxiliary::map_expression_pair::FormatScssMapExpressionPair,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::map_expression_pair::FormatScssMapExpressionPair::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssVariableModifier>
+    for crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssVariableModifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssVariableModifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssVariableModifier,
+        crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssVariableModifier,
+        crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssUseAs> for crate::scss::auxiliary::use_as::FormatScssUseAs {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::ScssUseAs, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssUseAs>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssUseAs {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssUseAs,
+        crate::scss::auxiliary::use_as::FormatScssUseAs,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::use_as::FormatScssUseAs::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssUseAs {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssUseAs,
+        crate::scss::auxiliary::use_as::FormatScssUseAs,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::use_as::FormatScssUseAs::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssModuleConfiguration>
+    for crate::scss::auxiliary::module_configuration::FormatScssModuleConfiguration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssModuleConfiguration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssModuleConfiguration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssModuleConfiguration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssModuleConfiguration,
+        crate::scss::auxiliary::module_configuration::FormatScssModuleConfiguration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::module_configuration::FormatScssModuleConfiguration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssModuleConfiguration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssModuleConfiguration,
+        crate::scss::auxiliary::module_configuration::FormatScssModuleConfiguration,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::module_configuration::FormatScssModuleConfiguration::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssUseAllNamespace>
+    for crate::scss::auxiliary::use_all_namespace::FormatScssUseAllNamespace
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssUseAllNamespace,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssUseAllNamespace>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssUseAllNamespace {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssUseAllNamespace,
+        crate::scss::auxiliary::use_all_namespace::FormatScssUseAllNamespace,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::use_all_namespace::FormatScssUseAllNamespace::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssUseAllNamespace {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssUseAllNamespace,
+        crate::scss::auxiliary::use_all_namespace::FormatScssUseAllNamespace,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::use_all_namespace::FormatScssUseAllNamespace::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssForwardAs>
+    for crate::scss::auxiliary::forward_as::FormatScssForwardAs
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssForwardAs,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssForwardAs>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssForwardAs {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssForwardAs,
+        crate::scss::auxiliary::forward_as::FormatScssForwardAs,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::forward_as::FormatScssForwardAs::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssForwardAs {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssForwardAs,
+        crate::scss::auxiliary::forward_as::FormatScssForwardAs,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::forward_as::FormatScssForwardAs::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssForwardVisibility>
+    for crate::scss::auxiliary::forward_visibility::FormatScssForwardVisibility
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssForwardVisibility,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssForwardVisibility>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssForwardVisibility {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssForwardVisibility,
+        crate::scss::auxiliary::forward_visibility::FormatScssForwardVisibility,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::forward_visibility::FormatScssForwardVisibility::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssForwardVisibility {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssForwardVisibility,
+        crate::scss::auxiliary::forward_visibility::FormatScssForwardVisibility,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::forward_visibility::FormatScssForwardVisibility::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssParameters>
+    for crate::scss::auxiliary::parameters::FormatScssParameters
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssParameters>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameters {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParameters,
+        crate::scss::auxiliary::parameters::FormatScssParameters,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::parameters::FormatScssParameters::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameters {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParameters,
+        crate::scss::auxiliary::parameters::FormatScssParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::parameters::FormatScssParameters::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssParameter>
+    for crate::scss::auxiliary::parameter::FormatScssParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssParameter,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParameter,
+        crate::scss::auxiliary::parameter::FormatScssParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::parameter::FormatScssParameter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParameter,
+        crate::scss::auxiliary::parameter::FormatScssParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::parameter::FormatScssParameter::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssParameterDefault>
+    for crate::scss::auxiliary::parameter_default::FormatScssParameterDefault
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssParameterDefault,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssParameterDefault>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameterDefault {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParameterDefault,
+        crate::scss::auxiliary::parameter_default::FormatScssParameterDefault,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::parameter_default::FormatScssParameterDefault::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameterDefault {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParameterDefault,
+        crate::scss::auxiliary::parameter_default::FormatScssParameterDefault,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::parameter_default::FormatScssParameterDefault::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssIncludeArguments>
+    for crate::scss::auxiliary::include_arguments::FormatScssIncludeArguments
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssIncludeArguments,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssIncludeArguments>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssIncludeArguments {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssIncludeArguments,
+        crate::scss::auxiliary::include_arguments::FormatScssIncludeArguments,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::include_arguments::FormatScssIncludeArguments::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssIncludeArguments {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssIncludeArguments,
+        crate::scss::auxiliary::include_arguments::FormatScssIncludeArguments,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::include_arguments::FormatScssIncludeArguments::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssArgument>
+    for crate::scss::auxiliary::argument::FormatScssArgument
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::ScssArgument, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssArgument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssArgument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssArgument,
+        crate::scss::auxiliary::argument::FormatScssArgument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::argument::FormatScssArgument::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssArgument {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssArgument,
+        crate::scss::auxiliary::argument::FormatScssArgument,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::argument::FormatScssArgument::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssArgumentName>
+    for crate::scss::auxiliary::argument_name::FormatScssArgumentName
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssArgumentName,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssArgumentName>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssArgumentName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssArgumentName,
+        crate::scss::auxiliary::argument_name::FormatScssArgumentName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::argument_name::FormatScssArgumentName::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssArgumentName {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssArgumentName,
+        crate::scss::auxiliary::argument_name::FormatScssArgumentName,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::argument_name::FormatScssArgumentName::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssExtendOptional>
+    for crate::scss::auxiliary::extend_optional::FormatScssExtendOptional
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssExtendOptional,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssExtendOptional>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssExtendOptional {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssExtendOptional,
+        crate::scss::auxiliary::extend_optional::FormatScssExtendOptional,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::extend_optional::FormatScssExtendOptional::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssExtendOptional {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssExtendOptional,
+        crate::scss::auxiliary::extend_optional::FormatScssExtendOptional,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::extend_optional::FormatScssExtendOptional::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssElseClause>
+    for crate::scss::auxiliary::else_clause::FormatScssElseClause
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssElseClause,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssElseClause>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssElseClause {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssElseClause,
+        crate::scss::auxiliary::else_clause::FormatScssElseClause,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::else_clause::FormatScssElseClause::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssElseClause {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssElseClause,
+        crate::scss::auxiliary::else_clause::FormatScssElseClause,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::else_clause::FormatScssElseClause::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinGuard>
+    for crate::less::auxiliary::mixin_guard::FormatLessMixinGuard
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinGuard,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinGuard>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinGuard {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinGuard,
+        crate::less::auxiliary::mixin_guard::FormatLessMixinGuard,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_guard::FormatLessMixinGuard::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinGuard {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinGuard,
+        crate::less::auxiliary::mixin_guard::FormatLessMixinGuard,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_guard::FormatLessMixinGuard::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinParameters>
+    for crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinParameters>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinParameters {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinParameters,
+        crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinParameters {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinParameters,
+        crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinSemicolonParameters>
+    for crate::less::auxiliary::mixin_semicolon_parameters::FormatLessMixinSemicolonParameters
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinSemicolonParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinSemicolonParameters>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinSemicolonParameters {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinSemicolonParameters,
+        crate::less::auxiliary::mixin_semicolon_parameters::FormatLessMixinSemicolonParameters,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: less :: auxiliary :: mixin_semicolon_parameters :: FormatLessMixinSemicolonParameters :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinSemicolonParameters {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinSemicolonParameters,
+        crate::less::auxiliary::mixin_semicolon_parameters::FormatLessMixinSemicolonParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: less :: auxiliary :: mixin_semicolon_parameters :: FormatLessMixinSemicolonParameters :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinParameter>
+    for crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinParameter,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinParameter,
+        crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinParameter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinParameter,
+        crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinParameterName>
+    for crate::less::auxiliary::mixin_parameter_name::FormatLessMixinParameterName
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinParameterName,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinParameterName>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinParameterName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinParameterName,
+        crate::less::auxiliary::mixin_parameter_name::FormatLessMixinParameterName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameter_name::FormatLessMixinParameterName::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinParameterName {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinParameterName,
+        crate::less::auxiliary::mixin_parameter_name::FormatLessMixinParameterName,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameter_name::FormatLessMixinParameterName::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessExtendTarget>
+    for crate::less::auxiliary::extend_target::FormatLessExtendTarget
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessExtendTarget,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessExtendTarget>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessExtendTarget {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessExtendTarget,
+        crate::less::auxiliary::extend_target::FormatLessExtendTarget,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::extend_target::FormatLessExtendTarget::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessExtendTarget {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessExtendTarget,
+        crate::less::auxiliary::extend_target::FormatLessExtendTarget,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::extend_target::FormatLessExtendTarget::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessImportOptions>
+    for crate::less::auxiliary::import_options::FormatLessImportOptions
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessImportOptions,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessImportOptions>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessImportOptions {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessImportOptions,
+        crate::less::auxiliary::import_options::FormatLessImportOptions,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::import_options::FormatLessImportOptions::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessImportOptions {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessImportOptions,
+        crate::less::auxiliary::import_options::FormatLessImportOptions,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::import_options::FormatLessImportOptions::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssComponentValueList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssComponentValueList,
+        crate::css::lists::component_value_list::FormatCssComponentValueList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssComponentValueList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssComponentValueList,
+        crate::css::lists::component_value_list::FormatCssComponentValueList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssComposesClassList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssComposesClassList,
+        crate::css::lists::composes_class_list::FormatCssComposesClassList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::composes_class_list::FormatCssComposesClassList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssComposesClassList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssComposesClassList,
+        crate::css::lists::composes_class_list::FormatCssComposesClassList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::composes_class_list::FormatCssComposesClassList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssCompoundSelectorList,
+        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssCompoundSelectorList,
+        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssDeclarationList,
+        crate::css::lists::declaration_list::FormatCssDeclarationList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::declaration_list::FormatCssDeclarationList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssDeclarationList,
+        crate::css::lists::declaration_list::FormatCssDeclarationList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::declaration_list::FormatCssDeclarationList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssDeclarationOrAtRuleList,
+        crate::css::lists::declaration_or_at_rule_list::FormatCssDeclarationOrAtRuleList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: lists :: declaration_or_at_rule_list :: FormatCssDeclarationOrAtRuleList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssDeclarationOrAtRuleList,
+        crate::css::lists::declaration_or_at_rule_list::FormatCssDeclarationOrAtRuleList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: lists :: declaration_or_at_rule_list :: FormatCssDeclarationOrAtRuleList :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssDeclarationOrRuleList,
+        crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssDeclarationOrRuleList,
+        crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDocumentMatcherList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssDocumentMatcherList,
+        crate::css::lists::document_matcher_list::FormatCssDocumentMatcherList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::document_matcher_list::FormatCssDocumentMatcherList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDocumentMatcherList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssDocumentMatcherList,
+        crate::css::lists::document_matcher_list::FormatCssDocumentMatcherList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::document_matcher_list::FormatCssDocumentMatcherList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItemList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssFontFeatureValuesItemList,
+        crate::css::lists::font_feature_values_item_list::FormatCssFontFeatureValuesItemList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: lists :: font_feature_values_item_list :: FormatCssFontFeatureValuesItemList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItemList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssFontFeatureValuesItemList,
+        crate::css::lists::font_feature_values_item_list::FormatCssFontFeatureValuesItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: lists :: font_feature_values_item_list :: FormatCssFontFeatureValuesItemList :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssGenericComponentValueList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssGenericComponentValueList,
+        crate::css::lists::generic_component_value_list::FormatCssGenericComponentValueList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: lists :: generic_component_value_list :: FormatCssGenericComponentValueList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssGenericComponentValueList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssGenericComponentValueList,
+        crate::css::lists::generic_component_value_list::FormatCssGenericComponentValueList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: lists :: generic_component_value_list :: FormatCssGenericComponentValueList :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItemList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssKeyframesItemList,
+        crate::css::lists::keyframes_item_list::FormatCssKeyframesItemList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::keyframes_item_list::FormatCssKeyframesItemList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItemList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssKeyframesItemList,
+        crate::css::lists::keyframes_item_list::FormatCssKeyframesItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::keyframes_item_list::FormatCssKeyframesItemList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesSelectorList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssKeyframesSelectorList,
+        crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList,
+    >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssListOfComponentValuesExpression {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssListOfComponentValuesExpression , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression > ;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesSelectorList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssKeyframesSelectorList,
+        crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList,
+    >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList::default(),
+        )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssComponentValueList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerNameList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssComponentValueList,
-        crate::css::lists::component_value_list::FormatCssComponentValueList,
+        biome_css_syntax::CssLayerNameList,
+        crate::css::lists::layer_name_list::FormatCssLayerNameList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+            crate::css::lists::layer_name_list::FormatCssLayerNameList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssComponentValueList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerNameList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssComponentValueList,
-        crate::css::lists::component_value_list::FormatCssComponentValueList,
+        biome_css_syntax::CssLayerNameList,
+        crate::css::lists::layer_name_list::FormatCssLayerNameList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+            crate::css::lists::layer_name_list::FormatCssLayerNameList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerReferenceList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssCompoundSelectorList,
-        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+        biome_css_syntax::CssLayerReferenceList,
+        crate::css::lists::layer_reference_list::FormatCssLayerReferenceList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+            crate::css::lists::layer_reference_list::FormatCssLayerReferenceList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerReferenceList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssCompoundSelectorList,
-        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+        biome_css_syntax::CssLayerReferenceList,
+        crate::css::lists::layer_reference_list::FormatCssLayerReferenceList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+            crate::css::lists::layer_reference_list::FormatCssLayerReferenceList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaQueryList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclarationList,
-        crate::css::lists::declaration_list::FormatCssDeclarationList,
+        biome_css_syntax::CssMediaQueryList,
+        crate::css::lists::media_query_list::FormatCssMediaQueryList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::declaration_list::FormatCssDeclarationList::default(),
+            crate::css::lists::media_query_list::FormatCssMediaQueryList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaQueryList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclarationList,
-        crate::css::lists::declaration_list::FormatCssDeclarationList,
+        biome_css_syntax::CssMediaQueryList,
+        crate::css::lists::media_query_list::FormatCssMediaQueryList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::declaration_list::FormatCssDeclarationList::default(),
+            crate::css::lists::media_query_list::FormatCssMediaQueryList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleItemList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclarationOrAtRuleList,
-        crate::css::lists::declaration_or_at_rule_list::FormatCssDeclarationOrAtRuleList,
+        biome_css_syntax::CssPageAtRuleItemList,
+        crate::css::lists::page_at_rule_item_list::FormatCssPageAtRuleItemList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: lists :: declaration_or_at_rule_list :: FormatCssDeclarationOrAtRuleList :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::page_at_rule_item_list::FormatCssPageAtRuleItemList::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleItemList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclarationOrAtRuleList,
-        crate::css::lists::declaration_or_at_rule_list::FormatCssDeclarationOrAtRuleList,
+        biome_css_syntax::CssPageAtRuleItemList,
+        crate::css::lists::page_at_rule_item_list::FormatCssPageAtRuleItemList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: lists :: declaration_or_at_rule_list :: FormatCssDeclarationOrAtRuleList :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::page_at_rule_item_list::FormatCssPageAtRuleItemList::default(),
+        )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclarationOrRuleList,
-        crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList,
+        biome_css_syntax::CssPageSelectorList,
+        crate::css::lists::page_selector_list::FormatCssPageSelectorList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList::default(),
+            crate::css::lists::page_selector_list::FormatCssPageSelectorList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclarationOrRuleList,
-        crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList,
+        biome_css_syntax::CssPageSelectorList,
+        crate::css::lists::page_selector_list::FormatCssPageSelectorList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList::default(),
+            crate::css::lists::page_selector_list::FormatCssPageSelectorList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDocumentMatcherList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudoList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDocumentMatcherList,
-        crate::css::lists::document_matcher_list::FormatCssDocumentMatcherList,
+        biome_css_syntax::CssPageSelectorPseudoList,
+        crate::css::lists::page_selector_pseudo_list::FormatCssPageSelectorPseudoList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::document_matcher_list::FormatCssDocumentMatcherList::default(),
+            crate::css::lists::page_selector_pseudo_list::FormatCssPageSelectorPseudoList::default(
+            ),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDocumentMatcherList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudoList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDocumentMatcherList,
-        crate::css::lists::document_matcher_list::FormatCssDocumentMatcherList,
+        biome_css_syntax::CssPageSelectorPseudoList,
+        crate::css::lists::page_selector_pseudo_list::FormatCssPageSelectorPseudoList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::document_matcher_list::FormatCssDocumentMatcherList::default(),
+            crate::css::lists::page_selector_pseudo_list::FormatCssPageSelectorPseudoList::default(
+            ),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItemList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssParameterList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssFontFeatureValuesItemList,
-        crate::css::lists::font_feature_values_item_list::FormatCssFontFeatureValuesItemList,
+        biome_css_syntax::CssParameterList,
+        crate::css::lists::parameter_list::FormatCssParameterList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: lists :: font_feature_values_item_list :: FormatCssFontFeatureValuesItemList :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::parameter_list::FormatCssParameterList::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItemList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssParameterList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssFontFeatureValuesItemList,
-        crate::css::lists::font_feature_values_item_list::FormatCssFontFeatureValuesItemList,
+        biome_css_syntax::CssParameterList,
+        crate::css::lists::parameter_list::FormatCssParameterList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: lists :: font_feature_values_item_list :: FormatCssFontFeatureValuesItemList :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::parameter_list::FormatCssParameterList::default(),
+        )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssGenericComponentValueList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoValueList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssGenericComponentValueList,
-        crate::css::lists::generic_component_value_list::FormatCssGenericComponentValueList,
+        biome_css_syntax::CssPseudoValueList,
+        crate::css::lists::pseudo_value_list::FormatCssPseudoValueList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: lists :: generic_component_value_list :: FormatCssGenericComponentValueList :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::pseudo_value_list::FormatCssPseudoValueList::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssGenericComponentValueList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoValueList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssGenericComponentValueList,
-        crate::css::lists::generic_component_value_list::FormatCssGenericComponentValueList,
+        biome_css_syntax::CssPseudoValueList,
+        crate::css::lists::pseudo_value_list::FormatCssPseudoValueList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: lists :: generic_component_value_list :: FormatCssGenericComponentValueList :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::pseudo_value_list::FormatCssPseudoValueList::default(),
+        )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItemList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelectorList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesItemList,
-        crate::css::lists::keyframes_item_list::FormatCssKeyframesItemList,
+        biome_css_syntax::CssRelativeSelectorList,
+        crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::keyframes_item_list::FormatCssKeyframesItemList::default(),
+            crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItemList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelectorList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesItemList,
-        crate::css::lists::keyframes_item_list::FormatCssKeyframesItemList,
+        biome_css_syntax::CssRelativeSelectorList,
+        crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::keyframes_item_list::FormatCssKeyframesItemList::default(),
+            crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesSelectorList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRuleList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesSelectorList,
-        crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList,
+        biome_css_syntax::CssRuleList,
+        crate::css::lists::rule_list::FormatCssRuleList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList::default(),
+            crate::css::lists::rule_list::FormatCssRuleList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesSelectorList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRuleList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesSelectorList,
-        crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList,
+        biome_css_syntax::CssRuleList,
+        crate::css::lists::rule_list::FormatCssRuleList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList::default(),
+            crate::css::lists::rule_list::FormatCssRuleList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerNameList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSelectorList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssLayerNameList,
-        crate::css::lists::layer_name_list::FormatCssLayerNameList,
+        biome_css_syntax::CssSelectorList,
+        crate::css::lists::selector_list::FormatCssSelectorList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::layer_name_list::FormatCssLayerNameList::default(),
+            crate::css::lists::selector_list::FormatCssSelectorList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerNameList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSelectorList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssLayerNameList,
-        crate::css::lists::layer_name_list::FormatCssLayerNameList,
+        biome_css_syntax::CssSelectorList,
+        crate::css::lists::selector_list::FormatCssSelectorList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::layer_name_list::FormatCssLayerNameList::default(),
+            crate::css::lists::selector_list::FormatCssSelectorList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerReferenceList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSubSelectorList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssLayerReferenceList,
-        crate::css::lists::layer_reference_list::FormatCssLayerReferenceList,
+        biome_css_syntax::CssSubSelectorList,
+        crate::css::lists::sub_selector_list::FormatCssSubSelectorList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::layer_reference_list::FormatCssLayerReferenceList::default(),
+            crate::css::lists::sub_selector_list::FormatCssSubSelectorList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerReferenceList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSubSelectorList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssLayerReferenceList,
-        crate::css::lists::layer_reference_list::FormatCssLayerReferenceList,
+        biome_css_syntax::CssSubSelectorList,
+        crate::css::lists::sub_selector_list::FormatCssSubSelectorList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::layer_reference_list::FormatCssLayerReferenceList::default(),
+            crate::css::lists::sub_selector_list::FormatCssSubSelectorList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaQueryList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssUrlModifierList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaQueryList,
-        crate::css::lists::media_query_list::FormatCssMediaQueryList,
+        biome_css_syntax::CssUrlModifierList,
+        crate::css::lists::url_modifier_list::FormatCssUrlModifierList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::media_query_list::FormatCssMediaQueryList::default(),
+            crate::css::lists::url_modifier_list::FormatCssUrlModifierList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaQueryList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUrlModifierList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaQueryList,
-        crate::css::lists::media_query_list::FormatCssMediaQueryList,
+        biome_css_syntax::CssUrlModifierList,
+        crate::css::lists::url_modifier_list::FormatCssUrlModifierList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::lists::media_query_list::FormatCssMediaQueryList::default(),
-        )
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::url_modifier_list::FormatCssUrlModifierList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleImportSpecifierList {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssValueAtRuleImportSpecifierList , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleImportSpecifierList {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssValueAtRuleImportSpecifierList , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList :: default ())
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleItemList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessExtendTargetList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPageAtRuleItemList,
-        crate::css::lists::page_at_rule_item_list::FormatCssPageAtRuleItemList,
+        biome_css_syntax::LessExtendTargetList,
+        crate::less::lists::extend_target_list::FormatLessExtendTargetList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::page_at_rule_item_list::FormatCssPageAtRuleItemList::default(),
+            crate::less::lists::extend_target_list::FormatLessExtendTargetList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleItemList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessExtendTargetList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPageAtRuleItemList,
-        crate::css::lists::page_at_rule_item_list::FormatCssPageAtRuleItemList,
+        biome_css_syntax::LessExtendTargetList,
+        crate::less::lists::extend_target_list::FormatLessExtendTargetList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::page_at_rule_item_list::FormatCssPageAtRuleItemList::default(),
+            crate::less::lists::extend_target_list::FormatLessExtendTargetList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessImportOptionList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPageSelectorList,
-        crate::css::lists::page_selector_list::FormatCssPageSelectorList,
+        biome_css_syntax::LessImportOptionList,
+        crate::less::lists::import_option_list::FormatLessImportOptionList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::page_selector_list::FormatCssPageSelectorList::default(),
+            crate::less::lists::import_option_list::FormatLessImportOptionList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessImportOptionList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPageSelectorList,
-        crate::css::lists::page_selector_list::FormatCssPageSelectorList,
+        biome_css_syntax::LessImportOptionList,
+        crate::less::lists::import_option_list::FormatLessImportOptionList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::page_selector_list::FormatCssPageSelectorList::default(),
+            crate::less::lists::import_option_list::FormatLessImportOptionList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudoList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinParameterList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPageSelectorPseudoList,
-        crate::css::lists::page_selector_pseudo_list::FormatCssPageSelectorPseudoList,
+        biome_css_syntax::LessMixinParameterList,
+        crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::page_selector_pseudo_list::FormatCssPageSelectorPseudoList::default(
-            ),
+            crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudoList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinParameterList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPageSelectorPseudoList,
-        crate::css::lists::page_selector_pseudo_list::FormatCssPageSelectorPseudoList,
+        biome_css_syntax::LessMixinParameterList,
+        crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::page_selector_pseudo_list::FormatCssPageSelectorPseudoList::default(
-            ),
+            crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssParameterList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinSemicolonParameterList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssParameterList,
-        crate::css::lists::parameter_list::FormatCssParameterList,
+        biome_css_syntax::LessMixinSemicolonParameterList,
+        crate::less::lists::mixin_semicolon_parameter_list::FormatLessMixinSemicolonParameterList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::lists::parameter_list::FormatCssParameterList::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: less :: lists :: mixin_semicolon_parameter_list :: FormatLessMixinSemicolonParameterList :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssParameterList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinSemicolonParameterList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssParameterList,
-        crate::css::lists::parameter_list::FormatCssParameterList,
+        biome_css_syntax::LessMixinSemicolonParameterList,
+        crate::less::lists::mixin_semicolon_parameter_list::FormatLessMixinSemicolonParameterList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::lists::parameter_list::FormatCssParameterList::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: less :: lists :: mixin_semicolon_parameter_list :: FormatLessMixinSemicolonParameterList :: default ())
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoValueList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssArgumentList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoValueList,
-        crate::css::lists::pseudo_value_list::FormatCssPseudoValueList,
+        biome_css_syntax::ScssArgumentList,
+        crate::scss::lists::argument_list::FormatScssArgumentList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::pseudo_value_list::FormatCssPseudoValueList::default(),
+            crate::scss::lists::argument_list::FormatScssArgumentList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoValueList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssArgumentList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoValueList,
-        crate::css::lists::pseudo_value_list::FormatCssPseudoValueList,
+        biome_css_syntax::ScssArgumentList,
+        crate::scss::lists::argument_list::FormatScssArgumentList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::pseudo_value_list::FormatCssPseudoValueList::default(),
+            crate::scss::lists::argument_list::FormatScssArgumentList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelectorList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssEachBindingList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssRelativeSelectorList,
-        crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList,
+        biome_css_syntax::ScssEachBindingList,
+        crate::scss::lists::each_binding_list::FormatScssEachBindingList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList::default(),
+            crate::scss::lists::each_binding_list::FormatScssEachBindingList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelectorList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssEachBindingList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssRelativeSelectorList,
-        crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList,
+        biome_css_syntax::ScssEachBindingList,
+        crate::scss::lists::each_binding_list::FormatScssEachBindingList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList::default(),
+            crate::scss::lists::each_binding_list::FormatScssEachBindingList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssRuleList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssForwardMemberList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssRuleList,
-        crate::css::lists::rule_list::FormatCssRuleList,
+        biome_css_syntax::ScssForwardMemberList,
+        crate::scss::lists::forward_member_list::FormatScssForwardMemberList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::rule_list::FormatCssRuleList::default(),
+            crate::scss::lists::forward_member_list::FormatScssForwardMemberList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRuleList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssForwardMemberList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssRuleList,
-        crate::css::lists::rule_list::FormatCssRuleList,
+        biome_css_syntax::ScssForwardMemberList,
+        crate::scss::lists::forward_member_list::FormatScssForwardMemberList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::rule_list::FormatCssRuleList::default(),
+            crate::scss::lists::forward_member_list::FormatScssForwardMemberList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSelectorList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssMapExpressionPairList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSelectorList,
-        crate::css::lists::selector_list::FormatCssSelectorList,
+        biome_css_syntax::ScssMapExpressionPairList,
+        crate::scss::lists::map_expression_pair_list::FormatScssMapExpressionPairList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::selector_list::FormatCssSelectorList::default(),
+            crate::scss::lists::map_expression_pair_list::FormatScssMapExpressionPairList::default(
+            ),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSelectorList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssMapExpressionPairList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSelectorList,
-        crate::css::lists::selector_list::FormatCssSelectorList,
+        biome_css_syntax::ScssMapExpressionPairList,
+        crate::scss::lists::map_expression_pair_list::FormatScssMapExpressionPairList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::selector_list::FormatCssSelectorList::default(),
+            crate::scss::lists::map_expression_pair_list::FormatScssMapExpressionPairList::default(
+            ),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSubSelectorList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameterList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSubSelectorList,
-        crate::css::lists::sub_selector_list::FormatCssSubSelectorList,
+        biome_css_syntax::ScssParameterList,
+        crate::scss::lists::parameter_list::FormatScssParameterList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::sub_selector_list::FormatCssSubSelectorList::default(),
+            crate::scss::lists::parameter_list::FormatScssParameterList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSubSelectorList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameterList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSubSelectorList,
-        crate::css::lists::sub_selector_list::FormatCssSubSelectorList,
+        biome_css_syntax::ScssParameterList,
+        crate::scss::lists::parameter_list::FormatScssParameterList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::sub_selector_list::FormatCssSubSelectorList::default(),
+            crate::scss::lists::parameter_list::FormatScssParameterList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssUrlModifierList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifierList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssUrlModifierList,
-        crate::css::lists::url_modifier_list::FormatCssUrlModifierList,
+        biome_css_syntax::ScssVariableModifierList,
+        crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::url_modifier_list::FormatCssUrlModifierList::default(),
+            crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUrlModifierList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifierList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssUrlModifierList,
-        crate::css::lists::url_modifier_list::FormatCssUrlModifierList,
+        biome_css_syntax::ScssVariableModifierList,
+        crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::url_modifier_list::FormatCssUrlModifierList::default(),
+            crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList::default(),
         )
     }
 }
@@ -6761,6 +9322,33 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssDeclarationName {
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssComposesImportSource {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssComposesImportSource,
+        crate::css::any::composes_import_source::FormatAnyCssComposesImportSource,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::composes_import_source::FormatAnyCssComposesImportSource::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssComposesImportSource {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssComposesImportSource,
+        crate::css::any::composes_import_source::FormatAnyCssComposesImportSource,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::composes_import_source::FormatAnyCssComposesImportSource::default(),
+        )
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssGenericComponentValue {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -7732,6 +10320,75 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssScopeRange {
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssValueAtRuleClause {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssValueAtRuleClause,
+        crate::css::any::value_at_rule_clause::FormatAnyCssValueAtRuleClause,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::value_at_rule_clause::FormatAnyCssValueAtRuleClause::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssValueAtRuleClause {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssValueAtRuleClause,
+        crate::css::any::value_at_rule_clause::FormatAnyCssValueAtRuleClause,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::value_at_rule_clause::FormatAnyCssValueAtRuleClause::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssValueAtRuleImportSource {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssValueAtRuleImportSource,
+        crate::css::any::value_at_rule_import_source::FormatAnyCssValueAtRuleImportSource,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: any :: value_at_rule_import_source :: FormatAnyCssValueAtRuleImportSource :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssValueAtRuleImportSource {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssValueAtRuleImportSource,
+        crate::css::any::value_at_rule_import_source::FormatAnyCssValueAtRuleImportSource,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: any :: value_at_rule_import_source :: FormatAnyCssValueAtRuleImportSource :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssValueAtRuleImportSpecifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssValueAtRuleImportSpecifier,
+        crate::css::any::value_at_rule_import_specifier::FormatAnyCssValueAtRuleImportSpecifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: any :: value_at_rule_import_specifier :: FormatAnyCssValueAtRuleImportSpecifier :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssValueAtRuleImportSpecifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssValueAtRuleImportSpecifier,
+        crate::css::any::value_at_rule_import_specifier::FormatAnyCssValueAtRuleImportSpecifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: any :: value_at_rule_import_specifier :: FormatAnyCssValueAtRuleImportSpecifier :: default ())
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssImportUrl {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -7969,3 +10626,138 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssExpression {
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssModuleMember {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssModuleMember,
+        crate::scss::any::module_member::FormatAnyScssModuleMember,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::module_member::FormatAnyScssModuleMember::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssModuleMember {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssModuleMember,
+        crate::scss::any::module_member::FormatAnyScssModuleMember,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::module_member::FormatAnyScssModuleMember::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssUseNamespace {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssUseNamespace,
+        crate::scss::any::use_namespace::FormatAnyScssUseNamespace,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::use_namespace::FormatAnyScssUseNamespace::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssUseNamespace {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssUseNamespace,
+        crate::scss::any::use_namespace::FormatAnyScssUseNamespace,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::use_namespace::FormatAnyScssUseNamespace::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssIncludeName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssIncludeName,
+        crate::scss::any::include_name::FormatAnyScssIncludeName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::include_name::FormatAnyScssIncludeName::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssIncludeName {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssIncludeName,
+        crate::scss::any::include_name::FormatAnyScssIncludeName,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::include_name::FormatAnyScssIncludeName::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssElseClauseBody {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssElseClauseBody,
+        crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssElseClauseBody {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssElseClauseBody,
+        crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinParameters {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyLessMixinParameters,
+        crate::less::any::mixin_parameters::FormatAnyLessMixinParameters,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::any::mixin_parameters::FormatAnyLessMixinParameters::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinParameters {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyLessMixinParameters,
+        crate::less::any::mixin_parameters::FormatAnyLessMixinParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::any::mixin_parameters::FormatAnyLessMixinParameters::default(),
+        )
+    }
+}
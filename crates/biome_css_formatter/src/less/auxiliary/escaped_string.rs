@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessEscapedString, LessEscapedStringFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessEscapedString;
+impl FormatNodeRule<LessEscapedString> for FormatLessEscapedString {
+    fn fmt_fields(&self, node: &LessEscapedString, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessEscapedStringFields {
+            bitwise_not_token,
+            value,
+        } = node.as_fields();
+
+        write!(f, [bitwise_not_token.format(), value.format()])
+    }
+}
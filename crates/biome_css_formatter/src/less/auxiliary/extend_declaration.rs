@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessExtendDeclaration, LessExtendDeclarationFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessExtendDeclaration;
+impl FormatNodeRule<LessExtendDeclaration> for FormatLessExtendDeclaration {
+    fn fmt_fields(&self, node: &LessExtendDeclaration, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessExtendDeclarationFields {
+            amp_token,
+            colon_token,
+            extend,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [amp_token.format(), colon_token.format(), extend.format()]
+        )?;
+
+        if semicolon_token.is_some() {
+            write!(f, [semicolon_token.format()])
+        } else {
+            write!(f, [text(";")])
+        }
+    }
+}
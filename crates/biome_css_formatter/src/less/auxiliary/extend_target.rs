@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessExtendTarget, LessExtendTargetFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessExtendTarget;
+impl FormatNodeRule<LessExtendTarget> for FormatLessExtendTarget {
+    fn fmt_fields(&self, node: &LessExtendTarget, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessExtendTargetFields {
+            selector,
+            all_token,
+        } = node.as_fields();
+
+        write!(f, [selector.format()])?;
+
+        if all_token.is_some() {
+            write!(f, [space(), all_token.format()])?;
+        }
+
+        Ok(())
+    }
+}
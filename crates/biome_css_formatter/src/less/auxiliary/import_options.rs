@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessImportOptions, LessImportOptionsFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessImportOptions;
+impl FormatNodeRule<LessImportOptions> for FormatLessImportOptions {
+    fn fmt_fields(&self, node: &LessImportOptions, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessImportOptionsFields {
+            l_paren_token,
+            options,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_paren_token.format(),
+                options.format(),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinGuard, LessMixinGuardFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinGuard;
+impl FormatNodeRule<LessMixinGuard> for FormatLessMixinGuard {
+    fn fmt_fields(&self, node: &LessMixinGuard, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinGuardFields {
+            when_token,
+            conditions,
+        } = node.as_fields();
+
+        write!(f, [when_token.format(), space(), conditions.format()])
+    }
+}
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinParameter, LessMixinParameterFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinParameter;
+impl FormatNodeRule<LessMixinParameter> for FormatLessMixinParameter {
+    fn fmt_fields(&self, node: &LessMixinParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinParameterFields { name, value } = node.as_fields();
+
+        if name.is_some() {
+            write!(f, [name.format(), space()])?;
+        }
+
+        write!(f, [value.format()])
+    }
+}
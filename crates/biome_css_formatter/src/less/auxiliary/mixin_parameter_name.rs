@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinParameterName, LessMixinParameterNameFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinParameterName;
+impl FormatNodeRule<LessMixinParameterName> for FormatLessMixinParameterName {
+    fn fmt_fields(&self, node: &LessMixinParameterName, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinParameterNameFields { name, colon_token } = node.as_fields();
+
+        write!(f, [name.format(), colon_token.format()])
+    }
+}
@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod declaration;
+pub(crate) mod escaped_string;
+pub(crate) mod extend_declaration;
+pub(crate) mod extend_target;
+pub(crate) mod import_options;
+pub(crate) mod mixin_call;
+pub(crate) mod mixin_declaration;
+pub(crate) mod mixin_guard;
+pub(crate) mod mixin_parameter;
+pub(crate) mod mixin_parameter_name;
+pub(crate) mod mixin_parameters;
+pub(crate) mod mixin_semicolon_parameters;
+pub(crate) mod operator;
+pub(crate) mod parenthesized_expression;
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessParenthesizedExpression, LessParenthesizedExpressionFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessParenthesizedExpression;
+impl FormatNodeRule<LessParenthesizedExpression> for FormatLessParenthesizedExpression {
+    fn fmt_fields(
+        &self,
+        node: &LessParenthesizedExpression,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let LessParenthesizedExpressionFields {
+            l_paren_token,
+            value,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [group(&format_args![
+                l_paren_token.format(),
+                soft_block_indent(&value.format()),
+                r_paren_token.format()
+            ])]
+        )
+    }
+}
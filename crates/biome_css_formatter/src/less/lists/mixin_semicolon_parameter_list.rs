@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use biome_css_syntax::LessMixinSemicolonParameterList;
+use biome_formatter::separated::TrailingSeparator;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinSemicolonParameterList;
+impl FormatRule<LessMixinSemicolonParameterList> for FormatLessMixinSemicolonParameterList {
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &LessMixinSemicolonParameterList,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        // The trailing semicolon is what makes a single parameter semicolon separated,
+        // e.g. `.mixin(1, 2;)` has a single parameter, so it must be kept.
+        let trailing_separator = if node.len() == 1 && node.trailing_separator().is_some() {
+            TrailingSeparator::Mandatory
+        } else {
+            TrailingSeparator::Omit
+        };
+
+        let separator = soft_line_break_or_space();
+        let mut joiner = f.join_with(&separator);
+
+        for formatted in node
+            .format_separated(";")
+            .with_trailing_separator(trailing_separator)
+        {
+            joiner.entry(&formatted);
+        }
+
+        joiner.finish()
+    }
+}
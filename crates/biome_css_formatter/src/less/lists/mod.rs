@@ -0,0 +1,6 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod extend_target_list;
+pub(crate) mod import_option_list;
+pub(crate) mod mixin_parameter_list;
+pub(crate) mod mixin_semicolon_parameter_list;
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessPseudoClassExtend, LessPseudoClassExtendFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessPseudoClassExtend;
+impl FormatNodeRule<LessPseudoClassExtend> for FormatLessPseudoClassExtend {
+    fn fmt_fields(&self, node: &LessPseudoClassExtend, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessPseudoClassExtendFields {
+            extend_token,
+            l_paren_token,
+            targets,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                group(&format_args![
+                    l_paren_token.format(),
+                    soft_block_indent(&targets.format()),
+                    r_paren_token.format()
+                ])
+            ]
+        )
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessPluginAtRule, LessPluginAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessPluginAtRule;
+impl FormatNodeRule<LessPluginAtRule> for FormatLessPluginAtRule {
+    fn fmt_fields(&self, node: &LessPluginAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessPluginAtRuleFields {
+            plugin_token,
+            path,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                plugin_token.format(),
+                space(),
+                path.format(),
+                semicolon_token.format()
+            ]
+        )
+    }
+}
@@ -3,7 +3,9 @@ pub mod context;
 mod css;
 mod cst;
 mod generated;
+mod less;
 mod prelude;
+mod scss;
 mod separated;
 mod utils;
 
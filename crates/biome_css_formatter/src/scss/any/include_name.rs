@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyScssIncludeName;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyScssIncludeName;
+impl FormatRule<AnyScssIncludeName> for FormatAnyScssIncludeName {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyScssIncludeName, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyScssIncludeName::CssCustomIdentifier(node) => node.format().fmt(f),
+            AnyScssIncludeName::ScssQualifiedName(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod else_clause_body;
+pub(crate) mod include_name;
+pub(crate) mod module_member;
+pub(crate) mod use_namespace;
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssArgument, ScssArgumentFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssArgument;
+impl FormatNodeRule<ScssArgument> for FormatScssArgument {
+    fn fmt_fields(&self, node: &ScssArgument, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssArgumentFields { name, value } = node.as_fields();
+
+        if name.is_some() {
+            write!(f, [name.format(), space()])?;
+        }
+
+        write!(f, [value.format()])
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssArgumentName, ScssArgumentNameFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssArgumentName;
+impl FormatNodeRule<ScssArgumentName> for FormatScssArgumentName {
+    fn fmt_fields(&self, node: &ScssArgumentName, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssArgumentNameFields { name, colon_token } = node.as_fields();
+
+        write!(f, [name.format(), colon_token.format()])
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssExtendOptional, ScssExtendOptionalFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssExtendOptional;
+impl FormatNodeRule<ScssExtendOptional> for FormatScssExtendOptional {
+    fn fmt_fields(&self, node: &ScssExtendOptional, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssExtendOptionalFields {
+            excl_token,
+            optional_token,
+        } = node.as_fields();
+
+        write!(f, [excl_token.format(), optional_token.format()])
+    }
+}
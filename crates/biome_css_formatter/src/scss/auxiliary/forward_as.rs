@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssForwardAs, ScssForwardAsFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssForwardAs;
+impl FormatNodeRule<ScssForwardAs> for FormatScssForwardAs {
+    fn fmt_fields(&self, node: &ScssForwardAs, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssForwardAsFields {
+            as_token,
+            prefix,
+            star_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                as_token.format(),
+                space(),
+                prefix.format(),
+                star_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,23 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod argument;
+pub(crate) mod argument_name;
+pub(crate) mod declaration;
+pub(crate) mod else_clause;
+pub(crate) mod extend_optional;
+pub(crate) mod forward_as;
+pub(crate) mod forward_visibility;
+pub(crate) mod include_arguments;
+pub(crate) mod interpolation;
+pub(crate) mod map_expression;
+pub(crate) mod map_expression_pair;
+pub(crate) mod module_configuration;
+pub(crate) mod operator;
+pub(crate) mod parameter;
+pub(crate) mod parameter_default;
+pub(crate) mod parameters;
+pub(crate) mod parenthesized_expression;
+pub(crate) mod qualified_name;
+pub(crate) mod use_all_namespace;
+pub(crate) mod use_as;
+pub(crate) mod variable_modifier;
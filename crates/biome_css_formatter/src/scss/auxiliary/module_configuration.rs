@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssModuleConfiguration, ScssModuleConfigurationFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssModuleConfiguration;
+impl FormatNodeRule<ScssModuleConfiguration> for FormatScssModuleConfiguration {
+    fn fmt_fields(&self, node: &ScssModuleConfiguration, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssModuleConfigurationFields { with_token, value } = node.as_fields();
+
+        write!(f, [with_token.format(), space(), value.format()])
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssParameterDefault, ScssParameterDefaultFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssParameterDefault;
+impl FormatNodeRule<ScssParameterDefault> for FormatScssParameterDefault {
+    fn fmt_fields(&self, node: &ScssParameterDefault, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssParameterDefaultFields { colon_token, value } = node.as_fields();
+
+        write!(f, [colon_token.format(), space(), value.format()])
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssParameters, ScssParametersFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssParameters;
+impl FormatNodeRule<ScssParameters> for FormatScssParameters {
+    fn fmt_fields(&self, node: &ScssParameters, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssParametersFields {
+            l_paren_token,
+            items,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [group(&format_args![
+                l_paren_token.format(),
+                soft_block_indent(&items.format()),
+                r_paren_token.format()
+            ])]
+        )
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssUseAs, ScssUseAsFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssUseAs;
+impl FormatNodeRule<ScssUseAs> for FormatScssUseAs {
+    fn fmt_fields(&self, node: &ScssUseAs, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssUseAsFields {
+            as_token,
+            namespace,
+        } = node.as_fields();
+
+        write!(f, [as_token.format(), space(), namespace.format()])
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssExtendAtRule, ScssExtendAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssExtendAtRule;
+impl FormatNodeRule<ScssExtendAtRule> for FormatScssExtendAtRule {
+    fn fmt_fields(&self, node: &ScssExtendAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssExtendAtRuleFields {
+            extend_token,
+            selectors,
+            optional,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                space(),
+                group(&indent(&selectors.format()))
+            ]
+        )?;
+
+        if optional.is_some() {
+            write!(f, [space(), optional.format()])?;
+        }
+
+        write!(f, [semicolon_token.format()])
+    }
+}
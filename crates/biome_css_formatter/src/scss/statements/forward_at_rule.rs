@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssForwardAtRule, ScssForwardAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssForwardAtRule;
+impl FormatNodeRule<ScssForwardAtRule> for FormatScssForwardAtRule {
+    fn fmt_fields(&self, node: &ScssForwardAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssForwardAtRuleFields {
+            forward_token,
+            url,
+            prefix,
+            visibility,
+            configuration,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(f, [forward_token.format(), space(), url.format()])?;
+
+        if prefix.is_some() {
+            write!(f, [space(), prefix.format()])?;
+        }
+
+        if visibility.is_some() {
+            write!(f, [space(), visibility.format()])?;
+        }
+
+        if configuration.is_some() {
+            write!(f, [space(), configuration.format()])?;
+        }
+
+        write!(f, [semicolon_token.format()])
+    }
+}
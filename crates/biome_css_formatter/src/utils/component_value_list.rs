@@ -1,5 +1,5 @@
 use crate::comments::CssComments;
-use biome_css_syntax::CssLanguage;
+use biome_css_syntax::{CssLanguage, CssSyntaxKind};
 use biome_formatter::FormatResult;
 use biome_formatter::{write, CstFormatContext};
 
@@ -17,9 +17,20 @@ where
     match layout {
         ValueListLayout::Fill => {
             let values = format_with(|f: &mut Formatter<'_, CssFormatContext>| {
-                f.fill()
-                    .entries(&soft_line_break_or_space(), node.iter().formatted())
-                    .finish()
+                let mut fill = f.fill();
+
+                for adjacent in group_adjacent_values(node) {
+                    fill.entry(
+                        &soft_line_break_or_space(),
+                        &format_with(|f: &mut CssFormatter| {
+                            f.join()
+                                .entries(adjacent.iter().cloned().formatted())
+                                .finish()
+                        }),
+                    );
+                }
+
+                fill.finish()
             });
 
             write!(f, [group(&indent(&values))])
@@ -29,6 +40,56 @@ where
     }
 }
 
+/// Splits the values of the list into groups that must be printed without any
+/// space between their members, because a space would change their meaning in
+/// SCSS: `#{$gutter}px` is a single value, while `#{$gutter} px` is a list, and
+/// `-$width` negates the variable, while `- $width` subtracts from the
+/// previous value. The same goes for `-@width` in Less.
+///
+/// Values are only kept together when they are written next to each other in
+/// the source, so plain CSS values are always printed one per group.
+fn group_adjacent_values<N, I>(node: &N) -> Vec<Vec<I>>
+where
+    N: AstNodeList<Language = CssLanguage, Node = I> + AstNode<Language = CssLanguage>,
+    I: AstNode<Language = CssLanguage>,
+{
+    let mut groups: Vec<Vec<I>> = Vec::new();
+    // Whether the previous value was separated from its own predecessor.
+    let mut previous_is_detached = true;
+
+    for value in node.iter() {
+        let previous = groups.last().and_then(|group| group.last());
+        let is_detached = previous.map_or(true, |previous| {
+            previous.syntax().text_trimmed_range().end()
+                != value.syntax().text_trimmed_range().start()
+        });
+
+        let keep_together = match previous {
+            Some(previous) if !is_detached => {
+                let previous_kind = previous.syntax().kind();
+
+                previous_kind == CssSyntaxKind::SCSS_INTERPOLATION
+                    || value.syntax().kind() == CssSyntaxKind::SCSS_INTERPOLATION
+                    // A unary operator, e.g. the minus of `margin: 0 -$gap`.
+                    || (matches!(
+                        previous_kind,
+                        CssSyntaxKind::SCSS_OPERATOR | CssSyntaxKind::LESS_OPERATOR
+                    ) && previous_is_detached)
+            }
+            _ => false,
+        };
+
+        match groups.last_mut() {
+            Some(group) if keep_together => group.push(value),
+            _ => groups.push(vec![value]),
+        }
+
+        previous_is_detached = is_detached;
+    }
+
+    groups
+}
+
 #[derive(Copy, Clone, Debug)]
 pub(crate) enum ValueListLayout {
     /// Tries to fit as many values on a single line as possible, then wraps
@@ -12,7 +12,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Default)]
 pub struct CssTestFormatLanguage {
-    _source_type: CssFileSource,
+    source_type: CssFileSource,
+}
+
+impl CssTestFormatLanguage {
+    pub fn new(source_type: CssFileSource) -> Self {
+        CssTestFormatLanguage { source_type }
+    }
 }
 
 impl TestFormatLanguage for CssTestFormatLanguage {
@@ -22,7 +28,16 @@ impl TestFormatLanguage for CssTestFormatLanguage {
     type FormatLanguage = CssFormatLanguage;
 
     fn parse(&self, text: &str) -> AnyParse {
-        let parse = parse_css(text, CssParserOptions::default());
+        let options = if self.source_type.is_scss() {
+            CssParserOptions::default().allow_scss_syntax()
+        } else if self.source_type.is_less() {
+            CssParserOptions::default().allow_less_syntax()
+        } else if self.source_type.is_css_modules() {
+            CssParserOptions::default().allow_css_modules()
+        } else {
+            CssParserOptions::default()
+        };
+        let parse = parse_css(text, options);
 
         AnyParse::new(parse.syntax().as_send().unwrap(), parse.into_diagnostics())
     }
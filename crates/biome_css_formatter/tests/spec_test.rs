@@ -1,4 +1,5 @@
 use biome_css_formatter::context::CssFormatOptions;
+use biome_css_syntax::CssFileSource;
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use std::path::Path;
 
@@ -23,6 +24,8 @@ mod language {
 ///
 /// * `css/null` -> input: `tests/specs/css/null.css`, expected output: `tests/specs/css/null.css.snap`
 /// * `null` -> input: `tests/specs/null.css`, expected output: `tests/specs/null.css.snap`
+/// * `scss/null` -> input: `tests/specs/scss/null.scss`, expected output: `tests/specs/scss/null.scss.snap`
+/// * `less/null` -> input: `tests/specs/less/null.less`, expected output: `tests/specs/less/null.less.snap`
 pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
     let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
 
@@ -30,8 +33,9 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
         return;
     };
 
-    let options = CssFormatOptions::default();
-    let language = language::CssTestFormatLanguage::default();
+    let source_type: CssFileSource = test_file.input_file().as_path().try_into().unwrap();
+    let options = CssFormatOptions::new(source_type);
+    let language = language::CssTestFormatLanguage::new(source_type);
 
     let snapshot = SpecSnapshot::new(test_file, test_directory, language, options);
 
@@ -494,6 +494,8 @@ impl<'src> CssLexer<'src> {
                 self.advance(1);
                 self.consume_byte(T!["$="])
             }
+            // SCSS variables, e.g. `$primary-color`
+            IDT if current == b'$' && self.config.allow_scss_syntax => self.consume_byte(T!["$"]),
             IDT | UNI | BSL if self.is_ident_start() => self.consume_identifier(),
 
             MUL => self.consume_mul(),
@@ -512,7 +514,15 @@ impl<'src> CssLexer<'src> {
             MOR => self.consume_mor(),
             TLD => self.consume_tilde(),
             PIP => self.consume_pipe(),
+            EQL if self.config.allow_scss_syntax && self.peek_byte() == Some(b'=') => {
+                self.advance(1);
+                self.consume_byte(T![==])
+            }
             EQL => self.consume_byte(T![=]),
+            EXL if self.config.allow_scss_syntax && self.peek_byte() == Some(b'=') => {
+                self.advance(1);
+                self.consume_byte(T![!=])
+            }
             EXL => self.consume_byte(T![!]),
             PRC => self.consume_byte(T![%]),
             Dispatch::AMP => self.consume_byte(T![&]),
@@ -900,6 +910,31 @@ impl<'src> CssLexer<'src> {
             b"ridge" => RIDGE_KW,
             b"inset" => INSET_KW,
             b"outset" => OUTSET_KW,
+            // SCSS keywords
+            b"use" => USE_KW,
+            b"forward" => FORWARD_KW,
+            b"as" => AS_KW,
+            b"with" => WITH_KW,
+            b"show" => SHOW_KW,
+            b"hide" => HIDE_KW,
+            b"mixin" => MIXIN_KW,
+            b"include" => INCLUDE_KW,
+            b"content" => CONTENT_KW,
+            b"extend" => EXTEND_KW,
+            b"optional" => OPTIONAL_KW,
+            b"if" => IF_KW,
+            b"else" => ELSE_KW,
+            b"each" => EACH_KW,
+            b"for" => FOR_KW,
+            b"through" => THROUGH_KW,
+            b"while" => WHILE_KW,
+            // Less keywords
+            b"when" => WHEN_KW,
+            b"all" => ALL_KW,
+            b"plugin" => PLUGIN_KW,
+            // CSS Modules keywords
+            b"composes" => COMPOSES_KW,
+            b"value" => VALUE_KW,
             // CSS-Wide keywords
             b"initial" => INITIAL_KW,
             b"inherit" => INHERIT_KW,
@@ -1205,7 +1240,11 @@ impl<'src> CssLexer<'src> {
                     COMMENT
                 }
             }
-            Some(b'/') if self.config.allow_wrong_line_comments => {
+            Some(b'/')
+                if self.config.allow_wrong_line_comments
+                    || self.config.allow_scss_syntax
+                    || self.config.allow_less_syntax =>
+            {
                 self.advance(2);
 
                 while let Some(chr) = self.current_byte() {
@@ -1323,6 +1362,11 @@ impl<'src> CssLexer<'src> {
             }
         }
 
+        // -$variable, the minus is a separate token in SCSS.
+        if self.config.allow_scss_syntax && self.peek_byte() == Some(b'$') {
+            return self.consume_byte(T![-]);
+        }
+
         // -identifier
         if self.is_ident_start() {
             return self.consume_identifier();
@@ -14,9 +14,8 @@ use std::time::Duration;
 // Assert the result of lexing a piece of source code,
 // and make sure the tokens yielded are fully lossless and the source can be reconstructed from only the tokens
 macro_rules! assert_lex {
-    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
-        let config = CssParserOptions::default().allow_wrong_line_comments();
-        let mut lexer = CssLexer::from_str($src).with_config(config);
+    (@with_config $config:expr, $src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
+        let mut lexer = CssLexer::from_str($src).with_config($config);
         let mut idx = 0;
         let mut tok_idx = TextSize::default();
 
@@ -64,6 +63,13 @@ macro_rules! assert_lex {
 
         assert_eq!($src, new_str, "Failed to reconstruct input");
     }};
+    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex!(
+            @with_config CssParserOptions::default().allow_wrong_line_comments(),
+            $src,
+            $($kind:$len,)*
+        )
+    };
 }
 
 // This is for testing if the lexer is truly lossless
@@ -408,6 +414,71 @@ fn wrong_line_comments() {
     }
 }
 
+#[test]
+fn scss_tokens() {
+    assert_lex! {
+        @with_config CssParserOptions::default().allow_scss_syntax(),
+        "$width == 10px // comment",
+        DOLLAR:1,
+        IDENT:5,
+        WHITESPACE:1,
+        EQ2:2,
+        WHITESPACE:1,
+        CSS_DIMENSION_VALUE:2,
+        PX_KW:2,
+        WHITESPACE:1,
+        COMMENT:10
+    }
+
+    assert_lex! {
+        @with_config CssParserOptions::default().allow_scss_syntax(),
+        "[href$=a]",
+        L_BRACK:1,
+        IDENT:4,
+        DOLLAR_EQ:2,
+        IDENT:1,
+        R_BRACK:1
+    }
+
+    assert_lex! {
+        @with_config CssParserOptions::default().allow_scss_syntax(),
+        "-$gap != 0",
+        MINUS:1,
+        DOLLAR:1,
+        IDENT:3,
+        WHITESPACE:1,
+        NEQ:2,
+        WHITESPACE:1,
+        CSS_NUMBER_LITERAL:1
+    }
+}
+
+#[test]
+fn less_tokens() {
+    assert_lex! {
+        @with_config CssParserOptions::default().allow_less_syntax(),
+        "@gutter-width: -@a // comment",
+        AT:1,
+        IDENT:12,
+        COLON:1,
+        WHITESPACE:1,
+        MINUS:1,
+        AT:1,
+        IDENT:1,
+        WHITESPACE:1,
+        COMMENT:10
+    }
+
+    assert_lex! {
+        @with_config CssParserOptions::default().allow_less_syntax(),
+        "~\"a\" when",
+        TILDE:1,
+        CSS_STRING_LITERAL:3,
+        WHITESPACE:1,
+        WHEN_KW:4
+    }
+}
+
 #[test]
 fn block_comment() {
     assert_lex! {
@@ -17,6 +17,27 @@ mod state;
 mod syntax;
 mod token_source;
 
+pub(crate) enum CssSyntaxFeatures {
+    /// Enable support for the SCSS syntax
+    Scss,
+    /// Enable support for the Less syntax
+    Less,
+    /// Enable support for CSS Modules
+    CssModules,
+}
+
+impl SyntaxFeature for CssSyntaxFeatures {
+    type Parser<'source> = CssParser<'source>;
+
+    fn is_supported(&self, p: &CssParser) -> bool {
+        match self {
+            CssSyntaxFeatures::Scss => p.options().allow_scss_syntax,
+            CssSyntaxFeatures::Less => p.options().allow_less_syntax,
+            CssSyntaxFeatures::CssModules => p.options().css_modules,
+        }
+    }
+}
+
 pub(crate) type CssLosslessTreeSink<'source> =
     LosslessTreeSink<'source, CssLanguage, CssSyntaxFactory>;
 
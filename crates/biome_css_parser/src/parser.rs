@@ -12,11 +12,21 @@ pub(crate) struct CssParser<'source> {
     context: ParserContext<CssSyntaxKind>,
     source: CssTokenSource<'source>,
     state: CssParserState,
+    options: CssParserOptions,
 }
 
 #[derive(Default, Debug, Clone)]
 pub struct CssParserOptions {
     pub allow_wrong_line_comments: bool,
+    /// Enables the SCSS syntax: variables, mixins, placeholders, control
+    /// directives, interpolations and `//` comments.
+    pub allow_scss_syntax: bool,
+    /// Enables the Less syntax: variables, mixins and guards, `:extend`,
+    /// operations, escapes and `//` comments.
+    pub allow_less_syntax: bool,
+    /// Enables the syntax of [CSS Modules](https://github.com/css-modules/css-modules):
+    /// the `composes` property and the `@value` at-rule.
+    pub css_modules: bool,
 }
 
 impl CssParserOptions {
@@ -24,17 +34,37 @@ impl CssParserOptions {
         self.allow_wrong_line_comments = true;
         self
     }
+
+    pub fn allow_scss_syntax(mut self) -> Self {
+        self.allow_scss_syntax = true;
+        self
+    }
+
+    pub fn allow_less_syntax(mut self) -> Self {
+        self.allow_less_syntax = true;
+        self
+    }
+
+    pub fn allow_css_modules(mut self) -> Self {
+        self.css_modules = true;
+        self
+    }
 }
 
 impl<'source> CssParser<'source> {
     pub fn new(source: &'source str, config: CssParserOptions) -> Self {
         Self {
             context: ParserContext::default(),
-            source: CssTokenSource::from_str(source, config),
+            source: CssTokenSource::from_str(source, config.clone()),
             state: CssParserState::new(),
+            options: config,
         }
     }
 
+    pub(crate) fn options(&self) -> &CssParserOptions {
+        &self.options
+    }
+
     /// Re-lexes the current token in the specified context. Returns the kind
     /// of the re-lexed token (can be the same as before if the context doesn't make a difference for the current token)
     #[allow(dead_code)] //TODO remote this once we actually don't use it
@@ -63,7 +63,7 @@ impl ParseSeparatedList for MediaQueryList {
     ) -> RecoveryResult {
         parsed_element.or_recover_with_token_set(
             p,
-            &ParseRecoveryTokenSet::new(CSS_BOGUS_MEDIA_QUERY, token_set!(T![,], T!['{'])),
+            &ParseRecoveryTokenSet::new(CSS_BOGUS_MEDIA_QUERY, token_set!(T![,], T!['{'], T![;])),
             expected_media_query,
         )
     }
@@ -77,10 +77,12 @@ impl ParseSeparatedList for MediaQueryList {
 fn parse_any_media_query(p: &mut CssParser) -> ParsedSyntax {
     if is_at_media_type_query(p) {
         parse_any_media_type_query(p)
-    } else {
+    } else if is_at_any_media_condition(p) {
         let m = p.start();
         parse_any_media_condition(p).ok(); // TODO handle error
         Present(m.complete(p, CSS_MEDIA_CONDITION_QUERY))
+    } else {
+        Absent
     }
 }
 
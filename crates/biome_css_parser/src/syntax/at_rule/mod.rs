@@ -18,6 +18,7 @@ mod property;
 mod scope;
 mod starting_style;
 mod supports;
+mod value;
 
 use crate::parser::CssParser;
 use crate::syntax::at_rule::charset::{is_at_charset_at_rule, parse_charset_at_rule};
@@ -47,12 +48,18 @@ use crate::syntax::at_rule::starting_style::{
     is_at_starting_style_at_rule, parse_starting_style_at_rule,
 };
 use crate::syntax::at_rule::supports::{is_at_supports_at_rule, parse_supports_at_rule};
+use crate::syntax::at_rule::value::{is_at_value_at_rule, parse_value_at_rule};
+use crate::syntax::less::{is_at_less_at_rule, parse_less_at_rule};
 use crate::syntax::parse_error::expected_any_at_rule;
+use crate::syntax::scss::{is_at_scss_at_rule, parse_scss_at_rule};
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::T;
 use biome_parser::prelude::ParsedSyntax::{Absent, Present};
 use biome_parser::prelude::*;
 
+pub(crate) use import::{is_at_import_url, parse_import_url};
+pub(crate) use media::MediaQueryList;
+
 use self::property::{is_at_property_at_rule, parse_property_at_rule};
 
 #[inline]
@@ -110,6 +117,8 @@ pub(crate) fn parse_any_at_rule(p: &mut CssParser) -> ParsedSyntax {
         parse_scope_at_rule(p)
     } else if is_at_supports_at_rule(p) {
         parse_supports_at_rule(p)
+    } else if is_at_less_at_rule(p) {
+        parse_less_at_rule(p)
     } else if is_at_import_at_rule(p) {
         parse_import_at_rule(p)
     } else if is_at_namespace_at_rule(p) {
@@ -120,6 +129,10 @@ pub(crate) fn parse_any_at_rule(p: &mut CssParser) -> ParsedSyntax {
         parse_document_at_rule(p)
     } else if is_at_property_at_rule(p) {
         parse_property_at_rule(p)
+    } else if is_at_value_at_rule(p) {
+        parse_value_at_rule(p)
+    } else if is_at_scss_at_rule(p) {
+        parse_scss_at_rule(p)
     } else {
         Absent
     }
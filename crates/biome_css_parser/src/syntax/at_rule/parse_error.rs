@@ -82,3 +82,17 @@ pub(crate) fn expected_any_font_feature_value_item(
     )
     .into_diagnostic(p)
 }
+
+pub(crate) fn expected_value_at_rule_import_specifier(
+    p: &CssParser,
+    range: TextRange,
+) -> ParseDiagnostic {
+    expected_node("value name", range, p)
+}
+
+pub(crate) fn expected_any_value_at_rule_import_source(
+    p: &CssParser,
+    range: TextRange,
+) -> ParseDiagnostic {
+    expect_one_of(&["identifier", "string"], range).into_diagnostic(p)
+}
@@ -0,0 +1,139 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::at_rule::parse_error::{
+    expected_any_value_at_rule_import_source, expected_value_at_rule_import_specifier,
+};
+use crate::syntax::parse_error::expected_identifier;
+use crate::syntax::property::GenericComponentValueList;
+use crate::syntax::{is_at_identifier, is_at_string, parse_custom_identifier, parse_string};
+use crate::CssSyntaxFeatures;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::parsed_syntax::ParsedSyntax::Present;
+use biome_parser::prelude::ParsedSyntax::Absent;
+use biome_parser::{parsed_syntax::ParsedSyntax, token_set, Parser, SyntaxFeature};
+
+/// Checks if the current token in the parser is a `@value` at-rule of CSS Modules.
+#[inline]
+pub(crate) fn is_at_value_at_rule(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::CssModules.is_supported(p) && p.at(T![value])
+}
+
+/// Parses a `@value` at-rule, which either defines a value or imports
+/// values from another module.
+/// For details, see [CSS Modules Values](https://github.com/css-modules/postcss-modules-values).
+/// # Examples
+/// ```css
+/// @value primary: #bf4040;
+/// @value primary as brand, secondary from "./colors.module.css";
+/// ```
+#[inline]
+pub(crate) fn parse_value_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_value_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![value]);
+
+    if is_at_value_at_rule_declaration_clause(p) {
+        parse_value_at_rule_declaration_clause(p).ok();
+    } else {
+        parse_value_at_rule_import_clause(p).ok();
+    }
+
+    p.expect(T![;]);
+
+    Present(m.complete(p, CSS_VALUE_AT_RULE))
+}
+
+#[inline]
+fn is_at_value_at_rule_declaration_clause(p: &mut CssParser) -> bool {
+    is_at_identifier(p) && p.nth_at(1, T![:])
+}
+
+#[inline]
+fn parse_value_at_rule_declaration_clause(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_value_at_rule_declaration_clause(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_custom_identifier(p, CssLexContext::Regular).ok();
+    p.bump(T![:]);
+    GenericComponentValueList.parse_list(p);
+
+    Present(m.complete(p, CSS_VALUE_AT_RULE_DECLARATION_CLAUSE))
+}
+
+#[inline]
+fn parse_value_at_rule_import_clause(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+
+    ValueAtRuleImportSpecifierList.parse_list(p);
+    p.expect(T![from]);
+
+    if is_at_string(p) {
+        parse_string(p).ok();
+    } else {
+        parse_custom_identifier(p, CssLexContext::Regular)
+            .or_add_diagnostic(p, expected_any_value_at_rule_import_source);
+    }
+
+    Present(m.complete(p, CSS_VALUE_AT_RULE_IMPORT_CLAUSE))
+}
+
+struct ValueAtRuleImportSpecifierList;
+
+impl ParseSeparatedList for ValueAtRuleImportSpecifierList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = CSS_VALUE_AT_RULE_IMPORT_SPECIFIER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_value_at_rule_import_specifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![from]) || p.at(T![;])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![from], T![;]]),
+            expected_value_at_rule_import_specifier,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
+
+#[inline]
+fn parse_value_at_rule_import_specifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_identifier(p) || p.at(T![from]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_custom_identifier(p, CssLexContext::Regular).ok();
+
+    if p.eat(T![as]) {
+        parse_custom_identifier(p, CssLexContext::Regular)
+            .or_add_diagnostic(p, expected_identifier);
+        Present(m.complete(p, CSS_VALUE_AT_RULE_NAMED_IMPORT_SPECIFIER))
+    } else {
+        Present(m.complete(p, CSS_VALUE_AT_RULE_IMPORT_SPECIFIER))
+    }
+}
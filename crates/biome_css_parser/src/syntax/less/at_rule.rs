@@ -0,0 +1,180 @@
+use crate::parser::CssParser;
+use crate::syntax::at_rule::{is_at_import_url, parse_import_url, MediaQueryList};
+use crate::syntax::less::parse_error::{expected_less_import_option, expected_less_import_url};
+use crate::syntax::parse_error::expected_string;
+use crate::syntax::{is_at_identifier, parse_regular_identifier, parse_string};
+use crate::CssSyntaxFeatures;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, SyntaxFeature, TokenSet};
+
+/// The options accepted by a Less `@import`.
+pub(super) const LESS_IMPORT_OPTION_NAMES: [&str; 7] = [
+    "reference",
+    "inline",
+    "less",
+    "css",
+    "once",
+    "multiple",
+    "optional",
+];
+
+#[inline]
+pub(crate) fn is_at_less_at_rule(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p)
+        && (is_at_less_plugin_at_rule(p) || is_at_less_import_at_rule(p))
+}
+
+/// Parses the Less at-rules after the `@` token.
+///
+/// They are checked before the CSS at-rules, because a Less `@import` starts
+/// like a CSS one.
+#[inline]
+pub(crate) fn parse_less_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_at_rule(p) {
+        return Absent;
+    }
+
+    if is_at_less_plugin_at_rule(p) {
+        parse_less_plugin_at_rule(p)
+    } else {
+        parse_less_import_at_rule(p)
+    }
+}
+
+#[inline]
+fn is_at_less_plugin_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![plugin])
+}
+
+/// Parses a `@plugin` rule, which loads a JavaScript plugin.
+///
+/// ```less
+/// @plugin "my-plugin";
+/// ```
+#[inline]
+fn parse_less_plugin_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_plugin_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![plugin]);
+
+    let kind = if parse_string(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, LESS_PLUGIN_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_string,
+        )
+        .is_ok()
+    {
+        LESS_PLUGIN_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    p.expect(T![;]);
+
+    Present(m.complete(p, kind))
+}
+
+const LESS_PLUGIN_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set!(T![;]);
+
+#[inline]
+fn is_at_less_import_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![import]) && p.nth_at(1, T!['('])
+}
+
+/// Parses an `@import` rule with import options.
+///
+/// ```less
+/// @import (reference, optional) "foo.less";
+/// ```
+#[inline]
+fn parse_less_import_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_import_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![import]);
+    parse_less_import_options(p).ok();
+
+    let kind = if is_at_import_url(p) {
+        parse_import_url(p).ok();
+        LESS_IMPORT_AT_RULE
+    } else {
+        p.error(expected_less_import_url(p, p.cur_range()));
+        CSS_BOGUS_AT_RULE
+    };
+
+    MediaQueryList::new(T![;]).parse_list(p);
+    p.expect(T![;]);
+
+    Present(m.complete(p, kind))
+}
+
+#[inline]
+fn parse_less_import_options(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    LessImportOptionList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, LESS_IMPORT_OPTIONS))
+}
+
+struct LessImportOptionList;
+
+impl ParseSeparatedList for LessImportOptionList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = LESS_IMPORT_OPTION_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if is_at_less_import_option(p) {
+            parse_regular_identifier(p)
+        } else {
+            Absent
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        // An unclosed list of options ends at the imported url
+        p.at(T![')']) || is_at_import_url(p)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![')'], T![;]]),
+            expected_less_import_option,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
+
+#[inline]
+fn is_at_less_import_option(p: &mut CssParser) -> bool {
+    is_at_identifier(p) && LESS_IMPORT_OPTION_NAMES.contains(&p.cur_text())
+}
@@ -0,0 +1,93 @@
+use crate::parser::CssParser;
+use crate::syntax::less::selector::parse_less_pseudo_class_extend;
+use crate::syntax::less::value::{is_at_less_identifier, parse_less_identifier};
+use crate::syntax::property::GenericComponentValueList;
+use crate::CssSyntaxFeatures;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, SyntaxFeature};
+
+/// `@page :first` is the only at-rule that can be followed by a colon.
+#[inline]
+pub(crate) fn is_at_less_declaration(p: &mut CssParser) -> bool {
+    is_at_less_identifier(p) && p.nth_at(2, T![:]) && !p.nth_at(1, T![page])
+}
+
+/// Parses a variable declaration, e.g. `@primary-color: #333;`.
+///
+/// Like regular declarations, the semicolon is optional before a closing brace.
+#[inline]
+pub(crate) fn parse_less_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_less_identifier(p).ok();
+    p.bump(T![:]);
+    GenericComponentValueList.parse_list(p);
+    eat_less_semicolon(p);
+
+    Present(m.complete(p, LESS_DECLARATION))
+}
+
+/// Checks if the parser is at `&:extend(...)` used as a statement inside a
+/// block. The same pseudo-class followed by a block is a regular rule.
+#[inline]
+pub(crate) fn is_at_less_extend_declaration(p: &mut CssParser) -> bool {
+    if !(CssSyntaxFeatures::Less.is_supported(p)
+        && p.at(T![&])
+        && p.nth_at(1, T![:])
+        && p.nth_at(2, T![extend])
+        && p.nth_at(3, T!['(']))
+    {
+        return false;
+    }
+
+    let mut depth = 0;
+    let mut n = 4;
+
+    loop {
+        match p.nth(n) {
+            T!['('] => depth += 1,
+            T![')'] if depth == 0 => return p.nth_at(n + 1, T![;]) || p.nth_at(n + 1, T!['}']),
+            T![')'] => depth -= 1,
+            T!['{'] | T!['}'] | T![;] | EOF => return false,
+            _ => {}
+        }
+        n += 1;
+    }
+}
+
+/// Parses an extension inside a block, e.g. `&:extend(.banner);`.
+#[inline]
+pub(crate) fn parse_less_extend_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_extend_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![&]);
+    p.bump(T![:]);
+    parse_less_pseudo_class_extend(p).ok();
+    eat_less_semicolon(p);
+
+    Present(m.complete(p, LESS_EXTEND_DECLARATION))
+}
+
+/// Eats the semicolon ending a statement, which is optional before a closing brace.
+#[inline]
+pub(crate) fn eat_less_semicolon(p: &mut CssParser) {
+    if !p.at(T!['}']) {
+        if p.nth_at(1, T!['}']) {
+            p.eat(T![;]);
+        } else {
+            p.expect(T![;]);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::less::declaration::eat_less_semicolon;
+use crate::syntax::less::parse_error::expected_less_mixin_parameter;
+use crate::syntax::less::value::{
+    is_nth_at_less_identifier, parse_less_identifier, parse_less_name,
+};
+use crate::syntax::parse_error::expected_component_value;
+use crate::syntax::property::{
+    is_at_generic_component_value, parse_generic_component_value, GenericComponentValueList,
+};
+use crate::syntax::{is_nth_at_identifier, parse_declaration_important};
+use crate::CssSyntaxFeatures;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, CompletedMarker, Marker, Parser, SyntaxFeature, TokenSet};
+
+const LESS_MIXIN_CALL_END_SET: TokenSet<CssSyntaxKind> = token_set![T![;], T!['}'], T![!]];
+
+/// A class followed by parentheses is either the declaration or a call of a
+/// mixin, while a class followed by the end of a statement is a call.
+#[inline]
+pub(crate) fn is_at_less_mixin(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p)
+        && p.at(T![.])
+        && is_nth_at_identifier(p, 1)
+        && (p.nth_at(2, T!['(']) || p.nth_at_ts(2, LESS_MIXIN_CALL_END_SET))
+}
+
+/// Parses a mixin declaration, e.g. `.bordered(@width: 2px) { ... }`, or a
+/// mixin call, e.g. `.bordered(4px);`.
+///
+/// Both start the same way, so the kind is only known once the parameters
+/// are parsed: a declaration continues with a guard or a block.
+#[inline]
+pub(crate) fn parse_less_mixin(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_mixin(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![.]);
+    parse_less_name(p).ok();
+    let has_parameters = parse_less_mixin_parameters(p).is_present();
+
+    if has_parameters && (p.at(T![when]) || p.at(T!['{'])) {
+        parse_less_mixin_guard(p).ok();
+        parse_declaration_or_rule_list_block(p);
+
+        return Present(m.complete(p, LESS_MIXIN_DECLARATION));
+    }
+
+    parse_declaration_important(p).ok();
+    eat_less_semicolon(p);
+
+    Present(m.complete(p, LESS_MIXIN_CALL))
+}
+
+#[inline]
+fn parse_less_mixin_parameters(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let (separator, kind) = if is_at_semicolon_separated_parameters(p) {
+        (T![;], LESS_MIXIN_SEMICOLON_PARAMETERS)
+    } else {
+        (T![,], LESS_MIXIN_PARAMETERS)
+    };
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    LessMixinParameterList::new(separator).parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, kind))
+}
+
+/// The parameters are separated by semicolons as soon as one semicolon
+/// appears between the parentheses, e.g. `.mixin(1, 2; 3)` has two parameters.
+#[inline]
+fn is_at_semicolon_separated_parameters(p: &mut CssParser) -> bool {
+    let mut depth = 0;
+    let mut n = 1;
+
+    loop {
+        match p.nth(n) {
+            T!['('] => depth += 1,
+            T![')'] if depth == 0 => return false,
+            T![')'] => depth -= 1,
+            T![;] if depth == 0 => return true,
+            T!['{'] | T!['}'] | EOF => return false,
+            _ => {}
+        }
+        n += 1;
+    }
+}
+
+const LESS_MIXIN_PARAMETER_RECOVERY_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![,], T![;], T![')'], T!['{'], T!['}']];
+
+struct LessMixinParameterList {
+    separator: CssSyntaxKind,
+}
+
+impl LessMixinParameterList {
+    fn new(separator: CssSyntaxKind) -> Self {
+        Self { separator }
+    }
+}
+
+impl ParseSeparatedList for LessMixinParameterList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = LESS_MIXIN_PARAMETER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_less_mixin_parameter(p, self.separator)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, LESS_MIXIN_PARAMETER_RECOVERY_SET),
+            expected_less_mixin_parameter,
+        )
+    }
+
+    fn finish_list(&mut self, p: &mut Self::Parser<'_>, m: Marker) -> CompletedMarker {
+        if self.separator == T![;] {
+            m.complete(p, LESS_MIXIN_SEMICOLON_PARAMETER_LIST)
+        } else {
+            m.complete(p, Self::LIST_KIND)
+        }
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        self.separator
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn is_at_less_mixin_parameter_name(p: &mut CssParser) -> bool {
+    is_nth_at_less_identifier(p, 0) && p.nth_at(2, T![:])
+}
+
+#[inline]
+fn parse_less_mixin_parameter(p: &mut CssParser, separator: CssSyntaxKind) -> ParsedSyntax {
+    if !(is_at_less_mixin_parameter_name(p) || is_at_generic_component_value(p)) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_less_mixin_parameter_name(p).ok();
+    LessMixinParameterValueList::new(separator).parse_list(p);
+
+    Present(m.complete(p, LESS_MIXIN_PARAMETER))
+}
+
+#[inline]
+fn parse_less_mixin_parameter_name(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_mixin_parameter_name(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_less_identifier(p).ok();
+    p.bump(T![:]);
+
+    Present(m.complete(p, LESS_MIXIN_PARAMETER_NAME))
+}
+
+/// The value of a parameter ends at the separator of the parameters, so
+/// commas are part of the value when the parameters are separated by semicolons.
+struct LessMixinParameterValueList {
+    separator: CssSyntaxKind,
+}
+
+impl LessMixinParameterValueList {
+    fn new(separator: CssSyntaxKind) -> Self {
+        Self { separator }
+    }
+}
+
+impl ParseNodeList for LessMixinParameterValueList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = CSS_GENERIC_COMPONENT_VALUE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_generic_component_value(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(self.separator) || p.at_ts(token_set![T![;], T![')'], T!['}']])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                CSS_BOGUS_PROPERTY_VALUE,
+                LESS_MIXIN_PARAMETER_RECOVERY_SET,
+            ),
+            expected_component_value,
+        )
+    }
+}
+
+/// Parses the guard of a mixin, e.g. `when (@mode = dark)`.
+#[inline]
+fn parse_less_mixin_guard(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![when]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![when]);
+    GenericComponentValueList.parse_list(p);
+
+    Present(m.complete(p, LESS_MIXIN_GUARD))
+}
@@ -0,0 +1,21 @@
+//! Parsing of the Less syntax, enabled by [crate::CssSyntaxFeatures::Less].
+
+mod at_rule;
+mod declaration;
+mod mixin;
+mod parse_error;
+mod selector;
+mod value;
+
+pub(crate) use at_rule::{is_at_less_at_rule, parse_less_at_rule};
+pub(crate) use declaration::{
+    is_at_less_declaration, is_at_less_extend_declaration, parse_less_declaration,
+    parse_less_extend_declaration,
+};
+pub(crate) use mixin::{is_at_less_mixin, parse_less_mixin};
+pub(crate) use selector::{is_at_less_pseudo_class_extend, parse_less_pseudo_class_extend};
+pub(crate) use value::{
+    is_at_less_escaped_string, is_at_less_identifier, is_at_less_operator,
+    is_at_less_parenthesized_expression, parse_less_escaped_string, parse_less_identifier,
+    parse_less_operator, parse_less_parenthesized_expression,
+};
@@ -0,0 +1,17 @@
+use crate::parser::CssParser;
+use crate::syntax::less::at_rule::LESS_IMPORT_OPTION_NAMES;
+use biome_parser::diagnostic::{expect_one_of, expected_node, ToDiagnostic};
+use biome_parser::prelude::ParseDiagnostic;
+use biome_rowan::TextRange;
+
+pub(crate) fn expected_less_mixin_parameter(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("parameter", range, p)
+}
+
+pub(crate) fn expected_less_import_option(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expect_one_of(&LESS_IMPORT_OPTION_NAMES, range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_less_import_url(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expect_one_of(&["url()", "string"], range).into_diagnostic(p)
+}
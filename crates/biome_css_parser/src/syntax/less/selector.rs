@@ -0,0 +1,82 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::expected_selector;
+use crate::syntax::selector::{eat_or_recover_selector_function_close_token, parse_selector};
+use crate::CssSyntaxFeatures;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, SyntaxFeature};
+
+#[inline]
+pub(crate) fn is_at_less_pseudo_class_extend(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p) && p.at(T![extend]) && p.nth_at(1, T!['('])
+}
+
+/// Parses the `:extend` pseudo-class, e.g. `.c:extend(.d all) {}`, which
+/// merges the selector with the selectors it extends.
+#[inline]
+pub(crate) fn parse_less_pseudo_class_extend(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_pseudo_class_extend(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![extend]);
+    p.bump(T!['(']);
+
+    let list = LessExtendTargetList.parse_list(p);
+    eat_or_recover_selector_function_close_token(p, list, expected_selector);
+
+    Present(m.complete(p, LESS_PSEUDO_CLASS_EXTEND))
+}
+
+struct LessExtendTargetList;
+
+impl ParseSeparatedList for LessExtendTargetList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = LESS_EXTEND_TARGET_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_less_extend_target(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS_SELECTOR, token_set![T![,], T![')'], T!['{']]),
+            expected_selector,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
+
+/// Parses a selector to extend, optionally followed by `all` to extend
+/// every selector the target appears in.
+#[inline]
+fn parse_less_extend_target(p: &mut CssParser) -> ParsedSyntax {
+    let selector = parse_selector(p);
+
+    if selector.is_absent() {
+        return Absent;
+    }
+
+    let m = selector.precede(p);
+    p.eat(T![all]);
+    Present(m.complete(p, LESS_EXTEND_TARGET))
+}
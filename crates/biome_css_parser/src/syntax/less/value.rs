@@ -0,0 +1,106 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::property::GenericComponentValueList;
+use crate::syntax::{is_nth_at_identifier, parse_custom_identifier_with_keywords, parse_string};
+use crate::CssSyntaxFeatures;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, SyntaxFeature, TokenSet};
+
+#[inline]
+pub(crate) fn is_at_less_identifier(p: &mut CssParser) -> bool {
+    is_nth_at_less_identifier(p, 0)
+}
+
+#[inline]
+pub(crate) fn is_nth_at_less_identifier(p: &mut CssParser, n: usize) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p) && p.nth_at(n, T![@]) && is_nth_at_identifier(p, n + 1)
+}
+
+/// Parses the name of a variable or a mixin. Like in Sass, these names are
+/// case-sensitive and are parsed as custom identifiers.
+#[inline]
+pub(crate) fn parse_less_name(p: &mut CssParser) -> ParsedSyntax {
+    parse_custom_identifier_with_keywords(p, CssLexContext::Regular, true)
+}
+
+/// Parses a Less variable reference, e.g. `@primary-color`.
+#[inline]
+pub(crate) fn parse_less_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![@]);
+    parse_less_name(p).ok();
+
+    Present(m.complete(p, LESS_IDENTIFIER))
+}
+
+#[inline]
+pub(crate) fn is_at_less_parenthesized_expression(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p) && p.at(T!['('])
+}
+
+/// Parses a parenthesized operation, e.g. `(@gutter + 1px)`.
+#[inline]
+pub(crate) fn parse_less_parenthesized_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_parenthesized_expression(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    GenericComponentValueList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, LESS_PARENTHESIZED_EXPRESSION))
+}
+
+#[inline]
+pub(crate) fn is_at_less_escaped_string(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p) && p.at(T![~]) && p.nth_at(1, CSS_STRING_LITERAL)
+}
+
+/// Parses an escaped string, e.g. `~"calc(100% - 10px)"`, which is emitted
+/// without its quotes.
+#[inline]
+pub(crate) fn parse_less_escaped_string(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_escaped_string(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![~]);
+    parse_string(p).ok();
+
+    Present(m.complete(p, LESS_ESCAPED_STRING))
+}
+
+const LESS_OPERATOR_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![+], T![-], T![*], T![=], T![<], T![>], T![<=], T![>=]];
+
+#[inline]
+pub(crate) fn is_at_less_operator(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p) && p.at_ts(LESS_OPERATOR_SET)
+}
+
+/// Parses an arithmetic operator or the comparison operator of a guard,
+/// e.g. `*` in `@width * 2`.
+#[inline]
+pub(crate) fn parse_less_operator(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_operator(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_ts(LESS_OPERATOR_SET);
+    Present(m.complete(p, LESS_OPERATOR))
+}
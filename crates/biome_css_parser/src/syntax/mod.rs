@@ -1,7 +1,9 @@
 mod at_rule;
 mod block;
+mod less;
 mod parse_error;
 mod property;
+mod scss;
 mod selector;
 mod value;
 
@@ -9,8 +11,19 @@ use crate::lexer::CssLexContext;
 use crate::parser::CssParser;
 use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
 use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::less::{
+    is_at_less_declaration, is_at_less_escaped_string, is_at_less_identifier, is_at_less_mixin,
+    is_at_less_parenthesized_expression, parse_less_declaration, parse_less_escaped_string,
+    parse_less_identifier, parse_less_mixin, parse_less_parenthesized_expression,
+};
 use crate::syntax::parse_error::expected_any_rule;
 use crate::syntax::property::{is_at_any_property, parse_any_property};
+use crate::syntax::scss::{
+    is_at_scss_declaration, is_at_scss_identifier, is_at_scss_interpolation,
+    is_at_scss_parenthesized_expression, is_at_scss_qualified_name, parse_scss_declaration,
+    parse_scss_identifier, parse_scss_interpolation, parse_scss_parenthesized_expression,
+    parse_scss_qualified_name,
+};
 use crate::syntax::selector::is_nth_at_selector;
 use crate::syntax::selector::relative_selector::{is_at_relative_selector, RelativeSelectorList};
 use crate::syntax::selector::SelectorList;
@@ -47,7 +60,11 @@ impl RuleList {
 
 #[inline]
 pub(crate) fn is_at_rule_list_element(p: &mut CssParser) -> bool {
-    is_at_at_rule(p) || is_at_qualified_rule(p)
+    is_at_less_declaration(p)
+        || is_at_at_rule(p)
+        || is_at_scss_declaration(p)
+        || is_at_less_mixin(p)
+        || is_at_qualified_rule(p)
 }
 
 struct RuleListParseRecovery;
@@ -68,8 +85,15 @@ impl ParseNodeList for RuleList {
     const LIST_KIND: Self::Kind = CSS_RULE_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        if is_at_at_rule(p) {
+        // Less variables start with `@` like at-rules, e.g. `@width: 10px;`
+        if is_at_less_declaration(p) {
+            parse_less_declaration(p)
+        } else if is_at_at_rule(p) {
             parse_at_rule(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
+        } else if is_at_less_mixin(p) {
+            parse_less_mixin(p)
         } else if is_at_qualified_rule(p) {
             parse_qualified_rule(p)
         } else {
@@ -222,7 +246,7 @@ fn is_at_declaration_important(p: &mut CssParser) -> bool {
 }
 
 #[inline]
-fn parse_declaration_important(p: &mut CssParser) -> ParsedSyntax {
+pub(crate) fn parse_declaration_important(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_declaration_important(p) {
         return Absent;
     }
@@ -234,7 +258,14 @@ fn parse_declaration_important(p: &mut CssParser) -> ParsedSyntax {
 
 #[inline]
 pub(crate) fn is_at_any_value(p: &mut CssParser) -> bool {
-    is_at_any_function(p)
+    is_at_scss_identifier(p)
+        || is_at_scss_qualified_name(p)
+        || is_at_scss_interpolation(p)
+        || is_at_scss_parenthesized_expression(p)
+        || is_at_less_identifier(p)
+        || is_at_less_parenthesized_expression(p)
+        || is_at_less_escaped_string(p)
+        || is_at_any_function(p)
         || is_at_identifier(p)
         || p.at(CSS_STRING_LITERAL)
         || is_at_any_dimension(p)
@@ -246,7 +277,21 @@ pub(crate) fn is_at_any_value(p: &mut CssParser) -> bool {
 
 #[inline]
 pub(crate) fn parse_any_value(p: &mut CssParser) -> ParsedSyntax {
-    if is_at_any_function(p) {
+    if is_at_scss_identifier(p) {
+        parse_scss_identifier(p)
+    } else if is_at_scss_qualified_name(p) {
+        parse_scss_qualified_name(p)
+    } else if is_at_scss_interpolation(p) {
+        parse_scss_interpolation(p)
+    } else if is_at_scss_parenthesized_expression(p) {
+        parse_scss_parenthesized_expression(p)
+    } else if is_at_less_identifier(p) {
+        parse_less_identifier(p)
+    } else if is_at_less_parenthesized_expression(p) {
+        parse_less_parenthesized_expression(p)
+    } else if is_at_less_escaped_string(p) {
+        parse_less_escaped_string(p)
+    } else if is_at_any_function(p) {
         parse_any_function(p)
     } else if is_at_dashed_identifier(p) {
         parse_dashed_identifier(p)
@@ -227,3 +227,7 @@ pub(crate) fn expected_component_value(p: &CssParser, range: TextRange) -> Parse
     )
     .into_diagnostic(p)
 }
+
+pub(crate) fn expected_composes_import_source(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expect_one_of(&["global", "string"], range).into_diagnostic(p)
+}
@@ -1,13 +1,22 @@
+use crate::lexer::CssLexContext;
 use crate::parser::CssParser;
-use crate::syntax::parse_error::expected_component_value;
-use crate::syntax::{is_at_any_value, is_at_identifier, parse_any_value, parse_regular_identifier};
+use crate::syntax::less::{is_at_less_operator, parse_less_operator};
+use crate::syntax::parse_error::{
+    expected_component_value, expected_composes_import_source, expected_identifier,
+};
+use crate::syntax::scss::{is_at_scss_operator, parse_scss_operator};
+use crate::syntax::{
+    is_at_any_value, is_at_identifier, is_at_string, parse_any_value, parse_custom_identifier,
+    parse_regular_identifier, parse_string,
+};
+use crate::CssSyntaxFeatures;
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::{CssSyntaxKind, T};
 use biome_parser::parse_lists::ParseNodeList;
 use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
 use biome_parser::prelude::ParsedSyntax;
 use biome_parser::prelude::ParsedSyntax::{Absent, Present};
-use biome_parser::{token_set, Parser, TokenSet};
+use biome_parser::{token_set, Parser, SyntaxFeature, TokenSet};
 
 pub(crate) fn is_at_any_property(p: &mut CssParser) -> bool {
     is_at_generic_property(p)
@@ -18,7 +27,83 @@ pub(crate) fn parse_any_property(p: &mut CssParser) -> ParsedSyntax {
         return Absent;
     }
 
-    parse_generic_property(p)
+    if is_at_composes_property(p) {
+        parse_composes_property(p)
+    } else {
+        parse_generic_property(p)
+    }
+}
+
+#[inline]
+fn is_at_composes_property(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::CssModules.is_supported(p) && p.at(T![composes]) && p.nth_at(1, T![:])
+}
+
+/// Parses the `composes` property of CSS Modules, e.g.
+/// `composes: base primary from "./shared.module.css";`.
+///
+/// The source is either a string with the path of another module or the
+/// `global` keyword, which refers to global class names.
+#[inline]
+fn parse_composes_property(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_composes_property(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_regular_identifier(p).ok();
+    p.bump(T![:]);
+
+    let value = p.start();
+    ComposesClassList.parse_list(p);
+
+    if p.at(T![from]) {
+        let specifier = p.start();
+        p.bump(T![from]);
+
+        if is_at_string(p) {
+            parse_string(p).ok();
+        } else {
+            parse_regular_identifier(p).or_add_diagnostic(p, expected_composes_import_source);
+        }
+
+        specifier.complete(p, CSS_COMPOSES_IMPORT_SPECIFIER);
+    }
+
+    value.complete(p, CSS_COMPOSES_PROPERTY_VALUE);
+
+    Present(m.complete(p, CSS_COMPOSES_PROPERTY))
+}
+
+struct ComposesClassList;
+
+impl ParseNodeList for ComposesClassList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = CSS_COMPOSES_CLASS_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_custom_identifier(p, CssLexContext::Regular)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![from]) || p.at_ts(CSS_END_OF_PROPERTY_VALUE_TOKEN_SET) || p.at(T![!])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                CSS_BOGUS_PROPERTY_VALUE,
+                CSS_END_OF_PROPERTY_VALUE_TOKEN_SET,
+            ),
+            expected_identifier,
+        )
+    }
 }
 
 #[inline]
@@ -56,6 +141,10 @@ impl ParseNodeList for GenericComponentValueList {
 
     fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
         p.at_ts(CSS_END_OF_PROPERTY_VALUE_TOKEN_SET) || p.at(T![')']) || /* !token is !important */ p.at(T![!])
+            // The condition of SCSS control directives ends with the block, e.g. `@if $a == b {`,
+            // and so does the guard of a Less mixin, e.g. `when (@a > 0) {`
+            || ((CssSyntaxFeatures::Scss.is_supported(p) || CssSyntaxFeatures::Less.is_supported(p))
+                && p.at(T!['{']))
     }
 
     fn recover(
@@ -76,7 +165,10 @@ impl ParseNodeList for GenericComponentValueList {
 
 #[inline]
 pub(crate) fn is_at_generic_component_value(p: &mut CssParser) -> bool {
-    is_at_any_value(p) || is_at_generic_delimiter(p)
+    is_at_any_value(p)
+        || is_at_generic_delimiter(p)
+        || is_at_scss_operator(p)
+        || is_at_less_operator(p)
 }
 
 #[inline]
@@ -87,6 +179,10 @@ pub(crate) fn parse_generic_component_value(p: &mut CssParser) -> ParsedSyntax {
 
     if is_at_generic_delimiter(p) {
         parse_generic_delimiter(p)
+    } else if is_at_scss_operator(p) {
+        parse_scss_operator(p)
+    } else if is_at_less_operator(p) {
+        parse_less_operator(p)
     } else {
         parse_any_value(p)
     }
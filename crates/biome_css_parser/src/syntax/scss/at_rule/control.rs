@@ -0,0 +1,189 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::parse_any_value;
+use crate::syntax::parse_error::expected_component_value;
+use crate::syntax::property::GenericComponentValueList;
+use crate::syntax::scss::parse_error::{expected_scss_for_operator, expected_scss_variable};
+use crate::syntax::scss::{is_at_scss_identifier, parse_scss_identifier};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser};
+
+#[inline]
+pub(crate) fn is_at_scss_if_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![if])
+}
+
+/// Parses an `@if` rule and its `@else` clauses.
+///
+/// ```scss
+/// @if $theme == dark {
+///     color: white;
+/// } @else if $theme == light {
+///     color: black;
+/// } @else {
+///     color: gray;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_if_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_if_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![if]);
+    GenericComponentValueList.parse_list(p);
+    parse_declaration_or_rule_list_block(p);
+    parse_scss_else_clause(p).ok();
+
+    Present(m.complete(p, SCSS_IF_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_else_clause(p: &mut CssParser) -> ParsedSyntax {
+    if !(p.at(T![@]) && p.nth_at(1, T![else])) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![@]);
+    p.bump(T![else]);
+
+    if is_at_scss_if_at_rule(p) {
+        parse_scss_if_at_rule(p).ok();
+    } else {
+        parse_declaration_or_rule_list_block(p);
+    }
+
+    Present(m.complete(p, SCSS_ELSE_CLAUSE))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_each_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![each])
+}
+
+/// Parses an `@each` rule, which iterates over a list or a map.
+///
+/// ```scss
+/// @each $name, $glyph in $icons {}
+/// ```
+#[inline]
+pub(crate) fn parse_scss_each_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_each_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![each]);
+    ScssEachBindingList.parse_list(p);
+    p.expect(T![in]);
+    GenericComponentValueList.parse_list(p);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_EACH_AT_RULE))
+}
+
+struct ScssEachBindingList;
+
+impl ParseSeparatedList for ScssEachBindingList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_EACH_BINDING_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_identifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![in]) || p.at(T!['{'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![in], T!['{']]),
+            expected_scss_variable,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
+
+#[inline]
+pub(crate) fn is_at_scss_for_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![for])
+}
+
+/// Parses a `@for` rule, which counts from one number to another.
+///
+/// ```scss
+/// @for $i from 1 through 3 {}
+/// ```
+#[inline]
+pub(crate) fn parse_scss_for_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_for_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![for]);
+
+    if is_at_scss_identifier(p) {
+        parse_scss_identifier(p).ok();
+    } else {
+        p.error(expected_scss_variable(p, p.cur_range()));
+    }
+
+    p.expect(T![from]);
+    parse_any_value(p).or_add_diagnostic(p, expected_component_value);
+
+    if !p.eat(T![through]) && !p.eat(T![to]) {
+        p.error(expected_scss_for_operator(p, p.cur_range()));
+    }
+
+    parse_any_value(p).or_add_diagnostic(p, expected_component_value);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_FOR_AT_RULE))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_while_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![while])
+}
+
+/// Parses a `@while` rule, which repeats its block while the condition is true.
+///
+/// ```scss
+/// @while $i > 0 {}
+/// ```
+#[inline]
+pub(crate) fn parse_scss_while_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_while_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![while]);
+    GenericComponentValueList.parse_list(p);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_WHILE_AT_RULE))
+}
@@ -0,0 +1,49 @@
+use crate::parser::CssParser;
+use crate::syntax::selector::SelectorList;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser};
+
+#[inline]
+pub(crate) fn is_at_scss_extend_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![extend])
+}
+
+/// Parses an `@extend` rule, which makes the enclosing rule inherit the styles
+/// of the given selectors.
+///
+/// ```scss
+/// @extend %message-shared;
+/// @extend .button !optional;
+/// ```
+#[inline]
+pub(crate) fn parse_scss_extend_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_extend_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![extend]);
+
+    SelectorList::default()
+        .with_end_kind_ts(token_set![T![;], T![!], T!['}']])
+        .with_recovery_ts(token_set![T![;], T![!], T!['}']])
+        .parse_list(p);
+
+    if p.at(T![!]) && p.nth_at(1, T![optional]) {
+        let optional = p.start();
+        p.bump(T![!]);
+        p.bump(T![optional]);
+        optional.complete(p, SCSS_EXTEND_OPTIONAL);
+    }
+
+    if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, SCSS_EXTEND_AT_RULE))
+}
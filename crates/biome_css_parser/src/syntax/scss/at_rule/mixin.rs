@@ -0,0 +1,266 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::parse_error::expected_identifier;
+use crate::syntax::property::is_at_generic_component_value;
+use crate::syntax::scss::parse_error::{expected_scss_argument, expected_scss_variable};
+use crate::syntax::scss::{
+    is_at_scss_identifier, is_at_scss_qualified_name, parse_scss_identifier, parse_scss_name,
+    ScssExpressionValueList,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, TokenSet};
+
+#[inline]
+pub(crate) fn is_at_scss_mixin_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![mixin])
+}
+
+/// Parses a `@mixin` rule, which defines styles that can be reused with `@include`.
+///
+/// ```scss
+/// @mixin theme($theme: DarkGray) {
+///     background: $theme;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_mixin_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_mixin_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![mixin]);
+    parse_scss_name(p).or_add_diagnostic(p, expected_identifier);
+    parse_scss_parameters(p).ok();
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_MIXIN_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_parameters(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    ScssParameterList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_PARAMETERS))
+}
+
+const SCSS_ARGUMENT_RECOVERY_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![,], T![')'], T![;], T!['{']];
+
+struct ScssParameterList;
+
+impl ParseSeparatedList for ScssParameterList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_PARAMETER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        // The list allows missing elements, so the empty parameter is reported here, e.g. `(1, , 2)`
+        if p.at(T![,]) {
+            p.error(expected_scss_variable(p, p.cur_range()));
+            return Absent;
+        }
+        parse_scss_parameter(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_ARGUMENT_RECOVERY_SET),
+            expected_scss_variable,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn parse_scss_parameter(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_identifier(p).ok();
+
+    if p.at(T![:]) {
+        let default_value = p.start();
+        p.bump(T![:]);
+        ScssExpressionValueList.parse_list(p);
+        default_value.complete(p, SCSS_PARAMETER_DEFAULT);
+    }
+
+    Present(m.complete(p, SCSS_PARAMETER))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_include_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![include])
+}
+
+/// Parses an `@include` rule, which applies the styles of a mixin. The content
+/// block is passed to the mixin and emitted where it uses `@content`.
+///
+/// ```scss
+/// @include theme($theme: DarkRed);
+/// @include breakpoint(md) {
+///     display: none;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_include_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_include_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![include]);
+
+    if is_at_scss_qualified_name(p) {
+        // The arguments belong to the rule, so the member is always parsed as a name.
+        let name = p.start();
+        parse_scss_name(p).ok();
+        p.bump(T![.]);
+        parse_scss_name(p).or_add_diagnostic(p, expected_identifier);
+        name.complete(p, SCSS_QUALIFIED_NAME);
+    } else {
+        parse_scss_name(p).or_add_diagnostic(p, expected_identifier);
+    }
+
+    parse_scss_include_arguments(p).ok();
+
+    if p.at(T!['{']) {
+        parse_declaration_or_rule_list_block(p);
+    } else if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, SCSS_INCLUDE_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_include_arguments(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    ScssArgumentList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_INCLUDE_ARGUMENTS))
+}
+
+struct ScssArgumentList;
+
+impl ParseSeparatedList for ScssArgumentList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_ARGUMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        // The list allows missing elements, so the empty argument is reported here, e.g. `(1, , 2)`
+        if p.at(T![,]) {
+            p.error(expected_scss_argument(p, p.cur_range()));
+            return Absent;
+        }
+        parse_scss_argument(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_ARGUMENT_RECOVERY_SET),
+            expected_scss_argument,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+/// Parses a positional argument, e.g. `2px`, or a keyword argument, e.g. `$theme: DarkRed`.
+#[inline]
+fn parse_scss_argument(p: &mut CssParser) -> ParsedSyntax {
+    if p.at(T![,]) || !is_at_generic_component_value(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    if is_at_scss_identifier(p) && p.nth_at(2, T![:]) {
+        let name = p.start();
+        parse_scss_identifier(p).ok();
+        p.bump(T![:]);
+        name.complete(p, SCSS_ARGUMENT_NAME);
+    }
+
+    ScssExpressionValueList.parse_list(p);
+
+    Present(m.complete(p, SCSS_ARGUMENT))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_content_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![content])
+}
+
+/// Parses a `@content` rule, which emits the block passed to the mixin.
+#[inline]
+pub(crate) fn parse_scss_content_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_content_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![content]);
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_CONTENT_AT_RULE))
+}
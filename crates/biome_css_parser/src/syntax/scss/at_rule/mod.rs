@@ -0,0 +1,69 @@
+mod control;
+mod extend;
+mod mixin;
+mod module;
+
+use crate::parser::CssParser;
+use crate::syntax::scss::at_rule::control::{
+    is_at_scss_each_at_rule, is_at_scss_for_at_rule, is_at_scss_if_at_rule,
+    is_at_scss_while_at_rule, parse_scss_each_at_rule, parse_scss_for_at_rule,
+    parse_scss_if_at_rule, parse_scss_while_at_rule,
+};
+use crate::syntax::scss::at_rule::extend::{is_at_scss_extend_at_rule, parse_scss_extend_at_rule};
+use crate::syntax::scss::at_rule::mixin::{
+    is_at_scss_content_at_rule, is_at_scss_include_at_rule, is_at_scss_mixin_at_rule,
+    parse_scss_content_at_rule, parse_scss_include_at_rule, parse_scss_mixin_at_rule,
+};
+use crate::syntax::scss::at_rule::module::{
+    is_at_scss_forward_at_rule, is_at_scss_use_at_rule, parse_scss_forward_at_rule,
+    parse_scss_use_at_rule,
+};
+use crate::CssSyntaxFeatures;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::Absent;
+use biome_parser::SyntaxFeature;
+
+#[inline]
+pub(crate) fn is_at_scss_at_rule(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p)
+        && (is_at_scss_use_at_rule(p)
+            || is_at_scss_forward_at_rule(p)
+            || is_at_scss_mixin_at_rule(p)
+            || is_at_scss_include_at_rule(p)
+            || is_at_scss_content_at_rule(p)
+            || is_at_scss_extend_at_rule(p)
+            || is_at_scss_if_at_rule(p)
+            || is_at_scss_each_at_rule(p)
+            || is_at_scss_for_at_rule(p)
+            || is_at_scss_while_at_rule(p))
+}
+
+/// Parses the SCSS at-rules after the `@` token.
+#[inline]
+pub(crate) fn parse_scss_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_at_rule(p) {
+        return Absent;
+    }
+
+    if is_at_scss_use_at_rule(p) {
+        parse_scss_use_at_rule(p)
+    } else if is_at_scss_forward_at_rule(p) {
+        parse_scss_forward_at_rule(p)
+    } else if is_at_scss_mixin_at_rule(p) {
+        parse_scss_mixin_at_rule(p)
+    } else if is_at_scss_include_at_rule(p) {
+        parse_scss_include_at_rule(p)
+    } else if is_at_scss_content_at_rule(p) {
+        parse_scss_content_at_rule(p)
+    } else if is_at_scss_extend_at_rule(p) {
+        parse_scss_extend_at_rule(p)
+    } else if is_at_scss_if_at_rule(p) {
+        parse_scss_if_at_rule(p)
+    } else if is_at_scss_each_at_rule(p) {
+        parse_scss_each_at_rule(p)
+    } else if is_at_scss_for_at_rule(p) {
+        parse_scss_for_at_rule(p)
+    } else {
+        parse_scss_while_at_rule(p)
+    }
+}
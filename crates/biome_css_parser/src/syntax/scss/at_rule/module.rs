@@ -0,0 +1,173 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{expected_identifier, expected_string};
+use crate::syntax::scss::parse_error::{expected_scss_map_expression, expected_scss_module_member};
+use crate::syntax::scss::{
+    parse_any_scss_module_member, parse_scss_map_expression, parse_scss_name,
+};
+use crate::syntax::{is_at_identifier, parse_string};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser};
+
+#[inline]
+pub(crate) fn is_at_scss_use_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![use])
+}
+
+/// Parses a `@use` rule, which loads the members of another stylesheet.
+///
+/// ```scss
+/// @use "sass:math" as m;
+/// @use "config" with ($primary: blue);
+/// ```
+#[inline]
+pub(crate) fn parse_scss_use_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_use_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![use]);
+    parse_string(p).or_add_diagnostic(p, expected_string);
+    parse_scss_use_as(p).ok();
+    parse_scss_module_configuration(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_USE_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_use_as(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![as]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![as]);
+
+    if p.at(T![*]) {
+        let namespace = p.start();
+        p.bump(T![*]);
+        namespace.complete(p, SCSS_USE_ALL_NAMESPACE);
+    } else {
+        parse_scss_name(p).or_add_diagnostic(p, expected_identifier);
+    }
+
+    Present(m.complete(p, SCSS_USE_AS))
+}
+
+#[inline]
+fn parse_scss_module_configuration(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![with]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![with]);
+    parse_scss_map_expression(p).or_add_diagnostic(p, expected_scss_map_expression);
+
+    Present(m.complete(p, SCSS_MODULE_CONFIGURATION))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_forward_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![forward])
+}
+
+/// Parses a `@forward` rule, which makes the members of another stylesheet
+/// available to the stylesheets that load this one.
+///
+/// ```scss
+/// @forward "src/list" as list-* hide list-reset, $horizontal-list-gap;
+/// ```
+#[inline]
+pub(crate) fn parse_scss_forward_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_forward_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![forward]);
+    parse_string(p).or_add_diagnostic(p, expected_string);
+    parse_scss_forward_as(p).ok();
+    parse_scss_forward_visibility(p).ok();
+    parse_scss_module_configuration(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_FORWARD_AT_RULE))
+}
+
+/// The prefix is lexed as a regular identifier that ends with a dash, e.g. `list-`,
+/// immediately followed by `*`.
+#[inline]
+fn parse_scss_forward_as(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![as]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![as]);
+    parse_scss_name(p).or_add_diagnostic(p, expected_identifier);
+    p.expect(T![*]);
+
+    Present(m.complete(p, SCSS_FORWARD_AS))
+}
+
+#[inline]
+fn parse_scss_forward_visibility(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![show]) && !p.at(T![hide]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump_ts(token_set![T![show], T![hide]]);
+    ScssForwardMemberList.parse_list(p);
+
+    Present(m.complete(p, SCSS_FORWARD_VISIBILITY))
+}
+
+struct ScssForwardMemberList;
+
+impl ParseSeparatedList for ScssForwardMemberList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_FORWARD_MEMBER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if p.at(T!["$"]) || is_at_identifier(p) {
+            parse_any_scss_module_member(p)
+        } else {
+            Absent
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![;]) || p.at(T![with])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![;]]),
+            expected_scss_module_member,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
@@ -0,0 +1,96 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::expected_component_value;
+use crate::syntax::property::GenericComponentValueList;
+use crate::syntax::scss::parse_error::expected_scss_variable_modifier;
+use crate::syntax::scss::{is_at_scss_identifier, parse_scss_identifier};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, TokenSet};
+
+#[inline]
+pub(crate) fn is_at_scss_declaration(p: &mut CssParser) -> bool {
+    is_at_scss_identifier(p) && p.nth_at(2, T![:])
+}
+
+/// Parses a variable declaration, e.g. `$primary-color: #333 !default;`.
+///
+/// Like regular declarations, the semicolon is optional before a closing brace.
+#[inline]
+pub(crate) fn parse_scss_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_identifier(p).ok();
+    p.bump(T![:]);
+    let value = GenericComponentValueList.parse_list(p);
+    if value.range(p).is_empty() {
+        p.error(expected_component_value(p, p.cur_range()));
+    }
+    ScssVariableModifierList.parse_list(p);
+
+    if !p.at(T!['}']) {
+        if p.nth_at(1, T!['}']) {
+            p.eat(T![;]);
+        } else {
+            p.expect(T![;]);
+        }
+    }
+
+    Present(m.complete(p, SCSS_DECLARATION))
+}
+
+const SCSS_VARIABLE_MODIFIER_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set![T![;], T!['}']];
+
+struct ScssVariableModifierList;
+
+impl ParseNodeList for ScssVariableModifierList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_VARIABLE_MODIFIER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_variable_modifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !p.at(T![!])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_VARIABLE_MODIFIER_RECOVERY_SET),
+            expected_scss_variable_modifier,
+        )
+    }
+}
+
+#[inline]
+fn is_at_scss_variable_modifier(p: &mut CssParser) -> bool {
+    p.at(T![!]) && (p.nth_at(1, T![default]) || p.nth_at(1, T![global]))
+}
+
+#[inline]
+fn parse_scss_variable_modifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_variable_modifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![!]);
+    p.bump_ts(token_set![T![default], T![global]]);
+
+    Present(m.complete(p, SCSS_VARIABLE_MODIFIER))
+}
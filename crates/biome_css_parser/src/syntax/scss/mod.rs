@@ -0,0 +1,18 @@
+//! Parsing of the SCSS syntax, enabled by [crate::CssSyntaxFeatures::Scss].
+
+mod at_rule;
+mod declaration;
+mod parse_error;
+mod selector;
+mod value;
+
+pub(crate) use at_rule::{is_at_scss_at_rule, parse_scss_at_rule};
+pub(crate) use declaration::{is_at_scss_declaration, parse_scss_declaration};
+pub(crate) use selector::{is_nth_at_scss_placeholder_selector, parse_scss_placeholder_selector};
+pub(crate) use value::{
+    is_at_scss_identifier, is_at_scss_interpolation, is_at_scss_operator,
+    is_at_scss_parenthesized_expression, is_at_scss_qualified_name, parse_any_scss_module_member,
+    parse_scss_identifier, parse_scss_interpolation, parse_scss_map_expression, parse_scss_name,
+    parse_scss_operator, parse_scss_parenthesized_expression, parse_scss_qualified_name,
+    ScssExpressionValueList,
+};
@@ -0,0 +1,35 @@
+use crate::parser::CssParser;
+use biome_parser::diagnostic::{expect_one_of, expected_node, ToDiagnostic};
+use biome_parser::prelude::ParseDiagnostic;
+use biome_rowan::TextRange;
+
+pub(crate) fn expected_scss_variable(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("variable", range, p)
+}
+
+pub(crate) fn expected_scss_variable_modifier(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expect_one_of(&["!default", "!global"], range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_scss_map_expression_pair(
+    p: &CssParser,
+    range: TextRange,
+) -> ParseDiagnostic {
+    expected_node("<key>: <value>", range, p)
+}
+
+pub(crate) fn expected_scss_argument(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("argument", range, p)
+}
+
+pub(crate) fn expected_scss_module_member(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expect_one_of(&["variable", "mixin", "function"], range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_scss_for_operator(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expect_one_of(&["through", "to"], range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_scss_map_expression(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("map", range, p)
+}
@@ -0,0 +1,30 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::expected_identifier;
+use crate::syntax::selector::parse_selector_custom_identifier;
+use crate::CssSyntaxFeatures;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, SyntaxFeature};
+
+#[inline]
+pub(crate) fn is_nth_at_scss_placeholder_selector(p: &mut CssParser, n: usize) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p) && p.nth_at(n, T![%])
+}
+
+/// Parses a placeholder selector, e.g. `%message-shared`, which is only
+/// emitted when it's extended with `@extend`.
+#[inline]
+pub(crate) fn parse_scss_placeholder_selector(p: &mut CssParser) -> ParsedSyntax {
+    if !is_nth_at_scss_placeholder_selector(p, 0) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![%]);
+    parse_selector_custom_identifier(p).or_add_diagnostic(p, expected_identifier);
+
+    Present(m.complete(p, SCSS_PLACEHOLDER_SELECTOR))
+}
@@ -0,0 +1,296 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{expected_component_value, expected_identifier};
+use crate::syntax::property::{parse_generic_component_value, GenericComponentValueList};
+use crate::syntax::scss::parse_error::expected_scss_map_expression_pair;
+use crate::syntax::value::function::{is_nth_at_function, parse_function};
+use crate::syntax::{
+    is_at_identifier, is_nth_at_identifier, parse_any_value, parse_custom_identifier_with_keywords,
+};
+use crate::CssSyntaxFeatures;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, CompletedMarker, Marker, Parser, SyntaxFeature, TokenSet};
+
+#[inline]
+pub(crate) fn is_at_scss_identifier(p: &mut CssParser) -> bool {
+    is_nth_at_scss_identifier(p, 0)
+}
+
+#[inline]
+pub(crate) fn is_nth_at_scss_identifier(p: &mut CssParser, n: usize) -> bool {
+    p.nth_at(n, T!["$"]) && is_nth_at_identifier(p, n + 1)
+}
+
+/// Parses the name of a variable, mixin or module. Unlike most CSS
+/// identifiers, Sass names are case-sensitive, so they are parsed as custom
+/// identifiers to keep them from being rewritten in lowercase.
+#[inline]
+pub(crate) fn parse_scss_name(p: &mut CssParser) -> ParsedSyntax {
+    parse_custom_identifier_with_keywords(p, CssLexContext::Regular, true)
+}
+
+/// Parses a SCSS variable reference, e.g. `$primary-color`.
+#[inline]
+pub(crate) fn parse_scss_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!["$"]);
+    parse_scss_name(p).ok();
+
+    Present(m.complete(p, SCSS_IDENTIFIER))
+}
+
+/// Checks if the parser is at a member of a module loaded with `@use`,
+/// e.g. `math.div(10px, 2)` or `colors.$primary`.
+#[inline]
+pub(crate) fn is_at_scss_qualified_name(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p)
+        && is_at_identifier(p)
+        && p.nth_at(1, T![.])
+        && (is_nth_at_identifier(p, 2) || is_nth_at_scss_identifier(p, 2))
+}
+
+#[inline]
+pub(crate) fn parse_scss_qualified_name(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_qualified_name(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_name(p).ok();
+    p.bump(T![.]);
+    parse_any_scss_module_member(p).or_add_diagnostic(p, expected_identifier);
+
+    Present(m.complete(p, SCSS_QUALIFIED_NAME))
+}
+
+/// Parses a member of a module: a variable, a function call or a mixin name.
+#[inline]
+pub(crate) fn parse_any_scss_module_member(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_scss_identifier(p) {
+        parse_scss_identifier(p)
+    } else if is_nth_at_function(p, 0) {
+        parse_function(p)
+    } else {
+        parse_scss_name(p)
+    }
+}
+
+#[inline]
+pub(crate) fn is_at_scss_interpolation(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p) && p.at(T![#]) && p.nth_at(1, T!['{'])
+}
+
+/// Parses an interpolation, e.g. `#{$gutter}`.
+#[inline]
+pub(crate) fn parse_scss_interpolation(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_interpolation(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![#]);
+    p.bump(T!['{']);
+    GenericComponentValueList.parse_list(p);
+    p.expect(T!['}']);
+
+    Present(m.complete(p, SCSS_INTERPOLATION))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_parenthesized_expression(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p) && p.at(T!['('])
+}
+
+/// Parses either a map, e.g. `(small: 576px, medium: 768px)`, or a
+/// parenthesized expression, e.g. `($gutter + 1px)`.
+#[inline]
+pub(crate) fn parse_scss_parenthesized_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_parenthesized_expression(p) {
+        return Absent;
+    }
+
+    if is_at_scss_map_expression(p) {
+        return parse_scss_map_expression(p);
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    GenericComponentValueList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_PARENTHESIZED_EXPRESSION))
+}
+
+/// A map starts with a key followed by a colon, where the key is either a
+/// variable or a single value.
+#[inline]
+pub(crate) fn is_at_scss_map_expression(p: &mut CssParser) -> bool {
+    if !is_at_scss_parenthesized_expression(p) {
+        return false;
+    }
+
+    if is_nth_at_scss_identifier(p, 1) {
+        return p.nth_at(3, T![:]);
+    }
+
+    (is_nth_at_identifier(p, 1)
+        || p.nth_at(1, CSS_STRING_LITERAL)
+        || p.nth_at(1, CSS_NUMBER_LITERAL))
+        && p.nth_at(2, T![:])
+}
+
+#[inline]
+pub(crate) fn parse_scss_map_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_parenthesized_expression(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    ScssMapExpressionPairList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_MAP_EXPRESSION))
+}
+
+struct ScssMapExpressionPairList;
+
+impl ParseSeparatedList for ScssMapExpressionPairList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_MAP_EXPRESSION_PAIR_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_map_expression_pair(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![')'], T![;]]),
+            expected_scss_map_expression_pair,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn parse_scss_map_expression_pair(p: &mut CssParser) -> ParsedSyntax {
+    let key = parse_any_value(p);
+
+    if key.is_absent() {
+        return Absent;
+    }
+
+    let m = key.precede(p);
+
+    p.expect(T![:]);
+    ScssExpressionValueList.parse_list(p);
+
+    Present(m.complete(p, SCSS_MAP_EXPRESSION_PAIR))
+}
+
+const SCSS_OPERATOR_SET: TokenSet<CssSyntaxKind> = token_set![
+    T![+],
+    T![-],
+    T![*],
+    T![%],
+    T![==],
+    T![!=],
+    T![<],
+    T![>],
+    T![<=],
+    T![>=]
+];
+
+#[inline]
+pub(crate) fn is_at_scss_operator(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p) && p.at_ts(SCSS_OPERATOR_SET)
+}
+
+/// Parses an arithmetic or comparison operator, e.g. `*` in `$width * 2`.
+#[inline]
+pub(crate) fn parse_scss_operator(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_operator(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_ts(SCSS_OPERATOR_SET);
+    Present(m.complete(p, SCSS_OPERATOR))
+}
+
+const SCSS_END_OF_EXPRESSION_VALUE_TOKEN_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![,], T![')'], T![;], T!['}']];
+
+/// A list of component values that ends at a comma, used where the comma
+/// separates the items of an enclosing list, e.g. the values of a map or the
+/// arguments of `@include`.
+pub(crate) struct ScssExpressionValueList;
+
+impl ParseNodeList for ScssExpressionValueList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = CSS_GENERIC_COMPONENT_VALUE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_generic_component_value(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(SCSS_END_OF_EXPRESSION_VALUE_TOKEN_SET) || p.at(T!['{'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                CSS_BOGUS_PROPERTY_VALUE,
+                SCSS_END_OF_EXPRESSION_VALUE_TOKEN_SET,
+            ),
+            expected_component_value,
+        )
+    }
+
+    fn finish_list(&mut self, p: &mut Self::Parser<'_>, m: Marker) -> CompletedMarker {
+        let list = m.complete(p, Self::LIST_KIND);
+        // The list follows a colon, so it can't be empty, e.g. `$theme: )`
+        if list.range(p).is_empty() {
+            p.error(expected_component_value(p, p.cur_range()));
+        }
+        list
+    }
+}
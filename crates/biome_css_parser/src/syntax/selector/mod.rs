@@ -8,6 +8,7 @@ use crate::parser::CssParser;
 use crate::syntax::parse_error::{
     expected_any_sub_selector, expected_compound_selector, expected_identifier, expected_selector,
 };
+use crate::syntax::scss::{is_nth_at_scss_placeholder_selector, parse_scss_placeholder_selector};
 use crate::syntax::selector::attribute::parse_attribute_selector;
 use crate::syntax::selector::pseudo_class::parse_pseudo_class_selector;
 use crate::syntax::selector::pseudo_element::parse_pseudo_element_selector;
@@ -15,6 +16,7 @@ use crate::syntax::{
     is_at_identifier, is_nth_at_identifier, parse_custom_identifier_with_keywords,
     parse_identifier, parse_regular_identifier,
 };
+use crate::CssSyntaxFeatures;
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::{CssSyntaxKind, TextRange, T};
 use biome_parser::diagnostic::ToDiagnostic;
@@ -24,7 +26,7 @@ use biome_parser::parse_recovery::{
 };
 use biome_parser::prelude::ParsedSyntax;
 use biome_parser::prelude::ParsedSyntax::{Absent, Present};
-use biome_parser::{token_set, CompletedMarker, Parser, ParserProgress, TokenSet};
+use biome_parser::{token_set, CompletedMarker, Parser, ParserProgress, SyntaxFeature, TokenSet};
 
 /// Determines the lexical context for parsing CSS selectors.
 ///
@@ -37,7 +39,14 @@ const SELECTOR_LEX_SET: TokenSet<CssSyntaxKind> =
 #[inline]
 fn selector_lex_context(p: &mut CssParser) -> CssLexContext {
     // It's an inverted logic for `is_nth_at_selector(p, 1)`.
-    if p.nth_at_ts(1, SELECTOR_LEX_SET) {
+    // In SCSS, `!optional` can follow the selectors of `@extend`.
+    // In Less, `all` can follow the selectors of `:extend`, which can end a statement.
+    if p.nth_at_ts(1, SELECTOR_LEX_SET)
+        || (CssSyntaxFeatures::Scss.is_supported(p) && p.nth_at(1, T![!]))
+        || (CssSyntaxFeatures::Less.is_supported(p)
+            && (p.nth_at_ts(1, token_set![T![;], T!['}']])
+                || (p.nth_at(1, T![all]) && p.nth_at_ts(2, token_set![T![,], T![')']]))))
+    {
         CssLexContext::Regular
     } else {
         CssLexContext::Selector
@@ -270,6 +279,7 @@ fn is_nth_at_compound_selector(p: &mut CssParser, n: usize) -> bool {
     p.nth_at(n, T![&])
         || is_nth_at_simple_selector(p, n)
         || p.nth_at_ts(n, SubSelectorList::START_SET)
+        || is_nth_at_scss_placeholder_selector(p, n)
 }
 
 /// Parses a compound selector in CSS.
@@ -403,7 +413,7 @@ impl ParseNodeList for SubSelectorList {
     }
 
     fn is_at_list_end(&self, p: &mut CssParser) -> bool {
-        !p.at_ts(Self::START_SET)
+        !(p.at_ts(Self::START_SET) || is_nth_at_scss_placeholder_selector(p, 0))
     }
 
     fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
@@ -428,6 +438,7 @@ fn parse_sub_selector(p: &mut CssParser) -> ParsedSyntax {
         T!['['] => parse_attribute_selector(p),
         T![:] => parse_pseudo_class_selector(p),
         T![::] => parse_pseudo_element_selector(p),
+        T![%] => parse_scss_placeholder_selector(p),
         _ => Absent,
     }
 }
@@ -521,7 +532,7 @@ fn parse_selector_identifier(p: &mut CssParser) -> ParsedSyntax {
 /// case-sensitive. These are distinguished from regular identifiers in
 /// selectors that are case-insensitive for safety in preserving the casing.
 #[inline]
-fn parse_selector_custom_identifier(p: &mut CssParser) -> ParsedSyntax {
+pub(crate) fn parse_selector_custom_identifier(p: &mut CssParser) -> ParsedSyntax {
     let context = selector_lex_context(p);
     // Class and ID selectors are technically `<ident>` _and_ case-sensitive.
     // To handle this, we use `<custom-ident>` instead, but also have to allow
@@ -32,6 +32,7 @@ use self::function_value_list::{
 use self::identifier::parse_pseudo_class_identifier;
 use crate::parser::CssParser;
 use crate::syntax::is_at_identifier;
+use crate::syntax::less::{is_at_less_pseudo_class_extend, parse_less_pseudo_class_extend};
 use crate::syntax::parse_error::expected_any_pseudo_class;
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::T;
@@ -70,7 +71,9 @@ fn parse_pseudo_class(p: &mut CssParser) -> ParsedSyntax {
         return Absent;
     }
 
-    if is_at_pseudo_class_function_identifier(p) {
+    if is_at_less_pseudo_class_extend(p) {
+        parse_less_pseudo_class_extend(p)
+    } else if is_at_pseudo_class_function_identifier(p) {
         parse_pseudo_class_function_identifier(p)
     } else if is_at_pseudo_class_function_selector(p) {
         parse_pseudo_class_function_selector(p)
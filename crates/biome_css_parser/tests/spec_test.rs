@@ -36,12 +36,32 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let content = fs::read_to_string(test_case_path)
         .expect("Expected test path to be a readable file in UTF8 encoding");
 
-    let parse_config = CssParserOptions::default().allow_wrong_line_comments();
+    let is_scss = test_case_path.extension().is_some_and(|ext| ext == "scss");
+    let is_less = test_case_path.extension().is_some_and(|ext| ext == "less");
+    let is_css_modules = file_name.ends_with(".module.css");
+
+    let mut parse_config = CssParserOptions::default().allow_wrong_line_comments();
+    if is_scss {
+        parse_config = parse_config.allow_scss_syntax();
+    }
+    if is_less {
+        parse_config = parse_config.allow_less_syntax();
+    }
+    if is_css_modules {
+        parse_config = parse_config.allow_css_modules();
+    }
     let parsed = parse_css(&content, parse_config);
     let formatted_ast = format!("{:#?}", parsed.tree());
 
+    let language = if is_scss {
+        "scss"
+    } else if is_less {
+        "less"
+    } else {
+        "css"
+    };
     let mut snapshot = String::new();
-    writeln!(snapshot, "\n## Input\n\n```css\n{content}\n```\n\n").unwrap();
+    writeln!(snapshot, "\n## Input\n\n```{language}\n{content}\n```\n\n").unwrap();
 
     writeln!(
         snapshot,
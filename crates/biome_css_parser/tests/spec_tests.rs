@@ -5,4 +5,8 @@ mod spec_test;
 mod ok {
     tests_macros::gen_tests! {"tests/css_test_suite/ok/**/*.css", crate::spec_test::run, "ok"}
     tests_macros::gen_tests! {"tests/css_test_suite/error/**/*.css", crate::spec_test::run, "error"}
+    tests_macros::gen_tests! {"tests/css_test_suite/ok/**/*.scss", crate::spec_test::run, "ok"}
+    tests_macros::gen_tests! {"tests/css_test_suite/error/**/*.scss", crate::spec_test::run, "error"}
+    tests_macros::gen_tests! {"tests/css_test_suite/ok/**/*.less", crate::spec_test::run, "ok"}
+    tests_macros::gen_tests! {"tests/css_test_suite/error/**/*.less", crate::spec_test::run, "error"}
 }
@@ -0,0 +1,169 @@
+//! Events emitted by the [SemanticEventExtractor] which are then constructed into the Semantic Model
+
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssExpression, AnyCssValue, CssFunction, CssGenericProperty,
+    CssPropertyAtRule, CssSyntaxNode, TextRange,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList};
+use std::collections::VecDeque;
+
+/// Events emitted by the [SemanticEventExtractor].
+/// These events are later made into the Semantic Model.
+#[derive(Debug, Eq, PartialEq)]
+pub enum SemanticEvent {
+    /// Tracks where a custom property is declared.
+    /// Generated for:
+    /// - Declarations whose name is a dashed identifier, e.g. `--primary: blue`
+    CustomPropertyDeclaration {
+        name: String,
+        /// The range of the name of the property
+        range: TextRange,
+    },
+
+    /// Tracks where a custom property is registered.
+    /// Generated for:
+    /// - `@property` at-rules
+    CustomPropertyRegistration {
+        name: String,
+        /// The range of the name of the property
+        range: TextRange,
+        /// Whether the registration has an `initial-value` descriptor
+        has_initial_value: bool,
+    },
+
+    /// Tracks where the value of a custom property is read.
+    /// Generated for:
+    /// - `var()` functions whose first argument is a dashed identifier
+    VarReference {
+        name: String,
+        /// The range of the `var()` function
+        range: TextRange,
+        /// The range of the fallback value, if any
+        fallback: Option<TextRange>,
+    },
+}
+
+/// Extracts [SemanticEvent] from [CssSyntaxNode].
+///
+/// For a simpler way to extract [SemanticEvent] see [semantic_model](crate::semantic_model).
+#[derive(Default, Debug)]
+pub struct SemanticEventExtractor {
+    stash: VecDeque<SemanticEvent>,
+}
+
+impl SemanticEventExtractor {
+    /// See [SemanticEvent] for a more detailed description of which events [CssSyntaxNode] generates.
+    #[inline]
+    pub fn enter(&mut self, node: &CssSyntaxNode) {
+        if let Some(property) = CssGenericProperty::cast_ref(node) {
+            self.enter_generic_property(&property);
+        } else if let Some(at_rule) = CssPropertyAtRule::cast_ref(node) {
+            self.enter_property_at_rule(&at_rule);
+        } else if let Some(function) = CssFunction::cast_ref(node) {
+            self.enter_function(&function);
+        }
+    }
+
+    fn enter_generic_property(&mut self, property: &CssGenericProperty) {
+        // The parser keeps the name of custom properties as a plain identifier
+        let (token, range) = match property.name() {
+            Ok(AnyCssDeclarationName::CssDashedIdentifier(name)) => {
+                (name.value_token(), name.range())
+            }
+            Ok(AnyCssDeclarationName::CssIdentifier(name)) => (name.value_token(), name.range()),
+            Err(_) => return,
+        };
+        if let Ok(token) = token {
+            if token.text_trimmed().starts_with("--") {
+                self.stash
+                    .push_back(SemanticEvent::CustomPropertyDeclaration {
+                        name: token.text_trimmed().to_string(),
+                        range,
+                    });
+            }
+        }
+    }
+
+    fn enter_property_at_rule(&mut self, at_rule: &CssPropertyAtRule) {
+        let (Ok(name), Ok(block)) = (at_rule.name(), at_rule.block()) else {
+            return;
+        };
+        let Ok(token) = name.value_token() else {
+            return;
+        };
+        let has_initial_value = block
+            .syntax()
+            .descendants()
+            .filter_map(CssGenericProperty::cast)
+            .any(|descriptor| {
+                matches!(
+                    descriptor.name(),
+                    Ok(AnyCssDeclarationName::CssIdentifier(name))
+                        if name.value_token().is_ok_and(|token| {
+                            token.text_trimmed().eq_ignore_ascii_case("initial-value")
+                        })
+                )
+            });
+        self.stash
+            .push_back(SemanticEvent::CustomPropertyRegistration {
+                name: token.text_trimmed().to_string(),
+                range: name.range(),
+                has_initial_value,
+            });
+    }
+
+    fn enter_function(&mut self, function: &CssFunction) {
+        let is_var = function
+            .name()
+            .and_then(|name| name.value_token())
+            .is_ok_and(|token| token.text_trimmed().eq_ignore_ascii_case("var"));
+        if !is_var {
+            return;
+        }
+
+        let items = function.items();
+        let mut elements = items.elements();
+        let Some(first) = elements.next() else {
+            return;
+        };
+        let Some(name) = first
+            .node()
+            .ok()
+            .and_then(|parameter| parameter.any_css_expression().ok())
+            .and_then(|expression| match expression {
+                AnyCssExpression::CssListOfComponentValuesExpression(expression) => {
+                    let values = expression.css_component_value_list();
+                    let mut values = values.iter();
+                    match (values.next(), values.next()) {
+                        (Some(AnyCssValue::CssDashedIdentifier(name)), None) => Some(name),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .and_then(|name| name.value_token().ok())
+        else {
+            return;
+        };
+
+        // The fallback is everything after the first comma, and can be empty: `var(--a,)`
+        let fallback = first.trailing_separator().ok().flatten().map(|comma| {
+            let rest = elements
+                .filter_map(|element| element.node().ok().map(AstNode::range))
+                .reduce(|first, last| first.cover(last));
+            rest.unwrap_or_else(|| comma.text_trimmed_range())
+        });
+
+        self.stash.push_back(SemanticEvent::VarReference {
+            name: name.text_trimmed().to_string(),
+            range: function.range(),
+            fallback,
+        });
+    }
+
+    /// Return any previous extracted [SemanticEvent].
+    #[inline]
+    pub fn pop(&mut self) -> Option<SemanticEvent> {
+        self.stash.pop_front()
+    }
+}
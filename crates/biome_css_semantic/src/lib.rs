@@ -0,0 +1,5 @@
+mod events;
+mod semantic_model;
+
+pub use events::*;
+pub use semantic_model::*;
@@ -0,0 +1,33 @@
+mod builder;
+mod model;
+mod project;
+
+#[cfg(test)]
+mod tests;
+
+use crate::{SemanticEvent, SemanticEventExtractor};
+use biome_css_syntax::{CssRoot, TextRange, TextSize};
+use biome_rowan::AstNode;
+use rustc_hash::FxHashMap;
+use std::rc::Rc;
+
+pub use builder::*;
+pub use model::*;
+pub use project::*;
+
+/// Build the complete [SemanticModel] of a parsed file.
+/// For a push based model to build the [SemanticModel], see [SemanticModelBuilder].
+pub fn semantic_model(root: &CssRoot) -> SemanticModel {
+    let mut extractor = SemanticEventExtractor::default();
+    let mut builder = SemanticModelBuilder::new(root.clone());
+
+    for node in root.syntax().descendants() {
+        extractor.enter(&node);
+    }
+
+    while let Some(e) = extractor.pop() {
+        builder.push_event(e);
+    }
+
+    builder.build()
+}
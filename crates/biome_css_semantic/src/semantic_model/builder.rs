@@ -0,0 +1,70 @@
+use super::*;
+
+/// Builds the [SemanticModel] consuming [SemanticEvent].
+/// For a good example on how to use it see [semantic_model].
+pub struct SemanticModelBuilder {
+    root: CssRoot,
+    declarations: Vec<CustomPropertyDeclaration>,
+    registrations: Vec<CustomPropertyRegistration>,
+    references: Vec<CustomPropertyReference>,
+    /// maps the start of a `var()` function to its index inside [SemanticModelBuilder::references]
+    references_by_start: FxHashMap<TextSize, usize>,
+}
+
+impl SemanticModelBuilder {
+    pub fn new(root: CssRoot) -> Self {
+        Self {
+            root,
+            declarations: Vec::new(),
+            registrations: Vec::new(),
+            references: Vec::new(),
+            references_by_start: FxHashMap::default(),
+        }
+    }
+
+    #[inline]
+    pub fn push_event(&mut self, e: SemanticEvent) {
+        match e {
+            SemanticEvent::CustomPropertyDeclaration { name, range } => {
+                self.declarations
+                    .push(CustomPropertyDeclaration { name, range });
+            }
+            SemanticEvent::CustomPropertyRegistration {
+                name,
+                range,
+                has_initial_value,
+            } => {
+                self.registrations.push(CustomPropertyRegistration {
+                    name,
+                    range,
+                    has_initial_value,
+                });
+            }
+            SemanticEvent::VarReference {
+                name,
+                range,
+                fallback,
+            } => {
+                self.references_by_start
+                    .insert(range.start(), self.references.len());
+                self.references.push(CustomPropertyReference {
+                    name,
+                    range,
+                    fallback,
+                });
+            }
+        }
+    }
+
+    #[inline]
+    pub fn build(self) -> SemanticModel {
+        let data = SemanticModelData {
+            root: self.root,
+            declarations: self.declarations,
+            registrations: self.registrations,
+            references: self.references,
+            references_by_start: self.references_by_start,
+        };
+        SemanticModel::new(data)
+    }
+}
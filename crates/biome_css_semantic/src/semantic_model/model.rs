@@ -0,0 +1,152 @@
+use super::*;
+use biome_css_syntax::CssFunction;
+
+/// Contains all the data of the [SemanticModel] and only lives behind an [Rc].
+#[derive(Debug)]
+pub(crate) struct SemanticModelData {
+    pub(crate) root: CssRoot,
+    // All the custom property declarations, in order of appearance
+    pub(crate) declarations: Vec<CustomPropertyDeclaration>,
+    // All the `@property` registrations, in order of appearance
+    pub(crate) registrations: Vec<CustomPropertyRegistration>,
+    // All the `var()` references, in order of appearance
+    pub(crate) references: Vec<CustomPropertyReference>,
+    // Maps the start of a `var()` function to its reference (usize points to references vec)
+    pub(crate) references_by_start: FxHashMap<TextSize, usize>,
+}
+
+/// The façade for all the semantic information of a CSS file: the custom
+/// properties it declares and registers, and the ones it reads with `var()`.
+///
+/// Custom properties aren't scoped to the rules that declare them, because
+/// their values are inherited through the DOM. The model only answers whether
+/// a name is declared or referenced somewhere in the file.
+#[derive(Clone, Debug)]
+pub struct SemanticModel {
+    pub(crate) data: Rc<SemanticModelData>,
+}
+
+impl SemanticModel {
+    pub(crate) fn new(data: SemanticModelData) -> Self {
+        Self {
+            data: Rc::new(data),
+        }
+    }
+
+    pub fn root(&self) -> &CssRoot {
+        &self.data.root
+    }
+
+    /// Returns all the custom property declarations of the file, e.g. `--primary: blue`
+    pub fn declarations(&self) -> impl Iterator<Item = &CustomPropertyDeclaration> {
+        self.data.declarations.iter()
+    }
+
+    /// Returns all the `@property` registrations of the file
+    pub fn registrations(&self) -> impl Iterator<Item = &CustomPropertyRegistration> {
+        self.data.registrations.iter()
+    }
+
+    /// Returns all the `var()` references of the file
+    pub fn references(&self) -> impl Iterator<Item = &CustomPropertyReference> {
+        self.data.references.iter()
+    }
+
+    /// Returns the reference of a `var()` function, if its first argument is
+    /// the name of a custom property
+    pub fn as_reference(&self, function: &CssFunction) -> Option<&CustomPropertyReference> {
+        let index = self
+            .data
+            .references_by_start
+            .get(&function.range().start())?;
+        Some(&self.data.references[*index])
+    }
+
+    /// Returns `true` if the file declares or registers the custom property `name`
+    pub fn is_declared(&self, name: &str) -> bool {
+        self.declarations()
+            .any(|declaration| declaration.name() == name)
+            || self
+                .registrations()
+                .any(|registration| registration.name() == name)
+    }
+
+    /// Returns `true` if the file reads the custom property `name` with `var()`
+    pub fn is_referenced(&self, name: &str) -> bool {
+        self.references().any(|reference| reference.name() == name)
+    }
+
+    /// Returns `true` if the file registers the custom property `name` with an
+    /// initial value, which means that the property always has a valid value
+    pub fn has_initial_value(&self, name: &str) -> bool {
+        self.registrations()
+            .any(|registration| registration.name() == name && registration.has_initial_value())
+    }
+}
+
+/// A custom property declared in a rule: `--primary: blue`
+#[derive(Debug)]
+pub struct CustomPropertyDeclaration {
+    pub(crate) name: String,
+    pub(crate) range: TextRange,
+}
+
+impl CustomPropertyDeclaration {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The range of the name of the property
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+}
+
+/// A custom property registered with `@property --angle { ... }`
+#[derive(Debug)]
+pub struct CustomPropertyRegistration {
+    pub(crate) name: String,
+    pub(crate) range: TextRange,
+    pub(crate) has_initial_value: bool,
+}
+
+impl CustomPropertyRegistration {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The range of the name of the property
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Whether the registration has an `initial-value` descriptor
+    pub fn has_initial_value(&self) -> bool {
+        self.has_initial_value
+    }
+}
+
+/// A custom property read with `var(--primary)` or `var(--primary, blue)`
+#[derive(Debug)]
+pub struct CustomPropertyReference {
+    pub(crate) name: String,
+    pub(crate) range: TextRange,
+    pub(crate) fallback: Option<TextRange>,
+}
+
+impl CustomPropertyReference {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The range of the `var()` function
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// The range of the fallback value. An empty fallback, as in `var(--a,)`,
+    /// has the range of the comma.
+    pub fn fallback(&self) -> Option<TextRange> {
+        self.fallback
+    }
+}
@@ -0,0 +1,48 @@
+use super::*;
+use rustc_hash::FxHashSet;
+
+/// The custom properties of the other stylesheets of a project.
+///
+/// Design tokens are usually declared in one stylesheet and read in many others,
+/// so the analysis of a single file needs to know what the rest of the project
+/// declares and references.
+#[derive(Debug, Default, Clone)]
+pub struct ProjectCustomProperties {
+    declared: FxHashSet<String>,
+    referenced: FxHashSet<String>,
+    with_initial_value: FxHashSet<String>,
+}
+
+impl ProjectCustomProperties {
+    /// Adds the custom properties of the stylesheet described by `model`
+    pub fn add_model(&mut self, model: &SemanticModel) {
+        for declaration in model.declarations() {
+            self.declared.insert(declaration.name().to_string());
+        }
+        for registration in model.registrations() {
+            self.declared.insert(registration.name().to_string());
+            if registration.has_initial_value() {
+                self.with_initial_value
+                    .insert(registration.name().to_string());
+            }
+        }
+        for reference in model.references() {
+            self.referenced.insert(reference.name().to_string());
+        }
+    }
+
+    /// Returns `true` if a stylesheet declares or registers the custom property `name`
+    pub fn is_declared(&self, name: &str) -> bool {
+        self.declared.contains(name)
+    }
+
+    /// Returns `true` if a stylesheet reads the custom property `name` with `var()`
+    pub fn is_referenced(&self, name: &str) -> bool {
+        self.referenced.contains(name)
+    }
+
+    /// Returns `true` if a stylesheet registers the custom property `name` with an initial value
+    pub fn has_initial_value(&self, name: &str) -> bool {
+        self.with_initial_value.contains(name)
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod test {
+    use crate::{semantic_model, ProjectCustomProperties};
+    use biome_css_parser::{parse_css, CssParserOptions};
+    use biome_css_syntax::CssFunction;
+    use biome_rowan::AstNode;
+
+    #[test]
+    pub fn ok_semantic_model() {
+        let r = parse_css(
+            r#"@property --angle { syntax: "<angle>"; inherits: false; initial-value: 0deg; }
+:root { --primary: blue; --spacing: 4px; }
+a { color: var(--primary); margin: var(--spacing, 8px); padding: var(--gap,); rotate: var(--angle, 10deg); }"#,
+            CssParserOptions::default(),
+        );
+        let model = semantic_model(&r.tree());
+
+        let declarations: Vec<_> = model.declarations().map(|d| d.name()).collect();
+        assert_eq!(declarations, ["--primary", "--spacing"]);
+
+        let registration = model.registrations().next().unwrap();
+        assert_eq!(registration.name(), "--angle");
+        assert!(registration.has_initial_value());
+
+        let references: Vec<_> = model.references().map(|r| r.name()).collect();
+        assert_eq!(references, ["--primary", "--spacing", "--gap", "--angle"]);
+
+        assert!(model.is_declared("--angle"));
+        assert!(model.is_declared("--primary"));
+        assert!(!model.is_declared("--gap"));
+        assert!(model.is_referenced("--gap"));
+        assert!(!model.is_referenced("--missing"));
+        assert!(model.has_initial_value("--angle"));
+        assert!(!model.has_initial_value("--primary"));
+    }
+
+    #[test]
+    pub fn ok_semantic_model_var_fallbacks() {
+        let source = "a { color: var(--a); margin: var(--b, 1px 2px); padding: var(--c,); }";
+        let r = parse_css(source, CssParserOptions::default());
+        let model = semantic_model(&r.tree());
+
+        let fallbacks: Vec<_> = r
+            .syntax()
+            .descendants()
+            .filter_map(CssFunction::cast)
+            .map(|function| {
+                let reference = model.as_reference(&function).unwrap();
+                reference.fallback().map(|range| &source[range])
+            })
+            .collect();
+        assert_eq!(fallbacks, [None, Some("1px 2px"), Some(",")]);
+    }
+
+    #[test]
+    pub fn ok_semantic_model_ignores_other_functions() {
+        let r = parse_css(
+            "a { color: rgb(var(--red) 0 0); width: calc(100% - 1px); margin: var(spacing); }",
+            CssParserOptions::default(),
+        );
+        let model = semantic_model(&r.tree());
+
+        let references: Vec<_> = model.references().map(|r| r.name()).collect();
+        assert_eq!(references, ["--red"]);
+    }
+
+    #[test]
+    pub fn ok_project_custom_properties() {
+        let tokens = parse_css(
+            "@property --angle { syntax: '<angle>'; inherits: false; initial-value: 0deg; }\n:root { --primary: blue; }",
+            CssParserOptions::default(),
+        );
+        let button = parse_css(
+            "button { color: var(--primary); }",
+            CssParserOptions::default(),
+        );
+
+        let mut project = ProjectCustomProperties::default();
+        project.add_model(&semantic_model(&tokens.tree()));
+        project.add_model(&semantic_model(&button.tree()));
+
+        assert!(project.is_declared("--primary"));
+        assert!(project.is_declared("--angle"));
+        assert!(!project.is_declared("--secondary"));
+        assert!(project.is_referenced("--primary"));
+        assert!(!project.is_referenced("--angle"));
+        assert!(project.has_initial_value("--angle"));
+    }
+}
@@ -6,15 +6,17 @@ use std::path::Path;
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
 )]
 pub struct CssFileSource {
-    // Unused until we potentially support postcss/less/sass
-    #[allow(unused)]
     variant: CssVariant,
 }
 
 /// The style of CSS contained in the file.
 ///
-/// Currently, Biome only supports plain CSS, and aims to be compatible with
-/// the latest Recommendation level standards.
+/// Plain CSS aims to be compatible with the latest Recommendation level
+/// standards. SCSS files are parsed with the additional syntax of the
+/// [Sass](https://sass-lang.com/documentation/syntax/) language, and Less
+/// files with the one of [Less](https://lesscss.org/features/). Files named
+/// `*.module.css` are [CSS Modules](https://github.com/css-modules/css-modules),
+/// whose class names are scoped to the file that defines them.
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
@@ -22,6 +24,9 @@ pub struct CssFileSource {
 enum CssVariant {
     #[default]
     Standard,
+    Scss,
+    Less,
+    CssModules,
 }
 
 impl CssFileSource {
@@ -30,6 +35,36 @@ impl CssFileSource {
             variant: CssVariant::Standard,
         }
     }
+
+    pub fn scss() -> Self {
+        Self {
+            variant: CssVariant::Scss,
+        }
+    }
+
+    pub fn less() -> Self {
+        Self {
+            variant: CssVariant::Less,
+        }
+    }
+
+    pub fn css_modules() -> Self {
+        Self {
+            variant: CssVariant::CssModules,
+        }
+    }
+
+    pub const fn is_scss(&self) -> bool {
+        matches!(self.variant, CssVariant::Scss)
+    }
+
+    pub const fn is_less(&self) -> bool {
+        matches!(self.variant, CssVariant::Less)
+    }
+
+    pub const fn is_css_modules(&self) -> bool {
+        matches!(self.variant, CssVariant::CssModules)
+    }
 }
 
 impl TryFrom<&Path> for CssFileSource {
@@ -57,11 +92,15 @@ fn compute_source_type_from_path_or_extension(
     file_name: &str,
     extension: &str,
 ) -> Result<CssFileSource, FileSourceError> {
-    let source_type = if file_name.ends_with(".css") {
+    let source_type = if file_name.ends_with(".module.css") {
+        CssFileSource::css_modules()
+    } else if file_name.ends_with(".css") {
         CssFileSource::css()
     } else {
         match extension {
             "css" => CssFileSource::css(),
+            "scss" => CssFileSource::scss(),
+            "less" => CssFileSource::less(),
             _ => {
                 return Err(FileSourceError::UnknownExtension(
                     file_name.into(),
@@ -60,6 +60,19 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssComplexSelector::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_COMPOSES_IMPORT_SPECIFIER => {
+                    let $pattern =
+                        unsafe { $crate::CssComposesImportSpecifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COMPOSES_PROPERTY => {
+                    let $pattern = unsafe { $crate::CssComposesProperty::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COMPOSES_PROPERTY_VALUE => {
+                    let $pattern = unsafe { $crate::CssComposesPropertyValue::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_COMPOUND_SELECTOR => {
                     let $pattern = unsafe { $crate::CssCompoundSelector::new_unchecked(node) };
                     $body
@@ -589,6 +602,237 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssUrlValueRaw::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_VALUE_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssValueAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_VALUE_AT_RULE_DECLARATION_CLAUSE => {
+                    let $pattern =
+                        unsafe { $crate::CssValueAtRuleDeclarationClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_VALUE_AT_RULE_IMPORT_CLAUSE => {
+                    let $pattern =
+                        unsafe { $crate::CssValueAtRuleImportClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_VALUE_AT_RULE_IMPORT_SPECIFIER => {
+                    let $pattern =
+                        unsafe { $crate::CssValueAtRuleImportSpecifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_VALUE_AT_RULE_NAMED_IMPORT_SPECIFIER => {
+                    let $pattern =
+                        unsafe { $crate::CssValueAtRuleNamedImportSpecifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_DECLARATION => {
+                    let $pattern = unsafe { $crate::LessDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_ESCAPED_STRING => {
+                    let $pattern = unsafe { $crate::LessEscapedString::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_EXTEND_DECLARATION => {
+                    let $pattern = unsafe { $crate::LessExtendDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_EXTEND_TARGET => {
+                    let $pattern = unsafe { $crate::LessExtendTarget::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_IDENTIFIER => {
+                    let $pattern = unsafe { $crate::LessIdentifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_IMPORT_AT_RULE => {
+                    let $pattern = unsafe { $crate::LessImportAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_IMPORT_OPTIONS => {
+                    let $pattern = unsafe { $crate::LessImportOptions::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_CALL => {
+                    let $pattern = unsafe { $crate::LessMixinCall::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_DECLARATION => {
+                    let $pattern = unsafe { $crate::LessMixinDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_GUARD => {
+                    let $pattern = unsafe { $crate::LessMixinGuard::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PARAMETER => {
+                    let $pattern = unsafe { $crate::LessMixinParameter::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PARAMETER_NAME => {
+                    let $pattern = unsafe { $crate::LessMixinParameterName::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PARAMETERS => {
+                    let $pattern = unsafe { $crate::LessMixinParameters::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_SEMICOLON_PARAMETERS => {
+                    let $pattern =
+                        unsafe { $crate::LessMixinSemicolonParameters::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_OPERATOR => {
+                    let $pattern = unsafe { $crate::LessOperator::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_PARENTHESIZED_EXPRESSION => {
+                    let $pattern =
+                        unsafe { $crate::LessParenthesizedExpression::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_PLUGIN_AT_RULE => {
+                    let $pattern = unsafe { $crate::LessPluginAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_PSEUDO_CLASS_EXTEND => {
+                    let $pattern = unsafe { $crate::LessPseudoClassExtend::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ARGUMENT => {
+                    let $pattern = unsafe { $crate::ScssArgument::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ARGUMENT_NAME => {
+                    let $pattern = unsafe { $crate::ScssArgumentName::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_CONTENT_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssContentAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_DECLARATION => {
+                    let $pattern = unsafe { $crate::ScssDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EACH_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssEachAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ELSE_CLAUSE => {
+                    let $pattern = unsafe { $crate::ScssElseClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EXTEND_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssExtendAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EXTEND_OPTIONAL => {
+                    let $pattern = unsafe { $crate::ScssExtendOptional::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FOR_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssForAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_AS => {
+                    let $pattern = unsafe { $crate::ScssForwardAs::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssForwardAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_VISIBILITY => {
+                    let $pattern = unsafe { $crate::ScssForwardVisibility::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_IDENTIFIER => {
+                    let $pattern = unsafe { $crate::ScssIdentifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_IF_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssIfAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INCLUDE_ARGUMENTS => {
+                    let $pattern = unsafe { $crate::ScssIncludeArguments::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INCLUDE_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssIncludeAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INTERPOLATION => {
+                    let $pattern = unsafe { $crate::ScssInterpolation::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MAP_EXPRESSION => {
+                    let $pattern = unsafe { $crate::ScssMapExpression::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR => {
+                    let $pattern = unsafe { $crate::ScssMapExpressionPair::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MIXIN_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssMixinAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MODULE_CONFIGURATION => {
+                    let $pattern = unsafe { $crate::ScssModuleConfiguration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_OPERATOR => {
+                    let $pattern = unsafe { $crate::ScssOperator::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER => {
+                    let $pattern = unsafe { $crate::ScssParameter::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER_DEFAULT => {
+                    let $pattern = unsafe { $crate::ScssParameterDefault::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETERS => {
+                    let $pattern = unsafe { $crate::ScssParameters::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARENTHESIZED_EXPRESSION => {
+                    let $pattern =
+                        unsafe { $crate::ScssParenthesizedExpression::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PLACEHOLDER_SELECTOR => {
+                    let $pattern = unsafe { $crate::ScssPlaceholderSelector::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_QUALIFIED_NAME => {
+                    let $pattern = unsafe { $crate::ScssQualifiedName::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_USE_ALL_NAMESPACE => {
+                    let $pattern = unsafe { $crate::ScssUseAllNamespace::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_USE_AS => {
+                    let $pattern = unsafe { $crate::ScssUseAs::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_USE_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssUseAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_VARIABLE_MODIFIER => {
+                    let $pattern = unsafe { $crate::ScssVariableModifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_WHILE_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssWhileAtRule::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_BOGUS => {
                     let $pattern = unsafe { $crate::CssBogus::new_unchecked(node) };
                     $body
@@ -675,6 +919,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssComponentValueList::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_COMPOSES_CLASS_LIST => {
+                    let $pattern = unsafe { $crate::CssComposesClassList::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_COMPOUND_SELECTOR_LIST => {
                     let $pattern = unsafe { $crate::CssCompoundSelectorList::new_unchecked(node) };
                     $body
@@ -767,6 +1015,53 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssUrlModifierList::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_VALUE_AT_RULE_IMPORT_SPECIFIER_LIST => {
+                    let $pattern =
+                        unsafe { $crate::CssValueAtRuleImportSpecifierList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_EXTEND_TARGET_LIST => {
+                    let $pattern = unsafe { $crate::LessExtendTargetList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_IMPORT_OPTION_LIST => {
+                    let $pattern = unsafe { $crate::LessImportOptionList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PARAMETER_LIST => {
+                    let $pattern = unsafe { $crate::LessMixinParameterList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_SEMICOLON_PARAMETER_LIST => {
+                    let $pattern =
+                        unsafe { $crate::LessMixinSemicolonParameterList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ARGUMENT_LIST => {
+                    let $pattern = unsafe { $crate::ScssArgumentList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EACH_BINDING_LIST => {
+                    let $pattern = unsafe { $crate::ScssEachBindingList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_MEMBER_LIST => {
+                    let $pattern = unsafe { $crate::ScssForwardMemberList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR_LIST => {
+                    let $pattern =
+                        unsafe { $crate::ScssMapExpressionPairList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER_LIST => {
+                    let $pattern = unsafe { $crate::ScssParameterList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_VARIABLE_MODIFIER_LIST => {
+                    let $pattern = unsafe { $crate::ScssVariableModifierList::new_unchecked(node) };
+                    $body
+                }
                 _ => unreachable!(),
             },
         }
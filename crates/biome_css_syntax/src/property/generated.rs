@@ -0,0 +1,3396 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use super::{CssPropertyDefinition, ValueGrammar, ValueType};
+
+static ABSOLUTE_SIZE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("xx-small"),
+    &ValueGrammar::Keyword("x-small"),
+    &ValueGrammar::Keyword("small"),
+    &ValueGrammar::Keyword("medium"),
+    &ValueGrammar::Keyword("large"),
+    &ValueGrammar::Keyword("x-large"),
+    &ValueGrammar::Keyword("xx-large"),
+    &ValueGrammar::Keyword("xxx-large"),
+]);
+static ATTACHMENT: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("scroll"),
+    &ValueGrammar::Keyword("fixed"),
+    &ValueGrammar::Keyword("local"),
+]);
+static BASELINE_POSITION: ValueGrammar = ValueGrammar::Sequence(&[
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("first"),
+            &ValueGrammar::Keyword("last"),
+        ]),
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+    &ValueGrammar::Keyword("baseline"),
+]);
+static BG_CLIP: ValueGrammar = ValueGrammar::OneOf(&[
+    &VISUAL_BOX,
+    &ValueGrammar::Keyword("border-area"),
+    &ValueGrammar::Keyword("text"),
+]);
+static BG_IMAGE: ValueGrammar = ValueGrammar::OneOf(&[&ValueGrammar::Keyword("none"), &IMAGE]);
+static BG_LAYER: ValueGrammar = ValueGrammar::AnyOf(&[
+    &BG_IMAGE,
+    &ValueGrammar::Sequence(&[
+        &BG_POSITION,
+        &ValueGrammar::Repeat {
+            grammar: &ValueGrammar::Sequence(&[&ValueGrammar::Delimiter('/'), &BG_SIZE]),
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+    ]),
+    &REPEAT_STYLE,
+    &ATTACHMENT,
+    &BG_CLIP,
+    &VISUAL_BOX,
+    &COLOR,
+]);
+static BG_POSITION: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::OneOf(&[
+        &ValueGrammar::Keyword("left"),
+        &ValueGrammar::Keyword("center"),
+        &ValueGrammar::Keyword("right"),
+        &ValueGrammar::Keyword("top"),
+        &ValueGrammar::Keyword("bottom"),
+        &LENGTH_PERCENTAGE,
+    ]),
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("left"),
+            &ValueGrammar::Keyword("center"),
+            &ValueGrammar::Keyword("right"),
+            &LENGTH_PERCENTAGE,
+        ]),
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("top"),
+            &ValueGrammar::Keyword("center"),
+            &ValueGrammar::Keyword("bottom"),
+            &LENGTH_PERCENTAGE,
+        ]),
+    ]),
+    &ValueGrammar::AllOf(&[
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("center"),
+            &ValueGrammar::Sequence(&[
+                &ValueGrammar::OneOf(&[
+                    &ValueGrammar::Keyword("left"),
+                    &ValueGrammar::Keyword("right"),
+                ]),
+                &ValueGrammar::Repeat {
+                    grammar: &LENGTH_PERCENTAGE,
+                    min: 0,
+                    max: Some(1),
+                    comma_separated: false,
+                },
+            ]),
+        ]),
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("center"),
+            &ValueGrammar::Sequence(&[
+                &ValueGrammar::OneOf(&[
+                    &ValueGrammar::Keyword("top"),
+                    &ValueGrammar::Keyword("bottom"),
+                ]),
+                &ValueGrammar::Repeat {
+                    grammar: &LENGTH_PERCENTAGE,
+                    min: 0,
+                    max: Some(1),
+                    comma_separated: false,
+                },
+            ]),
+        ]),
+    ]),
+]);
+static BG_SIZE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::OneOf(&[&LENGTH_PERCENTAGE, &ValueGrammar::Keyword("auto")]),
+        min: 1,
+        max: Some(2),
+        comma_separated: false,
+    },
+    &ValueGrammar::Keyword("cover"),
+    &ValueGrammar::Keyword("contain"),
+]);
+static BLEND_MODE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("multiply"),
+    &ValueGrammar::Keyword("screen"),
+    &ValueGrammar::Keyword("overlay"),
+    &ValueGrammar::Keyword("darken"),
+    &ValueGrammar::Keyword("lighten"),
+    &ValueGrammar::Keyword("color-dodge"),
+    &ValueGrammar::Keyword("color-burn"),
+    &ValueGrammar::Keyword("hard-light"),
+    &ValueGrammar::Keyword("soft-light"),
+    &ValueGrammar::Keyword("difference"),
+    &ValueGrammar::Keyword("exclusion"),
+    &ValueGrammar::Keyword("hue"),
+    &ValueGrammar::Keyword("saturation"),
+    &ValueGrammar::Keyword("color"),
+    &ValueGrammar::Keyword("luminosity"),
+]);
+static COLOR: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Type(ValueType::HexColor),
+    &NAMED_COLOR,
+    &SYSTEM_COLOR,
+    &ValueGrammar::Keyword("currentcolor"),
+    &ValueGrammar::Keyword("transparent"),
+    &ValueGrammar::Function("rgb"),
+    &ValueGrammar::Function("rgba"),
+    &ValueGrammar::Function("hsl"),
+    &ValueGrammar::Function("hsla"),
+    &ValueGrammar::Function("hwb"),
+    &ValueGrammar::Function("lab"),
+    &ValueGrammar::Function("lch"),
+    &ValueGrammar::Function("oklab"),
+    &ValueGrammar::Function("oklch"),
+    &ValueGrammar::Function("color"),
+    &ValueGrammar::Function("color-mix"),
+    &ValueGrammar::Function("light-dark"),
+]);
+static CONTENT_DISTRIBUTION: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("space-between"),
+    &ValueGrammar::Keyword("space-around"),
+    &ValueGrammar::Keyword("space-evenly"),
+    &ValueGrammar::Keyword("stretch"),
+]);
+static CONTENT_POSITION: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("center"),
+    &ValueGrammar::Keyword("start"),
+    &ValueGrammar::Keyword("end"),
+    &ValueGrammar::Keyword("flex-start"),
+    &ValueGrammar::Keyword("flex-end"),
+]);
+static CURSOR_KEYWORD: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("default"),
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Keyword("context-menu"),
+    &ValueGrammar::Keyword("help"),
+    &ValueGrammar::Keyword("pointer"),
+    &ValueGrammar::Keyword("progress"),
+    &ValueGrammar::Keyword("wait"),
+    &ValueGrammar::Keyword("cell"),
+    &ValueGrammar::Keyword("crosshair"),
+    &ValueGrammar::Keyword("text"),
+    &ValueGrammar::Keyword("vertical-text"),
+    &ValueGrammar::Keyword("alias"),
+    &ValueGrammar::Keyword("copy"),
+    &ValueGrammar::Keyword("move"),
+    &ValueGrammar::Keyword("no-drop"),
+    &ValueGrammar::Keyword("not-allowed"),
+    &ValueGrammar::Keyword("grab"),
+    &ValueGrammar::Keyword("grabbing"),
+    &ValueGrammar::Keyword("e-resize"),
+    &ValueGrammar::Keyword("n-resize"),
+    &ValueGrammar::Keyword("ne-resize"),
+    &ValueGrammar::Keyword("nw-resize"),
+    &ValueGrammar::Keyword("s-resize"),
+    &ValueGrammar::Keyword("se-resize"),
+    &ValueGrammar::Keyword("sw-resize"),
+    &ValueGrammar::Keyword("w-resize"),
+    &ValueGrammar::Keyword("ew-resize"),
+    &ValueGrammar::Keyword("ns-resize"),
+    &ValueGrammar::Keyword("nesw-resize"),
+    &ValueGrammar::Keyword("nwse-resize"),
+    &ValueGrammar::Keyword("col-resize"),
+    &ValueGrammar::Keyword("row-resize"),
+    &ValueGrammar::Keyword("all-scroll"),
+    &ValueGrammar::Keyword("zoom-in"),
+    &ValueGrammar::Keyword("zoom-out"),
+]);
+static DISPLAY_BOX: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("contents"),
+    &ValueGrammar::Keyword("none"),
+]);
+static DISPLAY_INSIDE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("flow"),
+    &ValueGrammar::Keyword("flow-root"),
+    &ValueGrammar::Keyword("table"),
+    &ValueGrammar::Keyword("flex"),
+    &ValueGrammar::Keyword("grid"),
+    &ValueGrammar::Keyword("ruby"),
+    &ValueGrammar::Keyword("math"),
+]);
+static DISPLAY_INTERNAL: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("table-row-group"),
+    &ValueGrammar::Keyword("table-header-group"),
+    &ValueGrammar::Keyword("table-footer-group"),
+    &ValueGrammar::Keyword("table-row"),
+    &ValueGrammar::Keyword("table-cell"),
+    &ValueGrammar::Keyword("table-column-group"),
+    &ValueGrammar::Keyword("table-column"),
+    &ValueGrammar::Keyword("table-caption"),
+    &ValueGrammar::Keyword("ruby-base"),
+    &ValueGrammar::Keyword("ruby-text"),
+    &ValueGrammar::Keyword("ruby-base-container"),
+    &ValueGrammar::Keyword("ruby-text-container"),
+]);
+static DISPLAY_LEGACY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("inline-block"),
+    &ValueGrammar::Keyword("inline-table"),
+    &ValueGrammar::Keyword("inline-flex"),
+    &ValueGrammar::Keyword("inline-grid"),
+]);
+static DISPLAY_LISTITEM: ValueGrammar = ValueGrammar::AllOf(&[
+    &ValueGrammar::Repeat {
+        grammar: &DISPLAY_OUTSIDE,
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("flow"),
+            &ValueGrammar::Keyword("flow-root"),
+        ]),
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+    &ValueGrammar::Keyword("list-item"),
+]);
+static DISPLAY_OUTSIDE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("block"),
+    &ValueGrammar::Keyword("inline"),
+    &ValueGrammar::Keyword("run-in"),
+]);
+static EASING_FUNCTION: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("linear"),
+    &ValueGrammar::Keyword("ease"),
+    &ValueGrammar::Keyword("ease-in"),
+    &ValueGrammar::Keyword("ease-out"),
+    &ValueGrammar::Keyword("ease-in-out"),
+    &ValueGrammar::Keyword("step-start"),
+    &ValueGrammar::Keyword("step-end"),
+    &ValueGrammar::Function("linear"),
+    &ValueGrammar::Function("cubic-bezier"),
+    &ValueGrammar::Function("steps"),
+]);
+static FILTER_FUNCTION: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Function("blur"),
+    &ValueGrammar::Function("brightness"),
+    &ValueGrammar::Function("contrast"),
+    &ValueGrammar::Function("drop-shadow"),
+    &ValueGrammar::Function("grayscale"),
+    &ValueGrammar::Function("hue-rotate"),
+    &ValueGrammar::Function("invert"),
+    &ValueGrammar::Function("opacity"),
+    &ValueGrammar::Function("saturate"),
+    &ValueGrammar::Function("sepia"),
+]);
+static FONT_VARIANT_CSS2: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("small-caps"),
+]);
+static FONT_WIDTH_CSS3: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("ultra-condensed"),
+    &ValueGrammar::Keyword("extra-condensed"),
+    &ValueGrammar::Keyword("condensed"),
+    &ValueGrammar::Keyword("semi-condensed"),
+    &ValueGrammar::Keyword("semi-expanded"),
+    &ValueGrammar::Keyword("expanded"),
+    &ValueGrammar::Keyword("extra-expanded"),
+    &ValueGrammar::Keyword("ultra-expanded"),
+]);
+static GRADIENT: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Function("linear-gradient"),
+    &ValueGrammar::Function("repeating-linear-gradient"),
+    &ValueGrammar::Function("radial-gradient"),
+    &ValueGrammar::Function("repeating-radial-gradient"),
+    &ValueGrammar::Function("conic-gradient"),
+    &ValueGrammar::Function("repeating-conic-gradient"),
+]);
+static GRID_LINE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Type(ValueType::CustomIdent),
+    &ValueGrammar::AllOf(&[
+        &ValueGrammar::Type(ValueType::Integer),
+        &ValueGrammar::Repeat {
+            grammar: &ValueGrammar::Type(ValueType::CustomIdent),
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+    ]),
+    &ValueGrammar::AllOf(&[
+        &ValueGrammar::Keyword("span"),
+        &ValueGrammar::AnyOf(&[
+            &ValueGrammar::Type(ValueType::Integer),
+            &ValueGrammar::Type(ValueType::CustomIdent),
+        ]),
+    ]),
+]);
+static IMAGE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Type(ValueType::Url),
+    &GRADIENT,
+    &ValueGrammar::Function("image"),
+    &ValueGrammar::Function("image-set"),
+    &ValueGrammar::Function("cross-fade"),
+    &ValueGrammar::Function("element"),
+    &ValueGrammar::Function("paint"),
+]);
+static KEYFRAMES_NAME: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Type(ValueType::CustomIdent),
+    &ValueGrammar::Type(ValueType::String),
+]);
+static LENGTH_PERCENTAGE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Type(ValueType::Length),
+    &ValueGrammar::Type(ValueType::Percentage),
+]);
+static LINE_STYLE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Keyword("hidden"),
+    &ValueGrammar::Keyword("dotted"),
+    &ValueGrammar::Keyword("dashed"),
+    &ValueGrammar::Keyword("solid"),
+    &ValueGrammar::Keyword("double"),
+    &ValueGrammar::Keyword("groove"),
+    &ValueGrammar::Keyword("ridge"),
+    &ValueGrammar::Keyword("inset"),
+    &ValueGrammar::Keyword("outset"),
+]);
+static LINE_WIDTH: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Type(ValueType::Length),
+    &ValueGrammar::Keyword("thin"),
+    &ValueGrammar::Keyword("medium"),
+    &ValueGrammar::Keyword("thick"),
+]);
+static NAMED_COLOR: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("aliceblue"),
+    &ValueGrammar::Keyword("antiquewhite"),
+    &ValueGrammar::Keyword("aqua"),
+    &ValueGrammar::Keyword("aquamarine"),
+    &ValueGrammar::Keyword("azure"),
+    &ValueGrammar::Keyword("beige"),
+    &ValueGrammar::Keyword("bisque"),
+    &ValueGrammar::Keyword("black"),
+    &ValueGrammar::Keyword("blanchedalmond"),
+    &ValueGrammar::Keyword("blue"),
+    &ValueGrammar::Keyword("blueviolet"),
+    &ValueGrammar::Keyword("brown"),
+    &ValueGrammar::Keyword("burlywood"),
+    &ValueGrammar::Keyword("cadetblue"),
+    &ValueGrammar::Keyword("chartreuse"),
+    &ValueGrammar::Keyword("chocolate"),
+    &ValueGrammar::Keyword("coral"),
+    &ValueGrammar::Keyword("cornflowerblue"),
+    &ValueGrammar::Keyword("cornsilk"),
+    &ValueGrammar::Keyword("crimson"),
+    &ValueGrammar::Keyword("cyan"),
+    &ValueGrammar::Keyword("darkblue"),
+    &ValueGrammar::Keyword("darkcyan"),
+    &ValueGrammar::Keyword("darkgoldenrod"),
+    &ValueGrammar::Keyword("darkgray"),
+    &ValueGrammar::Keyword("darkgreen"),
+    &ValueGrammar::Keyword("darkgrey"),
+    &ValueGrammar::Keyword("darkkhaki"),
+    &ValueGrammar::Keyword("darkmagenta"),
+    &ValueGrammar::Keyword("darkolivegreen"),
+    &ValueGrammar::Keyword("darkorange"),
+    &ValueGrammar::Keyword("darkorchid"),
+    &ValueGrammar::Keyword("darkred"),
+    &ValueGrammar::Keyword("darksalmon"),
+    &ValueGrammar::Keyword("darkseagreen"),
+    &ValueGrammar::Keyword("darkslateblue"),
+    &ValueGrammar::Keyword("darkslategray"),
+    &ValueGrammar::Keyword("darkslategrey"),
+    &ValueGrammar::Keyword("darkturquoise"),
+    &ValueGrammar::Keyword("darkviolet"),
+    &ValueGrammar::Keyword("deeppink"),
+    &ValueGrammar::Keyword("deepskyblue"),
+    &ValueGrammar::Keyword("dimgray"),
+    &ValueGrammar::Keyword("dimgrey"),
+    &ValueGrammar::Keyword("dodgerblue"),
+    &ValueGrammar::Keyword("firebrick"),
+    &ValueGrammar::Keyword("floralwhite"),
+    &ValueGrammar::Keyword("forestgreen"),
+    &ValueGrammar::Keyword("fuchsia"),
+    &ValueGrammar::Keyword("gainsboro"),
+    &ValueGrammar::Keyword("ghostwhite"),
+    &ValueGrammar::Keyword("gold"),
+    &ValueGrammar::Keyword("goldenrod"),
+    &ValueGrammar::Keyword("gray"),
+    &ValueGrammar::Keyword("green"),
+    &ValueGrammar::Keyword("greenyellow"),
+    &ValueGrammar::Keyword("grey"),
+    &ValueGrammar::Keyword("honeydew"),
+    &ValueGrammar::Keyword("hotpink"),
+    &ValueGrammar::Keyword("indianred"),
+    &ValueGrammar::Keyword("indigo"),
+    &ValueGrammar::Keyword("ivory"),
+    &ValueGrammar::Keyword("khaki"),
+    &ValueGrammar::Keyword("lavender"),
+    &ValueGrammar::Keyword("lavenderblush"),
+    &ValueGrammar::Keyword("lawngreen"),
+    &ValueGrammar::Keyword("lemonchiffon"),
+    &ValueGrammar::Keyword("lightblue"),
+    &ValueGrammar::Keyword("lightcoral"),
+    &ValueGrammar::Keyword("lightcyan"),
+    &ValueGrammar::Keyword("lightgoldenrodyellow"),
+    &ValueGrammar::Keyword("lightgray"),
+    &ValueGrammar::Keyword("lightgreen"),
+    &ValueGrammar::Keyword("lightgrey"),
+    &ValueGrammar::Keyword("lightpink"),
+    &ValueGrammar::Keyword("lightsalmon"),
+    &ValueGrammar::Keyword("lightseagreen"),
+    &ValueGrammar::Keyword("lightskyblue"),
+    &ValueGrammar::Keyword("lightslategray"),
+    &ValueGrammar::Keyword("lightslategrey"),
+    &ValueGrammar::Keyword("lightsteelblue"),
+    &ValueGrammar::Keyword("lightyellow"),
+    &ValueGrammar::Keyword("lime"),
+    &ValueGrammar::Keyword("limegreen"),
+    &ValueGrammar::Keyword("linen"),
+    &ValueGrammar::Keyword("magenta"),
+    &ValueGrammar::Keyword("maroon"),
+    &ValueGrammar::Keyword("mediumaquamarine"),
+    &ValueGrammar::Keyword("mediumblue"),
+    &ValueGrammar::Keyword("mediumorchid"),
+    &ValueGrammar::Keyword("mediumpurple"),
+    &ValueGrammar::Keyword("mediumseagreen"),
+    &ValueGrammar::Keyword("mediumslateblue"),
+    &ValueGrammar::Keyword("mediumspringgreen"),
+    &ValueGrammar::Keyword("mediumturquoise"),
+    &ValueGrammar::Keyword("mediumvioletred"),
+    &ValueGrammar::Keyword("midnightblue"),
+    &ValueGrammar::Keyword("mintcream"),
+    &ValueGrammar::Keyword("mistyrose"),
+    &ValueGrammar::Keyword("moccasin"),
+    &ValueGrammar::Keyword("navajowhite"),
+    &ValueGrammar::Keyword("navy"),
+    &ValueGrammar::Keyword("oldlace"),
+    &ValueGrammar::Keyword("olive"),
+    &ValueGrammar::Keyword("olivedrab"),
+    &ValueGrammar::Keyword("orange"),
+    &ValueGrammar::Keyword("orangered"),
+    &ValueGrammar::Keyword("orchid"),
+    &ValueGrammar::Keyword("palegoldenrod"),
+    &ValueGrammar::Keyword("palegreen"),
+    &ValueGrammar::Keyword("paleturquoise"),
+    &ValueGrammar::Keyword("palevioletred"),
+    &ValueGrammar::Keyword("papayawhip"),
+    &ValueGrammar::Keyword("peachpuff"),
+    &ValueGrammar::Keyword("peru"),
+    &ValueGrammar::Keyword("pink"),
+    &ValueGrammar::Keyword("plum"),
+    &ValueGrammar::Keyword("powderblue"),
+    &ValueGrammar::Keyword("purple"),
+    &ValueGrammar::Keyword("rebeccapurple"),
+    &ValueGrammar::Keyword("red"),
+    &ValueGrammar::Keyword("rosybrown"),
+    &ValueGrammar::Keyword("royalblue"),
+    &ValueGrammar::Keyword("saddlebrown"),
+    &ValueGrammar::Keyword("salmon"),
+    &ValueGrammar::Keyword("sandybrown"),
+    &ValueGrammar::Keyword("seagreen"),
+    &ValueGrammar::Keyword("seashell"),
+    &ValueGrammar::Keyword("sienna"),
+    &ValueGrammar::Keyword("silver"),
+    &ValueGrammar::Keyword("skyblue"),
+    &ValueGrammar::Keyword("slateblue"),
+    &ValueGrammar::Keyword("slategray"),
+    &ValueGrammar::Keyword("slategrey"),
+    &ValueGrammar::Keyword("snow"),
+    &ValueGrammar::Keyword("springgreen"),
+    &ValueGrammar::Keyword("steelblue"),
+    &ValueGrammar::Keyword("tan"),
+    &ValueGrammar::Keyword("teal"),
+    &ValueGrammar::Keyword("thistle"),
+    &ValueGrammar::Keyword("tomato"),
+    &ValueGrammar::Keyword("turquoise"),
+    &ValueGrammar::Keyword("violet"),
+    &ValueGrammar::Keyword("wheat"),
+    &ValueGrammar::Keyword("white"),
+    &ValueGrammar::Keyword("whitesmoke"),
+    &ValueGrammar::Keyword("yellow"),
+    &ValueGrammar::Keyword("yellowgreen"),
+]);
+static OVERFLOW_POSITION: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("unsafe"),
+    &ValueGrammar::Keyword("safe"),
+]);
+static RATIO: ValueGrammar = ValueGrammar::Sequence(&[
+    &ValueGrammar::Type(ValueType::Number),
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Sequence(&[
+            &ValueGrammar::Delimiter('/'),
+            &ValueGrammar::Type(ValueType::Number),
+        ]),
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+]);
+static RELATIVE_SIZE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("larger"),
+    &ValueGrammar::Keyword("smaller"),
+]);
+static REPEAT_STYLE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("repeat-x"),
+    &ValueGrammar::Keyword("repeat-y"),
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("repeat"),
+            &ValueGrammar::Keyword("space"),
+            &ValueGrammar::Keyword("round"),
+            &ValueGrammar::Keyword("no-repeat"),
+        ]),
+        min: 1,
+        max: Some(2),
+        comma_separated: false,
+    },
+]);
+static SELF_POSITION: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("center"),
+    &ValueGrammar::Keyword("start"),
+    &ValueGrammar::Keyword("end"),
+    &ValueGrammar::Keyword("self-start"),
+    &ValueGrammar::Keyword("self-end"),
+    &ValueGrammar::Keyword("flex-start"),
+    &ValueGrammar::Keyword("flex-end"),
+    &ValueGrammar::Keyword("anchor-center"),
+]);
+static SHADOW: ValueGrammar = ValueGrammar::AllOf(&[
+    &ValueGrammar::Repeat {
+        grammar: &COLOR,
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Type(ValueType::Length),
+        min: 2,
+        max: Some(4),
+        comma_separated: false,
+    },
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Keyword("inset"),
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+]);
+static SINGLE_ANIMATION: ValueGrammar = ValueGrammar::AnyOf(&[
+    &ValueGrammar::Type(ValueType::Time),
+    &EASING_FUNCTION,
+    &ValueGrammar::Type(ValueType::Time),
+    &SINGLE_ANIMATION_ITERATION_COUNT,
+    &SINGLE_ANIMATION_DIRECTION,
+    &SINGLE_ANIMATION_FILL_MODE,
+    &SINGLE_ANIMATION_PLAY_STATE,
+    &ValueGrammar::OneOf(&[&ValueGrammar::Keyword("none"), &KEYFRAMES_NAME]),
+]);
+static SINGLE_ANIMATION_DIRECTION: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("reverse"),
+    &ValueGrammar::Keyword("alternate"),
+    &ValueGrammar::Keyword("alternate-reverse"),
+]);
+static SINGLE_ANIMATION_FILL_MODE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Keyword("forwards"),
+    &ValueGrammar::Keyword("backwards"),
+    &ValueGrammar::Keyword("both"),
+]);
+static SINGLE_ANIMATION_ITERATION_COUNT: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("infinite"),
+    &ValueGrammar::Type(ValueType::Number),
+]);
+static SINGLE_ANIMATION_PLAY_STATE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("running"),
+    &ValueGrammar::Keyword("paused"),
+]);
+static SINGLE_TRANSITION: ValueGrammar = ValueGrammar::AnyOf(&[
+    &ValueGrammar::OneOf(&[&ValueGrammar::Keyword("none"), &SINGLE_TRANSITION_PROPERTY]),
+    &ValueGrammar::Type(ValueType::Time),
+    &EASING_FUNCTION,
+    &ValueGrammar::Type(ValueType::Time),
+    &TRANSITION_BEHAVIOR_VALUE,
+]);
+static SINGLE_TRANSITION_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("all"),
+    &ValueGrammar::Type(ValueType::CustomIdent),
+]);
+static SYSTEM_COLOR: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("accentcolor"),
+    &ValueGrammar::Keyword("accentcolortext"),
+    &ValueGrammar::Keyword("activetext"),
+    &ValueGrammar::Keyword("buttonborder"),
+    &ValueGrammar::Keyword("buttonface"),
+    &ValueGrammar::Keyword("buttontext"),
+    &ValueGrammar::Keyword("canvas"),
+    &ValueGrammar::Keyword("canvastext"),
+    &ValueGrammar::Keyword("field"),
+    &ValueGrammar::Keyword("fieldtext"),
+    &ValueGrammar::Keyword("graytext"),
+    &ValueGrammar::Keyword("highlight"),
+    &ValueGrammar::Keyword("highlighttext"),
+    &ValueGrammar::Keyword("linktext"),
+    &ValueGrammar::Keyword("mark"),
+    &ValueGrammar::Keyword("marktext"),
+    &ValueGrammar::Keyword("selecteditem"),
+    &ValueGrammar::Keyword("selecteditemtext"),
+    &ValueGrammar::Keyword("visitedtext"),
+    &ValueGrammar::Keyword("activeborder"),
+    &ValueGrammar::Keyword("activecaption"),
+    &ValueGrammar::Keyword("appworkspace"),
+    &ValueGrammar::Keyword("background"),
+    &ValueGrammar::Keyword("buttonhighlight"),
+    &ValueGrammar::Keyword("buttonshadow"),
+    &ValueGrammar::Keyword("captiontext"),
+    &ValueGrammar::Keyword("inactiveborder"),
+    &ValueGrammar::Keyword("inactivecaption"),
+    &ValueGrammar::Keyword("inactivecaptiontext"),
+    &ValueGrammar::Keyword("infobackground"),
+    &ValueGrammar::Keyword("infotext"),
+    &ValueGrammar::Keyword("menu"),
+    &ValueGrammar::Keyword("menutext"),
+    &ValueGrammar::Keyword("scrollbar"),
+    &ValueGrammar::Keyword("threeddarkshadow"),
+    &ValueGrammar::Keyword("threedface"),
+    &ValueGrammar::Keyword("threedhighlight"),
+    &ValueGrammar::Keyword("threedlightshadow"),
+    &ValueGrammar::Keyword("threedshadow"),
+    &ValueGrammar::Keyword("window"),
+    &ValueGrammar::Keyword("windowframe"),
+    &ValueGrammar::Keyword("windowtext"),
+]);
+static TRANSFORM_FUNCTION: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Function("matrix"),
+    &ValueGrammar::Function("matrix3d"),
+    &ValueGrammar::Function("perspective"),
+    &ValueGrammar::Function("rotate"),
+    &ValueGrammar::Function("rotate3d"),
+    &ValueGrammar::Function("rotatex"),
+    &ValueGrammar::Function("rotatey"),
+    &ValueGrammar::Function("rotatez"),
+    &ValueGrammar::Function("scale"),
+    &ValueGrammar::Function("scale3d"),
+    &ValueGrammar::Function("scalex"),
+    &ValueGrammar::Function("scaley"),
+    &ValueGrammar::Function("scalez"),
+    &ValueGrammar::Function("skew"),
+    &ValueGrammar::Function("skewx"),
+    &ValueGrammar::Function("skewy"),
+    &ValueGrammar::Function("translate"),
+    &ValueGrammar::Function("translate3d"),
+    &ValueGrammar::Function("translatex"),
+    &ValueGrammar::Function("translatey"),
+    &ValueGrammar::Function("translatez"),
+]);
+static TRANSITION_BEHAVIOR_VALUE: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("allow-discrete"),
+]);
+static VISUAL_BOX: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("content-box"),
+    &ValueGrammar::Keyword("padding-box"),
+    &ValueGrammar::Keyword("border-box"),
+]);
+static ACCENT_COLOR_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("auto"), &COLOR]);
+static ALIGN_CONTENT_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &BASELINE_POSITION,
+    &CONTENT_DISTRIBUTION,
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::Repeat {
+            grammar: &OVERFLOW_POSITION,
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+        &CONTENT_POSITION,
+    ]),
+]);
+static ALIGN_ITEMS_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("stretch"),
+    &BASELINE_POSITION,
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::Repeat {
+            grammar: &OVERFLOW_POSITION,
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+        &SELF_POSITION,
+    ]),
+]);
+static ALIGN_SELF_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("stretch"),
+    &BASELINE_POSITION,
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::Repeat {
+            grammar: &OVERFLOW_POSITION,
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+        &SELF_POSITION,
+    ]),
+]);
+static ANIMATION_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &SINGLE_ANIMATION,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static ANIMATION_DELAY_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &ValueGrammar::Type(ValueType::Time),
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static ANIMATION_DIRECTION_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &SINGLE_ANIMATION_DIRECTION,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static ANIMATION_DURATION_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &ValueGrammar::OneOf(&[
+        &ValueGrammar::Keyword("auto"),
+        &ValueGrammar::Type(ValueType::Time),
+    ]),
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static ANIMATION_FILL_MODE_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &SINGLE_ANIMATION_FILL_MODE,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static ANIMATION_ITERATION_COUNT_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &SINGLE_ANIMATION_ITERATION_COUNT,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static ANIMATION_NAME_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &ValueGrammar::OneOf(&[&ValueGrammar::Keyword("none"), &KEYFRAMES_NAME]),
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static ANIMATION_PLAY_STATE_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &SINGLE_ANIMATION_PLAY_STATE,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static ANIMATION_TIMING_FUNCTION_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &EASING_FUNCTION,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static ASPECT_RATIO_PROPERTY: ValueGrammar =
+    ValueGrammar::AnyOf(&[&ValueGrammar::Keyword("auto"), &RATIO]);
+static BACKDROP_FILTER_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::OneOf(&[&FILTER_FUNCTION, &ValueGrammar::Type(ValueType::Url)]),
+        min: 1,
+        max: None,
+        comma_separated: false,
+    },
+]);
+static BACKFACE_VISIBILITY_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("visible"),
+    &ValueGrammar::Keyword("hidden"),
+]);
+static BACKGROUND_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &BG_LAYER,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static BACKGROUND_ATTACHMENT_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &ATTACHMENT,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static BACKGROUND_BLEND_MODE_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &BLEND_MODE,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static BACKGROUND_CLIP_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &BG_CLIP,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static BACKGROUND_IMAGE_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &BG_IMAGE,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static BACKGROUND_ORIGIN_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &VISUAL_BOX,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static BACKGROUND_POSITION_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &BG_POSITION,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static BACKGROUND_REPEAT_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &REPEAT_STYLE,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static BACKGROUND_SIZE_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &BG_SIZE,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static BORDER_PROPERTY: ValueGrammar = ValueGrammar::AnyOf(&[&LINE_WIDTH, &LINE_STYLE, &COLOR]);
+static BORDER_BLOCK_COLOR_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &COLOR,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_BLOCK_STYLE_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LINE_STYLE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_BLOCK_WIDTH_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LINE_WIDTH,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_BOTTOM_LEFT_RADIUS_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LENGTH_PERCENTAGE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_BOTTOM_RIGHT_RADIUS_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LENGTH_PERCENTAGE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_COLLAPSE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("collapse"),
+    &ValueGrammar::Keyword("separate"),
+]);
+static BORDER_COLOR_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &COLOR,
+    min: 1,
+    max: Some(4),
+    comma_separated: false,
+};
+static BORDER_END_END_RADIUS_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LENGTH_PERCENTAGE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_END_START_RADIUS_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LENGTH_PERCENTAGE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_INLINE_COLOR_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &COLOR,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_INLINE_STYLE_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LINE_STYLE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_INLINE_WIDTH_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LINE_WIDTH,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_RADIUS_PROPERTY: ValueGrammar = ValueGrammar::Sequence(&[
+    &ValueGrammar::Repeat {
+        grammar: &LENGTH_PERCENTAGE,
+        min: 1,
+        max: Some(4),
+        comma_separated: false,
+    },
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Sequence(&[
+            &ValueGrammar::Delimiter('/'),
+            &ValueGrammar::Repeat {
+                grammar: &LENGTH_PERCENTAGE,
+                min: 1,
+                max: Some(4),
+                comma_separated: false,
+            },
+        ]),
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+]);
+static BORDER_SPACING_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &ValueGrammar::Type(ValueType::Length),
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_START_END_RADIUS_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LENGTH_PERCENTAGE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_START_START_RADIUS_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LENGTH_PERCENTAGE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_STYLE_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LINE_STYLE,
+    min: 1,
+    max: Some(4),
+    comma_separated: false,
+};
+static BORDER_TOP_LEFT_RADIUS_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LENGTH_PERCENTAGE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_TOP_RIGHT_RADIUS_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LENGTH_PERCENTAGE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static BORDER_WIDTH_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LINE_WIDTH,
+    min: 1,
+    max: Some(4),
+    comma_separated: false,
+};
+static BOTTOM_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("auto"), &LENGTH_PERCENTAGE]);
+static BOX_DECORATION_BREAK_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("slice"),
+    &ValueGrammar::Keyword("clone"),
+]);
+static BOX_SHADOW_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Repeat {
+        grammar: &SHADOW,
+        min: 1,
+        max: None,
+        comma_separated: true,
+    },
+]);
+static BOX_SIZING_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("content-box"),
+    &ValueGrammar::Keyword("border-box"),
+]);
+static BREAK_AFTER_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("avoid"),
+    &ValueGrammar::Keyword("always"),
+    &ValueGrammar::Keyword("all"),
+    &ValueGrammar::Keyword("avoid-page"),
+    &ValueGrammar::Keyword("page"),
+    &ValueGrammar::Keyword("left"),
+    &ValueGrammar::Keyword("right"),
+    &ValueGrammar::Keyword("recto"),
+    &ValueGrammar::Keyword("verso"),
+    &ValueGrammar::Keyword("avoid-column"),
+    &ValueGrammar::Keyword("column"),
+    &ValueGrammar::Keyword("avoid-region"),
+    &ValueGrammar::Keyword("region"),
+]);
+static BREAK_BEFORE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("avoid"),
+    &ValueGrammar::Keyword("always"),
+    &ValueGrammar::Keyword("all"),
+    &ValueGrammar::Keyword("avoid-page"),
+    &ValueGrammar::Keyword("page"),
+    &ValueGrammar::Keyword("left"),
+    &ValueGrammar::Keyword("right"),
+    &ValueGrammar::Keyword("recto"),
+    &ValueGrammar::Keyword("verso"),
+    &ValueGrammar::Keyword("avoid-column"),
+    &ValueGrammar::Keyword("column"),
+    &ValueGrammar::Keyword("avoid-region"),
+    &ValueGrammar::Keyword("region"),
+]);
+static BREAK_INSIDE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("avoid"),
+    &ValueGrammar::Keyword("avoid-page"),
+    &ValueGrammar::Keyword("avoid-column"),
+    &ValueGrammar::Keyword("avoid-region"),
+]);
+static CAPTION_SIDE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("top"),
+    &ValueGrammar::Keyword("bottom"),
+    &ValueGrammar::Keyword("block-start"),
+    &ValueGrammar::Keyword("block-end"),
+    &ValueGrammar::Keyword("inline-start"),
+    &ValueGrammar::Keyword("inline-end"),
+]);
+static CARET_COLOR_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("auto"), &COLOR]);
+static CLEAR_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("inline-start"),
+    &ValueGrammar::Keyword("inline-end"),
+    &ValueGrammar::Keyword("block-start"),
+    &ValueGrammar::Keyword("block-end"),
+    &ValueGrammar::Keyword("left"),
+    &ValueGrammar::Keyword("right"),
+    &ValueGrammar::Keyword("top"),
+    &ValueGrammar::Keyword("bottom"),
+    &ValueGrammar::Keyword("both-inline"),
+    &ValueGrammar::Keyword("both-block"),
+    &ValueGrammar::Keyword("both"),
+    &ValueGrammar::Keyword("none"),
+]);
+static COLUMN_COUNT_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Type(ValueType::Integer),
+]);
+static COLUMN_GAP_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("normal"), &LENGTH_PERCENTAGE]);
+static COLUMN_WIDTH_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Type(ValueType::Length),
+    &ValueGrammar::Keyword("min-content"),
+    &ValueGrammar::Keyword("max-content"),
+    &ValueGrammar::Keyword("fit-content"),
+    &ValueGrammar::Function("fit-content"),
+]);
+static COLUMNS_PROPERTY: ValueGrammar =
+    ValueGrammar::AnyOf(&[&COLUMN_WIDTH_PROPERTY, &COLUMN_COUNT_PROPERTY]);
+static CONTAIN_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Keyword("strict"),
+    &ValueGrammar::Keyword("content"),
+    &ValueGrammar::AnyOf(&[
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("size"),
+            &ValueGrammar::Keyword("inline-size"),
+        ]),
+        &ValueGrammar::Keyword("layout"),
+        &ValueGrammar::Keyword("style"),
+        &ValueGrammar::Keyword("paint"),
+    ]),
+]);
+static CONTAINER_PROPERTY: ValueGrammar = ValueGrammar::Sequence(&[
+    &CONTAINER_NAME_PROPERTY,
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Sequence(&[
+            &ValueGrammar::Delimiter('/'),
+            &CONTAINER_TYPE_PROPERTY,
+        ]),
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+]);
+static CONTAINER_NAME_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Type(ValueType::CustomIdent),
+        min: 1,
+        max: None,
+        comma_separated: false,
+    },
+]);
+static CONTAINER_TYPE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("size"),
+    &ValueGrammar::Keyword("inline-size"),
+]);
+static CONTENT_VISIBILITY_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("visible"),
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("hidden"),
+]);
+static COUNTER_INCREMENT_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Sequence(&[
+            &ValueGrammar::Type(ValueType::CustomIdent),
+            &ValueGrammar::Repeat {
+                grammar: &ValueGrammar::Type(ValueType::Integer),
+                min: 0,
+                max: Some(1),
+                comma_separated: false,
+            },
+        ]),
+        min: 1,
+        max: None,
+        comma_separated: false,
+    },
+    &ValueGrammar::Keyword("none"),
+]);
+static CURSOR_PROPERTY: ValueGrammar = ValueGrammar::Sequence(&[
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Sequence(&[
+            &ValueGrammar::OneOf(&[
+                &ValueGrammar::Type(ValueType::Url),
+                &ValueGrammar::Function("image-set"),
+            ]),
+            &ValueGrammar::Repeat {
+                grammar: &ValueGrammar::Sequence(&[
+                    &ValueGrammar::Type(ValueType::Number),
+                    &ValueGrammar::Type(ValueType::Number),
+                ]),
+                min: 0,
+                max: Some(1),
+                comma_separated: false,
+            },
+            &ValueGrammar::Delimiter(','),
+        ]),
+        min: 0,
+        max: None,
+        comma_separated: false,
+    },
+    &CURSOR_KEYWORD,
+]);
+static DIRECTION_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("ltr"), &ValueGrammar::Keyword("rtl")]);
+static DISPLAY_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::AnyOf(&[&DISPLAY_OUTSIDE, &DISPLAY_INSIDE]),
+    &DISPLAY_LISTITEM,
+    &DISPLAY_INTERNAL,
+    &DISPLAY_BOX,
+    &DISPLAY_LEGACY,
+]);
+static EMPTY_CELLS_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("show"),
+    &ValueGrammar::Keyword("hide"),
+]);
+static FILTER_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::OneOf(&[&FILTER_FUNCTION, &ValueGrammar::Type(ValueType::Url)]),
+        min: 1,
+        max: None,
+        comma_separated: false,
+    },
+]);
+static FLEX_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::AnyOf(&[
+        &ValueGrammar::Sequence(&[
+            &FLEX_GROW_PROPERTY,
+            &ValueGrammar::Repeat {
+                grammar: &FLEX_SHRINK_PROPERTY,
+                min: 0,
+                max: Some(1),
+                comma_separated: false,
+            },
+        ]),
+        &FLEX_BASIS_PROPERTY,
+    ]),
+]);
+static FLEX_BASIS_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("content"), &WIDTH_PROPERTY]);
+static FLEX_DIRECTION_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("row"),
+    &ValueGrammar::Keyword("row-reverse"),
+    &ValueGrammar::Keyword("column"),
+    &ValueGrammar::Keyword("column-reverse"),
+]);
+static FLEX_FLOW_PROPERTY: ValueGrammar =
+    ValueGrammar::AnyOf(&[&FLEX_DIRECTION_PROPERTY, &FLEX_WRAP_PROPERTY]);
+static FLEX_GROW_PROPERTY: ValueGrammar = ValueGrammar::Type(ValueType::Number);
+static FLEX_SHRINK_PROPERTY: ValueGrammar = ValueGrammar::Type(ValueType::Number);
+static FLEX_WRAP_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("nowrap"),
+    &ValueGrammar::Keyword("wrap"),
+    &ValueGrammar::Keyword("wrap-reverse"),
+]);
+static FLOAT_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("block-start"),
+    &ValueGrammar::Keyword("block-end"),
+    &ValueGrammar::Keyword("inline-start"),
+    &ValueGrammar::Keyword("inline-end"),
+    &ValueGrammar::Keyword("snap-block"),
+    &ValueGrammar::Function("snap-block"),
+    &ValueGrammar::Keyword("snap-inline"),
+    &ValueGrammar::Function("snap-inline"),
+    &ValueGrammar::Keyword("left"),
+    &ValueGrammar::Keyword("right"),
+    &ValueGrammar::Keyword("top"),
+    &ValueGrammar::Keyword("bottom"),
+    &ValueGrammar::Keyword("none"),
+]);
+static FONT_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::Repeat {
+            grammar: &ValueGrammar::AnyOf(&[
+                &FONT_STYLE_PROPERTY,
+                &FONT_VARIANT_CSS2,
+                &FONT_WEIGHT_PROPERTY,
+                &FONT_WIDTH_CSS3,
+            ]),
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+        &FONT_SIZE_PROPERTY,
+        &ValueGrammar::Repeat {
+            grammar: &ValueGrammar::Sequence(&[
+                &ValueGrammar::Delimiter('/'),
+                &LINE_HEIGHT_PROPERTY,
+            ]),
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+        &FONT_FAMILY_PROPERTY,
+    ]),
+    &ValueGrammar::Keyword("caption"),
+    &ValueGrammar::Keyword("icon"),
+    &ValueGrammar::Keyword("menu"),
+    &ValueGrammar::Keyword("message-box"),
+    &ValueGrammar::Keyword("small-caption"),
+    &ValueGrammar::Keyword("status-bar"),
+]);
+static FONT_FAMILY_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &ValueGrammar::OneOf(&[
+        &ValueGrammar::Type(ValueType::String),
+        &ValueGrammar::Repeat {
+            grammar: &ValueGrammar::Type(ValueType::CustomIdent),
+            min: 1,
+            max: None,
+            comma_separated: false,
+        },
+    ]),
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static FONT_KERNING_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("none"),
+]);
+static FONT_SIZE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ABSOLUTE_SIZE,
+    &RELATIVE_SIZE,
+    &LENGTH_PERCENTAGE,
+    &ValueGrammar::Keyword("math"),
+]);
+static FONT_STRETCH_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Type(ValueType::Percentage),
+    &ValueGrammar::Keyword("ultra-condensed"),
+    &ValueGrammar::Keyword("extra-condensed"),
+    &ValueGrammar::Keyword("condensed"),
+    &ValueGrammar::Keyword("semi-condensed"),
+    &ValueGrammar::Keyword("semi-expanded"),
+    &ValueGrammar::Keyword("expanded"),
+    &ValueGrammar::Keyword("extra-expanded"),
+    &ValueGrammar::Keyword("ultra-expanded"),
+]);
+static FONT_STYLE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("italic"),
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::Keyword("oblique"),
+        &ValueGrammar::Repeat {
+            grammar: &ValueGrammar::Type(ValueType::Angle),
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+    ]),
+]);
+static FONT_WEIGHT_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("bold"),
+    &ValueGrammar::Keyword("bolder"),
+    &ValueGrammar::Keyword("lighter"),
+    &ValueGrammar::Type(ValueType::Number),
+]);
+static GAP_PROPERTY: ValueGrammar = ValueGrammar::Sequence(&[
+    &ROW_GAP_PROPERTY,
+    &ValueGrammar::Repeat {
+        grammar: &COLUMN_GAP_PROPERTY,
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+]);
+static GRID_AREA_PROPERTY: ValueGrammar = ValueGrammar::Sequence(&[
+    &GRID_LINE,
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Sequence(&[&ValueGrammar::Delimiter('/'), &GRID_LINE]),
+        min: 0,
+        max: Some(3),
+        comma_separated: false,
+    },
+]);
+static GRID_AUTO_FLOW_PROPERTY: ValueGrammar = ValueGrammar::AnyOf(&[
+    &ValueGrammar::OneOf(&[
+        &ValueGrammar::Keyword("row"),
+        &ValueGrammar::Keyword("column"),
+    ]),
+    &ValueGrammar::Keyword("dense"),
+]);
+static GRID_COLUMN_PROPERTY: ValueGrammar = ValueGrammar::Sequence(&[
+    &GRID_LINE,
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Sequence(&[&ValueGrammar::Delimiter('/'), &GRID_LINE]),
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+]);
+static GRID_ROW_PROPERTY: ValueGrammar = ValueGrammar::Sequence(&[
+    &GRID_LINE,
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Sequence(&[&ValueGrammar::Delimiter('/'), &GRID_LINE]),
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+]);
+static HYPHENS_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Keyword("manual"),
+    &ValueGrammar::Keyword("auto"),
+]);
+static IMAGE_RENDERING_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("smooth"),
+    &ValueGrammar::Keyword("high-quality"),
+    &ValueGrammar::Keyword("pixelated"),
+    &ValueGrammar::Keyword("crisp-edges"),
+    &ValueGrammar::Keyword("optimizespeed"),
+    &ValueGrammar::Keyword("optimizequality"),
+]);
+static INSET_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &TOP_PROPERTY,
+    min: 1,
+    max: Some(4),
+    comma_separated: false,
+};
+static INSET_BLOCK_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &TOP_PROPERTY,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static INSET_INLINE_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &TOP_PROPERTY,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static ISOLATION_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("isolate"),
+]);
+static JUSTIFY_CONTENT_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &CONTENT_DISTRIBUTION,
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::Repeat {
+            grammar: &OVERFLOW_POSITION,
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+        &ValueGrammar::OneOf(&[
+            &CONTENT_POSITION,
+            &ValueGrammar::Keyword("left"),
+            &ValueGrammar::Keyword("right"),
+        ]),
+    ]),
+]);
+static JUSTIFY_ITEMS_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("stretch"),
+    &BASELINE_POSITION,
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::Repeat {
+            grammar: &OVERFLOW_POSITION,
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+        &ValueGrammar::OneOf(&[
+            &SELF_POSITION,
+            &ValueGrammar::Keyword("left"),
+            &ValueGrammar::Keyword("right"),
+        ]),
+    ]),
+    &ValueGrammar::Keyword("legacy"),
+    &ValueGrammar::AllOf(&[
+        &ValueGrammar::Keyword("legacy"),
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("left"),
+            &ValueGrammar::Keyword("right"),
+            &ValueGrammar::Keyword("center"),
+        ]),
+    ]),
+]);
+static JUSTIFY_SELF_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("stretch"),
+    &BASELINE_POSITION,
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::Repeat {
+            grammar: &OVERFLOW_POSITION,
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+        &ValueGrammar::OneOf(&[
+            &SELF_POSITION,
+            &ValueGrammar::Keyword("left"),
+            &ValueGrammar::Keyword("right"),
+        ]),
+    ]),
+]);
+static LEFT_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("auto"), &LENGTH_PERCENTAGE]);
+static LETTER_SPACING_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("normal"), &LENGTH_PERCENTAGE]);
+static LINE_HEIGHT_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Type(ValueType::Number),
+    &LENGTH_PERCENTAGE,
+]);
+static LIST_STYLE_PROPERTY: ValueGrammar = ValueGrammar::AnyOf(&[
+    &LIST_STYLE_POSITION_PROPERTY,
+    &LIST_STYLE_IMAGE_PROPERTY,
+    &LIST_STYLE_TYPE_PROPERTY,
+]);
+static LIST_STYLE_IMAGE_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&IMAGE, &ValueGrammar::Keyword("none")]);
+static LIST_STYLE_POSITION_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("inside"),
+    &ValueGrammar::Keyword("outside"),
+]);
+static LIST_STYLE_TYPE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Type(ValueType::CustomIdent),
+    &ValueGrammar::Function("symbols"),
+    &ValueGrammar::Type(ValueType::String),
+    &ValueGrammar::Keyword("none"),
+]);
+static MARGIN_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &MARGIN_TOP_PROPERTY,
+    min: 1,
+    max: Some(4),
+    comma_separated: false,
+};
+static MARGIN_BLOCK_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &MARGIN_TOP_PROPERTY,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static MARGIN_INLINE_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &MARGIN_TOP_PROPERTY,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static MARGIN_TOP_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&LENGTH_PERCENTAGE, &ValueGrammar::Keyword("auto")]);
+static MAX_WIDTH_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &LENGTH_PERCENTAGE,
+    &ValueGrammar::Keyword("min-content"),
+    &ValueGrammar::Keyword("max-content"),
+    &ValueGrammar::Keyword("fit-content"),
+    &ValueGrammar::Function("fit-content"),
+    &ValueGrammar::Keyword("stretch"),
+]);
+static MIX_BLEND_MODE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &BLEND_MODE,
+    &ValueGrammar::Keyword("plus-darker"),
+    &ValueGrammar::Keyword("plus-lighter"),
+]);
+static OBJECT_FIT_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("fill"),
+    &ValueGrammar::Keyword("contain"),
+    &ValueGrammar::Keyword("cover"),
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Keyword("scale-down"),
+]);
+static OPACITY_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Type(ValueType::Number),
+    &ValueGrammar::Type(ValueType::Percentage),
+]);
+static ORDER_PROPERTY: ValueGrammar = ValueGrammar::Type(ValueType::Integer);
+static OUTLINE_PROPERTY: ValueGrammar = ValueGrammar::AnyOf(&[
+    &OUTLINE_COLOR_PROPERTY,
+    &OUTLINE_STYLE_PROPERTY,
+    &LINE_WIDTH,
+]);
+static OUTLINE_COLOR_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("auto"), &COLOR]);
+static OUTLINE_OFFSET_PROPERTY: ValueGrammar = ValueGrammar::Type(ValueType::Length);
+static OUTLINE_STYLE_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("auto"), &LINE_STYLE]);
+static OVERFLOW_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &OVERFLOW_X_PROPERTY,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static OVERFLOW_WRAP_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("break-word"),
+    &ValueGrammar::Keyword("anywhere"),
+]);
+static OVERFLOW_X_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("visible"),
+    &ValueGrammar::Keyword("hidden"),
+    &ValueGrammar::Keyword("clip"),
+    &ValueGrammar::Keyword("scroll"),
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("overlay"),
+]);
+static OVERSCROLL_BEHAVIOR_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &ValueGrammar::OneOf(&[
+        &ValueGrammar::Keyword("contain"),
+        &ValueGrammar::Keyword("none"),
+        &ValueGrammar::Keyword("auto"),
+    ]),
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static PADDING_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LENGTH_PERCENTAGE,
+    min: 1,
+    max: Some(4),
+    comma_separated: false,
+};
+static PADDING_BLOCK_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LENGTH_PERCENTAGE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static PADDING_INLINE_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &LENGTH_PERCENTAGE,
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static PERSPECTIVE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Type(ValueType::Length),
+]);
+static PLACE_CONTENT_PROPERTY: ValueGrammar = ValueGrammar::Sequence(&[
+    &ALIGN_CONTENT_PROPERTY,
+    &ValueGrammar::Repeat {
+        grammar: &JUSTIFY_CONTENT_PROPERTY,
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+]);
+static PLACE_ITEMS_PROPERTY: ValueGrammar = ValueGrammar::Sequence(&[
+    &ALIGN_ITEMS_PROPERTY,
+    &ValueGrammar::Repeat {
+        grammar: &JUSTIFY_ITEMS_PROPERTY,
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+]);
+static PLACE_SELF_PROPERTY: ValueGrammar = ValueGrammar::Sequence(&[
+    &ALIGN_SELF_PROPERTY,
+    &ValueGrammar::Repeat {
+        grammar: &JUSTIFY_SELF_PROPERTY,
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+]);
+static POINTER_EVENTS_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("bounding-box"),
+    &ValueGrammar::Keyword("visiblepainted"),
+    &ValueGrammar::Keyword("visiblefill"),
+    &ValueGrammar::Keyword("visiblestroke"),
+    &ValueGrammar::Keyword("visible"),
+    &ValueGrammar::Keyword("painted"),
+    &ValueGrammar::Keyword("fill"),
+    &ValueGrammar::Keyword("stroke"),
+    &ValueGrammar::Keyword("all"),
+    &ValueGrammar::Keyword("none"),
+]);
+static POSITION_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("static"),
+    &ValueGrammar::Keyword("relative"),
+    &ValueGrammar::Keyword("absolute"),
+    &ValueGrammar::Keyword("sticky"),
+    &ValueGrammar::Keyword("fixed"),
+]);
+static RESIZE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Keyword("both"),
+    &ValueGrammar::Keyword("horizontal"),
+    &ValueGrammar::Keyword("vertical"),
+    &ValueGrammar::Keyword("block"),
+    &ValueGrammar::Keyword("inline"),
+]);
+static RIGHT_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("auto"), &LENGTH_PERCENTAGE]);
+static ROTATE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Type(ValueType::Angle),
+    &ValueGrammar::AllOf(&[
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("x"),
+            &ValueGrammar::Keyword("y"),
+            &ValueGrammar::Keyword("z"),
+            &ValueGrammar::Repeat {
+                grammar: &ValueGrammar::Type(ValueType::Number),
+                min: 3,
+                max: Some(3),
+                comma_separated: false,
+            },
+        ]),
+        &ValueGrammar::Type(ValueType::Angle),
+    ]),
+]);
+static ROW_GAP_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("normal"), &LENGTH_PERCENTAGE]);
+static SCALE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::OneOf(&[
+            &ValueGrammar::Type(ValueType::Number),
+            &ValueGrammar::Type(ValueType::Percentage),
+        ]),
+        min: 1,
+        max: Some(3),
+        comma_separated: false,
+    },
+]);
+static SCROLL_BEHAVIOR_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("smooth"),
+]);
+static SCROLL_SNAP_ALIGN_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &ValueGrammar::OneOf(&[
+        &ValueGrammar::Keyword("none"),
+        &ValueGrammar::Keyword("start"),
+        &ValueGrammar::Keyword("end"),
+        &ValueGrammar::Keyword("center"),
+    ]),
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static SCROLL_SNAP_TYPE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("x"),
+            &ValueGrammar::Keyword("y"),
+            &ValueGrammar::Keyword("block"),
+            &ValueGrammar::Keyword("inline"),
+            &ValueGrammar::Keyword("both"),
+        ]),
+        &ValueGrammar::Repeat {
+            grammar: &ValueGrammar::OneOf(&[
+                &ValueGrammar::Keyword("mandatory"),
+                &ValueGrammar::Keyword("proximity"),
+            ]),
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+    ]),
+]);
+static TAB_SIZE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Type(ValueType::Number),
+    &ValueGrammar::Type(ValueType::Length),
+]);
+static TABLE_LAYOUT_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("fixed"),
+]);
+static TEXT_ALIGN_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("start"),
+    &ValueGrammar::Keyword("end"),
+    &ValueGrammar::Keyword("left"),
+    &ValueGrammar::Keyword("right"),
+    &ValueGrammar::Keyword("center"),
+    &ValueGrammar::Keyword("justify"),
+    &ValueGrammar::Keyword("match-parent"),
+    &ValueGrammar::Keyword("justify-all"),
+]);
+static TEXT_ALIGN_LAST_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("start"),
+    &ValueGrammar::Keyword("end"),
+    &ValueGrammar::Keyword("left"),
+    &ValueGrammar::Keyword("right"),
+    &ValueGrammar::Keyword("center"),
+    &ValueGrammar::Keyword("justify"),
+    &ValueGrammar::Keyword("match-parent"),
+]);
+static TEXT_DECORATION_PROPERTY: ValueGrammar = ValueGrammar::AnyOf(&[
+    &TEXT_DECORATION_LINE_PROPERTY,
+    &TEXT_DECORATION_THICKNESS_PROPERTY,
+    &TEXT_DECORATION_STYLE_PROPERTY,
+    &COLOR,
+]);
+static TEXT_DECORATION_LINE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::AnyOf(&[
+        &ValueGrammar::Keyword("underline"),
+        &ValueGrammar::Keyword("overline"),
+        &ValueGrammar::Keyword("line-through"),
+        &ValueGrammar::Keyword("blink"),
+    ]),
+]);
+static TEXT_DECORATION_STYLE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("solid"),
+    &ValueGrammar::Keyword("double"),
+    &ValueGrammar::Keyword("dotted"),
+    &ValueGrammar::Keyword("dashed"),
+    &ValueGrammar::Keyword("wavy"),
+]);
+static TEXT_DECORATION_THICKNESS_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("from-font"),
+    &LENGTH_PERCENTAGE,
+]);
+static TEXT_INDENT_PROPERTY: ValueGrammar = ValueGrammar::AllOf(&[
+    &LENGTH_PERCENTAGE,
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Keyword("hanging"),
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::Keyword("each-line"),
+        min: 0,
+        max: Some(1),
+        comma_separated: false,
+    },
+]);
+static TEXT_OVERFLOW_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &ValueGrammar::OneOf(&[
+        &ValueGrammar::Keyword("clip"),
+        &ValueGrammar::Keyword("ellipsis"),
+        &ValueGrammar::Type(ValueType::String),
+    ]),
+    min: 1,
+    max: Some(2),
+    comma_separated: false,
+};
+static TEXT_RENDERING_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("optimizespeed"),
+    &ValueGrammar::Keyword("optimizelegibility"),
+    &ValueGrammar::Keyword("geometricprecision"),
+]);
+static TEXT_SHADOW_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::AllOf(&[
+            &ValueGrammar::Repeat {
+                grammar: &COLOR,
+                min: 0,
+                max: Some(1),
+                comma_separated: false,
+            },
+            &ValueGrammar::Repeat {
+                grammar: &ValueGrammar::Type(ValueType::Length),
+                min: 2,
+                max: Some(3),
+                comma_separated: false,
+            },
+        ]),
+        min: 1,
+        max: None,
+        comma_separated: true,
+    },
+]);
+static TEXT_TRANSFORM_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::AnyOf(&[
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("capitalize"),
+            &ValueGrammar::Keyword("uppercase"),
+            &ValueGrammar::Keyword("lowercase"),
+        ]),
+        &ValueGrammar::Keyword("full-width"),
+        &ValueGrammar::Keyword("full-size-kana"),
+    ]),
+    &ValueGrammar::Keyword("math-auto"),
+]);
+static TEXT_UNDERLINE_OFFSET_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("auto"), &LENGTH_PERCENTAGE]);
+static TEXT_WRAP_MODE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("wrap"),
+    &ValueGrammar::Keyword("nowrap"),
+]);
+static TOP_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("auto"), &LENGTH_PERCENTAGE]);
+static TOUCH_ACTION_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::AnyOf(&[
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("pan-x"),
+            &ValueGrammar::Keyword("pan-left"),
+            &ValueGrammar::Keyword("pan-right"),
+        ]),
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("pan-y"),
+            &ValueGrammar::Keyword("pan-up"),
+            &ValueGrammar::Keyword("pan-down"),
+        ]),
+        &ValueGrammar::Keyword("pinch-zoom"),
+    ]),
+    &ValueGrammar::Keyword("manipulation"),
+]);
+static TRANSFORM_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Repeat {
+        grammar: &TRANSFORM_FUNCTION,
+        min: 1,
+        max: None,
+        comma_separated: false,
+    },
+]);
+static TRANSFORM_ORIGIN_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::OneOf(&[
+        &ValueGrammar::Keyword("left"),
+        &ValueGrammar::Keyword("center"),
+        &ValueGrammar::Keyword("right"),
+        &ValueGrammar::Keyword("top"),
+        &ValueGrammar::Keyword("bottom"),
+        &LENGTH_PERCENTAGE,
+    ]),
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("left"),
+            &ValueGrammar::Keyword("center"),
+            &ValueGrammar::Keyword("right"),
+            &LENGTH_PERCENTAGE,
+        ]),
+        &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("top"),
+            &ValueGrammar::Keyword("center"),
+            &ValueGrammar::Keyword("bottom"),
+            &LENGTH_PERCENTAGE,
+        ]),
+        &ValueGrammar::Repeat {
+            grammar: &ValueGrammar::Type(ValueType::Length),
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+    ]),
+    &ValueGrammar::Sequence(&[
+        &ValueGrammar::AllOf(&[
+            &ValueGrammar::OneOf(&[
+                &ValueGrammar::Keyword("center"),
+                &ValueGrammar::Keyword("left"),
+                &ValueGrammar::Keyword("right"),
+            ]),
+            &ValueGrammar::OneOf(&[
+                &ValueGrammar::Keyword("center"),
+                &ValueGrammar::Keyword("top"),
+                &ValueGrammar::Keyword("bottom"),
+            ]),
+        ]),
+        &ValueGrammar::Repeat {
+            grammar: &ValueGrammar::Type(ValueType::Length),
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+    ]),
+]);
+static TRANSFORM_STYLE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("flat"),
+    &ValueGrammar::Keyword("preserve-3d"),
+]);
+static TRANSITION_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &SINGLE_TRANSITION,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static TRANSITION_BEHAVIOR_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &TRANSITION_BEHAVIOR_VALUE,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static TRANSITION_DELAY_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &ValueGrammar::Type(ValueType::Time),
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static TRANSITION_DURATION_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &ValueGrammar::Type(ValueType::Time),
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static TRANSITION_PROPERTY_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Repeat {
+        grammar: &SINGLE_TRANSITION_PROPERTY,
+        min: 1,
+        max: None,
+        comma_separated: true,
+    },
+]);
+static TRANSITION_TIMING_FUNCTION_PROPERTY: ValueGrammar = ValueGrammar::Repeat {
+    grammar: &EASING_FUNCTION,
+    min: 1,
+    max: None,
+    comma_separated: true,
+};
+static TRANSLATE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Sequence(&[
+        &LENGTH_PERCENTAGE,
+        &ValueGrammar::Repeat {
+            grammar: &ValueGrammar::Sequence(&[
+                &LENGTH_PERCENTAGE,
+                &ValueGrammar::Repeat {
+                    grammar: &ValueGrammar::Type(ValueType::Length),
+                    min: 0,
+                    max: Some(1),
+                    comma_separated: false,
+                },
+            ]),
+            min: 0,
+            max: Some(1),
+            comma_separated: false,
+        },
+    ]),
+]);
+static UNICODE_BIDI_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("embed"),
+    &ValueGrammar::Keyword("isolate"),
+    &ValueGrammar::Keyword("bidi-override"),
+    &ValueGrammar::Keyword("isolate-override"),
+    &ValueGrammar::Keyword("plaintext"),
+]);
+static USER_SELECT_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Keyword("text"),
+    &ValueGrammar::Keyword("none"),
+    &ValueGrammar::Keyword("contain"),
+    &ValueGrammar::Keyword("all"),
+]);
+static VERTICAL_ALIGN_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("baseline"),
+    &ValueGrammar::Keyword("sub"),
+    &ValueGrammar::Keyword("super"),
+    &ValueGrammar::Keyword("text-top"),
+    &ValueGrammar::Keyword("text-bottom"),
+    &ValueGrammar::Keyword("middle"),
+    &ValueGrammar::Keyword("top"),
+    &ValueGrammar::Keyword("bottom"),
+    &LENGTH_PERCENTAGE,
+]);
+static VISIBILITY_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("visible"),
+    &ValueGrammar::Keyword("hidden"),
+    &ValueGrammar::Keyword("collapse"),
+]);
+static WHITE_SPACE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("pre"),
+    &ValueGrammar::Keyword("pre-wrap"),
+    &ValueGrammar::Keyword("pre-line"),
+    &ValueGrammar::AnyOf(&[&WHITE_SPACE_COLLAPSE_PROPERTY, &TEXT_WRAP_MODE_PROPERTY]),
+]);
+static WHITE_SPACE_COLLAPSE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("collapse"),
+    &ValueGrammar::Keyword("discard"),
+    &ValueGrammar::Keyword("preserve"),
+    &ValueGrammar::Keyword("preserve-breaks"),
+    &ValueGrammar::Keyword("preserve-spaces"),
+    &ValueGrammar::Keyword("break-spaces"),
+]);
+static WIDTH_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &LENGTH_PERCENTAGE,
+    &ValueGrammar::Keyword("min-content"),
+    &ValueGrammar::Keyword("max-content"),
+    &ValueGrammar::Keyword("fit-content"),
+    &ValueGrammar::Function("fit-content"),
+    &ValueGrammar::Keyword("stretch"),
+]);
+static WILL_CHANGE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Repeat {
+        grammar: &ValueGrammar::OneOf(&[
+            &ValueGrammar::Keyword("scroll-position"),
+            &ValueGrammar::Keyword("contents"),
+            &ValueGrammar::Type(ValueType::CustomIdent),
+        ]),
+        min: 1,
+        max: None,
+        comma_separated: true,
+    },
+]);
+static WORD_BREAK_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("normal"),
+    &ValueGrammar::Keyword("keep-all"),
+    &ValueGrammar::Keyword("break-all"),
+    &ValueGrammar::Keyword("break-word"),
+    &ValueGrammar::Keyword("auto-phrase"),
+]);
+static WORD_SPACING_PROPERTY: ValueGrammar =
+    ValueGrammar::OneOf(&[&ValueGrammar::Keyword("normal"), &LENGTH_PERCENTAGE]);
+static WRITING_MODE_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("horizontal-tb"),
+    &ValueGrammar::Keyword("vertical-rl"),
+    &ValueGrammar::Keyword("vertical-lr"),
+    &ValueGrammar::Keyword("sideways-rl"),
+    &ValueGrammar::Keyword("sideways-lr"),
+    &ValueGrammar::Keyword("lr"),
+    &ValueGrammar::Keyword("lr-tb"),
+    &ValueGrammar::Keyword("rl"),
+    &ValueGrammar::Keyword("rl-tb"),
+    &ValueGrammar::Keyword("tb"),
+    &ValueGrammar::Keyword("tb-rl"),
+]);
+static Z_INDEX_PROPERTY: ValueGrammar = ValueGrammar::OneOf(&[
+    &ValueGrammar::Keyword("auto"),
+    &ValueGrammar::Type(ValueType::Integer),
+]);
+pub(super) static PROPERTIES: [CssPropertyDefinition; 257] = [
+    CssPropertyDefinition {
+        name: "accent-color",
+        syntax: "auto | <color>",
+        grammar: &ACCENT_COLOR_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "align-content",
+        syntax: "normal | <baseline-position> | <content-distribution> | <overflow-position>? <content-position>",
+        grammar: &ALIGN_CONTENT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "align-items",
+        syntax: "normal | stretch | <baseline-position> | <overflow-position>? <self-position>",
+        grammar: &ALIGN_ITEMS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "align-self",
+        syntax: "auto | normal | stretch | <baseline-position> | <overflow-position>? <self-position>",
+        grammar: &ALIGN_SELF_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "animation",
+        syntax: "<single-animation>#",
+        grammar: &ANIMATION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "animation-delay",
+        syntax: "<time>#",
+        grammar: &ANIMATION_DELAY_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "animation-direction",
+        syntax: "<single-animation-direction>#",
+        grammar: &ANIMATION_DIRECTION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "animation-duration",
+        syntax: "[ auto | <time> ]#",
+        grammar: &ANIMATION_DURATION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "animation-fill-mode",
+        syntax: "<single-animation-fill-mode>#",
+        grammar: &ANIMATION_FILL_MODE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "animation-iteration-count",
+        syntax: "<single-animation-iteration-count>#",
+        grammar: &ANIMATION_ITERATION_COUNT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "animation-name",
+        syntax: "[ none | <keyframes-name> ]#",
+        grammar: &ANIMATION_NAME_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "animation-play-state",
+        syntax: "<single-animation-play-state>#",
+        grammar: &ANIMATION_PLAY_STATE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "animation-timing-function",
+        syntax: "<easing-function>#",
+        grammar: &ANIMATION_TIMING_FUNCTION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "aspect-ratio",
+        syntax: "auto || <ratio>",
+        grammar: &ASPECT_RATIO_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "backdrop-filter",
+        syntax: "none | [ <filter-function> | <url> ]+",
+        grammar: &BACKDROP_FILTER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "backface-visibility",
+        syntax: "visible | hidden",
+        grammar: &BACKFACE_VISIBILITY_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "background",
+        syntax: "<bg-layer>#",
+        grammar: &BACKGROUND_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "background-attachment",
+        syntax: "<attachment>#",
+        grammar: &BACKGROUND_ATTACHMENT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "background-blend-mode",
+        syntax: "<blend-mode>#",
+        grammar: &BACKGROUND_BLEND_MODE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "background-clip",
+        syntax: "<bg-clip>#",
+        grammar: &BACKGROUND_CLIP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "background-color",
+        syntax: "<color>",
+        grammar: &COLOR,
+    },
+    CssPropertyDefinition {
+        name: "background-image",
+        syntax: "<bg-image>#",
+        grammar: &BACKGROUND_IMAGE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "background-origin",
+        syntax: "<visual-box>#",
+        grammar: &BACKGROUND_ORIGIN_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "background-position",
+        syntax: "<bg-position>#",
+        grammar: &BACKGROUND_POSITION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "background-repeat",
+        syntax: "<repeat-style>#",
+        grammar: &BACKGROUND_REPEAT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "background-size",
+        syntax: "<bg-size>#",
+        grammar: &BACKGROUND_SIZE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "block-size",
+        syntax: "<'width'>",
+        grammar: &WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border",
+        syntax: "<line-width> || <line-style> || <color>",
+        grammar: &BORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-block",
+        syntax: "<'border'>",
+        grammar: &BORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-block-color",
+        syntax: "<color>{1,2}",
+        grammar: &BORDER_BLOCK_COLOR_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-block-end",
+        syntax: "<'border'>",
+        grammar: &BORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-block-end-color",
+        syntax: "<color>",
+        grammar: &COLOR,
+    },
+    CssPropertyDefinition {
+        name: "border-block-end-style",
+        syntax: "<line-style>",
+        grammar: &LINE_STYLE,
+    },
+    CssPropertyDefinition {
+        name: "border-block-end-width",
+        syntax: "<line-width>",
+        grammar: &LINE_WIDTH,
+    },
+    CssPropertyDefinition {
+        name: "border-block-start",
+        syntax: "<'border'>",
+        grammar: &BORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-block-start-color",
+        syntax: "<color>",
+        grammar: &COLOR,
+    },
+    CssPropertyDefinition {
+        name: "border-block-start-style",
+        syntax: "<line-style>",
+        grammar: &LINE_STYLE,
+    },
+    CssPropertyDefinition {
+        name: "border-block-start-width",
+        syntax: "<line-width>",
+        grammar: &LINE_WIDTH,
+    },
+    CssPropertyDefinition {
+        name: "border-block-style",
+        syntax: "<line-style>{1,2}",
+        grammar: &BORDER_BLOCK_STYLE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-block-width",
+        syntax: "<line-width>{1,2}",
+        grammar: &BORDER_BLOCK_WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-bottom",
+        syntax: "<'border'>",
+        grammar: &BORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-bottom-color",
+        syntax: "<color>",
+        grammar: &COLOR,
+    },
+    CssPropertyDefinition {
+        name: "border-bottom-left-radius",
+        syntax: "<length-percentage>{1,2}",
+        grammar: &BORDER_BOTTOM_LEFT_RADIUS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-bottom-right-radius",
+        syntax: "<length-percentage>{1,2}",
+        grammar: &BORDER_BOTTOM_RIGHT_RADIUS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-bottom-style",
+        syntax: "<line-style>",
+        grammar: &LINE_STYLE,
+    },
+    CssPropertyDefinition {
+        name: "border-bottom-width",
+        syntax: "<line-width>",
+        grammar: &LINE_WIDTH,
+    },
+    CssPropertyDefinition {
+        name: "border-collapse",
+        syntax: "collapse | separate",
+        grammar: &BORDER_COLLAPSE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-color",
+        syntax: "<color>{1,4}",
+        grammar: &BORDER_COLOR_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-end-end-radius",
+        syntax: "<length-percentage>{1,2}",
+        grammar: &BORDER_END_END_RADIUS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-end-start-radius",
+        syntax: "<length-percentage>{1,2}",
+        grammar: &BORDER_END_START_RADIUS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-inline",
+        syntax: "<'border'>",
+        grammar: &BORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-inline-color",
+        syntax: "<color>{1,2}",
+        grammar: &BORDER_INLINE_COLOR_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-inline-end",
+        syntax: "<'border'>",
+        grammar: &BORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-inline-end-color",
+        syntax: "<color>",
+        grammar: &COLOR,
+    },
+    CssPropertyDefinition {
+        name: "border-inline-end-style",
+        syntax: "<line-style>",
+        grammar: &LINE_STYLE,
+    },
+    CssPropertyDefinition {
+        name: "border-inline-end-width",
+        syntax: "<line-width>",
+        grammar: &LINE_WIDTH,
+    },
+    CssPropertyDefinition {
+        name: "border-inline-start",
+        syntax: "<'border'>",
+        grammar: &BORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-inline-start-color",
+        syntax: "<color>",
+        grammar: &COLOR,
+    },
+    CssPropertyDefinition {
+        name: "border-inline-start-style",
+        syntax: "<line-style>",
+        grammar: &LINE_STYLE,
+    },
+    CssPropertyDefinition {
+        name: "border-inline-start-width",
+        syntax: "<line-width>",
+        grammar: &LINE_WIDTH,
+    },
+    CssPropertyDefinition {
+        name: "border-inline-style",
+        syntax: "<line-style>{1,2}",
+        grammar: &BORDER_INLINE_STYLE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-inline-width",
+        syntax: "<line-width>{1,2}",
+        grammar: &BORDER_INLINE_WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-left",
+        syntax: "<'border'>",
+        grammar: &BORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-left-color",
+        syntax: "<color>",
+        grammar: &COLOR,
+    },
+    CssPropertyDefinition {
+        name: "border-left-style",
+        syntax: "<line-style>",
+        grammar: &LINE_STYLE,
+    },
+    CssPropertyDefinition {
+        name: "border-left-width",
+        syntax: "<line-width>",
+        grammar: &LINE_WIDTH,
+    },
+    CssPropertyDefinition {
+        name: "border-radius",
+        syntax: "<length-percentage>{1,4} [ / <length-percentage>{1,4} ]?",
+        grammar: &BORDER_RADIUS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-right",
+        syntax: "<'border'>",
+        grammar: &BORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-right-color",
+        syntax: "<color>",
+        grammar: &COLOR,
+    },
+    CssPropertyDefinition {
+        name: "border-right-style",
+        syntax: "<line-style>",
+        grammar: &LINE_STYLE,
+    },
+    CssPropertyDefinition {
+        name: "border-right-width",
+        syntax: "<line-width>",
+        grammar: &LINE_WIDTH,
+    },
+    CssPropertyDefinition {
+        name: "border-spacing",
+        syntax: "<length>{1,2}",
+        grammar: &BORDER_SPACING_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-start-end-radius",
+        syntax: "<length-percentage>{1,2}",
+        grammar: &BORDER_START_END_RADIUS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-start-start-radius",
+        syntax: "<length-percentage>{1,2}",
+        grammar: &BORDER_START_START_RADIUS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-style",
+        syntax: "<line-style>{1,4}",
+        grammar: &BORDER_STYLE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-top",
+        syntax: "<'border'>",
+        grammar: &BORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-top-color",
+        syntax: "<color>",
+        grammar: &COLOR,
+    },
+    CssPropertyDefinition {
+        name: "border-top-left-radius",
+        syntax: "<length-percentage>{1,2}",
+        grammar: &BORDER_TOP_LEFT_RADIUS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-top-right-radius",
+        syntax: "<length-percentage>{1,2}",
+        grammar: &BORDER_TOP_RIGHT_RADIUS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "border-top-style",
+        syntax: "<line-style>",
+        grammar: &LINE_STYLE,
+    },
+    CssPropertyDefinition {
+        name: "border-top-width",
+        syntax: "<line-width>",
+        grammar: &LINE_WIDTH,
+    },
+    CssPropertyDefinition {
+        name: "border-width",
+        syntax: "<line-width>{1,4}",
+        grammar: &BORDER_WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "bottom",
+        syntax: "auto | <length-percentage>",
+        grammar: &BOTTOM_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "box-decoration-break",
+        syntax: "slice | clone",
+        grammar: &BOX_DECORATION_BREAK_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "box-shadow",
+        syntax: "none | <shadow>#",
+        grammar: &BOX_SHADOW_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "box-sizing",
+        syntax: "content-box | border-box",
+        grammar: &BOX_SIZING_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "break-after",
+        syntax: "auto | avoid | always | all | avoid-page | page | left | right | recto | verso | avoid-column | column | avoid-region | region",
+        grammar: &BREAK_AFTER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "break-before",
+        syntax: "auto | avoid | always | all | avoid-page | page | left | right | recto | verso | avoid-column | column | avoid-region | region",
+        grammar: &BREAK_BEFORE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "break-inside",
+        syntax: "auto | avoid | avoid-page | avoid-column | avoid-region",
+        grammar: &BREAK_INSIDE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "caption-side",
+        syntax: "top | bottom | block-start | block-end | inline-start | inline-end",
+        grammar: &CAPTION_SIDE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "caret-color",
+        syntax: "auto | <color>",
+        grammar: &CARET_COLOR_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "clear",
+        syntax: "inline-start | inline-end | block-start | block-end | left | right | top | bottom | both-inline | both-block | both | none",
+        grammar: &CLEAR_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "color",
+        syntax: "<color>",
+        grammar: &COLOR,
+    },
+    CssPropertyDefinition {
+        name: "column-count",
+        syntax: "auto | <integer>",
+        grammar: &COLUMN_COUNT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "column-gap",
+        syntax: "normal | <length-percentage>",
+        grammar: &COLUMN_GAP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "column-width",
+        syntax: "auto | <length> | min-content | max-content | fit-content | <fit-content()>",
+        grammar: &COLUMN_WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "columns",
+        syntax: "<'column-width'> || <'column-count'>",
+        grammar: &COLUMNS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "contain",
+        syntax: "none | strict | content | [ [ size | inline-size ] || layout || style || paint ]",
+        grammar: &CONTAIN_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "container",
+        syntax: "<'container-name'> [ / <'container-type'> ]?",
+        grammar: &CONTAINER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "container-name",
+        syntax: "none | <custom-ident>+",
+        grammar: &CONTAINER_NAME_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "container-type",
+        syntax: "normal | size | inline-size",
+        grammar: &CONTAINER_TYPE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "content-visibility",
+        syntax: "visible | auto | hidden",
+        grammar: &CONTENT_VISIBILITY_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "counter-increment",
+        syntax: "[ <custom-ident> <integer>? ]+ | none",
+        grammar: &COUNTER_INCREMENT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "cursor",
+        syntax: "[ [ <url> | <image-set()> ] [ <number> <number> ]? , ]* <cursor-keyword>",
+        grammar: &CURSOR_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "direction",
+        syntax: "ltr | rtl",
+        grammar: &DIRECTION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "display",
+        syntax: "[ <display-outside> || <display-inside> ] | <display-listitem> | <display-internal> | <display-box> | <display-legacy>",
+        grammar: &DISPLAY_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "empty-cells",
+        syntax: "show | hide",
+        grammar: &EMPTY_CELLS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "filter",
+        syntax: "none | [ <filter-function> | <url> ]+",
+        grammar: &FILTER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "flex",
+        syntax: "none | [ <'flex-grow'> <'flex-shrink'>? || <'flex-basis'> ]",
+        grammar: &FLEX_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "flex-basis",
+        syntax: "content | <'width'>",
+        grammar: &FLEX_BASIS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "flex-direction",
+        syntax: "row | row-reverse | column | column-reverse",
+        grammar: &FLEX_DIRECTION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "flex-flow",
+        syntax: "<'flex-direction'> || <'flex-wrap'>",
+        grammar: &FLEX_FLOW_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "flex-grow",
+        syntax: "<number>",
+        grammar: &FLEX_GROW_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "flex-shrink",
+        syntax: "<number>",
+        grammar: &FLEX_SHRINK_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "flex-wrap",
+        syntax: "nowrap | wrap | wrap-reverse",
+        grammar: &FLEX_WRAP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "float",
+        syntax: "block-start | block-end | inline-start | inline-end | snap-block | <snap-block()> | snap-inline | <snap-inline()> | left | right | top | bottom | none",
+        grammar: &FLOAT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "font",
+        syntax: "[ [ <'font-style'> || <font-variant-css2> || <'font-weight'> || <font-width-css3> ]? <'font-size'> [ / <'line-height'> ]? <'font-family'> ] | caption | icon | menu | message-box | small-caption | status-bar",
+        grammar: &FONT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "font-family",
+        syntax: "[ <string> | <custom-ident>+ ]#",
+        grammar: &FONT_FAMILY_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "font-kerning",
+        syntax: "auto | normal | none",
+        grammar: &FONT_KERNING_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "font-size",
+        syntax: "<absolute-size> | <relative-size> | <length-percentage> | math",
+        grammar: &FONT_SIZE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "font-stretch",
+        syntax: "normal | <percentage> | ultra-condensed | extra-condensed | condensed | semi-condensed | semi-expanded | expanded | extra-expanded | ultra-expanded",
+        grammar: &FONT_STRETCH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "font-style",
+        syntax: "normal | italic | oblique <angle>?",
+        grammar: &FONT_STYLE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "font-weight",
+        syntax: "normal | bold | bolder | lighter | <number>",
+        grammar: &FONT_WEIGHT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "gap",
+        syntax: "<'row-gap'> <'column-gap'>?",
+        grammar: &GAP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "grid-area",
+        syntax: "<grid-line> [ / <grid-line> ]{0,3}",
+        grammar: &GRID_AREA_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "grid-auto-flow",
+        syntax: "[ row | column ] || dense",
+        grammar: &GRID_AUTO_FLOW_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "grid-column",
+        syntax: "<grid-line> [ / <grid-line> ]?",
+        grammar: &GRID_COLUMN_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "grid-column-end",
+        syntax: "<grid-line>",
+        grammar: &GRID_LINE,
+    },
+    CssPropertyDefinition {
+        name: "grid-column-gap",
+        syntax: "<'column-gap'>",
+        grammar: &COLUMN_GAP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "grid-column-start",
+        syntax: "<grid-line>",
+        grammar: &GRID_LINE,
+    },
+    CssPropertyDefinition {
+        name: "grid-gap",
+        syntax: "<'gap'>",
+        grammar: &GAP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "grid-row",
+        syntax: "<grid-line> [ / <grid-line> ]?",
+        grammar: &GRID_ROW_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "grid-row-end",
+        syntax: "<grid-line>",
+        grammar: &GRID_LINE,
+    },
+    CssPropertyDefinition {
+        name: "grid-row-gap",
+        syntax: "<'row-gap'>",
+        grammar: &ROW_GAP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "grid-row-start",
+        syntax: "<grid-line>",
+        grammar: &GRID_LINE,
+    },
+    CssPropertyDefinition {
+        name: "height",
+        syntax: "<'width'>",
+        grammar: &WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "hyphens",
+        syntax: "none | manual | auto",
+        grammar: &HYPHENS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "image-rendering",
+        syntax: "auto | smooth | high-quality | pixelated | crisp-edges | optimizespeed | optimizequality",
+        grammar: &IMAGE_RENDERING_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "inline-size",
+        syntax: "<'width'>",
+        grammar: &WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "inset",
+        syntax: "<'top'>{1,4}",
+        grammar: &INSET_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "inset-block",
+        syntax: "<'top'>{1,2}",
+        grammar: &INSET_BLOCK_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "inset-block-end",
+        syntax: "<'top'>",
+        grammar: &TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "inset-block-start",
+        syntax: "<'top'>",
+        grammar: &TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "inset-inline",
+        syntax: "<'top'>{1,2}",
+        grammar: &INSET_INLINE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "inset-inline-end",
+        syntax: "<'top'>",
+        grammar: &TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "inset-inline-start",
+        syntax: "<'top'>",
+        grammar: &TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "isolation",
+        syntax: "auto | isolate",
+        grammar: &ISOLATION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "justify-content",
+        syntax: "normal | <content-distribution> | <overflow-position>? [ <content-position> | left | right ]",
+        grammar: &JUSTIFY_CONTENT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "justify-items",
+        syntax: "normal | stretch | <baseline-position> | <overflow-position>? [ <self-position> | left | right ] | legacy | legacy && [ left | right | center ]",
+        grammar: &JUSTIFY_ITEMS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "justify-self",
+        syntax: "auto | normal | stretch | <baseline-position> | <overflow-position>? [ <self-position> | left | right ]",
+        grammar: &JUSTIFY_SELF_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "left",
+        syntax: "auto | <length-percentage>",
+        grammar: &LEFT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "letter-spacing",
+        syntax: "normal | <length-percentage>",
+        grammar: &LETTER_SPACING_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "line-height",
+        syntax: "normal | <number> | <length-percentage>",
+        grammar: &LINE_HEIGHT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "list-style",
+        syntax: "<'list-style-position'> || <'list-style-image'> || <'list-style-type'>",
+        grammar: &LIST_STYLE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "list-style-image",
+        syntax: "<image> | none",
+        grammar: &LIST_STYLE_IMAGE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "list-style-position",
+        syntax: "inside | outside",
+        grammar: &LIST_STYLE_POSITION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "list-style-type",
+        syntax: "<custom-ident> | <symbols()> | <string> | none",
+        grammar: &LIST_STYLE_TYPE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "margin",
+        syntax: "<'margin-top'>{1,4}",
+        grammar: &MARGIN_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "margin-block",
+        syntax: "<'margin-top'>{1,2}",
+        grammar: &MARGIN_BLOCK_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "margin-block-end",
+        syntax: "<'margin-top'>",
+        grammar: &MARGIN_TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "margin-block-start",
+        syntax: "<'margin-top'>",
+        grammar: &MARGIN_TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "margin-bottom",
+        syntax: "<'margin-top'>",
+        grammar: &MARGIN_TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "margin-inline",
+        syntax: "<'margin-top'>{1,2}",
+        grammar: &MARGIN_INLINE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "margin-inline-end",
+        syntax: "<'margin-top'>",
+        grammar: &MARGIN_TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "margin-inline-start",
+        syntax: "<'margin-top'>",
+        grammar: &MARGIN_TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "margin-left",
+        syntax: "<'margin-top'>",
+        grammar: &MARGIN_TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "margin-right",
+        syntax: "<'margin-top'>",
+        grammar: &MARGIN_TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "margin-top",
+        syntax: "<length-percentage> | auto",
+        grammar: &MARGIN_TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "max-block-size",
+        syntax: "<'max-width'>",
+        grammar: &MAX_WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "max-height",
+        syntax: "<'max-width'>",
+        grammar: &MAX_WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "max-inline-size",
+        syntax: "<'max-width'>",
+        grammar: &MAX_WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "max-width",
+        syntax: "none | <length-percentage> | min-content | max-content | fit-content | <fit-content()> | stretch",
+        grammar: &MAX_WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "min-block-size",
+        syntax: "<'width'>",
+        grammar: &WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "min-height",
+        syntax: "<'width'>",
+        grammar: &WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "min-inline-size",
+        syntax: "<'width'>",
+        grammar: &WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "min-width",
+        syntax: "<'width'>",
+        grammar: &WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "mix-blend-mode",
+        syntax: "<blend-mode> | plus-darker | plus-lighter",
+        grammar: &MIX_BLEND_MODE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "object-fit",
+        syntax: "fill | contain | cover | none | scale-down",
+        grammar: &OBJECT_FIT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "object-position",
+        syntax: "<bg-position>",
+        grammar: &BG_POSITION,
+    },
+    CssPropertyDefinition {
+        name: "opacity",
+        syntax: "<number> | <percentage>",
+        grammar: &OPACITY_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "order",
+        syntax: "<integer>",
+        grammar: &ORDER_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "outline",
+        syntax: "<'outline-color'> || <'outline-style'> || <'outline-width'>",
+        grammar: &OUTLINE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "outline-color",
+        syntax: "auto | <color>",
+        grammar: &OUTLINE_COLOR_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "outline-offset",
+        syntax: "<length>",
+        grammar: &OUTLINE_OFFSET_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "outline-style",
+        syntax: "auto | <line-style>",
+        grammar: &OUTLINE_STYLE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "outline-width",
+        syntax: "<line-width>",
+        grammar: &LINE_WIDTH,
+    },
+    CssPropertyDefinition {
+        name: "overflow",
+        syntax: "<'overflow-x'>{1,2}",
+        grammar: &OVERFLOW_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "overflow-wrap",
+        syntax: "normal | break-word | anywhere",
+        grammar: &OVERFLOW_WRAP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "overflow-x",
+        syntax: "visible | hidden | clip | scroll | auto | overlay",
+        grammar: &OVERFLOW_X_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "overflow-y",
+        syntax: "<'overflow-x'>",
+        grammar: &OVERFLOW_X_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "overscroll-behavior",
+        syntax: "[ contain | none | auto ]{1,2}",
+        grammar: &OVERSCROLL_BEHAVIOR_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "padding",
+        syntax: "<'padding-top'>{1,4}",
+        grammar: &PADDING_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "padding-block",
+        syntax: "<'padding-top'>{1,2}",
+        grammar: &PADDING_BLOCK_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "padding-block-end",
+        syntax: "<'padding-top'>",
+        grammar: &LENGTH_PERCENTAGE,
+    },
+    CssPropertyDefinition {
+        name: "padding-block-start",
+        syntax: "<'padding-top'>",
+        grammar: &LENGTH_PERCENTAGE,
+    },
+    CssPropertyDefinition {
+        name: "padding-bottom",
+        syntax: "<'padding-top'>",
+        grammar: &LENGTH_PERCENTAGE,
+    },
+    CssPropertyDefinition {
+        name: "padding-inline",
+        syntax: "<'padding-top'>{1,2}",
+        grammar: &PADDING_INLINE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "padding-inline-end",
+        syntax: "<'padding-top'>",
+        grammar: &LENGTH_PERCENTAGE,
+    },
+    CssPropertyDefinition {
+        name: "padding-inline-start",
+        syntax: "<'padding-top'>",
+        grammar: &LENGTH_PERCENTAGE,
+    },
+    CssPropertyDefinition {
+        name: "padding-left",
+        syntax: "<'padding-top'>",
+        grammar: &LENGTH_PERCENTAGE,
+    },
+    CssPropertyDefinition {
+        name: "padding-right",
+        syntax: "<'padding-top'>",
+        grammar: &LENGTH_PERCENTAGE,
+    },
+    CssPropertyDefinition {
+        name: "padding-top",
+        syntax: "<length-percentage>",
+        grammar: &LENGTH_PERCENTAGE,
+    },
+    CssPropertyDefinition {
+        name: "perspective",
+        syntax: "none | <length>",
+        grammar: &PERSPECTIVE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "place-content",
+        syntax: "<'align-content'> <'justify-content'>?",
+        grammar: &PLACE_CONTENT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "place-items",
+        syntax: "<'align-items'> <'justify-items'>?",
+        grammar: &PLACE_ITEMS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "place-self",
+        syntax: "<'align-self'> <'justify-self'>?",
+        grammar: &PLACE_SELF_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "pointer-events",
+        syntax: "auto | bounding-box | visiblepainted | visiblefill | visiblestroke | visible | painted | fill | stroke | all | none",
+        grammar: &POINTER_EVENTS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "position",
+        syntax: "static | relative | absolute | sticky | fixed",
+        grammar: &POSITION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "resize",
+        syntax: "none | both | horizontal | vertical | block | inline",
+        grammar: &RESIZE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "right",
+        syntax: "auto | <length-percentage>",
+        grammar: &RIGHT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "rotate",
+        syntax: "none | <angle> | [ x | y | z | <number>{3} ] && <angle>",
+        grammar: &ROTATE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "row-gap",
+        syntax: "normal | <length-percentage>",
+        grammar: &ROW_GAP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "scale",
+        syntax: "none | [ <number> | <percentage> ]{1,3}",
+        grammar: &SCALE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "scroll-behavior",
+        syntax: "auto | smooth",
+        grammar: &SCROLL_BEHAVIOR_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "scroll-snap-align",
+        syntax: "[ none | start | end | center ]{1,2}",
+        grammar: &SCROLL_SNAP_ALIGN_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "scroll-snap-type",
+        syntax: "none | [ x | y | block | inline | both ] [ mandatory | proximity ]?",
+        grammar: &SCROLL_SNAP_TYPE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "tab-size",
+        syntax: "<number> | <length>",
+        grammar: &TAB_SIZE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "table-layout",
+        syntax: "auto | fixed",
+        grammar: &TABLE_LAYOUT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-align",
+        syntax: "start | end | left | right | center | justify | match-parent | justify-all",
+        grammar: &TEXT_ALIGN_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-align-last",
+        syntax: "auto | start | end | left | right | center | justify | match-parent",
+        grammar: &TEXT_ALIGN_LAST_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-decoration",
+        syntax: "<'text-decoration-line'> || <'text-decoration-thickness'> || <'text-decoration-style'> || <'text-decoration-color'>",
+        grammar: &TEXT_DECORATION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-decoration-color",
+        syntax: "<color>",
+        grammar: &COLOR,
+    },
+    CssPropertyDefinition {
+        name: "text-decoration-line",
+        syntax: "none | [ underline || overline || line-through || blink ]",
+        grammar: &TEXT_DECORATION_LINE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-decoration-style",
+        syntax: "solid | double | dotted | dashed | wavy",
+        grammar: &TEXT_DECORATION_STYLE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-decoration-thickness",
+        syntax: "auto | from-font | <length-percentage>",
+        grammar: &TEXT_DECORATION_THICKNESS_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-indent",
+        syntax: "<length-percentage> && hanging? && each-line?",
+        grammar: &TEXT_INDENT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-overflow",
+        syntax: "[ clip | ellipsis | <string> ]{1,2}",
+        grammar: &TEXT_OVERFLOW_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-rendering",
+        syntax: "auto | optimizespeed | optimizelegibility | geometricprecision",
+        grammar: &TEXT_RENDERING_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-shadow",
+        syntax: "none | [ <color>? && <length>{2,3} ]#",
+        grammar: &TEXT_SHADOW_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-transform",
+        syntax: "none | [ capitalize | uppercase | lowercase ] || full-width || full-size-kana | math-auto",
+        grammar: &TEXT_TRANSFORM_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-underline-offset",
+        syntax: "auto | <length-percentage>",
+        grammar: &TEXT_UNDERLINE_OFFSET_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "text-wrap-mode",
+        syntax: "wrap | nowrap",
+        grammar: &TEXT_WRAP_MODE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "top",
+        syntax: "auto | <length-percentage>",
+        grammar: &TOP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "touch-action",
+        syntax: "auto | none | [ [ pan-x | pan-left | pan-right ] || [ pan-y | pan-up | pan-down ] || pinch-zoom ] | manipulation",
+        grammar: &TOUCH_ACTION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "transform",
+        syntax: "none | <transform-function>+",
+        grammar: &TRANSFORM_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "transform-origin",
+        syntax: "[ left | center | right | top | bottom | <length-percentage> ] | [ left | center | right | <length-percentage> ] [ top | center | bottom | <length-percentage> ] <length>? | [ [ center | left | right ] && [ center | top | bottom ] ] <length>?",
+        grammar: &TRANSFORM_ORIGIN_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "transform-style",
+        syntax: "flat | preserve-3d",
+        grammar: &TRANSFORM_STYLE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "transition",
+        syntax: "<single-transition>#",
+        grammar: &TRANSITION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "transition-behavior",
+        syntax: "<transition-behavior-value>#",
+        grammar: &TRANSITION_BEHAVIOR_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "transition-delay",
+        syntax: "<time>#",
+        grammar: &TRANSITION_DELAY_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "transition-duration",
+        syntax: "<time>#",
+        grammar: &TRANSITION_DURATION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "transition-property",
+        syntax: "none | <single-transition-property>#",
+        grammar: &TRANSITION_PROPERTY_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "transition-timing-function",
+        syntax: "<easing-function>#",
+        grammar: &TRANSITION_TIMING_FUNCTION_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "translate",
+        syntax: "none | <length-percentage> [ <length-percentage> <length>? ]?",
+        grammar: &TRANSLATE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "unicode-bidi",
+        syntax: "normal | embed | isolate | bidi-override | isolate-override | plaintext",
+        grammar: &UNICODE_BIDI_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "user-select",
+        syntax: "auto | text | none | contain | all",
+        grammar: &USER_SELECT_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "vertical-align",
+        syntax: "baseline | sub | super | text-top | text-bottom | middle | top | bottom | <length-percentage>",
+        grammar: &VERTICAL_ALIGN_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "visibility",
+        syntax: "visible | hidden | collapse",
+        grammar: &VISIBILITY_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "white-space",
+        syntax: "normal | pre | pre-wrap | pre-line | <'white-space-collapse'> || <'text-wrap-mode'>",
+        grammar: &WHITE_SPACE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "white-space-collapse",
+        syntax: "collapse | discard | preserve | preserve-breaks | preserve-spaces | break-spaces",
+        grammar: &WHITE_SPACE_COLLAPSE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "width",
+        syntax: "auto | <length-percentage> | min-content | max-content | fit-content | <fit-content()> | stretch",
+        grammar: &WIDTH_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "will-change",
+        syntax: "auto | [ scroll-position | contents | <custom-ident> ]#",
+        grammar: &WILL_CHANGE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "word-break",
+        syntax: "normal | keep-all | break-all | break-word | auto-phrase",
+        grammar: &WORD_BREAK_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "word-spacing",
+        syntax: "normal | <length-percentage>",
+        grammar: &WORD_SPACING_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "word-wrap",
+        syntax: "<'overflow-wrap'>",
+        grammar: &OVERFLOW_WRAP_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "writing-mode",
+        syntax: "horizontal-tb | vertical-rl | vertical-lr | sideways-rl | sideways-lr | lr | lr-tb | rl | rl-tb | tb | tb-rl",
+        grammar: &WRITING_MODE_PROPERTY,
+    },
+    CssPropertyDefinition {
+        name: "z-index",
+        syntax: "auto | <integer>",
+        grammar: &Z_INDEX_PROPERTY,
+    },
+];
@@ -0,0 +1,85 @@
+//! The value grammars of the well-known CSS properties.
+//!
+//! Every declaration is parsed as a [CssGenericProperty](crate::CssGenericProperty), whose value
+//! is a flat list of component values. This module knows the grammars defined by the CSS
+//! specifications for the most common properties, and checks values against them with
+//! [validate_property_value].
+
+mod generated;
+mod value;
+
+pub use value::{
+    validate_property_value, CssPropertyValue, CssTypedComponentValue, CssValueKind,
+    InvalidPropertyValue, InvalidPropertyValueKind,
+};
+
+/// A property whose value grammar is known
+#[derive(Debug)]
+pub struct CssPropertyDefinition {
+    /// The name of the property, in lowercase
+    pub name: &'static str,
+    /// The value grammar, in the value definition syntax of the CSS specifications
+    pub syntax: &'static str,
+    pub grammar: &'static ValueGrammar,
+}
+
+/// A grammar of the [value definition syntax](https://drafts.csswg.org/css-values-4/#value-defs)
+#[derive(Debug)]
+pub enum ValueGrammar {
+    /// A keyword, matched case-insensitively: `auto`
+    Keyword(&'static str),
+    /// A value of a basic type: `<length>`
+    Type(ValueType),
+    /// A function, whose arguments aren't checked: `<fit-content()>`
+    Function(&'static str),
+    /// A literal `,` or `/`
+    Delimiter(char),
+    /// All the components, in order: `a b`
+    Sequence(&'static [&'static ValueGrammar]),
+    /// All the components, in any order: `a && b`
+    AllOf(&'static [&'static ValueGrammar]),
+    /// One or more of the components, in any order: `a || b`
+    AnyOf(&'static [&'static ValueGrammar]),
+    /// Exactly one of the components: `a | b`
+    OneOf(&'static [&'static ValueGrammar]),
+    /// A component repeated between `min` and `max` times: `a?`, `a*`, `a+`, `a#` or `a{1,4}`
+    Repeat {
+        grammar: &'static ValueGrammar,
+        min: u8,
+        max: Option<u8>,
+        /// Whether the repetitions are separated by commas, as in `a#`
+        comma_separated: bool,
+    },
+}
+
+/// The basic value types, validated by the kind of the component values
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ValueType {
+    Angle,
+    CustomIdent,
+    DashedIdent,
+    Flex,
+    Frequency,
+    HexColor,
+    Integer,
+    Length,
+    Number,
+    Percentage,
+    Resolution,
+    String,
+    Time,
+    Url,
+}
+
+/// Returns the definition of the property named `name`, if it's known.
+///
+/// Vendor-prefixed and custom properties are never known.
+pub fn property_definition(name: &str) -> Option<&'static CssPropertyDefinition> {
+    let index = generated::PROPERTIES
+        .binary_search_by(|definition| {
+            let bytes = definition.name.bytes();
+            bytes.cmp(name.bytes().map(|byte| byte.to_ascii_lowercase()))
+        })
+        .ok()?;
+    Some(&generated::PROPERTIES[index])
+}
@@ -0,0 +1,580 @@
+use super::{property_definition, CssPropertyDefinition, ValueGrammar, ValueType};
+use crate::{
+    AnyCssDeclarationName, AnyCssDimension, AnyCssFunction, AnyCssGenericComponentValue,
+    AnyCssValue, CssGenericProperty, CssNumber, CssSyntaxElement,
+};
+use biome_rowan::{AstNode, AstNodeList, SyntaxResult, TextRange};
+
+/// The keywords that every property accepts, on their own
+const CSS_WIDE_KEYWORDS: [&str; 5] = ["inherit", "initial", "revert", "revert-layer", "unset"];
+
+/// The functions that compute a numeric value, accepted wherever a number,
+/// a dimension or a percentage is expected
+const MATH_FUNCTIONS: [&str; 23] = [
+    "abs",
+    "acos",
+    "anchor",
+    "anchor-size",
+    "asin",
+    "atan",
+    "atan2",
+    "calc",
+    "clamp",
+    "cos",
+    "exp",
+    "hypot",
+    "log",
+    "max",
+    "min",
+    "mod",
+    "pow",
+    "rem",
+    "round",
+    "sign",
+    "sin",
+    "sqrt",
+    "tan",
+];
+
+/// The functions whose value is only known at computed-value time
+const SUBSTITUTION_FUNCTIONS: [&str; 3] = ["attr", "env", "var"];
+
+/// The result of [validate_property_value]
+#[derive(Debug, Clone)]
+pub enum CssPropertyValue {
+    /// The value matches the grammar of the property
+    Valid(Vec<CssTypedComponentValue>),
+    /// The value doesn't match the grammar of the property
+    Invalid(InvalidPropertyValue),
+    /// The property isn't known, or its value can't be checked statically,
+    /// e.g. because it uses `var()`, a vendor-prefixed keyword or some Sass syntax
+    Unchecked,
+}
+
+/// A component value of a valid property value, along with the kind of value it was matched as
+#[derive(Debug, Clone)]
+pub struct CssTypedComponentValue {
+    element: CssSyntaxElement,
+    kind: CssValueKind,
+}
+
+impl CssTypedComponentValue {
+    /// The component value: a node, or the `/` token of a [CssRatio](crate::CssRatio),
+    /// which is split into its two numbers and its delimiter
+    pub fn element(&self) -> &CssSyntaxElement {
+        &self.element
+    }
+
+    pub fn kind(&self) -> CssValueKind {
+        self.kind
+    }
+
+    pub fn range(&self) -> TextRange {
+        self.element.text_trimmed_range()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CssValueKind {
+    Keyword,
+    Type(ValueType),
+    Function,
+    Delimiter,
+}
+
+#[derive(Debug, Clone)]
+pub struct InvalidPropertyValue {
+    definition: &'static CssPropertyDefinition,
+    range: TextRange,
+    kind: InvalidPropertyValueKind,
+}
+
+impl InvalidPropertyValue {
+    pub fn definition(&self) -> &'static CssPropertyDefinition {
+        self.definition
+    }
+
+    /// The range of the first unexpected component value, or of the whole value
+    /// when it's incomplete
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    pub fn kind(&self) -> InvalidPropertyValueKind {
+        self.kind
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InvalidPropertyValueKind {
+    /// A component value isn't allowed at its position
+    Unexpected,
+    /// The value ends before the grammar of the property is satisfied
+    Incomplete,
+}
+
+/// Checks the value of `property` against the grammar of the property.
+///
+/// Values that use `var()`, vendor-prefixed keywords or some Sass or Less syntax
+/// are never checked, since the grammars don't know about them.
+pub fn validate_property_value(property: &CssGenericProperty) -> CssPropertyValue {
+    let Some(definition) = property.name().ok().and_then(|name| match name {
+        AnyCssDeclarationName::CssIdentifier(name) => {
+            property_definition(name.value_token().ok()?.text_trimmed())
+        }
+        AnyCssDeclarationName::CssDashedIdentifier(_) => None,
+    }) else {
+        return CssPropertyValue::Unchecked;
+    };
+    let value = property.value();
+    let Some(components) = collect_components(value.iter()) else {
+        return CssPropertyValue::Unchecked;
+    };
+    if components.is_empty() {
+        return CssPropertyValue::Unchecked;
+    }
+
+    if let [Component {
+        element,
+        value: ComponentValue::Identifier(name),
+    }] = components.as_slice()
+    {
+        if CSS_WIDE_KEYWORDS.contains(&name.as_str()) {
+            return CssPropertyValue::Valid(vec![CssTypedComponentValue {
+                element: element.clone(),
+                kind: CssValueKind::Keyword,
+            }]);
+        }
+    }
+
+    let mut matcher = Matcher {
+        components: &components,
+        furthest: 0,
+    };
+    let derivation = matcher
+        .match_grammar(definition.grammar, 0)
+        .into_iter()
+        .find(|derivation| derivation.end == components.len());
+    match derivation {
+        Some(derivation) => CssPropertyValue::Valid(
+            components
+                .into_iter()
+                .zip(derivation.kinds)
+                .map(|(component, kind)| CssTypedComponentValue {
+                    element: component.element,
+                    kind,
+                })
+                .collect(),
+        ),
+        None => {
+            let (range, kind) = match components.get(matcher.furthest) {
+                Some(component) => (
+                    component.element.text_trimmed_range(),
+                    InvalidPropertyValueKind::Unexpected,
+                ),
+                None => (
+                    value.syntax().text_trimmed_range(),
+                    InvalidPropertyValueKind::Incomplete,
+                ),
+            };
+            CssPropertyValue::Invalid(InvalidPropertyValue {
+                definition,
+                range,
+                kind,
+            })
+        }
+    }
+}
+
+struct Component {
+    element: CssSyntaxElement,
+    value: ComponentValue,
+}
+
+enum ComponentValue {
+    /// An identifier, in lowercase
+    Identifier(String),
+    DashedIdentifier,
+    String,
+    Number {
+        is_integer: bool,
+        is_zero: bool,
+    },
+    /// A dimension, with its unit in lowercase
+    Dimension(String),
+    Percentage,
+    HexColor {
+        is_valid: bool,
+    },
+    Url,
+    /// A function, with its name in lowercase
+    Function(String),
+    Delimiter(char),
+}
+
+/// Returns the component values of a property value, or [None] if the value can't be checked
+fn collect_components(
+    values: impl Iterator<Item = AnyCssGenericComponentValue>,
+) -> Option<Vec<Component>> {
+    let mut components = Vec::new();
+    for value in values {
+        let element = CssSyntaxElement::from(value.syntax().clone());
+        let value = match value {
+            AnyCssGenericComponentValue::AnyCssValue(value) => value,
+            AnyCssGenericComponentValue::CssGenericDelimiter(delimiter) => {
+                let delimiter = delimiter.value().ok()?;
+                let delimiter = delimiter.text_trimmed().chars().next()?;
+                components.push(Component {
+                    element,
+                    value: ComponentValue::Delimiter(delimiter),
+                });
+                continue;
+            }
+            AnyCssGenericComponentValue::LessOperator(_)
+            | AnyCssGenericComponentValue::ScssOperator(_) => return None,
+        };
+        let value = match value {
+            AnyCssValue::CssIdentifier(identifier) => {
+                identifier_value(identifier.value_token().ok()?.text_trimmed())?
+            }
+            AnyCssValue::CssCustomIdentifier(identifier) => {
+                identifier_value(identifier.value_token().ok()?.text_trimmed())?
+            }
+            AnyCssValue::CssDashedIdentifier(_) => ComponentValue::DashedIdentifier,
+            AnyCssValue::CssString(_) => ComponentValue::String,
+            AnyCssValue::CssNumber(number) => number_value(&number).ok()?,
+            AnyCssValue::AnyCssDimension(dimension) => match dimension {
+                AnyCssDimension::CssPercentage(_) => ComponentValue::Percentage,
+                AnyCssDimension::CssRegularDimension(dimension) => ComponentValue::Dimension(
+                    dimension
+                        .unit_token()
+                        .ok()?
+                        .text_trimmed()
+                        .to_ascii_lowercase(),
+                ),
+                AnyCssDimension::CssUnknownDimension(dimension) => ComponentValue::Dimension(
+                    dimension
+                        .unit_token()
+                        .ok()?
+                        .text_trimmed()
+                        .to_ascii_lowercase(),
+                ),
+            },
+            AnyCssValue::CssRatio(ratio) => {
+                // `1 / 2` is parsed as a ratio, but it's also the value of properties
+                // such as `grid-row`: the grammars see two numbers and a delimiter
+                let numerator = ratio.numerator().ok()?;
+                let slash = ratio.slash_token().ok()?;
+                let denominator = ratio.denominator().ok()?;
+                components.push(Component {
+                    value: number_value(&numerator).ok()?,
+                    element: numerator.into_syntax().into(),
+                });
+                components.push(Component {
+                    element: slash.into(),
+                    value: ComponentValue::Delimiter('/'),
+                });
+                components.push(Component {
+                    value: number_value(&denominator).ok()?,
+                    element: denominator.into_syntax().into(),
+                });
+                continue;
+            }
+            AnyCssValue::AnyCssFunction(AnyCssFunction::CssUrlFunction(_)) => ComponentValue::Url,
+            AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(function)) => {
+                let name = function.name().ok()?.value_token().ok()?;
+                let name = name.text_trimmed().to_ascii_lowercase();
+                if name.starts_with('-') || SUBSTITUTION_FUNCTIONS.contains(&name.as_str()) {
+                    return None;
+                }
+                ComponentValue::Function(name)
+            }
+            AnyCssValue::CssColor(color) => {
+                let color = color.value_token().ok()?;
+                let color = color.text_trimmed();
+                ComponentValue::HexColor {
+                    is_valid: matches!(color.len(), 3 | 4 | 6 | 8)
+                        && color.bytes().all(|byte| byte.is_ascii_hexdigit()),
+                }
+            }
+            AnyCssValue::LessEscapedString(_)
+            | AnyCssValue::LessIdentifier(_)
+            | AnyCssValue::LessParenthesizedExpression(_)
+            | AnyCssValue::ScssIdentifier(_)
+            | AnyCssValue::ScssInterpolation(_)
+            | AnyCssValue::ScssMapExpression(_)
+            | AnyCssValue::ScssParenthesizedExpression(_)
+            | AnyCssValue::ScssQualifiedName(_) => return None,
+        };
+        components.push(Component { element, value });
+    }
+    Some(components)
+}
+
+/// Vendor-prefixed keywords, e.g. `-webkit-box`, aren't part of the grammars
+fn identifier_value(text: &str) -> Option<ComponentValue> {
+    (!text.starts_with('-')).then(|| ComponentValue::Identifier(text.to_ascii_lowercase()))
+}
+
+fn number_value(number: &CssNumber) -> SyntaxResult<ComponentValue> {
+    let value = number.value_token()?;
+    let value = value.text_trimmed();
+    Ok(ComponentValue::Number {
+        is_integer: !value.contains(['.', 'e', 'E']),
+        is_zero: value.parse::<f64>().is_ok_and(|value| value == 0.0),
+    })
+}
+
+fn unit_type(unit: &str) -> Option<ValueType> {
+    let value_type = match unit {
+        "em" | "rem" | "ex" | "rex" | "cap" | "rcap" | "ch" | "rch" | "ic" | "ric" | "lh"
+        | "rlh" | "vw" | "svw" | "lvw" | "dvw" | "vh" | "svh" | "lvh" | "dvh" | "vi" | "svi"
+        | "lvi" | "dvi" | "vb" | "svb" | "lvb" | "dvb" | "vmin" | "svmin" | "lvmin" | "dvmin"
+        | "vmax" | "svmax" | "lvmax" | "dvmax" | "cqw" | "cqh" | "cqi" | "cqb" | "cqmin"
+        | "cqmax" | "cm" | "mm" | "q" | "in" | "pc" | "pt" | "px" | "mozmm" | "rpx" => {
+            ValueType::Length
+        }
+        "deg" | "grad" | "rad" | "turn" => ValueType::Angle,
+        "s" | "ms" => ValueType::Time,
+        "hz" | "khz" => ValueType::Frequency,
+        "dpi" | "dpcm" | "dppx" | "x" => ValueType::Resolution,
+        "fr" => ValueType::Flex,
+        _ => return None,
+    };
+    Some(value_type)
+}
+
+/// Returns whether the component value is of the basic type `value_type`
+fn is_of_type(value: &ComponentValue, value_type: ValueType) -> bool {
+    match value {
+        ComponentValue::Identifier(name) => {
+            value_type == ValueType::CustomIdent
+                && name != "default"
+                && !CSS_WIDE_KEYWORDS.contains(&name.as_str())
+        }
+        ComponentValue::DashedIdentifier => {
+            matches!(value_type, ValueType::CustomIdent | ValueType::DashedIdent)
+        }
+        ComponentValue::String => value_type == ValueType::String,
+        ComponentValue::Number {
+            is_integer,
+            is_zero,
+        } => match value_type {
+            ValueType::Number => true,
+            ValueType::Integer => *is_integer,
+            // Zero lengths can omit their unit, and so can zero angles in legacy syntaxes
+            ValueType::Length | ValueType::Angle => *is_zero,
+            _ => false,
+        },
+        ComponentValue::Dimension(unit) => unit_type(unit) == Some(value_type),
+        ComponentValue::Percentage => value_type == ValueType::Percentage,
+        ComponentValue::HexColor { is_valid } => *is_valid && value_type == ValueType::HexColor,
+        ComponentValue::Url => value_type == ValueType::Url,
+        ComponentValue::Function(name) => {
+            MATH_FUNCTIONS.contains(&name.as_str())
+                && matches!(
+                    value_type,
+                    ValueType::Angle
+                        | ValueType::Flex
+                        | ValueType::Frequency
+                        | ValueType::Integer
+                        | ValueType::Length
+                        | ValueType::Number
+                        | ValueType::Percentage
+                        | ValueType::Resolution
+                        | ValueType::Time
+                )
+        }
+        ComponentValue::Delimiter(_) => false,
+    }
+}
+
+/// A way of matching the component values from a start position to `end`
+#[derive(Clone)]
+struct Derivation {
+    end: usize,
+    /// The kinds of the component values that were matched
+    kinds: Vec<CssValueKind>,
+}
+
+impl Derivation {
+    fn empty(start: usize) -> Self {
+        Self {
+            end: start,
+            kinds: Vec::new(),
+        }
+    }
+
+    fn then(&self, next: Derivation) -> Self {
+        let mut kinds = self.kinds.clone();
+        kinds.extend(next.kinds);
+        Self {
+            end: next.end,
+            kinds,
+        }
+    }
+}
+
+/// Adds a derivation, unless another one already ends at the same position:
+/// the following component values match the same way for both
+fn push_derivation(derivations: &mut Vec<Derivation>, derivation: Derivation) {
+    if derivations.iter().all(|other| other.end != derivation.end) {
+        derivations.push(derivation);
+    }
+}
+
+struct Matcher<'a> {
+    components: &'a [Component],
+    /// The position following the furthest component value that matched a grammar.
+    /// When the value is invalid, it's the position of the first unexpected component value
+    furthest: usize,
+}
+
+impl Matcher<'_> {
+    /// Returns all the ways `grammar` can match the component values from `start`
+    fn match_grammar(&mut self, grammar: &ValueGrammar, start: usize) -> Vec<Derivation> {
+        match grammar {
+            ValueGrammar::Keyword(keyword) => self.match_component(start, |value| {
+                matches!(value, ComponentValue::Identifier(name) if name == keyword)
+                    .then_some(CssValueKind::Keyword)
+            }),
+            ValueGrammar::Type(value_type) => self.match_component(start, |value| {
+                is_of_type(value, *value_type).then_some(CssValueKind::Type(*value_type))
+            }),
+            ValueGrammar::Function(function) => self.match_component(start, |value| {
+                matches!(value, ComponentValue::Function(name) if name == function)
+                    .then_some(CssValueKind::Function)
+            }),
+            ValueGrammar::Delimiter(delimiter) => self.match_delimiter(start, *delimiter),
+            ValueGrammar::Sequence(grammars) => {
+                let mut derivations = vec![Derivation::empty(start)];
+                for grammar in grammars.iter() {
+                    let mut next = Vec::new();
+                    for derivation in &derivations {
+                        for following in self.match_grammar(grammar, derivation.end) {
+                            push_derivation(&mut next, derivation.then(following));
+                        }
+                    }
+                    derivations = next;
+                }
+                derivations
+            }
+            ValueGrammar::AllOf(grammars) => self.match_unordered(grammars, start, true),
+            ValueGrammar::AnyOf(grammars) => self.match_unordered(grammars, start, false),
+            ValueGrammar::OneOf(grammars) => {
+                let mut derivations = Vec::new();
+                for grammar in grammars.iter() {
+                    for derivation in self.match_grammar(grammar, start) {
+                        push_derivation(&mut derivations, derivation);
+                    }
+                }
+                derivations
+            }
+            ValueGrammar::Repeat {
+                grammar,
+                min,
+                max,
+                comma_separated,
+            } => {
+                let mut derivations = Vec::new();
+                if *min == 0 {
+                    derivations.push(Derivation::empty(start));
+                }
+                let mut current = vec![Derivation::empty(start)];
+                let mut count = 0;
+                while !current.is_empty() && max.map_or(true, |max| count < max) {
+                    count += 1;
+                    let mut next = Vec::new();
+                    for derivation in &current {
+                        let mut prefixes = vec![derivation.clone()];
+                        if *comma_separated && count > 1 {
+                            prefixes = self
+                                .match_delimiter(derivation.end, ',')
+                                .into_iter()
+                                .map(|comma| derivation.then(comma))
+                                .collect();
+                        }
+                        for prefix in prefixes {
+                            for repetition in self.match_grammar(grammar, prefix.end) {
+                                // Stop repeating a grammar that doesn't consume anything
+                                if repetition.end > derivation.end {
+                                    push_derivation(&mut next, prefix.then(repetition));
+                                }
+                            }
+                        }
+                    }
+                    if count >= *min {
+                        for derivation in &next {
+                            push_derivation(&mut derivations, derivation.clone());
+                        }
+                    }
+                    current = next;
+                }
+                derivations
+            }
+        }
+    }
+
+    /// Matches the components of `a && b` or `a || b`, in any order. `||` requires one or more
+    /// components that aren't empty, while `&&` requires all of them.
+    fn match_unordered(
+        &mut self,
+        grammars: &[&ValueGrammar],
+        start: usize,
+        all: bool,
+    ) -> Vec<Derivation> {
+        debug_assert!(grammars.len() < u32::BITS as usize);
+        let complete = (1u32 << grammars.len()) - 1;
+        let mut derivations = Vec::new();
+        let mut visited = vec![(0, start)];
+        let mut pending = vec![(0u32, Derivation::empty(start))];
+        while let Some((matched, derivation)) = pending.pop() {
+            if (all && matched == complete) || (!all && matched != 0) {
+                push_derivation(&mut derivations, derivation.clone());
+            }
+            for (index, grammar) in grammars.iter().enumerate() {
+                let bit = 1 << index;
+                if matched & bit != 0 {
+                    continue;
+                }
+                for following in self.match_grammar(grammar, derivation.end) {
+                    if !all && following.end == derivation.end {
+                        continue;
+                    }
+                    if visited.contains(&(matched | bit, following.end)) {
+                        continue;
+                    }
+                    visited.push((matched | bit, following.end));
+                    pending.push((matched | bit, derivation.then(following)));
+                }
+            }
+        }
+        derivations
+    }
+
+    fn match_delimiter(&mut self, start: usize, delimiter: char) -> Vec<Derivation> {
+        self.match_component(start, |value| {
+            matches!(value, ComponentValue::Delimiter(other) if *other == delimiter)
+                .then_some(CssValueKind::Delimiter)
+        })
+    }
+
+    fn match_component(
+        &mut self,
+        position: usize,
+        kind: impl FnOnce(&ComponentValue) -> Option<CssValueKind>,
+    ) -> Vec<Derivation> {
+        let Some(kind) = self
+            .components
+            .get(position)
+            .and_then(|component| kind(&component.value))
+        else {
+            return Vec::new();
+        };
+        self.furthest = self.furthest.max(position + 1);
+        vec![Derivation {
+            end: position + 1,
+            kinds: vec![kind],
+        }]
+    }
+}
@@ -59,7 +59,10 @@ pub fn main() -> io::Result<()> {
             fn from_str(name: &str) -> Result<Self, ()> {
                 match name {
                     #( #parse_arms )*
-                    _ => Err(()),
+                    _ => name
+                        .strip_prefix("plugin/")
+                        .and_then(Category::plugin)
+                        .ok_or(()),
                 }
             }
         }
@@ -104,6 +104,7 @@ define_categories! {
     "lint/nursery/noApproximativeNumericConstant": "https://biomejs.dev/linter/rules/no-approximative-numeric-constant",
     "lint/nursery/noBarrelFile": "https://biomejs.dev/linter/rules/no-barrel-file",
     "lint/nursery/noConsole": "https://biomejs.dev/linter/rules/no-console",
+    "lint/nursery/noDuplicateDependencies": "https://biomejs.dev/linter/rules/no-duplicate-dependencies",
     "lint/nursery/noDuplicateJsonKeys": "https://biomejs.dev/linter/rules/no-duplicate-json-keys",
     "lint/nursery/noDuplicateTestHooks": "https://biomejs.dev/linter/rules/no-duplicate-test-hooks",
     "lint/nursery/noEmptyBlockStatements": "https://biomejs.dev/linter/rules/no-empty-block-statements",
@@ -111,9 +112,12 @@ define_categories! {
     "lint/nursery/noExcessiveNestedTestSuites": "https://biomejs.dev/linter/rules/no-excessive-nested-test-suites",
     "lint/nursery/noExportsInTest": "https://biomejs.dev/linter/rules/no-exports-in-test",
     "lint/nursery/noFocusedTests": "https://biomejs.dev/linter/rules/no-focused-tests",
+    "lint/nursery/noJsonSchemaViolations": "https://biomejs.dev/linter/rules/no-json-schema-violations",
     "lint/nursery/noGlobalAssign": "https://biomejs.dev/linter/rules/no-global-assign",
     "lint/nursery/noGlobalEval": "https://biomejs.dev/linter/rules/no-global-eval",
+    "lint/nursery/noInvalidPackageExports": "https://biomejs.dev/linter/rules/no-invalid-package-exports",
     "lint/nursery/noInvalidUseBeforeDeclaration": "https://biomejs.dev/linter/rules/no-invalid-use-before-declaration",
+    "lint/nursery/noInvalidVersionRange": "https://biomejs.dev/linter/rules/no-invalid-version-range",
     "lint/nursery/noMisleadingCharacterClass": "https://biomejs.dev/linter/rules/no-misleading-character-class",
     "lint/nursery/noNamespaceImport": "https://biomejs.dev/linter/rules/no-namespace-import",
     "lint/nursery/noNodejsModules": "https://biomejs.dev/linter/rules/no-nodejs-modules",
@@ -124,10 +128,18 @@ define_categories! {
     "lint/nursery/noThenProperty": "https://biomejs.dev/linter/rules/no-then-property",
     "lint/nursery/noTypeOnlyImportAttributes": "https://biomejs.dev/linter/rules/no-type-only-import-attributes",
     "lint/nursery/noUndeclaredDependencies": "https://biomejs.dev/linter/rules/no-undeclared-dependencies",
+    "lint/nursery/noUndefinedCssModuleClasses": "https://biomejs.dev/linter/rules/no-undefined-css-module-classes",
+    "lint/nursery/noUndefinedCustomProperties": "https://biomejs.dev/linter/rules/no-undefined-custom-properties",
+    "lint/nursery/noUnknownLicense": "https://biomejs.dev/linter/rules/no-unknown-license",
+    "lint/nursery/noUnknownPropertyValue": "https://biomejs.dev/linter/rules/no-unknown-property-value",
+    "lint/nursery/noUnusedCssModuleClasses": "https://biomejs.dev/linter/rules/no-unused-css-module-classes",
+    "lint/nursery/noUnusedCustomProperties": "https://biomejs.dev/linter/rules/no-unused-custom-properties",
+    "lint/nursery/noUnusedDependencies": "https://biomejs.dev/linter/rules/no-unused-dependencies",
     "lint/nursery/noUnusedImports": "https://biomejs.dev/linter/rules/no-unused-imports",
     "lint/nursery/noUnusedPrivateClassMembers": "https://biomejs.dev/linter/rules/no-unused-private-class-members",
     "lint/nursery/noUselessLoneBlockStatements": "https://biomejs.dev/linter/rules/no-useless-lone-block-statements",
     "lint/nursery/noUselessTernary": "https://biomejs.dev/linter/rules/no-useless-ternary",
+    "lint/nursery/noUselessVarFallback": "https://biomejs.dev/linter/rules/no-useless-var-fallback",
     "lint/nursery/useAwait": "https://biomejs.dev/linter/rules/use-await",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/linter/rules/use-biome-suppression-comment",
     "lint/nursery/useConsistentArrayType": "https://biomejs.dev/linter/rules/use-consistent-array-type",
@@ -143,6 +155,7 @@ define_categories! {
     "lint/nursery/useNumberNamespace": "https://biomejs.dev/linter/rules/use-number-namespace",
     "lint/nursery/useShorthandFunctionType": "https://biomejs.dev/linter/rules/use-shorthand-function-type",
     "lint/nursery/useSortedClasses": "https://biomejs.dev/linter/rules/use-sorted-classes",
+    "lint/nursery/useSortedDependencies": "https://biomejs.dev/linter/rules/use-sorted-dependencies",
     "lint/performance/noAccumulatingSpread": "https://biomejs.dev/linter/rules/no-accumulating-spread",
     "lint/performance/noDelete": "https://biomejs.dev/linter/rules/no-delete",
     "lint/security/noDangerouslySetInnerHtml": "https://biomejs.dev/linter/rules/no-dangerously-set-inner-html",
@@ -236,6 +249,8 @@ define_categories! {
     "migrate",
     "deserialize",
     "project",
+    "search",
+    "plugin",
     "internalError/io",
     "internalError/fs",
     "internalError/panic",
@@ -1,13 +1,16 @@
 use std::{
+    collections::HashMap,
     hash::{Hash, Hasher},
     str::FromStr,
+    sync::{Mutex, MutexGuard, OnceLock},
 };
 
 /// Metadata for a diagnostic category
 ///
 /// This type cannot be instantiated outside of the `biome_diagnostics_categories`
 /// crate, which serves as a registry for all known diagnostic categories
-/// (currently this registry is fully static and generated at compile time)
+/// (the registry is generated at compile time, except for the categories of
+/// the plugins that are registered with [Category::register_plugin])
 #[derive(Debug)]
 pub struct Category {
     name: &'static str,
@@ -27,6 +30,64 @@ impl Category {
     pub fn link(&self) -> Option<&'static str> {
         self.link
     }
+
+    /// The maximum number of plugins whose categories can be registered
+    pub const MAX_PLUGINS: usize = 256;
+
+    /// Register the category `plugin/<name>` of the diagnostics emitted by the
+    /// plugin `name`, and return it
+    ///
+    /// Registering a plugin again returns its existing category. Returns `None`
+    /// if the name is empty, or if [Category::MAX_PLUGINS] plugins are already registered.
+    pub fn register_plugin(name: &str) -> Option<&'static Self> {
+        PluginRegistry::lock().register(name)
+    }
+
+    /// Return the category `plugin/<name>` if the plugin `name` is registered
+    pub fn plugin(name: &str) -> Option<&'static Self> {
+        PluginRegistry::lock().get(name)
+    }
+}
+
+/// The categories of the plugins that have been loaded
+///
+/// The plugins aren't known at compile time, so their categories are
+/// allocated when they're registered and live for the rest of the program.
+/// The registry is bounded so the allocated categories are too.
+#[derive(Default)]
+struct PluginRegistry {
+    categories: HashMap<Box<str>, &'static Category>,
+}
+
+impl PluginRegistry {
+    fn lock() -> MutexGuard<'static, Self> {
+        static REGISTRY: OnceLock<Mutex<PluginRegistry>> = OnceLock::new();
+
+        REGISTRY
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+
+    fn get(&self, name: &str) -> Option<&'static Category> {
+        self.categories.get(name).copied()
+    }
+
+    fn register(&mut self, name: &str) -> Option<&'static Category> {
+        if let Some(category) = self.get(name) {
+            return Some(category);
+        }
+        if name.is_empty() || self.categories.len() >= Category::MAX_PLUGINS {
+            return None;
+        }
+
+        let category: &'static Category = Box::leak(Box::new(Category {
+            name: Box::leak(format!("plugin/{name}").into_boxed_str()),
+            link: None,
+        }));
+        self.categories.insert(name.into(), category);
+        Some(category)
+    }
 }
 
 impl Eq for Category {}
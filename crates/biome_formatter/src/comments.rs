@@ -82,8 +82,11 @@ use self::{builder::CommentsBuilderVisitor, map::CommentsMap};
 use crate::formatter::Formatter;
 use crate::{buffer::Buffer, write};
 use crate::{CstFormatContext, FormatResult, FormatRule, TextSize, TransformSourceMap};
+use biome_diagnostics::{category, Category};
 use biome_rowan::syntax::SyntaxElementKey;
-use biome_rowan::{Language, SyntaxNode, SyntaxToken, SyntaxTriviaPieceComments};
+use biome_rowan::{
+    Direction, Language, SyntaxNode, SyntaxToken, SyntaxTriviaPieceComments, TextRange,
+};
 use rustc_hash::FxHashSet;
 #[cfg(debug_assertions)]
 use std::cell::{Cell, RefCell};
@@ -759,6 +762,17 @@ impl<L: Language> CommentPlacement<L> {
     }
 }
 
+/// The role of a comment delimiting a range of code that shouldn't be formatted
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SuppressionRangeKind {
+    /// `biome-ignore-start format: <reason>`: the code until the matching `biome-ignore-end`
+    Start,
+    /// `biome-ignore-end`: closes the last range opened by a `biome-ignore-start` comment
+    End,
+    /// `biome-ignore-all format: <reason>`: the whole file, including the code preceding the comment
+    File,
+}
+
 /// Defines how to format comments for a specific [Language].
 pub trait CommentStyle: Default {
     type Language: Language;
@@ -768,6 +782,12 @@ pub trait CommentStyle: Default {
         false
     }
 
+    /// Returns the kind of range suppression if a comment with the given `text` is a
+    /// `biome-ignore-start format:`, `biome-ignore-end` or `biome-ignore-all format:` comment.
+    fn get_suppression_range_kind(_text: &str) -> Option<SuppressionRangeKind> {
+        None
+    }
+
     /// Returns the (kind)[CommentKind] of the comment
     fn get_comment_kind(comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind;
 
@@ -818,11 +838,13 @@ impl<L: Language> Comments<L> {
         let builder = CommentsBuilderVisitor::new(style, source_map);
 
         let (comments, skipped) = builder.visit(root);
+        let suppressed_ranges = collect_suppressed_ranges::<Style>(root);
 
         Self {
             data: Rc::new(CommentsData {
                 root: Some(root.clone()),
                 is_suppression: Style::is_suppression,
+                suppressed_ranges,
 
                 comments,
                 with_skipped: skipped,
@@ -912,7 +934,8 @@ impl<L: Language> Comments<L> {
         self.data.with_skipped.contains(&token.key())
     }
 
-    /// Returns `true` if `node` has a [leading](self#leading-comments), [dangling](self#dangling-comments), or [trailing](self#trailing-comments) suppression comment.
+    /// Returns `true` if `node` has a [leading](self#leading-comments), [dangling](self#dangling-comments), or [trailing](self#trailing-comments) suppression comment,
+    /// or if it's inside a range delimited by `biome-ignore-start` and `biome-ignore-end` comments.
     ///
     /// # Examples
     ///
@@ -929,6 +952,24 @@ impl<L: Language> Comments<L> {
 
         self.leading_dangling_trailing_comments(node)
             .any(|comment| is_suppression(comment.piece().text()))
+            || self.is_in_suppressed_range(node)
+    }
+
+    /// Returns `true` if `node` is inside a range of code that shouldn't be formatted.
+    ///
+    /// Only the outermost nodes of the range are considered suppressed, because
+    /// they are formatted verbatim with all their descendants. The root is never
+    /// suppressed, so the end of the file is still formatted.
+    fn is_in_suppressed_range(&self, node: &SyntaxNode<L>) -> bool {
+        if self.data.suppressed_ranges.is_empty() || node.parent().is_none() {
+            return false;
+        }
+
+        let range = node.text_trimmed_range();
+        self.data
+            .suppressed_ranges
+            .iter()
+            .any(|suppressed| suppressed.contains_range(range))
     }
 
     #[cfg(not(debug_assertions))]
@@ -1029,11 +1070,78 @@ Node:
     }
 }
 
+/// Returns the kind of the first suppression of a comment that delimits a range of code excluded from
+/// formatting, given the range kind and the categories of each of its range suppressions.
+///
+/// `biome-ignore-start` and `biome-ignore-all` only apply to the formatter when they list the `format`
+/// category, whereas a `biome-ignore-end` without categories closes the last opened range.
+/// Used by the implementations of [CommentStyle::get_suppression_range_kind].
+pub fn find_suppression_range_kind<'a>(
+    suppressions: impl IntoIterator<Item = (SuppressionRangeKind, Vec<(&'a Category, Option<&'a str>)>)>,
+) -> Option<SuppressionRangeKind> {
+    suppressions.into_iter().find_map(|(kind, categories)| {
+        let is_format = categories
+            .iter()
+            .any(|(key, _)| *key == category!("format"));
+        match kind {
+            SuppressionRangeKind::End if categories.is_empty() => Some(kind),
+            _ if is_format => Some(kind),
+            _ => None,
+        }
+    })
+}
+
+/// Collects the ranges of code delimited by the range suppression comments of `root`.
+/// A range that isn't closed extends to the end of the file.
+fn collect_suppressed_ranges<Style: CommentStyle>(
+    root: &SyntaxNode<Style::Language>,
+) -> Vec<TextRange> {
+    let end_of_file = root.text_range().end();
+    let mut ranges = Vec::new();
+    let mut open_ranges = Vec::new();
+
+    for token in root.descendants_tokens(Direction::Next) {
+        let pieces = token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces());
+        for piece in pieces {
+            let Some(comment) = piece.as_comments() else {
+                continue;
+            };
+
+            match Style::get_suppression_range_kind(comment.text()) {
+                Some(SuppressionRangeKind::Start) => open_ranges.push(piece.text_range().end()),
+                Some(SuppressionRangeKind::End) => {
+                    if let Some(start) = open_ranges.pop() {
+                        ranges.push(TextRange::new(start, piece.text_range().start()));
+                    }
+                }
+                Some(SuppressionRangeKind::File) => {
+                    ranges.push(TextRange::new(root.text_range().start(), end_of_file));
+                }
+                None => {}
+            }
+        }
+    }
+
+    ranges.extend(
+        open_ranges
+            .into_iter()
+            .map(|start| TextRange::new(start, end_of_file)),
+    );
+
+    ranges
+}
+
 struct CommentsData<L: Language> {
     root: Option<SyntaxNode<L>>,
 
     is_suppression: fn(&str) -> bool,
 
+    /// Ranges of code delimited by range suppression comments
+    suppressed_ranges: Vec<TextRange>,
+
     /// Stores all leading node comments by node
     comments: CommentsMap<SyntaxElementKey, SourceComment<L>>,
     with_skipped: FxHashSet<SyntaxElementKey>,
@@ -1055,6 +1163,7 @@ impl<L: Language> Default for CommentsData<L> {
         Self {
             root: None,
             is_suppression: |_| false,
+            suppressed_ranges: Vec::new(),
             comments: Default::default(),
             with_skipped: Default::default(),
             #[cfg(debug_assertions)]
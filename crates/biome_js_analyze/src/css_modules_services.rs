@@ -0,0 +1,208 @@
+use crate::semantic_services::SemanticModelBuilderVisitor;
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor,
+};
+use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsCombinedSpecifier, AnyJsImportClause, AnyJsMemberExpression,
+    AnyJsNamedImportSpecifier, AnyJsRoot, JsIdentifierExpression, JsLanguage,
+    JsNamedImportSpecifiers, JsSyntaxNode, TextRange,
+};
+use biome_project::CssModule;
+use biome_rowan::{AstNode, AstSeparatedList};
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+
+/// The CSS modules imported by the analyzed file, keyed by the source of their import,
+/// e.g. `./button.module.css`
+#[derive(Debug, Clone, Default)]
+pub struct ImportedCssModules {
+    modules: FxHashMap<String, CssModule>,
+}
+
+impl ImportedCssModules {
+    pub fn insert(&mut self, source: impl Into<String>, module: CssModule) {
+        self.modules.insert(source.into(), module);
+    }
+
+    pub fn get(&self, source: &str) -> Option<&CssModule> {
+        self.modules.get(source)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+}
+
+pub struct CssModulesServices {
+    css_modules: Arc<ImportedCssModules>,
+    model: SemanticModel,
+}
+
+impl CssModulesServices {
+    /// Returns the CSS module imported by `clause`, if any, along with the
+    /// names the file accesses from it
+    pub(crate) fn css_module_accesses(
+        &self,
+        clause: &AnyJsImportClause,
+    ) -> Option<(&CssModule, CssModuleAccesses)> {
+        let source = clause.source().ok()?.inner_string_text().ok()?;
+        let module = self.css_modules.get(source.text())?;
+
+        let mut accesses = CssModuleAccesses::default();
+        match clause {
+            // The module is only imported for its side effects
+            AnyJsImportClause::JsImportBareClause(_) => accesses.is_dynamic = true,
+            AnyJsImportClause::JsImportDefaultClause(clause) => {
+                let specifier = clause.default_specifier().ok()?;
+                accesses.add_binding_references(&self.model, &specifier.local_name().ok()?);
+            }
+            AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                let specifier = clause.namespace_specifier().ok()?;
+                accesses.add_binding_references(&self.model, &specifier.local_name().ok()?);
+            }
+            AnyJsImportClause::JsImportNamedClause(clause) => {
+                accesses.add_named_specifiers(&clause.named_specifiers().ok()?);
+            }
+            AnyJsImportClause::JsImportCombinedClause(clause) => {
+                let specifier = clause.default_specifier().ok()?;
+                accesses.add_binding_references(&self.model, &specifier.local_name().ok()?);
+                match clause.specifier().ok()? {
+                    AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers) => {
+                        accesses.add_named_specifiers(&specifiers);
+                    }
+                    AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(specifier) => {
+                        accesses.add_binding_references(&self.model, &specifier.local_name().ok()?);
+                    }
+                }
+            }
+        }
+
+        Some((module, accesses))
+    }
+}
+
+/// The names a file accesses from an imported CSS module
+#[derive(Debug, Default)]
+pub(crate) struct CssModuleAccesses {
+    /// The names accessed statically, e.g. `styles.button` or `import { button }`,
+    /// with the range of the access
+    pub(crate) names: Vec<(String, TextRange)>,
+    /// Whether the imported object is used in a way that doesn't reveal which
+    /// names it accesses, e.g. `styles[variant]` or `fn(styles)`
+    pub(crate) is_dynamic: bool,
+}
+
+impl CssModuleAccesses {
+    fn add_binding_references(&mut self, model: &SemanticModel, binding: &AnyJsBinding) {
+        let Some(binding) = binding.as_js_identifier_binding() else {
+            self.is_dynamic = true;
+            return;
+        };
+
+        for reference in binding.all_references(model) {
+            let member = reference
+                .syntax()
+                .parent()
+                .and_then(JsIdentifierExpression::cast)
+                .and_then(|expression| {
+                    let member = AnyJsMemberExpression::cast(expression.syntax().parent()?)?;
+                    let is_object = member
+                        .object()
+                        .is_ok_and(|object| object.syntax() == expression.syntax());
+                    is_object.then_some(member)
+                });
+            match member
+                .as_ref()
+                .and_then(|member| Some((member, member.member_name()?)))
+            {
+                Some((member, name)) => {
+                    let range = match member {
+                        AnyJsMemberExpression::JsStaticMemberExpression(member) => member
+                            .member()
+                            .map_or_else(|_| member.range(), |name| name.range()),
+                        AnyJsMemberExpression::JsComputedMemberExpression(member) => member
+                            .member()
+                            .map_or_else(|_| member.range(), |name| name.range()),
+                    };
+                    self.names.push((name.text().to_string(), range));
+                }
+                None => self.is_dynamic = true,
+            }
+        }
+    }
+
+    fn add_named_specifiers(&mut self, specifiers: &JsNamedImportSpecifiers) {
+        for specifier in specifiers.specifiers().iter().flatten() {
+            let name = match &specifier {
+                AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => specifier
+                    .name()
+                    .and_then(|name| name.inner_string_text())
+                    .ok()
+                    .map(|name| name.text().to_string()),
+                AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_) => specifier
+                    .imported_name()
+                    .map(|name| name.text_trimmed().to_string()),
+                AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => None,
+            };
+            match name {
+                Some(name) => self.names.push((name, specifier.range())),
+                None => self.is_dynamic = true,
+            }
+        }
+    }
+}
+
+impl FromServices for CssModulesServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> Result<Self, MissingServicesDiagnostic> {
+        let css_modules: &Arc<ImportedCssModules> = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["ImportedCssModules"])
+        })?;
+        let model: &SemanticModel = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["SemanticModel"])
+        })?;
+        Ok(Self {
+            css_modules: css_modules.clone(),
+            model: model.clone(),
+        })
+    }
+}
+
+impl Phase for CssModulesServices {
+    fn phase() -> Phases {
+        Phases::Semantic
+    }
+}
+
+/// Query type usable by lint rules **that use the CSS modules imported by the file**
+/// to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct CssModules<N>(pub N);
+
+impl<N> Queryable for CssModules<N>
+where
+    N: AstNode<Language = JsLanguage> + 'static,
+{
+    type Input = JsSyntaxNode;
+    type Output = N;
+
+    type Language = JsLanguage;
+    type Services = CssModulesServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, root: &AnyJsRoot) {
+        analyzer.add_visitor(Phases::Syntax, || SemanticModelBuilderVisitor::new(root));
+        analyzer.add_visitor(Phases::Semantic, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
@@ -1002,4 +1002,10 @@ fn test_order() {
     for items in BROWSER.windows(2) {
         assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
     }
+    for items in SERVICE_WORKER.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+    for items in WORKER.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
 }
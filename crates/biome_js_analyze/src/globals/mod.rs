@@ -5,4 +5,72 @@
 pub mod browser;
 pub mod node;
 pub mod runtime;
+pub mod testing;
 pub mod typescript;
+
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{JsFileSource, Language};
+use browser::{BROWSER, SERVICE_WORKER, WORKER};
+use node::{BUN, DENO, NODE};
+use runtime::{BUILTIN, ES_2021};
+use serde::{Deserialize, Serialize};
+use testing::{JEST, MOCHA, VITEST};
+use typescript::TYPESCRIPT_BUILTIN;
+
+/// Returns `true` if `reference_name` is a global of the runtimes that Biome knows:
+/// ECMAScript, the browsers and Node.js, and TypeScript for TypeScript files
+pub fn is_known_global(reference_name: &str, source_type: &JsFileSource) -> bool {
+    ES_2021.binary_search(&reference_name).is_ok()
+        || BROWSER.binary_search(&reference_name).is_ok()
+        || NODE.binary_search(&reference_name).is_ok()
+        || match source_type.language() {
+            Language::JavaScript => BUILTIN.binary_search(&reference_name).is_ok(),
+            Language::TypeScript { .. } => {
+                BUILTIN.binary_search(&reference_name).is_ok()
+                    || TYPESCRIPT_BUILTIN.binary_search(&reference_name).is_ok()
+            }
+        }
+}
+
+/// An environment where the code runs, that defines its own globals
+#[derive(Clone, Copy, Debug, Deserialize, Deserializable, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum JsEnvironment {
+    Browser,
+    Node,
+    Worker,
+    Serviceworker,
+    Deno,
+    Bun,
+    Jest,
+    Vitest,
+    Mocha,
+}
+
+impl JsEnvironment {
+    /// Returns the sorted globals of the environment
+    pub fn globals(self) -> &'static [&'static str] {
+        match self {
+            JsEnvironment::Browser => &BROWSER,
+            JsEnvironment::Node => &NODE,
+            JsEnvironment::Worker => &WORKER,
+            JsEnvironment::Serviceworker => &SERVICE_WORKER,
+            JsEnvironment::Deno => &DENO,
+            JsEnvironment::Bun => &BUN,
+            JsEnvironment::Jest => &JEST,
+            JsEnvironment::Vitest => &VITEST,
+            JsEnvironment::Mocha => &MOCHA,
+        }
+    }
+
+    /// Returns `true` if [is_known_global] already knows the globals of the environment
+    pub fn is_known(self) -> bool {
+        matches!(self, JsEnvironment::Browser | JsEnvironment::Node)
+    }
+
+    /// Returns `true` if `name` is a global of the environment
+    pub fn has_global(self, name: &str) -> bool {
+        self.globals().binary_search(&name).is_ok()
+    }
+}
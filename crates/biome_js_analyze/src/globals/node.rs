@@ -233,6 +233,199 @@ pub const NODE_BUILTIN_MODULES: &[&str] = &[
 /// Sorted array of CommonJs builtin
 pub const COMMON_JS: [&str; 4] = ["exports", "global", "module", "require"];
 
+/// Sorted array of the globals of Deno: the `Deno` namespace and the web APIs
+///
+/// Source: https://docs.deno.com/api/web/
+pub const DENO: [&str; 94] = [
+    "AbortController",
+    "AbortSignal",
+    "Blob",
+    "BroadcastChannel",
+    "ByteLengthQueuingStrategy",
+    "Cache",
+    "CacheStorage",
+    "CloseEvent",
+    "CompressionStream",
+    "CountQueuingStrategy",
+    "Crypto",
+    "CryptoKey",
+    "CustomEvent",
+    "DOMException",
+    "DecompressionStream",
+    "Deno",
+    "ErrorEvent",
+    "Event",
+    "EventSource",
+    "EventTarget",
+    "File",
+    "FileReader",
+    "FormData",
+    "Headers",
+    "Location",
+    "MessageChannel",
+    "MessageEvent",
+    "MessagePort",
+    "Navigator",
+    "Performance",
+    "PerformanceEntry",
+    "PerformanceMark",
+    "PerformanceMeasure",
+    "ProgressEvent",
+    "PromiseRejectionEvent",
+    "ReadableByteStreamController",
+    "ReadableStream",
+    "ReadableStreamBYOBReader",
+    "ReadableStreamBYOBRequest",
+    "ReadableStreamDefaultController",
+    "ReadableStreamDefaultReader",
+    "Request",
+    "Response",
+    "Storage",
+    "SubtleCrypto",
+    "TextDecoder",
+    "TextDecoderStream",
+    "TextEncoder",
+    "TextEncoderStream",
+    "TransformStream",
+    "TransformStreamDefaultController",
+    "URL",
+    "URLPattern",
+    "URLSearchParams",
+    "WebSocket",
+    "Window",
+    "Worker",
+    "WritableStream",
+    "WritableStreamDefaultController",
+    "WritableStreamDefaultWriter",
+    "addEventListener",
+    "alert",
+    "atob",
+    "btoa",
+    "caches",
+    "clearInterval",
+    "clearTimeout",
+    "close",
+    "closed",
+    "confirm",
+    "console",
+    "crypto",
+    "dispatchEvent",
+    "fetch",
+    "localStorage",
+    "location",
+    "name",
+    "navigator",
+    "onbeforeunload",
+    "onerror",
+    "onload",
+    "onunhandledrejection",
+    "onunload",
+    "performance",
+    "prompt",
+    "queueMicrotask",
+    "removeEventListener",
+    "reportError",
+    "self",
+    "sessionStorage",
+    "setInterval",
+    "setTimeout",
+    "structuredClone",
+    "window",
+];
+
+/// Sorted array of the globals of Bun: the `Bun` namespace, its own APIs like
+/// `HTMLRewriter`, and the web and Node.js APIs it implements
+///
+/// Source: https://bun.sh/docs/api/globals
+pub const BUN: [&str; 86] = [
+    "AbortController",
+    "AbortSignal",
+    "Blob",
+    "BroadcastChannel",
+    "Buffer",
+    "BuildMessage",
+    "Bun",
+    "ByteLengthQueuingStrategy",
+    "CloseEvent",
+    "CompressionStream",
+    "CountQueuingStrategy",
+    "Crypto",
+    "CryptoKey",
+    "CustomEvent",
+    "DOMException",
+    "DecompressionStream",
+    "ErrorEvent",
+    "Event",
+    "EventSource",
+    "EventTarget",
+    "File",
+    "FormData",
+    "HTMLRewriter",
+    "Headers",
+    "MessageChannel",
+    "MessageEvent",
+    "MessagePort",
+    "Performance",
+    "PerformanceEntry",
+    "PerformanceMark",
+    "PerformanceMeasure",
+    "ProgressEvent",
+    "PromiseRejectionEvent",
+    "ReadableByteStreamController",
+    "ReadableStream",
+    "ReadableStreamBYOBReader",
+    "ReadableStreamBYOBRequest",
+    "ReadableStreamDefaultController",
+    "ReadableStreamDefaultReader",
+    "Request",
+    "ResolveMessage",
+    "Response",
+    "SubtleCrypto",
+    "TextDecoder",
+    "TextDecoderStream",
+    "TextEncoder",
+    "TextEncoderStream",
+    "TransformStream",
+    "TransformStreamDefaultController",
+    "URL",
+    "URLSearchParams",
+    "WebSocket",
+    "Worker",
+    "WritableStream",
+    "WritableStreamDefaultController",
+    "WritableStreamDefaultWriter",
+    "__dirname",
+    "__filename",
+    "addEventListener",
+    "alert",
+    "atob",
+    "btoa",
+    "clearImmediate",
+    "clearInterval",
+    "clearTimeout",
+    "confirm",
+    "console",
+    "crypto",
+    "dispatchEvent",
+    "exports",
+    "fetch",
+    "global",
+    "module",
+    "navigator",
+    "performance",
+    "process",
+    "prompt",
+    "queueMicrotask",
+    "removeEventListener",
+    "reportError",
+    "require",
+    "self",
+    "setImmediate",
+    "setInterval",
+    "setTimeout",
+    "structuredClone",
+];
+
 /// Returns `true` if `name` is a Node builtin module.
 ///
 /// ```
@@ -258,4 +451,10 @@ fn test_order() {
     for items in NODE_BUILTIN_MODULES.windows(2) {
         assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
     }
+    for items in DENO.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+    for items in BUN.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
 }
@@ -0,0 +1,77 @@
+/// Sorted array of the globals of Jest
+pub const JEST: [&str; 16] = [
+    "afterAll",
+    "afterEach",
+    "beforeAll",
+    "beforeEach",
+    "describe",
+    "expect",
+    "fdescribe",
+    "fit",
+    "it",
+    "jest",
+    "pit",
+    "require",
+    "test",
+    "xdescribe",
+    "xit",
+    "xtest",
+];
+
+/// Sorted array of the globals of Mocha
+pub const MOCHA: [&str; 20] = [
+    "after",
+    "afterEach",
+    "before",
+    "beforeEach",
+    "context",
+    "describe",
+    "it",
+    "mocha",
+    "run",
+    "setup",
+    "specify",
+    "suite",
+    "suiteSetup",
+    "suiteTeardown",
+    "teardown",
+    "test",
+    "xcontext",
+    "xdescribe",
+    "xit",
+    "xspecify",
+];
+
+/// Sorted array of the globals of Vitest, when its `globals` option is enabled
+pub const VITEST: [&str; 17] = [
+    "afterAll",
+    "afterEach",
+    "assert",
+    "assertType",
+    "beforeAll",
+    "beforeEach",
+    "chai",
+    "describe",
+    "expect",
+    "expectTypeOf",
+    "it",
+    "onTestFailed",
+    "onTestFinished",
+    "suite",
+    "test",
+    "vi",
+    "vitest",
+];
+
+#[test]
+fn test_order() {
+    for items in JEST.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+    for items in MOCHA.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+    for items in VITEST.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+}
@@ -2,14 +2,15 @@
 
 use crate::suppression_action::apply_suppression_comment;
 use biome_analyze::{
-    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
-    InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    SuppressionKind,
+    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerPlugin, AnalyzerSignal,
+    ControlFlow, InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction,
+    RuleCategories, RuleRegistry, SuppressionKind,
 };
 use biome_aria::{AriaProperties, AriaRoles};
 use biome_diagnostics::{category, Diagnostic, Error as DiagnosticError};
 use biome_js_syntax::suppression::SuppressionDiagnostic;
-use biome_js_syntax::{suppression::parse_suppression_comment, JsFileSource, JsLanguage};
+use biome_js_syntax::suppression::{parse_suppression_comment, Suppression, SuppressionScope};
+use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_project::PackageJson;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -21,6 +22,7 @@ mod aria_services;
 mod assists;
 mod ast_utils;
 mod control_flow;
+mod css_modules_services;
 pub mod globals;
 mod manifest_services;
 pub mod options;
@@ -33,10 +35,14 @@ mod syntax;
 pub mod utils;
 
 pub use crate::control_flow::ControlFlowGraph;
+pub use crate::css_modules_services::ImportedCssModules;
 pub use crate::registry::visit_registry;
 
 pub(crate) type JsRuleAction = RuleAction<JsLanguage>;
 
+/// List of the plugins to run alongside the lint rules
+pub type JsAnalyzerPlugins<'a> = &'a [Arc<dyn AnalyzerPlugin<JsLanguage>>];
+
 /// Return the static [MetadataRegistry] for the JS analyzer rules
 pub fn metadata() -> &'static MetadataRegistry {
     lazy_static::lazy_static! {
@@ -55,7 +61,9 @@ pub fn metadata() -> &'static MetadataRegistry {
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action.
 /// Additionally, this function takes a `inspect_matcher` function that can be
 /// used to inspect the "query matches" emitted by the analyzer before they are
-/// processed by the lint rules registry
+/// processed by the lint rules registry. The `plugins` are only run when the
+/// `filter` enables the lint rules
+#[allow(clippy::too_many_arguments)]
 pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
@@ -63,6 +71,8 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    css_modules: ImportedCssModules,
+    plugins: JsAnalyzerPlugins,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -81,6 +91,9 @@ where
                     if comment.is_legacy {
                         result.push(Ok(SuppressionKind::Deprecated));
                     }
+                    if let Some(scope) = suppression_scope(&comment) {
+                        result.push(Ok(scope));
+                    }
                     comment.categories
                 }
                 Err(err) => {
@@ -96,10 +109,14 @@ where
                     } else {
                         result.push(Ok(SuppressionKind::Everything));
                     }
+                } else if key == category!("plugin") {
+                    result.push(Ok(SuppressionKind::Plugin(value)));
                 } else {
                     let category = key.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
                         result.push(Ok(SuppressionKind::Rule(rule)));
+                    } else if let Some(plugin) = category.strip_prefix("plugin/") {
+                        result.push(Ok(SuppressionKind::Plugin(Some(plugin))));
                     }
                 }
             }
@@ -108,6 +125,26 @@ where
         result
     }
 
+    /// Returns the marker of a range or file suppression. The end of a range is
+    /// ignored if it only names categories unrelated to the linter, eg. `// biome-ignore-end format: <reason>`
+    fn suppression_scope<'a>(comment: &Suppression) -> Option<SuppressionKind<'a>> {
+        match comment.scope {
+            SuppressionScope::Line => None,
+            SuppressionScope::RangeStart => Some(SuppressionKind::RangeStart),
+            SuppressionScope::File => Some(SuppressionKind::File),
+            SuppressionScope::RangeEnd => {
+                let is_lint_end = comment.categories.is_empty()
+                    || comment.categories.iter().any(|(key, _)| {
+                        *key == category!("lint")
+                            || *key == category!("plugin")
+                            || key.name().starts_with("lint/")
+                            || key.name().starts_with("plugin/")
+                    });
+                is_lint_end.then_some(SuppressionKind::RangeEnd)
+            }
+        }
+    }
+
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
@@ -130,11 +167,18 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    if filter.categories.contains(RuleCategories::LINT) {
+        for plugin in plugins {
+            analyzer.add_plugin(plugin.clone());
+        }
+    }
+
     services.insert_service(Arc::new(AriaRoles));
     services.insert_service(Arc::new(AriaProperties));
     if let Some(manifest) = manifest {
         services.insert_service(Arc::new(manifest));
     }
+    services.insert_service(Arc::new(css_modules));
     services.insert_service(source_type);
     (
         analyzer.run(AnalyzerContext {
@@ -150,12 +194,15 @@ where
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action
+#[allow(clippy::too_many_arguments)]
 pub fn analyze<'a, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    css_modules: ImportedCssModules,
+    plugins: JsAnalyzerPlugins,
     emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -169,6 +216,8 @@ where
         options,
         source_type,
         manifest,
+        css_modules,
+        plugins,
         emit_signal,
     )
 }
@@ -234,8 +283,8 @@ mod tests {
     use biome_analyze::{AnalyzerOptions, Never, RuleCategories, RuleFilter, RuleKey};
     use biome_console::fmt::{Formatter, Termcolor};
     use biome_console::{markup, Markup};
-    use biome_diagnostics::category;
     use biome_diagnostics::termcolor::NoColor;
+    use biome_diagnostics::{category, Category};
     use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic, Severity};
     use biome_js_parser::{parse, JsParserOptions};
     use biome_js_syntax::{JsFileSource, TextRange, TextSize};
@@ -244,7 +293,7 @@ mod tests {
     use crate::semantic_analyzers::correctness::use_exhaustive_dependencies::{
         Hooks, HooksOptions,
     };
-    use crate::{analyze, AnalysisFilter, ControlFlow};
+    use crate::{analyze, AnalysisFilter, ControlFlow, ImportedCssModules};
 
     // #[ignore]
     #[test]
@@ -289,6 +338,8 @@ mod tests {
             &options,
             JsFileSource::tsx(),
             None,
+            ImportedCssModules::default(),
+            &[],
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -381,6 +432,8 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            ImportedCssModules::default(),
+            &[],
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let span = diag.get_span();
@@ -467,6 +520,8 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            ImportedCssModules::default(),
+            &[],
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let code = diag.category().unwrap();
@@ -479,4 +534,92 @@ mod tests {
             },
         );
     }
+
+    /// Returns the categories of the `noDoubleEquals` and suppression diagnostics emitted for `source`
+    fn lint_categories(source: &str) -> Vec<&'static Category> {
+        let parsed = parse(
+            source,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+
+        let mut categories = Vec::new();
+        let options = AnalyzerOptions::default();
+        analyze(
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            JsFileSource::js_module(),
+            None,
+            ImportedCssModules::default(),
+            &[],
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    let code = diag.category().unwrap();
+                    if code == category!("lint/suspicious/noDoubleEquals")
+                        || code.name().starts_with("suppressions/")
+                    {
+                        categories.push(code);
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        categories
+    }
+
+    #[test]
+    fn range_suppression() {
+        const SOURCE: &str = "
+            a == b;
+            // biome-ignore-start lint/suspicious/noDoubleEquals: generated code
+            a == b;
+            a == b;
+            // biome-ignore-end
+            a == b;
+        ";
+
+        assert_eq!(
+            lint_categories(SOURCE),
+            [
+                category!("lint/suspicious/noDoubleEquals"),
+                category!("lint/suspicious/noDoubleEquals")
+            ]
+        );
+    }
+
+    #[test]
+    fn file_suppression() {
+        const SOURCE: &str = "// biome-ignore-all lint/suspicious/noDoubleEquals: generated file
+            a == b;
+            a == b;
+        ";
+
+        assert!(lint_categories(SOURCE).is_empty());
+    }
+
+    #[test]
+    fn file_suppression_covers_preceding_code() {
+        const SOURCE: &str = "
+            a == b;
+            a == b;
+            // biome-ignore-all lint/suspicious/noDoubleEquals: generated file
+            a == b;
+        ";
+
+        assert!(lint_categories(SOURCE).is_empty());
+    }
+
+    #[test]
+    fn unused_range_suppression() {
+        const SOURCE: &str = "
+            // biome-ignore-start lint/suspicious/noDoubleEquals: generated code
+            a === b;
+            // biome-ignore-end
+        ";
+
+        assert_eq!(lint_categories(SOURCE), [category!("suppressions/unused")]);
+    }
 }
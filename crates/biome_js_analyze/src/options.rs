@@ -164,6 +164,7 @@ pub type NoThenProperty =
 pub type NoThisInStatic = < semantic_analyzers :: complexity :: no_this_in_static :: NoThisInStatic as biome_analyze :: Rule > :: Options ;
 pub type NoUndeclaredDependencies = < analyzers :: nursery :: no_undeclared_dependencies :: NoUndeclaredDependencies as biome_analyze :: Rule > :: Options ;
 pub type NoUndeclaredVariables = < semantic_analyzers :: correctness :: no_undeclared_variables :: NoUndeclaredVariables as biome_analyze :: Rule > :: Options ;
+pub type NoUndefinedCssModuleClasses = < semantic_analyzers :: nursery :: no_undefined_css_module_classes :: NoUndefinedCssModuleClasses as biome_analyze :: Rule > :: Options ;
 pub type NoUnnecessaryContinue = < analyzers :: correctness :: no_unnecessary_continue :: NoUnnecessaryContinue as biome_analyze :: Rule > :: Options ;
 pub type NoUnreachable =
     <analyzers::correctness::no_unreachable::NoUnreachable as biome_analyze::Rule>::Options;
@@ -174,6 +175,7 @@ pub type NoUnsafeFinally =
 pub type NoUnsafeNegation =
     <analyzers::suspicious::no_unsafe_negation::NoUnsafeNegation as biome_analyze::Rule>::Options;
 pub type NoUnsafeOptionalChaining = < analyzers :: correctness :: no_unsafe_optional_chaining :: NoUnsafeOptionalChaining as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedCssModuleClasses = < semantic_analyzers :: nursery :: no_unused_css_module_classes :: NoUnusedCssModuleClasses as biome_analyze :: Rule > :: Options ;
 pub type NoUnusedImports = < semantic_analyzers :: nursery :: no_unused_imports :: NoUnusedImports as biome_analyze :: Rule > :: Options ;
 pub type NoUnusedLabels =
     <analyzers::correctness::no_unused_labels::NoUnusedLabels as biome_analyze::Rule>::Options;
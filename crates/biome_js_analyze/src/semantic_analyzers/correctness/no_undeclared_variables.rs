@@ -1,12 +1,9 @@
-use crate::globals::browser::BROWSER;
-use crate::globals::node::NODE;
-use crate::globals::runtime::{BUILTIN, ES_2021};
-use crate::globals::typescript::TYPESCRIPT_BUILTIN;
+use crate::globals::is_known_global;
 use crate::semantic_services::SemanticServices;
 use biome_analyze::context::RuleContext;
 use biome_analyze::{declare_rule, Rule, RuleDiagnostic, RuleSource};
 use biome_console::markup;
-use biome_js_syntax::{JsFileSource, Language, TextRange, TsAsExpression, TsReferenceType};
+use biome_js_syntax::{JsFileSource, TextRange, TsAsExpression, TsReferenceType};
 use biome_rowan::AstNode;
 
 declare_rule! {
@@ -69,7 +66,7 @@ impl Rule for NoUndeclaredVariables {
                     return None;
                 }
 
-                if is_global(text, source_type) {
+                if is_known_global(text, source_type) {
                     return None;
                 }
 
@@ -90,16 +87,3 @@ impl Rule for NoUndeclaredVariables {
         ))
     }
 }
-
-fn is_global(reference_name: &str, source_type: &JsFileSource) -> bool {
-    ES_2021.binary_search(&reference_name).is_ok()
-        || BROWSER.binary_search(&reference_name).is_ok()
-        || NODE.binary_search(&reference_name).is_ok()
-        || match source_type.language() {
-            Language::JavaScript => BUILTIN.binary_search(&reference_name).is_ok(),
-            Language::TypeScript { .. } => {
-                BUILTIN.binary_search(&reference_name).is_ok()
-                    || TYPESCRIPT_BUILTIN.binary_search(&reference_name).is_ok()
-            }
-        }
-}
@@ -10,6 +10,8 @@ pub mod no_misleading_character_class;
 pub mod no_re_export_all;
 pub mod no_semicolon_in_jsx;
 pub mod no_then_property;
+pub mod no_undefined_css_module_classes;
+pub mod no_unused_css_module_classes;
 pub mod no_unused_imports;
 pub mod use_export_type;
 pub mod use_for_of;
@@ -30,6 +32,8 @@ declare_group! {
             self :: no_re_export_all :: NoReExportAll ,
             self :: no_semicolon_in_jsx :: NoSemicolonInJsx ,
             self :: no_then_property :: NoThenProperty ,
+            self :: no_undefined_css_module_classes :: NoUndefinedCssModuleClasses ,
+            self :: no_unused_css_module_classes :: NoUnusedCssModuleClasses ,
             self :: no_unused_imports :: NoUnusedImports ,
             self :: use_export_type :: UseExportType ,
             self :: use_for_of :: UseForOf ,
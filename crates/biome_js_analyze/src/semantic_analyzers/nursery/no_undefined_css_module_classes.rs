@@ -0,0 +1,85 @@
+use crate::css_modules_services::CssModules;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{JsImport, TextRange};
+
+declare_rule! {
+    /// Disallow the use of classes that aren't defined by the imported CSS module.
+    ///
+    /// A [CSS module](https://github.com/css-modules/css-modules) exports the local classes
+    /// it defines, as well as its `@value` declarations and its keyframes.
+    /// Accessing any other name on the imported object evaluates to `undefined`,
+    /// which usually means that the class was misspelled or removed from the module.
+    ///
+    /// Only the modules imported with a relative path ending in `.module.css` are checked.
+    /// Classes containing dashes can also be accessed in camel case.
+    ///
+    /// ## Examples
+    ///
+    /// The following examples import a `button.module.css` file that defines
+    /// the classes `.button` and `.primary-button`.
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,ignore
+    /// import styles from "./button.module.css";
+    ///
+    /// <button className={styles.secondaryButton} />;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx,ignore
+    /// import styles from "./button.module.css";
+    ///
+    /// <button className={`${styles.button} ${styles.primaryButton}`} />;
+    /// ```
+    ///
+    pub NoUndefinedCssModuleClasses {
+        version: "next",
+        name: "noUndefinedCssModuleClasses",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUndefinedCssModuleClasses {
+    type Query = CssModules<JsImport>;
+    type State = (String, TextRange);
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some((module, accesses)) = ctx
+            .query()
+            .import_clause()
+            .ok()
+            .and_then(|clause| ctx.css_module_accesses(&clause))
+        else {
+            return Vec::new();
+        };
+
+        accesses
+            .names
+            .into_iter()
+            .filter(|(name, _)| !module.exports(name))
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (name, range) = state;
+        let source_text = ctx.query().source_text().ok()?;
+        let source = source_text.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The class "<Emphasis>{name}</Emphasis>" isn't defined in "<Emphasis>{source}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Accessing it evaluates to "<Emphasis>"undefined"</Emphasis>"."
+            }),
+        )
+    }
+}
@@ -0,0 +1,130 @@
+use crate::css_modules_services::CssModules;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{JsImport, JsModuleItemList};
+use biome_project::is_class_accessed_as;
+use biome_rowan::{AstNode, AstNodeList};
+
+declare_rule! {
+    /// Disallow the classes of an imported CSS module that are never used.
+    ///
+    /// A [CSS module](https://github.com/css-modules/css-modules) scopes its classes to the
+    /// files that import it. A class that these files never access is dead code, that
+    /// usually remains after a refactoring.
+    ///
+    /// Only the modules imported with a relative path ending in `.module.css` are checked.
+    /// A class is considered used when it's accessed on the imported object, either as is or
+    /// in camel case, or when another class of the module composes it.
+    /// The rule doesn't report anything when the imported object is used dynamically,
+    /// e.g. `styles[variant]`, because the accessed classes can't be determined.
+    ///
+    /// When the file imports a module several times, the classes are reported on the first import.
+    /// The rule only knows about the file being analyzed: when a CSS module is imported by
+    /// several files, its classes are reported in each file that doesn't use them all.
+    ///
+    /// ## Examples
+    ///
+    /// The following examples import a `button.module.css` file that defines
+    /// the classes `.button` and `.primary-button`.
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,ignore
+    /// import styles from "./button.module.css";
+    ///
+    /// <button className={styles.button} />;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx,ignore
+    /// import styles from "./button.module.css";
+    ///
+    /// <button className={`${styles.button} ${styles.primaryButton}`} />;
+    /// ```
+    ///
+    pub NoUnusedCssModuleClasses {
+        version: "next",
+        name: "noUnusedCssModuleClasses",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnusedCssModuleClasses {
+    type Query = CssModules<JsImport>;
+    type State = String;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let import = ctx.query();
+        let Ok(source) = import.source_text() else {
+            return Vec::new();
+        };
+
+        // The file can import the module several times, e.g. once for its default
+        // export and once for some classes: the classes are reported on the first import
+        let imports: Vec<_> = import
+            .parent::<JsModuleItemList>()
+            .into_iter()
+            .flat_map(|items| items.iter())
+            .filter_map(|item| item.as_js_import().cloned())
+            .filter(|other| {
+                other
+                    .source_text()
+                    .is_ok_and(|other| other.text() == source.text())
+            })
+            .collect();
+        if imports.first() != Some(import) {
+            return Vec::new();
+        }
+
+        let mut module = None;
+        let mut names = Vec::new();
+        for import in &imports {
+            let Some((imported_module, accesses)) = import
+                .import_clause()
+                .ok()
+                .and_then(|clause| ctx.css_module_accesses(&clause))
+            else {
+                return Vec::new();
+            };
+            if accesses.is_dynamic {
+                return Vec::new();
+            }
+            module = Some(imported_module);
+            names.extend(accesses.names.into_iter().map(|(name, _)| name));
+        }
+        let Some(module) = module else {
+            return Vec::new();
+        };
+
+        module
+            .classes()
+            .filter(|class| {
+                !module.is_composed(class)
+                    && !names.iter().any(|name| is_class_accessed_as(class, name))
+            })
+            .map(String::from)
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, class: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let source = node.import_clause().ok()?.source().ok()?;
+        let source_text = source.inner_string_text().ok()?;
+        let source_text = source_text.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                source.range(),
+                markup! {
+                    "The class "<Emphasis>{class}</Emphasis>" of "<Emphasis>{source_text}</Emphasis>" is never used."
+                },
+            )
+            .note(markup! {
+                "Remove the class from the CSS module, or use it in this file."
+            }),
+        )
+    }
+}
@@ -1,3 +1,4 @@
+use crate::globals::JsEnvironment;
 use crate::semantic_services::SemanticServices;
 use biome_analyze::context::RuleContext;
 use biome_analyze::{declare_rule, Rule, RuleDiagnostic, RuleSource};
@@ -45,6 +46,22 @@ declare_rule! {
     /// In the example above, the rule will emit a diagnostics if tried to use `$` or `MooTools` without
     /// creating a local variable.
     ///
+    /// Use `deniedEnvironments` to restrict all the globals of some environments. For example,
+    /// the following options disallow the globals of Node.js, such as `process` or `require`,
+    /// in code that runs in the browsers. The globals that Node.js shares with the browsers,
+    /// such as `console`, are still allowed:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "deniedEnvironments": ["node"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// The available environments are the ones of the `javascript.environments` setting.
+    ///
     pub NoRestrictedGlobals {
         version: "1.0.0",
         name: "noRestrictedGlobals",
@@ -63,6 +80,10 @@ pub struct RestrictedGlobalsOptions {
     /// A list of names that should trigger the rule
     #[serde(skip_serializing_if = "Vec::is_empty")]
     denied_globals: Vec<String>,
+
+    /// A list of environments whose globals should trigger the rule
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    denied_environments: Vec<JsEnvironment>,
 }
 
 impl Rule for NoRestrictedGlobals {
@@ -101,8 +122,13 @@ impl Rule for NoRestrictedGlobals {
                 let text = token.text_trimmed();
                 let denied_globals: Vec<_> =
                     options.denied_globals.iter().map(AsRef::as_ref).collect();
-                is_restricted(text, &binding, denied_globals.as_slice())
-                    .map(|text| (token.text_trimmed_range(), text))
+                is_restricted(
+                    text,
+                    &binding,
+                    denied_globals.as_slice(),
+                    &options.denied_environments,
+                )
+                .map(|text| (token.text_trimmed_range(), text))
             })
             .collect()
     }
@@ -123,10 +149,34 @@ impl Rule for NoRestrictedGlobals {
     }
 }
 
-fn is_restricted(name: &str, binding: &Option<Binding>, denied_globals: &[&str]) -> Option<String> {
-    if binding.is_none() && (RESTRICTED_GLOBALS.contains(&name) || denied_globals.contains(&name)) {
+fn is_restricted(
+    name: &str,
+    binding: &Option<Binding>,
+    denied_globals: &[&str],
+    denied_environments: &[JsEnvironment],
+) -> Option<String> {
+    if binding.is_none()
+        && (RESTRICTED_GLOBALS.contains(&name)
+            || denied_globals.contains(&name)
+            || is_environment_global(name, denied_environments))
+    {
         Some(name.to_string())
     } else {
         None
     }
 }
+
+/// Returns `true` if `name` is a global of one of the `environments`.
+///
+/// A global that is also a global of the browsers or of Node.js, such as `console`
+/// or `fetch`, is only returned when these environments are in `environments` too.
+fn is_environment_global(name: &str, environments: &[JsEnvironment]) -> bool {
+    const RUNTIMES: [JsEnvironment; 2] = [JsEnvironment::Browser, JsEnvironment::Node];
+    environments
+        .iter()
+        .any(|environment| environment.has_global(name))
+        && RUNTIMES
+            .iter()
+            .filter(|runtime| runtime.has_global(name))
+            .all(|runtime| environments.contains(runtime))
+}
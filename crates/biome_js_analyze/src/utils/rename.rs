@@ -212,7 +212,8 @@ pub trait RenameSymbolExtensions {
     }
 }
 
-fn token_with_new_text(token: &JsSyntaxToken, new_text: &str) -> JsSyntaxToken {
+/// Returns a copy of `token` with the text `new_text`, keeping its trivia
+pub fn token_with_new_text(token: &JsSyntaxToken, new_text: &str) -> JsSyntaxToken {
     let new_text = format!(
         "{}{}{}",
         token.leading_trivia().text(),
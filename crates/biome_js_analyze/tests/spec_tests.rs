@@ -6,8 +6,8 @@ use biome_js_syntax::{JsFileSource, JsLanguage, ModuleKind};
 use biome_rowan::AstNode;
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
-    has_bogus_nodes_or_empty_slots, load_manifest, parse_test_path, register_leak_checker,
-    scripts_from_json, write_analyzer_snapshot, CheckActionType,
+    has_bogus_nodes_or_empty_slots, load_css_modules, load_manifest, parse_test_path,
+    register_leak_checker, scripts_from_json, write_analyzer_snapshot, CheckActionType,
 };
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
@@ -106,9 +106,17 @@ pub(crate) fn analyze_and_snap(
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
     let manifest = load_manifest(input_file, &mut diagnostics);
+    let css_modules = load_css_modules(input_file);
 
-    let (_, errors) =
-        biome_js_analyze::analyze(&root, filter, &options, source_type, manifest, |event| {
+    let (_, errors) = biome_js_analyze::analyze(
+        &root,
+        filter,
+        &options,
+        source_type,
+        manifest,
+        css_modules,
+        &[],
+        |event| {
             if let Some(mut diag) = event.diagnostic() {
                 for action in event.actions() {
                     if check_action_type.is_suppression() {
@@ -164,7 +172,8 @@ pub(crate) fn analyze_and_snap(
             }
 
             ControlFlow::<Never>::Continue(())
-        });
+        },
+    );
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
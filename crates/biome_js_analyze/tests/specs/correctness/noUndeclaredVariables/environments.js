@@ -0,0 +1,7 @@
+describe("sum", () => {
+    it("adds numbers", () => {
+        expect(1 + 2).toBe(3);
+    });
+});
+
+vi.fn();
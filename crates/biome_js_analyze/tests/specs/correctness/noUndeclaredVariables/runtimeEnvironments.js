@@ -0,0 +1,5 @@
+const text = await Deno.readTextFile("./README.md");
+const pattern = new URLPattern({ pathname: "/books/:id" });
+
+const server = Bun.serve({ fetch: () => new Response(text) });
+const rewriter = new HTMLRewriter();
@@ -0,0 +1,8 @@
+importScripts("./lib.js");
+
+self.addEventListener("fetch", (event) => {
+    event.respondWith(caches.match(event.request));
+});
+
+Deno.readTextFile("./file.txt");
+Bun.file("./file.txt");
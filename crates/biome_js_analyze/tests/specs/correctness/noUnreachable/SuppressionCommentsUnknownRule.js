@@ -0,0 +1,11 @@
+// biome-ignore lint/correctness/noSuchRule: the rule doesn't exist
+function SuppressionCommentsUnknownRule1() {
+    beforeReturn();
+}
+
+function SuppressionCommentsUnknownRule2() {
+    beforeReturn();
+    return;
+    // biome-ignore lint/correctness/noSuchRule lint/correctness/noUnreachable: still suppresses a rule
+    afterReturn();
+}
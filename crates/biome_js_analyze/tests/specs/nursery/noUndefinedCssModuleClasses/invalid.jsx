@@ -0,0 +1,9 @@
+import styles from "./button.module.css";
+import { secondaryButton } from "./button.module.css";
+
+export const Button = () => (
+	<button className={styles.secondary}>
+		<span className={styles["icon"]} />
+		<span className={styles.disabled} />
+	</button>
+);
@@ -0,0 +1,15 @@
+import styles from "./button.module.css";
+import { button, spacing } from "./button.module.css";
+import * as moduleStyles from "./button.module.css";
+import other from "./other.css";
+
+export const Button = ({ variant }) => (
+	<button
+		className={`${styles.button} ${styles.primaryButton} ${styles["primary-button"]}`}
+		style={{ margin: spacing, animationName: moduleStyles.fade }}
+	>
+		<span className={button} />
+		<span className={styles[variant]} />
+		<span className={other.whatever} />
+	</button>
+);
@@ -0,0 +1,7 @@
+import styles from "./card.module.css";
+
+export const Card = ({ title }) => (
+	<div className={styles.highlighted}>
+		<h2 className={styles.cardTitle}>{title}</h2>
+	</div>
+);
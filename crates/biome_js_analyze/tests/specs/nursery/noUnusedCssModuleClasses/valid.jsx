@@ -0,0 +1,10 @@
+import styles from "./card.module.css";
+import { unused, footer } from "./card.module.css";
+
+export const Card = ({ title }) => (
+	<div className={styles.highlighted}>
+		<h2 className={styles["card-title"]}>{title}</h2>
+		<p className={unused} />
+		<footer className={footer} />
+	</div>
+);
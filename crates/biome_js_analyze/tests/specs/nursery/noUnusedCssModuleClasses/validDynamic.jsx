@@ -0,0 +1,7 @@
+import "./card.module.css";
+import styles from "./card.module.css";
+import * as otherStyles from "./card.module.css";
+
+export const Card = ({ variant }) => <div className={styles[variant]} />;
+
+export const Title = () => <h2 className={getClassName(otherStyles)} />;
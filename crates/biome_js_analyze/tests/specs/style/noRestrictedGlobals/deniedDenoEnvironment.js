@@ -0,0 +1,4 @@
+Deno.exit(0);
+
+localStorage.getItem("key");
+fetch("/api");
@@ -0,0 +1,9 @@
+const env = process.env;
+const fs = require("fs");
+console.log(__dirname);
+
+function f(process) {
+    return process;
+}
+
+window.alert(document.title);
@@ -4,12 +4,12 @@ use biome_diagnostics_categories::category;
 use biome_formatter::comments::is_alignable_comment;
 use biome_formatter::{
     comments::{
-        CommentKind, CommentPlacement, CommentStyle, CommentTextPosition, Comments,
-        DecoratedComment, SourceComment,
+        find_suppression_range_kind, CommentKind, CommentPlacement, CommentStyle,
+        CommentTextPosition, Comments, DecoratedComment, SourceComment, SuppressionRangeKind,
     },
     write,
 };
-use biome_js_syntax::suppression::parse_suppression_comment;
+use biome_js_syntax::suppression::{parse_suppression_comment, SuppressionScope};
 use biome_js_syntax::JsSyntaxKind::JS_EXPORT;
 use biome_js_syntax::{
     AnyJsClass, AnyJsName, AnyJsRoot, AnyJsStatement, JsArrayHole, JsArrowFunctionExpression,
@@ -79,10 +79,24 @@ impl CommentStyle for JsCommentStyle {
     fn is_suppression(text: &str) -> bool {
         parse_suppression_comment(text)
             .filter_map(Result::ok)
+            .filter(|suppression| suppression.scope == SuppressionScope::Line)
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
     }
 
+    fn get_suppression_range_kind(text: &str) -> Option<SuppressionRangeKind> {
+        find_suppression_range_kind(parse_suppression_comment(text).filter_map(|suppression| {
+            let suppression = suppression.ok()?;
+            let kind = match suppression.scope {
+                SuppressionScope::Line => return None,
+                SuppressionScope::RangeStart => SuppressionRangeKind::Start,
+                SuppressionScope::RangeEnd => SuppressionRangeKind::End,
+                SuppressionScope::File => SuppressionRangeKind::File,
+            };
+            Some((kind, suppression.categories))
+        }))
+    }
+
     fn get_comment_kind(comment: &SyntaxTriviaPieceComments<JsLanguage>) -> CommentKind {
         if comment.text().starts_with("/*") {
             if comment.has_newline() {
@@ -0,0 +1,4 @@
+const   a   =   1;
+
+// biome-ignore-all format: generated file
+const   b   =   [1,2,3];
@@ -0,0 +1,8 @@
+const   a   =   1;
+
+// biome-ignore-start format: generated code
+const   b   =   [1,2,3];
+const   c   =   {a:1};
+// biome-ignore-end
+
+const   d   =   2;
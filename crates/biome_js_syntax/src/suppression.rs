@@ -1,4 +1,4 @@
-use biome_diagnostics::{Category, Diagnostic};
+use biome_diagnostics::{category, Category, Diagnostic};
 use biome_rowan::{TextRange, TextSize};
 
 /// Single instance of a suppression comment, with the following syntax:
@@ -25,6 +25,22 @@ pub struct Suppression<'a> {
     pub reason: &'a str,
     /// If the comment is `// rome-ignore`
     pub is_legacy: bool,
+    /// The code this suppression applies to
+    pub scope: SuppressionScope,
+}
+
+/// The code a suppression comment applies to
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionScope {
+    /// `// biome-ignore`: the next line, or the next node for the formatter
+    #[default]
+    Line,
+    /// `// biome-ignore-start`: the code until the matching `// biome-ignore-end`
+    RangeStart,
+    /// `// biome-ignore-end`: closes the last range opened by `// biome-ignore-start`
+    RangeEnd,
+    /// `// biome-ignore-all`: the code until the end of the file
+    File,
 }
 
 pub fn parse_suppression_comment(
@@ -84,6 +100,7 @@ pub fn parse_suppression_comment(
         ];
 
         let mut is_legacy = false;
+        let mut scope = SuppressionScope::Line;
         // it's a biome-ignore comment
         if line.starts_with("biome-ignore") {
             // Checks for `/biome[-_]ignore/i` without a regex, or skip the line
@@ -91,6 +108,18 @@ pub fn parse_suppression_comment(
             for pattern in PATTERN {
                 line = line.strip_prefix(pattern)?;
             }
+
+            for (suffix, suffix_scope) in [
+                ("-start", SuppressionScope::RangeStart),
+                ("-end", SuppressionScope::RangeEnd),
+                ("-all", SuppressionScope::File),
+            ] {
+                if let Some(rest) = line.strip_prefix(suffix) {
+                    line = rest;
+                    scope = suffix_scope;
+                    break;
+                }
+            }
         } else {
             is_legacy = true;
             for pattern in DEPRECATED_PATTERNS {
@@ -102,8 +131,19 @@ pub fn parse_suppression_comment(
         // entirely if it doesn't match
 
         let line = line.trim_start();
+
+        // The end of a range doesn't need to repeat the categories and the reason
+        if scope == SuppressionScope::RangeEnd && line.trim_end().is_empty() {
+            return Some(Ok(Suppression {
+                categories: Vec::new(),
+                reason: "",
+                is_legacy,
+                scope,
+            }));
+        }
+
         Some(
-            parse_suppression_line(line, is_legacy).map_err(|err| SuppressionDiagnostic {
+            parse_suppression_line(line, is_legacy, scope).map_err(|err| SuppressionDiagnostic {
                 message: err.message,
                 // Adjust the position of the diagnostic in the whole comment
                 span: err.span + offset_from(base, line),
@@ -172,6 +212,7 @@ impl biome_console::fmt::Display for SuppressionDiagnosticKind {
 fn parse_suppression_line(
     base: &str,
     is_legacy: bool,
+    scope: SuppressionScope,
 ) -> Result<Suppression, SuppressionDiagnostic> {
     let mut line = base;
     let mut categories = Vec::new();
@@ -187,24 +228,25 @@ fn parse_suppression_line(
 
         let (category, rest) = line.split_at(separator);
         let category = category.trim_end();
-        let category: Option<&'static Category> = if !category.is_empty() {
-            let category = category.parse().map_err(|()| SuppressionDiagnostic {
+
+        // Skip over and match the separator
+        let (separator, rest) = rest.split_at(1);
+
+        let category = if !category.is_empty() {
+            let parsed = parse_category(category, separator != "(");
+            Some(parsed.ok_or_else(|| SuppressionDiagnostic {
                 message: SuppressionDiagnosticKind::ParseCategory(category.into()),
                 span: TextRange::at(offset_from(base, category), TextSize::of(category)),
-            })?;
-            Some(category)
+            })?)
         } else {
             None
         };
 
-        // Skip over and match the separator
-        let (separator, rest) = rest.split_at(1);
-
         match separator {
             // Colon token: stop parsing categories
             ":" => {
                 if let Some(category) = category {
-                    categories.push((category, None));
+                    categories.push(category);
                 }
 
                 line = rest.trim_start();
@@ -212,7 +254,7 @@ fn parse_suppression_line(
             }
             // Paren token: parse a category + value
             "(" => {
-                let category = category.ok_or_else(|| SuppressionDiagnostic {
+                let (category, _) = category.ok_or_else(|| SuppressionDiagnostic {
                     message: SuppressionDiagnosticKind::MissingCategory,
                     span: TextRange::at(
                         offset_from(base, line),
@@ -234,7 +276,7 @@ fn parse_suppression_line(
             // Whitespace: push a category without value
             _ => {
                 if let Some(category) = category {
-                    categories.push((category, None));
+                    categories.push(category);
                 }
 
                 line = rest.trim_start();
@@ -247,9 +289,27 @@ fn parse_suppression_line(
         categories,
         reason,
         is_legacy,
+        scope,
     })
 }
 
+/// Parses the name of a category, along with its value.
+///
+/// When `allow_unknown_rule` is `true`, an unknown `lint/<group>/<rule>` category
+/// is read as `lint(<group>/<rule>)`, so the linter reports the unknown rule
+/// instead of failing to parse the whole comment.
+fn parse_category(
+    category: &str,
+    allow_unknown_rule: bool,
+) -> Option<(&'static Category, Option<&str>)> {
+    if let Ok(category) = category.parse() {
+        return Some((category, None));
+    }
+
+    let rule = category.strip_prefix("lint/")?;
+    (allow_unknown_rule && !rule.is_empty()).then_some((category!("lint"), Some(rule)))
+}
+
 /// Returns the byte offset of `substr` within `base`
 ///
 /// # Safety
@@ -282,7 +342,7 @@ mod tests_rome_ignore {
 
     use crate::suppression::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionScope};
 
     #[test]
     fn parse_simple_suppression() {
@@ -291,7 +351,8 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -300,7 +361,8 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -314,7 +376,8 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -329,7 +392,8 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -340,7 +404,8 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -349,7 +414,8 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -358,7 +424,8 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -374,7 +441,8 @@ mod tests_rome_ignore {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -387,7 +455,8 @@ mod tests_rome_ignore {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -404,7 +473,8 @@ mod tests_rome_ignore {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -422,7 +492,8 @@ mod tests_rome_ignore {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -435,7 +506,8 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -505,7 +577,7 @@ mod tests_biome_ignore {
 
     use crate::suppression::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionScope};
 
     #[test]
     fn parse_simple_suppression() {
@@ -514,7 +586,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -524,7 +597,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -538,7 +612,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -553,7 +628,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -564,7 +640,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -573,7 +650,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -582,7 +660,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -598,7 +677,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -611,7 +691,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -628,7 +709,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -646,7 +728,43 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
+            })],
+        );
+    }
+
+    #[test]
+    fn parse_range_suppressions() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-start lint/style/useConst: generated")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint/style/useConst"), None)],
+                reason: "generated",
+                is_legacy: false,
+                scope: SuppressionScope::RangeStart
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-end").collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![],
+                reason: "",
+                is_legacy: false,
+                scope: SuppressionScope::RangeEnd
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("/* biome-ignore-all format: generated */")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("format"), None)],
+                reason: "generated",
+                is_legacy: false,
+                scope: SuppressionScope::File
             })],
         );
     }
@@ -659,7 +777,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -720,4 +839,18 @@ mod tests_biome_ignore {
             })],
         );
     }
+
+    #[test]
+    fn parse_unknown_lint_rule() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore lint/suspicious/noSuchRule: explanation")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint"), Some("suspicious/noSuchRule"))],
+                reason: "explanation",
+                is_legacy: false,
+                scope: SuppressionScope::Line
+            })],
+        );
+    }
 }
@@ -2,13 +2,27 @@
 
 use biome_analyze::declare_group;
 
+pub mod no_duplicate_dependencies;
 pub mod no_duplicate_json_keys;
+pub mod no_invalid_package_exports;
+pub mod no_invalid_version_range;
+pub mod no_json_schema_violations;
+pub mod no_unknown_license;
+pub mod no_unused_dependencies;
+pub mod use_sorted_dependencies;
 
 declare_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_duplicate_dependencies :: NoDuplicateDependencies ,
             self :: no_duplicate_json_keys :: NoDuplicateJsonKeys ,
+            self :: no_invalid_package_exports :: NoInvalidPackageExports ,
+            self :: no_invalid_version_range :: NoInvalidVersionRange ,
+            self :: no_json_schema_violations :: NoJsonSchemaViolations ,
+            self :: no_unknown_license :: NoUnknownLicense ,
+            self :: no_unused_dependencies :: NoUnusedDependencies ,
+            self :: use_sorted_dependencies :: UseSortedDependencies ,
         ]
      }
 }
@@ -0,0 +1,159 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{JsonObjectValue, JsonRoot, TextRange};
+use biome_rowan::{AstNode, AstSeparatedList};
+use rustc_hash::FxHashMap;
+
+use crate::utils::{is_package_json, DEPENDENCY_KINDS};
+
+declare_rule! {
+    /// Disallow a dependency declared in several kinds of dependencies of `package.json`.
+    ///
+    /// A package listed in both `dependencies` and `optionalDependencies`, for example,
+    /// is installed with one of the two versions depending on the package manager.
+    ///
+    /// A package can be declared in both `peerDependencies` and `devDependencies`, because
+    /// the peer dependencies of a package are usually installed for its development.
+    ///
+    /// This rule only applies to `package.json` files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "dependencies": {
+    ///     "foo": "^1.0.0"
+    ///   },
+    ///   "devDependencies": {
+    ///     "foo": "^1.0.0"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///   "devDependencies": {
+    ///     "foo": "^1.0.0"
+    ///   },
+    ///   "peerDependencies": {
+    ///     "foo": "^1.0.0"
+    ///   }
+    /// }
+    /// ```
+    pub NoDuplicateDependencies {
+        version: "next",
+        name: "noDuplicateDependencies",
+        recommended: false,
+    }
+}
+
+pub struct DuplicateDependency {
+    name: String,
+    /// The first declaration of the dependency, and its kind
+    first: (TextRange, &'static str),
+    /// The duplicated declaration, and its kind
+    duplicate: (TextRange, &'static str),
+}
+
+impl Rule for NoDuplicateDependencies {
+    type Query = Ast<JsonObjectValue>;
+    type State = DuplicateDependency;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let object = ctx.query();
+        let is_root = object
+            .syntax()
+            .parent()
+            .is_some_and(|parent| JsonRoot::can_cast(parent.kind()));
+        if !is_root || !is_package_json(ctx.file_path()) {
+            return Vec::new();
+        }
+
+        let mut declarations = FxHashMap::<String, (TextRange, &'static str)>::default();
+        let mut duplicates = Vec::new();
+        for member in object.json_member_list().iter().flatten() {
+            let Some(kind) = member
+                .name()
+                .and_then(|name| name.inner_string_text())
+                .ok()
+                .and_then(|name| {
+                    DEPENDENCY_KINDS
+                        .iter()
+                        .find(|kind| **kind == name.text())
+                        .copied()
+                })
+            else {
+                continue;
+            };
+            let Some(dependencies) = member
+                .value()
+                .ok()
+                .and_then(|value| value.as_json_object_value().cloned())
+            else {
+                continue;
+            };
+            for dependency in dependencies.json_member_list().iter().flatten() {
+                let Ok(name) = dependency.name() else {
+                    continue;
+                };
+                let Ok(text) = name.inner_string_text() else {
+                    continue;
+                };
+                match declarations.get(text.text()) {
+                    Some(first) if !is_peer_and_dev_dependency(first.1, kind) => {
+                        duplicates.push(DuplicateDependency {
+                            name: text.text().to_string(),
+                            first: *first,
+                            duplicate: (name.range(), kind),
+                        });
+                    }
+                    Some(_) => {}
+                    None => {
+                        declarations.insert(text.text().to_string(), (name.range(), kind));
+                    }
+                }
+            }
+        }
+
+        duplicates
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DuplicateDependency {
+            name,
+            first,
+            duplicate,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                duplicate.0,
+                markup! {
+                    "The dependency "<Emphasis>{name}</Emphasis>" is declared in both "<Emphasis>{first.1}</Emphasis>" and "<Emphasis>{duplicate.1}</Emphasis>"."
+                },
+            )
+            .detail(
+                first.0,
+                markup! {
+                    "It was first declared here."
+                },
+            )
+            .note(markup! {
+                "Package managers resolve a dependency to a single version. Remove one of the declarations."
+            }),
+        )
+    }
+}
+
+fn is_peer_and_dev_dependency(first: &str, second: &str) -> bool {
+    matches!(
+        (first, second),
+        ("devDependencies", "peerDependencies") | ("peerDependencies", "devDependencies")
+    )
+}
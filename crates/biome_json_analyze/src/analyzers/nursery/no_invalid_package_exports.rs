@@ -0,0 +1,222 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{AnyJsonValue, JsonMember, TextRange};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+use crate::utils::{is_package_json, is_top_level_member};
+
+declare_rule! {
+    /// Disallow invalid `exports` in `package.json`.
+    ///
+    /// The `exports` field declares the [entry points](https://nodejs.org/api/packages.html#package-entry-points)
+    /// of a package. Node.js and the bundlers refuse to resolve an invalid entry point:
+    /// - a target must be a path that starts with `./`, or `null`;
+    /// - the subpaths must be `.` or start with `./`, and can't be mixed with conditions in the same object;
+    /// - the conditions can't contain subpaths;
+    /// - the `default` condition must be the last one, because the conditions are matched in order.
+    ///
+    /// This rule only applies to `package.json` files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "exports": {
+    ///     ".": "index.js",
+    ///     "import": "./index.mjs"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "exports": {
+    ///     "default": "./index.js",
+    ///     "import": "./index.mjs"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///   "exports": {
+    ///     ".": {
+    ///       "import": "./index.mjs",
+    ///       "default": "./index.js"
+    ///     },
+    ///     "./internal/*": null
+    ///   }
+    /// }
+    /// ```
+    pub NoInvalidPackageExports {
+        version: "next",
+        name: "noInvalidPackageExports",
+        recommended: false,
+    }
+}
+
+pub struct InvalidExport {
+    range: TextRange,
+    kind: InvalidExportKind,
+}
+
+pub enum InvalidExportKind {
+    /// A number or a boolean
+    InvalidType,
+    /// A path that doesn't start with `./`
+    InvalidTarget,
+    /// A condition next to subpaths, or a subpath next to conditions
+    MixedKeys,
+    /// A subpath inside conditions
+    SubpathInConditions,
+    /// A subpath that doesn't start with `./`
+    InvalidSubpath,
+    /// A `default` condition followed by other conditions
+    DefaultNotLast,
+}
+
+impl Rule for NoInvalidPackageExports {
+    type Query = Ast<JsonMember>;
+    type State = InvalidExport;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let member = ctx.query();
+        let mut invalid_exports = Vec::new();
+        if !is_package_json(ctx.file_path()) || !is_top_level_member(member) {
+            return invalid_exports;
+        }
+        let is_exports = member
+            .name()
+            .and_then(|name| name.inner_string_text())
+            .is_ok_and(|name| name.text() == "exports");
+        if let (true, Ok(value)) = (is_exports, member.value()) {
+            check_exports(&value, true, &mut invalid_exports);
+        }
+        invalid_exports
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state.kind {
+            InvalidExportKind::InvalidType => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "An export must be a path, an object, an array or "<Emphasis>"null"</Emphasis>"."
+                },
+            ),
+            InvalidExportKind::InvalidTarget => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The path of an export must start with "<Emphasis>"./"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The paths are relative to the root of the package, and can't point outside of it."
+            }),
+            InvalidExportKind::MixedKeys => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The subpaths of the package can't be mixed with conditions."
+                },
+            )
+            .note(markup! {
+                "Move the conditions inside the subpath "<Emphasis>"\".\""</Emphasis>"."
+            }),
+            InvalidExportKind::SubpathInConditions => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "A subpath can't be declared inside conditions."
+                },
+            )
+            .note(markup! {
+                "The subpaths must be the keys of the top-level object of "<Emphasis>"exports"</Emphasis>"."
+            }),
+            InvalidExportKind::InvalidSubpath => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "A subpath must be "<Emphasis>"\".\""</Emphasis>" or start with "<Emphasis>"./"</Emphasis>"."
+                },
+            ),
+            InvalidExportKind::DefaultNotLast => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The "<Emphasis>"default"</Emphasis>" condition must be the last condition."
+                },
+            )
+            .note(markup! {
+                "The conditions are matched in order, so the conditions that follow "<Emphasis>"default"</Emphasis>" are never used."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Collects the invalid exports of `value`.
+/// `is_root` is `true` for the value of the `exports` field, whose keys can be subpaths.
+fn check_exports(value: &AnyJsonValue, is_root: bool, invalid_exports: &mut Vec<InvalidExport>) {
+    let mut push = |range, kind| invalid_exports.push(InvalidExport { range, kind });
+    match value {
+        AnyJsonValue::JsonStringValue(target) => {
+            if target
+                .inner_string_text()
+                .is_ok_and(|text| !text.text().starts_with("./"))
+            {
+                push(target.range(), InvalidExportKind::InvalidTarget);
+            }
+        }
+        AnyJsonValue::JsonBooleanValue(_) | AnyJsonValue::JsonNumberValue(_) => {
+            push(value.range(), InvalidExportKind::InvalidType);
+        }
+        AnyJsonValue::JsonArrayValue(fallbacks) => {
+            for fallback in fallbacks.elements().iter().flatten() {
+                check_exports(&fallback, false, invalid_exports);
+            }
+        }
+        AnyJsonValue::JsonObjectValue(object) => {
+            let members: Vec<_> = object
+                .json_member_list()
+                .iter()
+                .filter_map(|member| {
+                    let member = member.ok()?;
+                    let name = member.name().ok()?;
+                    let text = name.inner_string_text().ok()?;
+                    Some((name, text, member.value().ok()))
+                })
+                .collect();
+            // The keys of `exports` are either all subpaths, or all conditions
+            let has_subpaths = members
+                .first()
+                .is_some_and(|(_, text, _)| text.text().starts_with('.'));
+            let last = members.len().saturating_sub(1);
+            for (index, (name, text, _)) in members.iter().enumerate() {
+                let is_subpath = text.text().starts_with('.');
+                if is_root && is_subpath != has_subpaths {
+                    push(name.range(), InvalidExportKind::MixedKeys);
+                } else if is_subpath {
+                    if !is_root {
+                        push(name.range(), InvalidExportKind::SubpathInConditions);
+                    } else if text.text() != "." && !text.text().starts_with("./") {
+                        push(name.range(), InvalidExportKind::InvalidSubpath);
+                    }
+                } else if text.text() == "default" && index != last {
+                    push(name.range(), InvalidExportKind::DefaultNotLast);
+                }
+            }
+            for value in members.iter().filter_map(|(_, _, value)| value.as_ref()) {
+                check_exports(value, false, invalid_exports);
+            }
+        }
+        AnyJsonValue::JsonNullValue(_) | AnyJsonValue::JsonBogusValue(_) => {}
+    }
+}
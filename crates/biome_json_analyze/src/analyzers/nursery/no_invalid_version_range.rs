@@ -0,0 +1,114 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{JsonMember, JsonStringValue};
+use biome_project::{is_valid_dependency_version, Version};
+use biome_rowan::AstNode;
+
+use crate::utils::{is_package_json, is_top_level_member, parent_member, DEPENDENCY_KINDS};
+
+declare_rule! {
+    /// Disallow invalid versions and version ranges in `package.json`.
+    ///
+    /// The `version` of the package must be a valid [semantic version](https://semver.org/),
+    /// and the versions of the dependencies and of the `engines` must be valid
+    /// [semver ranges](https://docs.npmjs.com/cli/v10/configuring-npm/package-json#dependencies).
+    ///
+    /// Tags, paths, URLs and protocols such as `workspace:` aren't checked.
+    ///
+    /// This rule only applies to `package.json` files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "version": "one",
+    ///   "dependencies": {
+    ///     "foo": "^latest"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///   "version": "1.0.0",
+    ///   "dependencies": {
+    ///     "foo": "^1.2.0",
+    ///     "bar": "latest",
+    ///     "baz": "workspace:*"
+    ///   }
+    /// }
+    /// ```
+    pub NoInvalidVersionRange {
+        version: "next",
+        name: "noInvalidVersionRange",
+        recommended: false,
+    }
+}
+
+pub enum InvalidVersion {
+    /// The version of the package
+    Version(JsonStringValue),
+    /// The version range of a dependency or an engine
+    Range(JsonStringValue),
+}
+
+impl Rule for NoInvalidVersionRange {
+    type Query = Ast<JsonMember>;
+    type State = InvalidVersion;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return None;
+        }
+        let member = ctx.query();
+        let value = member.value().ok()?;
+        let value = value.as_json_string_value()?;
+        let text = value.inner_string_text().ok()?;
+
+        if is_top_level_member(member) {
+            let name = member.name().ok()?.inner_string_text().ok()?;
+            return (name.text() == "version"
+                && matches!(Version::new(text.text()), Version::Literal(_)))
+            .then(|| InvalidVersion::Version(value.clone()));
+        }
+
+        let parent = parent_member(member)?;
+        let parent_name = parent.name().ok()?.inner_string_text().ok()?;
+        let has_ranges =
+            DEPENDENCY_KINDS.contains(&parent_name.text()) || parent_name.text() == "engines";
+        (has_ranges && is_top_level_member(&parent) && !is_valid_dependency_version(text.text()))
+            .then(|| InvalidVersion::Range(value.clone()))
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidVersion::Version(value) => RuleDiagnostic::new(
+                rule_category!(),
+                value.range(),
+                markup! {
+                    "The version "<Emphasis>{value.inner_string_text().ok()?.text()}</Emphasis>" isn't a valid semantic version."
+                },
+            )
+            .note(markup! {
+                "A version is made of three numbers, optionally followed by a pre-release and build metadata, e.g. "<Emphasis>"1.2.3-beta.1"</Emphasis>"."
+            }),
+            InvalidVersion::Range(value) => RuleDiagnostic::new(
+                rule_category!(),
+                value.range(),
+                markup! {
+                    "The version range "<Emphasis>{value.inner_string_text().ok()?.text()}</Emphasis>" is invalid."
+                },
+            )
+            .note(markup! {
+                "Package managers can't resolve this range. Use a valid semver range, e.g. "<Emphasis>"^1.2.3"</Emphasis>"."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
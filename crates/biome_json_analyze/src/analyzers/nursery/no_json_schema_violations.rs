@@ -0,0 +1,66 @@
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::JsonRoot;
+
+use crate::schema::SchemaViolation;
+use crate::schema_services::Schemas;
+
+declare_rule! {
+    /// Disallow the values that don't match the JSON schemas of the file.
+    ///
+    /// The schemas are associated to the files with the `json.schemas` configuration.
+    /// The keywords of `draft-07` and `2020-12` that constrain the shape of the values are
+    /// supported, and each diagnostic points to the member or the value that doesn't match.
+    ///
+    /// This rule only applies to the files matched by a schema.
+    ///
+    /// ## Examples
+    ///
+    /// With a schema that requires `port` to be an integer:
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "port": "8080"
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "port": 8080
+    /// }
+    /// ```
+    pub NoJsonSchemaViolations {
+        version: "next",
+        name: "noJsonSchemaViolations",
+        recommended: false,
+    }
+}
+
+impl Rule for NoJsonSchemaViolations {
+    type Query = Schemas<JsonRoot>;
+    type State = SchemaViolation;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        ctx.schemas()
+            .iter()
+            .flat_map(|schema| schema.validate(root))
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, violation: &Self::State) -> Option<RuleDiagnostic> {
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            violation.range(),
+            markup! {
+                {violation.message()}
+            },
+        ))
+    }
+}
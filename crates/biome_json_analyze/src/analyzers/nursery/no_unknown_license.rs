@@ -0,0 +1,87 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{JsonMember, TextRange};
+use biome_project::LICENSE_LIST;
+use biome_rowan::AstNode;
+
+use crate::utils::{is_package_json, is_top_level_member};
+
+declare_rule! {
+    /// Disallow licenses that aren't SPDX license identifiers in `package.json`.
+    ///
+    /// The `license` field must be a valid [SPDX license expression](https://spdx.github.io/spdx-spec/v2.3/SPDX-license-expressions/),
+    /// made of identifiers of the [SPDX license list](https://spdx.org/licenses/) combined with `AND`, `OR` and `WITH`.
+    /// Custom licenses can be referenced with `LicenseRef-<name>`, `SEE LICENSE IN <file>` or `UNLICENSED`.
+    ///
+    /// This rule only applies to `package.json` files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "license": "MIT License"
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///   "license": "(MIT OR Apache-2.0)"
+    /// }
+    /// ```
+    pub NoUnknownLicense {
+        version: "next",
+        name: "noUnknownLicense",
+        recommended: false,
+    }
+}
+
+pub struct UnknownLicense {
+    range: TextRange,
+    license: String,
+}
+
+impl Rule for NoUnknownLicense {
+    type Query = Ast<JsonMember>;
+    type State = UnknownLicense;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let member = ctx.query();
+        if !is_package_json(ctx.file_path()) || !is_top_level_member(member) {
+            return None;
+        }
+        let name = member.name().ok()?.inner_string_text().ok()?;
+        if name.text() != "license" {
+            return None;
+        }
+        let value = member.value().ok()?;
+        let value = value.as_json_string_value()?;
+        let expression = value.inner_string_text().ok()?;
+        let license = LICENSE_LIST.find_unknown_license(expression.text())?;
+
+        Some(UnknownLicense {
+            range: value.range(),
+            license: license.to_string(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The license "<Emphasis>{state.license}</Emphasis>" isn't a known SPDX license identifier."
+                },
+            )
+            .note(markup! {
+                "Use an identifier of the "<Hyperlink href="https://spdx.org/licenses/">"SPDX license list"</Hyperlink>", or "<Emphasis>"SEE LICENSE IN <file>"</Emphasis>" for a custom license."
+            }),
+        )
+    }
+}
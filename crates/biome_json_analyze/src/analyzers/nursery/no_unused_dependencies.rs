@@ -0,0 +1,146 @@
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_json_syntax::{JsonMember, JsonMemberName};
+use biome_rowan::{AstNode, AstSeparatedList};
+use serde::{Deserialize, Serialize};
+
+use crate::project_services::Project;
+use crate::utils::{is_package_json, is_top_level_member};
+
+declare_rule! {
+    /// Disallow dependencies of `package.json` that the package never imports.
+    ///
+    /// The rule collects the packages imported by the JavaScript and TypeScript files of the package,
+    /// with `import`, `export ... from`, `require()` or `import()`, and reports the entries of
+    /// `dependencies` that none of them imports.
+    /// The directories `node_modules`, the hidden directories and the directories of nested packages
+    /// aren't part of the package, nor the files ignored by the configuration or by the VCS.
+    ///
+    /// A type package, such as `@types/react` or `@types/babel__core`, is used when the package
+    /// it describes is imported.
+    ///
+    /// This rule only applies to `package.json` files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "dependencies": {
+    ///     "left-pad": "^1.3.0"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The dependencies that are used without being imported, such as the CLI tools invoked in `scripts`,
+    /// can be ignored with the `ignore` option. A name that ends with `/*` ignores all the packages of a scope.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "ignore": ["typescript", "@types/*"]
+    ///     }
+    /// }
+    /// ```
+    pub NoUnusedDependencies {
+        version: "next",
+        name: "noUnusedDependencies",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noUnusedDependencies`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UnusedDependenciesOptions {
+    /// The dependencies that are never reported. A name that ends with `/*` matches all the
+    /// packages of a scope, e.g. `@types/*`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+}
+
+impl UnusedDependenciesOptions {
+    fn is_ignored(&self, name: &str) -> bool {
+        self.ignore
+            .iter()
+            .any(|pattern| match pattern.strip_suffix("/*") {
+                Some(scope) => name
+                    .strip_prefix(scope)
+                    .is_some_and(|rest| rest.starts_with('/')),
+                None => pattern == name,
+            })
+    }
+}
+
+impl Rule for NoUnusedDependencies {
+    type Query = Project<JsonMember>;
+    type State = JsonMemberName;
+    type Signals = Vec<Self::State>;
+    type Options = UnusedDependenciesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let member = ctx.query();
+        let is_dependencies = is_package_json(ctx.file_path())
+            && is_top_level_member(member)
+            && member
+                .name()
+                .and_then(|name| name.inner_string_text())
+                .is_ok_and(|name| name.text() == "dependencies");
+        if !is_dependencies {
+            return Vec::new();
+        }
+        let Some(dependencies) = member
+            .value()
+            .ok()
+            .and_then(|value| value.as_json_object_value().cloned())
+        else {
+            return Vec::new();
+        };
+
+        let options = ctx.options();
+        dependencies
+            .json_member_list()
+            .iter()
+            .filter_map(|dependency| {
+                let name = dependency.ok()?.name().ok()?;
+                let text = name.inner_string_text().ok()?;
+                let text = text.text();
+                let is_used = ctx.is_imported(text)
+                    || described_package(text).is_some_and(|package| ctx.is_imported(&package))
+                    || options.is_ignored(text);
+                (!is_used).then_some(name)
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "The dependency "<Emphasis>{name.inner_string_text().ok()?.text()}</Emphasis>" is never imported by the package."
+                },
+            )
+            .note(markup! {
+                "Remove it from "<Emphasis>"dependencies"</Emphasis>", or add it to the "<Emphasis>"ignore"</Emphasis>" option if it's used without being imported."
+            }),
+        )
+    }
+}
+
+/// Returns the package described by the type package `name`,
+/// e.g. `react` for `@types/react` and `@babel/core` for `@types/babel__core`
+fn described_package(name: &str) -> Option<String> {
+    let package = name.strip_prefix("@types/")?;
+    Some(match package.split_once("__") {
+        Some((scope, name)) => format!("@{scope}/{name}"),
+        None => package.to_string(),
+    })
+}
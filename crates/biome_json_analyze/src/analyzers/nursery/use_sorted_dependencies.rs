@@ -0,0 +1,115 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{JsonMember, JsonMemberName};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+use crate::utils::{is_package_json, is_top_level_member, DEPENDENCY_KINDS};
+
+declare_rule! {
+    /// Enforce the alphabetical order of the dependencies in `package.json`.
+    ///
+    /// Package managers sort the dependencies when they add a new one.
+    /// Keeping them sorted avoids unrelated changes in the diffs of `package.json`,
+    /// and makes finding a dependency easier.
+    ///
+    /// The assist `sortKeys` can sort them.
+    ///
+    /// This rule only applies to `package.json` files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "dependencies": {
+    ///     "foo": "^1.0.0",
+    ///     "bar": "^1.0.0"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///   "dependencies": {
+    ///     "bar": "^1.0.0",
+    ///     "foo": "^1.0.0"
+    ///   }
+    /// }
+    /// ```
+    pub UseSortedDependencies {
+        version: "next",
+        name: "useSortedDependencies",
+        recommended: false,
+    }
+}
+
+pub struct UnsortedDependency {
+    /// The first dependency that isn't at its place
+    dependency: JsonMemberName,
+    /// The dependency before which it should be placed
+    next_dependency: JsonMemberName,
+}
+
+impl Rule for UseSortedDependencies {
+    type Query = Ast<JsonMember>;
+    type State = UnsortedDependency;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let member = ctx.query();
+        if !is_package_json(ctx.file_path()) || !is_top_level_member(member) {
+            return None;
+        }
+        let name = member.name().ok()?.inner_string_text().ok()?;
+        if !DEPENDENCY_KINDS.contains(&name.text()) {
+            return None;
+        }
+        let value = member.value().ok()?;
+        let dependencies = value.as_json_object_value()?;
+
+        let names = dependencies
+            .json_member_list()
+            .iter()
+            .map(|member| {
+                let name = member.ok()?.name().ok()?;
+                let text = name.inner_string_text().ok()?;
+                Some((name, text))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let (dependency, text) = &names
+            .windows(2)
+            .find(|pair| pair[0].1.text() > pair[1].1.text())?[1];
+        // The misplaced dependency should be placed before the first dependency that follows it
+        let (next_dependency, _) = names
+            .iter()
+            .find(|(_, next_text)| next_text.text() > text.text())?;
+
+        Some(UnsortedDependency {
+            dependency: dependency.clone(),
+            next_dependency: next_dependency.clone(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnsortedDependency {
+            dependency,
+            next_dependency,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                dependency.range(),
+                markup! {
+                    "The dependency "<Emphasis>{dependency.inner_string_text().ok()?.text()}</Emphasis>" should be placed before "<Emphasis>{next_dependency.inner_string_text().ok()?.text()}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The dependencies should be sorted alphabetically."
+            }),
+        )
+    }
+}
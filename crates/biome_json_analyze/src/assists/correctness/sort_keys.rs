@@ -0,0 +1,307 @@
+use std::borrow::Cow;
+
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, SourceActionKind,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_json_factory::make;
+use biome_json_syntax::{
+    JsonLanguage, JsonMember, JsonMemberList, JsonObjectValue, JsonRoot, JsonSyntaxToken, TextSize,
+    T,
+};
+use biome_rowan::{AstNode, AstNodeExt, AstSeparatedList, BatchMutationExt, SyntaxTriviaPiece};
+
+use crate::utils::{is_package_json, is_top_level_member};
+use crate::JsonRuleAction;
+
+declare_rule! {
+    /// Provides a whole-source code action to sort the keys of the objects in the file.
+    ///
+    /// The keys are sorted alphabetically, unless a list of keys is given with the `keysOrder`
+    /// option: the keys of the list come first, in the same order, followed by the other keys
+    /// sorted alphabetically.
+    ///
+    /// When no list is given, the top-level keys of `package.json` files follow the conventional
+    /// order of npm (`name`, `version`, `description`, ..., `scripts`, `dependencies`, ...),
+    /// and only the dependency objects are sorted alphabetically.
+    ///
+    /// The comments attached to a member are moved with it.
+    ///
+    /// ## Examples
+    ///
+    /// ```jsonc
+    /// {
+    ///     "zoo": "Zoo",
+    ///     // The animals
+    ///     "animals": "Animals"
+    /// }
+    /// ```
+    pub SortKeys {
+        version: "next",
+        name: "sortKeys",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for SortKeys {
+    type Query = Ast<JsonRoot>;
+    /// The start of the objects to sort, in reverse order of the source
+    type State = Vec<TextSize>;
+    type Signals = Option<Self::State>;
+    type Options = SortKeysOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let root = ctx.query();
+        let options = ctx.options();
+        let is_package_json = is_package_json(ctx.file_path());
+
+        let mut objects: Vec<_> = root
+            .syntax()
+            .descendants()
+            .filter_map(JsonObjectValue::cast)
+            .filter(|object| {
+                options
+                    .order_of(object, is_package_json)
+                    .and_then(|order| SortedMembers::new(&object.json_member_list(), &order))
+                    .is_some()
+            })
+            .map(|object| object.syntax().text_trimmed_range().start())
+            .collect();
+        // Sorting an object doesn't move the objects that start before it
+        objects.reverse();
+
+        (!objects.is_empty()).then_some(objects)
+    }
+
+    fn action(ctx: &RuleContext<Self>, objects: &Self::State) -> Option<JsonRuleAction> {
+        let root = ctx.query();
+        let options = ctx.options();
+        let is_package_json = is_package_json(ctx.file_path());
+
+        let mut sorted_root = root.clone();
+        for start in objects {
+            let object = sorted_root
+                .syntax()
+                .descendants()
+                .filter_map(JsonObjectValue::cast)
+                .find(|object| object.syntax().text_trimmed_range().start() == *start)?;
+            let order = options.order_of(&object, is_package_json)?;
+            let list = object.json_member_list();
+            let sorted_list = SortedMembers::new(&list, &order)?.build()?;
+            sorted_root = sorted_root.replace_node_discard_trivia(list, sorted_list)?;
+        }
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(root.value().ok()?, sorted_root.value().ok()?);
+
+        Some(JsonRuleAction {
+            category: ActionCategory::Source(SourceActionKind::Other(Cow::Borrowed("sortKeys"))),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Sort Keys (Biome)" }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Options for the rule `sortKeys`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SortKeysOptions {
+    /// The keys placed first, in order. The other keys are sorted alphabetically.
+    pub keys_order: Vec<String>,
+}
+
+impl SortKeysOptions {
+    /// Returns the order of the keys of `object`, or `None` if they shouldn't be sorted
+    fn order_of<'a>(
+        &'a self,
+        object: &JsonObjectValue,
+        is_package_json: bool,
+    ) -> Option<KeysOrder<'a>> {
+        if !self.keys_order.is_empty() {
+            return Some(KeysOrder::Custom(&self.keys_order));
+        }
+        if !is_package_json {
+            return Some(KeysOrder::Alphabetical);
+        }
+
+        let parent = object.syntax().parent()?;
+        if JsonRoot::can_cast(parent.kind()) {
+            return Some(KeysOrder::PackageJson);
+        }
+        // Only the dependency objects of the top-level object are sorted
+        let member = JsonMember::cast(parent)?;
+        let name = member.name().ok()?.inner_string_text().ok()?;
+        (is_top_level_member(&member) && PACKAGE_JSON_DEPENDENCIES.contains(&name.text()))
+            .then_some(KeysOrder::Alphabetical)
+    }
+}
+
+enum KeysOrder<'a> {
+    Alphabetical,
+    Custom(&'a [String]),
+    PackageJson,
+}
+
+impl KeysOrder<'_> {
+    fn sort_key<'a>(&self, name: &'a str) -> (usize, &'a str) {
+        let position = match self {
+            Self::Alphabetical => None,
+            Self::Custom(keys) => keys.iter().position(|key| key == name),
+            Self::PackageJson => PACKAGE_JSON_ORDER.iter().position(|key| *key == name),
+        };
+        (position.unwrap_or(usize::MAX), name)
+    }
+}
+
+/// A member of an object, with the trivia that follows it on the same line
+struct Entry {
+    member: JsonMember,
+    trailing_trivia: Vec<SyntaxTriviaPiece<JsonLanguage>>,
+}
+
+/// The members of a list, and the order in which they should appear
+struct SortedMembers {
+    entries: Vec<Entry>,
+    order: Vec<usize>,
+    has_trailing_separator: bool,
+}
+
+impl SortedMembers {
+    /// Returns `None` if the members are already sorted, or if they can't be moved safely
+    fn new(list: &JsonMemberList, order: &KeysOrder) -> Option<Self> {
+        let mut entries = Vec::with_capacity(list.len());
+        let mut names = Vec::with_capacity(list.len());
+        for element in list.elements() {
+            let member = element.node().ok()?.clone();
+            names.push(member.name().ok()?.inner_string_text().ok()?);
+            let entry = match element.trailing_separator().ok()? {
+                Some(separator) => {
+                    // A comment placed before the comma can't be moved with the member
+                    if separator.leading_trivia().pieces().next().is_some() {
+                        return None;
+                    }
+                    Entry {
+                        member,
+                        trailing_trivia: separator.trailing_trivia().pieces().collect(),
+                    }
+                }
+                None => {
+                    let trailing_trivia = member.syntax().last_trailing_trivia()?;
+                    Entry {
+                        member: member.with_trailing_trivia_pieces([])?,
+                        trailing_trivia: trailing_trivia.pieces().collect(),
+                    }
+                }
+            };
+            entries.push(entry);
+        }
+
+        let mut sorted: Vec<_> = (0..entries.len()).collect();
+        sorted.sort_by_cached_key(|index| order.sort_key(names[*index].text()));
+        if sorted
+            .iter()
+            .enumerate()
+            .all(|(index, sorted)| index == *sorted)
+        {
+            return None;
+        }
+
+        Some(Self {
+            entries,
+            order: sorted,
+            has_trailing_separator: list.trailing_separator().is_some(),
+        })
+    }
+
+    fn build(self) -> Option<JsonMemberList> {
+        let last = self.entries.len() - 1;
+        let mut members = Vec::with_capacity(self.entries.len());
+        let mut separators = Vec::with_capacity(self.entries.len());
+        for (index, position) in self.order.into_iter().enumerate() {
+            let Entry {
+                member,
+                trailing_trivia,
+            } = &self.entries[position];
+            if index != last || self.has_trailing_separator {
+                members.push(member.clone());
+                separators.push(
+                    JsonSyntaxToken::new_detached(T![,], ",", [], [])
+                        .with_trailing_trivia_pieces(trailing_trivia.iter().cloned()),
+                );
+            } else {
+                members.push(
+                    member
+                        .clone()
+                        .append_trivia_pieces(trailing_trivia.iter().cloned())?,
+                );
+            }
+        }
+
+        Some(make::json_member_list(members, separators))
+    }
+}
+
+/// The top-level objects of `package.json` whose keys are sorted alphabetically
+const PACKAGE_JSON_DEPENDENCIES: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "optionalDependencies",
+    "overrides",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "resolutions",
+];
+
+/// The conventional order of the top-level keys of `package.json`
+const PACKAGE_JSON_ORDER: &[&str] = &[
+    "$schema",
+    "name",
+    "displayName",
+    "version",
+    "private",
+    "description",
+    "categories",
+    "keywords",
+    "homepage",
+    "bugs",
+    "repository",
+    "funding",
+    "license",
+    "author",
+    "maintainers",
+    "contributors",
+    "publisher",
+    "sideEffects",
+    "type",
+    "imports",
+    "exports",
+    "main",
+    "module",
+    "browser",
+    "types",
+    "typesVersions",
+    "typings",
+    "bin",
+    "man",
+    "directories",
+    "files",
+    "workspaces",
+    "scripts",
+    "config",
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "optionalDependencies",
+    "bundleDependencies",
+    "bundledDependencies",
+    "overrides",
+    "resolutions",
+    "packageManager",
+    "engines",
+    "os",
+    "cpu",
+    "publishConfig",
+];
@@ -1,14 +1,25 @@
 mod analyzers;
+mod assists;
 pub mod options;
+mod project_services;
 mod registry;
+pub mod schema;
+mod schema_services;
+mod utils;
 
+pub use crate::assists::correctness::sort_keys::SortKeysOptions;
 pub use crate::registry::visit_registry;
+pub use crate::schema_services::JsonSchemas;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
+    parse_linter_suppression_comment, AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow,
+    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
 };
 use biome_diagnostics::Error;
 use biome_json_syntax::JsonLanguage;
+use biome_project::ProjectImports;
+use std::sync::Arc;
+
+pub(crate) type JsonRuleAction = RuleAction<JsonLanguage>;
 
 /// Return the static [MetadataRegistry] for the JSON analyzer rules
 pub fn metadata() -> &'static MetadataRegistry {
@@ -25,18 +36,30 @@ pub fn metadata() -> &'static MetadataRegistry {
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
-/// then call `emit_signal` when an analysis rule emits a diagnostic or action
+/// then call `emit_signal` when an analysis rule emits a diagnostic or action.
+/// `project_imports` are the packages imported by the source files of the project, and
+/// `schemas` are the JSON schemas that the file must match
 pub fn analyze<'a, F, B>(
     root: &LanguageRoot<JsonLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    project_imports: ProjectImports,
+    schemas: JsonSchemas,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<JsonLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        project_imports,
+        schemas,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -50,6 +73,8 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    project_imports: ProjectImports,
+    schemas: JsonSchemas,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -57,15 +82,10 @@ where
     F: FnMut(&dyn AnalyzerSignal<JsonLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    fn parse_linter_suppression_comment(
-        _text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
-        vec![]
-    }
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
@@ -84,6 +104,9 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    services.insert_service(Arc::new(project_imports));
+    services.insert_service(Arc::new(schemas));
+
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -106,7 +129,7 @@ mod tests {
     use biome_json_syntax::TextRange;
     use std::slice;
 
-    use crate::{analyze, AnalysisFilter, ControlFlow};
+    use crate::{analyze, AnalysisFilter, ControlFlow, JsonSchemas, ProjectImports};
 
     #[ignore]
     #[test]
@@ -141,6 +164,8 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            ProjectImports::default(),
+            JsonSchemas::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -2,4 +2,12 @@
 
 use crate::analyzers;
 
+pub type NoDuplicateDependencies = < analyzers :: nursery :: no_duplicate_dependencies :: NoDuplicateDependencies as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateJsonKeys = < analyzers :: nursery :: no_duplicate_json_keys :: NoDuplicateJsonKeys as biome_analyze :: Rule > :: Options ;
+pub type NoInvalidPackageExports = < analyzers :: nursery :: no_invalid_package_exports :: NoInvalidPackageExports as biome_analyze :: Rule > :: Options ;
+pub type NoInvalidVersionRange = < analyzers :: nursery :: no_invalid_version_range :: NoInvalidVersionRange as biome_analyze :: Rule > :: Options ;
+pub type NoJsonSchemaViolations = < analyzers :: nursery :: no_json_schema_violations :: NoJsonSchemaViolations as biome_analyze :: Rule > :: Options ;
+pub type NoUnknownLicense =
+    <analyzers::nursery::no_unknown_license::NoUnknownLicense as biome_analyze::Rule>::Options;
+pub type NoUnusedDependencies = < analyzers :: nursery :: no_unused_dependencies :: NoUnusedDependencies as biome_analyze :: Rule > :: Options ;
+pub type UseSortedDependencies = < analyzers :: nursery :: use_sorted_dependencies :: UseSortedDependencies as biome_analyze :: Rule > :: Options ;
@@ -0,0 +1,68 @@
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor,
+};
+use biome_json_syntax::{JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_project::ProjectImports;
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct ProjectServices {
+    imports: Arc<ProjectImports>,
+}
+
+impl ProjectServices {
+    /// Returns `true` if a source file of the project imports the package `name`
+    pub(crate) fn is_imported(&self, name: &str) -> bool {
+        self.imports.is_imported(name)
+    }
+}
+
+impl FromServices for ProjectServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> Result<Self, MissingServicesDiagnostic> {
+        let imports: &Arc<ProjectImports> = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["ProjectImports"])
+        })?;
+
+        Ok(Self {
+            imports: imports.clone(),
+        })
+    }
+}
+
+impl Phase for ProjectServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the imports of the project** to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct Project<N>(pub N);
+
+impl<N> Queryable for Project<N>
+where
+    N: AstNode<Language = JsonLanguage> + 'static,
+{
+    type Input = JsonSyntaxNode;
+    type Output = N;
+
+    type Language = JsonLanguage;
+    type Services = ProjectServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsonLanguage>, _: &JsonRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
@@ -0,0 +1,989 @@
+//! Validation of JSON documents against a [JSON Schema](https://json-schema.org/).
+//!
+//! The document is validated from its syntax tree, so that each diagnostic points to the
+//! member or the value that doesn't match the schema. The keywords of draft-07 and 2020-12
+//! that constrain the shape of the values are supported. The annotations, the formats and
+//! the references to other documents are ignored.
+
+use biome_json_syntax::{
+    AnyJsonValue, JsonArrayValue, JsonMember, JsonObjectValue, JsonRoot, JsonSyntaxNode, TextRange,
+    TextSize,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TokenAtOffset};
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use serde_json::{Map, Value};
+use std::fmt::Display;
+
+/// References can be recursive, the validation stops at this depth
+const MAX_DEPTH: usize = 64;
+
+/// A value of the document that doesn't match the schema
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SchemaViolation {
+    message: String,
+    range: TextRange,
+}
+
+impl SchemaViolation {
+    fn new(range: TextRange, message: impl Display) -> Self {
+        Self {
+            message: message.to_string(),
+            range,
+        }
+    }
+
+    /// The range of the member or of the value that doesn't match the schema
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Describes how the value doesn't match the schema
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// A property of an object, as described by the schema
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SchemaProperty {
+    pub name: String,
+    /// The `description` of the property schema, if any
+    pub description: Option<String>,
+}
+
+/// A JSON Schema document, `draft-07` or `2020-12`
+#[derive(Debug, Clone)]
+pub struct JsonSchema {
+    root: Value,
+    /// The regular expressions of the `pattern` and `patternProperties` keywords, compiled once
+    patterns: FxHashMap<String, Regex>,
+}
+
+impl JsonSchema {
+    pub fn new(root: Value) -> Self {
+        let mut patterns = FxHashMap::default();
+        collect_patterns(&root, &mut patterns);
+        Self { root, patterns }
+    }
+
+    /// Validates the document `root`, and returns each violation of the schema
+    pub fn validate(&self, root: &JsonRoot) -> Vec<SchemaViolation> {
+        let mut diagnostics = Vec::new();
+        if let Ok(value) = root.value() {
+            self.validate_value(&self.root, &value, 0, &mut diagnostics);
+        }
+        diagnostics
+    }
+
+    /// Returns the properties that the schema describes for the object that contains
+    /// `offset`. The properties already declared in the object are skipped.
+    pub fn complete_properties(&self, root: &JsonRoot, offset: TextSize) -> Vec<SchemaProperty> {
+        let Some(object) = object_at_offset(root, offset) else {
+            return Vec::new();
+        };
+        let Some(path) = path_of(object.syntax()) else {
+            return Vec::new();
+        };
+
+        let mut schemas = vec![&self.root];
+        for segment in &path {
+            schemas = schemas
+                .into_iter()
+                .flat_map(|schema| self.expand(schema, 0))
+                .filter_map(|schema| match segment {
+                    PathSegment::Key(key) => property_schema(schema, key, &self.patterns),
+                    PathSegment::Index(index) => item_schema(schema, *index),
+                })
+                .collect();
+        }
+
+        let declared = object
+            .json_member_list()
+            .iter()
+            .flatten()
+            .filter_map(|member| member_name(&member))
+            .collect::<Vec<_>>();
+        let mut properties: Vec<SchemaProperty> = Vec::new();
+        for schema in schemas
+            .into_iter()
+            .flat_map(|schema| self.expand(schema, 0))
+        {
+            let Some(schema_properties) = schema.get("properties").and_then(Value::as_object)
+            else {
+                continue;
+            };
+            for (name, property) in schema_properties {
+                if declared.contains(name)
+                    || properties.iter().any(|property| &property.name == name)
+                {
+                    continue;
+                }
+                let description = self
+                    .resolve(property, 0)
+                    .and_then(|property| property.get("description"))
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+                properties.push(SchemaProperty {
+                    name: name.clone(),
+                    description,
+                });
+            }
+        }
+
+        properties
+    }
+
+    /// Returns `schema` and the schemas it's combined with through `$ref` and `allOf`,
+    /// `anyOf` or `oneOf`: they can all describe the properties of a value
+    fn expand<'a>(&'a self, schema: &'a Value, depth: usize) -> Vec<&'a Value> {
+        let Some(schema) = self.resolve(schema, depth) else {
+            return Vec::new();
+        };
+
+        let mut schemas = vec![schema];
+        if depth < MAX_DEPTH {
+            if let Some(target) =
+                reference(schema).and_then(|reference| reference_target(&self.root, reference))
+            {
+                schemas.extend(self.expand(target, depth + 1));
+            }
+            for keyword in ["allOf", "anyOf", "oneOf"] {
+                for subschema in schema
+                    .get(keyword)
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                {
+                    schemas.extend(self.expand(subschema, depth + 1));
+                }
+            }
+        }
+        schemas
+    }
+
+    /// Returns the schema that `schema` references, when it's only a reference
+    fn resolve<'a>(&'a self, schema: &'a Value, depth: usize) -> Option<&'a Value> {
+        match schema.as_object() {
+            Some(object) if object.len() == 1 && depth < MAX_DEPTH => match reference(schema) {
+                Some(reference) => {
+                    self.resolve(reference_target(&self.root, reference)?, depth + 1)
+                }
+                None => Some(schema),
+            },
+            _ => Some(schema),
+        }
+    }
+
+    fn validate_value(
+        &self,
+        schema: &Value,
+        value: &AnyJsonValue,
+        depth: usize,
+        diagnostics: &mut Vec<SchemaViolation>,
+    ) {
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => {
+                diagnostics.push(SchemaViolation::new(
+                    value.range(),
+                    "The schema doesn't allow any value here.",
+                ));
+                return;
+            }
+            Value::Object(schema) => schema,
+            _ => return,
+        };
+        if depth > MAX_DEPTH || matches!(value, AnyJsonValue::JsonBogusValue(_)) {
+            return;
+        }
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            if let Some(target) = reference_target(&self.root, reference) {
+                self.validate_value(target, value, depth + 1, diagnostics);
+            }
+        }
+
+        if let Some(expected) = schema.get("type") {
+            let kind = ValueKind::of(value);
+            let types = match expected {
+                Value::String(name) => vec![name.as_str()],
+                Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            };
+            if !types.is_empty() && !types.iter().any(|name| kind.matches(name)) {
+                diagnostics.push(SchemaViolation::new(
+                    value.range(),
+                    format_args!(
+                        "Expected a value of type {}, but found {}.",
+                        types.join(" or "),
+                        kind.name()
+                    ),
+                ));
+                // The other keywords would report the same mistake
+                return;
+            }
+        }
+
+        if let Some(expected) = schema.get("const") {
+            if to_value(value)
+                .as_ref()
+                .is_some_and(|actual| !equals(actual, expected))
+            {
+                diagnostics.push(SchemaViolation::new(
+                    value.range(),
+                    format_args!("Expected the value {expected}."),
+                ));
+            }
+        }
+
+        if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+            let is_allowed = to_value(value).map_or(true, |actual| {
+                allowed.iter().any(|item| equals(&actual, item))
+            });
+            if !is_allowed {
+                let allowed = allowed
+                    .iter()
+                    .map(Value::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                diagnostics.push(SchemaViolation::new(
+                    value.range(),
+                    format_args!("Expected one of the values {allowed}."),
+                ));
+            }
+        }
+
+        match value {
+            AnyJsonValue::JsonStringValue(string) => {
+                if let Ok(text) = string.inner_string_text() {
+                    validate_string(
+                        schema,
+                        text.text(),
+                        value.range(),
+                        &self.patterns,
+                        diagnostics,
+                    );
+                }
+            }
+            AnyJsonValue::JsonNumberValue(number) => {
+                let number = number
+                    .value_token()
+                    .ok()
+                    .and_then(|token| token.text_trimmed().parse::<f64>().ok());
+                if let Some(number) = number {
+                    validate_number(schema, number, value.range(), diagnostics);
+                }
+            }
+            AnyJsonValue::JsonObjectValue(object) => {
+                self.validate_object(schema, object, depth, diagnostics);
+            }
+            AnyJsonValue::JsonArrayValue(array) => {
+                self.validate_array(schema, array, depth, diagnostics);
+            }
+            _ => {}
+        }
+
+        if let Some(subschemas) = schema.get("allOf").and_then(Value::as_array) {
+            for subschema in subschemas {
+                self.validate_value(subschema, value, depth + 1, diagnostics);
+            }
+        }
+
+        if let Some(subschemas) = schema.get("anyOf").and_then(Value::as_array) {
+            let results = self.validate_alternatives(subschemas, value, depth);
+            if !results.iter().any(Vec::is_empty) {
+                diagnostics.extend(closest_alternative(results));
+            }
+        }
+
+        if let Some(subschemas) = schema.get("oneOf").and_then(Value::as_array) {
+            let results = self.validate_alternatives(subschemas, value, depth);
+            let matches = results.iter().filter(|result| result.is_empty()).count();
+            if matches == 0 {
+                diagnostics.extend(closest_alternative(results));
+            } else if matches > 1 {
+                diagnostics.push(SchemaViolation::new(
+                    value.range(),
+                    "The value matches more than one of the schemas of oneOf.",
+                ));
+            }
+        }
+
+        if let Some(subschema) = schema.get("not") {
+            let mut results = Vec::new();
+            self.validate_value(subschema, value, depth + 1, &mut results);
+            if results.is_empty() {
+                diagnostics.push(SchemaViolation::new(
+                    value.range(),
+                    "The value matches a schema that it must not match.",
+                ));
+            }
+        }
+
+        if let Some(condition) = schema.get("if") {
+            let mut results = Vec::new();
+            self.validate_value(condition, value, depth + 1, &mut results);
+            let branch = if results.is_empty() {
+                schema.get("then")
+            } else {
+                schema.get("else")
+            };
+            if let Some(branch) = branch {
+                self.validate_value(branch, value, depth + 1, diagnostics);
+            }
+        }
+    }
+
+    fn validate_alternatives(
+        &self,
+        subschemas: &[Value],
+        value: &AnyJsonValue,
+        depth: usize,
+    ) -> Vec<Vec<SchemaViolation>> {
+        subschemas
+            .iter()
+            .map(|subschema| {
+                let mut results = Vec::new();
+                self.validate_value(subschema, value, depth + 1, &mut results);
+                results
+            })
+            .collect()
+    }
+
+    fn validate_object(
+        &self,
+        schema: &Map<String, Value>,
+        object: &JsonObjectValue,
+        depth: usize,
+        diagnostics: &mut Vec<SchemaViolation>,
+    ) {
+        let members = object
+            .json_member_list()
+            .iter()
+            .flatten()
+            .filter_map(|member| Some((member_name(&member)?, member)))
+            .collect::<Vec<_>>();
+
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !members.iter().any(|(member_name, _)| member_name == name) {
+                    diagnostics.push(SchemaViolation::new(
+                        object.range(),
+                        format_args!("The property \"{name}\" is required."),
+                    ));
+                }
+            }
+        }
+
+        let count = members.len() as u64;
+        if let Some(min) = schema.get("minProperties").and_then(Value::as_u64) {
+            if count < min {
+                diagnostics.push(SchemaViolation::new(
+                    object.range(),
+                    format_args!("Expected at least {min} properties, but found {count}."),
+                ));
+            }
+        }
+        if let Some(max) = schema.get("maxProperties").and_then(Value::as_u64) {
+            if count > max {
+                diagnostics.push(SchemaViolation::new(
+                    object.range(),
+                    format_args!("Expected at most {max} properties, but found {count}."),
+                ));
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let pattern_properties = schema
+            .get("patternProperties")
+            .and_then(Value::as_object)
+            .map(|patterns| {
+                patterns
+                    .iter()
+                    .filter_map(|(pattern, schema)| Some((self.patterns.get(pattern)?, schema)))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let additional_properties = schema.get("additionalProperties");
+
+        for (name, member) in &members {
+            let Ok(member_value) = member.value() else {
+                continue;
+            };
+
+            let mut is_described = false;
+            if let Some(property) = properties.and_then(|properties| properties.get(name)) {
+                is_described = true;
+                self.validate_value(property, &member_value, depth + 1, diagnostics);
+            }
+            for (pattern, property) in &pattern_properties {
+                if pattern.is_match(name) {
+                    is_described = true;
+                    self.validate_value(property, &member_value, depth + 1, diagnostics);
+                }
+            }
+
+            match additional_properties {
+                Some(Value::Bool(false)) if !is_described => {
+                    let range = member.name().map_or(member.range(), |name| name.range());
+                    diagnostics.push(SchemaViolation::new(
+                        range,
+                        format_args!("The property \"{name}\" isn't allowed by the schema."),
+                    ));
+                }
+                Some(additional) if !is_described => {
+                    self.validate_value(additional, &member_value, depth + 1, diagnostics);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn validate_array(
+        &self,
+        schema: &Map<String, Value>,
+        array: &JsonArrayValue,
+        depth: usize,
+        diagnostics: &mut Vec<SchemaViolation>,
+    ) {
+        let elements = array.elements().iter().flatten().collect::<Vec<_>>();
+
+        let count = elements.len() as u64;
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if count < min {
+                diagnostics.push(SchemaViolation::new(
+                    array.range(),
+                    format_args!("Expected at least {min} items, but found {count}."),
+                ));
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if count > max {
+                diagnostics.push(SchemaViolation::new(
+                    array.range(),
+                    format_args!("Expected at most {max} items, but found {count}."),
+                ));
+            }
+        }
+
+        // `prefixItems` describes the first items since 2020-12, `items` did it before
+        let (prefix_items, rest) = match (schema.get("prefixItems"), schema.get("items")) {
+            (Some(Value::Array(prefix_items)), rest) => (prefix_items.as_slice(), rest),
+            (_, Some(Value::Array(prefix_items))) => {
+                (prefix_items.as_slice(), schema.get("additionalItems"))
+            }
+            (_, rest) => (&[][..], rest),
+        };
+        for (index, element) in elements.iter().enumerate() {
+            let item_schema = prefix_items.get(index).or(rest);
+            if let Some(item_schema) = item_schema {
+                self.validate_value(item_schema, element, depth + 1, diagnostics);
+            }
+        }
+
+        if let Some(contains) = schema.get("contains") {
+            let contains_match = elements.iter().any(|element| {
+                let mut results = Vec::new();
+                self.validate_value(contains, element, depth + 1, &mut results);
+                results.is_empty()
+            });
+            if !contains_match {
+                diagnostics.push(SchemaViolation::new(
+                    array.range(),
+                    "None of the items matches the schema of contains.",
+                ));
+            }
+        }
+
+        if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+            let values = elements.iter().map(to_value).collect::<Vec<_>>();
+            for (index, value) in values.iter().enumerate() {
+                let Some(value) = value else {
+                    continue;
+                };
+                let is_duplicate = values[..index]
+                    .iter()
+                    .flatten()
+                    .any(|previous| equals(previous, value));
+                if is_duplicate {
+                    diagnostics.push(SchemaViolation::new(
+                        elements[index].range(),
+                        "The items of this array must be unique, this item is a duplicate.",
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn validate_string(
+    schema: &Map<String, Value>,
+    text: &str,
+    range: TextRange,
+    patterns: &FxHashMap<String, Regex>,
+    diagnostics: &mut Vec<SchemaViolation>,
+) {
+    let length = text.chars().count() as u64;
+    if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+        if length < min {
+            diagnostics.push(SchemaViolation::new(
+                range,
+                format_args!("Expected a string of at least {min} characters."),
+            ));
+        }
+    }
+    if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+        if length > max {
+            diagnostics.push(SchemaViolation::new(
+                range,
+                format_args!("Expected a string of at most {max} characters."),
+            ));
+        }
+    }
+    if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+        if patterns
+            .get(pattern)
+            .is_some_and(|regex| !regex.is_match(text))
+        {
+            diagnostics.push(SchemaViolation::new(
+                range,
+                format_args!("The string doesn't match the pattern {pattern}."),
+            ));
+        }
+    }
+}
+
+fn validate_number(
+    schema: &Map<String, Value>,
+    number: f64,
+    range: TextRange,
+    diagnostics: &mut Vec<SchemaViolation>,
+) {
+    let bound = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+
+    if let Some(minimum) = bound("minimum").filter(|minimum| number < *minimum) {
+        diagnostics.push(SchemaViolation::new(
+            range,
+            format_args!("Expected a number greater than or equal to {minimum}."),
+        ));
+    }
+    if let Some(maximum) = bound("maximum").filter(|maximum| number > *maximum) {
+        diagnostics.push(SchemaViolation::new(
+            range,
+            format_args!("Expected a number less than or equal to {maximum}."),
+        ));
+    }
+    if let Some(minimum) = bound("exclusiveMinimum").filter(|minimum| number <= *minimum) {
+        diagnostics.push(SchemaViolation::new(
+            range,
+            format_args!("Expected a number greater than {minimum}."),
+        ));
+    }
+    if let Some(maximum) = bound("exclusiveMaximum").filter(|maximum| number >= *maximum) {
+        diagnostics.push(SchemaViolation::new(
+            range,
+            format_args!("Expected a number less than {maximum}."),
+        ));
+    }
+    if let Some(divisor) = bound("multipleOf").filter(|divisor| *divisor > 0.0) {
+        let quotient = number / divisor;
+        if (quotient - quotient.round()).abs() > f64::EPSILON * quotient.abs().max(1.0) {
+            diagnostics.push(SchemaViolation::new(
+                range,
+                format_args!("Expected a multiple of {divisor}."),
+            ));
+        }
+    }
+}
+
+/// Returns the diagnostics of the alternative that has the fewest violations,
+/// which is likely the one the author meant
+fn closest_alternative(results: Vec<Vec<SchemaViolation>>) -> Vec<SchemaViolation> {
+    results.into_iter().min_by_key(Vec::len).unwrap_or_default()
+}
+
+#[derive(Debug, Copy, Clone)]
+enum ValueKind {
+    Null,
+    Boolean,
+    Integer,
+    Number,
+    String,
+    Array,
+    Object,
+    Unknown,
+}
+
+impl ValueKind {
+    fn of(value: &AnyJsonValue) -> Self {
+        match value {
+            AnyJsonValue::JsonNullValue(_) => Self::Null,
+            AnyJsonValue::JsonBooleanValue(_) => Self::Boolean,
+            AnyJsonValue::JsonNumberValue(number) => {
+                let number = number
+                    .value_token()
+                    .ok()
+                    .and_then(|token| token.text_trimmed().parse::<f64>().ok());
+                match number {
+                    Some(number) if number.fract() == 0.0 => Self::Integer,
+                    _ => Self::Number,
+                }
+            }
+            AnyJsonValue::JsonStringValue(_) => Self::String,
+            AnyJsonValue::JsonArrayValue(_) => Self::Array,
+            AnyJsonValue::JsonObjectValue(_) => Self::Object,
+            AnyJsonValue::JsonBogusValue(_) => Self::Unknown,
+        }
+    }
+
+    fn matches(self, name: &str) -> bool {
+        match self {
+            // An integer is also a number
+            Self::Integer => matches!(name, "integer" | "number"),
+            Self::Unknown => true,
+            _ => self.name() == name,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Integer => "integer",
+            Self::Number => "number",
+            Self::String => "string",
+            Self::Array => "array",
+            Self::Object => "object",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// Converts a value of the syntax tree, so it can be compared with the values of the schema
+fn to_value(value: &AnyJsonValue) -> Option<Value> {
+    Some(match value {
+        AnyJsonValue::JsonNullValue(_) => Value::Null,
+        AnyJsonValue::JsonBooleanValue(boolean) => {
+            Value::Bool(boolean.value_token().ok()?.text_trimmed() == "true")
+        }
+        AnyJsonValue::JsonNumberValue(number) => {
+            serde_json::from_str(number.value_token().ok()?.text_trimmed()).ok()?
+        }
+        AnyJsonValue::JsonStringValue(string) => {
+            Value::String(string.inner_string_text().ok()?.text().to_string())
+        }
+        AnyJsonValue::JsonArrayValue(array) => Value::Array(
+            array
+                .elements()
+                .iter()
+                .map(|element| to_value(&element.ok()?))
+                .collect::<Option<_>>()?,
+        ),
+        AnyJsonValue::JsonObjectValue(object) => Value::Object(
+            object
+                .json_member_list()
+                .iter()
+                .map(|member| {
+                    let member = member.ok()?;
+                    Some((member_name(&member)?, to_value(&member.value().ok()?)?))
+                })
+                .collect::<Option<_>>()?,
+        ),
+        AnyJsonValue::JsonBogusValue(_) => return None,
+    })
+}
+
+/// Compares two values, the numbers are equal when they have the same value: `1` and `1.0` are equal
+fn equals(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => left.as_f64() == right.as_f64(),
+        (Value::Array(left), Value::Array(right)) => {
+            left.len() == right.len() && left.iter().zip(right).all(|(l, r)| equals(l, r))
+        }
+        (Value::Object(left), Value::Object(right)) => {
+            left.len() == right.len()
+                && left
+                    .iter()
+                    .all(|(key, l)| right.get(key).is_some_and(|r| equals(l, r)))
+        }
+        _ => left == right,
+    }
+}
+
+fn member_name(member: &JsonMember) -> Option<String> {
+    Some(member.name().ok()?.inner_string_text().ok()?.to_string())
+}
+
+fn reference(schema: &Value) -> Option<&str> {
+    schema.get("$ref").and_then(Value::as_str)
+}
+
+/// Resolves a reference to a location of the same document, e.g. `#/definitions/rule` or `#/$defs/rule`
+fn reference_target<'a>(root: &'a Value, reference: &str) -> Option<&'a Value> {
+    let pointer = reference.strip_prefix('#')?;
+    if pointer.is_empty() {
+        Some(root)
+    } else {
+        root.pointer(pointer)
+    }
+}
+
+/// Returns the schema of the property `key` of an object described by `schema`
+fn property_schema<'a>(
+    schema: &'a Value,
+    key: &str,
+    patterns: &FxHashMap<String, Regex>,
+) -> Option<&'a Value> {
+    if let Some(property) = schema
+        .get("properties")
+        .and_then(|properties| properties.get(key))
+    {
+        return Some(property);
+    }
+    let pattern_property = schema
+        .get("patternProperties")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .find(|(pattern, _)| {
+            patterns
+                .get(pattern.as_str())
+                .is_some_and(|regex| regex.is_match(key))
+        });
+    if let Some((_, property)) = pattern_property {
+        return Some(property);
+    }
+    schema
+        .get("additionalProperties")
+        .filter(|additional| additional.is_object())
+}
+
+/// Compiles the regular expressions of the `pattern` and `patternProperties` keywords
+/// of `schema` and of its subschemas. The invalid expressions are skipped.
+fn collect_patterns(schema: &Value, patterns: &mut FxHashMap<String, Regex>) {
+    fn compile(pattern: &str, patterns: &mut FxHashMap<String, Regex>) {
+        if !patterns.contains_key(pattern) {
+            if let Ok(regex) = Regex::new(pattern) {
+                patterns.insert(pattern.to_string(), regex);
+            }
+        }
+    }
+
+    match schema {
+        Value::Object(object) => {
+            if let Some(pattern) = object.get("pattern").and_then(Value::as_str) {
+                compile(pattern, patterns);
+            }
+            if let Some(pattern_properties) =
+                object.get("patternProperties").and_then(Value::as_object)
+            {
+                for pattern in pattern_properties.keys() {
+                    compile(pattern, patterns);
+                }
+            }
+            for value in object.values() {
+                collect_patterns(value, patterns);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_patterns(item, patterns);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the schema of the item at `index` of an array described by `schema`
+fn item_schema(schema: &Value, index: usize) -> Option<&Value> {
+    match (schema.get("prefixItems"), schema.get("items")) {
+        (Some(Value::Array(prefix_items)), rest) => prefix_items.get(index).or(rest),
+        (_, Some(Value::Array(prefix_items))) => prefix_items
+            .get(index)
+            .or_else(|| schema.get("additionalItems")),
+        (_, rest) => rest,
+    }
+}
+
+#[derive(Debug)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Returns the object in which a property can be inserted at `offset`
+fn object_at_offset(root: &JsonRoot, offset: TextSize) -> Option<JsonObjectValue> {
+    let token = match root.syntax().token_at_offset(offset) {
+        TokenAtOffset::None => return None,
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(_, right) => right,
+    };
+    let parent = token.parent()?;
+    // The keys are completed, not the values
+    let is_value = AnyJsonValue::cast_ref(&parent)
+        .is_some_and(|value| !matches!(value, AnyJsonValue::JsonObjectValue(_)));
+    if is_value {
+        return None;
+    }
+    parent
+        .ancestors()
+        .find(|node| {
+            JsonObjectValue::can_cast(node.kind()) || JsonArrayValue::can_cast(node.kind())
+        })
+        .and_then(JsonObjectValue::cast)
+}
+
+/// Returns the keys and the indices that lead from the root of the document to `node`
+fn path_of(node: &JsonSyntaxNode) -> Option<Vec<PathSegment>> {
+    let mut path = Vec::new();
+    let mut node = node.clone();
+    while let Some(parent) = node.parent() {
+        if let Some(member) = JsonMember::cast_ref(&parent) {
+            path.push(PathSegment::Key(member_name(&member)?));
+            // The member list, then the object
+            node = parent.parent()?.parent()?;
+        } else if let Some(array) = parent.parent().and_then(JsonArrayValue::cast) {
+            let index = array
+                .elements()
+                .iter()
+                .position(|element| element.is_ok_and(|element| element.syntax() == &node))?;
+            path.push(PathSegment::Index(index));
+            node = array.into_syntax();
+        } else {
+            break;
+        }
+    }
+    path.reverse();
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_json_parser::{parse_json, JsonParserOptions};
+    use serde_json::json;
+
+    fn messages(schema: Value, source: &str) -> Vec<(String, String)> {
+        let parsed = parse_json(source, JsonParserOptions::default());
+        JsonSchema::new(schema)
+            .validate(&parsed.tree())
+            .into_iter()
+            .map(|violation| {
+                let range = violation.range();
+                (source[range].to_string(), violation.message().to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reports_type_mismatches() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "port": { "type": "integer", "minimum": 1 }
+            }
+        });
+        assert_eq!(
+            messages(schema, r#"{ "name": 1, "port": 0 }"#),
+            vec![
+                (
+                    "1".to_string(),
+                    "Expected a value of type string, but found integer.".to_string()
+                ),
+                (
+                    "0".to_string(),
+                    "Expected a number greater than or equal to 1.".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_missing_and_additional_properties() {
+        let schema = json!({
+            "required": ["name"],
+            "properties": { "name": { "type": "string" } },
+            "additionalProperties": false
+        });
+        assert_eq!(
+            messages(schema, r#"{ "nmae": "api" }"#),
+            vec![
+                (
+                    r#"{ "nmae": "api" }"#.to_string(),
+                    r#"The property "name" is required."#.to_string()
+                ),
+                (
+                    r#""nmae""#.to_string(),
+                    r#"The property "nmae" isn't allowed by the schema."#.to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_local_references() {
+        let schema = json!({
+            "$defs": { "level": { "enum": ["error", "warn"] } },
+            "type": "array",
+            "items": { "$ref": "#/$defs/level" },
+            "uniqueItems": true
+        });
+        assert_eq!(
+            messages(schema, r#"["error", "info", "error"]"#),
+            vec![
+                (
+                    r#""info""#.to_string(),
+                    r#"Expected one of the values "error", "warn"."#.to_string()
+                ),
+                (
+                    r#""error""#.to_string(),
+                    "The items of this array must be unique, this item is a duplicate.".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn accepts_one_alternative() {
+        let schema = json!({
+            "anyOf": [{ "type": "string" }, { "type": "boolean" }]
+        });
+        assert!(messages(schema.clone(), "true").is_empty());
+        assert_eq!(
+            messages(schema, "null"),
+            vec![(
+                "null".to_string(),
+                "Expected a value of type string, but found null.".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn completes_properties() {
+        let schema = JsonSchema::new(json!({
+            "properties": {
+                "server": { "$ref": "#/definitions/server" }
+            },
+            "definitions": {
+                "server": {
+                    "properties": {
+                        "host": { "description": "The host name" },
+                        "port": {}
+                    }
+                }
+            }
+        }));
+        let source = r#"{ "server": { "port": 80,  } }"#;
+        let parsed = parse_json(source, JsonParserOptions::default());
+        let offset = TextSize::from(source.find(",  }").unwrap() as u32 + 2);
+        assert_eq!(
+            schema.complete_properties(&parsed.tree(), offset),
+            vec![SchemaProperty {
+                name: "host".to_string(),
+                description: Some("The host name".to_string()),
+            }]
+        );
+    }
+}
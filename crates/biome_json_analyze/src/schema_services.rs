@@ -0,0 +1,78 @@
+use crate::schema::JsonSchema;
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor,
+};
+use biome_json_syntax::{JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+/// The JSON schemas that the file must match
+#[derive(Debug, Clone, Default)]
+pub struct JsonSchemas(Vec<Arc<JsonSchema>>);
+
+impl JsonSchemas {
+    pub fn new(schemas: Vec<Arc<JsonSchema>>) -> Self {
+        Self(schemas)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SchemaServices {
+    schemas: Arc<JsonSchemas>,
+}
+
+impl SchemaServices {
+    /// Returns the JSON schemas that the file must match
+    pub(crate) fn schemas(&self) -> &[Arc<JsonSchema>] {
+        &self.schemas.0
+    }
+}
+
+impl FromServices for SchemaServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> Result<Self, MissingServicesDiagnostic> {
+        let schemas: &Arc<JsonSchemas> = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["JsonSchemas"])
+        })?;
+
+        Ok(Self {
+            schemas: schemas.clone(),
+        })
+    }
+}
+
+impl Phase for SchemaServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the JSON schemas of the file** to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct Schemas<N>(pub N);
+
+impl<N> Queryable for Schemas<N>
+where
+    N: AstNode<Language = JsonLanguage> + 'static,
+{
+    type Input = JsonSyntaxNode;
+    type Output = N;
+
+    type Language = JsonLanguage;
+    type Services = SchemaServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsonLanguage>, _: &JsonRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
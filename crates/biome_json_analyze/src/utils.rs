@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use biome_json_syntax::{JsonMember, JsonRoot};
+use biome_rowan::AstNode;
+
+/// The top-level fields of `package.json` that map the dependencies of the package to their version
+pub(crate) const DEPENDENCY_KINDS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "optionalDependencies",
+    "peerDependencies",
+];
+
+pub(crate) fn is_package_json(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|file_name| file_name == "package.json")
+}
+
+/// Whether `member` is a member of the top-level object of the file
+pub(crate) fn is_top_level_member(member: &JsonMember) -> bool {
+    member
+        .syntax()
+        .grand_parent()
+        .and_then(|object| object.parent())
+        .is_some_and(|parent| JsonRoot::can_cast(parent.kind()))
+}
+
+/// Returns the member that contains the object in which `member` is declared
+pub(crate) fn parent_member(member: &JsonMember) -> Option<JsonMember> {
+    member
+        .syntax()
+        .grand_parent()
+        .and_then(|object| object.parent())
+        .and_then(JsonMember::cast)
+}
@@ -1,23 +1,33 @@
 use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::JsFileSource;
+use biome_json_analyze::schema::JsonSchema;
+use biome_json_analyze::JsonSchemas;
 use biome_json_parser::{parse_json, JsonParserOptions};
 use biome_json_syntax::JsonLanguage;
+use biome_project::ProjectImports;
 use biome_rowan::AstNode;
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
     has_bogus_nodes_or_empty_slots, parse_test_path, register_leak_checker,
     write_analyzer_snapshot,
 };
-use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
+use std::{ffi::OsStr, fs::read_to_string, path::Path, slice, sync::Arc};
 
-tests_macros::gen_tests! {"tests/specs/**/*.{json}", crate::run_test, "module"}
+tests_macros::gen_tests! {"tests/specs/**/*.{json,jsonc}", crate::run_test, "module"}
 
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     register_leak_checker();
 
     let input_file = Path::new(input);
     let file_name = input_file.file_name().and_then(OsStr::to_str).unwrap();
+    // The options of the test `<name>.json` are read from `<name>.options.json`,
+    // and its schema from `<name>.schema.json`
+    if file_name.ends_with(".options.json") || file_name.ends_with(".schema.json") {
+        return;
+    }
 
     let (group, rule) = parse_test_path(input_file);
     if rule == "specs" || rule == "suppression" {
@@ -67,36 +77,44 @@ pub(crate) fn analyze_and_snap(
     file_name: &str,
     input_file: &Path,
 ) -> usize {
-    let parsed = parse_json(input_code, JsonParserOptions::default());
+    let parse_options = if input_file.extension() == Some(OsStr::new("jsonc")) {
+        JsonParserOptions::default().with_allow_comments()
+    } else {
+        JsonParserOptions::default()
+    };
+    let parsed = parse_json(input_code, parse_options);
     let root = parsed.tree();
 
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
+    let project_imports = load_project_imports(input_file);
+    let schemas = load_schemas(input_file);
+
+    let (_, errors) =
+        biome_json_analyze::analyze(&root, filter, &options, project_imports, schemas, |event| {
+            if let Some(mut diag) = event.diagnostic() {
+                for action in event.actions() {
+                    if !action.is_suppression() {
+                        check_code_action(input_file, input_code, parse_options, &action);
+                        diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                    }
+                }
+
+                let error = diag.with_severity(Severity::Warning);
+                diagnostics.push(diagnostic_to_string(file_name, input_code, error));
+                return ControlFlow::Continue(());
+            }
 
-    let (_, errors) = biome_json_analyze::analyze(&root, filter, &options, |event| {
-        if let Some(mut diag) = event.diagnostic() {
             for action in event.actions() {
                 if !action.is_suppression() {
-                    check_code_action(input_file, input_code, &action);
-                    diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                    check_code_action(input_file, input_code, parse_options, &action);
+                    code_fixes.push(code_fix_to_string(input_code, action));
                 }
             }
 
-            let error = diag.with_severity(Severity::Warning);
-            diagnostics.push(diagnostic_to_string(file_name, input_code, error));
-            return ControlFlow::Continue(());
-        }
-
-        for action in event.actions() {
-            if !action.is_suppression() {
-                check_code_action(input_file, input_code, &action);
-                code_fixes.push(code_fix_to_string(input_code, action));
-            }
-        }
-
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        });
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -112,7 +130,44 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
-fn check_code_action(path: &Path, source: &str, action: &AnalyzerAction<JsonLanguage>) {
+/// Collects the packages imported by the JavaScript files placed next to the test file
+fn load_project_imports(input_file: &Path) -> ProjectImports {
+    let mut imports = ProjectImports::default();
+    let Some(entries) = input_file
+        .parent()
+        .and_then(|directory| std::fs::read_dir(directory).ok())
+    else {
+        return imports;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let Ok(file_source) = JsFileSource::try_from(path.as_path()) else {
+            continue;
+        };
+        if let Ok(content) = read_to_string(&path) {
+            let parse = parse(&content, file_source, JsParserOptions::default());
+            imports.add_module(&parse.tree());
+        }
+    }
+
+    imports
+}
+
+fn load_schemas(input_file: &Path) -> JsonSchemas {
+    let schema_file = input_file.with_extension("schema.json");
+    let Ok(content) = read_to_string(schema_file) else {
+        return JsonSchemas::default();
+    };
+    let schema = serde_json::from_str(&content).expect("the schema of the test is invalid");
+    JsonSchemas::new(vec![Arc::new(JsonSchema::new(schema))])
+}
+
+fn check_code_action(
+    path: &Path,
+    source: &str,
+    parse_options: JsonParserOptions,
+    action: &AnalyzerAction<JsonLanguage>,
+) {
     let (_, text_edit) = action.mutation.as_text_edits().unwrap_or_default();
 
     let output = text_edit.new_string(source);
@@ -136,6 +191,6 @@ fn check_code_action(path: &Path, source: &str, action: &AnalyzerAction<JsonLang
     }
 
     // Re-parse the modified code and panic if the resulting tree has syntax errors
-    let re_parse = parse_json(&output, JsonParserOptions::default());
+    let re_parse = parse_json(&output, parse_options);
     assert_errors_are_absent(re_parse.tree().syntax(), re_parse.diagnostics(), path);
 }
@@ -0,0 +1,7 @@
+import React from "react";
+import { map } from "lodash/fp";
+import { helper } from "./helper.js";
+export { parse } from "@scope/parser/sub";
+
+const chalk = require("chalk");
+const fs = require("node:fs");
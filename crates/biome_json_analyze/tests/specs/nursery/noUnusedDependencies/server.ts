@@ -0,0 +1,6 @@
+import type { Request } from "express";
+import transform from "@babel/core";
+
+export async function load() {
+	return await import("dotenv");
+}
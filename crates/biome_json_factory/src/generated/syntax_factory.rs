@@ -107,7 +107,7 @@ impl SyntaxFactory for JsonSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == JSON_STRING_LITERAL {
+                    if matches!(element.kind(), JSON_STRING_LITERAL | IDENT) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
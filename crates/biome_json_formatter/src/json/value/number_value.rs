@@ -7,6 +7,12 @@ pub(crate) struct FormatJsonNumberValue;
 
 impl FormatNodeRule<JsonNumberValue> for FormatJsonNumberValue {
     fn fmt_fields(&self, node: &JsonNumberValue, f: &mut JsonFormatter) -> FormatResult<()> {
-        format_number_token(&node.value_token()?).fmt(f)
+        let token = node.value_token()?;
+        // The hexadecimal numbers, `Infinity` and `NaN` of JSON5 are kept as is
+        if token.text_trimmed().contains(['x', 'X', 'I', 'N']) {
+            token.format().fmt(f)
+        } else {
+            format_number_token(&token).fmt(f)
+        }
     }
 }
@@ -12,7 +12,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Default)]
 pub struct JsonTestFormatLanguage {
-    _source_type: JsonFileSource,
+    source_type: JsonFileSource,
+}
+
+impl JsonTestFormatLanguage {
+    pub fn new(source_type: JsonFileSource) -> Self {
+        JsonTestFormatLanguage { source_type }
+    }
 }
 
 impl TestFormatLanguage for JsonTestFormatLanguage {
@@ -22,7 +28,12 @@ impl TestFormatLanguage for JsonTestFormatLanguage {
     type FormatLanguage = JsonFormatLanguage;
 
     fn parse(&self, text: &str) -> AnyParse {
-        let parse = parse_json(text, JsonParserOptions::default().with_allow_comments());
+        let options = if self.source_type.is_json5() {
+            JsonParserOptions::default().with_json5()
+        } else {
+            JsonParserOptions::default().with_allow_comments()
+        };
+        let parse = parse_json(text, options);
 
         AnyParse::new(parse.syntax().as_send().unwrap(), parse.into_diagnostics())
     }
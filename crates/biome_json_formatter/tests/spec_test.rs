@@ -1,5 +1,6 @@
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use biome_json_formatter::context::JsonFormatOptions;
+use biome_json_syntax::JsonFileSource;
 use std::path::Path;
 
 mod language {
@@ -23,6 +24,7 @@ mod language {
 ///
 /// * `json/null` -> input: `tests/specs/json/null.json`, expected output: `tests/specs/json/null.json.snap`
 /// * `null` -> input: `tests/specs/null.json`, expected output: `tests/specs/null.json.snap`
+/// * `json5/null` -> input: `tests/specs/json5/null.json5`, expected output: `tests/specs/json5/null.json5.snap`
 pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
     let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
 
@@ -30,8 +32,9 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
         return;
     };
 
+    let source_type: JsonFileSource = test_file.input_file().as_path().try_into().unwrap();
     let options = JsonFormatOptions::default();
-    let language = language::JsonTestFormatLanguage::default();
+    let language = language::JsonTestFormatLanguage::new(source_type);
 
     let snapshot = SpecSnapshot::new(test_file, test_directory, language, options);
 
@@ -139,6 +139,8 @@ impl<'src> Lexer<'src> {
                     b'\r' | b'\n' => {
                         break;
                     }
+                    // JSON5 allows the whitespace characters of JavaScript
+                    _ if self.options.json5 => self.advance(1),
                     _ => {
                         let start = self.text_position();
                         self.advance(1);
@@ -318,6 +320,11 @@ impl<'src> Lexer<'src> {
 
             SLH => self.lex_slash(),
 
+            PLS if self.options.json5 => self.lex_number(current),
+            PRD if self.options.json5 && matches!(self.peek_byte(), Some(b'0'..=b'9')) => {
+                self.lex_number(current)
+            }
+
             UNI => {
                 let chr = self.current_char_unchecked();
 
@@ -358,10 +365,17 @@ impl<'src> Lexer<'src> {
 
         let start = self.text_position();
 
-        if current == b'-' {
+        // A leading `+` is only lexed as a number in JSON5
+        if matches!(current, b'-' | b'+') {
             self.advance(1);
         }
 
+        if self.options.json5 {
+            if let Some(kind) = self.lex_json5_number() {
+                return kind;
+            }
+        }
+
         let mut state = LexNumberState::FirstDigit;
 
         loop {
@@ -403,6 +417,16 @@ impl<'src> Lexer<'src> {
                         {
                             LexNumberState::FractionalPart
                         }
+                        // JSON5 allows a leading or a trailing decimal point: `.5` and `5.`
+                        LexNumberState::FirstDigit
+                            if self.options.json5
+                                && matches!(self.current_byte(), Some(b'0'..=b'9')) =>
+                        {
+                            LexNumberState::FractionalPart
+                        }
+                        LexNumberState::IntegerPart if self.options.json5 => {
+                            LexNumberState::FractionalPart
+                        }
                         LexNumberState::IntegerPart => LexNumberState::Invalid {
                             position: self.text_position(),
                             reason: InvalidNumberReason::MissingFraction,
@@ -452,8 +476,9 @@ impl<'src> Lexer<'src> {
             | LexNumberState::FractionalPart
             | LexNumberState::Exponent => JSON_NUMBER_LITERAL,
             LexNumberState::FirstDigit => {
+                let sign = if current == b'+' { "Plus" } else { "Minus" };
                 let err = ParseDiagnostic::new(
-                    "Minus must be followed by a digit",
+                    format!("{sign} must be followed by a digit"),
                     start..self.text_position(),
                 );
                 self.diagnostics.push(err);
@@ -492,6 +517,42 @@ impl<'src> Lexer<'src> {
         }
     }
 
+    /// Lexes the JSON5 numbers that aren't decimal numbers: `Infinity`, `NaN` and the
+    /// hexadecimal numbers. Assumes that the lexer is positioned after the sign, if any.
+    ///
+    /// Returns `None` if the number is a decimal number.
+    fn lex_json5_number(&mut self) -> Option<JsonSyntaxKind> {
+        let rest = &self.source[self.position..];
+        if let Some(name) = ["Infinity", "NaN"].into_iter().find(|name| {
+            rest.strip_prefix(name)
+                .is_some_and(|rest| !rest.starts_with(is_js_id_continue))
+        }) {
+            self.advance(name.len());
+            return Some(JSON_NUMBER_LITERAL);
+        }
+
+        if !matches!(rest.as_bytes(), [b'0', b'x' | b'X', ..]) {
+            return None;
+        }
+        let start = self.text_position();
+        self.advance(2);
+        let mut has_digits = false;
+        while let Some(b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F') = self.current_byte() {
+            has_digits = true;
+            self.advance(1);
+        }
+
+        if has_digits {
+            Some(JSON_NUMBER_LITERAL)
+        } else {
+            self.diagnostics.push(
+                ParseDiagnostic::new("Missing hexadecimal digits", start..self.text_position())
+                    .with_hint("Add the digits of the number, e.g. `0x1F`."),
+            );
+            Some(ERROR_TOKEN)
+        }
+    }
+
     fn lex_string_literal(&mut self, quote: u8) -> JsonSyntaxKind {
         // Handle invalid quotes
         self.assert_at_char_boundary();
@@ -499,7 +560,7 @@ impl<'src> Lexer<'src> {
 
         self.advance(1); // Skip over the quote
         let mut state = match quote {
-            b'\'' => LexStringState::InvalidQuote,
+            b'\'' if !self.options.json5 => LexStringState::InvalidQuote,
             _ => LexStringState::InString,
         };
 
@@ -540,6 +601,45 @@ impl<'src> Lexer<'src> {
                             self.advance(1);
                         }
 
+                        // A line continuation: the escaped line break isn't part of the string
+                        Some(b'\n' | b'\r') if self.options.json5 => {
+                            self.consume_newline();
+                        }
+
+                        Some(b'x') if self.options.json5 => {
+                            self.advance(1);
+                            for _ in 0..2 {
+                                match self.current_byte() {
+                                    Some(byte) if byte.is_ascii_hexdigit() => self.advance(1),
+                                    _ => {
+                                        if matches!(state, LexStringState::InString) {
+                                            self.diagnostics.push(
+                                                ParseDiagnostic::new(
+                                                    "Invalid hexadecimal escape sequence",
+                                                    escape_start..self.text_position(),
+                                                )
+                                                .with_hint("A hexadecimal escape sequence must consist of 2 hexadecimal numbers: `\\xXX`, e.g. `\\x2F` for '/'."),
+                                            );
+                                            state = LexStringState::InvalidEscapeSequence;
+                                        }
+                                        break;
+                                    }
+                                }
+                            }
+                        }
+
+                        // JSON5 allows escaping any character except the digits, but `\0` that isn't
+                        // followed by a digit
+                        Some(b'0')
+                            if self.options.json5
+                                && !matches!(self.peek_byte(), Some(b'0'..=b'9')) =>
+                        {
+                            self.advance(1)
+                        }
+                        Some(byte) if self.options.json5 && !byte.is_ascii_digit() => {
+                            self.advance_byte_or_char(byte)
+                        }
+
                         Some(_) => {
                             if matches!(state, LexStringState::InString) {
                                 let c = self.current_char_unchecked();
@@ -586,7 +686,12 @@ impl<'src> Lexer<'src> {
                 // * quotation mark: (U+0022),
                 // * reverse solidus (U+005C),
                 // * and the **control characters U+0000 to U+001F** <- This
-                ERR | WHS if matches!(state, LexStringState::InString) && chr <= 0x1f => {
+                // JSON5 allows the control characters
+                ERR | WHS
+                    if !self.options.json5
+                        && matches!(state, LexStringState::InString)
+                        && chr <= 0x1f =>
+                {
                     self.diagnostics.push(
                         ParseDiagnostic::new(
 
@@ -681,10 +786,13 @@ impl<'src> Lexer<'src> {
     }
 
     /// Implements basic lexing of identifiers without support for escape sequences.
-    /// This is merely for improved error recovery as identifiers are not valid in JSON.
+    /// Identifiers are only valid as member names in JSON5, and as `Infinity` and `NaN`
+    /// that are lexed as numbers. Otherwise, this is merely for improved error recovery.
     fn lex_identifier(&mut self, first: u8) -> JsonSyntaxKind {
         self.assert_at_char_boundary();
 
+        let start = self.position;
+
         let mut keyword = KeywordMatcher::from_byte(first);
 
         self.advance_byte_or_char(first);
@@ -715,6 +823,11 @@ impl<'src> Lexer<'src> {
             KeywordMatcher::Null => NULL_KW,
             KeywordMatcher::True => TRUE_KW,
             KeywordMatcher::False => FALSE_KW,
+            _ if self.options.json5
+                && matches!(&self.source[start..self.position], "Infinity" | "NaN") =>
+            {
+                JSON_NUMBER_LITERAL
+            }
             _ => IDENT,
         }
     }
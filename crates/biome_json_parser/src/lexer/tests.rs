@@ -2,6 +2,7 @@
 #![allow(unused_mut, unused_variables, unused_assignments)]
 
 use super::{Lexer, TextSize};
+use crate::JsonParserOptions;
 use biome_json_syntax::JsonSyntaxKind::{self, EOF};
 use quickcheck_macros::quickcheck;
 use std::sync::mpsc::channel;
@@ -11,8 +12,8 @@ use std::time::Duration;
 // Assert the result of lexing a piece of source code,
 // and make sure the tokens yielded are fully lossless and the source can be reconstructed from only the tokens
 macro_rules! assert_lex {
-    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
-        let mut lexer = Lexer::from_str($src);
+    (@lexer $lexer:expr, $src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
+        let mut lexer = $lexer;
         let mut idx = 0;
         let mut tok_idx = TextSize::default();
 
@@ -56,6 +57,17 @@ macro_rules! assert_lex {
 
         assert_eq!($src, new_str, "Failed to reconstruct input");
     }};
+    // Lexes the source as JSON5
+    (json5 $src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex!(
+            @lexer Lexer::from_str($src).with_options(JsonParserOptions::default().with_json5()),
+            $src,
+            $($kind:$len,)*
+        )
+    };
+    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex!(@lexer Lexer::from_str($src), $src, $($kind:$len,)*)
+    };
 }
 
 // This is for testing if the lexer is truly lossless
@@ -449,3 +461,124 @@ fn keywords() {
         assert_eq!(lexer.next_token().expect("Expected EOF token").kind, EOF);
     }
 }
+
+#[test]
+fn json5_numbers() {
+    assert_lex! {
+        json5 "0x1F",
+        JSON_NUMBER_LITERAL:4,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 "-0Xab",
+        JSON_NUMBER_LITERAL:5,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 ".5",
+        JSON_NUMBER_LITERAL:2,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 "5.e3",
+        JSON_NUMBER_LITERAL:4,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 "+1",
+        JSON_NUMBER_LITERAL:2,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 "Infinity",
+        JSON_NUMBER_LITERAL:8,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 "-NaN",
+        JSON_NUMBER_LITERAL:4,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_invalid_numbers() {
+    assert_lex! {
+        json5 "0x",
+        ERROR_TOKEN:2,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 "01",
+        ERROR_TOKEN:2,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 "+",
+        ERROR_TOKEN:1,
+        EOF:0
+    }
+
+    assert_lex! {
+        "+1",
+        ERROR_TOKEN:1,
+        JSON_NUMBER_LITERAL:1,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_strings() {
+    assert_lex! {
+        json5 r#"'single "quoted"'"#,
+        JSON_STRING_LITERAL:17,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 "'line \\\ncontinued'",
+        JSON_STRING_LITERAL:18,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 r#""\x41 \v \0 \'""#,
+        JSON_STRING_LITERAL:15,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 r#""\x4""#,
+        ERROR_TOKEN:5,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 r#""\1""#,
+        ERROR_TOKEN:4,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_identifiers() {
+    assert_lex! {
+        json5 "$unquoted_key1",
+        IDENT:14,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5 "Infinityx",
+        IDENT:9,
+        EOF:0
+    }
+}
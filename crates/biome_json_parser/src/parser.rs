@@ -16,6 +16,10 @@ pub(crate) struct JsonParser<'source> {
 pub struct JsonParserOptions {
     pub allow_comments: bool,
     pub allow_trailing_commas: bool,
+    /// Whether the source is parsed as [JSON5](https://spec.json5.org/): unquoted member
+    /// names, single-quoted and multiline strings, and the numbers of JavaScript
+    /// (`0x1F`, `.5`, `+1`, `Infinity`, `NaN`) are allowed.
+    pub json5: bool,
 }
 
 impl JsonParserOptions {
@@ -28,6 +32,14 @@ impl JsonParserOptions {
         self.allow_trailing_commas = true;
         self
     }
+
+    /// Parses the source as JSON5, which also allows comments and trailing commas
+    pub fn with_json5(mut self) -> Self {
+        self.allow_comments = true;
+        self.allow_trailing_commas = true;
+        self.json5 = true;
+        self
+    }
 }
 
 impl<'source> JsonParser<'source> {
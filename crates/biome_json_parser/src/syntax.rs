@@ -75,7 +75,12 @@ fn parse_value(p: &mut JsonParser) -> ParsedSyntax {
 
         IDENT => {
             let m = p.start();
-            p.error(p.err_builder("String values must be double quoted.", p.cur_range()));
+            let message = if p.options().json5 {
+                "String values must be quoted."
+            } else {
+                "String values must be double quoted."
+            };
+            p.error(p.err_builder(message, p.cur_range()));
             p.bump(IDENT);
             Present(m.complete(p, JSON_BOGUS_VALUE))
         }
@@ -282,16 +287,33 @@ fn parse_member_name(p: &mut JsonParser) -> ParsedSyntax {
             p.bump(JSON_STRING_LITERAL);
             Present(m.complete(p, JSON_MEMBER_NAME))
         }
+        IDENT if p.options().json5 => {
+            let m = p.start();
+            p.bump(IDENT);
+            Present(m.complete(p, JSON_MEMBER_NAME))
+        }
+        // The keywords, `Infinity` and `NaN` are valid member names in JSON5
+        T![null] | T![true] | T![false] | JSON_NUMBER_LITERAL
+            if p.options().json5 && is_identifier_name(p.cur_text()) =>
+        {
+            let m = p.start();
+            p.bump_remap(IDENT);
+            Present(m.complete(p, JSON_MEMBER_NAME))
+        }
         IDENT => {
             let m = p.start();
             p.error(p.err_builder("Property key must be double quoted", p.cur_range()));
             p.bump(IDENT);
-            Present(m.complete(p, JSON_MEMBER_NAME))
+            Present(m.complete(p, JSON_BOGUS))
         }
         _ => Absent,
     }
 }
 
+fn is_identifier_name(text: &str) -> bool {
+    matches!(text, "null" | "true" | "false" | "Infinity" | "NaN")
+}
+
 fn parse_array_element(p: &mut JsonParser) -> SequenceItem {
     match parse_sequence_value(p) {
         Ok(parsed) => SequenceItem::Parsed(parsed),
@@ -18,6 +18,7 @@ enum JsonVariant {
     #[default]
     Standard,
     Jsonc,
+    Json5,
 }
 
 impl JsonFileSource {
@@ -35,6 +36,13 @@ impl JsonFileSource {
         }
     }
 
+    pub fn json5() -> Self {
+        Self {
+            variant: JsonVariant::Json5,
+            allow_trailing_comma: false,
+        }
+    }
+
     pub fn with_trailing_comma(mut self, option_value: bool) -> Self {
         self.allow_trailing_comma = option_value;
         self
@@ -51,6 +59,10 @@ impl JsonFileSource {
     pub const fn is_jsonc(&self) -> bool {
         matches!(self.variant, JsonVariant::Jsonc)
     }
+
+    pub const fn is_json5(&self) -> bool {
+        matches!(self.variant, JsonVariant::Json5)
+    }
 }
 
 impl TryFrom<&Path> for JsonFileSource {
@@ -84,6 +96,7 @@ fn compute_source_type_from_path_or_extension(
         match extension {
             "json" => JsonFileSource::json(),
             "jsonc" => JsonFileSource::jsonc(),
+            "json5" => JsonFileSource::json5(),
             _ => {
                 return Err(FileSourceError::UnknownExtension(
                     file_name.into(),
@@ -1,4 +1,8 @@
 pub(crate) mod analysis;
+pub(crate) mod completion;
+pub(crate) mod diagnostics;
 pub(crate) mod formatting;
+pub(crate) mod ranges;
 pub(crate) mod rename;
+pub(crate) mod semantic_tokens;
 pub(crate) mod text_document;
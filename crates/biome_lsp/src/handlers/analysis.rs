@@ -1,5 +1,5 @@
-use crate::converters::from_proto;
 use crate::converters::line_index::LineIndex;
+use crate::converters::{from_proto, to_proto};
 use crate::session::Session;
 use crate::utils;
 use anyhow::{Context, Result};
@@ -7,10 +7,13 @@ use biome_analyze::{ActionCategory, SourceActionKind};
 use biome_diagnostics::Applicability;
 use biome_fs::BiomePath;
 use biome_rowan::{TextRange, TextSize};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::configuration::linter::disable_rule_edit;
+use biome_service::file_handlers::{
+    AstroFileHandler, DocumentFileSource, SvelteFileHandler, VueFileHandler,
+};
 use biome_service::workspace::{
     FeatureName, FeaturesBuilder, FixFileMode, FixFileParams, GetFileContentParams,
-    PullActionsParams, SupportsFeatureParams,
+    PullActionsParams, RuleCode, SupportsFeatureParams,
 };
 use biome_service::WorkspaceError;
 use std::borrow::Cow;
@@ -139,6 +142,34 @@ pub(crate) fn code_actions(
         None
     };
 
+    // Generate the actions that apply to every diagnostic of a rule: fixing all of them,
+    // suppressing the rule in the file, and disabling the rule in the configuration.
+    // They're generated for the rules that emitted a diagnostic under the cursor, the
+    // ones that can be suppressed. They aren't quick-fixes that can be applied on save
+    let rule_actions = if has_quick_fix {
+        Vec::new()
+    } else {
+        let mut rules: Vec<_> = result
+            .actions
+            .iter()
+            .filter(|action| action.category.matches("quickfix.suppressRule"))
+            .filter_map(|action| action.rule_name.clone())
+            .collect();
+        rules.sort_unstable();
+        rules.dedup();
+        rule_actions(
+            session,
+            &url,
+            &biome_path,
+            &doc.line_index,
+            &content,
+            &diagnostics,
+            &rules,
+            offset,
+            &filters,
+        )?
+    };
+
     let mut has_fixes = false;
 
     let mut actions: Vec<_> = result
@@ -175,6 +206,7 @@ pub(crate) fn code_actions(
             Some(CodeActionOrCommand::CodeAction(action))
         })
         .chain(fix_all)
+        .chain(rule_actions)
         .collect();
 
     // If any actions is marked as fixing a diagnostic, hide other actions
@@ -215,6 +247,7 @@ fn fix_all(
         path: biome_path,
         fix_file_mode: FixFileMode::SafeFixes,
         should_format,
+        only_rule: None,
     })?;
 
     if fixed.actions.is_empty() {
@@ -296,3 +329,202 @@ fn fix_all(
         data: None,
     })))
 }
+
+const FIX_ALL_RULE_KIND: &str = "quickfix.fixAllRule.biome";
+const SUPPRESS_RULE_IN_FILE_KIND: &str = "quickfix.suppressRuleInFile.biome";
+const DISABLE_RULE_KIND: &str = "quickfix.disableRule.biome";
+
+/// Generates the code actions of `rules` for the `diagnostics` they emitted
+///
+/// For each rule, the actions are:
+/// - fixing all the diagnostics of the rule in the document, when the rule has fixes;
+/// - suppressing the rule in the whole document with a `biome-ignore-all` comment;
+/// - disabling the rule in the configuration file.
+#[allow(clippy::too_many_arguments)]
+fn rule_actions(
+    session: &Session,
+    url: &lsp::Url,
+    biome_path: &BiomePath,
+    line_index: &LineIndex,
+    content: &str,
+    diagnostics: &[lsp::Diagnostic],
+    rules: &[(Cow<'static, str>, Cow<'static, str>)],
+    offset: Option<u32>,
+    filters: &[&str],
+) -> Result<Vec<CodeActionOrCommand>> {
+    let matches_filters = |kind: &str| {
+        filters.is_empty()
+            || filters
+                .iter()
+                .any(|filter| kind == *filter || kind.starts_with(&format!("{filter}.")))
+    };
+    let document_file_source = DocumentFileSource::from_path(biome_path);
+    let configuration_file = session.configuration_file(biome_path);
+
+    let mut actions = Vec::new();
+    for (group, rule) in rules {
+        let code = format!("lint/{group}/{rule}");
+        let rule_diagnostics: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.code == Some(lsp::NumberOrString::String(code.clone())))
+            .cloned()
+            .collect();
+        if rule_diagnostics.is_empty() {
+            continue;
+        }
+
+        let kind = format!("{FIX_ALL_RULE_KIND}.{group}.{rule}");
+        if matches_filters(&kind) {
+            let fixed = session.workspace.fix_file(FixFileParams {
+                path: biome_path.clone(),
+                fix_file_mode: FixFileMode::SafeFixes,
+                should_format: false,
+                only_rule: Some(RuleCode {
+                    group: group.to_string(),
+                    rule: rule.to_string(),
+                }),
+            })?;
+            if !fixed.actions.is_empty() {
+                let edit = lsp::TextEdit {
+                    range: lsp::Range {
+                        start: lsp::Position::new(0, 0),
+                        end: lsp::Position::new(line_index.len(), 0),
+                    },
+                    new_text: fixed.code,
+                };
+                actions.push(rule_action(
+                    format!("Fix all {rule} issues in this file"),
+                    kind,
+                    &rule_diagnostics,
+                    url.clone(),
+                    edit,
+                ));
+            }
+        }
+
+        let kind = format!("{SUPPRESS_RULE_IN_FILE_KIND}.{group}.{rule}");
+        let comment = match document_file_source {
+            DocumentFileSource::Js(_) => {
+                Some(format!("// biome-ignore-all {code}: <explanation>\n"))
+            }
+            DocumentFileSource::Css(_) => {
+                Some(format!("/* biome-ignore-all {code}: <explanation> */\n"))
+            }
+            _ => None,
+        };
+        if let Some(comment) = comment.filter(|_| matches_filters(&kind)) {
+            let position = to_proto::position(
+                line_index,
+                file_suppression_offset(content, offset.unwrap_or_default()),
+                session.position_encoding(),
+            )?;
+            let edit = lsp::TextEdit {
+                range: lsp::Range::new(position, position),
+                new_text: comment,
+            };
+            actions.push(rule_action(
+                format!("Suppress rule {code} for the whole file"),
+                kind,
+                &rule_diagnostics,
+                url.clone(),
+                edit,
+            ));
+        }
+
+        let kind = format!("{DISABLE_RULE_KIND}.{group}.{rule}");
+        let Some((config_path, config_content)) = configuration_file
+            .as_ref()
+            .filter(|_| matches_filters(&kind))
+        else {
+            continue;
+        };
+        let Some((range, new_text)) = disable_rule_edit(config_content, config_path, group, rule)
+        else {
+            continue;
+        };
+        let Ok(config_url) = lsp::Url::from_file_path(config_path) else {
+            continue;
+        };
+        let config_line_index = LineIndex::new(config_content);
+        let edit = lsp::TextEdit {
+            range: to_proto::range(&config_line_index, range, session.position_encoding())?,
+            new_text,
+        };
+        actions.push(rule_action(
+            format!("Disable rule {code} in the configuration"),
+            kind,
+            &rule_diagnostics,
+            config_url,
+            edit,
+        ));
+    }
+
+    Ok(actions)
+}
+
+/// Returns the offset where the comment that suppresses a rule in the whole
+/// file is inserted, for a script starting at `start`
+///
+/// The comment goes after the shebang and the comments that start the file,
+/// like a license header or a `// @ts-check` directive, which must stay first.
+fn file_suppression_offset(content: &str, start: u32) -> TextSize {
+    let start = start as usize;
+    let mut rest = &content[start..];
+    if rest.starts_with("#!") {
+        rest = rest.find('\n').map_or("", |index| &rest[index + 1..]);
+    }
+    let mut insertion = content.len() - rest.len();
+
+    loop {
+        let trimmed = rest.trim_start();
+        let comment_end = if trimmed.starts_with("//") {
+            trimmed.find('\n').unwrap_or(trimmed.len())
+        } else if trimmed.starts_with("/*") {
+            match trimmed.find("*/") {
+                Some(index) => index + 2,
+                None => break,
+            }
+        } else {
+            break;
+        };
+
+        // The comment is followed by code on the same line
+        let after_comment = &trimmed[comment_end..];
+        let line_end = after_comment.find('\n');
+        let trailing = line_end.map_or(after_comment, |index| &after_comment[..index]);
+        if !trailing.trim().is_empty() {
+            break;
+        }
+
+        rest = line_end.map_or("", |index| &after_comment[index + 1..]);
+        insertion = content.len() - rest.len();
+    }
+
+    TextSize::from(insertion as u32)
+}
+
+fn rule_action(
+    title: String,
+    kind: String,
+    diagnostics: &[lsp::Diagnostic],
+    url: lsp::Url,
+    edit: lsp::TextEdit,
+) -> CodeActionOrCommand {
+    let mut changes = HashMap::new();
+    changes.insert(url, vec![edit]);
+
+    CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title,
+        kind: Some(CodeActionKind::from(kind)),
+        diagnostics: Some(diagnostics.to_vec()),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(false),
+        disabled: None,
+        data: None,
+    })
+}
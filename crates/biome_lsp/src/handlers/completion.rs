@@ -0,0 +1,57 @@
+use crate::converters::from_proto;
+use crate::session::Session;
+use anyhow::{Context, Result};
+use biome_service::workspace::GetCompletionsParams;
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, Documentation,
+};
+
+/// Handler for the `textDocument/completion` request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn completion(
+    session: &Session,
+    params: CompletionParams,
+) -> Result<Option<CompletionResponse>> {
+    let url = params.text_document_position.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let offset = from_proto::offset(
+        &doc.line_index,
+        params.text_document_position.position,
+        position_encoding,
+    )
+    .with_context(|| {
+        format!(
+            "failed to access position {:?} in document {url}",
+            params.text_document_position.position
+        )
+    })?;
+
+    let result = match session.workspace.get_completions(GetCompletionsParams {
+        path: biome_path,
+        offset,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let items = result
+        .items
+        .into_iter()
+        .map(|item| CompletionItem {
+            label: item.label,
+            kind: Some(CompletionItemKind::PROPERTY),
+            insert_text: Some(item.insert_text),
+            documentation: item.documentation.map(Documentation::String),
+            ..Default::default()
+        })
+        .collect();
+
+    Ok(Some(CompletionResponse::Array(items)))
+}
@@ -0,0 +1,119 @@
+use crate::session::Session;
+use anyhow::Result;
+use rustc_hash::FxHashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tower_lsp::lsp_types::{
+    DocumentDiagnosticParams, DocumentDiagnosticReport, DocumentDiagnosticReportResult,
+    FullDocumentDiagnosticReport, RelatedFullDocumentDiagnosticReport,
+    RelatedUnchangedDocumentDiagnosticReport, UnchangedDocumentDiagnosticReport, Url,
+    WorkspaceDiagnosticParams, WorkspaceDiagnosticReport, WorkspaceDiagnosticReportResult,
+    WorkspaceDocumentDiagnosticReport, WorkspaceFullDocumentDiagnosticReport,
+    WorkspaceUnchangedDocumentDiagnosticReport,
+};
+use tracing::error;
+
+/// Handler for the `textDocument/diagnostic` request
+///
+/// The file isn't analyzed again when the client already has its diagnostics,
+/// that is when `previous_result_id` is the result id of its current content.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_diagnostic(
+    session: &Session,
+    params: DocumentDiagnosticParams,
+) -> Result<DocumentDiagnosticReportResult> {
+    let url = params.text_document.uri;
+    let content = session.file_content(&url)?;
+    let result_id = session.diagnostics_result_id(&content);
+
+    if params.previous_result_id.as_ref() == Some(&result_id) {
+        return Ok(DocumentDiagnosticReportResult::Report(
+            DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+                related_documents: None,
+                unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                    result_id,
+                },
+            }),
+        ));
+    }
+
+    let items = session.file_diagnostics(&url, content)?;
+
+    Ok(DocumentDiagnosticReportResult::Report(
+        DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+            related_documents: None,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                result_id: Some(result_id),
+                items,
+            },
+        }),
+    ))
+}
+
+/// Handler for the `workspace/diagnostic` request
+///
+/// It reports the diagnostics of every file of the workspace folders, including
+/// the files that aren't open. The files are found with the same rules as the CLI:
+/// the files ignored by the configuration or by the VCS are skipped.
+///
+/// The files whose result id is in `previous_result_ids` aren't analyzed again, they're
+/// reported as unchanged. The analysis stops as soon as `cancelled` is set, and the
+/// files analyzed so far are reported.
+#[tracing::instrument(level = "debug", skip(session, cancelled), err)]
+pub(crate) fn workspace_diagnostic(
+    session: &Session,
+    params: WorkspaceDiagnosticParams,
+    cancelled: &AtomicBool,
+) -> Result<WorkspaceDiagnosticReportResult> {
+    let previous_result_ids: FxHashMap<_, _> = params
+        .previous_result_ids
+        .into_iter()
+        .map(|previous| (previous.uri, previous.value))
+        .collect();
+
+    let items = session
+        .workspace_files()
+        .iter()
+        .take_while(|_| !cancelled.load(Ordering::Relaxed))
+        .filter_map(|path| {
+            let url = Url::from_file_path(path).ok()?;
+            let version = session.document(&url).ok().map(|doc| doc.version as i64);
+            let report = session.file_content(&url).and_then(|content| {
+                let result_id = session.diagnostics_result_id(&content);
+                if previous_result_ids.get(&url) == Some(&result_id) {
+                    return Ok(WorkspaceDocumentDiagnosticReport::Unchanged(
+                        WorkspaceUnchangedDocumentDiagnosticReport {
+                            uri: url.clone(),
+                            version,
+                            unchanged_document_diagnostic_report:
+                                UnchangedDocumentDiagnosticReport { result_id },
+                        },
+                    ));
+                }
+
+                let items = session.file_diagnostics(&url, content)?;
+                Ok(WorkspaceDocumentDiagnosticReport::Full(
+                    WorkspaceFullDocumentDiagnosticReport {
+                        uri: url.clone(),
+                        version,
+                        full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                            result_id: Some(result_id),
+                            items,
+                        },
+                    },
+                ))
+            });
+
+            match report {
+                Ok(report) => Some(report),
+                Err(err) => {
+                    error!("Failed to compute the diagnostics of {}: {}", url, err);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    Ok(WorkspaceDiagnosticReportResult::Report(
+        WorkspaceDiagnosticReport { items },
+    ))
+}
@@ -0,0 +1,113 @@
+use crate::converters::{from_proto, to_proto};
+use crate::session::Session;
+use anyhow::Result;
+use biome_service::workspace::{
+    self, GetFoldingRangesParams, GetSelectionRangesParams, GetSelectionRangesResult,
+};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    FoldingRange, FoldingRangeKind, FoldingRangeParams, SelectionRange, SelectionRangeParams,
+};
+
+/// Handler for the `textDocument/foldingRange` request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn folding_range(
+    session: &Session,
+    params: FoldingRangeParams,
+) -> Result<Option<Vec<FoldingRange>>> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let result = match session
+        .workspace
+        .get_folding_ranges(GetFoldingRangesParams { path: biome_path })
+    {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut ranges = Vec::with_capacity(result.ranges.len());
+    for folding_range in result.ranges {
+        let range = to_proto::range(&doc.line_index, folding_range.range, position_encoding)?;
+        let (kind, end_line) = match folding_range.kind {
+            // The line of the closing delimiter stays visible
+            workspace::FoldingRangeKind::Region => (None, range.end.line.saturating_sub(1)),
+            workspace::FoldingRangeKind::Comment => {
+                (Some(FoldingRangeKind::Comment), range.end.line)
+            }
+            workspace::FoldingRangeKind::Imports => {
+                (Some(FoldingRangeKind::Imports), range.end.line)
+            }
+        };
+        if end_line <= range.start.line {
+            continue;
+        }
+
+        ranges.push(FoldingRange {
+            start_line: range.start.line,
+            end_line,
+            kind,
+            ..Default::default()
+        });
+    }
+
+    Ok(Some(ranges))
+}
+
+/// Handler for the `textDocument/selectionRange` request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn selection_range(
+    session: &Session,
+    params: SelectionRangeParams,
+) -> Result<Option<Vec<SelectionRange>>> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let offsets = params
+        .positions
+        .iter()
+        .map(|position| from_proto::offset(&doc.line_index, *position, position_encoding))
+        .collect::<Result<Vec<_>>>()?;
+
+    let GetSelectionRangesResult { ranges } =
+        match session
+            .workspace
+            .get_selection_ranges(GetSelectionRangesParams {
+                path: biome_path,
+                offsets,
+            }) {
+            Ok(result) => result,
+            Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+                return Ok(None);
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+    let mut selection_ranges = Vec::with_capacity(ranges.len());
+    for (position, ranges) in params.positions.into_iter().zip(ranges) {
+        // Each range is the parent of the previous one: the list is built from the outermost range
+        let mut selection_range = None;
+        for range in ranges.into_iter().rev() {
+            selection_range = Some(SelectionRange {
+                range: to_proto::range(&doc.line_index, range, position_encoding)?,
+                parent: selection_range.map(Box::new),
+            });
+        }
+
+        // The protocol requires a range for each position, an empty range is
+        // returned when the position isn't inside a node
+        selection_ranges.push(selection_range.unwrap_or(SelectionRange {
+            range: tower_lsp::lsp_types::Range::new(position, position),
+            parent: None,
+        }));
+    }
+
+    Ok(Some(selection_ranges))
+}
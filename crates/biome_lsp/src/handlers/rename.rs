@@ -1,9 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::converters::from_proto;
+use crate::converters::line_index::LineIndex;
 use crate::{session::Session, utils};
 use anyhow::{Context, Result};
-use tower_lsp::lsp_types::{RenameParams, WorkspaceEdit};
+use biome_fs::{BiomePath, OpenOptions};
+use biome_service::file_handlers::DocumentFileSource;
+use biome_service::workspace::RenameImportsParams;
+use tower_lsp::lsp_types::{RenameParams, TextEdit, Url, WorkspaceEdit};
 use tracing::trace;
 
 #[tracing::instrument(level = "debug", skip(session), err)]
@@ -30,9 +34,9 @@ pub(crate) fn rename(session: &Session, params: RenameParams) -> Result<Option<W
     let result = session
         .workspace
         .rename(biome_service::workspace::RenameParams {
-            path: biome_path,
+            path: biome_path.clone(),
             symbol_at: cursor_range,
-            new_name: params.new_name,
+            new_name: params.new_name.clone(),
         })?;
 
     let mut changes = HashMap::new();
@@ -41,6 +45,17 @@ pub(crate) fn rename(session: &Session, params: RenameParams) -> Result<Option<W
         utils::text_edit(&doc.line_index, result.indels, position_encoding, None)?,
     );
 
+    // The binding is exported with its name: the files that import it are renamed too
+    if let Some(exported_name) = result.exported_name {
+        rename_imports(
+            session,
+            biome_path,
+            &exported_name,
+            &params.new_name,
+            &mut changes,
+        )?;
+    }
+
     let workspace_edit = WorkspaceEdit {
         changes: Some(changes),
         document_changes: None,
@@ -49,3 +64,112 @@ pub(crate) fn rename(session: &Session, params: RenameParams) -> Result<Option<W
 
     Ok(Some(workspace_edit))
 }
+
+/// Renames the imports of the binding `exported_name` of `module` in the files of the
+/// workspace. When a file re-exports the binding, the files that import it from this
+/// file are renamed as well.
+fn rename_imports(
+    session: &Session,
+    module: BiomePath,
+    exported_name: &str,
+    new_name: &str,
+    changes: &mut HashMap<Url, Vec<TextEdit>>,
+) -> Result<()> {
+    let workspace_files = session.workspace_files();
+    let files: Vec<_> = workspace_files
+        .iter()
+        .filter(|path| {
+            let is_embedded = path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| matches!(extension, "vue" | "astro" | "svelte"));
+            !is_embedded
+                && matches!(
+                    DocumentFileSource::from_path(path),
+                    DocumentFileSource::Js(_)
+                )
+        })
+        .collect();
+
+    // The files that aren't open in the client are read from the disk once, and only those
+    // that can import or re-export the binding are kept
+    let mut closed_files = HashMap::new();
+    for path in &files {
+        let Ok(url) = Url::from_file_path(path) else {
+            continue;
+        };
+        if session.document(&url).is_ok() {
+            continue;
+        }
+        let mut content = String::new();
+        session
+            .fs
+            .open_with_options(path, OpenOptions::default().read(true))?
+            .read_to_string(&mut content)?;
+        if content.contains(exported_name) || re_exports_all(&content) {
+            closed_files.insert(*path, content);
+        }
+    }
+
+    let mut modules = vec![module];
+    let mut visited = HashSet::new();
+    while let Some(module) = modules.pop() {
+        if !visited.insert(module.to_path_buf()) {
+            continue;
+        }
+
+        for path in &files {
+            if *path == &*module {
+                continue;
+            }
+            let Ok(url) = Url::from_file_path(path) else {
+                continue;
+            };
+            let biome_path = BiomePath::new(path);
+            let mut params = RenameImportsParams {
+                path: biome_path.clone(),
+                module: module.clone(),
+                exported_name: exported_name.to_string(),
+                new_name: new_name.to_string(),
+                content: None,
+            };
+
+            let (result, line_index) = if let Ok(doc) = session.document(&url) {
+                (session.workspace.rename_imports(params)?, doc.line_index)
+            } else if let Some(content) = closed_files.get(path) {
+                params.content = Some(content.clone());
+                (
+                    session.workspace.rename_imports(params)?,
+                    LineIndex::new(content),
+                )
+            } else {
+                continue;
+            };
+
+            if result.re_exported {
+                modules.push(biome_path);
+            }
+            if !result.indels.is_empty() {
+                let edits = utils::text_edit(
+                    &line_index,
+                    result.indels,
+                    session.position_encoding(),
+                    None,
+                )?;
+                changes.entry(url).or_default().extend(edits);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `content` may contain an `export * from "..."` clause, which re-exports the
+/// bindings of a module without naming them
+fn re_exports_all(content: &str) -> bool {
+    content.match_indices("export").any(|(index, export)| {
+        content[index + export.len()..]
+            .trim_start()
+            .starts_with('*')
+    })
+}
@@ -0,0 +1,142 @@
+use crate::converters::line_index::LineIndex;
+use crate::converters::{from_proto, to_proto, PositionEncoding};
+use crate::session::Session;
+use anyhow::Result;
+use biome_service::workspace::{self, SemanticTokenKind};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens, SemanticTokensLegend,
+    SemanticTokensParams, SemanticTokensRangeParams, SemanticTokensRangeResult,
+    SemanticTokensResult, Url,
+};
+
+/// The token types of the legend, the index of a type is its identifier in the tokens
+const TOKEN_TYPES: [SemanticTokenType; 4] = [
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::CLASS,
+    SemanticTokenType::TYPE_PARAMETER,
+];
+
+/// The token modifiers of the legend, the index of a modifier is its bit in the tokens
+const TOKEN_MODIFIERS: [SemanticTokenModifier; 5] = [
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::DEFAULT_LIBRARY,
+    SemanticTokenModifier::new("imported"),
+    SemanticTokenModifier::new("unresolved"),
+];
+
+const DECLARATION: u32 = 1 << 0;
+const READONLY: u32 = 1 << 1;
+const DEFAULT_LIBRARY: u32 = 1 << 2;
+const IMPORTED: u32 = 1 << 3;
+const UNRESOLVED: u32 = 1 << 4;
+
+pub(crate) fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// Handler for the `textDocument/semanticTokens/full` request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_full(
+    session: &Session,
+    params: SemanticTokensParams,
+) -> Result<Option<SemanticTokensResult>> {
+    let tokens = semantic_tokens(session, &params.text_document.uri, None)?;
+    Ok(tokens.map(SemanticTokensResult::Tokens))
+}
+
+/// Handler for the `textDocument/semanticTokens/range` request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_range(
+    session: &Session,
+    params: SemanticTokensRangeParams,
+) -> Result<Option<SemanticTokensRangeResult>> {
+    let tokens = semantic_tokens(session, &params.text_document.uri, Some(params.range))?;
+    Ok(tokens.map(SemanticTokensRangeResult::Tokens))
+}
+
+fn semantic_tokens(
+    session: &Session,
+    url: &Url,
+    range: Option<tower_lsp::lsp_types::Range>,
+) -> Result<Option<SemanticTokens>> {
+    let biome_path = session.file_path(url)?;
+    let doc = session.document(url)?;
+    let position_encoding = session.position_encoding();
+    let range = range
+        .map(|range| from_proto::text_range(&doc.line_index, range, position_encoding))
+        .transpose()?;
+
+    let result = match session
+        .workspace
+        .semantic_tokens(workspace::SemanticTokensParams {
+            path: biome_path,
+            range,
+        }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let data = encode_tokens(&doc.line_index, position_encoding, result.tokens)?;
+    Ok(Some(SemanticTokens {
+        result_id: None,
+        data,
+    }))
+}
+
+/// Encodes the tokens relatively to the previous token, as required by the protocol
+fn encode_tokens(
+    line_index: &LineIndex,
+    position_encoding: PositionEncoding,
+    tokens: Vec<workspace::SemanticToken>,
+) -> Result<Vec<SemanticToken>> {
+    let mut data = Vec::with_capacity(tokens.len());
+    let mut previous_line = 0;
+    let mut previous_start = 0;
+    for token in tokens {
+        let range = to_proto::range(line_index, token.range, position_encoding)?;
+        let (token_type, mut token_modifiers_bitset) = match token.kind {
+            SemanticTokenKind::Parameter => (0, 0),
+            SemanticTokenKind::Variable => (1, 0),
+            SemanticTokenKind::Import => (1, IMPORTED),
+            SemanticTokenKind::Global => (1, DEFAULT_LIBRARY),
+            SemanticTokenKind::Class => (2, 0),
+            SemanticTokenKind::TypeParameter => (3, 0),
+            SemanticTokenKind::Unresolved => (1, UNRESOLVED),
+        };
+        if token.is_declaration {
+            token_modifiers_bitset |= DECLARATION;
+        }
+        if token.is_readonly {
+            token_modifiers_bitset |= READONLY;
+        }
+
+        let delta_line = range.start.line - previous_line;
+        let delta_start = if delta_line == 0 {
+            range.start.character - previous_start
+        } else {
+            range.start.character
+        };
+        data.push(SemanticToken {
+            delta_line,
+            delta_start,
+            // The identifiers don't span several lines
+            length: range.end.character - range.start.character,
+            token_type,
+            token_modifiers_bitset,
+        });
+
+        previous_line = range.start.line;
+        previous_start = range.start.character;
+    }
+
+    Ok(data)
+}
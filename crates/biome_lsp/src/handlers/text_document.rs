@@ -22,6 +22,8 @@ pub(crate) async fn did_open(
     let biome_path = session.file_path(&url)?;
     let doc = Document::new(version, &content);
 
+    session.load_nested_configurations(&biome_path).await;
+
     session.workspace.open_file(OpenFileParams {
         path: biome_path,
         version,
@@ -92,6 +94,8 @@ pub(crate) async fn did_close(
         .close_file(CloseFileParams { path: biome_path })?;
 
     session.remove_document(&url);
+    // The diagnostics of the file are now computed from its content on the disk
+    session.invalidate_diagnostics();
 
     let diagnostics = vec![];
     let version = None;
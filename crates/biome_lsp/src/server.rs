@@ -128,31 +128,44 @@ impl LSPServer {
             },
         );
 
+        let base_paths = self.session.workspace_folders();
+        let watchers: Vec<_> = base_paths
+            .iter()
+            .flat_map(|base_path| {
+                [
+                    FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!(
+                            "{}/biome.json",
+                            base_path.display()
+                        )),
+                        kind: Some(WatchKind::all()),
+                    },
+                    // TODO: Biome 2.0 remove it
+                    FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!(
+                            "{}/rome.json",
+                            base_path.display()
+                        )),
+                        kind: Some(WatchKind::all()),
+                    },
+                    // The files of the workspace are collected again when files are created or deleted,
+                    // and their pulled diagnostics are checked again when they change
+                    FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!("{}/**", base_path.display())),
+                        kind: Some(WatchKind::all()),
+                    },
+                ]
+            })
+            .collect();
         capabilities.add_capability(
             "biome_did_change_workspace_settings",
             "workspace/didChangeWatchedFiles",
-            if let Some(base_path) = self.session.base_path() {
+            if watchers.is_empty() {
+                CapabilityStatus::Disable
+            } else {
                 CapabilityStatus::Enable(Some(json!(DidChangeWatchedFilesRegistrationOptions {
-                    watchers: vec![
-                        FileSystemWatcher {
-                            glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.json",
-                                base_path.display()
-                            )),
-                            kind: Some(WatchKind::all()),
-                        },
-                        // TODO: Biome 2.0 remove it
-                        FileSystemWatcher {
-                            glob_pattern: GlobPattern::String(format!(
-                                "{}/rome.json",
-                                base_path.display()
-                            )),
-                            kind: Some(WatchKind::all()),
-                        }
-                    ],
+                    watchers
                 })))
-            } else {
-                CapabilityStatus::Disable
             },
         );
 
@@ -248,16 +261,13 @@ impl LanguageServer for LSPServer {
                 version: client_info.version,
             }),
             params.root_uri,
+            params.workspace_folders,
         );
 
         if params.root_path.is_some() {
             warn!("The Biome Server was initialized with the deprecated `root_path` parameter: this is not supported, use `root_uri` instead");
         }
 
-        if params.workspace_folders.is_some() {
-            warn!("The Biome Server was initialized with the `workspace_folders` parameter: this is unsupported at the moment, use `root_uri` instead");
-        }
-
         //
         let init = InitializeResult {
             capabilities: server_capabilities,
@@ -309,6 +319,17 @@ impl LanguageServer for LSPServer {
 
     #[tracing::instrument(level = "trace", skip(self))]
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        if params.changes.iter().any(|change| {
+            matches!(
+                change.typ,
+                FileChangeType::CREATED | FileChangeType::DELETED
+            )
+        }) {
+            self.session.invalidate_workspace_files();
+        } else {
+            self.session.invalidate_diagnostics();
+        }
+
         let file_paths = params
             .changes
             .iter()
@@ -316,23 +337,24 @@ impl LanguageServer for LSPServer {
         for file_path in file_paths {
             match file_path {
                 Ok(file_path) => {
-                    let base_path = self.session.base_path();
-                    if let Some(base_path) = base_path {
-                        let possible_rome_json = file_path.strip_prefix(&base_path);
-                        if let Ok(possible_rome_json) = possible_rome_json {
-                            if possible_rome_json.display().to_string() == ROME_JSON
-                                || ConfigName::file_names()
-                                    .contains(&&*possible_rome_json.display().to_string())
-                            {
-                                self.session.load_workspace_settings().await;
-                                self.session.load_manifest().await;
-                                self.setup_capabilities().await;
-                                self.session.update_all_diagnostics().await;
-                                // for now we are only interested to the configuration file,
-                                // so it's OK to exist the loop
-                                break;
-                            }
-                        }
+                    let base_paths = self.session.workspace_folders();
+                    let is_configuration_file = base_paths.iter().any(|base_path| {
+                        file_path
+                            .strip_prefix(base_path)
+                            .is_ok_and(|possible_rome_json| {
+                                possible_rome_json.display().to_string() == ROME_JSON
+                                    || ConfigName::file_names()
+                                        .contains(&&*possible_rome_json.display().to_string())
+                            })
+                    });
+                    if is_configuration_file {
+                        self.session.load_workspace_settings().await;
+                        self.session.load_manifest().await;
+                        self.setup_capabilities().await;
+                        self.session.update_all_diagnostics().await;
+                        // for now we are only interested to the configuration file,
+                        // so it's OK to exist the loop
+                        break;
                     }
                 }
                 Err(_) => {
@@ -343,6 +365,16 @@ impl LanguageServer for LSPServer {
         }
     }
 
+    #[tracing::instrument(level = "trace", skip(self))]
+    async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        self.session
+            .update_workspace_folders(params.event.added, params.event.removed);
+        self.session.load_workspace_settings().await;
+        self.session.load_manifest().await;
+        self.setup_capabilities().await;
+        self.session.update_all_diagnostics().await;
+    }
+
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
         handlers::text_document::did_open(&self.session, params)
             .await
@@ -368,6 +400,107 @@ impl LanguageServer for LSPServer {
         .map_err(into_lsp_error)?
     }
 
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> LspResult<DocumentDiagnosticReportResult> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::diagnostics::document_diagnostic(&self.session, params)
+                .map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> LspResult<WorkspaceDiagnosticReportResult> {
+        // Subscribed before the analysis, so the changes made during the analysis aren't missed
+        let mut changes = self.session.subscribe_workspace_changes();
+        loop {
+            // The whole workspace is traversed and analyzed, so it runs on the blocking
+            // thread pool instead of the task serving the requests. The analysis stops
+            // when the request is cancelled, because this future is dropped.
+            let session = self.session.clone();
+            let cancelled = CancelOnDrop::default();
+            let flag = cancelled.0.clone();
+            let request = params.clone();
+            let result = spawn_blocking(move || {
+                handlers::diagnostics::workspace_diagnostic(&session, request, &flag)
+            })
+            .await;
+
+            let result = match result {
+                Ok(Ok(result)) => result,
+                Ok(Err(err)) => return Err(into_lsp_error(err)),
+                Err(err) => match err.try_into_panic() {
+                    Ok(err) => return Err(panic_to_lsp_error(err)),
+                    Err(err) => return Err(into_lsp_error(err)),
+                },
+            };
+
+            // When nothing changed since the previous request, the request is kept open until
+            // something changes, instead of letting the client ask again straight away
+            let is_unchanged = !params.previous_result_ids.is_empty()
+                && matches!(&result, WorkspaceDiagnosticReportResult::Report(report) if report
+                    .items
+                    .iter()
+                    .all(|item| matches!(item, WorkspaceDocumentDiagnosticReport::Unchanged(_))));
+            if !is_unchanged || changes.changed().await.is_err() {
+                return Ok(result);
+            }
+        }
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> LspResult<Option<SemanticTokensResult>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_full(&self.session, params)
+                .map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> LspResult<Option<SemanticTokensRangeResult>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_range(&self.session, params)
+                .map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn completion(&self, params: CompletionParams) -> LspResult<Option<CompletionResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::completion::completion(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> LspResult<Option<Vec<FoldingRange>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::folding_range(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> LspResult<Option<Vec<SelectionRange>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::selection_range(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
     async fn formatting(
         &self,
         params: DocumentFormattingParams,
@@ -437,6 +570,17 @@ impl Drop for LSPServer {
     }
 }
 
+/// Flag set when it's dropped, used to stop the work of a request running on
+/// the blocking thread pool once the request is cancelled
+#[derive(Default)]
+struct CancelOnDrop(Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
 /// Map of active sessions connected to a [ServerFactory].
 type Sessions = Arc<Mutex<FxHashMap<SessionKey, SessionHandle>>>;
 
@@ -528,6 +672,17 @@ impl ServerFactory {
             .clone()
             .unwrap_or_else(workspace::server_sync);
 
+        self.create_with_workspace(config_path, fs, workspace)
+    }
+
+    /// Create a new [ServerConnection] from this factory whose session reads
+    /// the files through `fs` and forwards its requests to `workspace`
+    pub fn create_with_workspace(
+        &self,
+        config_path: Option<PathBuf>,
+        fs: DynRef<'static, dyn FileSystem>,
+        workspace: Arc<dyn Workspace>,
+    ) -> ServerConnection {
         let session_key = SessionKey(self.next_session_key.fetch_add(1, Ordering::Relaxed));
 
         let mut builder = LspService::build(move |client| {
@@ -568,6 +723,7 @@ impl ServerFactory {
         workspace_method!(builder, file_features);
         workspace_method!(builder, is_path_ignored);
         workspace_method!(builder, update_settings);
+        workspace_method!(builder, unregister_project_folder);
         workspace_method!(builder, open_file);
         workspace_method!(builder, open_project);
         workspace_method!(builder, update_current_project);
@@ -584,7 +740,15 @@ impl ServerFactory {
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
+        workspace_method!(builder, rename_imports);
+        workspace_method!(builder, semantic_tokens);
+        workspace_method!(builder, get_folding_ranges);
+        workspace_method!(builder, get_selection_ranges);
+        workspace_method!(builder, get_completions);
         workspace_method!(builder, organize_imports);
+        workspace_method!(builder, parse_pattern);
+        workspace_method!(builder, search_pattern);
+        workspace_method!(builder, drop_pattern);
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
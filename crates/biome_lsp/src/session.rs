@@ -1,3 +1,4 @@
+use crate::converters::line_index::LineIndex;
 use crate::converters::{negotiated_encoding, PositionEncoding, WideEncoding};
 use crate::documents::Document;
 use crate::extension_settings::ExtensionSettings;
@@ -6,32 +7,39 @@ use crate::utils;
 use anyhow::Result;
 use biome_analyze::RuleCategories;
 use biome_console::markup;
-use biome_diagnostics::PrintDescription;
-use biome_fs::{BiomePath, FileSystem};
-use biome_service::configuration::{load_configuration, LoadedConfiguration};
+use biome_diagnostics::{Error, PrintDescription};
+use biome_fs::{
+    BiomePath, ConfigName, FileSystem, OpenOptions, PathInterner, TraversalContext, TraversalScope,
+};
+use biome_service::configuration::{
+    load_configuration, load_json_schemas, load_nested_configuration, load_plugins,
+    LoadedConfiguration,
+};
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
-    FeaturesBuilder, GetFileContentParams, OpenProjectParams, PullDiagnosticsParams,
-    SupportsFeatureParams, UpdateProjectParams,
+    FeatureName, FeaturesBuilder, GetFileContentParams, IsPathIgnoredParams, OpenProjectParams,
+    PullDiagnosticsParams, SupportsFeatureParams, UnregisterProjectFolderParams,
+    UpdateProjectParams,
 };
 use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
 use biome_service::{ConfigurationBasePath, Workspace};
 use biome_service::{DynRef, WorkspaceError};
 use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 use serde_json::Value;
-use std::path::PathBuf;
-use std::sync::atomic::AtomicU8;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicU64, AtomicU8};
 use std::sync::Arc;
-use std::sync::RwLock;
-use tokio::sync::Notify;
+use std::sync::{Mutex, RwLock};
 use tokio::sync::OnceCell;
+use tokio::sync::{watch, Notify};
 use tower_lsp::lsp_types;
 use tower_lsp::lsp_types::Unregistration;
 use tower_lsp::lsp_types::Url;
-use tower_lsp::lsp_types::{MessageType, Registration};
+use tower_lsp::lsp_types::{MessageType, Registration, WorkspaceFolder};
 use tracing::{debug, error, info};
 
 pub(crate) struct ClientInformation {
@@ -68,6 +76,26 @@ pub(crate) struct Session {
 
     documents: RwLock<FxHashMap<lsp_types::Url, Document>>,
 
+    /// The paths of the workspace folders opened by the client. Each folder is a
+    /// project with its own configuration.
+    workspace_folders: RwLock<Vec<PathBuf>>,
+
+    /// The directories that were already searched for a nested configuration file
+    nested_configuration_directories: RwLock<FxHashSet<PathBuf>>,
+
+    /// The files of the workspace folders that can be linted. They're collected the first
+    /// time they're needed, and again after files are created or deleted, or the settings change.
+    workspace_files: RwLock<Option<Arc<[PathBuf]>>>,
+
+    /// Incremented every time the settings, the files of the workspace or their content change.
+    /// It's part of the result ids of the pulled diagnostics, so they're all reported again:
+    /// the diagnostics of a file can depend on the content of the other files.
+    diagnostics_generation: AtomicU64,
+
+    /// Notified every time a file of the workspace or the settings change, so the
+    /// "workspace/diagnostic" requests waiting for a change can report them
+    workspace_changes: watch::Sender<()>,
+
     pub(crate) cancellation: Arc<Notify>,
 
     pub(crate) config_path: Option<PathBuf>,
@@ -79,7 +107,6 @@ struct InitializeParams {
     /// The capabilities provided by the client as part of [`lsp_types::InitializeParams`]
     client_capabilities: lsp_types::ClientCapabilities,
     client_information: Option<ClientInformation>,
-    root_uri: Option<Url>,
 }
 
 #[repr(u8)]
@@ -150,6 +177,11 @@ impl Session {
             workspace,
             configuration_status: AtomicU8::new(ConfigurationStatus::Missing as u8),
             documents,
+            workspace_folders: RwLock::default(),
+            nested_configuration_directories: RwLock::default(),
+            workspace_files: RwLock::default(),
+            diagnostics_generation: AtomicU64::default(),
+            workspace_changes: watch::channel(()).0,
             extension_settings: config,
             fs,
             cancellation,
@@ -163,21 +195,61 @@ impl Session {
     }
 
     /// Initialize this session instance with the incoming initialization parameters from the client
+    ///
+    /// The workspace folders sent by the client take precedence over the root URI.
     pub(crate) fn initialize(
         &self,
         client_capabilities: lsp_types::ClientCapabilities,
         client_information: Option<ClientInformation>,
         root_uri: Option<Url>,
+        workspace_folders: Option<Vec<WorkspaceFolder>>,
     ) {
         let result = self.initialize_params.set(InitializeParams {
             client_capabilities,
             client_information,
-            root_uri,
         });
 
         if let Err(err) = result {
             error!("Failed to initialize session: {err}");
         }
+
+        let folders = match workspace_folders {
+            Some(folders) if !folders.is_empty() => folders
+                .iter()
+                .filter_map(|folder| folder_path(&folder.uri))
+                .collect(),
+            _ => root_uri
+                .as_ref()
+                .and_then(folder_path)
+                .into_iter()
+                .collect(),
+        };
+        *self.workspace_folders.write().unwrap() = folders;
+    }
+
+    /// Updates the workspace folders after the client sent a `workspace/didChangeWorkspaceFolders`
+    /// notification. The settings of the removed folders are dropped from the workspace.
+    pub(crate) fn update_workspace_folders(
+        &self,
+        added: Vec<WorkspaceFolder>,
+        removed: Vec<WorkspaceFolder>,
+    ) {
+        let mut workspace_folders = self.workspace_folders.write().unwrap();
+        for path in removed.iter().filter_map(|folder| folder_path(&folder.uri)) {
+            workspace_folders.retain(|folder| folder != &path);
+            let result = self
+                .workspace
+                .unregister_project_folder(UnregisterProjectFolderParams { path });
+            if let Err(err) = result {
+                error!("Failed to remove the workspace folder: {}", err);
+            }
+        }
+        for path in added.iter().filter_map(|folder| folder_path(&folder.uri)) {
+            if !workspace_folders.contains(&path) {
+                workspace_folders.push(path);
+            }
+        }
+        self.invalidate_workspace_files();
     }
 
     /// Register a set of capabilities with the client
@@ -255,8 +327,12 @@ impl Session {
         self.documents.write().unwrap().remove(url);
     }
 
+    /// Returns the path of the document matching the provided [`lsp_types::Url`].
+    ///
+    /// The path is kept absolute, so the workspace can find the workspace folder
+    /// the document belongs to.
     pub(crate) fn file_path(&self, url: &lsp_types::Url) -> Result<BiomePath> {
-        let mut path_to_file = match url.to_file_path() {
+        let path_to_file = match url.to_file_path() {
             Err(_) => {
                 // If we can't create a path, it's probably because the file doesn't exist.
                 // It can be a newly created file that it's not on disk
@@ -265,26 +341,105 @@ impl Session {
             Ok(path) => path,
         };
 
-        let relative_path = self.initialize_params.get().and_then(|initialize_params| {
-            let root_uri = initialize_params.root_uri.as_ref()?;
-            let root_path = root_uri.to_file_path().ok()?;
-            path_to_file.strip_prefix(&root_path).ok()
-        });
-
-        if let Some(relative_path) = relative_path {
-            path_to_file = relative_path.into();
-        }
-
         Ok(BiomePath::new(path_to_file))
     }
 
     /// Computes diagnostics for the file matching the provided url and publishes
     /// them to the client. Called from [`handlers::text_document`] when a file's
     /// contents changes.
+    ///
+    /// Nothing is published when the client pulls the diagnostics itself.
     #[tracing::instrument(level = "trace", skip_all, fields(url = display(&url), diagnostic_count), err)]
     pub(crate) async fn update_diagnostics(&self, url: lsp_types::Url) -> Result<()> {
-        let biome_path = self.file_path(&url)?;
+        if self.supports_pull_diagnostics() {
+            self.invalidate_diagnostics();
+            return Ok(());
+        }
+
         let doc = self.document(&url)?;
+        let diagnostics = self.compute_diagnostics(&url, &doc.line_index, None)?;
+
+        tracing::Span::current().record("diagnostic_count", diagnostics.len());
+
+        self.client
+            .publish_diagnostics(url, diagnostics, Some(doc.version))
+            .await;
+
+        Ok(())
+    }
+
+    /// Returns the content of the file matching the provided url. The file is
+    /// read from the disk when it isn't open in the client.
+    pub(crate) fn file_content(&self, url: &lsp_types::Url) -> Result<String> {
+        let biome_path = self.file_path(url)?;
+        if self.document(url).is_ok() {
+            let content = self
+                .workspace
+                .get_file_content(GetFileContentParams { path: biome_path })?;
+            return Ok(content);
+        }
+
+        let mut content = String::new();
+        self.fs
+            .open_with_options(&biome_path, OpenOptions::default().read(true))?
+            .read_to_string(&mut content)?;
+        Ok(content)
+    }
+
+    /// Returns the result id of the diagnostics of a file with the provided content.
+    ///
+    /// It changes when the content of the file changes, or when the settings, the files
+    /// of the workspace or their content change.
+    pub(crate) fn diagnostics_result_id(&self, content: &str) -> String {
+        let mut hasher = FxHasher::default();
+        content.hash(&mut hasher);
+        let generation = self.diagnostics_generation.load(Ordering::Relaxed);
+        format!("{generation}:{:016x}", hasher.finish())
+    }
+
+    /// Computes the diagnostics of the file matching the provided url, given its
+    /// `content` returned by [Session::file_content]. The file isn't opened in
+    /// the workspace when it isn't open in the client.
+    pub(crate) fn file_diagnostics(
+        &self,
+        url: &lsp_types::Url,
+        content: String,
+    ) -> Result<Vec<lsp_types::Diagnostic>> {
+        if let Ok(doc) = self.document(url) {
+            return self.compute_diagnostics(url, &doc.line_index, None);
+        }
+
+        let line_index = LineIndex::new(&content);
+        self.compute_diagnostics(url, &line_index, Some(content))
+    }
+
+    /// Returns a receiver notified every time a file of the workspace or the settings change
+    pub(crate) fn subscribe_workspace_changes(&self) -> watch::Receiver<()> {
+        self.workspace_changes.subscribe()
+    }
+
+    /// Notifies the "workspace/diagnostic" requests waiting for a change
+    fn notify_workspace_change(&self) {
+        self.workspace_changes.send_replace(());
+    }
+
+    /// Changes the result ids of the diagnostics of every file, because a change to the
+    /// content of one file can change the diagnostics of the others, and notifies the
+    /// "workspace/diagnostic" requests waiting for a change
+    pub(crate) fn invalidate_diagnostics(&self) {
+        self.diagnostics_generation.fetch_add(1, Ordering::Relaxed);
+        self.notify_workspace_change();
+    }
+
+    /// Computes the diagnostics of a file. The `content` of the file must be
+    /// provided when it isn't open in the workspace.
+    fn compute_diagnostics(
+        &self,
+        url: &lsp_types::Url,
+        line_index: &LineIndex,
+        content: Option<String>,
+    ) -> Result<Vec<lsp_types::Diagnostic>> {
+        let biome_path = self.file_path(url)?;
         let file_features = self.workspace.file_features(SupportsFeatureParams {
             feature: FeaturesBuilder::new()
                 .with_linter()
@@ -312,12 +467,16 @@ impl Session {
                 path: biome_path.clone(),
                 categories,
                 max_diagnostics: u64::MAX,
+                content: content.clone(),
             })?;
 
             tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
-            let content = self.workspace.get_file_content(GetFileContentParams {
-                path: biome_path.clone(),
-            })?;
+            let content = match content {
+                Some(content) => content,
+                None => self.workspace.get_file_content(GetFileContentParams {
+                    path: biome_path.clone(),
+                })?,
+            };
             let offset = match biome_path.extension().and_then(|s| s.to_str()) {
                 Some("vue") => VueFileHandler::start(content.as_str()),
                 Some("astro") => AstroFileHandler::start(content.as_str()),
@@ -331,8 +490,8 @@ impl Session {
                 .filter_map(|d| {
                     match utils::diagnostic_to_lsp(
                         d,
-                        &url,
-                        &doc.line_index,
+                        url,
+                        line_index,
                         self.position_encoding(),
                         offset,
                     ) {
@@ -350,17 +509,26 @@ impl Session {
             result
         };
 
-        tracing::Span::current().record("diagnostic_count", diagnostics.len());
-
-        self.client
-            .publish_diagnostics(url, diagnostics, Some(doc.version))
-            .await;
-
-        Ok(())
+        Ok(diagnostics)
     }
 
     /// Updates diagnostics for every [`Document`] in this [`Session`]
+    ///
+    /// When the client pulls the diagnostics, it's asked to pull them again instead.
     pub(crate) async fn update_all_diagnostics(&self) {
+        if self.supports_pull_diagnostics() {
+            if self.supports_diagnostic_refresh() {
+                let result = self
+                    .client
+                    .send_request::<lsp_types::request::WorkspaceDiagnosticRefresh>(())
+                    .await;
+                if let Err(err) = result {
+                    error!("Error while refreshing the diagnostics: {}", err);
+                }
+            }
+            return;
+        }
+
         let mut futures: FuturesUnordered<_> = self
             .documents
             .read()
@@ -376,6 +544,26 @@ impl Session {
         }
     }
 
+    /// True if the client supports the pull model for diagnostics, through the
+    /// "textDocument/diagnostic" and "workspace/diagnostic" requests
+    pub(crate) fn supports_pull_diagnostics(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .and_then(|c| c.diagnostic.as_ref())
+            .is_some()
+    }
+
+    /// True if the client supports the "workspace/diagnostic/refresh" request
+    fn supports_diagnostic_refresh(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.workspace.as_ref())
+            .and_then(|c| c.diagnostic.as_ref())
+            .and_then(|c| c.refresh_support)
+            == Some(true)
+    }
+
     /// True if the client supports dynamic registration of "workspace/didChangeConfiguration" requests
     pub(crate) fn can_register_did_change_configuration(&self) -> bool {
         self.initialize_params
@@ -386,20 +574,76 @@ impl Session {
             == Some(true)
     }
 
-    /// Returns the base path of the workspace on the filesystem if it has one
-    pub(crate) fn base_path(&self) -> Option<PathBuf> {
-        let initialize_params = self.initialize_params.get()?;
+    /// Returns the paths of the workspace folders on the filesystem
+    pub(crate) fn workspace_folders(&self) -> Vec<PathBuf> {
+        self.workspace_folders.read().unwrap().clone()
+    }
 
-        let root_uri = initialize_params.root_uri.as_ref()?;
-        match root_uri.to_file_path() {
-            Ok(base_path) => Some(base_path),
-            Err(()) => {
-                error!(
-                    "The Workspace root URI {root_uri:?} could not be parsed as a filesystem path"
-                );
-                None
-            }
+    /// Returns the files of the workspace folders that can be linted, including the files
+    /// that aren't open. The files are found with the same rules as the CLI: the files
+    /// ignored by the configuration or by the VCS are skipped.
+    pub(crate) fn workspace_files(&self) -> Arc<[PathBuf]> {
+        if let Some(files) = self.workspace_files.read().unwrap().as_ref() {
+            return files.clone();
+        }
+
+        let mut folders = self.workspace_folders();
+        if folders.is_empty() {
+            folders.extend(self.fs.working_directory());
         }
+
+        let (interner, _) = PathInterner::new();
+        let context = WorkspaceTraversal {
+            session: self,
+            interner,
+            files: Mutex::default(),
+        };
+        self.fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+            for folder in folders {
+                scope.spawn(&context, folder);
+            }
+        }));
+
+        let mut files = context.files.into_inner().unwrap();
+        files.sort();
+        let files: Arc<[PathBuf]> = files.into();
+        *self.workspace_files.write().unwrap() = Some(files.clone());
+        files
+    }
+
+    /// Drops the files returned by [Session::workspace_files], so they're collected again,
+    /// and changes the result ids of the diagnostics of every file
+    pub(crate) fn invalidate_workspace_files(&self) {
+        self.workspace_files.write().unwrap().take();
+        self.invalidate_diagnostics();
+    }
+
+    /// Returns the path of the configuration file that applies to `path`, and its content.
+    ///
+    /// The configuration file is the one set by the client, or the closest `biome.json`
+    /// or `biome.jsonc` found in the ancestors of `path`.
+    pub(crate) fn configuration_file(&self, path: &Path) -> Option<(PathBuf, String)> {
+        let config_file = match &self.config_path {
+            Some(config_path) if self.fs.path_is_file(config_path) => config_path.clone(),
+            Some(config_path) => ConfigName::file_names()
+                .iter()
+                .map(|name| config_path.join(name))
+                .find(|file| self.fs.path_is_file(file))?,
+            None => path.ancestors().skip(1).find_map(|directory| {
+                ConfigName::file_names()
+                    .iter()
+                    .map(|name| directory.join(name))
+                    .find(|file| self.fs.path_is_file(file))
+            })?,
+        };
+
+        let mut content = String::new();
+        self.fs
+            .open_with_options(&config_file, OpenOptions::default().read(true))
+            .ok()?
+            .read_to_string(&mut content)
+            .ok()?;
+        Some((config_file, content))
     }
 
     /// Returns a reference to the client information for this session
@@ -407,20 +651,139 @@ impl Session {
         self.initialize_params.get()?.client_information.as_ref()
     }
 
-    /// This function attempts to read the `biome.json` configuration file from
-    /// the root URI and update the workspace settings accordingly
+    /// This function attempts to read the `biome.json` configuration file of
+    /// each workspace folder and update the settings of the folders accordingly
     #[tracing::instrument(level = "trace", skip(self))]
     pub(crate) async fn load_workspace_settings(&self) {
-        let base_path = if let Some(config_path) = &self.config_path {
-            ConfigurationBasePath::FromUser(config_path.clone())
+        let workspace_folders = self.workspace_folders();
+        let status = if let Some(config_path) = &self.config_path {
+            let base_path = ConfigurationBasePath::FromUser(config_path.clone());
+            self.load_project_settings(base_path, None).await
+        } else if workspace_folders.is_empty() {
+            self.load_project_settings(ConfigurationBasePath::default(), None)
+                .await
         } else {
-            match self.base_path() {
-                None => ConfigurationBasePath::default(),
-                Some(path) => ConfigurationBasePath::Lsp(path),
+            let mut status = ConfigurationStatus::Loaded;
+            for folder in workspace_folders {
+                let base_path = ConfigurationBasePath::Lsp(folder.clone());
+                let folder_status = self.load_project_settings(base_path, Some(folder)).await;
+                if matches!(folder_status, ConfigurationStatus::Error) {
+                    status = ConfigurationStatus::Error;
+                }
             }
+            status
         };
 
-        let status = match load_configuration(&self.fs, base_path) {
+        self.set_configuration_status(status);
+        self.invalidate_workspace_files();
+
+        // The nested configurations are merged with the settings that were just loaded,
+        // so they're loaded again for the documents that are already open
+        self.nested_configuration_directories
+            .write()
+            .unwrap()
+            .clear();
+        let paths: Vec<_> = self
+            .documents
+            .read()
+            .unwrap()
+            .keys()
+            .filter_map(|url| self.file_path(url).ok())
+            .collect();
+        for path in paths {
+            self.load_nested_configurations(&path).await;
+        }
+    }
+
+    /// Loads the nested configuration files (`"root": false`) found in the directories
+    /// between the workspace folder and the file at `path`, from the outermost to the innermost
+    pub(crate) async fn load_nested_configurations(&self, path: &Path) {
+        let workspace_folder = self
+            .workspace_folders()
+            .into_iter()
+            .filter(|folder| path.starts_with(folder))
+            .max_by_key(|folder| folder.components().count());
+        let Some(workspace_folder) = workspace_folder else {
+            return;
+        };
+
+        let mut directories: Vec<_> = path
+            .ancestors()
+            .skip(1)
+            .take_while(|directory| *directory != workspace_folder)
+            .map(Path::to_path_buf)
+            .collect();
+        directories.reverse();
+
+        for directory in directories {
+            let inserted = self
+                .nested_configuration_directories
+                .write()
+                .unwrap()
+                .insert(directory.clone());
+            if !inserted {
+                continue;
+            }
+
+            let loaded_configuration = match load_nested_configuration(&*self.fs, &directory) {
+                Ok(Some(loaded_configuration)) => loaded_configuration,
+                Ok(None) => continue,
+                Err(err) => {
+                    error!(
+                        "Couldn't load the nested configuration file, reason:\n {}",
+                        err
+                    );
+                    continue;
+                }
+            };
+
+            if loaded_configuration.has_errors() {
+                error!("Couldn't load the nested configuration file, reasons:");
+                for diagnostic in loaded_configuration.as_diagnostics_iter() {
+                    let message = PrintDescription(diagnostic).to_string();
+                    self.client.log_message(MessageType::ERROR, message).await;
+                }
+                continue;
+            }
+
+            let LoadedConfiguration { configuration, .. } = loaded_configuration;
+            let result =
+                load_plugins(&*self.fs, configuration.plugins.as_ref()).and_then(|plugins| {
+                    let json_schemas = load_json_schemas(
+                        &*self.fs,
+                        configuration
+                            .json
+                            .as_ref()
+                            .and_then(|json| json.schemas.as_ref()),
+                    )?;
+                    self.workspace.update_settings(UpdateSettingsParams {
+                        working_directory: Some(directory.clone()),
+                        configuration,
+                        vcs_base_path: None,
+                        gitignore_matches: vec![],
+                        plugins,
+                        json_schemas,
+                        project_folder: Some(directory.clone()),
+                    })
+                });
+
+            if let Err(error) = result {
+                error!(
+                    "Failed to set the settings of the nested configuration: {}",
+                    error
+                );
+            }
+        }
+    }
+
+    /// Loads the configuration file found from `base_path`, and sets it as the settings
+    /// of `project_folder`
+    async fn load_project_settings(
+        &self,
+        base_path: ConfigurationBasePath,
+        project_folder: Option<PathBuf>,
+    ) -> ConfigurationStatus {
+        match load_configuration(&*self.fs, base_path) {
             Ok(loaded_configuration) => {
                 if loaded_configuration.has_errors() {
                     error!("Couldn't load the configuration file, reasons:");
@@ -439,16 +802,32 @@ impl Session {
                     debug!("{configuration:#?}");
                     let fs = &self.fs;
 
-                    let result =
-                        configuration.retrieve_gitignore_matches(fs, configuration_path.as_deref());
+                    let result = configuration
+                        .retrieve_gitignore_matches(fs, configuration_path.as_deref())
+                        .and_then(|(vcs_base_path, gitignore_matches)| {
+                            let plugins = load_plugins(&**fs, configuration.plugins.as_ref())?;
+                            let json_schemas = load_json_schemas(
+                                &**fs,
+                                configuration
+                                    .json
+                                    .as_ref()
+                                    .and_then(|json| json.schemas.as_ref()),
+                            )?;
+                            Ok((vcs_base_path, gitignore_matches, plugins, json_schemas))
+                        });
 
                     match result {
-                        Ok((vcs_base_path, gitignore_matches)) => {
+                        Ok((vcs_base_path, gitignore_matches, plugins, json_schemas)) => {
                             let result = self.workspace.update_settings(UpdateSettingsParams {
-                                working_directory: fs.working_directory(),
+                                working_directory: project_folder
+                                    .clone()
+                                    .or(fs.working_directory()),
                                 configuration,
                                 vcs_base_path,
                                 gitignore_matches,
+                                plugins,
+                                json_schemas,
+                                project_folder,
                             });
 
                             if let Err(error) = result {
@@ -470,22 +849,18 @@ impl Session {
                 error!("Couldn't load the configuration file, reason:\n {}", err);
                 ConfigurationStatus::Error
             }
-        };
-
-        self.set_configuration_status(status);
+        }
     }
 
+    /// Loads the `package.json` file of each workspace folder
     #[tracing::instrument(level = "trace", skip(self))]
     pub(crate) async fn load_manifest(&self) {
-        let base_path = self
-            .manifest_path
-            .as_deref()
-            .map(PathBuf::from)
-            .or(self.base_path());
-        if let Some(base_path) = base_path {
-            let result = self
-                .fs
-                .auto_search(base_path.clone(), &["package.json"], false);
+        let base_paths = match &self.manifest_path {
+            Some(manifest_path) => vec![manifest_path.clone()],
+            None => self.workspace_folders(),
+        };
+        for base_path in base_paths {
+            let result = self.fs.auto_search(base_path, &["package.json"], false);
             match result {
                 Ok(result) => {
                     if let Some(result) = result {
@@ -595,3 +970,59 @@ impl Session {
             .unwrap_or(PositionEncoding::Wide(WideEncoding::Utf16))
     }
 }
+
+/// Converts the URI of a workspace folder to a path on the filesystem
+fn folder_path(uri: &Url) -> Option<PathBuf> {
+    match uri.to_file_path() {
+        Ok(path) => Some(path),
+        Err(()) => {
+            error!("The Workspace folder URI {uri:?} could not be parsed as a filesystem path");
+            None
+        }
+    }
+}
+
+/// Collects the files of a traversal of the workspace folders
+struct WorkspaceTraversal<'a> {
+    session: &'a Session,
+    interner: PathInterner,
+    files: Mutex<Vec<PathBuf>>,
+}
+
+impl TraversalContext for WorkspaceTraversal<'_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, error: Error) {
+        error!("Error while traversing the workspace: {:?}", error);
+    }
+
+    fn can_handle(&self, path: &BiomePath) -> bool {
+        let workspace = &self.session.workspace;
+        if !self.session.fs.path_is_file(path) {
+            return !workspace
+                .is_path_ignored(IsPathIgnoredParams {
+                    biome_path: path.clone(),
+                    feature: FeatureName::Lint,
+                })
+                .unwrap_or(true);
+        }
+
+        workspace
+            .file_features(SupportsFeatureParams {
+                path: path.clone(),
+                feature: FeaturesBuilder::new()
+                    .with_linter()
+                    .with_organize_imports()
+                    .build(),
+            })
+            .is_ok_and(|file_features| {
+                file_features.supports_lint() || file_features.supports_organize_imports()
+            })
+    }
+
+    fn handle_file(&self, path: &Path) {
+        self.files.lock().unwrap().push(path.to_path_buf());
+    }
+}
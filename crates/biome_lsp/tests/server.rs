@@ -8,7 +8,7 @@ use biome_lsp::ServerFactory;
 use biome_lsp::WorkspaceSettings;
 use biome_service::workspace::GetSyntaxTreeResult;
 use biome_service::workspace::{GetFileContentParams, GetSyntaxTreeParams};
-use biome_service::DynRef;
+use biome_service::{workspace, DynRef};
 use futures::channel::mpsc::{channel, Sender};
 use futures::Sink;
 use futures::SinkExt;
@@ -165,6 +165,69 @@ impl Server {
         Ok(())
     }
 
+    /// Implementation of the `initialize` request for a client that pulls the diagnostics
+    #[allow(deprecated)]
+    async fn initialize_with_pull_diagnostics(&mut self) -> Result<()> {
+        let _res: InitializeResult = self
+            .request(
+                "initialize",
+                "_init",
+                InitializeParams {
+                    process_id: None,
+                    root_path: None,
+                    root_uri: Some(url!("")),
+                    initialization_options: None,
+                    capabilities: ClientCapabilities {
+                        text_document: Some(lsp::TextDocumentClientCapabilities {
+                            diagnostic: Some(lsp::DiagnosticClientCapabilities::default()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    trace: None,
+                    workspace_folders: None,
+                    client_info: None,
+                    locale: None,
+                },
+            )
+            .await?
+            .context("initialize returned None")?;
+
+        Ok(())
+    }
+
+    /// Implementation of the `initialize` request for a client that opened several workspace folders
+    #[allow(deprecated)]
+    async fn initialize_with_workspace_folders(&mut self, folders: Vec<Url>) -> Result<()> {
+        let workspace_folders = folders
+            .into_iter()
+            .map(|uri| lsp::WorkspaceFolder {
+                name: uri.path().to_string(),
+                uri,
+            })
+            .collect();
+        let _res: InitializeResult = self
+            .request(
+                "initialize",
+                "_init",
+                InitializeParams {
+                    process_id: None,
+                    root_path: None,
+                    root_uri: None,
+                    initialization_options: None,
+                    capabilities: ClientCapabilities::default(),
+                    trace: None,
+                    workspace_folders: Some(workspace_folders),
+                    client_info: None,
+                    locale: None,
+                },
+            )
+            .await?
+            .context("initialize returned None")?;
+
+        Ok(())
+    }
+
     /// Basic implementation of the `initialized` notification for tests
     async fn initialized(&mut self) -> Result<()> {
         self.notify("initialized", InitializedParams {}).await
@@ -250,6 +313,45 @@ impl Server {
         .await
     }
 
+    async fn remove_workspace_folder(&mut self, uri: Url) -> Result<()> {
+        self.notify(
+            "workspace/didChangeWorkspaceFolders",
+            lsp::DidChangeWorkspaceFoldersParams {
+                event: lsp::WorkspaceFoldersChangeEvent {
+                    added: vec![],
+                    removed: vec![lsp::WorkspaceFolder {
+                        name: uri.path().to_string(),
+                        uri,
+                    }],
+                },
+            },
+        )
+        .await
+    }
+
+    async fn format_document(&mut self, uri: Url) -> Result<Option<Vec<TextEdit>>> {
+        self.request(
+            "textDocument/formatting",
+            "formatting",
+            DocumentFormattingParams {
+                text_document: TextDocumentIdentifier { uri },
+                options: FormattingOptions {
+                    tab_size: 4,
+                    insert_spaces: false,
+                    properties: HashMap::default(),
+                    trim_trailing_whitespace: None,
+                    insert_final_newline: None,
+                    trim_final_newlines: None,
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("formatting returned None")
+    }
+
     async fn change_document(
         &mut self,
         version: i32,
@@ -268,6 +370,30 @@ impl Server {
         .await
     }
 
+    async fn pull_document_diagnostics(
+        &mut self,
+        uri: Url,
+        previous_result_id: Option<String>,
+    ) -> Result<lsp::DocumentDiagnosticReportResult> {
+        self.request(
+            "textDocument/diagnostic",
+            "document_diagnostic",
+            lsp::DocumentDiagnosticParams {
+                text_document: TextDocumentIdentifier { uri },
+                identifier: None,
+                previous_result_id,
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("textDocument/diagnostic returned None")
+    }
+
     async fn close_document(&mut self) -> Result<()> {
         self.notify(
             "textDocument/didClose",
@@ -328,6 +454,7 @@ where
         let res = match req.method() {
             "workspace/configuration" => {
                 let settings = WorkspaceSettings {
+                    rename: Some(true),
                     ..WorkspaceSettings::default()
                 };
                 let result =
@@ -435,7 +562,7 @@ async fn document_lifecycle() -> Result<()> {
             "biome/get_syntax_tree",
             "get_syntax_tree",
             GetSyntaxTreeParams {
-                path: BiomePath::new("document.js"),
+                path: BiomePath::new(url!("document.js").to_file_path().unwrap()),
             },
         )
         .await?
@@ -935,7 +1062,87 @@ async fn pull_quick_fixes() -> Result<()> {
         data: None,
     });
 
-    assert_eq!(res, vec![expected_code_action, expected_suppression_action]);
+    let mut fix_all_rule_changes = HashMap::default();
+    fix_all_rule_changes.insert(
+        url!("document.js"),
+        vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: 0,
+                    character: 0,
+                },
+                end: Position {
+                    line: 1,
+                    character: 0,
+                },
+            },
+            new_text: String::from("if(a === 0) {}"),
+        }],
+    );
+
+    let expected_fix_all_rule_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Fix all noCompareNegZero issues in this file"),
+        kind: Some(lsp::CodeActionKind::new(
+            "quickfix.fixAllRule.biome.suspicious.noCompareNegZero",
+        )),
+        diagnostics: Some(vec![fixable_diagnostic(0)?]),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(fix_all_rule_changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(false),
+        disabled: None,
+        data: None,
+    });
+
+    let mut file_suppression_changes = HashMap::default();
+    file_suppression_changes.insert(
+        url!("document.js"),
+        vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: 0,
+                    character: 0,
+                },
+                end: Position {
+                    line: 0,
+                    character: 0,
+                },
+            },
+            new_text: String::from(
+                "// biome-ignore-all lint/suspicious/noCompareNegZero: <explanation>\n",
+            ),
+        }],
+    );
+
+    let expected_file_suppression_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Suppress rule lint/suspicious/noCompareNegZero for the whole file"),
+        kind: Some(lsp::CodeActionKind::new(
+            "quickfix.suppressRuleInFile.biome.suspicious.noCompareNegZero",
+        )),
+        diagnostics: Some(vec![fixable_diagnostic(0)?]),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(file_suppression_changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(false),
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(
+        res,
+        vec![
+            expected_code_action,
+            expected_suppression_action,
+            expected_fix_all_rule_action,
+            expected_file_suppression_action
+        ]
+    );
 
     server.close_document().await?;
 
@@ -1253,7 +1460,7 @@ async fn pull_quick_fixes_include_unsafe() -> Result<()> {
         kind: Some(lsp::CodeActionKind::new(
             "quickfix.suppressRule.biome.suspicious.noDoubleEquals",
         )),
-        diagnostics: Some(vec![unsafe_fixable]),
+        diagnostics: Some(vec![unsafe_fixable.clone()]),
         edit: Some(lsp::WorkspaceEdit {
             changes: Some(suppression_changes),
             document_changes: None,
@@ -1265,7 +1472,51 @@ async fn pull_quick_fixes_include_unsafe() -> Result<()> {
         data: None,
     });
 
-    assert_eq!(res, vec![expected_code_action, expected_suppression_action]);
+    let mut file_suppression_changes = HashMap::default();
+    file_suppression_changes.insert(
+        url!("document.js"),
+        vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: 0,
+                    character: 0,
+                },
+                end: Position {
+                    line: 0,
+                    character: 0,
+                },
+            },
+            new_text: String::from(
+                "// biome-ignore-all lint/suspicious/noDoubleEquals: <explanation>\n",
+            ),
+        }],
+    );
+
+    let expected_file_suppression_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Suppress rule lint/suspicious/noDoubleEquals for the whole file"),
+        kind: Some(lsp::CodeActionKind::new(
+            "quickfix.suppressRuleInFile.biome.suspicious.noDoubleEquals",
+        )),
+        diagnostics: Some(vec![unsafe_fixable]),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(file_suppression_changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(false),
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(
+        res,
+        vec![
+            expected_code_action,
+            expected_suppression_action,
+            expected_file_suppression_action
+        ]
+    );
 
     server.close_document().await?;
 
@@ -1276,67 +1527,912 @@ async fn pull_quick_fixes_include_unsafe() -> Result<()> {
 }
 
 #[tokio::test]
-async fn pull_diagnostics_for_rome_json() -> Result<()> {
+async fn pull_fix_all_rule_action() -> Result<()> {
     let factory = ServerFactory::default();
     let (service, client) = factory.create(None).into_inner();
     let (stream, sink) = client.split();
     let mut server = Server::new(service);
 
-    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let safe_fixable = lsp::Diagnostic {
+        range: Range {
+            start: Position {
+                line: 0,
+                character: 3,
+            },
+            end: Position {
+                line: 0,
+                character: 11,
+            },
+        },
+        severity: Some(lsp::DiagnosticSeverity::ERROR),
+        code: Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noCompareNegZero",
+        ))),
+        code_description: None,
+        source: Some(String::from("biome")),
+        message: String::from("Do not use the === operator to compare against -0."),
+        related_information: None,
+        tags: None,
+        data: None,
+    };
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
     let reader = tokio::spawn(client_handler(stream, sink, sender));
 
     server.initialize().await?;
     server.initialized().await?;
 
-    let incorrect_config = r#"{
-        "formatter": {
-            "indentStyle": "magic"
-        }
-    }"#;
     server
-        .open_named_document(incorrect_config, url!("biome.json"), "json")
+        .open_document("if(a === -0) {}\nif(b === -0) {}")
         .await?;
 
-    let notification = tokio::select! {
-        msg = receiver.next() => msg,
-        _ = sleep(Duration::from_secs(1)) => {
-            panic!("timed out waiting for the server to send diagnostics")
-        }
-    };
-
-    assert_eq!(
-        notification,
-        Some(ServerNotification::PublishDiagnostics(
-            PublishDiagnosticsParams {
-                uri: url!("biome.json"),
-                version: Some(0),
-                diagnostics: vec![lsp::Diagnostic {
-                    range: Range {
-                        start: Position {
-                            line: 2,
-                            character: 27,
-                        },
-                        end: Position {
-                            line: 2,
-                            character: 34,
-                        },
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            lsp::CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 6,
                     },
-                    severity: Some(lsp::DiagnosticSeverity::ERROR),
-                    code: Some(lsp::NumberOrString::String(String::from("deserialize",))),
-                    code_description: None,
-                    source: Some(String::from("biome")),
-                    message: String::from("Found an unknown value `magic`.",),
-                    related_information: None,
-                    tags: None,
-                    data: None,
-                }],
-            }
-        ))
-    );
-
-    server.close_document().await?;
-
-    server.shutdown().await?;
+                    end: Position {
+                        line: 0,
+                        character: 6,
+                    },
+                },
+                context: lsp::CodeActionContext {
+                    diagnostics: vec![safe_fixable.clone()],
+                    only: Some(vec![lsp::CodeActionKind::new("quickfix.fixAllRule")]),
+                    ..Default::default()
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    let mut fix_all_rule_changes = HashMap::default();
+    fix_all_rule_changes.insert(
+        url!("document.js"),
+        vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: 0,
+                    character: 0,
+                },
+                end: Position {
+                    line: 2,
+                    character: 0,
+                },
+            },
+            new_text: String::from("if(a === 0) {}\nif(b === 0) {}"),
+        }],
+    );
+
+    let expected_fix_all_rule_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Fix all noCompareNegZero issues in this file"),
+        kind: Some(lsp::CodeActionKind::new(
+            "quickfix.fixAllRule.biome.suspicious.noCompareNegZero",
+        )),
+        diagnostics: Some(vec![safe_fixable]),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(fix_all_rule_changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(false),
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(res, vec![expected_fix_all_rule_action]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_fix_all_rule_action_skips_unsafe_fixes() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let unsafe_fixable = lsp::Diagnostic {
+        range: Range {
+            start: Position {
+                line: 0,
+                character: 6,
+            },
+            end: Position {
+                line: 0,
+                character: 9,
+            },
+        },
+        severity: Some(lsp::DiagnosticSeverity::ERROR),
+        code: Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noDoubleEquals",
+        ))),
+        code_description: None,
+        source: Some(String::from("biome")),
+        message: String::from("Use === instead of ==."),
+        related_information: None,
+        tags: None,
+        data: None,
+    };
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("if(a == 0) {}").await?;
+
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            lsp::CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 6,
+                    },
+                    end: Position {
+                        line: 0,
+                        character: 6,
+                    },
+                },
+                context: lsp::CodeActionContext {
+                    diagnostics: vec![unsafe_fixable],
+                    only: Some(vec![lsp::CodeActionKind::new("quickfix.fixAllRule")]),
+                    ..Default::default()
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    assert!(res.is_empty());
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_suppress_rule_in_file_action_after_shebang() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let safe_fixable = lsp::Diagnostic {
+        range: Range {
+            start: Position {
+                line: 2,
+                character: 3,
+            },
+            end: Position {
+                line: 2,
+                character: 11,
+            },
+        },
+        severity: Some(lsp::DiagnosticSeverity::ERROR),
+        code: Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noCompareNegZero",
+        ))),
+        code_description: None,
+        source: Some(String::from("biome")),
+        message: String::from("Do not use the === operator to compare against -0."),
+        related_information: None,
+        tags: None,
+        data: None,
+    };
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("#!/usr/bin/env node\n// @ts-check\nif(a === -0) {}\n")
+        .await?;
+
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            lsp::CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: Range {
+                    start: Position {
+                        line: 2,
+                        character: 6,
+                    },
+                    end: Position {
+                        line: 2,
+                        character: 6,
+                    },
+                },
+                context: lsp::CodeActionContext {
+                    diagnostics: vec![safe_fixable.clone()],
+                    only: Some(vec![lsp::CodeActionKind::new(
+                        "quickfix.suppressRuleInFile",
+                    )]),
+                    ..Default::default()
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    let mut file_suppression_changes = HashMap::default();
+    file_suppression_changes.insert(
+        url!("document.js"),
+        vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: 2,
+                    character: 0,
+                },
+                end: Position {
+                    line: 2,
+                    character: 0,
+                },
+            },
+            new_text: String::from(
+                "// biome-ignore-all lint/suspicious/noCompareNegZero: <explanation>\n",
+            ),
+        }],
+    );
+
+    let expected_file_suppression_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Suppress rule lint/suspicious/noCompareNegZero for the whole file"),
+        kind: Some(lsp::CodeActionKind::new(
+            "quickfix.suppressRuleInFile.biome.suspicious.noCompareNegZero",
+        )),
+        diagnostics: Some(vec![safe_fixable]),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(file_suppression_changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(false),
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(res, vec![expected_file_suppression_action]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_disable_rule_action() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(url!("biome.json").to_file_path().unwrap(), "{}");
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let diagnostic = lsp::Diagnostic {
+        range: Range {
+            start: Position {
+                line: 0,
+                character: 5,
+            },
+            end: Position {
+                line: 0,
+                character: 7,
+            },
+        },
+        severity: Some(lsp::DiagnosticSeverity::ERROR),
+        code: Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noDoubleEquals",
+        ))),
+        code_description: None,
+        source: Some(String::from("biome")),
+        message: String::from("Use === instead of =="),
+        related_information: None,
+        tags: None,
+        data: None,
+    };
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("if(a == 0) {}").await?;
+
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            lsp::CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 6,
+                    },
+                    end: Position {
+                        line: 0,
+                        character: 6,
+                    },
+                },
+                context: lsp::CodeActionContext {
+                    diagnostics: vec![diagnostic.clone()],
+                    only: Some(vec![lsp::CodeActionKind::new("quickfix.disableRule")]),
+                    ..Default::default()
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    let mut changes = HashMap::default();
+    changes.insert(
+        url!("biome.json"),
+        vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: 0,
+                    character: 1,
+                },
+                end: Position {
+                    line: 0,
+                    character: 1,
+                },
+            },
+            new_text: String::from(
+                r#" "linter": { "rules": { "suspicious": { "noDoubleEquals": "off" } } } "#,
+            ),
+        }],
+    );
+
+    let expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Disable rule lint/suspicious/noDoubleEquals in the configuration"),
+        kind: Some(lsp::CodeActionKind::new(
+            "quickfix.disableRule.biome.suspicious.noDoubleEquals",
+        )),
+        diagnostics: Some(vec![diagnostic]),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(false),
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(res, vec![expected_action]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_disable_rule_action_keeps_options() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        url!("biome.json").to_file_path().unwrap(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDoubleEquals": { "level": "warn" } } } } }"#,
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let diagnostic = lsp::Diagnostic {
+        range: Range {
+            start: Position {
+                line: 0,
+                character: 5,
+            },
+            end: Position {
+                line: 0,
+                character: 7,
+            },
+        },
+        severity: Some(lsp::DiagnosticSeverity::ERROR),
+        code: Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noDoubleEquals",
+        ))),
+        code_description: None,
+        source: Some(String::from("biome")),
+        message: String::from("Use === instead of =="),
+        related_information: None,
+        tags: None,
+        data: None,
+    };
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("if(a == 0) {}").await?;
+
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            lsp::CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 6,
+                    },
+                    end: Position {
+                        line: 0,
+                        character: 6,
+                    },
+                },
+                context: lsp::CodeActionContext {
+                    diagnostics: vec![diagnostic.clone()],
+                    only: Some(vec![lsp::CodeActionKind::new("quickfix.disableRule")]),
+                    ..Default::default()
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    let mut changes = HashMap::default();
+    changes.insert(
+        url!("biome.json"),
+        vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: 0,
+                    character: 70,
+                },
+                end: Position {
+                    line: 0,
+                    character: 76,
+                },
+            },
+            new_text: String::from(r#""off""#),
+        }],
+    );
+
+    let expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Disable rule lint/suspicious/noDoubleEquals in the configuration"),
+        kind: Some(lsp::CodeActionKind::new(
+            "quickfix.disableRule.biome.suspicious.noDoubleEquals",
+        )),
+        diagnostics: Some(vec![diagnostic]),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(false),
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(res, vec![expected_action]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_semantic_tokens() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("const a = 1;\nfunction f(p) { return a + p + b + console; }")
+        .await?;
+
+    let res: Option<lsp::SemanticTokensResult> = server
+        .request(
+            "textDocument/semanticTokens/full",
+            "pull_semantic_tokens",
+            lsp::SemanticTokensParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?;
+
+    let token =
+        |delta_line, delta_start, length, token_type, token_modifiers_bitset| lsp::SemanticToken {
+            delta_line,
+            delta_start,
+            length,
+            token_type,
+            token_modifiers_bitset,
+        };
+    let expected = lsp::SemanticTokensResult::Tokens(lsp::SemanticTokens {
+        result_id: None,
+        data: vec![
+            // `a`: a const variable declaration
+            token(0, 6, 1, 1, 0b11),
+            // `p`: a parameter declaration
+            token(1, 11, 1, 0, 0b1),
+            // `a`: a reference to a const variable
+            token(0, 12, 1, 1, 0b10),
+            // `p`: a reference to a parameter
+            token(0, 4, 1, 0, 0),
+            // `b`: an unresolved reference
+            token(0, 4, 1, 1, 0b10000),
+            // `console`: a global
+            token(0, 4, 7, 1, 0b100),
+        ],
+    });
+
+    assert_eq!(res, Some(expected));
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_folding_ranges() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document(
+            r#"import a from "a";
+import b from "b";
+
+// first line
+// second line
+function f() {
+    return [
+        a, b
+    ];
+}"#,
+        )
+        .await?;
+
+    let res: Option<Vec<lsp::FoldingRange>> = server
+        .request(
+            "textDocument/foldingRange",
+            "pull_folding_ranges",
+            lsp::FoldingRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?;
+
+    let folding_range = |start_line, end_line, kind| lsp::FoldingRange {
+        start_line,
+        end_line,
+        kind,
+        ..Default::default()
+    };
+    assert_eq!(
+        res,
+        Some(vec![
+            folding_range(0, 1, Some(lsp::FoldingRangeKind::Imports)),
+            folding_range(3, 4, Some(lsp::FoldingRangeKind::Comment)),
+            folding_range(5, 8, None),
+            folding_range(6, 7, None),
+        ])
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_selection_ranges() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("foo(bar, [1])").await?;
+
+    let res: Option<Vec<lsp::SelectionRange>> = server
+        .request(
+            "textDocument/selectionRange",
+            "pull_selection_ranges",
+            lsp::SelectionRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                positions: vec![Position::new(0, 10)],
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?;
+
+    let selection_range = |start, end, parent: Option<lsp::SelectionRange>| lsp::SelectionRange {
+        range: Range::new(Position::new(0, start), Position::new(0, end)),
+        parent: parent.map(Box::new),
+    };
+    // `1`, `[1]`, `bar, [1]`, `(bar, [1])` and the call expression
+    let call_expression = selection_range(0, 13, None);
+    let call_arguments = selection_range(3, 13, Some(call_expression));
+    let argument_list = selection_range(4, 12, Some(call_arguments));
+    let array = selection_range(9, 12, Some(argument_list));
+    let number = selection_range(10, 11, Some(array));
+
+    assert_eq!(res, Some(vec![number]));
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_json_schema_completions() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        url!("biome.json").to_file_path().unwrap(),
+        r#"{ "json": { "schemas": [{ "include": ["document.json"], "path": "schema.json" }] } }"#,
+    );
+    fs.insert(
+        url!("schema.json").to_file_path().unwrap(),
+        r#"{
+    "type": "object",
+    "properties": {
+        "name": { "type": "string", "description": "The name of the service" },
+        "port": { "type": "integer" }
+    }
+}"#,
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_named_document("{\n  \"port\": 80,\n  \n}", url!("document.json"), "json")
+        .await?;
+
+    let res: Option<lsp::CompletionResponse> = server
+        .request(
+            "textDocument/completion",
+            "pull_json_schema_completions",
+            lsp::CompletionParams {
+                text_document_position: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: url!("document.json"),
+                    },
+                    position: Position {
+                        line: 2,
+                        character: 2,
+                    },
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+                context: None,
+            },
+        )
+        .await?;
+
+    assert_eq!(
+        res,
+        Some(lsp::CompletionResponse::Array(vec![lsp::CompletionItem {
+            label: String::from("name"),
+            kind: Some(lsp::CompletionItemKind::PROPERTY),
+            insert_text: Some(String::from("\"name\"")),
+            documentation: Some(lsp::Documentation::String(String::from(
+                "The name of the service"
+            ))),
+            ..Default::default()
+        }]))
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_diagnostics_for_rome_json() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    let incorrect_config = r#"{
+        "formatter": {
+            "indentStyle": "magic"
+        }
+    }"#;
+    server
+        .open_named_document(incorrect_config, url!("biome.json"), "json")
+        .await?;
+
+    let notification = tokio::select! {
+        msg = receiver.next() => msg,
+        _ = sleep(Duration::from_secs(1)) => {
+            panic!("timed out waiting for the server to send diagnostics")
+        }
+    };
+
+    assert_eq!(
+        notification,
+        Some(ServerNotification::PublishDiagnostics(
+            PublishDiagnosticsParams {
+                uri: url!("biome.json"),
+                version: Some(0),
+                diagnostics: vec![lsp::Diagnostic {
+                    range: Range {
+                        start: Position {
+                            line: 2,
+                            character: 27,
+                        },
+                        end: Position {
+                            line: 2,
+                            character: 34,
+                        },
+                    },
+                    severity: Some(lsp::DiagnosticSeverity::ERROR),
+                    code: Some(lsp::NumberOrString::String(String::from("deserialize",))),
+                    code_description: None,
+                    source: Some(String::from("biome")),
+                    message: String::from("Found an unknown value `magic`.",),
+                    related_information: None,
+                    tags: None,
+                    data: None,
+                }],
+            }
+        ))
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
     reader.abort();
 
     Ok(())
@@ -1869,7 +2965,7 @@ isSpreadAssignment;
             "biome/get_file_content",
             "get_file_content",
             GetFileContentParams {
-                path: BiomePath::new("document.js"),
+                path: BiomePath::new(url!("document.js").to_file_path().unwrap()),
             },
         )
         .await?
@@ -2005,6 +3101,322 @@ async fn does_not_format_ignored_files() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn applies_the_configuration_of_each_workspace_folder() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+        "files": {
+            "ignore": ["document.js"]
+        }
+    }"#;
+
+    fs.insert(url!("first/biome.json").to_file_path().unwrap(), config);
+    fs.insert(url!("second/biome.json").to_file_path().unwrap(), "{}");
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server
+        .initialize_with_workspace_folders(vec![url!("first"), url!("second")])
+        .await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+
+    server
+        .open_named_document("statement (   );", url!("first/document.js"), "js")
+        .await?;
+    server
+        .open_named_document("statement (   );", url!("second/document.js"), "js")
+        .await?;
+
+    let res = server.format_document(url!("first/document.js")).await?;
+    assert!(res.is_none());
+
+    let res = server.format_document(url!("second/document.js")).await?;
+    assert!(res.is_some());
+
+    // Once the folder is removed, its configuration doesn't apply anymore
+    server.remove_workspace_folder(url!("first")).await?;
+
+    let res = server.format_document(url!("first/document.js")).await?;
+    assert!(res.is_some());
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn applies_nested_configuration() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+        "root": false,
+        "files": {
+            "ignore": ["document.js"]
+        }
+    }"#;
+
+    fs.insert(url!("project/biome.json").to_file_path().unwrap(), "{}");
+    fs.insert(
+        url!("project/packages/a/biome.json")
+            .to_file_path()
+            .unwrap(),
+        config,
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server
+        .initialize_with_workspace_folders(vec![url!("project")])
+        .await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(
+            "statement (   );",
+            url!("project/packages/a/document.js"),
+            "js",
+        )
+        .await?;
+    server
+        .open_named_document("statement (   );", url!("project/document.js"), "js")
+        .await?;
+
+    let res = server
+        .format_document(url!("project/packages/a/document.js"))
+        .await?;
+    assert!(res.is_none());
+
+    let res = server.format_document(url!("project/document.js")).await?;
+    assert!(res.is_some());
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_workspace_diagnostics() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+        "files": {
+            "ignore": ["ignored.js"]
+        }
+    }"#;
+
+    fs.insert(url!("biome.json").to_file_path().unwrap(), config);
+    fs.insert(url!("document.js").to_file_path().unwrap(), "a == b");
+    fs.insert(url!("other.js").to_file_path().unwrap(), "a != b");
+    fs.insert(url!("ignored.js").to_file_path().unwrap(), "a == b");
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize_with_pull_diagnostics().await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+
+    let report: lsp::WorkspaceDiagnosticReportResult = server
+        .request(
+            "workspace/diagnostic",
+            "workspace_diagnostic",
+            lsp::WorkspaceDiagnosticParams {
+                identifier: None,
+                previous_result_ids: vec![],
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("workspace/diagnostic returned None")?;
+
+    let lsp::WorkspaceDiagnosticReportResult::Report(report) = report else {
+        bail!("expected a full workspace diagnostic report");
+    };
+    let mut previous_result_ids = Vec::new();
+    let mut reports = HashMap::new();
+    for item in report.items {
+        let lsp::WorkspaceDocumentDiagnosticReport::Full(report) = item else {
+            bail!("expected only full document reports");
+        };
+        let result_id = report
+            .full_document_diagnostic_report
+            .result_id
+            .context("the document report has no result id")?;
+        if report.uri != url!("other.js") {
+            previous_result_ids.push(lsp::PreviousResultId {
+                uri: report.uri.clone(),
+                value: result_id,
+            });
+        }
+        reports.insert(report.uri, report.full_document_diagnostic_report.items);
+    }
+
+    let diagnostics = reports
+        .get(&url!("document.js"))
+        .context("the diagnostics of document.js are missing")?;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].code,
+        Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noDoubleEquals"
+        )))
+    );
+    assert!(reports.contains_key(&url!("other.js")));
+    assert!(!reports.contains_key(&url!("ignored.js")));
+
+    // The files the client already has the diagnostics of are reported as unchanged
+    let report: lsp::WorkspaceDiagnosticReportResult = server
+        .request(
+            "workspace/diagnostic",
+            "workspace_diagnostic",
+            lsp::WorkspaceDiagnosticParams {
+                identifier: None,
+                previous_result_ids,
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("workspace/diagnostic returned None")?;
+
+    let lsp::WorkspaceDiagnosticReportResult::Report(report) = report else {
+        bail!("expected a full workspace diagnostic report");
+    };
+    for item in report.items {
+        match item {
+            lsp::WorkspaceDocumentDiagnosticReport::Full(report) => {
+                assert_eq!(report.uri, url!("other.js"));
+            }
+            lsp::WorkspaceDocumentDiagnosticReport::Unchanged(report) => {
+                assert_ne!(report.uri, url!("other.js"));
+            }
+        }
+    }
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_diagnostics_changed_by_another_file() -> Result<()> {
+    let factory = ServerFactory::default();
+    // The workspace reads the files of the package on its own, so it gets a copy of the project
+    let project_fs = || {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            url!("biome.json").to_file_path().unwrap(),
+            r#"{
+                "linter": {
+                    "rules": {
+                        "nursery": { "noUnusedDependencies": "error" }
+                    }
+                }
+            }"#,
+        );
+        fs.insert(
+            url!("package.json").to_file_path().unwrap(),
+            r#"{ "dependencies": { "lodash": "latest" } }"#,
+        );
+        fs.insert(url!("document.js").to_file_path().unwrap(), "foo();");
+        fs
+    };
+    let workspace = workspace::server_sync_with_fs(Box::new(project_fs()));
+    let (service, client) = factory
+        .create_with_workspace(None, DynRef::Owned(Box::new(project_fs())), workspace)
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize_with_pull_diagnostics().await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+
+    server.open_document("foo();").await?;
+
+    let report = server
+        .pull_document_diagnostics(url!("package.json"), None)
+        .await?;
+    let lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Full(report)) =
+        report
+    else {
+        bail!("expected a full document diagnostic report");
+    };
+    let report = report.full_document_diagnostic_report;
+    assert_eq!(report.items.len(), 1);
+    assert_eq!(
+        report.items[0].code,
+        Some(lsp::NumberOrString::String(String::from(
+            "lint/nursery/noUnusedDependencies"
+        )))
+    );
+
+    // Importing the dependency from document.js changes the diagnostics of package.json,
+    // so they're reported again even though package.json didn't change
+    server
+        .change_document(
+            1,
+            vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: String::from("import \"lodash\";"),
+            }],
+        )
+        .await?;
+
+    let report = server
+        .pull_document_diagnostics(url!("package.json"), report.result_id)
+        .await?;
+    let lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Full(report)) =
+        report
+    else {
+        bail!("expected a full document diagnostic report");
+    };
+    assert!(report.full_document_diagnostic_report.items.is_empty());
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 #[ignore = "Find a way to retrieve the last notification sent"]
 async fn pull_diagnostics_from_manifest() -> Result<()> {
@@ -2135,3 +3547,78 @@ async fn server_shutdown() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn rename_imports_through_barrel_re_export() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        url!("module.js").to_file_path().unwrap(),
+        "export function foo() {}\n",
+    );
+    fs.insert(
+        url!("index.js").to_file_path().unwrap(),
+        "export * from \"./module\";\n",
+    );
+    fs.insert(
+        url!("consumer.js").to_file_path().unwrap(),
+        "import { foo } from \"./index\";\nfoo();\n",
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(
+            "export function foo() {}\n",
+            url!("module.js"),
+            "javascript",
+        )
+        .await?;
+
+    let edit: lsp::WorkspaceEdit = server
+        .request(
+            "textDocument/rename",
+            "rename",
+            lsp::RenameParams {
+                text_document_position: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: url!("module.js"),
+                    },
+                    position: Position {
+                        line: 0,
+                        character: 16,
+                    },
+                },
+                new_name: String::from("renamed"),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("rename returned None")?;
+
+    let changes = edit.changes.context("the rename has no changes")?;
+    // The barrel doesn't mention the binding, only the file that imports it through the barrel changes
+    assert!(!changes.contains_key(&url!("index.js")));
+    let edits = changes
+        .get(&url!("consumer.js"))
+        .context("the import through the barrel wasn't renamed")?;
+    assert_eq!(edits.len(), 2);
+    assert!(edits.iter().all(|edit| edit.new_text == "renamed"));
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
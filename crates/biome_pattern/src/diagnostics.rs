@@ -0,0 +1,27 @@
+use biome_diagnostics::Diagnostic;
+use serde::{Deserialize, Serialize};
+
+/// Error emitted when the source of a pattern can't be compiled
+#[derive(Debug, Deserialize, Diagnostic, Serialize)]
+#[diagnostic(
+    category = "search",
+    message(
+        message("Invalid pattern: "{self.reason}),
+        description = "Invalid pattern: {reason}"
+    )
+)]
+pub struct PatternError {
+    reason: String,
+}
+
+impl PatternError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
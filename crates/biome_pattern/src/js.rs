@@ -0,0 +1,134 @@
+use crate::{Metavariable, PatternError, PatternLanguage};
+use biome_diagnostics::PrintDescription;
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::{
+    inner_string_text, AnyJsExpression, AnyJsRoot, AnyJsStatement, AnyTsType,
+    JsExpressionStatement, JsFileSource, JsFunctionBody, JsFunctionDeclaration, JsLanguage,
+    JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+};
+use biome_rowan::{AstNode, AstNodeList};
+
+/// Name of the function wrapping the patterns that are only valid inside a
+/// function body, e.g. `return $value;`
+const FUNCTION_BODY_PATTERN: &str = "__biome_pattern__";
+
+impl PatternLanguage for JsLanguage {
+    fn parse_pattern(source: &str) -> Result<JsSyntaxNode, PatternError> {
+        // TSX is the most permissive flavour: it accepts type annotations and JSX
+        let parsed = parse(source, JsFileSource::tsx(), JsParserOptions::default());
+        let Some(diagnostic) = parsed.diagnostics().first() else {
+            return Ok(parsed.syntax());
+        };
+
+        // Statements such as `return`, `yield` and `await` are only valid inside a function body
+        let wrapped = format!("async function* {FUNCTION_BODY_PATTERN}() {{\n{source}\n}}");
+        let parsed_in_function = parse(&wrapped, JsFileSource::tsx(), JsParserOptions::default());
+        if parsed_in_function.diagnostics().is_empty() {
+            return Ok(parsed_in_function.syntax());
+        }
+
+        Err(PatternError::new(format!(
+            "The pattern isn't valid code: {}",
+            PrintDescription(diagnostic)
+        )))
+    }
+
+    fn pattern_node(root: &JsSyntaxNode) -> Option<JsSyntaxNode> {
+        let mut items: Vec<_> = match AnyJsRoot::cast_ref(root)? {
+            AnyJsRoot::JsModule(module) => {
+                module.items().iter().map(AstNode::into_syntax).collect()
+            }
+            AnyJsRoot::JsScript(script) => script
+                .statements()
+                .iter()
+                .map(AstNode::into_syntax)
+                .collect(),
+            AnyJsRoot::JsExpressionSnipped(snipped) => {
+                return snipped.expression().ok().map(AstNode::into_syntax)
+            }
+        };
+
+        if let Some(body) = items.first().and_then(function_body_pattern) {
+            items = body.statements().iter().map(AstNode::into_syntax).collect();
+        }
+
+        let [item] = items.as_slice() else {
+            return None;
+        };
+
+        // An expression statement such as `foo();` matches the expression `foo()`, wherever it's placed
+        match JsExpressionStatement::cast_ref(item) {
+            Some(statement) => {
+                let mut expression = statement.expression().ok()?;
+                // Parentheses are needed to write some expressions as statements, e.g. `({ a })`
+                while let AnyJsExpression::JsParenthesizedExpression(parenthesized) = expression {
+                    expression = parenthesized.expression().ok()?;
+                }
+                Some(expression.into_syntax())
+            }
+            None => Some(item.clone()),
+        }
+    }
+
+    fn metavariable(node: &JsSyntaxNode) -> Option<Metavariable> {
+        // Lists made of a single `$name` element, e.g. the arguments of `foo($name)`, aren't
+        // metavariables: their elements are matched one by one
+        if !(AnyJsExpression::can_cast(node.kind())
+            || AnyJsStatement::can_cast(node.kind())
+            || AnyTsType::can_cast(node.kind())
+            || is_identifier_kind(node.kind()))
+        {
+            return None;
+        }
+
+        let first = node.first_token()?;
+        if first.kind() != JsSyntaxKind::IDENT || node.last_token()? != first {
+            return None;
+        }
+
+        Metavariable::from_text(first.text_trimmed())
+    }
+
+    fn are_tokens_equivalent(left: &JsSyntaxToken, right: &JsSyntaxToken) -> bool {
+        // Quotes of string literals are a matter of formatting
+        left.kind() == right.kind() && inner_string_text(left) == inner_string_text(right)
+    }
+
+    fn can_replace(matched: &JsSyntaxNode, replacement: &JsSyntaxNode) -> bool {
+        // Any expression can take the place of another expression, the same goes for statements
+        (AnyJsExpression::can_cast(matched.kind()) && AnyJsExpression::can_cast(replacement.kind()))
+            || (AnyJsStatement::can_cast(matched.kind())
+                && AnyJsStatement::can_cast(replacement.kind()))
+            || matched.kind() == replacement.kind()
+    }
+}
+
+/// Returns the body of the function wrapping a pattern that is only valid inside a function body
+fn function_body_pattern(item: &JsSyntaxNode) -> Option<JsFunctionBody> {
+    let function = JsFunctionDeclaration::cast_ref(item)?;
+    let name = function
+        .id()
+        .ok()?
+        .as_js_identifier_binding()?
+        .name_token()
+        .ok()?;
+    if name.text_trimmed() != FUNCTION_BODY_PATTERN {
+        return None;
+    }
+
+    function.body().ok()
+}
+
+/// Whether `kind` is the kind of a node made of a single identifier, e.g. a binding or the name of a member
+fn is_identifier_kind(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JsSyntaxKind::JS_REFERENCE_IDENTIFIER
+            | JsSyntaxKind::JS_IDENTIFIER_BINDING
+            | JsSyntaxKind::JS_IDENTIFIER_ASSIGNMENT
+            | JsSyntaxKind::JS_NAME
+            | JsSyntaxKind::JS_LITERAL_MEMBER_NAME
+            | JsSyntaxKind::JSX_NAME
+            | JsSyntaxKind::JSX_REFERENCE_IDENTIFIER
+    )
+}
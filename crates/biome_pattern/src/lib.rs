@@ -0,0 +1,433 @@
+//! Structural search of code snippets inside Biome syntax trees.
+//!
+//! A pattern is a snippet of code written in the language of the files being searched,
+//! for example `console.log($msg)`. Identifiers starting with `$` are *metavariables*:
+//!
+//! - `$name` matches any single node, and binds it to `name`. When the same
+//!   metavariable appears more than once in a pattern, all its occurrences must
+//!   match equivalent code;
+//! - `$$$name` matches any sequence of nodes (including an empty one), for
+//!   example the arguments of a call: `foo($$$args)`;
+//! - `$_` and `$$$_` match anything without creating a binding.
+//!
+//! Trivia (whitespaces, newlines and comments) is ignored while matching, hence the
+//! formatting of the searched code doesn't matter.
+//!
+//! A match can be rewritten using another pattern as template, where the metavariables
+//! are replaced by the code they're bound to. [PatternPlugin] uses this to turn a pattern
+//! into a lint rule.
+
+mod diagnostics;
+mod js;
+mod matcher;
+mod plugin;
+
+pub use crate::diagnostics::PatternError;
+use crate::matcher::Matcher;
+pub use crate::plugin::PatternPlugin;
+use biome_rowan::{
+    Direction, Language, SendNode, SyntaxElement, SyntaxNode, SyntaxToken, TextRange, WalkEvent,
+};
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+/// A language that supports structural patterns
+pub trait PatternLanguage: Language + 'static {
+    /// Parses the source of a pattern, and returns the root of the parsed tree
+    fn parse_pattern(source: &str) -> Result<SyntaxNode<Self>, PatternError>;
+
+    /// Returns the node, inside the tree returned by [PatternLanguage::parse_pattern],
+    /// that should be matched against the searched code.
+    fn pattern_node(root: &SyntaxNode<Self>) -> Option<SyntaxNode<Self>>;
+
+    /// Returns the metavariable represented by `node`, if any
+    fn metavariable(node: &SyntaxNode<Self>) -> Option<Metavariable>;
+
+    /// Whether two tokens should be considered equal while matching.
+    ///
+    /// By default, tokens are equivalent when they have the same kind and the same text,
+    /// trivia excluded.
+    fn are_tokens_equivalent(left: &SyntaxToken<Self>, right: &SyntaxToken<Self>) -> bool {
+        left.kind() == right.kind() && left.text_trimmed() == right.text_trimmed()
+    }
+
+    /// Whether `replacement` can take the place of `matched` in a syntax tree.
+    ///
+    /// By default, only nodes of the same kind can replace each other.
+    fn can_replace(matched: &SyntaxNode<Self>, replacement: &SyntaxNode<Self>) -> bool {
+        matched.kind() == replacement.kind()
+    }
+}
+
+/// A placeholder inside a pattern, e.g. `$msg` or `$$$args`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Metavariable {
+    /// The name of the metavariable, without the `$` prefix
+    name: String,
+    /// Whether the metavariable can match a sequence of nodes
+    variadic: bool,
+}
+
+impl Metavariable {
+    /// Creates a metavariable from its textual representation, e.g. `$msg` or `$$$args`.
+    ///
+    /// Returns [None] if `text` isn't a metavariable. The single `$` isn't considered
+    /// a metavariable, as it's a commonly used identifier.
+    pub fn from_text(text: &str) -> Option<Self> {
+        let (name, variadic) = match text.strip_prefix("$$$") {
+            Some(name) => (name, true),
+            None => (text.strip_prefix('$')?, false),
+        };
+
+        if name.is_empty() || name.starts_with('$') {
+            return None;
+        }
+
+        Some(Self {
+            name: name.to_string(),
+            variadic,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub const fn is_variadic(&self) -> bool {
+        self.variadic
+    }
+
+    /// Anonymous metavariables (`$_`) match anything, but they don't bind what they match
+    pub fn is_anonymous(&self) -> bool {
+        self.name == "_"
+    }
+}
+
+/// A compiled pattern, ready to be matched against syntax trees of the language `L`.
+///
+/// The type is [Send] and [Sync], so it can be shared across threads.
+pub struct Pattern<L> {
+    /// The source code of the pattern
+    source: String,
+    /// The root of the parsed pattern
+    root: SendNode,
+    _language: PhantomData<L>,
+}
+
+impl<L> Clone for Pattern<L> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            root: self.root.clone(),
+            _language: PhantomData,
+        }
+    }
+}
+
+impl<L> Debug for Pattern<L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pattern")
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+impl<L: PatternLanguage> Pattern<L> {
+    /// Parses and validates `source` as a pattern
+    pub fn parse(source: &str) -> Result<Self, PatternError> {
+        let root = L::parse_pattern(source)?;
+        if L::pattern_node(&root).is_none() {
+            return Err(PatternError::new(
+                "The pattern must contain exactly one expression or statement.",
+            ));
+        }
+
+        Ok(Self {
+            source: source.to_string(),
+            root: root
+                .as_send()
+                .expect("the parsed pattern should be a root node"),
+            _language: PhantomData,
+        })
+    }
+
+    /// The source code of the pattern
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Iterates over the metavariables of the pattern, in document order
+    pub fn metavariables(&self) -> impl Iterator<Item = Metavariable> {
+        self.node()
+            .descendants()
+            .filter_map(|node| L::metavariable(&node))
+    }
+
+    fn node(&self) -> SyntaxNode<L> {
+        let root = self
+            .root
+            .clone()
+            .into_node::<L>()
+            .expect("the pattern was parsed with the same language");
+        L::pattern_node(&root).expect("the pattern was validated when parsed")
+    }
+
+    /// Returns whether `target` matches this pattern. The descendants of `target` aren't inspected.
+    pub fn matches(&self, target: &SyntaxNode<L>) -> Option<PatternMatch<L>> {
+        let mut matcher = Matcher::default();
+        if matcher.match_node(&self.node(), target) {
+            Some(PatternMatch {
+                node: target.clone(),
+                bindings: matcher.into_bindings(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns all the nodes inside `root` (included) that match this pattern, in document order.
+    ///
+    /// Matches can be nested: when a node matches, its descendants are still inspected.
+    pub fn find_matches(&self, root: &SyntaxNode<L>) -> Vec<PatternMatch<L>> {
+        let pattern = self.node();
+        let mut matches = Vec::new();
+        for event in root.preorder() {
+            if let WalkEvent::Enter(node) = event {
+                let mut matcher = Matcher::default();
+                if matcher.match_node(&pattern, &node) {
+                    matches.push(PatternMatch {
+                        node,
+                        bindings: matcher.into_bindings(),
+                    });
+                }
+            }
+        }
+        matches
+    }
+}
+
+/// A node that matched a [Pattern], with the code bound to the metavariables of the pattern
+#[derive(Debug, Clone)]
+pub struct PatternMatch<L: Language> {
+    node: SyntaxNode<L>,
+    bindings: Vec<(String, Vec<SyntaxElement<L>>)>,
+}
+
+impl<L: Language> PatternMatch<L> {
+    /// The node that matched the pattern
+    pub fn node(&self) -> &SyntaxNode<L> {
+        &self.node
+    }
+
+    /// The range of the match, trivia excluded
+    pub fn range(&self) -> TextRange {
+        self.node.text_trimmed_range()
+    }
+
+    /// The elements bound to the metavariable called `name`
+    pub fn binding(&self, name: &str) -> Option<&[SyntaxElement<L>]> {
+        self.bindings
+            .iter()
+            .find(|(binding, _)| binding == name)
+            .map(|(_, elements)| elements.as_slice())
+    }
+
+    /// The source code bound to the metavariable called `name`, trivia around it excluded.
+    ///
+    /// Returns an empty string for a variadic metavariable that matched an empty sequence.
+    pub fn binding_text(&self, name: &str) -> Option<String> {
+        let elements = self.binding(name)?;
+        let (Some(first), Some(last)) = (elements.first(), elements.last()) else {
+            return Some(String::new());
+        };
+
+        let range = TextRange::new(
+            first.text_trimmed_range().start(),
+            last.text_trimmed_range().end(),
+        );
+        let text = self
+            .node
+            .text()
+            .slice(range - self.node.text_range().start());
+        Some(text.to_string())
+    }
+
+    /// Iterates over the names of the metavariables bound by this match
+    pub fn binding_names(&self) -> impl Iterator<Item = &str> {
+        self.bindings.iter().map(|(name, _)| name.as_str())
+    }
+}
+
+impl<L: PatternLanguage> PatternMatch<L> {
+    /// Builds the code that should replace this match, by substituting the
+    /// metavariables of `template` with the code they're bound to.
+    ///
+    /// The returned node is detached, and it doesn't have leading or trailing trivia.
+    pub fn rewrite(&self, template: &Pattern<L>) -> Result<SyntaxNode<L>, PatternError> {
+        let mut text = String::new();
+        let mut events = template.node().preorder_with_tokens(Direction::Next);
+        while let Some(event) = events.next() {
+            let WalkEvent::Enter(element) = event else {
+                continue;
+            };
+
+            match element {
+                SyntaxElement::Node(node) => {
+                    let Some(metavariable) = L::metavariable(&node) else {
+                        continue;
+                    };
+                    let binding = self.binding_text(metavariable.name()).ok_or_else(|| {
+                        PatternError::new(format!(
+                            "The metavariable ${} isn't bound by the pattern.",
+                            metavariable.name()
+                        ))
+                    })?;
+
+                    // Keep the trivia of the template around the substituted code
+                    let token = node
+                        .first_token()
+                        .expect("a metavariable is made of a single token");
+                    text.push_str(token.leading_trivia().text());
+                    text.push_str(&binding);
+                    text.push_str(token.trailing_trivia().text());
+                    events.skip_subtree();
+                }
+                SyntaxElement::Token(token) => text.push_str(token.text()),
+            }
+        }
+
+        let root = L::parse_pattern(text.trim())?;
+        let node = L::pattern_node(&root).ok_or_else(|| {
+            PatternError::new("The rewritten code must be a single expression or statement.")
+        })?;
+
+        Ok(node.detach())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::{JsFileSource, JsLanguage};
+
+    fn find(pattern: &str, code: &str) -> Vec<String> {
+        let pattern = Pattern::<JsLanguage>::parse(pattern).unwrap();
+        let root = parse(code, JsFileSource::tsx(), JsParserOptions::default()).syntax();
+        pattern
+            .find_matches(&root)
+            .into_iter()
+            .map(|found| found.node().text_trimmed().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn matches_ignoring_trivia() {
+        let found = find(
+            "console.log($msg)",
+            "console.log('a');\nconsole . log ( /* comment */ b );\nconsole.info(c);",
+        );
+        assert_eq!(
+            found,
+            ["console.log('a')", "console . log ( /* comment */ b )"]
+        );
+    }
+
+    #[test]
+    fn binds_metavariables() {
+        let pattern = Pattern::<JsLanguage>::parse("foo($first, $second)").unwrap();
+        let root = parse(
+            "foo(a.b, 1 + 2)",
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        )
+        .syntax();
+        let found = pattern.find_matches(&root);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].binding_text("first").as_deref(), Some("a.b"));
+        assert_eq!(found[0].binding_text("second").as_deref(), Some("1 + 2"));
+    }
+
+    #[test]
+    fn metavariables_match_a_single_node() {
+        let found = find(
+            "console.log($msg)",
+            "console.log(); console.log(a); console.log(a, b);",
+        );
+        assert_eq!(found, ["console.log(a)"]);
+    }
+
+    #[test]
+    fn repeated_metavariables_must_be_equivalent() {
+        let found = find("$a === $a", "x === x; x === y; x.y===x . y;");
+        assert_eq!(found, ["x === x", "x.y===x . y"]);
+    }
+
+    #[test]
+    fn variadic_metavariables() {
+        let found = find("foo($$$args)", "foo(); foo(a); foo(a, b, c); bar(a);");
+        assert_eq!(found, ["foo()", "foo(a)", "foo(a, b, c)"]);
+
+        let pattern = Pattern::<JsLanguage>::parse("foo(a, $$$rest)").unwrap();
+        let root = parse(
+            "foo(a, b, c)",
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        )
+        .syntax();
+        let found = pattern.find_matches(&root);
+        assert_eq!(found[0].binding_text("rest").as_deref(), Some("b, c"));
+    }
+
+    #[test]
+    fn string_quotes_are_ignored() {
+        let found = find("require('fs')", r#"require("fs"); require('path');"#);
+        assert_eq!(found, [r#"require("fs")"#]);
+    }
+
+    #[test]
+    fn statement_patterns() {
+        let found = find(
+            "if ($cond) { return; }",
+            "function f() { if (a) { return; } if (b) { return 1; } }",
+        );
+        assert_eq!(found, ["if (a) { return; }"]);
+    }
+
+    #[test]
+    fn function_body_statement_patterns() {
+        let found = find(
+            "return $value;",
+            "function f() { if (a) { return; } return b; }",
+        );
+        assert_eq!(found, ["return b;"]);
+    }
+
+    #[test]
+    fn invalid_patterns() {
+        assert!(Pattern::<JsLanguage>::parse("").is_err());
+        assert!(Pattern::<JsLanguage>::parse("foo(").is_err());
+        assert!(Pattern::<JsLanguage>::parse("a; b;").is_err());
+    }
+
+    #[test]
+    fn metavariable_from_text() {
+        assert_eq!(
+            Metavariable::from_text("$msg"),
+            Some(Metavariable {
+                name: "msg".to_string(),
+                variadic: false
+            })
+        );
+        assert_eq!(
+            Metavariable::from_text("$$$args"),
+            Some(Metavariable {
+                name: "args".to_string(),
+                variadic: true
+            })
+        );
+        assert_eq!(Metavariable::from_text("$"), None);
+        assert_eq!(Metavariable::from_text("$$"), None);
+        assert_eq!(Metavariable::from_text("msg"), None);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{Metavariable, PatternLanguage};
+use biome_rowan::{Direction, NodeOrToken, SyntaxElement, SyntaxNode, SyntaxToken};
+
+type Bindings<L> = Vec<(String, Vec<SyntaxElement<L>>)>;
+
+/// Matches a pattern against a node, collecting the code bound to the metavariables
+pub(crate) struct Matcher<L: PatternLanguage> {
+    bindings: Bindings<L>,
+}
+
+impl<L: PatternLanguage> Default for Matcher<L> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+}
+
+impl<L: PatternLanguage> Matcher<L> {
+    pub(crate) fn into_bindings(self) -> Bindings<L> {
+        self.bindings
+    }
+
+    /// Two nodes match when they have the same kind and all their children match, in order.
+    /// A metavariable matches any node.
+    pub(crate) fn match_node(&mut self, pattern: &SyntaxNode<L>, target: &SyntaxNode<L>) -> bool {
+        if let Some(metavariable) = L::metavariable(pattern) {
+            return self.bind(&metavariable, vec![target.clone().into()]);
+        }
+
+        if pattern.kind() != target.kind() {
+            return false;
+        }
+
+        let pattern_children: Vec<_> = pattern.children_with_tokens().collect();
+        let target_children: Vec<_> = target.children_with_tokens().collect();
+        self.match_sequence(&pattern_children, &target_children)
+    }
+
+    fn match_element(&mut self, pattern: &SyntaxElement<L>, target: &SyntaxElement<L>) -> bool {
+        match (pattern, target) {
+            (NodeOrToken::Node(pattern), NodeOrToken::Node(target)) => {
+                self.match_node(pattern, target)
+            }
+            (NodeOrToken::Node(pattern), NodeOrToken::Token(_)) => match L::metavariable(pattern) {
+                Some(metavariable) => self.bind(&metavariable, vec![target.clone()]),
+                None => false,
+            },
+            (NodeOrToken::Token(pattern), NodeOrToken::Token(target)) => {
+                L::are_tokens_equivalent(pattern, target)
+            }
+            (NodeOrToken::Token(_), NodeOrToken::Node(_)) => false,
+        }
+    }
+
+    /// Matches two lists of children. Variadic metavariables can consume any number of
+    /// elements, so the function backtracks until the rest of the sequence matches.
+    fn match_sequence(
+        &mut self,
+        patterns: &[SyntaxElement<L>],
+        targets: &[SyntaxElement<L>],
+    ) -> bool {
+        let Some((pattern, rest)) = patterns.split_first() else {
+            return targets.is_empty();
+        };
+
+        let variadic = pattern
+            .as_node()
+            .and_then(L::metavariable)
+            .filter(Metavariable::is_variadic);
+
+        if let Some(metavariable) = variadic {
+            for consumed in 0..=targets.len() {
+                let snapshot = self.bindings.clone();
+                if self.bind(&metavariable, targets[..consumed].to_vec())
+                    && self.match_sequence(rest, &targets[consumed..])
+                {
+                    return true;
+                }
+                self.bindings = snapshot;
+            }
+            return false;
+        }
+
+        match targets.split_first() {
+            Some((target, targets)) => {
+                self.match_element(pattern, target) && self.match_sequence(rest, targets)
+            }
+            None => false,
+        }
+    }
+
+    /// Binds `elements` to `metavariable`. If the metavariable was already bound,
+    /// the new elements must be equivalent to the ones that were bound before.
+    fn bind(&mut self, metavariable: &Metavariable, elements: Vec<SyntaxElement<L>>) -> bool {
+        if metavariable.is_anonymous() {
+            return true;
+        }
+
+        let bound = self
+            .bindings
+            .iter()
+            .find(|(name, _)| name == metavariable.name());
+
+        match bound {
+            Some((_, bound)) => are_equivalent::<L>(bound, &elements),
+            None => {
+                self.bindings
+                    .push((metavariable.name().to_string(), elements));
+                true
+            }
+        }
+    }
+}
+
+/// Checks that two sequences of elements contain equivalent tokens, trivia excluded
+fn are_equivalent<L: PatternLanguage>(
+    left: &[SyntaxElement<L>],
+    right: &[SyntaxElement<L>],
+) -> bool {
+    let mut left = left.iter().flat_map(tokens);
+    let mut right = right.iter().flat_map(tokens);
+    loop {
+        match (left.next(), right.next()) {
+            (Some(left), Some(right)) => {
+                if !L::are_tokens_equivalent(&left, &right) {
+                    return false;
+                }
+            }
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+fn tokens<L: PatternLanguage>(
+    element: &SyntaxElement<L>,
+) -> Box<dyn Iterator<Item = SyntaxToken<L>>> {
+    match element {
+        NodeOrToken::Node(node) => Box::new(node.descendants_tokens(Direction::Next)),
+        NodeOrToken::Token(token) => Box::new(std::iter::once(token.clone())),
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{Pattern, PatternError, PatternLanguage, PatternMatch};
+use biome_analyze::{ActionCategory, AnalyzerAction, AnalyzerPlugin, PluginSignal};
+use biome_console::markup;
+use biome_diagnostics::{Applicability, Severity};
+use biome_rowan::{BatchMutation, SyntaxNode};
+
+/// A lint rule defined by a [Pattern]: every match of the pattern is reported
+/// with the same message.
+///
+/// When a rewrite template is provided, the diagnostics come with a code action
+/// that replaces the match with the template, where the metavariables are
+/// substituted with the code they matched.
+#[derive(Debug)]
+pub struct PatternPlugin<L: PatternLanguage> {
+    name: String,
+    pattern: Pattern<L>,
+    message: String,
+    severity: Severity,
+    rewrite: Option<Pattern<L>>,
+}
+
+impl<L: PatternLanguage> PatternPlugin<L> {
+    pub fn new(
+        name: impl Into<String>,
+        pattern: Pattern<L>,
+        message: impl Into<String>,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            pattern,
+            message: message.into(),
+            severity,
+            rewrite: None,
+        }
+    }
+
+    /// Sets the template used to rewrite the matches of the pattern.
+    ///
+    /// Returns an error if the template uses a metavariable that the pattern doesn't bind.
+    pub fn with_rewrite(mut self, rewrite: Pattern<L>) -> Result<Self, PatternError> {
+        if let Some(unbound) = rewrite.metavariables().find(|metavariable| {
+            metavariable.is_anonymous()
+                || !self
+                    .pattern
+                    .metavariables()
+                    .any(|bound| bound.name() == metavariable.name())
+        }) {
+            return Err(PatternError::new(format!(
+                "The rewrite of the plugin {} uses the metavariable ${}, which isn't bound by its pattern.",
+                self.name,
+                unbound.name()
+            )));
+        }
+
+        self.rewrite = Some(rewrite);
+        Ok(self)
+    }
+
+    fn action(&self, root: &SyntaxNode<L>, found: &PatternMatch<L>) -> Option<AnalyzerAction<L>> {
+        let rewrite = self.rewrite.as_ref()?;
+        let replacement = found.rewrite(rewrite).ok()?;
+
+        // A rewrite that still matches the pattern would be applied forever
+        if !L::can_replace(found.node(), &replacement)
+            || self.pattern.matches(&replacement).is_some()
+        {
+            return None;
+        }
+
+        let mut mutation = BatchMutation::new(root.clone());
+        mutation.replace_element(found.node().clone().into(), replacement.into());
+
+        Some(AnalyzerAction {
+            rule_name: None,
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Rewrite the code as suggested by the plugin." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+impl<L> AnalyzerPlugin<L> for PatternPlugin<L>
+where
+    L: PatternLanguage + Send + Sync + 'static,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn evaluate(&self, root: &SyntaxNode<L>) -> Vec<PluginSignal<L>> {
+        self.pattern
+            .find_matches(root)
+            .into_iter()
+            .map(|found| {
+                let message = &self.message;
+                let signal = PluginSignal::new(found.range(), markup! { {message} }.to_owned())
+                    .with_severity(self.severity);
+                match self.action(root, &found) {
+                    Some(action) => signal.with_action(action),
+                    None => signal,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::{JsFileSource, JsLanguage};
+
+    fn plugin(pattern: &str, rewrite: Option<&str>) -> PatternPlugin<JsLanguage> {
+        let plugin = PatternPlugin::new(
+            "test",
+            Pattern::parse(pattern).unwrap(),
+            "Found a match.",
+            Severity::Warning,
+        );
+        match rewrite {
+            Some(rewrite) => plugin
+                .with_rewrite(Pattern::parse(rewrite).unwrap())
+                .unwrap(),
+            None => plugin,
+        }
+    }
+
+    fn fix(plugin: &PatternPlugin<JsLanguage>, code: &str) -> Vec<String> {
+        let root = parse(code, JsFileSource::js_module(), JsParserOptions::default()).syntax();
+        plugin
+            .evaluate(&root)
+            .into_iter()
+            .filter_map(|signal| signal.action().cloned())
+            .map(|action| action.mutation.commit().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn reports_matches() {
+        let plugin = plugin("console.log($msg)", None);
+        let root = parse(
+            "console.log(a); console.info(b); console.log(c);",
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        )
+        .syntax();
+        assert_eq!(plugin.evaluate(&root).len(), 2);
+    }
+
+    #[test]
+    fn rewrites_matches() {
+        let plugin = plugin("console.log($msg)", Some("logger.info($msg)"));
+        assert_eq!(
+            fix(&plugin, "console.log( 'a' + b ); // comment"),
+            ["logger.info('a' + b); // comment"]
+        );
+    }
+
+    #[test]
+    fn rewrites_variadic_metavariables() {
+        let plugin = plugin(
+            "$obj.indexOf($$$args) !== -1",
+            Some("$obj.includes($$$args)"),
+        );
+        assert_eq!(
+            fix(&plugin, "if (list.indexOf(x, 1) !== -1) {}"),
+            ["if (list.includes(x, 1)) {}"]
+        );
+    }
+
+    #[test]
+    fn skips_rewrites_that_still_match() {
+        let plugin = plugin("foo($a)", Some("foo(foo($a))"));
+        assert!(fix(&plugin, "foo(a)").is_empty());
+    }
+
+    #[test]
+    fn rejects_unbound_metavariables() {
+        let plugin = PatternPlugin::<JsLanguage>::new(
+            "test",
+            Pattern::parse("console.log($msg)").unwrap(),
+            "Found a match.",
+            Severity::Warning,
+        );
+        assert!(plugin
+            .with_rewrite(Pattern::parse("logger.info($other)").unwrap())
+            .is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use biome_css_syntax::{
+    AnyCssKeyframeName, CssClassSelector, CssComposesProperty, CssKeyframesAtRule,
+    CssPseudoClassFunctionSelector, CssRoot, CssSyntaxToken, CssValueAtRuleDeclarationClause,
+    CssValueAtRuleImportSpecifier, CssValueAtRuleNamedImportSpecifier, T,
+};
+use biome_rowan::{AstNode, SyntaxResult};
+use rustc_hash::FxHashSet;
+
+/// The names exported by a [CSS module](https://github.com/css-modules/css-modules),
+/// which files can access as properties of the object they import, e.g.
+/// `styles.button`.
+#[derive(Debug, Default, Clone)]
+pub struct CssModule {
+    /// The local classes defined by the module, in order of appearance
+    classes: Vec<String>,
+    /// The local classes that other classes of the module compose
+    composed_classes: FxHashSet<String>,
+    /// The names of the values declared or imported with `@value` and of the keyframes
+    values: FxHashSet<String>,
+}
+
+impl CssModule {
+    pub fn from_root(root: &CssRoot) -> Self {
+        let mut module = Self::default();
+
+        for node in root.syntax().descendants() {
+            if let Some(selector) = CssClassSelector::cast_ref(&node) {
+                if !is_global_selector(&selector) {
+                    if let Ok(name) = selector.name().and_then(|name| name.value_token()) {
+                        module.add_class(name.text_trimmed());
+                    }
+                }
+            } else if let Some(property) = CssComposesProperty::cast_ref(&node) {
+                // The classes composed from other modules aren't defined by this one
+                if let Ok(value) = property.value() {
+                    if value.specifier().is_none() {
+                        for class in value.classes() {
+                            if let Ok(name) = class.value_token() {
+                                module
+                                    .composed_classes
+                                    .insert(name.text_trimmed().to_string());
+                            }
+                        }
+                    }
+                }
+            } else if let Some(clause) = CssValueAtRuleDeclarationClause::cast_ref(&node) {
+                module.add_value(clause.name().and_then(|name| name.value_token()));
+            } else if let Some(specifier) = CssValueAtRuleImportSpecifier::cast_ref(&node) {
+                module.add_value(specifier.name().and_then(|name| name.value_token()));
+            } else if let Some(specifier) = CssValueAtRuleNamedImportSpecifier::cast_ref(&node) {
+                module.add_value(specifier.local_name().and_then(|name| name.value_token()));
+            } else if let Some(at_rule) = CssKeyframesAtRule::cast_ref(&node) {
+                if let Ok(AnyCssKeyframeName::CssCustomIdentifier(name)) = at_rule.name() {
+                    module.add_value(name.value_token());
+                }
+            }
+        }
+
+        module
+    }
+
+    fn add_class(&mut self, name: &str) {
+        if !self.classes.iter().any(|class| class == name) {
+            self.classes.push(name.to_string());
+        }
+    }
+
+    fn add_value(&mut self, name: SyntaxResult<CssSyntaxToken>) {
+        if let Ok(name) = name {
+            self.values.insert(name.text_trimmed().to_string());
+        }
+    }
+
+    /// The local classes defined by the module, in order of appearance
+    pub fn classes(&self) -> impl Iterator<Item = &str> {
+        self.classes.iter().map(String::as_str)
+    }
+
+    /// Returns `true` if another class of the module composes `class`
+    pub fn is_composed(&self, class: &str) -> bool {
+        self.composed_classes.contains(class)
+    }
+
+    /// Returns `true` if `name`, accessed as a property of the imported
+    /// object, refers to a class or a value of the module. Classes containing
+    /// dashes can also be accessed in camel case, e.g. `styles.primaryButton`
+    /// for `.primary-button`.
+    pub fn exports(&self, name: &str) -> bool {
+        self.values.contains(name)
+            || self
+                .classes()
+                .any(|class| is_class_accessed_as(class, name))
+    }
+}
+
+/// Returns `true` if the class can be accessed from JavaScript with `name`,
+/// either as is or in camel case.
+pub fn is_class_accessed_as(class: &str, name: &str) -> bool {
+    if class == name {
+        return true;
+    }
+
+    if !class.contains('-') {
+        return false;
+    }
+
+    let mut camel_case = String::with_capacity(class.len());
+    let mut uppercase_next = false;
+    for char in class.chars() {
+        if char == '-' {
+            uppercase_next = !camel_case.is_empty();
+        } else if uppercase_next {
+            camel_case.extend(char.to_uppercase());
+            uppercase_next = false;
+        } else {
+            camel_case.push(char);
+        }
+    }
+
+    camel_case == name
+}
+
+/// Returns `true` if the class is inside `:global(...)`, and so isn't scoped to the module
+fn is_global_selector(selector: &CssClassSelector) -> bool {
+    selector
+        .syntax()
+        .ancestors()
+        .filter_map(CssPseudoClassFunctionSelector::cast)
+        .any(|function| function.name().is_ok_and(|name| name.kind() == T![global]))
+}
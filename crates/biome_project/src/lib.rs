@@ -1,14 +1,21 @@
+mod css_module;
 mod diagnostics;
 mod license;
 mod node_js_project;
+mod project_imports;
 
+pub use crate::css_module::{is_class_accessed_as, CssModule};
 pub use crate::diagnostics::{ProjectAnalyzeDiagnostic, ProjectDiagnostic};
 use biome_deserialize::{DeserializationDiagnostic, Deserialized};
 use biome_diagnostics::serde::Diagnostic;
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_rowan::Language;
 pub use license::generated::*;
-pub use node_js_project::{NodeJsProject, PackageJson};
+pub use node_js_project::{
+    is_valid_dependency_version, Dependencies, Exports, NodeJsProject, PackageJson, Version,
+    Workspaces,
+};
+pub use project_imports::ProjectImports;
 use std::any::TypeId;
 use std::fmt::Debug;
 use std::path::Path;
@@ -20,4 +20,36 @@ impl LicenseList {
 
         license_found.is_some_and(|license| license.is_deprecated_license_id)
     }
+
+    /// Returns the first license of the SPDX `expression` that isn't in the list, e.g. `Foo`
+    /// in `(MIT OR Foo)`. The special values `UNLICENSED` and `SEE LICENSE IN <file>` are accepted.
+    pub fn find_unknown_license<'a>(&self, expression: &'a str) -> Option<&'a str> {
+        let expression = expression.trim();
+        if expression == "UNLICENSED" || expression.starts_with("SEE LICENSE IN ") {
+            return None;
+        }
+
+        let mut words = expression
+            .split(|c: char| c.is_whitespace() || matches!(c, '(' | ')'))
+            .filter(|word| !word.is_empty());
+        let mut has_license = false;
+        while let Some(word) = words.next() {
+            match word {
+                "AND" | "OR" => {}
+                // The exception that follows, e.g. `Classpath-exception-2.0`, isn't a license
+                "WITH" => {
+                    words.next();
+                }
+                _ => {
+                    has_license = true;
+                    let license_id = word.strip_suffix('+').unwrap_or(word);
+                    if !license_id.starts_with("LicenseRef-") && !self.is_valid(license_id) {
+                        return Some(word);
+                    }
+                }
+            }
+        }
+
+        (!has_license).then_some(expression)
+    }
 }
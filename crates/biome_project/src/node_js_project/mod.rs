@@ -1,6 +1,8 @@
 mod package_json;
 
-pub use crate::node_js_project::package_json::PackageJson;
+pub use crate::node_js_project::package_json::{
+    is_valid_dependency_version, Dependencies, Exports, PackageJson, Version, Workspaces,
+};
 use crate::{Manifest, Project, ProjectAnalyzeDiagnostic, ProjectAnalyzeResult, LICENSE_LIST};
 use biome_json_syntax::JsonRoot;
 use biome_rowan::Language;
@@ -49,10 +51,10 @@ impl Project for NodeJsProject {
     fn analyze(&self) -> ProjectAnalyzeResult {
         let mut diagnostics = vec![];
         if let Some((license, range)) = &self.manifest.license {
-            if !LICENSE_LIST.is_valid(license) {
+            if LICENSE_LIST.find_unknown_license(license).is_some() {
                 diagnostics
                     .push(ProjectAnalyzeDiagnostic::new_invalid_license(license).with_range(range))
-            } else if !LICENSE_LIST.is_deprecated(license) {
+            } else if LICENSE_LIST.is_deprecated(license) {
                 diagnostics.push(
                     ProjectAnalyzeDiagnostic::new_deprecated_license(license).with_range(range),
                 )
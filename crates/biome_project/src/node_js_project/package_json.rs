@@ -13,9 +13,15 @@ pub struct PackageJson {
     pub version: Option<Version>,
     pub name: Option<String>,
     pub description: Option<String>,
+    pub scripts: FxHashMap<String, String>,
+    pub exports: Option<Exports>,
+    pub engines: FxHashMap<String, String>,
     pub dependencies: Dependencies,
     pub dev_dependencies: Dependencies,
+    pub peer_dependencies: Dependencies,
     pub optional_dependencies: Dependencies,
+    pub workspaces: Workspaces,
+    pub files: Vec<String>,
     pub license: Option<(String, TextRange)>,
 }
 
@@ -46,6 +52,48 @@ pub enum Version {
     Literal(String),
 }
 
+impl Version {
+    pub fn new(version: &str) -> Self {
+        match version.parse() {
+            Ok(version) => Self::SemVer(version),
+            Err(_) => Self::Literal(version.to_string()),
+        }
+    }
+}
+
+/// Whether `specifier`, the version of a dependency, is valid.
+///
+/// A specifier that looks like a version range must be a valid semver range.
+/// The other specifiers, such as tags, paths, URLs or `workspace:` protocols, are accepted.
+pub fn is_valid_dependency_version(specifier: &str) -> bool {
+    let specifier = specifier.trim();
+    let looks_like_range = specifier.trim_start_matches('v').starts_with(|c: char| {
+        c.is_ascii_digit() || matches!(c, '^' | '~' | '<' | '>' | '=' | '*')
+    });
+    !looks_like_range || specifier.parse::<node_semver::Range>().is_ok()
+}
+
+/// The entry points of a package, declared by the `exports` field
+#[derive(Debug, Clone)]
+pub enum Exports {
+    /// A path, or `null` for a subpath that isn't exported
+    Target(Option<String>),
+    /// Targets tried in order, until one is supported
+    Fallbacks(Vec<Exports>),
+    /// Subpaths (`"./feature"`) or conditions (`"import"`) associated with their entry points
+    Map(Vec<(String, Exports)>),
+}
+
+/// The glob patterns of the packages of a workspace, declared by the `workspaces` field
+#[derive(Debug, Default, Clone)]
+pub struct Workspaces(Vec<String>);
+
+impl Workspaces {
+    pub fn patterns(&self) -> &[String] {
+        &self.0
+    }
+}
+
 impl Deserializable for PackageJson {
     fn deserialize(
         value: &impl DeserializableValue,
@@ -83,7 +131,6 @@ impl DeserializationVisitor for PackageJsonVisitor {
                 }
                 "license" => {
                     let license_range = value.range();
-                    // TODO: add proper parsing of license, e.g. support for AND keywords
                     result.license = Deserializable::deserialize(&value, &key_text, diagnostics)
                         .map(|license| (license, license_range));
                 }
@@ -91,6 +138,23 @@ impl DeserializationVisitor for PackageJsonVisitor {
                     result.description =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "scripts" => {
+                    if let Some(scripts) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.scripts = scripts;
+                    }
+                }
+                "exports" => {
+                    result.exports = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "engines" => {
+                    if let Some(engines) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.engines = engines;
+                    }
+                }
                 "dependencies" => {
                     if let Some(deps) = Deserializable::deserialize(&value, &key_text, diagnostics)
                     {
@@ -103,12 +167,31 @@ impl DeserializationVisitor for PackageJsonVisitor {
                         result.dev_dependencies = deps;
                     }
                 }
+                "peerDependencies" => {
+                    if let Some(deps) = Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.peer_dependencies = deps;
+                    }
+                }
                 "optionalDependencies" => {
                     if let Some(deps) = Deserializable::deserialize(&value, &key_text, diagnostics)
                     {
                         result.optional_dependencies = deps;
                     }
                 }
+                "workspaces" => {
+                    if let Some(workspaces) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.workspaces = workspaces;
+                    }
+                }
+                "files" => {
+                    if let Some(files) = Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.files = files;
+                    }
+                }
                 _ => {
                     // each package can add their own field, so we should ignore any extraneous key
                     // and only deserialize the ones that Rome deems important
@@ -126,9 +209,132 @@ impl Deserializable for Version {
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self> {
         let value = Text::deserialize(value, name, diagnostics)?;
-        match value.text().parse() {
-            Ok(version) => Some(Version::SemVer(version)),
-            Err(_) => Some(Version::Literal(value.text().to_string())),
+        Some(Version::new(value.text()))
+    }
+}
+
+impl Deserializable for Exports {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(ExportsVisitor, name, diagnostics)
+    }
+}
+
+struct ExportsVisitor;
+impl DeserializationVisitor for ExportsVisitor {
+    type Output = Exports;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::NULL
+        .union(VisitableType::STR)
+        .union(VisitableType::ARRAY)
+        .union(VisitableType::MAP);
+
+    fn visit_null(
+        self,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(Exports::Target(None))
+    }
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(Exports::Target(Some(value.text().to_string())))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let fallbacks = items
+            .flatten()
+            .filter_map(|item| Deserializable::deserialize(&item, name, diagnostics))
+            .collect();
+        Some(Exports::Fallbacks(fallbacks))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut entries = Vec::new();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(exports) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                entries.push((key_text.text().to_string(), exports));
+            }
         }
+        Some(Exports::Map(entries))
+    }
+}
+
+impl Deserializable for Workspaces {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(WorkspacesVisitor, name, diagnostics)
+    }
+}
+
+struct WorkspacesVisitor;
+impl DeserializationVisitor for WorkspacesVisitor {
+    type Output = Workspaces;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::ARRAY.union(VisitableType::MAP);
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let patterns = items
+            .flatten()
+            .filter_map(|item| Deserializable::deserialize(&item, name, diagnostics))
+            .collect();
+        Some(Workspaces(patterns))
+    }
+
+    // Yarn also accepts an object: `{ "packages": [...], "nohoist": [...] }`
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Workspaces::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if key_text.text() == "packages" {
+                if let Some(patterns) = Deserializable::deserialize(&value, &key_text, diagnostics)
+                {
+                    result.0 = patterns;
+                }
+            }
+        }
+        Some(result)
     }
 }
@@ -0,0 +1,62 @@
+use biome_js_syntax::{AnyJsImportSpecifierLike, AnyJsRoot};
+use biome_rowan::AstNode;
+use rustc_hash::FxHashSet;
+
+/// The packages imported by the source files of a project.
+///
+/// A dependency declared in the manifest of the project is unused when
+/// no source file imports it.
+#[derive(Debug, Default, Clone)]
+pub struct ProjectImports {
+    packages: FxHashSet<String>,
+}
+
+impl ProjectImports {
+    /// Adds the packages imported by the module `root`, with `import`, `export ... from`,
+    /// `require()` or `import()`
+    pub fn add_module(&mut self, root: &AnyJsRoot) {
+        for specifier in root
+            .syntax()
+            .descendants()
+            .filter_map(AnyJsImportSpecifierLike::cast)
+        {
+            if let Some(text) = specifier.inner_string_text() {
+                self.add_specifier(text.text());
+            }
+        }
+    }
+
+    /// Adds the package imported by the import specifier `specifier`.
+    /// Paths, URLs, subpath imports (`#internal`) and protocols (`node:fs`)
+    /// don't import a package.
+    pub fn add_specifier(&mut self, specifier: &str) {
+        if let Some(package) = package_name(specifier) {
+            if !self.packages.contains(package) {
+                self.packages.insert(package.to_string());
+            }
+        }
+    }
+
+    /// Returns `true` if a source file imports the package `name`, or one of its subpaths
+    pub fn is_imported(&self, name: &str) -> bool {
+        self.packages.contains(name)
+    }
+}
+
+/// Returns the name of the package imported by the bare specifier `specifier`,
+/// e.g. `@scope/name` for `@scope/name/feature`
+fn package_name(specifier: &str) -> Option<&str> {
+    if specifier.starts_with(['.', '/', '#']) || specifier.contains(':') {
+        return None;
+    }
+
+    let mut segments = specifier.split('/');
+    let first = segments.next().filter(|segment| !segment.is_empty())?;
+    let length = if first.starts_with('@') {
+        let name = segments.next().filter(|segment| !segment.is_empty())?;
+        first.len() + 1 + name.len()
+    } else {
+        first.len()
+    };
+    Some(&specifier[..length])
+}
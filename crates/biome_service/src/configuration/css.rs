@@ -1,8 +1,10 @@
 use crate::configuration::{deserialize_line_width, serialize_line_width, PlainIndentStyle};
+use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{LineEnding, LineWidth, QuoteStyle};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Options applied to CSS files
 #[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -17,6 +19,10 @@ pub struct CssConfiguration {
     /// Formatting options
     #[partial(type, bpaf(external(partial_css_formatter), optional))]
     pub formatter: CssFormatter,
+
+    /// Assists options
+    #[partial(type, bpaf(external(partial_css_assists), optional))]
+    pub assists: CssAssists,
 }
 
 /// Options that changes how the CSS parser behaves
@@ -81,3 +87,50 @@ impl Default for CssFormatter {
         }
     }
 }
+
+/// Options of the source actions applied to CSS files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct CssAssists {
+    /// Sort the declarations of the blocks when organizing the file, e.g. with `biome check --apply`
+    #[partial(bpaf(hide))]
+    pub sort_declarations: bool,
+
+    /// The order of the sorted declarations. Defaults to `grouped`.
+    #[partial(bpaf(hide))]
+    pub declarations_order: CssDeclarationsOrder,
+
+    /// The properties, in order, used when `declarationsOrder` is `custom`
+    #[partial(bpaf(hide))]
+    pub custom_order: StringSet,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum CssDeclarationsOrder {
+    /// Sort the declarations by name
+    Alphabetical,
+    /// Group the declarations by concern: positioning, box model, typography, visual and animation
+    #[default]
+    Grouped,
+    /// Sort the declarations with the properties of `customOrder`
+    Custom,
+}
+
+impl FromStr for CssDeclarationsOrder {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "alphabetical" => Ok(Self::Alphabetical),
+            "grouped" => Ok(Self::Grouped),
+            "custom" => Ok(Self::Custom),
+            _ => Err("Value not supported for CssDeclarationsOrder"),
+        }
+    }
+}
@@ -1,12 +1,15 @@
 mod formatter;
 
-use biome_deserialize::StringSet;
+use biome_deserialize::{Merge, StringSet};
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_js_analyze::globals::JsEnvironment;
 use bpaf::Bpaf;
 pub use formatter::{
     partial_javascript_formatter, JavascriptFormatter, PartialJavascriptFormatter,
 };
+use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// A set of options applied to the JavaScript files
 #[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -28,6 +31,12 @@ pub struct JavascriptConfiguration {
     #[partial(bpaf(hide))]
     pub globals: StringSet,
 
+    /// The environments where the code runs, e.g. `node` or `jest`.
+    ///
+    /// The globals of these environments should not emit diagnostics.
+    #[partial(bpaf(hide))]
+    pub environments: JsEnvironments,
+
     #[partial(type, bpaf(external(partial_javascript_organize_imports), optional))]
     pub organize_imports: JavascriptOrganizeImports,
 }
@@ -38,6 +47,31 @@ pub struct JavascriptConfiguration {
 #[partial(serde(default, deny_unknown_fields))]
 pub struct JavascriptOrganizeImports {}
 
+/// A set of [JsEnvironment]
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JsEnvironments(IndexSet<JsEnvironment>);
+
+impl JsEnvironments {
+    pub fn into_index_set(self) -> IndexSet<JsEnvironment> {
+        self.0
+    }
+}
+
+impl Merge for JsEnvironments {
+    fn merge_with(&mut self, other: Self) {
+        self.0.extend(other.0)
+    }
+}
+
+impl FromStr for JsEnvironments {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(JsEnvironments::default())
+    }
+}
+
 /// Options that changes how the JavaScript parser behaves
 #[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
 #[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
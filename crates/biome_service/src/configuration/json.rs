@@ -1,9 +1,16 @@
-use crate::configuration::{deserialize_line_width, serialize_line_width, PlainIndentStyle};
+use crate::configuration::{
+    deserialize_line_width, serialize_line_width, ConfigurationDiagnostic, PlainIndentStyle,
+};
+use crate::WorkspaceError;
+use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{LineEnding, LineWidth};
+use biome_fs::{FileSystem, OpenOptions};
 use biome_json_formatter::context::TrailingCommas;
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::str::FromStr;
 
 /// Options applied to JSON files
 #[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -18,6 +25,134 @@ pub struct JsonConfiguration {
     /// Formatting options
     #[partial(type, bpaf(external(partial_json_formatter), optional))]
     pub formatter: JsonFormatter,
+
+    /// The JSON schemas that the files must match. Each schema is a local file,
+    /// `draft-07` or `2020-12`, associated to the files matched by the `include` patterns.
+    #[partial(bpaf(hide))]
+    pub schemas: JsonSchemas,
+
+    /// Assists options
+    #[partial(type, bpaf(external(partial_json_assists), optional))]
+    pub assists: JsonAssists,
+}
+
+/// Options of the source actions applied to JSON files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct JsonAssists {
+    /// Sort the keys of the objects when organizing the file, e.g. with `biome check --apply`
+    #[partial(bpaf(hide))]
+    pub sort_keys: bool,
+
+    /// The keys placed first, in order, when sorting the keys. The other keys are sorted
+    /// alphabetically. Use `overrides` to give a different order to some files.
+    #[partial(bpaf(hide))]
+    pub keys_order: StringSet,
+}
+
+/// A list of [JsonSchemaAssociation]
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JsonSchemas(#[bpaf(hide)] pub Vec<JsonSchemaAssociation>);
+
+impl FromStr for JsonSchemas {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+/// Associates a JSON schema to some files
+///
+/// ```json
+/// {
+///     "include": ["services/*/config.json"],
+///     "path": "./schemas/service.schema.json"
+/// }
+/// ```
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonSchemaAssociation {
+    /// A list of Unix shell style patterns. The files that match these patterns are
+    /// validated against the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub include: Option<StringSet>,
+
+    /// The path of the schema file, relative to the configuration file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub path: Option<String>,
+}
+
+impl FromStr for JsonSchemaAssociation {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+/// A JSON schema listed in `json.schemas`, already read from the file system
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaDefinition {
+    /// The patterns of the files validated against the schema
+    pub include: StringSet,
+    /// The content of the schema file
+    pub schema: serde_json::Value,
+}
+
+/// Reads the schema files listed in `schemas`. Their relative paths were already resolved
+/// from the directory of their configuration file when the configuration was loaded.
+pub fn load_json_schemas(
+    fs: &dyn FileSystem,
+    schemas: Option<&JsonSchemas>,
+) -> Result<Vec<JsonSchemaDefinition>, WorkspaceError> {
+    let Some(schemas) = schemas else {
+        return Ok(Vec::new());
+    };
+
+    let mut definitions = Vec::with_capacity(schemas.0.len());
+    for association in &schemas.0 {
+        // An association without schema doesn't validate anything
+        let Some(path) = association.path.as_deref() else {
+            continue;
+        };
+        let schema_path = Path::new(path);
+
+        let mut file = fs
+            .open_with_options(schema_path, OpenOptions::default().read(true))
+            .map_err(|_| WorkspaceError::cant_read_file(schema_path.display().to_string()))?;
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .map_err(|_| WorkspaceError::cant_read_file(schema_path.display().to_string()))?;
+
+        let schema = serde_json::from_str(&content).map_err(|error| {
+            WorkspaceError::Configuration(ConfigurationDiagnostic::invalid_configuration(
+                format_args!(
+                    "The JSON schema {} is invalid: {error}",
+                    schema_path.display()
+                ),
+            ))
+        })?;
+
+        definitions.push(JsonSchemaDefinition {
+            include: association.include.clone().unwrap_or_default(),
+            schema,
+        });
+    }
+
+    Ok(definitions)
 }
 
 /// Options that changes how the JSON parser behaves
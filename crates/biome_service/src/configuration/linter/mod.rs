@@ -10,12 +10,16 @@ use biome_deserialize::{Deserializable, StringSet};
 use biome_deserialize::{DeserializableValue, DeserializationDiagnostic, Merge, VisitableType};
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_diagnostics::Severity;
+use biome_fs::ConfigName;
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_json_syntax::AnyJsonValue;
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
 use bpaf::Bpaf;
 pub use rules::*;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 #[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -81,6 +85,96 @@ pub fn to_linter_settings(
     })
 }
 
+/// Computes the edit that turns off the rule `group/rule` in `content`, the content of the
+/// configuration file at `file_path`.
+///
+/// The members of `linter.rules.<group>` that are missing are inserted, while the rest of
+/// the file is kept as is. Returns the range to replace and the new text, or [None] if the
+/// file can't be parsed or if one of the members isn't an object. When the rule is configured
+/// with options, only its `level` is set to `"off"`.
+pub fn disable_rule_edit(
+    content: &str,
+    file_path: &Path,
+    group: &str,
+    rule: &str,
+) -> Option<(TextRange, String)> {
+    let parser_options =
+        if file_path.file_name().and_then(|s| s.to_str()) == Some(ConfigName::biome_jsonc()) {
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas()
+        } else {
+            JsonParserOptions::default()
+        };
+    let parse = parse_json(content, parser_options);
+    if parse.has_errors() {
+        return None;
+    }
+
+    let AnyJsonValue::JsonObjectValue(mut object) = parse.tree().value().ok()? else {
+        return None;
+    };
+    let mut path = vec!["linter", "rules", group, rule];
+    let mut index = 0;
+    while let Some(key) = path.get(index) {
+        let members = object.json_member_list();
+        let member = members.iter().flatten().find(|member| {
+            member
+                .name()
+                .and_then(|name| name.inner_string_text())
+                .is_ok_and(|name| name.text() == *key)
+        });
+
+        let Some(member) = member else {
+            // Inserts the missing members after the last member of the object
+            let value = path[index + 1..]
+                .iter()
+                .rev()
+                .fold(String::from("\"off\""), |value, key| {
+                    format!("{{ \"{key}\": {value} }}")
+                });
+            let member_text = format!("\"{key}\": {value}");
+            return match members.last() {
+                Some(last) => {
+                    let last = last.ok()?;
+                    match members.trailing_separator() {
+                        Some(separator) => Some((
+                            TextRange::empty(separator.text_trimmed_range().end()),
+                            format!(" {member_text}"),
+                        )),
+                        None => Some((
+                            TextRange::empty(last.syntax().text_trimmed_range().end()),
+                            format!(", {member_text}"),
+                        )),
+                    }
+                }
+                None => Some((
+                    TextRange::empty(object.l_curly_token().ok()?.text_trimmed_range().end()),
+                    format!(" {member_text} "),
+                )),
+            };
+        };
+
+        let value = member.value().ok()?;
+        let is_last = index == path.len() - 1;
+        match value {
+            // The rule has options: only its level is turned off
+            AnyJsonValue::JsonObjectValue(value) if is_last && path.len() == 4 => {
+                path.push("level");
+                object = value;
+            }
+            AnyJsonValue::JsonObjectValue(value) if !is_last => object = value,
+            value if is_last => {
+                return Some((value.syntax().text_trimmed_range(), String::from("\"off\"")));
+            }
+            _ => return None,
+        }
+        index += 1;
+    }
+
+    None
+}
+
 impl TryFrom<OverrideLinterConfiguration> for LinterSettings {
     type Error = WorkspaceError;
 
@@ -11,6 +11,7 @@ pub mod json;
 pub mod linter;
 pub mod organize_imports;
 mod overrides;
+pub mod plugins;
 pub mod vcs;
 
 use crate::configuration::diagnostics::CantLoadExtendFile;
@@ -31,41 +32,43 @@ use biome_deserialize::json::deserialize_from_json_str;
 use biome_deserialize::{Deserialized, Merge, StringSet};
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_diagnostics::{DiagnosticExt, Error, Severity};
-use biome_fs::{AutoSearchResult, ConfigName, FileSystem, OpenOptions};
+use biome_fs::{AutoSearchResult, BiomePath, ConfigName, FileSystem, OpenOptions};
 use biome_js_analyze::metadata;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::{parse_json, JsonParserOptions};
 use bpaf::Bpaf;
 pub use css::{
-    partial_css_configuration, CssConfiguration, CssFormatter, PartialCssConfiguration,
-    PartialCssFormatter,
+    partial_css_configuration, CssAssists, CssConfiguration, CssDeclarationsOrder, CssFormatter,
+    PartialCssAssists, PartialCssConfiguration, PartialCssFormatter,
 };
 pub use formatter::{
     deserialize_line_width, partial_formatter_configuration, serialize_line_width,
     FormatterConfiguration, PartialFormatterConfiguration, PlainIndentStyle,
 };
 pub use javascript::{
-    partial_javascript_configuration, JavascriptConfiguration, JavascriptFormatter,
+    partial_javascript_configuration, JavascriptConfiguration, JavascriptFormatter, JsEnvironments,
     PartialJavascriptConfiguration, PartialJavascriptFormatter,
 };
 pub use json::{
-    partial_json_configuration, JsonConfiguration, JsonFormatter, PartialJsonConfiguration,
-    PartialJsonFormatter,
+    load_json_schemas, partial_json_configuration, JsonAssists, JsonConfiguration, JsonFormatter,
+    JsonSchemaAssociation, JsonSchemaDefinition, JsonSchemas, PartialJsonAssists,
+    PartialJsonConfiguration, PartialJsonFormatter,
 };
 pub use linter::{
     partial_linter_configuration, LinterConfiguration, PartialLinterConfiguration,
     RuleConfiguration, Rules,
 };
 pub use overrides::to_override_settings;
+pub use plugins::{load_plugins, PluginDefinition};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::io::ErrorKind;
 use std::iter::FusedIterator;
 use std::num::NonZeroU64;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 /// The configuration that is contained inside the file `biome.json`
-#[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
 #[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
 #[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
 #[partial(serde(deny_unknown_fields, rename_all = "camelCase"))]
@@ -75,6 +78,12 @@ pub struct Configuration {
     #[partial(bpaf(hide))]
     pub schema: String,
 
+    /// Whether this is the root configuration, `true` by default. Set it to `false` in the
+    /// configuration file of a subdirectory: the file is merged over the configuration of the
+    /// parent directories, and applies to the files of that subdirectory.
+    #[partial(bpaf(hide))]
+    pub root: bool,
+
     /// The configuration of the VCS integration
     #[partial(type, bpaf(external(partial_vcs_configuration), optional, hide_usage))]
     pub vcs: VcsConfiguration,
@@ -117,6 +126,31 @@ pub struct Configuration {
     /// A list of granular patterns that should be applied only to a sub set of files
     #[partial(bpaf(hide))]
     pub overrides: Overrides,
+
+    /// A list of paths to plugin files, each one defining a lint rule through a code pattern.
+    /// Relative paths are resolved from the directory of the configuration file.
+    #[partial(bpaf(hide))]
+    pub plugins: StringSet,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            schema: Default::default(),
+            root: true,
+            vcs: Default::default(),
+            files: Default::default(),
+            formatter: Default::default(),
+            organize_imports: Default::default(),
+            linter: Default::default(),
+            javascript: Default::default(),
+            json: Default::default(),
+            css: Default::default(),
+            extends: Default::default(),
+            overrides: Default::default(),
+            plugins: Default::default(),
+        }
+    }
 }
 
 impl PartialConfiguration {
@@ -205,6 +239,12 @@ impl PartialConfiguration {
     pub fn is_vcs_enabled(&self) -> bool {
         !self.is_vcs_disabled()
     }
+
+    /// Whether this is the configuration of a subdirectory, which extends the configuration
+    /// of the parent directories
+    pub fn is_nested(&self) -> bool {
+        self.root == Some(false)
+    }
 }
 
 /// The configuration of the filesystem
@@ -277,13 +317,73 @@ impl ConfigurationBasePath {
     }
 }
 
+/// Resolves a path written in the configuration file against `directory_path`, the directory
+/// of the configuration file. The `.` components are dropped, so `./plugin.json` and
+/// `plugin.json` point to the same file.
+fn resolve_configuration_path(directory_path: &Path, path: &str) -> String {
+    directory_path
+        .join(path)
+        .components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect::<PathBuf>()
+        .display()
+        .to_string()
+}
+
 /// Load the partial configuration for this session of the CLI.
+///
+/// If the configuration file found is nested (`"root": false`), it's merged over the
+/// configuration of the parent directories.
 pub fn load_configuration(
-    fs: &DynRef<'_, dyn FileSystem>,
+    fs: &dyn FileSystem,
     config_path: ConfigurationBasePath,
 ) -> Result<LoadedConfiguration, WorkspaceError> {
     let config = load_config(fs, config_path)?;
-    LoadedConfiguration::try_from_payload(config, fs)
+    LoadedConfiguration::try_from_payload(config, fs)?.with_parent_configuration(fs)
+}
+
+/// Load the configuration file placed inside `directory`, only if it's a nested configuration
+/// (`"root": false`).
+///
+/// Unlike [load_configuration], the configuration files of the parent directories aren't loaded:
+/// the workspace merges the nested configuration over the settings of the enclosing project.
+pub fn load_nested_configuration(
+    fs: &dyn FileSystem,
+    directory: &Path,
+) -> Result<Option<LoadedConfiguration>, WorkspaceError> {
+    let Some(payload) = read_configuration_in(fs, directory)? else {
+        return Ok(None);
+    };
+
+    let loaded_configuration = LoadedConfiguration::try_from_payload(Some(payload), fs)?;
+    if loaded_configuration.configuration.is_nested() {
+        Ok(Some(loaded_configuration))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Reads the configuration file placed inside `directory`, without searching the parent
+/// directories
+fn read_configuration_in(fs: &dyn FileSystem, directory: &Path) -> LoadConfig {
+    let Some(file_path) = ConfigName::file_names()
+        .iter()
+        .map(|file_name| directory.join(file_name))
+        .find(|file_path| fs.path_is_file(file_path))
+    else {
+        return Ok(None);
+    };
+
+    let mut content = String::new();
+    fs.open_with_options(file_path.as_path(), OpenOptions::default().read(true))
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .map_err(|_| WorkspaceError::cant_read_file(file_path.display().to_string()))?;
+
+    Ok(Some(to_configuration_payload(AutoSearchResult {
+        content,
+        directory_path: directory.to_path_buf(),
+        file_path,
+    })))
 }
 
 /// Load the configuration from the file system.
@@ -295,10 +395,7 @@ pub fn load_configuration(
 ///
 /// If a the configuration base path was provided by the user, the function will error. If not, Biome will use
 /// its defaults.
-fn load_config(
-    file_system: &DynRef<'_, dyn FileSystem>,
-    base_path: ConfigurationBasePath,
-) -> LoadConfig {
+fn load_config(file_system: &dyn FileSystem, base_path: ConfigurationBasePath) -> LoadConfig {
     let deprecated_config_name = file_system.deprecated_config_name();
     let working_directory = file_system.working_directory();
     let configuration_directory = match base_path {
@@ -336,29 +433,30 @@ fn load_config(
         )?;
     }
 
-    if let Some(auto_search_result) = auto_search_result {
-        let AutoSearchResult {
-            content,
-            directory_path,
-            file_path,
-        } = auto_search_result;
-        let parser_options =
-            if file_path.file_name().and_then(|s| s.to_str()) == Some(ConfigName::biome_jsonc()) {
-                JsonParserOptions::default()
-                    .with_allow_comments()
-                    .with_allow_trailing_commas()
-            } else {
-                JsonParserOptions::default()
-            };
-        let deserialized =
-            deserialize_from_json_str::<PartialConfiguration>(&content, parser_options, "");
-        Ok(Some(ConfigurationPayload {
-            deserialized,
-            configuration_file_path: file_path,
-            configuration_directory_path: directory_path,
-        }))
-    } else {
-        Ok(None)
+    Ok(auto_search_result.map(to_configuration_payload))
+}
+
+/// Deserializes the configuration file that was found
+fn to_configuration_payload(auto_search_result: AutoSearchResult) -> ConfigurationPayload {
+    let AutoSearchResult {
+        content,
+        directory_path,
+        file_path,
+    } = auto_search_result;
+    let parser_options =
+        if file_path.file_name().and_then(|s| s.to_str()) == Some(ConfigName::biome_jsonc()) {
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas()
+        } else {
+            JsonParserOptions::default()
+        };
+    let deserialized =
+        deserialize_from_json_str::<PartialConfiguration>(&content, parser_options, "");
+    ConfigurationPayload {
+        deserialized,
+        configuration_file_path: file_path,
+        configuration_directory_path: directory_path,
     }
 }
 
@@ -425,11 +523,39 @@ pub fn to_analyzer_rules(settings: &WorkspaceSettings, path: &Path) -> AnalyzerR
     let mut analyzer_rules = AnalyzerRules::default();
     if let Some(rules) = linter_settings.rules.as_ref() {
         push_to_analyzer_rules(rules, metadata(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, biome_json_analyze::metadata(), &mut analyzer_rules);
     }
 
     overrides.override_analyzer_rules(path, analyzer_rules)
 }
 
+/// Returns the globals of a specific [Path], given the [WorkspaceSettings]: the globals
+/// of the configuration, and the globals of its environments
+pub fn to_analyzer_globals(settings: &WorkspaceSettings, path: &Path) -> Vec<String> {
+    let javascript = &settings.languages.javascript;
+    let overrides = &settings.override_settings;
+    let biome_path = BiomePath::new(path);
+
+    let mut globals: Vec<String> = overrides
+        .override_js_globals(&biome_path, &javascript.globals)
+        .into_iter()
+        .collect();
+    for environment in overrides
+        .override_js_environments(&biome_path, &javascript.linter.environments)
+        .into_iter()
+        .filter(|environment| !environment.is_known())
+    {
+        globals.extend(
+            environment
+                .globals()
+                .iter()
+                .map(|global| (*global).to_string()),
+        );
+    }
+
+    globals
+}
+
 /// Information regarding the configuration that was found.
 ///
 /// This contains the expanded configuration including default values where no
@@ -505,7 +631,7 @@ impl FusedIterator for ConfigurationDiagnosticsIter<'_> {}
 impl LoadedConfiguration {
     fn try_from_payload(
         value: Option<ConfigurationPayload>,
-        fs: &DynRef<'_, dyn FileSystem>,
+        fs: &dyn FileSystem,
     ) -> Result<Self, WorkspaceError> {
         let Some(value) = value else {
             return Ok(LoadedConfiguration::default());
@@ -528,6 +654,7 @@ impl LoadedConfiguration {
                         &mut diagnostics,
                     )?;
                     partial_configuration.migrate_deprecated_fields();
+                    partial_configuration.resolve_relative_paths(&configuration_directory_path);
                     partial_configuration
                 }
                 None => PartialConfiguration::default(),
@@ -542,6 +669,35 @@ impl LoadedConfiguration {
             file_path: Some(configuration_file_path),
         })
     }
+
+    /// When the configuration is nested, it merges the configuration over the configuration
+    /// of the closest parent directory that has one, as if the latter was listed in `extends`.
+    fn with_parent_configuration(mut self, fs: &dyn FileSystem) -> Result<Self, WorkspaceError> {
+        if !self.configuration.is_nested() {
+            return Ok(self);
+        }
+        let Some(parent_directory) = self.directory_path().and_then(Path::parent) else {
+            return Ok(self);
+        };
+        let mut payload = None;
+        for directory in parent_directory.ancestors() {
+            payload = read_configuration_in(fs, directory)?;
+            if payload.is_some() {
+                break;
+            }
+        }
+
+        let LoadedConfiguration {
+            mut configuration,
+            diagnostics,
+            ..
+        } = LoadedConfiguration::try_from_payload(payload, fs)?.with_parent_configuration(fs)?;
+        configuration.merge_with(self.configuration);
+        self.configuration = configuration;
+        self.diagnostics.extend(diagnostics);
+
+        Ok(self)
+    }
 }
 
 impl PartialConfiguration {
@@ -553,7 +709,7 @@ impl PartialConfiguration {
     /// If a configuration can't be resolved from the file system, the operation will fail.
     fn apply_extends(
         &mut self,
-        fs: &DynRef<'_, dyn FileSystem>,
+        fs: &dyn FileSystem,
         file_path: &Path,
         directory_path: &Path,
         diagnostics: &mut Vec<Error>,
@@ -591,7 +747,7 @@ impl PartialConfiguration {
     /// It attempts to deserialize all the configuration files that were specified in the `extends` property
     fn deserialize_extends(
         &mut self,
-        fs: &DynRef<'_, dyn FileSystem>,
+        fs: &dyn FileSystem,
         directory_path: &Path,
     ) -> Result<Vec<Deserialized<PartialConfiguration>>, WorkspaceError> {
         let Some(extends) = &self.extends else {
@@ -650,6 +806,29 @@ impl PartialConfiguration {
         Ok(deserialized_configurations)
     }
 
+    /// Resolves the relative paths of `plugins` and `json.schemas` against `directory_path`,
+    /// the directory of the configuration file, so they keep pointing to the same files
+    /// once the configuration is merged with the one of another directory.
+    fn resolve_relative_paths(&mut self, directory_path: &Path) {
+        if let Some(plugins) = self.plugins.as_mut() {
+            *plugins = StringSet::new(
+                plugins
+                    .iter()
+                    .map(|path| resolve_configuration_path(directory_path, path))
+                    .collect(),
+            );
+        }
+        if let Some(schemas) = self.json.as_mut().and_then(|json| json.schemas.as_mut()) {
+            for path in schemas
+                .0
+                .iter_mut()
+                .filter_map(|schema| schema.path.as_mut())
+            {
+                *path = resolve_configuration_path(directory_path, path);
+            }
+        }
+    }
+
     /// Checks for the presence of deprecated fields and updates the
     /// configuration to apply them to the new schema.
     fn migrate_deprecated_fields(&mut self) {
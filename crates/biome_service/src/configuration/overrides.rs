@@ -1,4 +1,4 @@
-use super::javascript::PartialJavascriptConfiguration;
+use super::javascript::{JsEnvironments, PartialJavascriptConfiguration};
 use super::json::PartialJsonConfiguration;
 use super::PartialCssConfiguration;
 use crate::configuration::formatter::{deserialize_line_width, serialize_line_width};
@@ -300,6 +300,10 @@ fn to_javascript_language_settings(
         .globals
         .map(StringSet::into_index_set)
         .or_else(|| parent_settings.globals.clone());
+    language_setting.linter.environments = conf
+        .environments
+        .map(JsEnvironments::into_index_set)
+        .or_else(|| parent_settings.linter.environments.clone());
 
     language_setting
 }
@@ -335,6 +339,14 @@ fn to_json_language_settings(
         .allow_trailing_commas
         .unwrap_or(parent_parser.allow_trailing_commas);
 
+    let assists = conf.assists.take().unwrap_or_default();
+    let parent_assists = &parent_settings.assists;
+    language_setting.assists.sort_keys = assists.sort_keys.unwrap_or(parent_assists.sort_keys);
+    language_setting.assists.keys_order = assists.keys_order.map_or_else(
+        || parent_assists.keys_order.clone(),
+        |keys_order| keys_order.into_index_set().into_iter().collect(),
+    );
+
     language_setting
 }
 
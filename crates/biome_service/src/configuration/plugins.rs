@@ -0,0 +1,170 @@
+use crate::configuration::linter::RulePlainConfiguration;
+use crate::configuration::ConfigurationDiagnostic;
+use crate::WorkspaceError;
+use biome_analyze::AnalyzerPlugin;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{DeserializableValidator, DeserializationDiagnostic, StringSet};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::{Category, PrintDescription, Severity};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_js_syntax::JsLanguage;
+use biome_json_parser::JsonParserOptions;
+use biome_pattern::{Pattern, PatternPlugin};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+/// The content of a plugin file: a lint rule defined by a structural pattern.
+///
+/// ```json
+/// {
+///     "name": "noConsoleLog",
+///     "pattern": "console.log($msg)",
+///     "message": "Use the logger instead of console.log.",
+///     "level": "error",
+///     "rewrite": "logger.info($msg)"
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[deserializable(with_validator)]
+pub struct PluginDefinition {
+    /// The name of the plugin. Its diagnostics use the category `plugin/<name>`, which suppresses them: `// biome-ignore plugin/<name>: <explanation>`
+    pub name: String,
+
+    /// The code to report. Identifiers starting with `$` are metavariables that match any code.
+    pub pattern: String,
+
+    /// The message of the diagnostics emitted for each match of the pattern
+    pub message: String,
+
+    /// The severity of the diagnostics. Use `"off"` to disable the plugin
+    pub level: RulePlainConfiguration,
+
+    /// The code that should replace the matches, it can use the metavariables of the pattern
+    pub rewrite: Option<String>,
+}
+
+impl DeserializableValidator for PluginDefinition {
+    fn validate(
+        &self,
+        _name: &str,
+        range: biome_rowan::TextRange,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> bool {
+        for (field, value) in [
+            ("name", &self.name),
+            ("pattern", &self.pattern),
+            ("message", &self.message),
+        ] {
+            if value.trim().is_empty() {
+                diagnostics.push(
+                    DeserializationDiagnostic::new(format_args!(
+                        "The plugin must have a non-empty {field}."
+                    ))
+                    .with_range(range),
+                );
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Reads the plugin files listed in `plugins`. Their relative paths were already resolved
+/// from the directory of their configuration file when the configuration was loaded.
+pub fn load_plugins(
+    fs: &dyn FileSystem,
+    plugins: Option<&StringSet>,
+) -> Result<Vec<PluginDefinition>, WorkspaceError> {
+    let Some(plugins) = plugins else {
+        return Ok(Vec::new());
+    };
+
+    let mut definitions = Vec::with_capacity(plugins.len());
+    for path in plugins.iter() {
+        let plugin_path = Path::new(path);
+
+        let mut file = fs
+            .open_with_options(plugin_path, OpenOptions::default().read(true))
+            .map_err(|_| WorkspaceError::cant_read_file(plugin_path.display().to_string()))?;
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .map_err(|_| WorkspaceError::cant_read_file(plugin_path.display().to_string()))?;
+
+        let (definition, diagnostics) = deserialize_from_json_str::<PluginDefinition>(
+            &content,
+            JsonParserOptions::default(),
+            "",
+        )
+        .consume();
+
+        let error = diagnostics
+            .iter()
+            .find(|diagnostic| diagnostic.severity() >= Severity::Error);
+        let definition = match (definition, error) {
+            (Some(definition), None) => definition,
+            (_, diagnostic) => {
+                let reason = diagnostic
+                    .map(|diagnostic| PrintDescription(diagnostic).to_string())
+                    .unwrap_or_default();
+                return Err(WorkspaceError::Configuration(
+                    ConfigurationDiagnostic::invalid_configuration(format_args!(
+                        "The plugin {} is invalid: {reason}",
+                        plugin_path.display()
+                    )),
+                ));
+            }
+        };
+
+        register_plugin_category(&definition.name)?;
+        definitions.push(definition);
+    }
+
+    Ok(definitions)
+}
+
+/// Registers the category `plugin/<name>` of a loaded plugin, so its
+/// diagnostics and suppression comments can use it
+fn register_plugin_category(name: &str) -> Result<(), WorkspaceError> {
+    match Category::register_plugin(name) {
+        Some(_) => Ok(()),
+        None => Err(WorkspaceError::Configuration(
+            ConfigurationDiagnostic::invalid_configuration(format_args!(
+                "The plugin {name} can't be loaded, because Biome can't load more than {} plugins.",
+                Category::MAX_PLUGINS
+            )),
+        )),
+    }
+}
+
+/// Compiles the plugin definitions into analyzer plugins. Disabled plugins are skipped.
+pub fn to_analyzer_plugins(
+    definitions: Vec<PluginDefinition>,
+) -> Result<Vec<Arc<dyn AnalyzerPlugin<JsLanguage>>>, WorkspaceError> {
+    let mut plugins: Vec<Arc<dyn AnalyzerPlugin<JsLanguage>>> = Vec::new();
+    for definition in definitions {
+        // The definitions may come from another process, where they were loaded
+        register_plugin_category(&definition.name)?;
+        if definition.level == RulePlainConfiguration::Off {
+            continue;
+        }
+
+        let plugin = PatternPlugin::new(
+            definition.name,
+            Pattern::parse(&definition.pattern)?,
+            definition.message,
+            definition.level.into(),
+        );
+        let plugin = match definition.rewrite {
+            Some(rewrite) => plugin.with_rewrite(Pattern::parse(&rewrite)?)?,
+            None => plugin,
+        };
+
+        plugins.push(Arc::new(plugin));
+    }
+
+    Ok(plugins)
+}
@@ -9,6 +9,7 @@ use biome_formatter::{FormatError, PrintError};
 use biome_fs::{BiomePath, FileSystemDiagnostic};
 use biome_js_analyze::utils::rename::RenameError;
 use biome_js_analyze::RuleError;
+use biome_pattern::PatternError;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::ffi::OsStr;
@@ -44,6 +45,8 @@ pub enum WorkspaceError {
     Configuration(ConfigurationDiagnostic),
     /// Error thrown when Biome cannot rename a symbol.
     RenameError(RenameError),
+    /// Error thrown when a structural pattern can't be compiled or searched
+    PatternError(PatternError),
     /// Error emitted by the underlying transport layer for a remote Workspace
     TransportError(TransportError),
     /// Emitted when the file is ignored and should not be processed
@@ -138,6 +141,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::RuleError(error) => error.category(),
             WorkspaceError::Configuration(error) => error.category(),
             WorkspaceError::RenameError(error) => error.category(),
+            WorkspaceError::PatternError(error) => error.category(),
             WorkspaceError::TransportError(error) => error.category(),
             WorkspaceError::ReportNotSerializable(error) => error.category(),
             WorkspaceError::NotFound(error) => error.category(),
@@ -161,6 +165,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::RuleError(error) => Diagnostic::description(error, fmt),
             WorkspaceError::Configuration(error) => error.description(fmt),
             WorkspaceError::RenameError(error) => error.description(fmt),
+            WorkspaceError::PatternError(error) => error.description(fmt),
             WorkspaceError::TransportError(error) => error.description(fmt),
             WorkspaceError::ReportNotSerializable(error) => error.description(fmt),
             WorkspaceError::NotFound(error) => error.description(fmt),
@@ -184,6 +189,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::RuleError(error) => error.message(fmt),
             WorkspaceError::Configuration(error) => error.message(fmt),
             WorkspaceError::RenameError(error) => error.message(fmt),
+            WorkspaceError::PatternError(error) => error.message(fmt),
             WorkspaceError::TransportError(error) => error.message(fmt),
             WorkspaceError::ReportNotSerializable(error) => error.message(fmt),
             WorkspaceError::NotFound(error) => error.message(fmt),
@@ -206,6 +212,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::RuleError(error) => error.severity(),
             WorkspaceError::Configuration(error) => error.severity(),
             WorkspaceError::RenameError(error) => error.severity(),
+            WorkspaceError::PatternError(error) => error.severity(),
             WorkspaceError::TransportError(error) => error.severity(),
             WorkspaceError::ReportNotSerializable(error) => error.severity(),
             WorkspaceError::DirtyWorkspace(error) => error.severity(),
@@ -229,6 +236,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::RuleError(error) => error.tags(),
             WorkspaceError::Configuration(error) => error.tags(),
             WorkspaceError::RenameError(error) => error.tags(),
+            WorkspaceError::PatternError(error) => error.tags(),
             WorkspaceError::TransportError(error) => error.tags(),
             WorkspaceError::ReportNotSerializable(error) => error.tags(),
             WorkspaceError::DirtyWorkspace(error) => error.tags(),
@@ -252,6 +260,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::RuleError(error) => error.location(),
             WorkspaceError::Configuration(error) => error.location(),
             WorkspaceError::RenameError(error) => error.location(),
+            WorkspaceError::PatternError(error) => error.location(),
             WorkspaceError::TransportError(error) => error.location(),
             WorkspaceError::ReportNotSerializable(error) => error.location(),
             WorkspaceError::DirtyWorkspace(error) => error.location(),
@@ -275,6 +284,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::RuleError(error) => Diagnostic::source(error),
             WorkspaceError::Configuration(error) => Diagnostic::source(error),
             WorkspaceError::RenameError(error) => Diagnostic::source(error),
+            WorkspaceError::PatternError(error) => Diagnostic::source(error),
             WorkspaceError::TransportError(error) => Diagnostic::source(error),
             WorkspaceError::ReportNotSerializable(error) => Diagnostic::source(error),
             WorkspaceError::DirtyWorkspace(error) => Diagnostic::source(error),
@@ -298,6 +308,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::RuleError(error) => error.advices(visitor),
             WorkspaceError::Configuration(error) => error.advices(visitor),
             WorkspaceError::RenameError(error) => error.advices(visitor),
+            WorkspaceError::PatternError(error) => error.advices(visitor),
             WorkspaceError::TransportError(error) => error.advices(visitor),
             WorkspaceError::ReportNotSerializable(error) => error.advices(visitor),
             WorkspaceError::DirtyWorkspace(error) => error.advices(visitor),
@@ -320,6 +331,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::RuleError(error) => error.verbose_advices(visitor),
             WorkspaceError::Configuration(error) => error.verbose_advices(visitor),
             WorkspaceError::RenameError(error) => error.verbose_advices(visitor),
+            WorkspaceError::PatternError(error) => error.verbose_advices(visitor),
             WorkspaceError::TransportError(error) => error.verbose_advices(visitor),
             WorkspaceError::ReportNotSerializable(error) => error.verbose_advices(visitor),
             WorkspaceError::DirtyWorkspace(error) => error.verbose_advices(visitor),
@@ -361,6 +373,12 @@ impl From<FileSystemDiagnostic> for WorkspaceError {
     }
 }
 
+impl From<PatternError> for WorkspaceError {
+    fn from(err: PatternError) -> Self {
+        Self::PatternError(err)
+    }
+}
+
 impl From<ConfigurationDiagnostic> for WorkspaceError {
     fn from(value: ConfigurationDiagnostic) -> Self {
         Self::Configuration(value)
@@ -1,7 +1,7 @@
 use crate::file_handlers::{
     javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
     ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, Mime,
-    ParseResult, ParserCapabilities,
+    ParseResult, ParserCapabilities, SearchCapabilities,
 };
 use crate::settings::SettingsHandle;
 use crate::workspace::{
@@ -72,7 +72,11 @@ impl ExtensionHandler for AstroFileHandler {
 
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                folding_ranges: None,
+                selection_ranges: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: None,
                 debug_control_flow: None,
@@ -82,6 +86,9 @@ impl ExtensionHandler for AstroFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                rename_imports: None,
+                semantic_tokens: None,
+                completions: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
@@ -90,6 +97,7 @@ impl ExtensionHandler for AstroFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
+            search: SearchCapabilities { search: None },
         }
     }
 }
@@ -162,6 +170,10 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     javascript::fix_all(params)
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    javascript::organize_imports(parse)
+fn organize_imports(
+    path: &BiomePath,
+    parse: AnyParse,
+    settings: SettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    javascript::organize_imports(path, parse, settings)
 }
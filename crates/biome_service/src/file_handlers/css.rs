@@ -1,25 +1,40 @@
-use super::{ExtensionHandler, Mime, ParseResult};
+use super::{CodeActionsParams, ExtensionHandler, Mime, ParseResult};
+use crate::configuration::CssDeclarationsOrder;
+use crate::file_handlers::ranges;
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, FormatterCapabilities, ParserCapabilities,
+    SearchCapabilities,
 };
 use crate::settings::{
     FormatSettings, Language, LanguageListSettings, LanguageSettings, OverrideSettings,
-    SettingsHandle,
+    SettingsHandle, WorkspaceSettings,
+};
+use crate::workspace::{
+    CodeAction, DocumentFileSource, FoldingRange, GetSyntaxTreeResult, OrganizeImportsResult,
+    PullActionsResult,
 };
-use crate::workspace::{DocumentFileSource, GetSyntaxTreeResult};
 use crate::WorkspaceError;
+use biome_analyze::options::RuleOptions;
+use biome_analyze::{
+    AnalysisFilter, AnalyzerOptions, AnalyzerRules, ControlFlow, Never, RuleCategories, RuleFilter,
+    RuleKey,
+};
+use biome_css_analyze::{DeclarationsOrder, SortDeclarationsOptions};
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_formatter::{can_format_css_yet, format_node};
 use biome_css_parser::CssParserOptions;
+use biome_css_semantic::ProjectCustomProperties;
 use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxNode};
 use biome_formatter::{
     FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
 };
 use biome_fs::BiomePath;
+use biome_js_analyze::RuleError;
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
+use biome_rowan::{AstNode, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
+use std::borrow::Cow;
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -38,10 +53,19 @@ pub struct CssParserSettings {
     pub allow_wrong_line_comments: bool,
 }
 
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CssAssistsSettings {
+    pub sort_declarations: bool,
+    pub declarations_order: CssDeclarationsOrder,
+    pub custom_order: Vec<String>,
+}
+
 impl Language for CssLanguage {
     type FormatterSettings = CssFormatterSettings;
     type LinterSettings = ();
     type OrganizeImportsSettings = ();
+    type AssistsSettings = CssAssistsSettings;
     type FormatOptions = CssFormatOptions;
     type ParserSettings = CssParserSettings;
     fn lookup_settings(language: &LanguageListSettings) -> &LanguageSettings<Self> {
@@ -100,7 +124,11 @@ impl ExtensionHandler for CssFileHandler {
 
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(selection_ranges),
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -108,10 +136,13 @@ impl ExtensionHandler for CssFileHandler {
             },
             analyzer: AnalyzerCapabilities {
                 lint: None,
-                code_actions: None,
+                code_actions: Some(code_actions),
                 rename: None,
+                rename_imports: None,
+                semantic_tokens: None,
+                completions: None,
                 fix_all: None,
-                organize_imports: None,
+                organize_imports: Some(organize_imports),
             },
             // TODO(faulty): Once the CSS formatter is sufficiently stable, we
             // will unhide its capabilities from services. But in the meantime,
@@ -131,25 +162,31 @@ impl ExtensionHandler for CssFileHandler {
                     format_on_type: None,
                 }
             },
+            search: SearchCapabilities { search: None },
         }
     }
 }
 
 fn parse(
     biome_path: &BiomePath,
-    _file_source: DocumentFileSource,
+    file_source: DocumentFileSource,
     text: &str,
     settings: SettingsHandle,
     cache: &mut NodeCache,
 ) -> ParseResult {
     let parser = &settings.as_ref().languages.css.parser;
     let overrides = &settings.as_ref().override_settings;
-    let options: CssParserOptions =
+    let mut options: CssParserOptions =
         overrides
             .as_css_parser_options(biome_path)
             .unwrap_or(CssParserOptions {
                 allow_wrong_line_comments: parser.allow_wrong_line_comments,
+                ..CssParserOptions::default()
             });
+    let css_file_source = file_source.to_css_file_source();
+    options.allow_scss_syntax = css_file_source.map(|fs| fs.is_scss()) == Some(true);
+    options.allow_less_syntax = css_file_source.map(|fs| fs.is_less()) == Some(true);
+    options.css_modules = css_file_source.map(|fs| fs.is_css_modules()) == Some(true);
     let parse = biome_css_parser::parse_css_with_cache(text, cache, options);
     let root = parse.syntax();
     let diagnostics = parse.into_diagnostics();
@@ -172,6 +209,16 @@ fn debug_syntax_tree(_rome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeRe
     }
 }
 
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let syntax: CssSyntaxNode = parse.syntax();
+    ranges::folding_ranges(&syntax, |_| false, |_| false)
+}
+
+fn selection_ranges(parse: AnyParse, offsets: &[TextSize]) -> Vec<Vec<TextRange>> {
+    let syntax: CssSyntaxNode = parse.syntax();
+    ranges::selection_ranges(&syntax, offsets)
+}
+
 fn debug_formatter_ir(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
@@ -257,3 +304,129 @@ fn format_on_type(
     let printed = biome_css_formatter::format_sub_tree(options, &root_node)?;
     Ok(printed)
 }
+
+/// The assists applied when organizing a CSS file
+const SORT_DECLARATIONS: RuleFilter<'static> = RuleFilter::Rule("correctness", "sortDeclarations");
+
+/// Pushes the options of the assists applied to CSS files to `analyzer_rules`
+pub fn push_css_assists_to_analyzer_rules(
+    settings: &WorkspaceSettings,
+    analyzer_rules: &mut AnalyzerRules,
+) {
+    let assists = &settings.languages.css.assists;
+    let order = match assists.declarations_order {
+        CssDeclarationsOrder::Alphabetical => DeclarationsOrder::Alphabetical,
+        CssDeclarationsOrder::Grouped => DeclarationsOrder::Grouped,
+        CssDeclarationsOrder::Custom => DeclarationsOrder::Custom(assists.custom_order.clone()),
+    };
+    analyzer_rules.push_rule(
+        RuleKey::new("correctness", "sortDeclarations"),
+        RuleOptions::new(SortDeclarationsOptions { order }),
+    );
+}
+
+fn compute_analyzer_options(settings: &SettingsHandle) -> AnalyzerOptions {
+    let mut options = AnalyzerOptions::default();
+    push_css_assists_to_analyzer_rules(settings.as_ref(), &mut options.configuration.rules);
+    options
+}
+
+fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    let CodeActionsParams {
+        parse,
+        range,
+        settings,
+        ..
+    } = params;
+    let mut actions = Vec::new();
+    if !settings.as_ref().css_sort_declarations_enabled() {
+        return PullActionsResult { actions };
+    }
+
+    let tree: CssRoot = parse.tree();
+    let filter = AnalysisFilter {
+        enabled_rules: Some(&[SORT_DECLARATIONS]),
+        categories: RuleCategories::ACTION,
+        range: Some(range),
+        ..AnalysisFilter::default()
+    };
+    let analyzer_options = compute_analyzer_options(&settings);
+
+    biome_css_analyze::analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        ProjectCustomProperties::default(),
+        |signal| {
+            actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                CodeAction {
+                    category: item.category.clone(),
+                    rule_name: item
+                        .rule_name
+                        .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                    suggestion: item.suggestion,
+                }
+            }));
+
+            ControlFlow::<Never>::Continue(())
+        },
+    );
+
+    PullActionsResult { actions }
+}
+
+fn organize_imports(
+    _: &BiomePath,
+    parse: AnyParse,
+    settings: SettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    let tree: CssRoot = parse.tree();
+    if !settings.as_ref().css_sort_declarations_enabled() {
+        return Ok(OrganizeImportsResult {
+            code: tree.syntax().to_string(),
+        });
+    }
+
+    let filter = AnalysisFilter {
+        enabled_rules: Some(&[SORT_DECLARATIONS]),
+        categories: RuleCategories::ACTION,
+        ..AnalysisFilter::default()
+    };
+    let analyzer_options = compute_analyzer_options(&settings);
+
+    let (action, _) = biome_css_analyze::analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        ProjectCustomProperties::default(),
+        |signal| {
+            for action in signal.actions() {
+                if action.is_suppression() {
+                    continue;
+                }
+
+                return ControlFlow::Break(action);
+            }
+            ControlFlow::Continue(())
+        },
+    );
+
+    let Some(action) = action else {
+        return Ok(OrganizeImportsResult {
+            code: tree.syntax().to_string(),
+        });
+    };
+    let Some(tree) = CssRoot::cast(action.mutation.commit()) else {
+        return Err(WorkspaceError::RuleError(
+            RuleError::ReplacedRootWithNonRootError {
+                rule_name: action
+                    .rule_name
+                    .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+            },
+        ));
+    };
+
+    Ok(OrganizeImportsResult {
+        code: tree.syntax().to_string(),
+    })
+}
@@ -1,17 +1,20 @@
 use super::{
     AnalyzerCapabilities, CodeActionsParams, DebugCapabilities, ExtensionHandler,
     FormatterCapabilities, LintParams, LintResults, Mime, ParseResult, ParserCapabilities,
+    SearchCapabilities,
 };
-use crate::configuration::to_analyzer_rules;
+use crate::configuration::{to_analyzer_globals, to_analyzer_rules};
 use crate::diagnostics::extension_error;
+use crate::file_handlers::ranges;
 use crate::file_handlers::{is_diagnostic_error, FixAllParams};
 use crate::settings::OverrideSettings;
 use crate::workspace::{DocumentFileSource, OrganizeImportsResult};
 use crate::{
     settings::{FormatSettings, Language, LanguageListSettings, LanguageSettings, SettingsHandle},
     workspace::{
-        CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
-        RenameResult,
+        CodeAction, FixAction, FixFileMode, FixFileResult, FoldingRange, GetSyntaxTreeResult,
+        PullActionsResult, RenameImportsResult, RenameResult, SemanticToken, SemanticTokenKind,
+        SemanticTokensResult,
     },
     WorkspaceError,
 };
@@ -19,15 +22,21 @@ use biome_analyze::{
     AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, GroupCategory, Never,
     QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup,
 };
-use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
+use biome_css_parser::{parse_css, CssParserOptions};
+use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Error, Severity};
 use biome_formatter::{
     AttributePosition, FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
     QuoteStyle,
 };
-use biome_fs::BiomePath;
-use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
+use biome_fs::{BiomePath, FileSystem, PathInterner, TraversalContext, TraversalScope};
+use biome_js_analyze::globals::{is_known_global, JsEnvironment};
+use biome_js_analyze::utils::rename::{
+    token_with_new_text, AnyJsRenamableDeclaration, RenamableNode, RenameError,
+    RenameSymbolExtensions,
+};
 use biome_js_analyze::{
-    analyze, analyze_with_inspect_matcher, visit_registry, ControlFlowGraph, RuleError,
+    analyze, analyze_with_inspect_matcher, visit_registry, ControlFlowGraph, ImportedCssModules,
+    RuleError,
 };
 use biome_js_formatter::context::trailing_comma::TrailingComma;
 use biome_js_formatter::context::{
@@ -35,15 +44,27 @@ use biome_js_formatter::context::{
 };
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
-use biome_js_semantic::{semantic_model, SemanticModelOptions};
+use biome_js_semantic::{semantic_model, SemanticModel, SemanticModelOptions};
+use biome_js_syntax::binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding};
 use biome_js_syntax::{
-    AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
+    AnyJsBinding, AnyJsCombinedSpecifier, AnyJsExportClause, AnyJsImportClause, AnyJsModuleItem,
+    AnyJsNamedImportSpecifier, AnyJsRoot, AnyJsVariableDeclaration, JsExport,
+    JsExportNamedShorthandSpecifier, JsFileSource, JsIdentifierAssignment, JsLanguage,
+    JsLiteralExportName, JsModuleSource, JsReferenceIdentifier, JsSyntaxKind, JsSyntaxNode,
+    JsxReferenceIdentifier, TextRange, TextSize, TokenAtOffset,
 };
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache};
+use biome_pattern::{Pattern, PatternMatch};
+use biome_project::{CssModule, ProjectImports};
+use biome_rowan::{
+    AstNode, AstSeparatedList, BatchMutation, BatchMutationExt, Direction, NodeCache, SyntaxResult,
+};
+use indexmap::IndexSet;
 use std::borrow::Cow;
+use std::ffi::OsStr;
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
 use tracing::{debug, debug_span, error, info, trace, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -75,6 +96,8 @@ pub struct JsParserSettings {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsLinterSettings {
     pub globals: Vec<String>,
+    /// The environments whose globals are known, see [JsEnvironment]
+    pub environments: Option<IndexSet<JsEnvironment>>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -86,6 +109,7 @@ impl Language for JsLanguage {
     type LinterSettings = JsLinterSettings;
     type FormatOptions = JsFormatOptions;
     type OrganizeImportsSettings = JsOrganizeImportsSettings;
+    type AssistsSettings = ();
     type ParserSettings = JsParserSettings;
 
     fn lookup_settings(languages: &LanguageListSettings) -> &LanguageSettings<Self> {
@@ -162,7 +186,11 @@ impl ExtensionHandler for JsFileHandler {
 
     fn capabilities(&self) -> super::Capabilities {
         super::Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(selection_ranges),
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: Some(debug_control_flow),
@@ -173,6 +201,9 @@ impl ExtensionHandler for JsFileHandler {
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
                 rename: Some(rename),
+                rename_imports: Some(rename_imports),
+                semantic_tokens: Some(semantic_tokens),
+                completions: None,
                 organize_imports: Some(organize_imports),
             },
             formatter: FormatterCapabilities {
@@ -180,6 +211,9 @@ impl ExtensionHandler for JsFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
         }
     }
 }
@@ -222,6 +256,20 @@ fn debug_syntax_tree(_rome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeRe
     }
 }
 
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let syntax: JsSyntaxNode = parse.syntax();
+    ranges::folding_ranges(
+        &syntax,
+        |kind| kind == JsSyntaxKind::JSX_ELEMENT,
+        |kind| kind == JsSyntaxKind::JS_IMPORT,
+    )
+}
+
+fn selection_ranges(parse: AnyParse, offsets: &[TextSize]) -> Vec<Vec<TextRange>> {
+    let syntax: JsSyntaxNode = parse.syntax();
+    ranges::selection_ranges(&syntax, offsets)
+}
+
 fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
     let mut control_flow_graph = None;
 
@@ -260,6 +308,8 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
         &options,
         JsFileSource::default(),
         None,
+        ImportedCssModules::default(),
+        &[],
         |_| ControlFlow::<Never>::Continue(()),
     );
 
@@ -339,6 +389,8 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 &analyzer_options,
                 file_source,
                 params.manifest,
+                params.css_modules,
+                &settings.plugins,
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
@@ -437,6 +489,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         settings,
         path,
         manifest,
+        css_modules,
         language,
     } = params;
     debug_span!("Code actions JavaScript", range =? range, path =? path).in_scope(move || {
@@ -487,6 +540,8 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 &analyzer_options,
                 source_type,
                 manifest,
+                css_modules,
+                &settings.as_ref().plugins,
                 |signal| {
                     actions.extend(signal.actions().into_code_action_iter().map(|item| {
                         CodeAction {
@@ -520,6 +575,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         biome_path,
         mut filter,
         manifest,
+        css_modules,
         document_file_source,
     } = params;
 
@@ -544,6 +600,8 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             &analyzer_options,
             file_source,
             manifest.clone(),
+            css_modules.clone(),
+            &settings.as_ref().plugins,
             |signal| {
                 let current_diagnostic = signal.diagnostic();
 
@@ -723,8 +781,11 @@ fn rename(
     {
         let original_name = node.text_trimmed();
         let range = node.text_range();
-        match node.try_into() {
+        match AnyJsRenamableDeclaration::try_from(node) {
             Ok(node) => {
+                let exported_name = node
+                    .binding(&model)
+                    .and_then(|declaration| exported_name(&model, &declaration));
                 let mut batch = root.begin();
                 let result = batch.rename_any_renamable_node(&model, node, &new_name);
                 if !result {
@@ -735,7 +796,11 @@ fn rename(
                     }))
                 } else {
                     let (range, indels) = batch.as_text_edits().unwrap_or_default();
-                    Ok(RenameResult { range, indels })
+                    Ok(RenameResult {
+                        range,
+                        indels,
+                        exported_name,
+                    })
                 }
             }
             Err(err) => Err(WorkspaceError::RenameError(err)),
@@ -747,7 +812,477 @@ fn rename(
     }
 }
 
-pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+/// Returns the name of the declaration when the module exports it with this name, with
+/// an exported declaration or with an `export { name }` clause
+fn exported_name(model: &SemanticModel, declaration: &JsSyntaxNode) -> Option<String> {
+    let binding = model.as_binding(&AnyJsIdentifierBinding::cast_ref(declaration)?);
+    // The binding of a function declaration is hoisted out of the function's own scope
+    let scope = model
+        .scope_hoisted_to(declaration)
+        .unwrap_or_else(|| binding.scope());
+    if scope != model.global_scope() {
+        return None;
+    }
+
+    let is_exported_declaration = declaration
+        .ancestors()
+        .find_map(JsExport::cast)
+        .and_then(|export| export.export_clause().ok())
+        .is_some_and(|clause| matches!(clause, AnyJsExportClause::AnyJsDeclarationClause(_)));
+    let is_exported = is_exported_declaration
+        || binding.all_references().any(|reference| {
+            reference
+                .syntax()
+                .parent()
+                .is_some_and(|parent| JsExportNamedShorthandSpecifier::can_cast(parent.kind()))
+        });
+
+    is_exported.then(|| declaration.text_trimmed().to_string())
+}
+
+fn rename_imports(
+    path: &BiomePath,
+    parse: AnyParse,
+    module: &BiomePath,
+    exported_name: &str,
+    new_name: &str,
+) -> Result<RenameImportsResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let AnyJsRoot::JsModule(js_module) = &root else {
+        return Ok(RenameImportsResult::default());
+    };
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let imports_module = |source: SyntaxResult<JsModuleSource>| {
+        source
+            .and_then(|source| source.inner_string_text())
+            .is_ok_and(|source| resolves_to_module(path, source.text(), module))
+    };
+
+    let mut batch = root.clone().begin();
+    let mut re_exported = false;
+    for item in js_module.items() {
+        match item {
+            AnyJsModuleItem::JsImport(import) => {
+                let Ok(clause) = import.import_clause() else {
+                    continue;
+                };
+                if !imports_module(clause.source()) {
+                    continue;
+                }
+                let specifiers = match clause {
+                    AnyJsImportClause::JsImportNamedClause(clause) => {
+                        clause.named_specifiers().ok()
+                    }
+                    AnyJsImportClause::JsImportCombinedClause(clause) => match clause.specifier() {
+                        Ok(AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers)) => {
+                            Some(specifiers)
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                for specifier in specifiers.iter().flat_map(|s| s.specifiers()).flatten() {
+                    match specifier {
+                        // import { name as local } from "./module"
+                        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                            if let Ok(name) = specifier.name() {
+                                rename_export_name(&mut batch, &name, exported_name, new_name);
+                            }
+                        }
+                        // import { name } from "./module"
+                        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+                            let Ok(AnyJsBinding::JsIdentifierBinding(binding)) =
+                                specifier.local_name()
+                            else {
+                                continue;
+                            };
+                            let Ok(name_token) = binding.name_token() else {
+                                continue;
+                            };
+                            if name_token.text_trimmed() != exported_name {
+                                continue;
+                            }
+                            // When the new name is already bound in the file, the binding
+                            // keeps its name and imports the new name under it
+                            if !batch.rename_node_declaration(&model, binding, new_name) {
+                                let alias = format!("{new_name} as {exported_name}");
+                                let token = token_with_new_text(&name_token, &alias);
+                                batch.replace_token(name_token, token);
+                            }
+                        }
+                        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {}
+                    }
+                }
+            }
+            AnyJsModuleItem::JsExport(export) => match export.export_clause() {
+                // export { name } from "./module"
+                Ok(AnyJsExportClause::JsExportNamedFromClause(clause)) => {
+                    if !imports_module(clause.source()) {
+                        continue;
+                    }
+                    for specifier in clause.specifiers().iter().flatten() {
+                        let Ok(name) = specifier.source_name() else {
+                            continue;
+                        };
+                        if rename_export_name(&mut batch, &name, exported_name, new_name)
+                            && specifier.export_as().is_none()
+                        {
+                            re_exported = true;
+                        }
+                    }
+                }
+                // export * from "./module"
+                Ok(AnyJsExportClause::JsExportFromClause(clause)) => {
+                    if clause.export_as().is_none() && imports_module(clause.source()) {
+                        re_exported = true;
+                    }
+                }
+                _ => {}
+            },
+            AnyJsModuleItem::AnyJsStatement(_) => {}
+        }
+    }
+
+    let (_, indels) = batch.as_text_edits().unwrap_or_default();
+    Ok(RenameImportsResult {
+        indels,
+        re_exported,
+    })
+}
+
+/// Replaces `name` with `new_name` when it's `exported_name`. Returns `true` if it was replaced.
+fn rename_export_name(
+    batch: &mut BatchMutation<JsLanguage>,
+    name: &JsLiteralExportName,
+    exported_name: &str,
+    new_name: &str,
+) -> bool {
+    let Ok(token) = name.value() else {
+        return false;
+    };
+    if !name
+        .inner_string_text()
+        .is_ok_and(|name| name.text() == exported_name)
+    {
+        return false;
+    }
+
+    let new_text = if token.kind() == JsSyntaxKind::JS_STRING_LITERAL {
+        let quote = &token.text_trimmed()[..1];
+        format!("{quote}{new_name}{quote}")
+    } else {
+        new_name.to_string()
+    };
+    let new_token = token_with_new_text(&token, &new_text);
+    batch.replace_token(token, new_token);
+    true
+}
+
+/// Returns `true` if the module specifier `source` of the file at `path` resolves to `module`.
+///
+/// Only the relative specifiers are resolved, without reading the file system. The extension
+/// can be omitted or be the JavaScript extension of a TypeScript module, and a directory
+/// resolves to its `index` module.
+fn resolves_to_module(path: &Path, source: &str, module: &Path) -> bool {
+    let Some(resolved) = resolve_relative_specifier(path, source) else {
+        return false;
+    };
+
+    let module_stem = module.with_extension("");
+    let has_js_extension = resolved
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| matches!(extension, "js" | "jsx" | "mjs" | "cjs"));
+    resolved == module
+        || resolved == module_stem
+        || (has_js_extension && resolved.with_extension("") == module_stem)
+        || (module_stem.file_name().is_some_and(|name| name == "index")
+            && resolved.join("index") == module_stem)
+}
+
+/// Returns the path the relative module specifier `source` of the file at `path` points to
+fn resolve_relative_specifier(path: &Path, source: &str) -> Option<PathBuf> {
+    if !source.starts_with("./") && !source.starts_with("../") {
+        return None;
+    }
+    let mut resolved = path.parent()?.to_path_buf();
+    for component in Path::new(source).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            component => resolved.push(component),
+        }
+    }
+    Some(resolved)
+}
+
+/// Parses the CSS modules imported by the module `root` of the file at `path`.
+/// `read_module` returns the content of the module at the given path.
+pub(crate) fn imported_css_modules(
+    root: &AnyJsRoot,
+    path: &Path,
+    read_module: impl Fn(&Path) -> Option<String>,
+) -> ImportedCssModules {
+    let mut css_modules = ImportedCssModules::default();
+    let Some(module) = root.as_js_module() else {
+        return css_modules;
+    };
+
+    for item in module.items() {
+        let Some(source) = item
+            .as_js_import()
+            .and_then(|import| import.source_text().ok())
+        else {
+            continue;
+        };
+        let source = source.text();
+        if !source.ends_with(".module.css") || css_modules.get(source).is_some() {
+            continue;
+        }
+        let Some(content) = resolve_relative_specifier(path, source)
+            .and_then(|module_path| read_module(&module_path))
+        else {
+            continue;
+        };
+        let parse = parse_css(&content, CssParserOptions::default().allow_css_modules());
+        css_modules.insert(source, CssModule::from_root(&parse.tree()));
+    }
+
+    css_modules
+}
+
+/// Collects the packages imported by the JavaScript files of the package whose manifest
+/// is at `manifest_path`. The files are found by traversing `fs`: the directories
+/// `node_modules`, the hidden directories, the directories of nested packages and the
+/// paths for which `is_ignored` returns `true` are skipped.
+/// `read_file` returns the content of the file at the given path.
+pub(crate) fn project_imports(
+    fs: &dyn FileSystem,
+    manifest_path: &Path,
+    is_ignored: impl Fn(&Path) -> bool + Sync,
+    read_file: impl Fn(&Path) -> Option<String> + Sync,
+) -> ProjectImports {
+    let Some(package_directory) = manifest_path.parent() else {
+        return ProjectImports::default();
+    };
+
+    let (interner, _) = PathInterner::new();
+    let context = ProjectImportsTraversal {
+        fs,
+        package_directory,
+        interner,
+        is_ignored,
+        read_file,
+        imports: Mutex::default(),
+    };
+    fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+        scope.spawn(&context, package_directory.to_path_buf());
+    }));
+
+    context.imports.into_inner().unwrap()
+}
+
+/// The traversal of the files of a package, run by [project_imports]
+struct ProjectImportsTraversal<'a, I, R> {
+    fs: &'a dyn FileSystem,
+    package_directory: &'a Path,
+    interner: PathInterner,
+    is_ignored: I,
+    read_file: R,
+    imports: Mutex<ProjectImports>,
+}
+
+impl<I, R> TraversalContext for ProjectImportsTraversal<'_, I, R>
+where
+    I: Fn(&Path) -> bool + Sync,
+    R: Fn(&Path) -> Option<String> + Sync,
+{
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, error: Error) {
+        debug!(
+            "Error while collecting the imports of the package: {:?}",
+            error
+        );
+    }
+
+    fn can_handle(&self, path: &BiomePath) -> bool {
+        let path = path.as_path();
+        if path == self.package_directory {
+            return true;
+        }
+        if (self.is_ignored)(path) {
+            return false;
+        }
+
+        // The directories between the package directory and the path, including the path
+        // itself when it's a directory
+        let is_file = self.fs.path_is_file(path);
+        let is_skipped = path
+            .ancestors()
+            .skip(usize::from(is_file))
+            .take_while(|directory| *directory != self.package_directory)
+            .any(|directory| {
+                let is_skipped_directory = directory
+                    .file_name()
+                    .and_then(OsStr::to_str)
+                    .is_some_and(|name| name == "node_modules" || name.starts_with('.'));
+                is_skipped_directory || self.fs.path_exists(&directory.join("package.json"))
+            });
+
+        !is_skipped && (!is_file || JsFileSource::try_from(path).is_ok())
+    }
+
+    fn handle_file(&self, path: &Path) {
+        let Ok(file_source) = JsFileSource::try_from(path) else {
+            return;
+        };
+        if let Some(content) = (self.read_file)(path) {
+            let parse = biome_js_parser::parse(&content, file_source, JsParserOptions::default());
+            self.imports.lock().unwrap().add_module(&parse.tree());
+        }
+    }
+}
+
+fn semantic_tokens(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: SettingsHandle,
+    range: Option<TextRange>,
+) -> SemanticTokensResult {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let globals = compute_analyzer_options(&settings, PathBuf::from(path.as_path()))
+        .configuration
+        .globals;
+    let source_type = document_file_source.to_js_file_source().unwrap_or_default();
+
+    let tokens = root
+        .syntax()
+        .descendants()
+        .filter_map(|node| {
+            if let Some(binding) = AnyJsIdentifierBinding::cast_ref(&node) {
+                let name_token = binding.name_token().ok()?;
+                let (kind, is_readonly) = binding_token_kind(&binding)?;
+                return Some(SemanticToken {
+                    range: name_token.text_trimmed_range(),
+                    kind,
+                    is_declaration: true,
+                    is_readonly,
+                });
+            }
+
+            let (name_token, binding) = match node.kind() {
+                JsSyntaxKind::JS_REFERENCE_IDENTIFIER => {
+                    let reference = JsReferenceIdentifier::unwrap_cast(node);
+                    (reference.value_token().ok()?, model.binding(&reference))
+                }
+                JsSyntaxKind::JS_IDENTIFIER_ASSIGNMENT => {
+                    let assignment = JsIdentifierAssignment::unwrap_cast(node);
+                    (assignment.name_token().ok()?, model.binding(&assignment))
+                }
+                JsSyntaxKind::JSX_REFERENCE_IDENTIFIER => {
+                    let reference = JsxReferenceIdentifier::unwrap_cast(node);
+                    (reference.value_token().ok()?, model.binding(&reference))
+                }
+                _ => return None,
+            };
+            let (kind, is_readonly) = match binding {
+                Some(binding) => binding_token_kind(&binding.tree())?,
+                None => {
+                    let name = name_token.text_trimmed();
+                    if globals.iter().any(|global| global == name)
+                        || is_known_global(name, &source_type)
+                    {
+                        (SemanticTokenKind::Global, false)
+                    } else {
+                        (SemanticTokenKind::Unresolved, false)
+                    }
+                }
+            };
+            Some(SemanticToken {
+                range: name_token.text_trimmed_range(),
+                kind,
+                is_declaration: false,
+                is_readonly,
+            })
+        })
+        .filter(|token| range.map_or(true, |range| token.range.intersect(range).is_some()))
+        .collect();
+
+    SemanticTokensResult { tokens }
+}
+
+/// Returns the kind of the tokens of `binding`, and whether the binding is read-only.
+/// Returns [None] for the bindings that aren't classified, e.g. functions and types.
+fn binding_token_kind(binding: &AnyJsIdentifierBinding) -> Option<(SemanticTokenKind, bool)> {
+    if matches!(binding, AnyJsIdentifierBinding::TsTypeParameterName(_)) {
+        return Some((SemanticTokenKind::TypeParameter, false));
+    }
+
+    let kind = match binding.declaration()? {
+        AnyJsBindingDeclaration::JsArrowFunctionExpression(_)
+        | AnyJsBindingDeclaration::JsFormalParameter(_)
+        | AnyJsBindingDeclaration::JsRestParameter(_)
+        | AnyJsBindingDeclaration::JsBogusParameter(_)
+        | AnyJsBindingDeclaration::TsIndexSignatureParameter(_)
+        | AnyJsBindingDeclaration::TsPropertyParameter(_) => SemanticTokenKind::Parameter,
+        AnyJsBindingDeclaration::TsInferType(_)
+        | AnyJsBindingDeclaration::TsMappedType(_)
+        | AnyJsBindingDeclaration::TsTypeParameter(_) => SemanticTokenKind::TypeParameter,
+        AnyJsBindingDeclaration::JsClassDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassExpression(_)
+        | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_) => SemanticTokenKind::Class,
+        AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsBogusNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsDefaultImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsNamespaceImportSpecifier(_)
+        | AnyJsBindingDeclaration::TsImportEqualsDeclaration(_) => {
+            return Some((SemanticTokenKind::Import, true));
+        }
+        AnyJsBindingDeclaration::JsVariableDeclarator(_)
+        | AnyJsBindingDeclaration::JsCatchDeclaration(_) => SemanticTokenKind::Variable,
+        // The bindings of a pattern are either parameters or variables
+        AnyJsBindingDeclaration::JsArrayBindingPatternElement(_)
+        | AnyJsBindingDeclaration::JsArrayBindingPatternRestElement(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternProperty(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternRest(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternShorthandProperty(_) => {
+            let is_parameter = binding.syntax().ancestors().any(|ancestor| {
+                matches!(
+                    ancestor.kind(),
+                    JsSyntaxKind::JS_FORMAL_PARAMETER | JsSyntaxKind::JS_REST_PARAMETER
+                )
+            });
+            if is_parameter {
+                SemanticTokenKind::Parameter
+            } else {
+                SemanticTokenKind::Variable
+            }
+        }
+        _ => return None,
+    };
+
+    let is_readonly = kind == SemanticTokenKind::Variable
+        && binding
+            .syntax()
+            .ancestors()
+            .find_map(AnyJsVariableDeclaration::cast)
+            .is_some_and(|declaration| declaration.is_const());
+    Some((kind, is_readonly))
+}
+
+pub(crate) fn organize_imports(
+    _: &BiomePath,
+    parse: AnyParse,
+    _: SettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
     let mut tree: AnyJsRoot = parse.tree();
 
     let filter = AnalysisFilter {
@@ -762,6 +1297,8 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
         &AnalyzerOptions::default(),
         JsFileSource::default(),
         None,
+        ImportedCssModules::default(),
+        &[],
         |signal| {
             for action in signal.actions() {
                 if action.is_suppression() {
@@ -798,18 +1335,20 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
     }
 }
 
+fn search(parse: AnyParse, pattern: &Pattern<JsLanguage>) -> Vec<TextRange> {
+    let tree = parse.syntax::<JsLanguage>();
+    pattern
+        .find_matches(&tree)
+        .iter()
+        .map(PatternMatch::range)
+        .collect()
+}
+
 fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
     let settings = settings.as_ref();
     let configuration = AnalyzerConfiguration {
         rules: to_analyzer_rules(settings, file_path.as_path()),
-        globals: settings
-            .override_settings
-            .override_js_globals(
-                &BiomePath::new(file_path.as_path()),
-                &settings.languages.javascript.globals,
-            )
-            .into_iter()
-            .collect(),
+        globals: to_analyzer_globals(settings, file_path.as_path()),
     };
 
     AnalyzerOptions {
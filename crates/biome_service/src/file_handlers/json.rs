@@ -1,33 +1,40 @@
 use super::{CodeActionsParams, DocumentFileSource, ExtensionHandler, Mime, ParseResult};
 use crate::configuration::{to_analyzer_rules, PartialConfiguration};
+use crate::file_handlers::ranges;
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, FixAllParams, FormatterCapabilities, LintParams,
-    LintResults, ParserCapabilities,
+    LintResults, ParserCapabilities, SearchCapabilities,
 };
 use crate::settings::{
     FormatSettings, Language, LanguageListSettings, LanguageSettings, OverrideSettings,
-    SettingsHandle,
+    SettingsHandle, WorkspaceSettings,
 };
 use crate::workspace::{
-    FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
+    CodeAction, CompletionItem, FixFileResult, FoldingRange, GetSyntaxTreeResult,
+    OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
+use biome_analyze::options::RuleOptions;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never, RuleCategories,
+    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules, ControlFlow, Never,
+    RuleCategories, RuleFilter, RuleKey,
 };
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
 use biome_fs::{BiomePath, ConfigName, ROME_JSON};
-use biome_json_analyze::analyze;
+use biome_js_analyze::RuleError;
+use biome_json_analyze::{analyze, JsonSchemas, SortKeysOptions};
 use biome_json_formatter::context::{JsonFormatOptions, TrailingCommas};
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
-use biome_json_syntax::{JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_json_syntax::{JsonLanguage, JsonRoot, JsonSyntaxKind, JsonSyntaxNode};
 use biome_parser::AnyParse;
+use biome_project::ProjectImports;
 use biome_rowan::{AstNode, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -48,10 +55,18 @@ pub struct JsonParserSettings {
     pub allow_trailing_commas: bool,
 }
 
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JsonAssistsSettings {
+    pub sort_keys: bool,
+    pub keys_order: Vec<String>,
+}
+
 impl Language for JsonLanguage {
     type FormatterSettings = JsonFormatterSettings;
     type LinterSettings = ();
     type OrganizeImportsSettings = ();
+    type AssistsSettings = JsonAssistsSettings;
     type FormatOptions = JsonFormatOptions;
     type ParserSettings = JsonParserSettings;
     fn lookup_settings(language: &LanguageListSettings) -> &LanguageSettings<Self> {
@@ -113,7 +128,11 @@ impl ExtensionHandler for JsonFileHandler {
 
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(selection_ranges),
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -123,6 +142,9 @@ impl ExtensionHandler for JsonFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                rename_imports: None,
+                semantic_tokens: None,
+                completions: Some(completions),
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
@@ -131,6 +153,7 @@ impl ExtensionHandler for JsonFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
+            search: SearchCapabilities { search: None },
         }
     }
 }
@@ -156,13 +179,18 @@ fn parse(
 ) -> ParseResult {
     let parser = &settings.as_ref().languages.json.parser;
     let overrides = &settings.as_ref().override_settings;
+    let is_json5 = file_source.to_json_file_source().map(|fs| fs.is_json5()) == Some(true);
     let options: JsonParserOptions = overrides.override_json_parser_options(
         biome_path,
         JsonParserOptions {
             allow_comments: parser.allow_comments
                 || file_source.to_json_file_source().map(|fs| fs.is_jsonc()) == Some(true)
+                || is_json5
+                || is_file_allowed(biome_path),
+            allow_trailing_commas: parser.allow_trailing_commas
+                || is_json5
                 || is_file_allowed(biome_path),
-            allow_trailing_commas: parser.allow_trailing_commas || is_file_allowed(biome_path),
+            json5: is_json5,
         },
     );
     if let DocumentFileSource::Json(file_source) = &mut file_source {
@@ -191,6 +219,16 @@ fn debug_syntax_tree(_rome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeRe
     }
 }
 
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let syntax: JsonSyntaxNode = parse.syntax();
+    ranges::folding_ranges(&syntax, |_| false, |_| false)
+}
+
+fn selection_ranges(parse: AnyParse, offsets: &[TextSize]) -> Vec<Vec<TextRange>> {
+    let syntax: JsonSyntaxNode = parse.syntax();
+    ranges::selection_ranges(&syntax, offsets)
+}
+
 fn debug_formatter_ir(
     path: &BiomePath,
     document_file_source: &DocumentFileSource,
@@ -322,48 +360,61 @@ fn lint(params: LintParams) -> LintResults {
             filter.categories = params.categories;
             let has_lint = filter.categories.contains(RuleCategories::LINT);
 
-            let (_, analyze_diagnostics) = analyze(&root, filter, &analyzer_options, |signal| {
-                if let Some(mut diagnostic) = signal.diagnostic() {
-                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
-                    if !has_lint && diagnostic.category() == Some(category!("suppressions/unused"))
-                    {
-                        return ControlFlow::<Never>::Continue(());
-                    }
+            let (_, analyze_diagnostics) = analyze(
+                &root,
+                filter,
+                &analyzer_options,
+                params.project_imports,
+                JsonSchemas::new(
+                    settings
+                        .json_schemas(params.path.as_path())
+                        .cloned()
+                        .collect(),
+                ),
+                |signal| {
+                    if let Some(mut diagnostic) = signal.diagnostic() {
+                        // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                        if !has_lint
+                            && diagnostic.category() == Some(category!("suppressions/unused"))
+                        {
+                            return ControlFlow::<Never>::Continue(());
+                        }
 
-                    diagnostic_count += 1;
-
-                    // We do now check if the severity of the diagnostics should be changed.
-                    // The configuration allows to change the severity of the diagnostics emitted by rules.
-                    let severity = diagnostic
-                        .category()
-                        .filter(|category| category.name().starts_with("lint/"))
-                        .map(|category| {
-                            rules
-                                .as_ref()
-                                .and_then(|rules| rules.get_severity_from_code(category))
-                                .unwrap_or(Severity::Warning)
-                        })
-                        .unwrap_or_else(|| diagnostic.severity());
-
-                    if severity <= Severity::Error {
-                        errors += 1;
-                    }
+                        diagnostic_count += 1;
+
+                        // We do now check if the severity of the diagnostics should be changed.
+                        // The configuration allows to change the severity of the diagnostics emitted by rules.
+                        let severity = diagnostic
+                            .category()
+                            .filter(|category| category.name().starts_with("lint/"))
+                            .map(|category| {
+                                rules
+                                    .as_ref()
+                                    .and_then(|rules| rules.get_severity_from_code(category))
+                                    .unwrap_or(Severity::Warning)
+                            })
+                            .unwrap_or_else(|| diagnostic.severity());
+
+                        if severity <= Severity::Error {
+                            errors += 1;
+                        }
 
-                    if diagnostic_count <= params.max_diagnostics {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diagnostic = diagnostic.add_code_suggestion(action.into());
+                        if diagnostic_count <= params.max_diagnostics {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diagnostic = diagnostic.add_code_suggestion(action.into());
+                                }
                             }
-                        }
 
-                        let error = diagnostic.with_severity(severity);
+                            let error = diagnostic.with_severity(severity);
 
-                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                            diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                        }
                     }
-                }
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                },
+            );
 
             diagnostics.extend(
                 analyze_diagnostics
@@ -380,10 +431,90 @@ fn lint(params: LintParams) -> LintResults {
         })
 }
 
-fn code_actions(_: CodeActionsParams) -> PullActionsResult {
-    PullActionsResult {
-        actions: Vec::new(),
+/// Suggests the properties described by the JSON schemas of the file
+fn completions(
+    path: &BiomePath,
+    parse: AnyParse,
+    settings: SettingsHandle,
+    offset: TextSize,
+) -> Vec<CompletionItem> {
+    let root: JsonRoot = parse.tree();
+    // The quotes are inserted when the cursor isn't inside a key yet
+    let is_inside_string = match root.syntax().token_at_offset(offset) {
+        TokenAtOffset::Single(token) | TokenAtOffset::Between(token, _) => {
+            token.kind() == JsonSyntaxKind::JSON_STRING_LITERAL
+                && token.text_trimmed_range().start() < offset
+                && (token.text_trimmed_range().end() > offset
+                    || !token.text_trimmed().ends_with('"'))
+        }
+        TokenAtOffset::None => false,
+    };
+
+    let mut items: Vec<CompletionItem> = Vec::new();
+    for schema in settings.as_ref().json_schemas(path.as_path()) {
+        for property in schema.complete_properties(&root, offset) {
+            if items.iter().any(|item| item.label == property.name) {
+                continue;
+            }
+            let insert_text = if is_inside_string {
+                property.name.clone()
+            } else {
+                format!("\"{}\"", property.name)
+            };
+            items.push(CompletionItem {
+                label: property.name,
+                insert_text,
+                documentation: property.description,
+            });
+        }
     }
+    items
+}
+
+fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    let CodeActionsParams {
+        parse,
+        range,
+        settings,
+        path,
+        ..
+    } = params;
+    let mut actions = Vec::new();
+    if !settings.as_ref().json_sort_keys_enabled(path.as_path()) {
+        return PullActionsResult { actions };
+    }
+
+    let tree: JsonRoot = parse.tree();
+    let filter = AnalysisFilter {
+        enabled_rules: Some(&[SORT_KEYS]),
+        categories: RuleCategories::ACTION,
+        range: Some(range),
+        ..AnalysisFilter::default()
+    };
+    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(path.as_path()));
+
+    analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        ProjectImports::default(),
+        JsonSchemas::default(),
+        |signal| {
+            actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                CodeAction {
+                    category: item.category.clone(),
+                    rule_name: item
+                        .rule_name
+                        .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                    suggestion: item.suggestion,
+                }
+            }));
+
+            ControlFlow::<Never>::Continue(())
+        },
+    );
+
+    PullActionsResult { actions }
 }
 
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
@@ -396,15 +527,89 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     })
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+fn organize_imports(
+    path: &BiomePath,
+    parse: AnyParse,
+    settings: SettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    let tree: JsonRoot = parse.tree();
+    if !settings.as_ref().json_sort_keys_enabled(path.as_path()) {
+        return Ok(OrganizeImportsResult {
+            code: tree.syntax().to_string(),
+        });
+    }
+
+    let filter = AnalysisFilter {
+        enabled_rules: Some(&[SORT_KEYS]),
+        categories: RuleCategories::ACTION,
+        ..AnalysisFilter::default()
+    };
+    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(path.as_path()));
+
+    let (action, _) = analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        ProjectImports::default(),
+        JsonSchemas::default(),
+        |signal| {
+            for action in signal.actions() {
+                if action.is_suppression() {
+                    continue;
+                }
+
+                return ControlFlow::Break(action);
+            }
+            ControlFlow::Continue(())
+        },
+    );
+
+    let Some(action) = action else {
+        return Ok(OrganizeImportsResult {
+            code: tree.syntax().to_string(),
+        });
+    };
+    let Some(tree) = JsonRoot::cast(action.mutation.commit()) else {
+        return Err(WorkspaceError::RuleError(
+            RuleError::ReplacedRootWithNonRootError {
+                rule_name: action
+                    .rule_name
+                    .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+            },
+        ));
+    };
+
     Ok(OrganizeImportsResult {
-        code: parse.syntax::<JsonLanguage>().to_string(),
+        code: tree.syntax().to_string(),
     })
 }
 
+/// The assists applied when organizing a JSON file
+const SORT_KEYS: RuleFilter<'static> = RuleFilter::Rule("correctness", "sortKeys");
+
+/// Pushes the options of the assists applied to the JSON file at `path` to `analyzer_rules`
+pub fn push_json_assists_to_analyzer_rules(
+    settings: &WorkspaceSettings,
+    path: &Path,
+    analyzer_rules: &mut AnalyzerRules,
+) {
+    let assists = settings
+        .override_settings
+        .json_assists(path)
+        .unwrap_or(&settings.languages.json.assists);
+    analyzer_rules.push_rule(
+        RuleKey::new("correctness", "sortKeys"),
+        RuleOptions::new(SortKeysOptions {
+            keys_order: assists.keys_order.clone(),
+        }),
+    );
+}
+
 fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
+    let mut rules = to_analyzer_rules(settings.as_ref(), file_path.as_path());
+    push_json_assists_to_analyzer_rules(settings.as_ref(), file_path.as_path(), &mut rules);
     let configuration = AnalyzerConfiguration {
-        rules: to_analyzer_rules(settings.as_ref(), file_path.as_path()),
+        rules,
         globals: vec![],
     };
     AnalyzerOptions {
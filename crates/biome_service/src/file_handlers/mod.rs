@@ -8,7 +8,10 @@ pub use crate::file_handlers::vue::{VueFileHandler, VUE_FENCE};
 use crate::workspace::{FixFileMode, OrganizeImportsResult};
 use crate::{
     settings::SettingsHandle,
-    workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
+    workspace::{
+        CompletionItem, FixFileResult, FoldingRange, GetSyntaxTreeResult, PullActionsResult,
+        RenameImportsResult, RenameResult, SemanticTokensResult,
+    },
     Rules, WorkspaceError,
 };
 use biome_analyze::{AnalysisFilter, AnalyzerDiagnostic, RuleCategories};
@@ -19,12 +22,17 @@ use biome_css_syntax::CssFileSource;
 use biome_diagnostics::{Diagnostic, Severity};
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
-use biome_js_syntax::{EmbeddingKind, JsFileSource, ModuleKind, TextRange, TextSize};
+use biome_js_analyze::ImportedCssModules;
+use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage, ModuleKind, TextRange, TextSize};
 use biome_json_syntax::JsonFileSource;
 use biome_parser::AnyParse;
-use biome_project::PackageJson;
+use biome_pattern::Pattern;
+use biome_project::{PackageJson, ProjectImports};
 use biome_rowan::NodeCache;
+pub use css::push_css_assists_to_analyzer_rules;
 pub use javascript::JsFormatterSettings;
+pub(crate) use javascript::{imported_css_modules, project_imports};
+pub use json::push_json_assists_to_analyzer_rules;
 use std::ffi::OsStr;
 use std::path::Path;
 
@@ -32,6 +40,7 @@ mod astro;
 mod css;
 mod javascript;
 mod json;
+mod ranges;
 mod svelte;
 mod unknown;
 mod vue;
@@ -113,10 +122,13 @@ impl DocumentFileSource {
             "tsx" => JsFileSource::tsx().into(),
             "json" => JsonFileSource::json().into(),
             "jsonc" => JsonFileSource::jsonc().into(),
+            "json5" => JsonFileSource::json5().into(),
             "astro" => JsFileSource::astro().into(),
             "vue" => JsFileSource::vue().into(),
             "svelte" => JsFileSource::svelte().into(),
             "css" => CssFileSource::css().into(),
+            "scss" => CssFileSource::scss().into(),
+            "less" => CssFileSource::less().into(),
             _ => DocumentFileSource::Unknown,
         }
     }
@@ -135,6 +147,7 @@ impl DocumentFileSource {
             "typescriptreact" => JsFileSource::tsx().into(),
             "json" => JsonFileSource::json().into(),
             "jsonc" => JsonFileSource::json().into(),
+            "json5" => JsonFileSource::json5().into(),
             "astro" => JsFileSource::astro().into(),
             "vue" => JsFileSource::vue().into(),
             "svelte" => JsFileSource::svelte().into(),
@@ -279,6 +292,8 @@ impl biome_console::fmt::Display for DocumentFileSource {
             DocumentFileSource::Json(json) => {
                 if json.is_jsonc() {
                     fmt.write_markup(markup! { "JSONC" })
+                } else if json.is_json5() {
+                    fmt.write_markup(markup! { "JSON5" })
                 } else {
                     fmt.write_markup(markup! { "JSON" })
                 }
@@ -325,6 +340,7 @@ pub struct FixAllParams<'a> {
     pub(crate) should_format: bool,
     pub(crate) biome_path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) css_modules: ImportedCssModules,
     pub(crate) document_file_source: DocumentFileSource,
 }
 
@@ -335,6 +351,7 @@ pub struct Capabilities {
     pub(crate) debug: DebugCapabilities,
     pub(crate) analyzer: AnalyzerCapabilities,
     pub(crate) formatter: FormatterCapabilities,
+    pub(crate) search: SearchCapabilities,
 }
 
 #[derive(Clone)]
@@ -346,10 +363,17 @@ pub struct ParseResult {
 type Parse =
     fn(&BiomePath, DocumentFileSource, &str, SettingsHandle, &mut NodeCache) -> ParseResult;
 
+type FoldingRanges = fn(AnyParse) -> Vec<FoldingRange>;
+type SelectionRanges = fn(AnyParse, &[TextSize]) -> Vec<Vec<TextRange>>;
+
 #[derive(Default)]
 pub struct ParserCapabilities {
     /// Parse a file
     pub(crate) parse: Option<Parse>,
+    /// It computes the ranges of a file that can be folded
+    pub(crate) folding_ranges: Option<FoldingRanges>,
+    /// It computes the ranges of the nodes that contain some offsets
+    pub(crate) selection_ranges: Option<SelectionRanges>,
 }
 
 type DebugSyntaxTree = fn(&BiomePath, AnyParse) -> GetSyntaxTreeResult;
@@ -375,6 +399,8 @@ pub(crate) struct LintParams<'a> {
     pub(crate) path: &'a BiomePath,
     pub(crate) categories: RuleCategories,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) css_modules: ImportedCssModules,
+    pub(crate) project_imports: ProjectImports,
 }
 
 pub(crate) struct LintResults {
@@ -390,6 +416,7 @@ pub(crate) struct CodeActionsParams<'a> {
     pub(crate) settings: SettingsHandle<'a>,
     pub(crate) path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) css_modules: ImportedCssModules,
     pub(crate) language: DocumentFileSource,
 }
 
@@ -397,7 +424,18 @@ type Lint = fn(LintParams) -> LintResults;
 type CodeActions = fn(CodeActionsParams) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
 type Rename = fn(&BiomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
-type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
+type RenameImports =
+    fn(&BiomePath, AnyParse, &BiomePath, &str, &str) -> Result<RenameImportsResult, WorkspaceError>;
+type SemanticTokens = fn(
+    &BiomePath,
+    &DocumentFileSource,
+    AnyParse,
+    SettingsHandle,
+    Option<TextRange>,
+) -> SemanticTokensResult;
+type Completions = fn(&BiomePath, AnyParse, SettingsHandle, TextSize) -> Vec<CompletionItem>;
+type OrganizeImports =
+    fn(&BiomePath, AnyParse, SettingsHandle) -> Result<OrganizeImportsResult, WorkspaceError>;
 
 #[derive(Default)]
 pub struct AnalyzerCapabilities {
@@ -409,6 +447,12 @@ pub struct AnalyzerCapabilities {
     pub(crate) fix_all: Option<FixAll>,
     /// It renames a binding inside a file
     pub(crate) rename: Option<Rename>,
+    /// It renames the imports of a binding exported by another file
+    pub(crate) rename_imports: Option<RenameImports>,
+    /// It classifies the identifiers of a file
+    pub(crate) semantic_tokens: Option<SemanticTokens>,
+    /// It suggests the items that can be inserted at a position of a file
+    pub(crate) completions: Option<Completions>,
     /// It organize imports
     pub(crate) organize_imports: Option<OrganizeImports>,
 }
@@ -444,6 +488,14 @@ pub(crate) struct FormatterCapabilities {
     pub(crate) format_on_type: Option<FormatOnType>,
 }
 
+type Search = fn(AnyParse, &Pattern<JsLanguage>) -> Vec<TextRange>;
+
+#[derive(Default)]
+pub(crate) struct SearchCapabilities {
+    /// It searches the occurrences of a structural pattern inside a file
+    pub(crate) search: Option<Search>,
+}
+
 /// Main trait to use to add a new language to Biome
 pub(crate) trait ExtensionHandler {
     /// MIME types used to identify a certain language
@@ -0,0 +1,172 @@
+//! Computes the folding ranges and the selection ranges of a file from its syntax tree.
+//!
+//! The computation doesn't depend on the language: the handlers only tell which
+//! kinds of nodes are regions or imports.
+
+use crate::workspace::{FoldingRange, FoldingRangeKind};
+use biome_rowan::{
+    Direction, Language, SyntaxNode, SyntaxToken, TextRange, TextSize, TokenAtOffset,
+};
+
+/// Returns the ranges of `root` that span several lines and can be folded:
+/// - the nodes delimited by brackets, e.g. blocks, objects and arrays;
+/// - the nodes for which `is_region` returns `true`, e.g. JSX elements;
+/// - the groups of consecutive nodes for which `is_import` returns `true`;
+/// - the multiline comments, and the groups of consecutive single line comments.
+pub(crate) fn folding_ranges<L: Language>(
+    root: &SyntaxNode<L>,
+    is_region: impl Fn(L::Kind) -> bool,
+    is_import: impl Fn(L::Kind) -> bool,
+) -> Vec<FoldingRange> {
+    let text = root.text().to_string();
+    let mut ranges = Vec::new();
+    let mut push = |range: TextRange, kind: FoldingRangeKind| {
+        if text[range].contains('\n') {
+            ranges.push(FoldingRange { range, kind });
+        }
+    };
+
+    for node in root.descendants() {
+        let kind = node.kind();
+        if is_region(kind) {
+            push(node.text_trimmed_range(), FoldingRangeKind::Region);
+        } else if let Some(range) = delimited_range(&node) {
+            push(range, FoldingRangeKind::Region);
+        }
+
+        let starts_import_group = is_import(kind)
+            && !node
+                .prev_sibling()
+                .is_some_and(|previous| is_import(previous.kind()));
+        if starts_import_group {
+            let last = node
+                .siblings(Direction::Next)
+                .take_while(|sibling| is_import(sibling.kind()))
+                .last()
+                .unwrap_or_else(|| node.clone());
+            let range = TextRange::new(
+                node.text_trimmed_range().start(),
+                last.text_trimmed_range().end(),
+            );
+            push(range, FoldingRangeKind::Imports);
+        }
+    }
+
+    for token in root.descendants_tokens(Direction::Next) {
+        for range in comment_groups(&token) {
+            push(range, FoldingRangeKind::Comment);
+        }
+    }
+
+    ranges.sort_by_key(|folding_range| folding_range.range.start());
+    ranges.dedup_by(|a, b| a.range == b.range);
+    ranges
+}
+
+/// Returns the range from the opening bracket to the closing bracket of `node`,
+/// when both brackets are children of the node
+fn delimited_range<L: Language>(node: &SyntaxNode<L>) -> Option<TextRange> {
+    let first = node.first_token()?;
+    let last = node.last_token()?;
+    if first.parent().as_ref() != Some(node) || last.parent().as_ref() != Some(node) {
+        return None;
+    }
+
+    let closing = match first.text_trimmed() {
+        "{" => "}",
+        "[" => "]",
+        "(" => ")",
+        _ => return None,
+    };
+    (last.text_trimmed() == closing).then(|| {
+        TextRange::new(
+            first.text_trimmed_range().start(),
+            last.text_trimmed_range().end(),
+        )
+    })
+}
+
+/// Returns the ranges of the comments of the leading trivia of `token`. The single line
+/// comments that aren't separated by an empty line are grouped in the same range.
+fn comment_groups<L: Language>(token: &SyntaxToken<L>) -> Vec<TextRange> {
+    let mut groups = Vec::new();
+    let mut group: Option<TextRange> = None;
+    let mut newlines = 0;
+
+    for piece in token.leading_trivia().pieces() {
+        if piece.is_newline() {
+            newlines += 1;
+        } else if let Some(comment) = piece.as_comments() {
+            if comment.has_newline() {
+                groups.extend(group.take());
+                groups.push(comment.text_range());
+            } else {
+                group = match group {
+                    Some(range) if newlines <= 1 => Some(range.cover(comment.text_range())),
+                    _ => {
+                        groups.extend(group.take());
+                        Some(comment.text_range())
+                    }
+                };
+            }
+            newlines = 0;
+        } else if !piece.is_whitespace() {
+            groups.extend(group.take());
+        }
+    }
+
+    groups.extend(group);
+    groups
+}
+
+/// Returns, for each offset, the ranges of the token and of the nodes that contain it,
+/// from the innermost to the outermost
+pub(crate) fn selection_ranges<L: Language>(
+    root: &SyntaxNode<L>,
+    offsets: &[TextSize],
+) -> Vec<Vec<TextRange>> {
+    offsets
+        .iter()
+        .map(|offset| selection_range(root, *offset))
+        .collect()
+}
+
+fn selection_range<L: Language>(root: &SyntaxNode<L>, offset: TextSize) -> Vec<TextRange> {
+    let token = match root.token_at_offset(offset) {
+        TokenAtOffset::None => return Vec::new(),
+        TokenAtOffset::Single(token) => token,
+        // The cursor is at the end of a word, e.g. `call|(`: the word is selected
+        TokenAtOffset::Between(left, right) => {
+            if is_word(&left) && !is_word(&right) {
+                left
+            } else {
+                right
+            }
+        }
+    };
+
+    let mut ranges = Vec::new();
+    let mut push = |range: TextRange| {
+        if !range.is_empty() && ranges.last() != Some(&range) {
+            ranges.push(range);
+        }
+    };
+
+    let token_range = token.text_trimmed_range();
+    if token_range.contains_inclusive(offset) {
+        push(token_range);
+    }
+    for node in token.ancestors() {
+        push(node.text_trimmed_range());
+    }
+
+    ranges
+}
+
+fn is_word<L: Language>(token: &SyntaxToken<L>) -> bool {
+    token
+        .text_trimmed()
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '-'))
+}
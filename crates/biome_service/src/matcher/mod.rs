@@ -98,7 +98,15 @@ impl Matcher {
 
     fn run_match(&self, source: &Path) -> bool {
         for pattern in &self.patterns {
-            let matches = if pattern.matches_path_with(source, self.options) {
+            // Absolute paths inside the root are also matched relatively to the root,
+            // so patterns like `src/**` keep working
+            let matches = if pattern.matches_path_with(source, self.options)
+                || self
+                    .root
+                    .as_deref()
+                    .and_then(|root| source.strip_prefix(root).ok())
+                    .is_some_and(|relative| pattern.matches_path_with(relative, self.options))
+            {
                 true
             } else {
                 // Here we cover cases where the user specifies single files inside the patterns.
@@ -163,6 +171,20 @@ mod test {
         assert!(result);
     }
 
+    #[test]
+    fn matches_path_relative_to_root() {
+        let current = env::current_dir().unwrap();
+        let mut ignore = Matcher::new(MatchOptions::default());
+        ignore.set_root(current.clone());
+        ignore.add_pattern("src/**/*.rs").unwrap();
+
+        let path = current.join("src/workspace.rs");
+        assert!(ignore.matches_path(path.as_path()));
+
+        let path = current.join("tests/spec_tests.rs");
+        assert!(!ignore.matches_path(path.as_path()));
+    }
+
     #[test]
     fn matches_path_for_single_file_or_directory_name() {
         let dir = "inv";
@@ -4,13 +4,14 @@ use crate::configuration::linter::to_linter_settings;
 use crate::configuration::organize_imports::{to_organize_imports_settings, OrganizeImports};
 use crate::configuration::{
     push_to_analyzer_rules, to_override_settings, CssConfiguration, FormatterConfiguration,
-    JavascriptConfiguration, JsonConfiguration, LinterConfiguration, PartialConfiguration,
+    JavascriptConfiguration, JsonConfiguration, JsonSchemaDefinition, LinterConfiguration,
+    PartialConfiguration,
 };
 use crate::workspace::DocumentFileSource;
 use crate::{
     configuration::FilesConfiguration, ConfigurationDiagnostic, Matcher, Rules, WorkspaceError,
 };
-use biome_analyze::AnalyzerRules;
+use biome_analyze::{AnalyzerPlugin, AnalyzerRules};
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssLanguage;
@@ -18,10 +19,12 @@ use biome_deserialize::{Merge, StringSet};
 use biome_diagnostics::Category;
 use biome_formatter::{AttributePosition, IndentStyle, IndentWidth, LineEnding, LineWidth};
 use biome_fs::BiomePath;
+use biome_js_analyze::globals::JsEnvironment;
 use biome_js_analyze::metadata;
 use biome_js_formatter::context::JsFormatOptions;
 use biome_js_parser::JsParserOptions;
 use biome_js_syntax::JsLanguage;
+use biome_json_analyze::schema::JsonSchema;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
@@ -31,7 +34,7 @@ use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::{
     num::NonZeroU64,
-    sync::{RwLock, RwLockReadGuard},
+    sync::{Arc, RwLock, RwLockReadGuard},
 };
 
 /// Global settings for the entire workspace
@@ -49,6 +52,10 @@ pub struct WorkspaceSettings {
     pub organize_imports: OrganizeImportsSettings,
     /// overrides
     pub override_settings: OverrideSettings,
+    /// Lint rules defined by the plugins listed in the configuration
+    pub plugins: Vec<Arc<dyn AnalyzerPlugin<JsLanguage>>>,
+    /// The JSON schemas listed in the configuration
+    pub json_schemas: Vec<Arc<JsonSchemaSettings>>,
 }
 
 impl WorkspaceSettings {
@@ -75,6 +82,18 @@ impl WorkspaceSettings {
         enabled == Some(&false)
     }
 
+    /// Whether the declarations of CSS files are sorted when organizing them
+    pub fn css_sort_declarations_enabled(&self) -> bool {
+        self.languages.css.assists.sort_declarations
+    }
+
+    /// Whether the keys of the JSON file at `path` are sorted when organizing it
+    pub fn json_sort_keys_enabled(&self, path: &Path) -> bool {
+        self.override_settings
+            .json_sort_keys_enabled(path)
+            .unwrap_or(self.languages.json.assists.sort_keys)
+    }
+
     /// Retrieves the settings of the linter
     pub fn linter(&self) -> &LinterSettings {
         &self.linter
@@ -85,6 +104,14 @@ impl WorkspaceSettings {
         &self.organize_imports
     }
 
+    /// Returns the JSON schemas that the file at `path` must match
+    pub fn json_schemas<'a>(&'a self, path: &'a Path) -> impl Iterator<Item = &'a Arc<JsonSchema>> {
+        self.json_schemas
+            .iter()
+            .filter(move |settings| settings.include.matches_path(path))
+            .map(|settings| &settings.schema)
+    }
+
     /// The (configuration)[Configuration] is merged into the workspace
     #[tracing::instrument(level = "trace", skip(self))]
     pub fn merge_with_configuration(
@@ -187,6 +214,85 @@ impl WorkspaceSettings {
     }
 }
 
+/// Settings of every project folder registered in the workspace
+#[derive(Debug, Default)]
+pub struct ProjectsSettings {
+    /// Settings applied to the files that don't belong to any project folder
+    default: ProjectSettings,
+    /// Settings of each registered project folder, paired with the path of the folder
+    folders: Vec<(PathBuf, ProjectSettings)>,
+}
+
+/// The settings of a project folder
+#[derive(Debug, Default)]
+pub struct ProjectSettings {
+    /// The settings computed from the configuration
+    pub settings: WorkspaceSettings,
+    /// The configuration the settings were computed from. Nested configurations
+    /// are merged over it.
+    pub configuration: PartialConfiguration,
+}
+
+impl ProjectsSettings {
+    /// Returns the index of the project folder that contains `path`.
+    ///
+    /// When folders are nested, the innermost folder wins.
+    fn folder_index(&self, path: &Path) -> Option<usize> {
+        self.folders
+            .iter()
+            .enumerate()
+            .filter(|(_, (folder, _))| path.starts_with(folder))
+            .max_by_key(|(_, (folder, _))| folder.components().count())
+            .map(|(index, _)| index)
+    }
+
+    fn get(&self, index: Option<usize>) -> &WorkspaceSettings {
+        index
+            .and_then(|index| self.folders.get(index))
+            .map_or(&self.default.settings, |(_, project)| &project.settings)
+    }
+
+    /// Returns the settings that apply to the file at `path`
+    pub fn settings_for_path(&self, path: &Path) -> &WorkspaceSettings {
+        self.get(self.folder_index(path))
+    }
+
+    /// Returns the project enclosing the project folder at `folder`: the innermost
+    /// other project folder that contains it, or the default project.
+    pub fn parent_project(&self, folder: &Path) -> &ProjectSettings {
+        self.folders
+            .iter()
+            .filter(|(path, _)| path != folder && folder.starts_with(path))
+            .max_by_key(|(path, _)| path.components().count())
+            .map_or(&self.default, |(_, project)| project)
+    }
+
+    /// Returns the project folder at `folder`, registering the folder if it wasn't
+    /// registered yet. When `folder` is [None], the default project is returned.
+    pub fn project_mut(&mut self, folder: Option<PathBuf>) -> &mut ProjectSettings {
+        let Some(folder) = folder else {
+            return &mut self.default;
+        };
+        let index = match self.folders.iter().position(|(path, _)| path == &folder) {
+            Some(index) => index,
+            None => {
+                self.folders.push((folder, ProjectSettings::default()));
+                self.folders.len() - 1
+            }
+        };
+        &mut self.folders[index].1
+    }
+
+    /// Removes the project folder at `folder` and its settings.
+    ///
+    /// Returns `false` if the folder wasn't registered.
+    pub fn remove_folder(&mut self, folder: &Path) -> bool {
+        let len = self.folders.len();
+        self.folders.retain(|(path, _)| path != folder);
+        len != self.folders.len()
+    }
+}
+
 /// Formatter settings for the entire workspace
 #[derive(Debug)]
 pub struct FormatSettings {
@@ -331,6 +437,7 @@ impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
             javascript.parser.unsafe_parameter_decorators_enabled;
 
         language_setting.globals = Some(javascript.globals.into_index_set());
+        language_setting.linter.environments = Some(javascript.environments.into_index_set());
 
         language_setting
     }
@@ -347,6 +454,13 @@ impl From<JsonConfiguration> for LanguageSettings<JsonLanguage> {
         language_setting.formatter.line_width = json.formatter.line_width;
         language_setting.formatter.indent_width = json.formatter.indent_width.map(Into::into);
         language_setting.formatter.indent_style = json.formatter.indent_style.map(Into::into);
+        language_setting.assists.sort_keys = json.assists.sort_keys;
+        language_setting.assists.keys_order = json
+            .assists
+            .keys_order
+            .into_index_set()
+            .into_iter()
+            .collect();
 
         language_setting
     }
@@ -361,6 +475,14 @@ impl From<CssConfiguration> for LanguageSettings<CssLanguage> {
         language_setting.formatter.indent_width = css.formatter.indent_width.map(Into::into);
         language_setting.formatter.indent_style = css.formatter.indent_style.map(Into::into);
         language_setting.formatter.quote_style = Some(css.formatter.quote_style);
+        language_setting.assists.sort_declarations = css.assists.sort_declarations;
+        language_setting.assists.declarations_order = css.assists.declarations_order;
+        language_setting.assists.custom_order = css
+            .assists
+            .custom_order
+            .into_index_set()
+            .into_iter()
+            .collect();
 
         language_setting
     }
@@ -375,6 +497,9 @@ pub trait Language: biome_rowan::Language {
     /// Organize imports settings type for this language
     type OrganizeImportsSettings: Default;
 
+    /// Assists settings type for this language
+    type AssistsSettings: Default;
+
     /// Fully resolved formatter options type for this language
     type FormatOptions: biome_formatter::FormatOptions;
 
@@ -409,6 +534,9 @@ pub struct LanguageSettings<L: Language> {
     /// Organize imports settings for this language
     pub organize_imports: L::OrganizeImportsSettings,
 
+    /// Assists settings for this language
+    pub assists: L::AssistsSettings,
+
     /// Parser settings for this language
     pub parser: L::ParserSettings,
 }
@@ -484,20 +612,23 @@ fn to_file_settings(
 /// the deferred language-specific options resolution is called
 #[derive(Debug)]
 pub struct SettingsHandle<'a> {
-    inner: RwLockReadGuard<'a, WorkspaceSettings>,
+    inner: RwLockReadGuard<'a, ProjectsSettings>,
+    /// The project folder of the file, [None] when the file uses the default settings
+    folder: Option<usize>,
 }
 
 impl<'a> SettingsHandle<'a> {
-    pub(crate) fn new(settings: &'a RwLock<WorkspaceSettings>) -> Self {
-        Self {
-            inner: settings.read().unwrap(),
-        }
+    /// Locks the settings that apply to the file at `path`
+    pub(crate) fn new(settings: &'a RwLock<ProjectsSettings>, path: &Path) -> Self {
+        let inner = settings.read().unwrap();
+        let folder = inner.folder_index(path);
+        Self { inner, folder }
     }
 }
 
 impl<'a> AsRef<WorkspaceSettings> for SettingsHandle<'a> {
     fn as_ref(&self) -> &WorkspaceSettings {
-        &self.inner
+        self.inner.get(self.folder)
     }
 }
 
@@ -511,10 +642,11 @@ impl<'a> SettingsHandle<'a> {
     where
         L: Language,
     {
+        let settings = self.as_ref();
         L::resolve_format_options(
-            &self.inner.formatter,
-            &self.inner.override_settings,
-            &L::lookup_settings(&self.inner.languages).formatter,
+            &settings.formatter,
+            &settings.override_settings,
+            &L::lookup_settings(&settings.languages).formatter,
             path,
             file_source,
         )
@@ -589,6 +721,27 @@ impl OverrideSettings {
             .unwrap_or_default()
     }
 
+    pub fn override_js_environments(
+        &self,
+        path: &BiomePath,
+        base_set: &Option<IndexSet<JsEnvironment>>,
+    ) -> IndexSet<JsEnvironment> {
+        self.patterns
+            .iter()
+            .fold(base_set.as_ref(), |environments, pattern| {
+                let included = pattern.include.matches_path(path);
+                let excluded = pattern.exclude.matches_path(path);
+
+                if included && !excluded {
+                    pattern.languages.javascript.linter.environments.as_ref()
+                } else {
+                    environments
+                }
+            })
+            .cloned()
+            .unwrap_or_default()
+    }
+
     /// It scans the current override rules and return the formatting options that of the first override is matched
     pub fn override_json_format_options(
         &self,
@@ -675,6 +828,7 @@ impl OverrideSettings {
 
                 return Some(CssParserOptions {
                     allow_wrong_line_comments: css_parser.allow_wrong_line_comments,
+                    ..CssParserOptions::default()
                 });
             }
         }
@@ -695,6 +849,11 @@ impl OverrideSettings {
                 if !excluded && !pattern.include.is_empty() && pattern.include.matches_path(path) {
                     if let Some(rules) = pattern.linter.rules.as_ref() {
                         push_to_analyzer_rules(rules, metadata(), &mut analyzer_rules);
+                        push_to_analyzer_rules(
+                            rules,
+                            biome_json_analyze::metadata(),
+                            &mut analyzer_rules,
+                        );
                     }
                 }
 
@@ -749,6 +908,29 @@ impl OverrideSettings {
         }
         None
     }
+
+    /// Scans the overrides and returns whether the keys of the JSON file at `path` are sorted,
+    /// if an override applies to it
+    pub fn json_sort_keys_enabled(&self, path: &Path) -> Option<bool> {
+        self.json_assists(path).map(|assists| assists.sort_keys)
+    }
+
+    /// Scans the overrides and returns the assists settings of the JSON file at `path`,
+    /// if an override applies to it
+    pub fn json_assists(
+        &self,
+        path: &Path,
+    ) -> Option<&<JsonLanguage as Language>::AssistsSettings> {
+        self.patterns
+            .iter()
+            .filter(|pattern| {
+                !pattern.exclude.matches_path(path)
+                    && !pattern.include.is_empty()
+                    && pattern.include.matches_path(path)
+            })
+            .last()
+            .map(|pattern| &pattern.languages.json.assists)
+    }
 }
 #[derive(Debug, Default)]
 pub struct OverrideSettingPattern {
@@ -934,6 +1116,30 @@ pub fn to_matcher(
     Ok(matcher)
 }
 
+/// A JSON schema, and the files it applies to
+#[derive(Debug)]
+pub struct JsonSchemaSettings {
+    pub include: Matcher,
+    pub schema: Arc<JsonSchema>,
+}
+
+/// Compiles the JSON schemas of the configuration. The `include` patterns are resolved
+/// from `working_directory`.
+pub fn to_json_schema_settings(
+    working_directory: Option<PathBuf>,
+    definitions: Vec<JsonSchemaDefinition>,
+) -> Result<Vec<Arc<JsonSchemaSettings>>, WorkspaceError> {
+    definitions
+        .into_iter()
+        .map(|definition| {
+            Ok(Arc::new(JsonSchemaSettings {
+                include: to_matcher(working_directory.clone(), Some(&definition.include))?,
+                schema: Arc::new(JsonSchema::new(definition.schema)),
+            }))
+        })
+        .collect()
+}
+
 fn to_git_ignore(path: PathBuf, matches: &[String]) -> Result<Gitignore, WorkspaceError> {
     let mut gitignore_builder = GitignoreBuilder::new(path.clone());
 
@@ -59,7 +59,7 @@ use biome_console::{markup, Markup, MarkupBuf};
 use biome_css_formatter::can_format_css_yet;
 use biome_diagnostics::CodeSuggestion;
 use biome_formatter::Printed;
-use biome_fs::BiomePath;
+use biome_fs::{BiomePath, FileSystem};
 use biome_js_syntax::{TextRange, TextSize};
 use biome_text_edit::TextEdit;
 use std::collections::HashMap;
@@ -69,7 +69,7 @@ use std::{borrow::Cow, panic::RefUnwindSafe, sync::Arc};
 use tracing::debug;
 
 pub use self::client::{TransportRequest, WorkspaceClient, WorkspaceTransport};
-use crate::configuration::PartialConfiguration;
+use crate::configuration::{JsonSchemaDefinition, PartialConfiguration, PluginDefinition};
 pub use crate::file_handlers::DocumentFileSource;
 use crate::settings::WorkspaceSettings;
 
@@ -117,10 +117,11 @@ impl FileFeaturesResult {
     }
 
     /// By default, all features are not supported by a file.
-    const WORKSPACE_FEATURES: [(FeatureName, SupportKind); 3] = [
+    const WORKSPACE_FEATURES: [(FeatureName, SupportKind); 4] = [
         (FeatureName::Lint, SupportKind::FileNotSupported),
         (FeatureName::Format, SupportKind::FileNotSupported),
         (FeatureName::OrganizeImports, SupportKind::FileNotSupported),
+        (FeatureName::Search, SupportKind::FileNotSupported),
     ];
 
     pub fn new() -> Self {
@@ -142,6 +143,10 @@ impl FileFeaturesResult {
             self.features_supported
                 .insert(FeatureName::OrganizeImports, SupportKind::Supported);
         }
+        if capabilities.search.search.is_some() {
+            self.features_supported
+                .insert(FeatureName::Search, SupportKind::Supported);
+        }
 
         self
     }
@@ -187,7 +192,10 @@ impl FileFeaturesResult {
                 self.features_supported
                     .insert(FeatureName::OrganizeImports, SupportKind::FeatureNotEnabled);
             }
-        } else if !settings.organize_imports().enabled {
+        } else if !settings.organize_imports().enabled
+            || (file_source.is_css_like() && !settings.css_sort_declarations_enabled())
+            || (file_source.is_json_like() && !settings.json_sort_keys_enabled(path))
+        {
             self.features_supported
                 .insert(FeatureName::OrganizeImports, SupportKind::FeatureNotEnabled);
         }
@@ -239,6 +247,10 @@ impl FileFeaturesResult {
         self.supports_for(&FeatureName::OrganizeImports)
     }
 
+    pub fn supports_search(&self) -> bool {
+        self.supports_for(&FeatureName::Search)
+    }
+
     /// Loops through all the features of the current file, and if a feature is [SupportKind::FileNotSupported],
     /// it gets changed to [SupportKind::Ignored]
     pub fn ignore_not_supported(&mut self) {
@@ -360,6 +372,7 @@ pub enum FeatureName {
     Format,
     Lint,
     OrganizeImports,
+    Search,
 }
 
 #[derive(Debug, Default)]
@@ -383,6 +396,11 @@ impl FeaturesBuilder {
         self
     }
 
+    pub fn with_search(mut self) -> Self {
+        self.0.push(FeatureName::Search);
+        self
+    }
+
     pub fn build(self) -> Vec<FeatureName> {
         self.0
     }
@@ -397,6 +415,22 @@ pub struct UpdateSettingsParams {
     // @ematipico TODO: have a better data structure for this
     pub gitignore_matches: Vec<String>,
     pub working_directory: Option<PathBuf>,
+    /// The plugins listed in the configuration, already read from the file system
+    #[serde(default)]
+    pub plugins: Vec<PluginDefinition>,
+    /// The JSON schemas listed in the configuration, already read from the file system
+    #[serde(default)]
+    pub json_schemas: Vec<JsonSchemaDefinition>,
+    /// The project folder these settings belong to. Settings without a project folder
+    /// apply to the files that aren't inside any registered project folder.
+    #[serde(default)]
+    pub project_folder: Option<PathBuf>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UnregisterProjectFolderParams {
+    pub path: PathBuf,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -483,6 +517,9 @@ pub struct PullDiagnosticsParams {
     pub path: BiomePath,
     pub categories: RuleCategories,
     pub max_diagnostics: u64,
+    /// The content of the file, when it isn't open in the workspace. The file
+    /// is analyzed without being stored, so it stays closed.
+    pub content: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -550,6 +587,19 @@ pub struct FixFileParams {
     pub path: BiomePath,
     pub fix_file_mode: FixFileMode,
     pub should_format: bool,
+    /// When set, only the fixes of this rule are applied
+    #[serde(default)]
+    pub only_rule: Option<RuleCode>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+/// The code of a rule, e.g. `suspicious/noDoubleEquals`
+pub struct RuleCode {
+    /// The group of the rule, e.g. `suspicious`
+    pub group: String,
+    /// The name of the rule, e.g. `noDoubleEquals`
+    pub rule: String,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -591,6 +641,155 @@ pub struct RenameResult {
     pub range: TextRange,
     /// List of text edit operations to apply on the source code
     pub indels: TextEdit,
+    /// The name the module exported the binding with, when the rename changed it.
+    /// The imports of the binding in other files must be renamed as well
+    #[serde(default)]
+    pub exported_name: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RenameImportsParams {
+    /// The file that imports the binding
+    pub path: BiomePath,
+    /// The module that exports the binding
+    pub module: BiomePath,
+    /// The name the module exported the binding with
+    pub exported_name: String,
+    pub new_name: String,
+    /// The content of the file, when it isn't open in the workspace. The file
+    /// is renamed without being stored, so it stays closed.
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RenameImportsResult {
+    /// List of text edit operations to apply on the source code
+    pub indels: TextEdit,
+    /// Whether the file re-exports the binding with the same name, in which case
+    /// the files that import it from this file must be renamed as well
+    pub re_exported: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SemanticTokensParams {
+    pub path: BiomePath,
+    /// When set, only the tokens that intersect this range are returned
+    pub range: Option<TextRange>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SemanticTokensResult {
+    /// The tokens of the file, sorted by their position
+    pub tokens: Vec<SemanticToken>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SemanticToken {
+    pub range: TextRange,
+    pub kind: SemanticTokenKind,
+    /// Whether the token declares the binding
+    pub is_declaration: bool,
+    /// Whether the binding can't be assigned, e.g. a `const` variable or an import
+    pub is_readonly: bool,
+}
+
+/// The classification of an identifier, computed from the semantic model
+#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SemanticTokenKind {
+    /// A parameter of a function
+    Parameter,
+    /// A variable declared in the file
+    Variable,
+    /// A binding imported from another module
+    Import,
+    /// A global of the configuration or of the runtimes Biome knows
+    Global,
+    /// A class declared in the file
+    Class,
+    /// A type parameter
+    TypeParameter,
+    /// A reference to a binding that isn't declared
+    Unresolved,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetFoldingRangesParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetFoldingRangesResult {
+    /// The ranges that can be folded, sorted by their start
+    pub ranges: Vec<FoldingRange>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FoldingRange {
+    /// The range to fold. For a [FoldingRangeKind::Region], the range ends
+    /// with the closing delimiter of the node, which editors usually keep visible
+    pub range: TextRange,
+    pub kind: FoldingRangeKind,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FoldingRangeKind {
+    /// A node delimited by brackets, or an element such as a JSX element
+    Region,
+    /// A multiline comment, or consecutive single line comments
+    Comment,
+    /// Consecutive import statements
+    Imports,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSelectionRangesParams {
+    pub path: BiomePath,
+    /// The positions where the selection starts
+    pub offsets: Vec<TextSize>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSelectionRangesResult {
+    /// For each offset of the params, the ranges that contain it, from the
+    /// innermost to the outermost
+    pub ranges: Vec<Vec<TextRange>>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetCompletionsParams {
+    pub path: BiomePath,
+    /// The position of the cursor
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetCompletionsResult {
+    pub items: Vec<CompletionItem>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CompletionItem {
+    /// The name of the item
+    pub label: String,
+    /// The text inserted at the position of the cursor
+    pub insert_text: String,
+    /// A description of the item, e.g. the `description` of a property in a JSON schema
+    pub documentation: Option<String>,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Default, Deserialize, Serialize)]
@@ -655,6 +854,51 @@ impl RageEntry {
     }
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ParsePatternParams {
+    /// The source code of the pattern, e.g. `console.log($msg)`
+    pub pattern: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ParsePatternResult {
+    pub pattern_id: PatternId,
+}
+
+/// Identifier of a pattern compiled by [Workspace::parse_pattern]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PatternId(u64);
+
+impl From<u64> for PatternId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SearchPatternParams {
+    pub path: BiomePath,
+    pub pattern: PatternId,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SearchResults {
+    pub file: BiomePath,
+    /// The ranges of the code that matched the pattern, trivia excluded
+    pub matches: Vec<TextRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DropPatternParams {
+    pub pattern: PatternId,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct IsPathIgnoredParams {
@@ -680,9 +924,15 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// If the file path matches, than `true` is returned and it should be considered ignored.
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError>;
 
-    /// Update the global settings for this workspace
+    /// Update the settings of a project folder, or the global settings for this workspace
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError>;
 
+    /// Remove a project folder and its settings from the workspace
+    fn unregister_project_folder(
+        &self,
+        params: UnregisterProjectFolderParams,
+    ) -> Result<(), WorkspaceError>;
+
     /// Add a new file to the workspace
     fn open_file(&self, params: OpenFileParams) -> Result<(), WorkspaceError>;
 
@@ -743,6 +993,36 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Return the content of the file after renaming a symbol
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
+    /// Return the edits that rename the imports of a binding exported by another module
+    fn rename_imports(
+        &self,
+        params: RenameImportsParams,
+    ) -> Result<RenameImportsResult, WorkspaceError>;
+
+    /// Returns the semantic classification of the identifiers of a file
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError>;
+
+    /// Returns the ranges of a file that can be folded
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError>;
+
+    /// Returns the ranges of the syntax nodes that contain some positions of a file
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError>;
+
+    /// Returns the items that can be inserted at a position of a file
+    fn get_completions(
+        &self,
+        params: GetCompletionsParams,
+    ) -> Result<GetCompletionsResult, WorkspaceError>;
+
     /// Returns debug information about this workspace.
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError>;
 
@@ -754,6 +1034,18 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         &self,
         params: OrganizeImportsParams,
     ) -> Result<OrganizeImportsResult, WorkspaceError>;
+
+    /// Compiles a structural pattern, so it can be searched in many files with [Workspace::search_pattern]
+    fn parse_pattern(
+        &self,
+        params: ParsePatternParams,
+    ) -> Result<ParsePatternResult, WorkspaceError>;
+
+    /// Searches the occurrences of a compiled pattern inside a file
+    fn search_pattern(&self, params: SearchPatternParams) -> Result<SearchResults, WorkspaceError>;
+
+    /// Releases a pattern compiled with [Workspace::parse_pattern]
+    fn drop_pattern(&self, params: DropPatternParams) -> Result<(), WorkspaceError>;
 }
 
 /// Convenience function for constructing a server instance of [Workspace]
@@ -761,11 +1053,23 @@ pub fn server() -> Box<dyn Workspace> {
     Box::new(server::WorkspaceServer::new())
 }
 
+/// Convenience function for constructing a server instance of [Workspace] that
+/// reads the files that aren't open from `fs`
+pub fn server_with_fs(fs: Box<dyn FileSystem>) -> Box<dyn Workspace> {
+    Box::new(server::WorkspaceServer::with_fs(fs))
+}
+
 /// Convenience function for constructing a server instance of [Workspace]
 pub fn server_sync() -> Arc<dyn Workspace> {
     Arc::new(server::WorkspaceServer::new())
 }
 
+/// Convenience function for constructing a server instance of [Workspace]
+/// that reads the files that aren't open from `fs`
+pub fn server_sync_with_fs(fs: Box<dyn FileSystem>) -> Arc<dyn Workspace> {
+    Arc::new(server::WorkspaceServer::with_fs(fs))
+}
+
 /// Convenience function for constructing a client instance of [Workspace]
 pub fn client<T>(transport: T) -> Result<Box<dyn Workspace>, WorkspaceError>
 where
@@ -826,6 +1130,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             path: self.path.clone(),
             categories,
             max_diagnostics: max_diagnostics.into(),
+            content: None,
         })
     }
 
@@ -865,6 +1170,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             path: self.path.clone(),
             fix_file_mode,
             should_format,
+            only_rule: None,
         })
     }
 
@@ -873,6 +1179,13 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             path: self.path.clone(),
         })
     }
+
+    pub fn search_pattern(&self, pattern: &PatternId) -> Result<SearchResults, WorkspaceError> {
+        self.workspace.search_pattern(SearchPatternParams {
+            path: self.path.clone(),
+            pattern: *pattern,
+        })
+    }
 }
 
 impl<'app, W: Workspace + ?Sized> Drop for FileGuard<'app, W> {
@@ -1,7 +1,8 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OpenProjectParams,
-    OrganizeImportsParams, OrganizeImportsResult, RageParams, RageResult, ServerInfo,
-    UpdateProjectParams,
+    DropPatternParams, FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams,
+    OpenProjectParams, OrganizeImportsParams, OrganizeImportsResult, ParsePatternParams,
+    ParsePatternResult, RageParams, RageResult, SearchPatternParams, SearchResults, ServerInfo,
+    UnregisterProjectFolderParams, UpdateProjectParams,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -14,10 +15,13 @@ use std::{
 
 use super::{
     ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult,
-    SupportsFeatureParams, UpdateSettingsParams,
+    FormatOnTypeParams, FormatRangeParams, GetCompletionsParams, GetCompletionsResult,
+    GetControlFlowGraphParams, GetFoldingRangesParams, GetFoldingRangesResult,
+    GetFormatterIRParams, GetSelectionRangesParams, GetSelectionRangesResult, GetSyntaxTreeParams,
+    GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
+    PullDiagnosticsParams, PullDiagnosticsResult, RenameImportsParams, RenameImportsResult,
+    RenameParams, RenameResult, SemanticTokensParams, SemanticTokensResult, SupportsFeatureParams,
+    UpdateSettingsParams,
 };
 
 pub struct WorkspaceClient<T> {
@@ -115,6 +119,13 @@ where
         self.request("biome/update_settings", params)
     }
 
+    fn unregister_project_folder(
+        &self,
+        params: UnregisterProjectFolderParams,
+    ) -> Result<(), WorkspaceError> {
+        self.request("biome/unregister_project_folder", params)
+    }
+
     fn open_file(&self, params: OpenFileParams) -> Result<(), WorkspaceError> {
         self.request("biome/open_file", params)
     }
@@ -188,6 +199,41 @@ where
         self.request("biome/rename", params)
     }
 
+    fn rename_imports(
+        &self,
+        params: RenameImportsParams,
+    ) -> Result<RenameImportsResult, WorkspaceError> {
+        self.request("biome/rename_imports", params)
+    }
+
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError> {
+        self.request("biome/semantic_tokens", params)
+    }
+
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError> {
+        self.request("biome/get_folding_ranges", params)
+    }
+
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError> {
+        self.request("biome/get_selection_ranges", params)
+    }
+
+    fn get_completions(
+        &self,
+        params: GetCompletionsParams,
+    ) -> Result<GetCompletionsResult, WorkspaceError> {
+        self.request("biome/get_completions", params)
+    }
+
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError> {
         self.request("biome/rage", params)
     }
@@ -202,4 +248,19 @@ where
     ) -> Result<OrganizeImportsResult, WorkspaceError> {
         self.request("biome/organize_imports", params)
     }
+
+    fn parse_pattern(
+        &self,
+        params: ParsePatternParams,
+    ) -> Result<ParsePatternResult, WorkspaceError> {
+        self.request("biome/parse_pattern", params)
+    }
+
+    fn search_pattern(&self, params: SearchPatternParams) -> Result<SearchResults, WorkspaceError> {
+        self.request("biome/search_pattern", params)
+    }
+
+    fn drop_pattern(&self, params: DropPatternParams) -> Result<(), WorkspaceError> {
+        self.request("biome/drop_pattern", params)
+    }
 }
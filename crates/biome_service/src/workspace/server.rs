@@ -1,46 +1,58 @@
 use super::{
     ChangeFileParams, CloseFileParams, FeatureName, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, OpenProjectParams, PullActionsParams,
-    PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult, RenameResult,
-    SupportsFeatureParams, UpdateProjectParams, UpdateSettingsParams,
+    FormatOnTypeParams, FormatRangeParams, GetCompletionsParams, GetCompletionsResult,
+    GetControlFlowGraphParams, GetFoldingRangesParams, GetFoldingRangesResult,
+    GetFormatterIRParams, GetSelectionRangesParams, GetSelectionRangesResult, GetSyntaxTreeParams,
+    GetSyntaxTreeResult, OpenFileParams, OpenProjectParams, PullActionsParams, PullActionsResult,
+    PullDiagnosticsParams, PullDiagnosticsResult, RenameImportsParams, RenameImportsResult,
+    RenameResult, SemanticTokensParams, SemanticTokensResult, SupportsFeatureParams,
+    UnregisterProjectFolderParams, UpdateProjectParams, UpdateSettingsParams,
 };
+use crate::configuration::plugins::to_analyzer_plugins;
 use crate::file_handlers::{
-    Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams, LintParams, ParseResult,
+    imported_css_modules, project_imports, Capabilities, CodeActionsParams, DocumentFileSource,
+    FixAllParams, LintParams, ParseResult,
 };
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    DropPatternParams, FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams,
+    OrganizeImportsParams, OrganizeImportsResult, ParsePatternParams, ParsePatternResult,
+    PatternId, RageEntry, RageParams, RageResult, RuleCode, SearchPatternParams, SearchResults,
+    ServerInfo,
 };
 use crate::{
     file_handlers::Features,
-    settings::{SettingsHandle, WorkspaceSettings},
+    settings::{to_json_schema_settings, ProjectsSettings, SettingsHandle},
     Workspace, WorkspaceError,
 };
-use biome_analyze::AnalysisFilter;
+use biome_analyze::{AnalysisFilter, RuleCategories, RuleFilter};
+use biome_deserialize::Merge;
 use biome_diagnostics::{
     serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
 };
 use biome_formatter::Printed;
-use biome_fs::{BiomePath, ConfigName};
+use biome_fs::{BiomePath, ConfigName, FileSystem, OpenOptions, OsFileSystem};
+use biome_js_analyze::ImportedCssModules;
+use biome_js_syntax::JsLanguage;
 use biome_json_parser::{parse_json_with_cache, JsonParserOptions};
 use biome_json_syntax::JsonFileSource;
 use biome_parser::AnyParse;
-use biome_project::NodeJsProject;
+use biome_pattern::{Pattern, PatternError};
+use biome_project::{NodeJsProject, ProjectImports};
 use biome_rowan::NodeCache;
 use dashmap::{mapref::entry::Entry, DashMap};
 use indexmap::IndexSet;
 use std::borrow::Borrow;
 use std::ffi::OsStr;
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::{debug, info, info_span};
 
 pub(super) struct WorkspaceServer {
     /// features available throughout the application
     features: Features,
-    /// global settings object for this workspace
-    settings: RwLock<WorkspaceSettings>,
+    /// settings of the project folders of this workspace
+    settings: RwLock<ProjectsSettings>,
     /// Stores the document (text content + version number) associated with a URL
     documents: DashMap<BiomePath, Document>,
     /// Stores the result of the parser (syntax tree + diagnostics) for a given URL
@@ -51,8 +63,18 @@ pub(super) struct WorkspaceServer {
     manifests: DashMap<BiomePath, NodeJsProject>,
     /// The current focused project
     current_project_path: RwLock<Option<BiomePath>>,
+    /// The paths of the manifests of all the projects set by [Workspace::update_current_project]
+    project_paths: RwLock<Vec<BiomePath>>,
     /// Stores the document sources used across the workspace
     file_sources: RwLock<IndexSet<DocumentFileSource>>,
+    /// Stores the patterns compiled by [Workspace::parse_pattern]
+    patterns: DashMap<PatternId, Pattern<JsLanguage>>,
+    /// The identifier of the next compiled pattern
+    next_pattern_id: AtomicU64,
+    /// Stores the packages imported by the source files of each package, by the path of its manifest
+    project_imports: DashMap<BiomePath, ProjectImports>,
+    /// File system used to read the files that aren't open in the workspace
+    fs: Box<dyn FileSystem>,
 }
 
 /// The `Workspace` object is long-lived, so we want it to be able to cross
@@ -80,6 +102,11 @@ impl WorkspaceServer {
     /// [Default] to disallow instances of [Workspace] from being created
     /// outside a [crate::App]
     pub(crate) fn new() -> Self {
+        Self::with_fs(Box::<OsFileSystem>::default())
+    }
+
+    /// Create a new [Workspace] that reads the files that aren't open from `fs`
+    pub(crate) fn with_fs(fs: Box<dyn FileSystem>) -> Self {
         Self {
             features: Features::new(),
             settings: RwLock::default(),
@@ -88,12 +115,18 @@ impl WorkspaceServer {
             file_features: DashMap::default(),
             manifests: DashMap::default(),
             current_project_path: RwLock::default(),
+            project_paths: RwLock::default(),
             file_sources: RwLock::default(),
+            patterns: DashMap::default(),
+            next_pattern_id: AtomicU64::default(),
+            project_imports: DashMap::default(),
+            fs,
         }
     }
 
-    fn settings(&self) -> SettingsHandle {
-        SettingsHandle::new(&self.settings)
+    /// Returns the settings that apply to the file at `path`
+    fn settings(&self, path: &Path) -> SettingsHandle {
+        SettingsHandle::new(&self.settings, path)
     }
 
     /// Get the supported capabilities for a given file path
@@ -134,14 +167,25 @@ impl WorkspaceServer {
         }
     }
 
-    /// Returns the current project. The information of this project depend on path set by [WorkspaceServer::update_current_project]
+    /// Returns the project of the file at `file_path`: the innermost project whose manifest
+    /// is in an ancestor directory of the file. When no project contains the file, the current
+    /// project set by [WorkspaceServer::update_current_project] is returned.
     ///
     /// ## Errors
     ///
     /// - If no document is found in the workspace. Usually, you'll have to call [WorkspaceServer::open_project] to store said document.
-    fn get_current_project(&self) -> Result<Option<NodeJsProject>, WorkspaceError> {
-        let path = self.current_project_path.read().unwrap();
-        if let Some(path) = path.as_ref() {
+    fn get_project(&self, file_path: &Path) -> Result<Option<NodeJsProject>, WorkspaceError> {
+        let project_paths = self.project_paths.read().unwrap();
+        let current_project_path = self.current_project_path.read().unwrap();
+        let path = project_paths
+            .iter()
+            .filter(|path| {
+                path.parent()
+                    .is_some_and(|directory| file_path.starts_with(directory))
+            })
+            .max_by_key(|path| path.components().count())
+            .or(current_project_path.as_ref());
+        if let Some(path) = path {
             match self.manifests.entry(path.clone()) {
                 Entry::Occupied(entry) => Ok(Some(entry.get().clone())),
                 Entry::Vacant(entry) => {
@@ -168,6 +212,81 @@ impl WorkspaceServer {
         }
     }
 
+    /// Returns the CSS modules imported by the JavaScript file at `path`. The content
+    /// of a module is read from the workspace if the module is open, and from the
+    /// file system otherwise.
+    fn get_css_modules(&self, path: &BiomePath, parse: &AnyParse) -> ImportedCssModules {
+        if !matches!(self.get_file_source(path), DocumentFileSource::Js(_)) {
+            return ImportedCssModules::default();
+        }
+
+        imported_css_modules(&parse.tree(), path, |module_path| {
+            self.read_file(module_path)
+        })
+    }
+
+    /// Returns the content of the file at `path`. It's read from the workspace
+    /// if the file is open, and from the file system otherwise.
+    fn read_file(&self, path: &Path) -> Option<String> {
+        if let Some(document) = self.documents.get(&BiomePath::new(path)) {
+            return Some(document.content.clone());
+        }
+
+        let mut content = String::new();
+        self.fs
+            .open_with_options(path, OpenOptions::default().read(true))
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .ok()?;
+        Some(content)
+    }
+
+    /// Returns the packages imported by the JavaScript files of the package whose
+    /// manifest is at `path`. The imports are only collected when the rule
+    /// `noUnusedDependencies` is enabled for a `package.json` file.
+    ///
+    /// The files ignored by `files.ignore`, `files.include` or the VCS are skipped.
+    /// The imports are cached until a file of the package or the settings change.
+    fn get_project_imports(&self, path: &BiomePath, categories: RuleCategories) -> ProjectImports {
+        let is_package_json = path.file_name().is_some_and(|name| name == "package.json");
+        if !is_package_json || !categories.contains(RuleCategories::LINT) {
+            return ProjectImports::default();
+        }
+        let is_enabled = self
+            .settings(path)
+            .as_ref()
+            .as_rules(path.as_path())
+            .is_some_and(|rules| {
+                rules
+                    .as_enabled_rules()
+                    .contains(&RuleFilter::Rule("nursery", "noUnusedDependencies"))
+            });
+        if !is_enabled {
+            return ProjectImports::default();
+        }
+
+        if let Some(imports) = self.project_imports.get(path) {
+            return imports.clone();
+        }
+
+        let imports = project_imports(
+            self.fs.as_ref(),
+            path.as_path(),
+            |path| self.is_ignored_by_top_level_config(path),
+            |module_path| self.read_file(module_path),
+        );
+        self.project_imports.insert(path.clone(), imports.clone());
+        imports
+    }
+
+    /// Drops the cached imports of the packages that contain the file at `path`
+    fn invalidate_project_imports(&self, path: &Path) {
+        self.project_imports.retain(|manifest_path, _| {
+            manifest_path
+                .parent()
+                .map_or(true, |directory| !path.starts_with(directory))
+        });
+    }
+
     /// Get the parser result for a given file
     ///
     /// Returns and error if no file exists in the workspace with this path or
@@ -190,7 +309,7 @@ impl WorkspaceServer {
                     .ok_or_else(self.build_capability_error(biome_path))?;
 
                 let size_limit = {
-                    let settings = self.settings();
+                    let settings = self.settings(biome_path);
                     let settings = settings.as_ref();
                     let limit = settings.files.max_size.get();
                     usize::try_from(limit).unwrap_or(usize::MAX)
@@ -206,7 +325,7 @@ impl WorkspaceServer {
                     ));
                 }
 
-                let settings = self.settings();
+                let settings = self.settings(biome_path);
                 let mut file_sources = self.file_sources.write().unwrap();
                 let Some(file_source) = file_sources.get_index(document.file_source_index) else {
                     return Err(WorkspaceError::not_found());
@@ -231,6 +350,41 @@ impl WorkspaceServer {
         }
     }
 
+    /// Parses the `content` of a file that isn't open in the workspace. Unlike
+    /// [WorkspaceServer::get_parse], neither the document nor its syntax tree are stored.
+    fn parse_closed_file(
+        &self,
+        biome_path: &BiomePath,
+        content: &str,
+    ) -> Result<AnyParse, WorkspaceError> {
+        let parse = self
+            .get_file_capabilities(biome_path)
+            .parser
+            .parse
+            .ok_or_else(self.build_capability_error(biome_path))?;
+
+        let settings = self.settings(biome_path);
+        let size_limit =
+            usize::try_from(settings.as_ref().files.max_size.get()).unwrap_or(usize::MAX);
+        let size = content.as_bytes().len();
+        if size >= size_limit {
+            return Err(WorkspaceError::file_too_large(
+                biome_path.to_path_buf().display().to_string(),
+                size,
+                size_limit,
+            ));
+        }
+
+        let ParseResult { any_parse, .. } = parse(
+            biome_path,
+            DocumentFileSource::from_path(biome_path),
+            content,
+            settings,
+            &mut NodeCache::default(),
+        );
+        Ok(any_parse)
+    }
+
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
     /// or in the feature `ignore`/`include`
     fn is_ignored(&self, path: &Path, feature: FeatureName) -> bool {
@@ -245,7 +399,7 @@ impl WorkspaceServer {
 
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
     fn is_ignored_by_top_level_config(&self, path: &Path) -> bool {
-        let settings = self.settings();
+        let settings = self.settings(path);
         let is_included = settings.as_ref().files.included_files.is_empty()
             || settings.as_ref().files.included_files.matches_path(path);
         !is_included
@@ -273,8 +427,10 @@ impl WorkspaceServer {
 
     /// Check whether a file is ignored in the feature `ignore`/`include`
     fn is_ignored_by_feature_config(&self, path: &Path, feature: FeatureName) -> bool {
-        let settings = self.settings();
+        let settings = self.settings(path);
         let (feature_included_files, feature_ignored_files) = match feature {
+            // Search doesn't have its own `include`/`ignore`
+            FeatureName::Search => return false,
             FeatureName::Format => {
                 let formatter = &settings.as_ref().formatter;
                 (&formatter.included_files, &formatter.ignored_files)
@@ -312,12 +468,13 @@ impl Workspace for WorkspaceServer {
                 let capabilities = self.get_file_capabilities(&params.path);
                 let language = DocumentFileSource::from_path_and_known_filename(&params.path);
                 let path = params.path.as_path();
-                let settings = self.settings.read().unwrap();
+                let settings = self.settings(path);
+                let settings = settings.as_ref();
                 let mut file_features = FileFeaturesResult::new();
                 let file_name = path.file_name().and_then(|s| s.to_str());
                 file_features = file_features
                     .with_capabilities(&capabilities)
-                    .with_settings_and_language(&settings, &language, path);
+                    .with_settings_and_language(settings, &language, path);
 
                 if settings.files.ignore_unknown
                     && language == DocumentFileSource::Unknown
@@ -353,24 +510,78 @@ impl Workspace for WorkspaceServer {
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError> {
         Ok(self.is_ignored(params.biome_path.as_path(), params.feature))
     }
-    /// Update the global settings for this workspace
+    /// Update the settings of a project folder, or the global settings for this workspace
     ///
     /// ## Panics
     /// This function may panic if the internal settings mutex has been poisoned
     /// by another thread having previously panicked while holding the lock
     #[tracing::instrument(level = "trace", skip(self))]
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
-        let mut settings = self.settings.write().unwrap();
+        let mut projects = self.settings.write().unwrap();
+        let mut configuration = params.configuration;
+        let mut plugins = to_analyzer_plugins(params.plugins)?;
+        let mut json_schemas =
+            to_json_schema_settings(params.working_directory.clone(), params.json_schemas)?;
+        let mut inherited_git_ignore = None;
+
+        // A nested configuration is merged over the configuration of the enclosing project,
+        // and inherits its plugins, JSON schemas and VCS settings
+        if let Some(folder) = params.project_folder.as_deref() {
+            if configuration.is_nested() {
+                let parent = projects.parent_project(folder);
+                let mut parent_configuration = parent.configuration.clone();
+                parent_configuration.merge_with(configuration);
+                configuration = parent_configuration;
+
+                let mut parent_plugins = parent.settings.plugins.clone();
+                parent_plugins.extend(plugins);
+                plugins = parent_plugins;
+
+                let mut parent_json_schemas = parent.settings.json_schemas.clone();
+                parent_json_schemas.extend(json_schemas);
+                json_schemas = parent_json_schemas;
+                inherited_git_ignore = parent.settings.files.git_ignore.clone();
+            }
+        }
 
-        settings.merge_with_configuration(
-            params.configuration,
+        let project = projects.project_mut(params.project_folder);
+        project.settings.merge_with_configuration(
+            configuration.clone(),
             params.working_directory,
             params.vcs_base_path,
             params.gitignore_matches.as_slice(),
         )?;
+        if project.settings.files.git_ignore.is_none() {
+            project.settings.files.git_ignore = inherited_git_ignore;
+        }
+        project.settings.plugins = plugins;
+        project.settings.json_schemas = json_schemas;
+        project.configuration = configuration;
 
         // settings changed, hence everything that is computed from the settings needs to be purged
         self.file_features.clear();
+        self.project_imports.clear();
+        Ok(())
+    }
+
+    /// Remove a project folder and its settings from the workspace
+    ///
+    /// ## Panics
+    /// This function may panic if the internal settings mutex has been poisoned
+    /// by another thread having previously panicked while holding the lock
+    fn unregister_project_folder(
+        &self,
+        params: UnregisterProjectFolderParams,
+    ) -> Result<(), WorkspaceError> {
+        let removed = self
+            .settings
+            .write()
+            .unwrap()
+            .remove_folder(params.path.as_path());
+        if removed {
+            self.file_features.clear();
+            self.project_imports.clear();
+        }
         Ok(())
     }
 
@@ -383,6 +594,7 @@ impl Workspace for WorkspaceServer {
                 .unwrap_or(DocumentFileSource::from_path(&params.path)),
         );
         self.syntax.remove(&params.path);
+        self.invalidate_project_imports(&params.path);
         self.documents.insert(
             params.path,
             Document {
@@ -412,6 +624,10 @@ impl Workspace for WorkspaceServer {
     }
 
     fn update_current_project(&self, params: UpdateProjectParams) -> Result<(), WorkspaceError> {
+        let mut project_paths = self.project_paths.write().unwrap();
+        if !project_paths.contains(&params.path) {
+            project_paths.push(params.path.clone());
+        }
         let mut current_project_path = self.current_project_path.write().unwrap();
         let _ = current_project_path.insert(params.path);
         Ok(())
@@ -456,7 +672,7 @@ impl Workspace for WorkspaceServer {
             .debug
             .debug_formatter_ir
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone())?;
 
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
@@ -487,6 +703,7 @@ impl Workspace for WorkspaceServer {
         document.content = params.content;
 
         self.syntax.remove(&params.path);
+        self.invalidate_project_imports(&params.path);
         Ok(())
     }
 
@@ -497,6 +714,7 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(WorkspaceError::not_found)?;
 
         self.syntax.remove(&params.path);
+        self.invalidate_project_imports(&params.path);
         Ok(())
     }
 
@@ -506,19 +724,28 @@ impl Workspace for WorkspaceServer {
         &self,
         params: PullDiagnosticsParams,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
-        let parse = self.get_parse(params.path.clone())?;
-        let manifest = self.get_current_project()?.map(|pr| pr.manifest);
+        let parse = match params.content.as_deref() {
+            Some(content) if !self.documents.contains_key(&params.path) => {
+                self.parse_closed_file(&params.path, content)?
+            }
+            _ => self.get_parse(params.path.clone())?,
+        };
+        let manifest = self.get_project(&params.path)?.map(|pr| pr.manifest);
+        let css_modules = self.get_css_modules(&params.path, &parse);
+        let project_imports = self.get_project_imports(&params.path, params.categories);
         let (diagnostics, errors, skipped_diagnostics) =
             if let Some(lint) = self.get_file_capabilities(&params.path).analyzer.lint {
                 info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
                     let results = lint(LintParams {
                         parse,
-                        settings: self.settings(),
+                        settings: self.settings(&params.path),
                         max_diagnostics: params.max_diagnostics as u32,
                         path: &params.path,
                         language: self.get_file_source(&params.path),
                         categories: params.categories,
                         manifest,
+                        css_modules,
+                        project_imports,
                     });
 
                     (
@@ -562,17 +789,19 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(params.path.clone())?;
-        let settings = self.settings.read().unwrap();
-        let rules = settings.linter().rules.as_ref();
-        let manifest = self.get_current_project()?.map(|pr| pr.manifest);
+        let settings = self.settings(&params.path);
+        let rules = settings.as_ref().linter().rules.as_ref();
+        let manifest = self.get_project(&params.path)?.map(|pr| pr.manifest);
+        let css_modules = self.get_css_modules(&params.path, &parse);
         let language = self.get_file_source(&params.path);
         Ok(code_actions(CodeActionsParams {
             parse,
             range: params.range,
             rules,
-            settings: self.settings(),
+            settings: self.settings(&params.path),
             path: &params.path,
             manifest,
+            css_modules,
             language,
         }))
     }
@@ -585,7 +814,7 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone())?;
 
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
@@ -601,7 +830,7 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format_range
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone())?;
 
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
@@ -624,7 +853,7 @@ impl Workspace for WorkspaceServer {
             .format_on_type
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone())?;
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
@@ -647,28 +876,33 @@ impl Workspace for WorkspaceServer {
             .analyzer
             .fix_all
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone())?;
         // Compute final rules (taking `overrides` into account)
-        let rules = settings.as_rules(params.path.as_path());
-        let rule_filter_list = rules
-            .as_ref()
-            .map(|rules| rules.as_enabled_rules())
-            .unwrap_or_default()
-            .into_iter()
-            .collect::<Vec<_>>();
+        let rules = settings.as_ref().as_rules(params.path.as_path());
+        let rule_filter_list = match &params.only_rule {
+            Some(RuleCode { group, rule }) => vec![RuleFilter::Rule(group, rule)],
+            None => rules
+                .as_ref()
+                .map(|rules| rules.as_enabled_rules())
+                .unwrap_or_default()
+                .into_iter()
+                .collect::<Vec<_>>(),
+        };
         let filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
-        let manifest = self.get_current_project()?.map(|pr| pr.manifest);
+        let manifest = self.get_project(&params.path)?.map(|pr| pr.manifest);
+        let css_modules = self.get_css_modules(&params.path, &parse);
         let language = self.get_file_source(&params.path);
         fix_all(FixAllParams {
             parse,
             rules: rules.as_ref().map(|x| x.borrow()),
             fix_file_mode: params.fix_file_mode,
             filter,
-            settings: self.settings(),
+            settings: self.settings(&params.path),
             should_format: params.should_format,
             biome_path: &params.path,
             manifest,
+            css_modules,
             document_file_source: language,
         })
     }
@@ -686,6 +920,104 @@ impl Workspace for WorkspaceServer {
         Ok(result)
     }
 
+    fn rename_imports(
+        &self,
+        params: RenameImportsParams,
+    ) -> Result<RenameImportsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let rename_imports = capabilities
+            .analyzer
+            .rename_imports
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = match params.content.as_deref() {
+            Some(content) if !self.documents.contains_key(&params.path) => {
+                self.parse_closed_file(&params.path, content)?
+            }
+            _ => self.get_parse(params.path.clone())?,
+        };
+        let result = rename_imports(
+            &params.path,
+            parse,
+            &params.module,
+            &params.exported_name,
+            &params.new_name,
+        )?;
+
+        Ok(result)
+    }
+
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let semantic_tokens = capabilities
+            .analyzer
+            .semantic_tokens
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let settings = self.settings(&params.path);
+        let document_file_source = self.get_file_source(&params.path);
+        Ok(semantic_tokens(
+            &params.path,
+            &document_file_source,
+            parse,
+            settings,
+            params.range,
+        ))
+    }
+
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let folding_ranges = capabilities
+            .parser
+            .folding_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetFoldingRangesResult {
+            ranges: folding_ranges(parse),
+        })
+    }
+
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let selection_ranges = capabilities
+            .parser
+            .selection_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetSelectionRangesResult {
+            ranges: selection_ranges(parse, &params.offsets),
+        })
+    }
+
+    fn get_completions(
+        &self,
+        params: GetCompletionsParams,
+    ) -> Result<GetCompletionsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let completions = capabilities
+            .analyzer
+            .completions
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let settings = self.settings(&params.path);
+        Ok(GetCompletionsResult {
+            items: completions(&params.path, parse, settings, params.offset),
+        })
+    }
+
     fn rage(&self, _: RageParams) -> Result<RageResult, WorkspaceError> {
         let entries = vec![
             RageEntry::section("Workspace"),
@@ -709,9 +1041,48 @@ impl Workspace for WorkspaceServer {
             .organize_imports
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let parse = self.get_parse(params.path)?;
-        let result = organize_imports(parse)?;
+        let settings = self.settings(&params.path);
+        let parse = self.get_parse(params.path.clone())?;
+        let result = organize_imports(&params.path, parse, settings)?;
 
         Ok(result)
     }
+
+    fn parse_pattern(
+        &self,
+        params: ParsePatternParams,
+    ) -> Result<ParsePatternResult, WorkspaceError> {
+        let pattern = Pattern::parse(&params.pattern)?;
+        let pattern_id = PatternId::from(self.next_pattern_id.fetch_add(1, Ordering::Relaxed));
+        self.patterns.insert(pattern_id, pattern);
+
+        Ok(ParsePatternResult { pattern_id })
+    }
+
+    fn search_pattern(&self, params: SearchPatternParams) -> Result<SearchResults, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let search = capabilities
+            .search
+            .search
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let pattern = self
+            .patterns
+            .get(&params.pattern)
+            .map(|pattern| pattern.clone())
+            .ok_or_else(|| PatternError::new("The pattern doesn't exist, or it was dropped."))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let matches = search(parse, &pattern);
+
+        Ok(SearchResults {
+            file: params.path,
+            matches,
+        })
+    }
+
+    fn drop_pattern(&self, params: DropPatternParams) -> Result<(), WorkspaceError> {
+        self.patterns.remove(&params.pattern);
+        Ok(())
+    }
 }
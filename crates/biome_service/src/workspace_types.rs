@@ -453,10 +453,11 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 19] {
+pub fn methods() -> [WorkspaceMethod; 28] {
     [
         WorkspaceMethod::of::<SupportsFeatureParams, SupportsFeatureResult>("file_features"),
         workspace_method!(update_settings),
+        workspace_method!(unregister_project_folder),
         workspace_method!(update_current_project),
         workspace_method!(open_project),
         workspace_method!(open_file),
@@ -474,5 +475,13 @@ pub fn methods() -> [WorkspaceMethod; 19] {
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
         workspace_method!(rename),
+        workspace_method!(rename_imports),
+        workspace_method!(semantic_tokens),
+        workspace_method!(get_folding_ranges),
+        workspace_method!(get_selection_ranges),
+        workspace_method!(get_completions),
+        workspace_method!(parse_pattern),
+        workspace_method!(search_pattern),
+        workspace_method!(drop_pattern),
     ]
 }
@@ -1,7 +1,15 @@
-use biome_fs::BiomePath;
+use biome_analyze::RuleCategories;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_fs::{BiomePath, MemoryFileSystem};
 use biome_js_syntax::{JsFileSource, TextSize};
+use biome_json_parser::JsonParserOptions;
+use biome_json_syntax::JsonFileSource;
+use biome_service::configuration::PartialConfiguration;
 use biome_service::file_handlers::DocumentFileSource;
-use biome_service::workspace::{server, FileGuard, OpenFileParams};
+use biome_service::workspace::{
+    server, server_with_fs, FileGuard, OpenFileParams, PullDiagnosticsParams, RenameImportsParams,
+    RenameParams, UpdateSettingsParams,
+};
 
 #[test]
 fn debug_control_flow() {
@@ -28,3 +36,180 @@ fn debug_control_flow() {
 
     assert_eq!(cfg, GRAPH);
 }
+
+#[test]
+fn rename_exported_binding() {
+    const SOURCE: &str = "export function foo() {}\nfunction bar() {}\nexport { bar as baz };";
+
+    let workspace = server();
+
+    let _file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: BiomePath::new("module.js"),
+            content: SOURCE.into(),
+            version: 0,
+            document_file_source: Some(DocumentFileSource::from(JsFileSource::default())),
+        },
+    )
+    .unwrap();
+
+    let result = workspace
+        .rename(RenameParams {
+            path: BiomePath::new("module.js"),
+            symbol_at: TextSize::from(16),
+            new_name: "renamed".into(),
+        })
+        .unwrap();
+    assert_eq!(result.exported_name.as_deref(), Some("foo"));
+
+    // The module exports `bar` as `baz`, the name of the export doesn't change
+    let result = workspace
+        .rename(RenameParams {
+            path: BiomePath::new("module.js"),
+            symbol_at: TextSize::from(35),
+            new_name: "renamed".into(),
+        })
+        .unwrap();
+    assert_eq!(result.exported_name, None);
+}
+
+#[test]
+fn rename_imports_of_module() {
+    const SOURCE: &str = r#"import { foo } from "./module";
+import { foo as local } from "../src/module.js";
+import { foo as other } from "./other";
+export { foo } from "./module";
+foo();
+"#;
+    const EXPECTED: &str = r#"import { renamed } from "./module";
+import { renamed as local } from "../src/module.js";
+import { foo as other } from "./other";
+export { renamed } from "./module";
+renamed();
+"#;
+
+    let workspace = server();
+
+    let _file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: BiomePath::new("src/file.js"),
+            content: SOURCE.into(),
+            version: 0,
+            document_file_source: Some(DocumentFileSource::from(JsFileSource::default())),
+        },
+    )
+    .unwrap();
+
+    let result = workspace
+        .rename_imports(RenameImportsParams {
+            path: BiomePath::new("src/file.js"),
+            module: BiomePath::new("src/module.ts"),
+            exported_name: "foo".into(),
+            new_name: "renamed".into(),
+            content: None,
+        })
+        .unwrap();
+
+    assert_eq!(result.indels.new_string(SOURCE), EXPECTED);
+    assert!(result.re_exported);
+}
+
+#[test]
+fn rename_imports_of_closed_barrel() {
+    const SOURCE: &str = "export * from \"./module\";\nexport * as namespace from \"./other\";\n";
+
+    let workspace = server();
+
+    let result = workspace
+        .rename_imports(RenameImportsParams {
+            path: BiomePath::new("src/index.js"),
+            module: BiomePath::new("src/module.js"),
+            exported_name: "foo".into(),
+            new_name: "renamed".into(),
+            content: Some(SOURCE.into()),
+        })
+        .unwrap();
+
+    // The barrel doesn't mention the binding, but the files that import it from the barrel must be renamed
+    assert!(result.indels.is_empty());
+    assert!(result.re_exported);
+}
+
+#[test]
+fn unused_dependencies_skip_ignored_files() {
+    const CONFIGURATION: &str = r#"{
+        "files": { "ignore": ["**/ignored.js"] },
+        "linter": { "rules": { "nursery": { "noUnusedDependencies": "error" } } }
+    }"#;
+    const MANIFEST: &str = r#"{
+        "dependencies": { "used": "1.0.0", "ignored-only": "1.0.0", "nested-only": "1.0.0", "later": "1.0.0" }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("/project/src/index.js".into(), "import \"used\";");
+    fs.insert("/project/ignored.js".into(), "import \"ignored-only\";");
+    fs.insert("/project/nested/package.json".into(), "{}");
+    fs.insert("/project/nested/index.js".into(), "import \"nested-only\";");
+    fs.insert("/project/src/later.js".into(), "");
+    let workspace = server_with_fs(Box::new(fs));
+
+    let configuration = deserialize_from_json_str::<PartialConfiguration>(
+        CONFIGURATION,
+        JsonParserOptions::default(),
+        "",
+    )
+    .into_deserialized()
+    .unwrap();
+    workspace
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            vcs_base_path: None,
+            gitignore_matches: vec![],
+            working_directory: None,
+            plugins: vec![],
+            json_schemas: vec![],
+            project_folder: None,
+        })
+        .unwrap();
+
+    let _manifest = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: BiomePath::new("/project/package.json"),
+            content: MANIFEST.into(),
+            version: 0,
+            document_file_source: Some(DocumentFileSource::from(JsonFileSource::json())),
+        },
+    )
+    .unwrap();
+    let unused_dependencies = || {
+        workspace
+            .pull_diagnostics(PullDiagnosticsParams {
+                path: BiomePath::new("/project/package.json"),
+                categories: RuleCategories::LINT,
+                max_diagnostics: 100,
+                content: None,
+            })
+            .unwrap()
+            .diagnostics
+            .len()
+    };
+
+    // `ignored-only`, `nested-only` and `later` aren't imported by the files of the package
+    assert_eq!(unused_dependencies(), 3);
+
+    // Opening a file of the package drops the cached imports, its imports are read from the workspace
+    let _file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: BiomePath::new("/project/src/later.js"),
+            content: "import \"later\";".into(),
+            version: 0,
+            document_file_source: Some(DocumentFileSource::from(JsFileSource::default())),
+        },
+    )
+    .unwrap();
+    assert_eq!(unused_dependencies(), 2);
+}
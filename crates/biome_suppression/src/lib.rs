@@ -1,9 +1,9 @@
-use biome_diagnostics::{Category, Diagnostic};
+use biome_diagnostics::{category, Category, Diagnostic};
 use biome_rowan::{TextRange, TextSize};
 
 /// Single instance of a suppression comment, with the following syntax:
 ///
-/// `// rome-ignore { <category> { (<value>) }? }+: <reason>`
+/// `// biome-ignore { <category> { (<value>) }? }+: <reason>`
 ///
 /// The category broadly describes what feature is being suppressed (formatting,
 /// linting, ...) with the value being and optional, category-specific name of
@@ -23,6 +23,22 @@ pub struct Suppression<'a> {
     pub categories: Vec<(&'a Category, Option<&'a str>)>,
     /// Reason for this suppression comment to exist
     pub reason: &'a str,
+    /// The code this suppression applies to
+    pub scope: SuppressionScope,
+}
+
+/// The code a suppression comment applies to
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionScope {
+    /// `biome-ignore`: the next line, or the next node for the formatter
+    #[default]
+    Line,
+    /// `biome-ignore-start`: the code until the matching `biome-ignore-end`
+    RangeStart,
+    /// `biome-ignore-end`: closes the last range opened by `biome-ignore-start`
+    RangeEnd,
+    /// `biome-ignore-all`: the code until the end of the file
+    File,
 }
 
 pub fn parse_suppression_comment(
@@ -64,15 +80,41 @@ pub fn parse_suppression_comment(
             ['e', 'E'],
         ];
 
-        // Checks for `/rome[-_]ignore/i` without a regex, or skip the line
-        // entirely if it doesn't match
-        for pattern in PATTERNS {
-            line = line.strip_prefix(pattern)?;
+        let mut scope = SuppressionScope::Line;
+        if let Some(rest) = line.strip_prefix("biome-ignore") {
+            line = rest;
+            for (suffix, suffix_scope) in [
+                ("-start", SuppressionScope::RangeStart),
+                ("-end", SuppressionScope::RangeEnd),
+                ("-all", SuppressionScope::File),
+            ] {
+                if let Some(rest) = line.strip_prefix(suffix) {
+                    line = rest;
+                    scope = suffix_scope;
+                    break;
+                }
+            }
+        } else {
+            // Checks for `/rome[-_]ignore/i` without a regex, or skip the line
+            // entirely if it doesn't match
+            for pattern in PATTERNS {
+                line = line.strip_prefix(pattern)?;
+            }
         }
 
         let line = line.trim_start();
+
+        // The end of a range doesn't need to repeat the categories and the reason
+        if scope == SuppressionScope::RangeEnd && line.trim_end().is_empty() {
+            return Some(Ok(Suppression {
+                categories: Vec::new(),
+                reason: "",
+                scope,
+            }));
+        }
+
         Some(
-            parse_suppression_line(line).map_err(|err| SuppressionDiagnostic {
+            parse_suppression_line(line, scope).map_err(|err| SuppressionDiagnostic {
                 message: err.message,
                 // Adjust the position of the diagnostic in the whole comment
                 span: err.span + offset_from(base, line),
@@ -138,7 +180,10 @@ impl biome_console::fmt::Display for SuppressionDiagnosticKind {
 }
 
 /// Parse the `{ <category> { (<value>) }? }+: <reason>` section of a suppression line
-fn parse_suppression_line(base: &str) -> Result<Suppression, SuppressionDiagnostic> {
+fn parse_suppression_line(
+    base: &str,
+    scope: SuppressionScope,
+) -> Result<Suppression, SuppressionDiagnostic> {
     let mut line = base;
     let mut categories = Vec::new();
 
@@ -153,24 +198,25 @@ fn parse_suppression_line(base: &str) -> Result<Suppression, SuppressionDiagnost
 
         let (category, rest) = line.split_at(separator);
         let category = category.trim_end();
-        let category: Option<&'static Category> = if !category.is_empty() {
-            let category = category.parse().map_err(|()| SuppressionDiagnostic {
+
+        // Skip over and match the separator
+        let (separator, rest) = rest.split_at(1);
+
+        let category = if !category.is_empty() {
+            let parsed = parse_category(category, separator != "(");
+            Some(parsed.ok_or_else(|| SuppressionDiagnostic {
                 message: SuppressionDiagnosticKind::ParseCategory(category.into()),
                 span: TextRange::at(offset_from(base, category), TextSize::of(category)),
-            })?;
-            Some(category)
+            })?)
         } else {
             None
         };
 
-        // Skip over and match the separator
-        let (separator, rest) = rest.split_at(1);
-
         match separator {
             // Colon token: stop parsing categories
             ":" => {
                 if let Some(category) = category {
-                    categories.push((category, None));
+                    categories.push(category);
                 }
 
                 line = rest.trim_start();
@@ -178,7 +224,7 @@ fn parse_suppression_line(base: &str) -> Result<Suppression, SuppressionDiagnost
             }
             // Paren token: parse a category + value
             "(" => {
-                let category = category.ok_or_else(|| SuppressionDiagnostic {
+                let (category, _) = category.ok_or_else(|| SuppressionDiagnostic {
                     message: SuppressionDiagnosticKind::MissingCategory,
                     span: TextRange::at(
                         offset_from(base, line),
@@ -200,7 +246,7 @@ fn parse_suppression_line(base: &str) -> Result<Suppression, SuppressionDiagnost
             // Whitespace: push a category without value
             _ => {
                 if let Some(category) = category {
-                    categories.push((category, None));
+                    categories.push(category);
                 }
 
                 line = rest.trim_start();
@@ -209,7 +255,28 @@ fn parse_suppression_line(base: &str) -> Result<Suppression, SuppressionDiagnost
     }
 
     let reason = line.trim_end();
-    Ok(Suppression { categories, reason })
+    Ok(Suppression {
+        categories,
+        reason,
+        scope,
+    })
+}
+
+/// Parses the name of a category, along with its value.
+///
+/// When `allow_unknown_rule` is `true`, an unknown `lint/<group>/<rule>` category
+/// is read as `lint(<group>/<rule>)`, so the linter reports the unknown rule
+/// instead of failing to parse the whole comment.
+fn parse_category(
+    category: &str,
+    allow_unknown_rule: bool,
+) -> Option<(&'static Category, Option<&str>)> {
+    if let Ok(category) = category.parse() {
+        return Some((category, None));
+    }
+
+    let rule = category.strip_prefix("lint/")?;
+    (allow_unknown_rule && !rule.is_empty()).then_some((category!("lint"), Some(rule)))
 }
 
 /// Returns the byte offset of `substr` within `base`
@@ -244,7 +311,7 @@ mod tests {
 
     use crate::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionScope};
 
     #[test]
     fn parse_simple_suppression() {
@@ -252,7 +319,8 @@ mod tests {
             parse_suppression_comment("// rome-ignore parse: explanation1").collect::<Vec<_>>(),
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
-                reason: "explanation1"
+                reason: "explanation1",
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -260,7 +328,8 @@ mod tests {
             parse_suppression_comment("/** rome-ignore parse: explanation2 */").collect::<Vec<_>>(),
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
-                reason: "explanation2"
+                reason: "explanation2",
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -273,7 +342,8 @@ mod tests {
             .collect::<Vec<_>>(),
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
-                reason: "explanation3"
+                reason: "explanation3",
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -287,7 +357,8 @@ mod tests {
             .collect::<Vec<_>>(),
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
-                reason: "explanation4"
+                reason: "explanation4",
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -297,7 +368,8 @@ mod tests {
             parse_suppression_comment("/* rome-ignore format: explanation").collect::<Vec<_>>(),
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -305,7 +377,8 @@ mod tests {
             parse_suppression_comment("/* rome-ignore format: explanation *").collect::<Vec<_>>(),
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -313,7 +386,8 @@ mod tests {
             parse_suppression_comment("/* rome-ignore format: explanation /").collect::<Vec<_>>(),
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -328,7 +402,8 @@ mod tests {
                     (category!("parse"), Some("foo")),
                     (category!("parse"), Some("dog"))
                 ],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -340,7 +415,8 @@ mod tests {
                     (category!("parse"), Some("bar")),
                     (category!("parse"), Some("cat"))
                 ],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -356,7 +432,8 @@ mod tests {
                     (category!("parse"), Some("yes")),
                     (category!("parse"), Some("frog"))
                 ],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -373,7 +450,39 @@ mod tests {
                     (category!("parse"), Some("wow")),
                     (category!("parse"), Some("fish"))
                 ],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line
+            })],
+        );
+    }
+
+    #[test]
+    fn parse_range_suppressions() {
+        assert_eq!(
+            parse_suppression_comment("/* biome-ignore-start format: generated */")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("format"), None)],
+                reason: "generated",
+                scope: SuppressionScope::RangeStart
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("/* biome-ignore-end */").collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![],
+                reason: "",
+                scope: SuppressionScope::RangeEnd
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-all format: generated").collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("format"), None)],
+                reason: "generated",
+                scope: SuppressionScope::File
             })],
         );
     }
@@ -385,7 +494,8 @@ mod tests {
                 .collect::<Vec<_>>(),
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -446,4 +556,17 @@ mod tests {
             })],
         );
     }
+
+    #[test]
+    fn parse_unknown_lint_rule() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore lint/suspicious/noSuchRule: explanation")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint"), Some("suspicious/noSuchRule"))],
+                reason: "explanation",
+                scope: SuppressionScope::Line
+            })],
+        );
+    }
 }
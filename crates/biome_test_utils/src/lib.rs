@@ -1,12 +1,17 @@
 use biome_analyze::{AnalyzerAction, AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
 use biome_console::fmt::{Formatter, Termcolor};
 use biome_console::markup;
+use biome_css_parser::{parse_css, CssParserOptions};
 use biome_diagnostics::termcolor::Buffer;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDiagnostic};
+use biome_js_analyze::ImportedCssModules;
 use biome_json_parser::{JsonParserOptions, ParseDiagnostic};
-use biome_project::PackageJson;
+use biome_project::{CssModule, PackageJson};
 use biome_rowan::{SyntaxKind, SyntaxNode, SyntaxSlot};
-use biome_service::configuration::to_analyzer_rules;
+use biome_service::configuration::{to_analyzer_globals, to_analyzer_rules};
+use biome_service::file_handlers::{
+    push_css_assists_to_analyzer_rules, push_json_assists_to_analyzer_rules,
+};
 use biome_service::settings::{Language, WorkspaceSettings};
 use biome_service::PartialConfiguration;
 use json_comments::StripComments;
@@ -69,6 +74,13 @@ pub fn create_analyzer_options(
                 .merge_with_configuration(configuration, None, None, &[])
                 .unwrap();
             analyzer_configuration.rules = to_analyzer_rules(&settings, input_file);
+            push_css_assists_to_analyzer_rules(&settings, &mut analyzer_configuration.rules);
+            push_json_assists_to_analyzer_rules(
+                &settings,
+                input_file,
+                &mut analyzer_configuration.rules,
+            );
+            analyzer_configuration.globals = to_analyzer_globals(&settings, input_file);
         }
     }
 
@@ -107,6 +119,36 @@ pub fn load_manifest(input_file: &Path, diagnostics: &mut Vec<String>) -> Option
     None
 }
 
+/// Loads the CSS modules in the directory of the test file, as if the file
+/// imported each of them with `./<file name>`
+pub fn load_css_modules(input_file: &Path) -> ImportedCssModules {
+    let mut css_modules = ImportedCssModules::default();
+    let Some(entries) = input_file
+        .parent()
+        .and_then(|directory| std::fs::read_dir(directory).ok())
+    else {
+        return css_modules;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let Some(file_name) = path.file_name().and_then(OsStr::to_str) else {
+            continue;
+        };
+        if !file_name.ends_with(".module.css") {
+            continue;
+        }
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            let parse = parse_css(&content, CssParserOptions::default().allow_css_modules());
+            css_modules.insert(
+                format!("./{file_name}"),
+                CssModule::from_root(&parse.tree()),
+            );
+        }
+    }
+
+    css_modules
+}
+
 pub fn diagnostic_to_string(name: &str, source: &str, diag: Error) -> String {
     let error = diag.with_file_path(name).with_file_source_code(source);
     let text = markup_to_string(biome_console::markup! {
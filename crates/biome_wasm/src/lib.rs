@@ -2,10 +2,12 @@ use js_sys::Error;
 use wasm_bindgen::prelude::*;
 
 use biome_service::workspace::{
-    self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
-    FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, OrganizeImportsParams, PullActionsParams, PullDiagnosticsParams,
-    RenameParams, UpdateSettingsParams,
+    self, ChangeFileParams, CloseFileParams, DropPatternParams, FixFileParams, FormatFileParams,
+    FormatOnTypeParams, FormatRangeParams, GetCompletionsParams, GetControlFlowGraphParams,
+    GetFileContentParams, GetFoldingRangesParams, GetFormatterIRParams, GetSelectionRangesParams,
+    GetSyntaxTreeParams, OrganizeImportsParams, ParsePatternParams, PullActionsParams,
+    PullDiagnosticsParams, RenameImportsParams, RenameParams, SearchPatternParams,
+    SemanticTokensParams, UpdateSettingsParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
@@ -194,6 +196,101 @@ impl Workspace {
             .map(IRenameResult::from)
             .map_err(into_error)
     }
+
+    #[wasm_bindgen(js_name = renameImports)]
+    pub fn rename_imports(
+        &self,
+        params: IRenameImportsParams,
+    ) -> Result<IRenameImportsResult, Error> {
+        let params: RenameImportsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.rename_imports(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IRenameImportsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = semanticTokens)]
+    pub fn semantic_tokens(
+        &self,
+        params: ISemanticTokensParams,
+    ) -> Result<ISemanticTokensResult, Error> {
+        let params: SemanticTokensParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.semantic_tokens(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ISemanticTokensResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getFoldingRanges)]
+    pub fn get_folding_ranges(
+        &self,
+        params: IGetFoldingRangesParams,
+    ) -> Result<IGetFoldingRangesResult, Error> {
+        let params: GetFoldingRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_folding_ranges(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetFoldingRangesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getSelectionRanges)]
+    pub fn get_selection_ranges(
+        &self,
+        params: IGetSelectionRangesParams,
+    ) -> Result<IGetSelectionRangesResult, Error> {
+        let params: GetSelectionRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_selection_ranges(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetSelectionRangesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getCompletions)]
+    pub fn get_completions(
+        &self,
+        params: IGetCompletionsParams,
+    ) -> Result<IGetCompletionsResult, Error> {
+        let params: GetCompletionsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_completions(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetCompletionsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = parsePattern)]
+    pub fn parse_pattern(&self, params: IParsePatternParams) -> Result<IParsePatternResult, Error> {
+        let params: ParsePatternParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.parse_pattern(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IParsePatternResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = searchPattern)]
+    pub fn search_pattern(&self, params: ISearchPatternParams) -> Result<ISearchResults, Error> {
+        let params: SearchPatternParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.search_pattern(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ISearchResults::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = dropPattern)]
+    pub fn drop_pattern(&self, params: IDropPatternParams) -> Result<(), Error> {
+        let params: DropPatternParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        self.inner.drop_pattern(params).map_err(into_error)
+    }
 }
 
 fn to_value<T: serde::ser::Serialize + ?Sized>(
@@ -4,7 +4,7 @@ export interface SupportsFeatureParams {
 	feature: FeatureName[];
 	path: BiomePath;
 }
-export type FeatureName = "Format" | "Lint" | "OrganizeImports";
+export type FeatureName = "Format" | "Lint" | "OrganizeImports" | "Search";
 export interface BiomePath {
 	path: string;
 }
@@ -20,6 +20,18 @@ export type SupportKind =
 export interface UpdateSettingsParams {
 	configuration: PartialConfiguration;
 	gitignore_matches: string[];
+	/**
+	 * The JSON schemas listed in the configuration, already read from the file system
+	 */
+	json_schemas?: JsonSchemaDefinition[];
+	/**
+	 * The plugins listed in the configuration, already read from the file system
+	 */
+	plugins?: PluginDefinition[];
+	/**
+	 * The project folder these settings belong to. Settings without a project folder apply to the files that aren't inside any registered project folder.
+	 */
+	project_folder?: string;
 	vcs_base_path?: string;
 	working_directory?: string;
 }
@@ -67,15 +79,67 @@ export interface PartialConfiguration {
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
 	overrides?: Overrides;
+	/**
+	 * A list of paths to plugin files, each one defining a lint rule through a code pattern. Relative paths are resolved from the directory of the configuration file.
+	 */
+	plugins?: StringSet;
+	/**
+	 * Whether this is the root configuration, `true` by default. Set it to `false` in the configuration file of a subdirectory: the file is merged over the configuration of the parent directories, and applies to the files of that subdirectory.
+	 */
+	root?: boolean;
 	/**
 	 * The configuration of the VCS integration
 	 */
 	vcs?: PartialVcsConfiguration;
 }
+/**
+ * A JSON schema listed in `json.schemas`, already read from the file system
+ */
+export interface JsonSchemaDefinition {
+	/**
+	 * The patterns of the files validated against the schema
+	 */
+	include: StringSet;
+	/**
+	 * The content of the schema file
+	 */
+	schema: any;
+}
+/**
+	* The content of a plugin file: a lint rule defined by a structural pattern.
+
+```json { "name": "noConsoleLog", "pattern": "console.log($msg)", "message": "Use the logger instead of console.log.", "level": "error", "rewrite": "logger.info($msg)" } ``` 
+	 */
+export interface PluginDefinition {
+	/**
+	 * The severity of the diagnostics. Use `"off"` to disable the plugin
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * The message of the diagnostics emitted for each match of the pattern
+	 */
+	message: string;
+	/**
+	 * The name of the plugin. Its diagnostics use the category `plugin/<name>`, which suppresses them: `// biome-ignore plugin/<name>: <explanation>`
+	 */
+	name: string;
+	/**
+	 * The code to report. Identifiers starting with `$` are metavariables that match any code.
+	 */
+	pattern: string;
+	/**
+	 * The code that should replace the matches, it can use the metavariables of the pattern
+	 */
+	rewrite?: string;
+}
 /**
  * Options applied to CSS files
  */
 export interface PartialCssConfiguration {
+	/**
+	 * Assists options
+	 */
+	assists?: PartialCssAssists;
 	/**
 	 * Formatting options
 	 */
@@ -153,6 +217,12 @@ export interface PartialFormatterConfiguration {
  * A set of options applied to the JavaScript files
  */
 export interface PartialJavascriptConfiguration {
+	/**
+	* The environments where the code runs, e.g. `node` or `jest`.
+
+The globals of these environments should not emit diagnostics. 
+	 */
+	environments?: JsEnvironments;
 	/**
 	 * Formatting options
 	 */
@@ -173,6 +243,10 @@ If defined here, they should not emit diagnostics.
  * Options applied to JSON files
  */
 export interface PartialJsonConfiguration {
+	/**
+	 * Assists options
+	 */
+	assists?: PartialJsonAssists;
 	/**
 	 * Formatting options
 	 */
@@ -181,6 +255,10 @@ export interface PartialJsonConfiguration {
 	 * Parsing options
 	 */
 	parser?: PartialJsonParser;
+	/**
+	 * The JSON schemas that the files must match. Each schema is a local file, `draft-07` or `2020-12`, associated to the files matched by the `include` patterns.
+	 */
+	schemas?: JsonSchemas;
 }
 export interface PartialLinterConfiguration {
 	/**
@@ -242,6 +320,24 @@ If Biome can't find the configuration, it will attempt to use the current workin
 	 */
 	useIgnoreFile?: boolean;
 }
+export type RulePlainConfiguration = "warn" | "error" | "off";
+/**
+ * Options of the source actions applied to CSS files
+ */
+export interface PartialCssAssists {
+	/**
+	 * The properties, in order, used when `declarationsOrder` is `custom`
+	 */
+	customOrder?: StringSet;
+	/**
+	 * The order of the sorted declarations. Defaults to `grouped`.
+	 */
+	declarationsOrder?: CssDeclarationsOrder;
+	/**
+	 * Sort the declarations of the blocks when organizing the file, e.g. with `biome check --apply`
+	 */
+	sortDeclarations?: boolean;
+}
 export interface PartialCssFormatter {
 	/**
 	 * Control the formatter for CSS (and its super languages) files.
@@ -287,6 +383,10 @@ export type LineEnding = "lf" | "crlf" | "cr";
 The allowed range of values is 1..=320 
 	 */
 export type LineWidth = number;
+/**
+ * A set of [JsEnvironment]
+ */
+export type JsEnvironments = JsEnvironment[];
 /**
  * Formatting options specific to the JavaScript files
  */
@@ -364,6 +464,19 @@ These decorators belong to an old proposal, and they are subject to change.
 	 */
 	unsafeParameterDecoratorsEnabled?: boolean;
 }
+/**
+ * Options of the source actions applied to JSON files
+ */
+export interface PartialJsonAssists {
+	/**
+	 * The keys placed first, in order, when sorting the keys. The other keys are sorted alphabetically. Use `overrides` to give a different order to some files.
+	 */
+	keysOrder?: StringSet;
+	/**
+	 * Sort the keys of the objects when organizing the file, e.g. with `biome check --apply`
+	 */
+	sortKeys?: boolean;
+}
 export interface PartialJsonFormatter {
 	/**
 	 * Control the formatter for JSON (and its super languages) files.
@@ -407,6 +520,10 @@ export interface PartialJsonParser {
 	 */
 	allowTrailingCommas?: boolean;
 }
+/**
+ * A list of [JsonSchemaAssociation]
+ */
+export type JsonSchemas = JsonSchemaAssociation[];
 export interface Rules {
 	a11y?: A11y;
 	/**
@@ -460,7 +577,21 @@ export interface OverridePattern {
 	organizeImports?: OverrideOrganizeImportsConfiguration;
 }
 export type VcsClientKind = "git";
+export type CssDeclarationsOrder = "alphabetical" | "grouped" | "custom";
 export type QuoteStyle = "double" | "single";
+/**
+ * An environment where the code runs, that defines its own globals
+ */
+export type JsEnvironment =
+	| "browser"
+	| "node"
+	| "worker"
+	| "serviceworker"
+	| "deno"
+	| "bun"
+	| "jest"
+	| "vitest"
+	| "mocha";
 export type ArrowParentheses = "always" | "asNeeded";
 export type QuoteProperties = "asNeeded" | "preserve";
 export type Semicolons = "always" | "asNeeded";
@@ -469,6 +600,21 @@ export type Semicolons = "always" | "asNeeded";
  */
 export type TrailingComma = "all" | "es5" | "none";
 export type TrailingCommas = "none" | "all";
+/**
+	* Associates a JSON schema to some files
+
+```json { "include": ["services/*/config.json"], "path": "./schemas/service.schema.json" } ``` 
+	 */
+export interface JsonSchemaAssociation {
+	/**
+	 * A list of Unix shell style patterns. The files that match these patterns are validated against the schema.
+	 */
+	include?: StringSet;
+	/**
+	 * The path of the schema file, relative to the configuration file
+	 */
+	path?: string;
+}
 /**
  * A list of rules that belong to this group
  */
@@ -876,6 +1022,10 @@ export interface Nursery {
 	 * Disallow the use of console.
 	 */
 	noConsole?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow a dependency declared in several kinds of dependencies of package.json.
+	 */
+	noDuplicateDependencies?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow two keys with the same name inside a JSON object.
 	 */
@@ -912,10 +1062,22 @@ export interface Nursery {
 	 * Disallow the use of global eval().
 	 */
 	noGlobalEval?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow invalid exports in package.json.
+	 */
+	noInvalidPackageExports?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow the use of variables and function parameters before their declaration
 	 */
 	noInvalidUseBeforeDeclaration?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow invalid versions and version ranges in package.json.
+	 */
+	noInvalidVersionRange?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the values that don't match the JSON schemas of the file.
+	 */
+	noJsonSchemaViolations?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow characters made with multiple code points in character class syntax.
 	 */
@@ -952,6 +1114,22 @@ export interface Nursery {
 	 * Disallow the use of dependencies that aren't specified in the package.json.
 	 */
 	noUndeclaredDependencies?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the use of classes that aren't defined by the imported CSS module.
+	 */
+	noUndefinedCssModuleClasses?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow licenses that aren't SPDX license identifiers in package.json.
+	 */
+	noUnknownLicense?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the classes of an imported CSS module that are never used.
+	 */
+	noUnusedCssModuleClasses?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow dependencies of package.json that the package never imports.
+	 */
+	noUnusedDependencies?: RuleConfiguration_for_UnusedDependenciesOptions;
 	/**
 	 * Disallow unused imports.
 	 */
@@ -1028,6 +1206,10 @@ export interface Nursery {
 	 * Enforce the sorting of CSS utility classes.
 	 */
 	useSortedClasses?: RuleConfiguration_for_UtilityClassSortingOptions;
+	/**
+	 * Enforce the alphabetical order of the dependencies in package.json.
+	 */
+	useSortedDependencies?: RuleConfiguration_for_Null;
 }
 /**
  * A list of rules that belong to this group
@@ -1474,6 +1656,9 @@ export type RuleConfiguration_for_DeprecatedHooksOptions =
 export type RuleConfiguration_for_RestrictedImportsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_RestrictedImportsOptions;
+export type RuleConfiguration_for_UnusedDependenciesOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UnusedDependenciesOptions;
 export type RuleConfiguration_for_ConsistentArrayTypeOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_ConsistentArrayTypeOptions;
@@ -1489,7 +1674,6 @@ export type RuleConfiguration_for_RestrictedGlobalsOptions =
 export type RuleConfiguration_for_NamingConventionOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_NamingConventionOptions;
-export type RulePlainConfiguration = "warn" | "error" | "off";
 export interface RuleWithOptions_for_Null {
 	level: RulePlainConfiguration;
 	options: null;
@@ -1514,6 +1698,10 @@ export interface RuleWithOptions_for_RestrictedImportsOptions {
 	level: RulePlainConfiguration;
 	options: RestrictedImportsOptions;
 }
+export interface RuleWithOptions_for_UnusedDependenciesOptions {
+	level: RulePlainConfiguration;
+	options: UnusedDependenciesOptions;
+}
 export interface RuleWithOptions_for_ConsistentArrayTypeOptions {
 	level: RulePlainConfiguration;
 	options: ConsistentArrayTypeOptions;
@@ -1569,6 +1757,15 @@ export interface RestrictedImportsOptions {
 	 */
 	paths: {};
 }
+/**
+ * Options for the rule `noUnusedDependencies`.
+ */
+export interface UnusedDependenciesOptions {
+	/**
+	 * The dependencies that are never reported. A name that ends with `/*` matches all the packages of a scope, e.g. `@types/*`.
+	 */
+	ignore: string[];
+}
 export interface ConsistentArrayTypeOptions {
 	syntax: ConsistentArrayType;
 }
@@ -1603,6 +1800,10 @@ export interface UtilityClassSortingOptions {
  * Options for the rule `noRestrictedGlobals`.
  */
 export interface RestrictedGlobalsOptions {
+	/**
+	 * A list of environments whose globals should trigger the rule
+	 */
+	deniedEnvironments: JsEnvironment[];
 	/**
 	 * A list of names that should trigger the rule
 	 */
@@ -1656,6 +1857,9 @@ export type FilenameCase =
 	| "kebab-case"
 	| "PascalCase"
 	| "snake_case";
+export interface UnregisterProjectFolderParams {
+	path: string;
+}
 export interface UpdateProjectParams {
 	path: BiomePath;
 }
@@ -1707,13 +1911,13 @@ export type LanguageVariant = "Standard" | "StandardRestricted" | "Jsx";
 Defaults to the latest stable ECMAScript standard. 
 	 */
 export type LanguageVersion = "ES2022" | "ESNext";
-export type JsonVariant = "Standard" | "Jsonc";
+export type JsonVariant = "Standard" | "Jsonc" | "Json5";
 /**
 	* The style of CSS contained in the file.
 
-Currently, Biome only supports plain CSS, and aims to be compatible with the latest Recommendation level standards. 
+Plain CSS aims to be compatible with the latest Recommendation level standards. SCSS files are parsed with the additional syntax of the [Sass](https://sass-lang.com/documentation/syntax/) language, and Less files with the one of [Less](https://lesscss.org/features/). Files named `*.module.css` are [CSS Modules](https://github.com/css-modules/css-modules), whose class names are scoped to the file that defines them. 
 	 */
-export type CssVariant = "Standard";
+export type CssVariant = "Standard" | "Scss" | "Less" | "CssModules";
 export interface ChangeFileParams {
 	content: string;
 	path: BiomePath;
@@ -1748,6 +1952,10 @@ export interface GetFormatterIRParams {
 }
 export interface PullDiagnosticsParams {
 	categories: RuleCategories;
+	/**
+	 * The content of the file, when it isn't open in the workspace. The file is analyzed without being stored, so it stays closed.
+	 */
+	content?: string;
 	max_diagnostics: number;
 	path: BiomePath;
 }
@@ -1870,6 +2078,7 @@ export type Category =
 	| "lint/nursery/noApproximativeNumericConstant"
 	| "lint/nursery/noBarrelFile"
 	| "lint/nursery/noConsole"
+	| "lint/nursery/noDuplicateDependencies"
 	| "lint/nursery/noDuplicateJsonKeys"
 	| "lint/nursery/noDuplicateTestHooks"
 	| "lint/nursery/noEmptyBlockStatements"
@@ -1877,9 +2086,12 @@ export type Category =
 	| "lint/nursery/noExcessiveNestedTestSuites"
 	| "lint/nursery/noExportsInTest"
 	| "lint/nursery/noFocusedTests"
+	| "lint/nursery/noJsonSchemaViolations"
 	| "lint/nursery/noGlobalAssign"
 	| "lint/nursery/noGlobalEval"
+	| "lint/nursery/noInvalidPackageExports"
 	| "lint/nursery/noInvalidUseBeforeDeclaration"
+	| "lint/nursery/noInvalidVersionRange"
 	| "lint/nursery/noMisleadingCharacterClass"
 	| "lint/nursery/noNamespaceImport"
 	| "lint/nursery/noNodejsModules"
@@ -1890,10 +2102,18 @@ export type Category =
 	| "lint/nursery/noThenProperty"
 	| "lint/nursery/noTypeOnlyImportAttributes"
 	| "lint/nursery/noUndeclaredDependencies"
+	| "lint/nursery/noUndefinedCssModuleClasses"
+	| "lint/nursery/noUndefinedCustomProperties"
+	| "lint/nursery/noUnknownLicense"
+	| "lint/nursery/noUnknownPropertyValue"
+	| "lint/nursery/noUnusedCssModuleClasses"
+	| "lint/nursery/noUnusedCustomProperties"
+	| "lint/nursery/noUnusedDependencies"
 	| "lint/nursery/noUnusedImports"
 	| "lint/nursery/noUnusedPrivateClassMembers"
 	| "lint/nursery/noUselessLoneBlockStatements"
 	| "lint/nursery/noUselessTernary"
+	| "lint/nursery/noUselessVarFallback"
 	| "lint/nursery/useAwait"
 	| "lint/nursery/useBiomeSuppressionComment"
 	| "lint/nursery/useConsistentArrayType"
@@ -1909,6 +2129,7 @@ export type Category =
 	| "lint/nursery/useNumberNamespace"
 	| "lint/nursery/useShorthandFunctionType"
 	| "lint/nursery/useSortedClasses"
+	| "lint/nursery/useSortedDependencies"
 	| "lint/performance/noAccumulatingSpread"
 	| "lint/performance/noDelete"
 	| "lint/security/noDangerouslySetInnerHtml"
@@ -2000,6 +2221,8 @@ export type Category =
 	| "migrate"
 	| "deserialize"
 	| "project"
+	| "search"
+	| "plugin"
 	| "internalError/io"
 	| "internalError/fs"
 	| "internalError/panic"
@@ -2199,6 +2422,10 @@ export interface FormatOnTypeParams {
 }
 export interface FixFileParams {
 	fix_file_mode: FixFileMode;
+	/**
+	 * When set, only the fixes of this rule are applied
+	 */
+	only_rule?: RuleCode;
 	path: BiomePath;
 	should_format: boolean;
 }
@@ -2206,6 +2433,19 @@ export interface FixFileParams {
  * Which fixes should be applied during the analyzing phase
  */
 export type FixFileMode = "SafeFixes" | "SafeAndUnsafeFixes";
+/**
+ * The code of a rule, e.g. `suspicious/noDoubleEquals`
+ */
+export interface RuleCode {
+	/**
+	 * The group of the rule, e.g. `suspicious`
+	 */
+	group: string;
+	/**
+	 * The name of the rule, e.g. `noDoubleEquals`
+	 */
+	rule: string;
+}
 export interface FixFileResult {
 	/**
 	 * List of all the code actions applied to the file
@@ -2240,6 +2480,10 @@ export interface RenameParams {
 	symbol_at: TextSize;
 }
 export interface RenameResult {
+	/**
+	 * The name the module exported the binding with, when the rename changed it. The imports of the binding in other files must be renamed as well
+	 */
+	exported_name?: string;
 	/**
 	 * List of text edit operations to apply on the source code
 	 */
@@ -2249,10 +2493,157 @@ export interface RenameResult {
 	 */
 	range: TextRange;
 }
+export interface RenameImportsParams {
+	/**
+	 * The content of the file, when it isn't open in the workspace. The file is renamed without being stored, so it stays closed.
+	 */
+	content?: string;
+	/**
+	 * The name the module exported the binding with
+	 */
+	exported_name: string;
+	/**
+	 * The module that exports the binding
+	 */
+	module: BiomePath;
+	new_name: string;
+	/**
+	 * The file that imports the binding
+	 */
+	path: BiomePath;
+}
+export interface RenameImportsResult {
+	/**
+	 * List of text edit operations to apply on the source code
+	 */
+	indels: TextEdit;
+	/**
+	 * Whether the file re-exports the binding with the same name, in which case the files that import it from this file must be renamed as well
+	 */
+	re_exported: boolean;
+}
+export interface SemanticTokensParams {
+	path: BiomePath;
+	/**
+	 * When set, only the tokens that intersect this range are returned
+	 */
+	range?: TextRange;
+}
+export interface SemanticTokensResult {
+	/**
+	 * The tokens of the file, sorted by their position
+	 */
+	tokens: SemanticToken[];
+}
+export interface SemanticToken {
+	/**
+	 * Whether the token declares the binding
+	 */
+	is_declaration: boolean;
+	/**
+	 * Whether the binding can't be assigned, e.g. a `const` variable or an import
+	 */
+	is_readonly: boolean;
+	kind: SemanticTokenKind;
+	range: TextRange;
+}
+/**
+ * The classification of an identifier, computed from the semantic model
+ */
+export type SemanticTokenKind =
+	| "Parameter"
+	| "Variable"
+	| "Import"
+	| "Global"
+	| "Class"
+	| "TypeParameter"
+	| "Unresolved";
+export interface GetFoldingRangesParams {
+	path: BiomePath;
+}
+export interface GetFoldingRangesResult {
+	/**
+	 * The ranges that can be folded, sorted by their start
+	 */
+	ranges: FoldingRange[];
+}
+export interface FoldingRange {
+	kind: FoldingRangeKind;
+	/**
+	 * The range to fold. For a [FoldingRangeKind::Region], the range ends with the closing delimiter of the node, which editors usually keep visible
+	 */
+	range: TextRange;
+}
+export type FoldingRangeKind = "Region" | "Comment" | "Imports";
+export interface GetSelectionRangesParams {
+	/**
+	 * The positions where the selection starts
+	 */
+	offsets: TextSize[];
+	path: BiomePath;
+}
+export interface GetSelectionRangesResult {
+	/**
+	 * For each offset of the params, the ranges that contain it, from the innermost to the outermost
+	 */
+	ranges: TextRange[][];
+}
+export interface GetCompletionsParams {
+	/**
+	 * The position of the cursor
+	 */
+	offset: TextSize;
+	path: BiomePath;
+}
+export interface GetCompletionsResult {
+	items: CompletionItem[];
+}
+export interface CompletionItem {
+	/**
+	 * A description of the item, e.g. the `description` of a property in a JSON schema
+	 */
+	documentation?: string;
+	/**
+	 * The text inserted at the position of the cursor
+	 */
+	insert_text: string;
+	/**
+	 * The name of the item
+	 */
+	label: string;
+}
+export interface ParsePatternParams {
+	/**
+	 * The source code of the pattern, e.g. `console.log($msg)`
+	 */
+	pattern: string;
+}
+export interface ParsePatternResult {
+	pattern_id: PatternId;
+}
+/**
+ * Identifier of a pattern compiled by [Workspace::parse_pattern]
+ */
+export type PatternId = number;
+export interface SearchPatternParams {
+	path: BiomePath;
+	pattern: PatternId;
+}
+export interface SearchResults {
+	file: BiomePath;
+	/**
+	 * The ranges of the code that matched the pattern, trivia excluded
+	 */
+	matches: TextRange[];
+}
+export interface DropPatternParams {
+	pattern: PatternId;
+}
 export type Configuration = PartialConfiguration;
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<SupportsFeatureResult>;
 	updateSettings(params: UpdateSettingsParams): Promise<void>;
+	unregisterProjectFolder(params: UnregisterProjectFolderParams): Promise<void>;
 	updateCurrentProject(params: UpdateProjectParams): Promise<void>;
 	openProject(params: OpenProjectParams): Promise<void>;
 	openFile(params: OpenFileParams): Promise<void>;
@@ -2274,6 +2665,18 @@ export interface Workspace {
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	renameImports(params: RenameImportsParams): Promise<RenameImportsResult>;
+	semanticTokens(params: SemanticTokensParams): Promise<SemanticTokensResult>;
+	getFoldingRanges(
+		params: GetFoldingRangesParams,
+	): Promise<GetFoldingRangesResult>;
+	getSelectionRanges(
+		params: GetSelectionRangesParams,
+	): Promise<GetSelectionRangesResult>;
+	getCompletions(params: GetCompletionsParams): Promise<GetCompletionsResult>;
+	parsePattern(params: ParsePatternParams): Promise<ParsePatternResult>;
+	searchPattern(params: SearchPatternParams): Promise<SearchResults>;
+	dropPattern(params: DropPatternParams): Promise<void>;
 	destroy(): void;
 }
 export function createWorkspace(transport: Transport): Workspace {
@@ -2284,6 +2687,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		updateSettings(params) {
 			return transport.request("biome/update_settings", params);
 		},
+		unregisterProjectFolder(params) {
+			return transport.request("biome/unregister_project_folder", params);
+		},
 		updateCurrentProject(params) {
 			return transport.request("biome/update_current_project", params);
 		},
@@ -2335,6 +2741,30 @@ export function createWorkspace(transport: Transport): Workspace {
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
+		renameImports(params) {
+			return transport.request("biome/rename_imports", params);
+		},
+		semanticTokens(params) {
+			return transport.request("biome/semantic_tokens", params);
+		},
+		getFoldingRanges(params) {
+			return transport.request("biome/get_folding_ranges", params);
+		},
+		getSelectionRanges(params) {
+			return transport.request("biome/get_selection_ranges", params);
+		},
+		getCompletions(params) {
+			return transport.request("biome/get_completions", params);
+		},
+		parsePattern(params) {
+			return transport.request("biome/parse_pattern", params);
+		},
+		searchPattern(params) {
+			return transport.request("biome/search_pattern", params);
+		},
+		dropPattern(params) {
+			return transport.request("biome/drop_pattern", params);
+		},
 		destroy() {
 			transport.destroy();
 		},
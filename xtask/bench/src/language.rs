@@ -4,7 +4,7 @@ use biome_css_formatter::context::{CssFormatContext, CssFormatOptions};
 use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssSyntaxNode;
 use biome_formatter::{FormatResult, Formatted, PrintResult, Printed};
-use biome_js_analyze::analyze;
+use biome_js_analyze::{analyze, ImportedCssModules};
 use biome_js_formatter::context::{JsFormatContext, JsFormatOptions};
 use biome_js_parser::JsParserOptions;
 use biome_js_syntax::{AnyJsRoot, JsFileSource, JsSyntaxNode};
@@ -167,6 +167,8 @@ impl Analyze {
                     &options,
                     JsFileSource::default(),
                     None,
+                    ImportedCssModules::default(),
+                    &[],
                     |event| {
                         black_box(event.diagnostic());
                         black_box(event.actions());
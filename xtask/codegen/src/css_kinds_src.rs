@@ -40,6 +40,8 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         ("%=", "PERCENTEQ"),
         ("@", "AT"),
         ("$=", "DOLLAR_EQ"),
+        ("$", "DOLLAR"),
+        ("==", "EQ2"),
         ("~=", "TILDE_EQ"),
         ("-->", "CDC"),
         ("<!--", "CDO"),
@@ -115,6 +117,31 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "ridge",
         "inset",
         "outset",
+        // SCSS
+        "use",
+        "forward",
+        "as",
+        "with",
+        "show",
+        "hide",
+        "mixin",
+        "include",
+        "content",
+        "extend",
+        "optional",
+        "if",
+        "else",
+        "each",
+        "for",
+        "through",
+        "while",
+        // Less
+        "when",
+        "all",
+        "plugin",
+        // CSS Modules
+        "composes",
+        "value",
         // HERE: Add new regular keywords _above_ here. Be sure to also add them
         // to `consume_identifier` in `biome_css_parser/src/lexer/mod.rs` as well.
         // CSS-wide keywords
@@ -296,6 +323,10 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_GENERIC_COMPONENT_VALUE_LIST",
         "CSS_GENERIC_DELIMITER",
         "CSS_GENERIC_PROPERTY",
+        "CSS_COMPOSES_PROPERTY",
+        "CSS_COMPOSES_PROPERTY_VALUE",
+        "CSS_COMPOSES_CLASS_LIST",
+        "CSS_COMPOSES_IMPORT_SPECIFIER",
         "CSS_UNKNOWN_PROPERTY_VALUE",
         // Properties
         "CSS_PARAMETER_LIST",
@@ -359,6 +390,12 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_COLOR_PROFILE_AT_RULE",
         "CSS_COUNTER_STYLE_AT_RULE",
         "CSS_PROPERTY_AT_RULE",
+        "CSS_VALUE_AT_RULE",
+        "CSS_VALUE_AT_RULE_DECLARATION_CLAUSE",
+        "CSS_VALUE_AT_RULE_IMPORT_CLAUSE",
+        "CSS_VALUE_AT_RULE_IMPORT_SPECIFIER_LIST",
+        "CSS_VALUE_AT_RULE_IMPORT_SPECIFIER",
+        "CSS_VALUE_AT_RULE_NAMED_IMPORT_SPECIFIER",
         "CSS_CONTAINER_AT_RULE",
         "CSS_CONTAINER_NOT_QUERY",
         "CSS_CONTAINER_AND_QUERY",
@@ -443,6 +480,69 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_DOCUMENT_AT_RULE",
         "CSS_DOCUMENT_MATCHER_LIST",
         "CSS_DOCUMENT_CUSTOM_MATCHER",
+        // SCSS nodes
+        "SCSS_IDENTIFIER",
+        "SCSS_QUALIFIED_NAME",
+        "SCSS_INTERPOLATION",
+        "SCSS_OPERATOR",
+        "SCSS_PARENTHESIZED_EXPRESSION",
+        "SCSS_MAP_EXPRESSION",
+        "SCSS_MAP_EXPRESSION_PAIR_LIST",
+        "SCSS_MAP_EXPRESSION_PAIR",
+        "SCSS_DECLARATION",
+        "SCSS_VARIABLE_MODIFIER_LIST",
+        "SCSS_VARIABLE_MODIFIER",
+        "SCSS_PLACEHOLDER_SELECTOR",
+        "SCSS_USE_AT_RULE",
+        "SCSS_USE_AS",
+        "SCSS_USE_ALL_NAMESPACE",
+        "SCSS_MODULE_CONFIGURATION",
+        "SCSS_FORWARD_AT_RULE",
+        "SCSS_FORWARD_AS",
+        "SCSS_FORWARD_VISIBILITY",
+        "SCSS_FORWARD_MEMBER_LIST",
+        "SCSS_MIXIN_AT_RULE",
+        "SCSS_PARAMETERS",
+        "SCSS_PARAMETER_LIST",
+        "SCSS_PARAMETER",
+        "SCSS_PARAMETER_DEFAULT",
+        "SCSS_INCLUDE_AT_RULE",
+        "SCSS_INCLUDE_ARGUMENTS",
+        "SCSS_ARGUMENT_LIST",
+        "SCSS_ARGUMENT",
+        "SCSS_ARGUMENT_NAME",
+        "SCSS_CONTENT_AT_RULE",
+        "SCSS_EXTEND_AT_RULE",
+        "SCSS_EXTEND_OPTIONAL",
+        "SCSS_IF_AT_RULE",
+        "SCSS_ELSE_CLAUSE",
+        "SCSS_EACH_AT_RULE",
+        "SCSS_EACH_BINDING_LIST",
+        "SCSS_FOR_AT_RULE",
+        "SCSS_WHILE_AT_RULE",
+        // Less nodes
+        "LESS_IDENTIFIER",
+        "LESS_PARENTHESIZED_EXPRESSION",
+        "LESS_OPERATOR",
+        "LESS_ESCAPED_STRING",
+        "LESS_DECLARATION",
+        "LESS_MIXIN_DECLARATION",
+        "LESS_MIXIN_CALL",
+        "LESS_MIXIN_PARAMETERS",
+        "LESS_MIXIN_PARAMETER_LIST",
+        "LESS_MIXIN_SEMICOLON_PARAMETERS",
+        "LESS_MIXIN_SEMICOLON_PARAMETER_LIST",
+        "LESS_MIXIN_PARAMETER",
+        "LESS_MIXIN_PARAMETER_NAME",
+        "LESS_MIXIN_GUARD",
+        "LESS_PSEUDO_CLASS_EXTEND",
+        "LESS_EXTEND_TARGET_LIST",
+        "LESS_EXTEND_TARGET",
+        "LESS_EXTEND_DECLARATION",
+        "LESS_PLUGIN_AT_RULE",
+        "LESS_IMPORT_AT_RULE",
+        "LESS_IMPORT_OPTIONS",
+        "LESS_IMPORT_OPTION_LIST",
         // Bogus nodes
         "CSS_BOGUS",
         "CSS_BOGUS_BLOCK",
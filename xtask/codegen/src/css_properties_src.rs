@@ -0,0 +1,342 @@
+//! The value grammars of the CSS properties known by Biome.
+//!
+//! Grammars are written in the [value definition syntax] of the CSS specifications.
+//! `<name>` refers to a value type: either one of the basic types of
+//! [CSS_BASIC_VALUE_TYPES], or one of [CSS_VALUE_TYPES]. `<'name'>` refers to the value
+//! grammar of another property, and `<name()>` to a function whose arguments aren't checked.
+//!
+//! Numeric ranges such as `<length [0,∞]>` aren't supported: they are left out of the
+//! grammars, which are allowed to be more permissive than the specifications.
+//! Run `cargo codegen css-properties` after editing this file.
+//!
+//! [value definition syntax]: https://drafts.csswg.org/css-values-4/#value-defs
+
+/// The value types that are validated directly by the syntax tree, rather than by a grammar
+pub const CSS_BASIC_VALUE_TYPES: &[(&str, &str)] = &[
+    ("angle", "Angle"),
+    ("custom-ident", "CustomIdent"),
+    ("dashed-ident", "DashedIdent"),
+    ("flex", "Flex"),
+    ("frequency", "Frequency"),
+    ("hex-color", "HexColor"),
+    ("integer", "Integer"),
+    ("length", "Length"),
+    ("number", "Number"),
+    ("percentage", "Percentage"),
+    ("resolution", "Resolution"),
+    ("string", "String"),
+    ("time", "Time"),
+    ("url", "Url"),
+];
+
+pub const CSS_VALUE_TYPES: &[(&str, &str)] = &[
+    ("absolute-size", "xx-small | x-small | small | medium | large | x-large | xx-large | xxx-large"),
+    ("attachment", "scroll | fixed | local"),
+    ("baseline-position", "[ first | last ]? baseline"),
+    ("bg-clip", "<visual-box> | border-area | text"),
+    ("bg-image", "none | <image>"),
+    ("bg-layer", "<bg-image> || <bg-position> [ / <bg-size> ]? || <repeat-style> || <attachment> || <bg-clip> || <visual-box> || <color>"),
+    ("bg-position", "[ left | center | right | top | bottom | <length-percentage> ] | [ left | center | right | <length-percentage> ] [ top | center | bottom | <length-percentage> ] | [ center | [ left | right ] <length-percentage>? ] && [ center | [ top | bottom ] <length-percentage>? ]"),
+    ("bg-size", "[ <length-percentage> | auto ]{1,2} | cover | contain"),
+    ("blend-mode", "normal | multiply | screen | overlay | darken | lighten | color-dodge | color-burn | hard-light | soft-light | difference | exclusion | hue | saturation | color | luminosity"),
+    ("color", "<hex-color> | <named-color> | <system-color> | currentcolor | transparent | <rgb()> | <rgba()> | <hsl()> | <hsla()> | <hwb()> | <lab()> | <lch()> | <oklab()> | <oklch()> | <color()> | <color-mix()> | <light-dark()>"),
+    ("content-distribution", "space-between | space-around | space-evenly | stretch"),
+    ("content-position", "center | start | end | flex-start | flex-end"),
+    ("cursor-keyword", "auto | default | none | context-menu | help | pointer | progress | wait | cell | crosshair | text | vertical-text | alias | copy | move | no-drop | not-allowed | grab | grabbing | e-resize | n-resize | ne-resize | nw-resize | s-resize | se-resize | sw-resize | w-resize | ew-resize | ns-resize | nesw-resize | nwse-resize | col-resize | row-resize | all-scroll | zoom-in | zoom-out"),
+    ("display-box", "contents | none"),
+    ("display-inside", "flow | flow-root | table | flex | grid | ruby | math"),
+    ("display-internal", "table-row-group | table-header-group | table-footer-group | table-row | table-cell | table-column-group | table-column | table-caption | ruby-base | ruby-text | ruby-base-container | ruby-text-container"),
+    ("display-legacy", "inline-block | inline-table | inline-flex | inline-grid"),
+    ("display-listitem", "<display-outside>? && [ flow | flow-root ]? && list-item"),
+    ("display-outside", "block | inline | run-in"),
+    ("easing-function", "linear | ease | ease-in | ease-out | ease-in-out | step-start | step-end | <linear()> | <cubic-bezier()> | <steps()>"),
+    ("filter-function", "<blur()> | <brightness()> | <contrast()> | <drop-shadow()> | <grayscale()> | <hue-rotate()> | <invert()> | <opacity()> | <saturate()> | <sepia()>"),
+    ("font-variant-css2", "normal | small-caps"),
+    ("font-width-css3", "normal | ultra-condensed | extra-condensed | condensed | semi-condensed | semi-expanded | expanded | extra-expanded | ultra-expanded"),
+    ("gradient", "<linear-gradient()> | <repeating-linear-gradient()> | <radial-gradient()> | <repeating-radial-gradient()> | <conic-gradient()> | <repeating-conic-gradient()>"),
+    ("grid-line", "auto | <custom-ident> | [ <integer> && <custom-ident>? ] | [ span && [ <integer> || <custom-ident> ] ]"),
+    ("image", "<url> | <gradient> | <image()> | <image-set()> | <cross-fade()> | <element()> | <paint()>"),
+    ("keyframes-name", "<custom-ident> | <string>"),
+    ("length-percentage", "<length> | <percentage>"),
+    ("line-style", "none | hidden | dotted | dashed | solid | double | groove | ridge | inset | outset"),
+    ("line-width", "<length> | thin | medium | thick"),
+    ("named-color", "aliceblue | antiquewhite | aqua | aquamarine | azure | beige | bisque | black | blanchedalmond | blue | blueviolet | brown | burlywood | cadetblue | chartreuse | chocolate | coral | cornflowerblue | cornsilk | crimson | cyan | darkblue | darkcyan | darkgoldenrod | darkgray | darkgreen | darkgrey | darkkhaki | darkmagenta | darkolivegreen | darkorange | darkorchid | darkred | darksalmon | darkseagreen | darkslateblue | darkslategray | darkslategrey | darkturquoise | darkviolet | deeppink | deepskyblue | dimgray | dimgrey | dodgerblue | firebrick | floralwhite | forestgreen | fuchsia | gainsboro | ghostwhite | gold | goldenrod | gray | green | greenyellow | grey | honeydew | hotpink | indianred | indigo | ivory | khaki | lavender | lavenderblush | lawngreen | lemonchiffon | lightblue | lightcoral | lightcyan | lightgoldenrodyellow | lightgray | lightgreen | lightgrey | lightpink | lightsalmon | lightseagreen | lightskyblue | lightslategray | lightslategrey | lightsteelblue | lightyellow | lime | limegreen | linen | magenta | maroon | mediumaquamarine | mediumblue | mediumorchid | mediumpurple | mediumseagreen | mediumslateblue | mediumspringgreen | mediumturquoise | mediumvioletred | midnightblue | mintcream | mistyrose | moccasin | navajowhite | navy | oldlace | olive | olivedrab | orange | orangered | orchid | palegoldenrod | palegreen | paleturquoise | palevioletred | papayawhip | peachpuff | peru | pink | plum | powderblue | purple | rebeccapurple | red | rosybrown | royalblue | saddlebrown | salmon | sandybrown | seagreen | seashell | sienna | silver | skyblue | slateblue | slategray | slategrey | snow | springgreen | steelblue | tan | teal | thistle | tomato | turquoise | violet | wheat | white | whitesmoke | yellow | yellowgreen"),
+    ("overflow-position", "unsafe | safe"),
+    ("ratio", "<number> [ / <number> ]?"),
+    ("relative-size", "larger | smaller"),
+    ("repeat-style", "repeat-x | repeat-y | [ repeat | space | round | no-repeat ]{1,2}"),
+    ("self-position", "center | start | end | self-start | self-end | flex-start | flex-end | anchor-center"),
+    ("shadow", "<color>? && <length>{2,4} && inset?"),
+    ("single-animation", "<time> || <easing-function> || <time> || <single-animation-iteration-count> || <single-animation-direction> || <single-animation-fill-mode> || <single-animation-play-state> || [ none | <keyframes-name> ]"),
+    ("single-animation-direction", "normal | reverse | alternate | alternate-reverse"),
+    ("single-animation-fill-mode", "none | forwards | backwards | both"),
+    ("single-animation-iteration-count", "infinite | <number>"),
+    ("single-animation-play-state", "running | paused"),
+    ("single-transition", "[ none | <single-transition-property> ] || <time> || <easing-function> || <time> || <transition-behavior-value>"),
+    ("single-transition-property", "all | <custom-ident>"),
+    ("system-color", "accentcolor | accentcolortext | activetext | buttonborder | buttonface | buttontext | canvas | canvastext | field | fieldtext | graytext | highlight | highlighttext | linktext | mark | marktext | selecteditem | selecteditemtext | visitedtext | activeborder | activecaption | appworkspace | background | buttonhighlight | buttonshadow | captiontext | inactiveborder | inactivecaption | inactivecaptiontext | infobackground | infotext | menu | menutext | scrollbar | threeddarkshadow | threedface | threedhighlight | threedlightshadow | threedshadow | window | windowframe | windowtext"),
+    ("transform-function", "<matrix()> | <matrix3d()> | <perspective()> | <rotate()> | <rotate3d()> | <rotatex()> | <rotatey()> | <rotatez()> | <scale()> | <scale3d()> | <scalex()> | <scaley()> | <scalez()> | <skew()> | <skewx()> | <skewy()> | <translate()> | <translate3d()> | <translatex()> | <translatey()> | <translatez()>"),
+    ("transition-behavior-value", "normal | allow-discrete"),
+    ("visual-box", "content-box | padding-box | border-box"),
+];
+
+/// The known properties, sorted by name
+pub const CSS_PROPERTIES: &[(&str, &str)] = &[
+    ("accent-color", "auto | <color>"),
+    ("align-content", "normal | <baseline-position> | <content-distribution> | <overflow-position>? <content-position>"),
+    ("align-items", "normal | stretch | <baseline-position> | <overflow-position>? <self-position>"),
+    ("align-self", "auto | normal | stretch | <baseline-position> | <overflow-position>? <self-position>"),
+    ("animation", "<single-animation>#"),
+    ("animation-delay", "<time>#"),
+    ("animation-direction", "<single-animation-direction>#"),
+    ("animation-duration", "[ auto | <time> ]#"),
+    ("animation-fill-mode", "<single-animation-fill-mode>#"),
+    ("animation-iteration-count", "<single-animation-iteration-count>#"),
+    ("animation-name", "[ none | <keyframes-name> ]#"),
+    ("animation-play-state", "<single-animation-play-state>#"),
+    ("animation-timing-function", "<easing-function>#"),
+    ("aspect-ratio", "auto || <ratio>"),
+    ("backdrop-filter", "none | [ <filter-function> | <url> ]+"),
+    ("backface-visibility", "visible | hidden"),
+    ("background", "<bg-layer>#"),
+    ("background-attachment", "<attachment>#"),
+    ("background-blend-mode", "<blend-mode>#"),
+    ("background-clip", "<bg-clip>#"),
+    ("background-color", "<color>"),
+    ("background-image", "<bg-image>#"),
+    ("background-origin", "<visual-box>#"),
+    ("background-position", "<bg-position>#"),
+    ("background-repeat", "<repeat-style>#"),
+    ("background-size", "<bg-size>#"),
+    ("block-size", "<'width'>"),
+    ("border", "<line-width> || <line-style> || <color>"),
+    ("border-block", "<'border'>"),
+    ("border-block-color", "<color>{1,2}"),
+    ("border-block-end", "<'border'>"),
+    ("border-block-end-color", "<color>"),
+    ("border-block-end-style", "<line-style>"),
+    ("border-block-end-width", "<line-width>"),
+    ("border-block-start", "<'border'>"),
+    ("border-block-start-color", "<color>"),
+    ("border-block-start-style", "<line-style>"),
+    ("border-block-start-width", "<line-width>"),
+    ("border-block-style", "<line-style>{1,2}"),
+    ("border-block-width", "<line-width>{1,2}"),
+    ("border-bottom", "<'border'>"),
+    ("border-bottom-color", "<color>"),
+    ("border-bottom-left-radius", "<length-percentage>{1,2}"),
+    ("border-bottom-right-radius", "<length-percentage>{1,2}"),
+    ("border-bottom-style", "<line-style>"),
+    ("border-bottom-width", "<line-width>"),
+    ("border-collapse", "collapse | separate"),
+    ("border-color", "<color>{1,4}"),
+    ("border-end-end-radius", "<length-percentage>{1,2}"),
+    ("border-end-start-radius", "<length-percentage>{1,2}"),
+    ("border-inline", "<'border'>"),
+    ("border-inline-color", "<color>{1,2}"),
+    ("border-inline-end", "<'border'>"),
+    ("border-inline-end-color", "<color>"),
+    ("border-inline-end-style", "<line-style>"),
+    ("border-inline-end-width", "<line-width>"),
+    ("border-inline-start", "<'border'>"),
+    ("border-inline-start-color", "<color>"),
+    ("border-inline-start-style", "<line-style>"),
+    ("border-inline-start-width", "<line-width>"),
+    ("border-inline-style", "<line-style>{1,2}"),
+    ("border-inline-width", "<line-width>{1,2}"),
+    ("border-left", "<'border'>"),
+    ("border-left-color", "<color>"),
+    ("border-left-style", "<line-style>"),
+    ("border-left-width", "<line-width>"),
+    ("border-radius", "<length-percentage>{1,4} [ / <length-percentage>{1,4} ]?"),
+    ("border-right", "<'border'>"),
+    ("border-right-color", "<color>"),
+    ("border-right-style", "<line-style>"),
+    ("border-right-width", "<line-width>"),
+    ("border-spacing", "<length>{1,2}"),
+    ("border-start-end-radius", "<length-percentage>{1,2}"),
+    ("border-start-start-radius", "<length-percentage>{1,2}"),
+    ("border-style", "<line-style>{1,4}"),
+    ("border-top", "<'border'>"),
+    ("border-top-color", "<color>"),
+    ("border-top-left-radius", "<length-percentage>{1,2}"),
+    ("border-top-right-radius", "<length-percentage>{1,2}"),
+    ("border-top-style", "<line-style>"),
+    ("border-top-width", "<line-width>"),
+    ("border-width", "<line-width>{1,4}"),
+    ("bottom", "auto | <length-percentage>"),
+    ("box-decoration-break", "slice | clone"),
+    ("box-shadow", "none | <shadow>#"),
+    ("box-sizing", "content-box | border-box"),
+    ("break-after", "auto | avoid | always | all | avoid-page | page | left | right | recto | verso | avoid-column | column | avoid-region | region"),
+    ("break-before", "auto | avoid | always | all | avoid-page | page | left | right | recto | verso | avoid-column | column | avoid-region | region"),
+    ("break-inside", "auto | avoid | avoid-page | avoid-column | avoid-region"),
+    ("caption-side", "top | bottom | block-start | block-end | inline-start | inline-end"),
+    ("caret-color", "auto | <color>"),
+    ("clear", "inline-start | inline-end | block-start | block-end | left | right | top | bottom | both-inline | both-block | both | none"),
+    ("color", "<color>"),
+    ("column-count", "auto | <integer>"),
+    ("column-gap", "normal | <length-percentage>"),
+    ("column-width", "auto | <length> | min-content | max-content | fit-content | <fit-content()>"),
+    ("columns", "<'column-width'> || <'column-count'>"),
+    ("contain", "none | strict | content | [ [ size | inline-size ] || layout || style || paint ]"),
+    ("container", "<'container-name'> [ / <'container-type'> ]?"),
+    ("container-name", "none | <custom-ident>+"),
+    ("container-type", "normal | size | inline-size"),
+    ("content-visibility", "visible | auto | hidden"),
+    ("counter-increment", "[ <custom-ident> <integer>? ]+ | none"),
+    ("cursor", "[ [ <url> | <image-set()> ] [ <number> <number> ]? , ]* <cursor-keyword>"),
+    ("direction", "ltr | rtl"),
+    ("display", "[ <display-outside> || <display-inside> ] | <display-listitem> | <display-internal> | <display-box> | <display-legacy>"),
+    ("empty-cells", "show | hide"),
+    ("filter", "none | [ <filter-function> | <url> ]+"),
+    ("flex", "none | [ <'flex-grow'> <'flex-shrink'>? || <'flex-basis'> ]"),
+    ("flex-basis", "content | <'width'>"),
+    ("flex-direction", "row | row-reverse | column | column-reverse"),
+    ("flex-flow", "<'flex-direction'> || <'flex-wrap'>"),
+    ("flex-grow", "<number>"),
+    ("flex-shrink", "<number>"),
+    ("flex-wrap", "nowrap | wrap | wrap-reverse"),
+    ("float", "block-start | block-end | inline-start | inline-end | snap-block | <snap-block()> | snap-inline | <snap-inline()> | left | right | top | bottom | none"),
+    ("font", "[ [ <'font-style'> || <font-variant-css2> || <'font-weight'> || <font-width-css3> ]? <'font-size'> [ / <'line-height'> ]? <'font-family'> ] | caption | icon | menu | message-box | small-caption | status-bar"),
+    ("font-family", "[ <string> | <custom-ident>+ ]#"),
+    ("font-kerning", "auto | normal | none"),
+    ("font-size", "<absolute-size> | <relative-size> | <length-percentage> | math"),
+    ("font-stretch", "normal | <percentage> | ultra-condensed | extra-condensed | condensed | semi-condensed | semi-expanded | expanded | extra-expanded | ultra-expanded"),
+    ("font-style", "normal | italic | oblique <angle>?"),
+    ("font-weight", "normal | bold | bolder | lighter | <number>"),
+    ("gap", "<'row-gap'> <'column-gap'>?"),
+    ("grid-area", "<grid-line> [ / <grid-line> ]{0,3}"),
+    ("grid-auto-flow", "[ row | column ] || dense"),
+    ("grid-column", "<grid-line> [ / <grid-line> ]?"),
+    ("grid-column-end", "<grid-line>"),
+    ("grid-column-gap", "<'column-gap'>"),
+    ("grid-column-start", "<grid-line>"),
+    ("grid-gap", "<'gap'>"),
+    ("grid-row", "<grid-line> [ / <grid-line> ]?"),
+    ("grid-row-end", "<grid-line>"),
+    ("grid-row-gap", "<'row-gap'>"),
+    ("grid-row-start", "<grid-line>"),
+    ("height", "<'width'>"),
+    ("hyphens", "none | manual | auto"),
+    ("image-rendering", "auto | smooth | high-quality | pixelated | crisp-edges | optimizespeed | optimizequality"),
+    ("inline-size", "<'width'>"),
+    ("inset", "<'top'>{1,4}"),
+    ("inset-block", "<'top'>{1,2}"),
+    ("inset-block-end", "<'top'>"),
+    ("inset-block-start", "<'top'>"),
+    ("inset-inline", "<'top'>{1,2}"),
+    ("inset-inline-end", "<'top'>"),
+    ("inset-inline-start", "<'top'>"),
+    ("isolation", "auto | isolate"),
+    ("justify-content", "normal | <content-distribution> | <overflow-position>? [ <content-position> | left | right ]"),
+    ("justify-items", "normal | stretch | <baseline-position> | <overflow-position>? [ <self-position> | left | right ] | legacy | legacy && [ left | right | center ]"),
+    ("justify-self", "auto | normal | stretch | <baseline-position> | <overflow-position>? [ <self-position> | left | right ]"),
+    ("left", "auto | <length-percentage>"),
+    ("letter-spacing", "normal | <length-percentage>"),
+    ("line-height", "normal | <number> | <length-percentage>"),
+    ("list-style", "<'list-style-position'> || <'list-style-image'> || <'list-style-type'>"),
+    ("list-style-image", "<image> | none"),
+    ("list-style-position", "inside | outside"),
+    ("list-style-type", "<custom-ident> | <symbols()> | <string> | none"),
+    ("margin", "<'margin-top'>{1,4}"),
+    ("margin-block", "<'margin-top'>{1,2}"),
+    ("margin-block-end", "<'margin-top'>"),
+    ("margin-block-start", "<'margin-top'>"),
+    ("margin-bottom", "<'margin-top'>"),
+    ("margin-inline", "<'margin-top'>{1,2}"),
+    ("margin-inline-end", "<'margin-top'>"),
+    ("margin-inline-start", "<'margin-top'>"),
+    ("margin-left", "<'margin-top'>"),
+    ("margin-right", "<'margin-top'>"),
+    ("margin-top", "<length-percentage> | auto"),
+    ("max-block-size", "<'max-width'>"),
+    ("max-height", "<'max-width'>"),
+    ("max-inline-size", "<'max-width'>"),
+    ("max-width", "none | <length-percentage> | min-content | max-content | fit-content | <fit-content()> | stretch"),
+    ("min-block-size", "<'width'>"),
+    ("min-height", "<'width'>"),
+    ("min-inline-size", "<'width'>"),
+    ("min-width", "<'width'>"),
+    ("mix-blend-mode", "<blend-mode> | plus-darker | plus-lighter"),
+    ("object-fit", "fill | contain | cover | none | scale-down"),
+    ("object-position", "<bg-position>"),
+    ("opacity", "<number> | <percentage>"),
+    ("order", "<integer>"),
+    ("outline", "<'outline-color'> || <'outline-style'> || <'outline-width'>"),
+    ("outline-color", "auto | <color>"),
+    ("outline-offset", "<length>"),
+    ("outline-style", "auto | <line-style>"),
+    ("outline-width", "<line-width>"),
+    ("overflow", "<'overflow-x'>{1,2}"),
+    ("overflow-wrap", "normal | break-word | anywhere"),
+    ("overflow-x", "visible | hidden | clip | scroll | auto | overlay"),
+    ("overflow-y", "<'overflow-x'>"),
+    ("overscroll-behavior", "[ contain | none | auto ]{1,2}"),
+    ("padding", "<'padding-top'>{1,4}"),
+    ("padding-block", "<'padding-top'>{1,2}"),
+    ("padding-block-end", "<'padding-top'>"),
+    ("padding-block-start", "<'padding-top'>"),
+    ("padding-bottom", "<'padding-top'>"),
+    ("padding-inline", "<'padding-top'>{1,2}"),
+    ("padding-inline-end", "<'padding-top'>"),
+    ("padding-inline-start", "<'padding-top'>"),
+    ("padding-left", "<'padding-top'>"),
+    ("padding-right", "<'padding-top'>"),
+    ("padding-top", "<length-percentage>"),
+    ("perspective", "none | <length>"),
+    ("place-content", "<'align-content'> <'justify-content'>?"),
+    ("place-items", "<'align-items'> <'justify-items'>?"),
+    ("place-self", "<'align-self'> <'justify-self'>?"),
+    ("pointer-events", "auto | bounding-box | visiblepainted | visiblefill | visiblestroke | visible | painted | fill | stroke | all | none"),
+    ("position", "static | relative | absolute | sticky | fixed"),
+    ("resize", "none | both | horizontal | vertical | block | inline"),
+    ("right", "auto | <length-percentage>"),
+    ("rotate", "none | <angle> | [ x | y | z | <number>{3} ] && <angle>"),
+    ("row-gap", "normal | <length-percentage>"),
+    ("scale", "none | [ <number> | <percentage> ]{1,3}"),
+    ("scroll-behavior", "auto | smooth"),
+    ("scroll-snap-align", "[ none | start | end | center ]{1,2}"),
+    ("scroll-snap-type", "none | [ x | y | block | inline | both ] [ mandatory | proximity ]?"),
+    ("tab-size", "<number> | <length>"),
+    ("table-layout", "auto | fixed"),
+    ("text-align", "start | end | left | right | center | justify | match-parent | justify-all"),
+    ("text-align-last", "auto | start | end | left | right | center | justify | match-parent"),
+    ("text-decoration", "<'text-decoration-line'> || <'text-decoration-thickness'> || <'text-decoration-style'> || <'text-decoration-color'>"),
+    ("text-decoration-color", "<color>"),
+    ("text-decoration-line", "none | [ underline || overline || line-through || blink ]"),
+    ("text-decoration-style", "solid | double | dotted | dashed | wavy"),
+    ("text-decoration-thickness", "auto | from-font | <length-percentage>"),
+    ("text-indent", "<length-percentage> && hanging? && each-line?"),
+    ("text-overflow", "[ clip | ellipsis | <string> ]{1,2}"),
+    ("text-rendering", "auto | optimizespeed | optimizelegibility | geometricprecision"),
+    ("text-shadow", "none | [ <color>? && <length>{2,3} ]#"),
+    ("text-transform", "none | [ capitalize | uppercase | lowercase ] || full-width || full-size-kana | math-auto"),
+    ("text-underline-offset", "auto | <length-percentage>"),
+    ("text-wrap-mode", "wrap | nowrap"),
+    ("top", "auto | <length-percentage>"),
+    ("touch-action", "auto | none | [ [ pan-x | pan-left | pan-right ] || [ pan-y | pan-up | pan-down ] || pinch-zoom ] | manipulation"),
+    ("transform", "none | <transform-function>+"),
+    ("transform-origin", "[ left | center | right | top | bottom | <length-percentage> ] | [ left | center | right | <length-percentage> ] [ top | center | bottom | <length-percentage> ] <length>? | [ [ center | left | right ] && [ center | top | bottom ] ] <length>?"),
+    ("transform-style", "flat | preserve-3d"),
+    ("transition", "<single-transition>#"),
+    ("transition-behavior", "<transition-behavior-value>#"),
+    ("transition-delay", "<time>#"),
+    ("transition-duration", "<time>#"),
+    ("transition-property", "none | <single-transition-property>#"),
+    ("transition-timing-function", "<easing-function>#"),
+    ("translate", "none | <length-percentage> [ <length-percentage> <length>? ]?"),
+    ("unicode-bidi", "normal | embed | isolate | bidi-override | isolate-override | plaintext"),
+    ("user-select", "auto | text | none | contain | all"),
+    ("vertical-align", "baseline | sub | super | text-top | text-bottom | middle | top | bottom | <length-percentage>"),
+    ("visibility", "visible | hidden | collapse"),
+    ("white-space", "normal | pre | pre-wrap | pre-line | <'white-space-collapse'> || <'text-wrap-mode'>"),
+    ("white-space-collapse", "collapse | discard | preserve | preserve-breaks | preserve-spaces | break-spaces"),
+    ("width", "auto | <length-percentage> | min-content | max-content | fit-content | <fit-content()> | stretch"),
+    ("will-change", "auto | [ scroll-position | contents | <custom-ident> ]#"),
+    ("word-break", "normal | keep-all | break-all | break-word | auto-phrase"),
+    ("word-spacing", "normal | <length-percentage>"),
+    ("word-wrap", "<'overflow-wrap'>"),
+    ("writing-mode", "horizontal-tb | vertical-rl | vertical-lr | sideways-rl | sideways-lr | lr | lr-tb | rl | rl-tb | tb | tb-rl"),
+    ("z-index", "auto | <integer>"),
+];
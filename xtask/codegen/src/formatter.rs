@@ -558,6 +558,8 @@ enum NodeDialect {
     Jsx,
     Json,
     Css,
+    Scss,
+    Less,
 }
 
 impl NodeDialect {
@@ -568,6 +570,8 @@ impl NodeDialect {
             NodeDialect::Jsx,
             NodeDialect::Json,
             NodeDialect::Css,
+            NodeDialect::Scss,
+            NodeDialect::Less,
         ]
     }
 
@@ -582,6 +586,8 @@ impl NodeDialect {
             NodeDialect::Jsx => "jsx",
             NodeDialect::Json => "json",
             NodeDialect::Css => "css",
+            NodeDialect::Scss => "scss",
+            NodeDialect::Less => "less",
         }
     }
 
@@ -592,6 +598,8 @@ impl NodeDialect {
             "Ts" => NodeDialect::Ts,
             "Json" => NodeDialect::Json,
             "Css" => NodeDialect::Css,
+            "Scss" => NodeDialect::Scss,
+            "Less" => NodeDialect::Less,
             _ => {
                 eprintln!("missing prefix {}", name);
                 NodeDialect::Js
@@ -51,20 +51,23 @@ fn generate_json_analyzer() -> Result<()> {
     let mut analyzers = BTreeMap::new();
     generate_category("analyzers", &mut analyzers, &base_path)?;
 
+    let mut assists = BTreeMap::new();
+    generate_category("assists", &mut assists, &base_path)?;
+
     generate_options(&["analyzers"], &base_path)?;
 
-    update_css_registry_builder(analyzers)
+    update_json_registry_builder(analyzers, assists)
 }
 
 fn generate_css_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/biome_css_analyze/src");
     let mut analyzers = BTreeMap::new();
-    generate_category(
-        "analyzers",
-        &mut analyzers,
-        &(project_root().join("crates/biome_css_analyze/src")),
-    )?;
+    generate_category("analyzers", &mut analyzers, &base_path)?;
 
-    update_json_registry_builder(analyzers)
+    let mut assists = BTreeMap::new();
+    generate_category("assists", &mut assists, &base_path)?;
+
+    update_css_registry_builder(analyzers, assists)
 }
 
 fn generate_options(categories: &[&str], base_path: &Path) -> Result<()> {
@@ -264,10 +267,16 @@ fn update_js_registry_builder(
     Ok(())
 }
 
-fn update_json_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+fn update_json_registry_builder(
+    analyzers: BTreeMap<&'static str, TokenStream>,
+    assists: BTreeMap<&'static str, TokenStream>,
+) -> Result<()> {
     let path = project_root().join("crates/biome_json_analyze/src/registry.rs");
 
-    let categories = analyzers.into_values();
+    let categories = analyzers
+        .into_iter()
+        .chain(assists)
+        .map(|(_, tokens)| tokens);
 
     let tokens = xtask::reformat(quote! {
         use biome_analyze::RegistryVisitor;
@@ -283,10 +292,16 @@ fn update_json_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>)
     Ok(())
 }
 
-fn update_css_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+fn update_css_registry_builder(
+    analyzers: BTreeMap<&'static str, TokenStream>,
+    assists: BTreeMap<&'static str, TokenStream>,
+) -> Result<()> {
     let path = project_root().join("crates/biome_css_analyze/src/registry.rs");
 
-    let categories = analyzers.into_values();
+    let categories = analyzers
+        .into_iter()
+        .chain(assists)
+        .map(|(_, tokens)| tokens);
 
     let tokens = xtask::reformat(quote! {
         use biome_analyze::RegistryVisitor;
@@ -0,0 +1,306 @@
+//! Generates the value grammars of the known CSS properties,
+//! from the definitions of [crate::css_properties_src].
+
+use crate::css_properties_src::{CSS_BASIC_VALUE_TYPES, CSS_PROPERTIES, CSS_VALUE_TYPES};
+use crate::update;
+use anyhow::{bail, Context};
+use std::fmt::Write;
+use xtask::{project_root, Mode, Result};
+
+const PROPERTIES: &str = "crates/biome_css_syntax/src/property/generated.rs";
+
+pub fn generate_css_properties(mode: Mode) -> Result<()> {
+    let mut output = String::from("use super::{CssPropertyDefinition, ValueGrammar, ValueType};\n");
+
+    for (name, syntax) in CSS_VALUE_TYPES {
+        let grammar = parse(syntax).with_context(|| format!("invalid value type <{name}>"))?;
+        if matches!(grammar, Grammar::Named(_) | Grammar::Property(_)) {
+            bail!("the value type <{name}> is an alias of another type");
+        }
+        writeln!(
+            output,
+            "static {}: ValueGrammar = {};",
+            static_name(name),
+            value(&grammar)?
+        )?;
+    }
+
+    for (name, syntax) in CSS_PROPERTIES {
+        let grammar = parse(syntax).with_context(|| format!("invalid property <'{name}'>"))?;
+        // Aliases share the grammar of the property they refer to
+        if !matches!(grammar, Grammar::Named(_) | Grammar::Property(_)) {
+            writeln!(
+                output,
+                "static {}_PROPERTY: ValueGrammar = {};",
+                static_name(name),
+                value(&grammar)?
+            )?;
+        }
+    }
+
+    if CSS_PROPERTIES.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+        bail!("the properties must be sorted by name");
+    }
+    writeln!(
+        output,
+        "pub(super) static PROPERTIES: [CssPropertyDefinition; {}] = [",
+        CSS_PROPERTIES.len()
+    )?;
+    // The table is written by hand, because rustfmt doesn't format long string literals
+    for (name, syntax) in CSS_PROPERTIES {
+        writeln!(output, "    CssPropertyDefinition {{")?;
+        writeln!(output, "        name: \"{name}\",")?;
+        writeln!(output, "        syntax: \"{syntax}\",")?;
+        writeln!(output, "        grammar: &{},", property_static(name)?)?;
+        writeln!(output, "    }},")?;
+    }
+    writeln!(output, "];")?;
+
+    let path = project_root().join(PROPERTIES);
+    update(&path, &xtask::reformat(output)?, &mode)?;
+
+    Ok(())
+}
+
+/// A parsed grammar of the value definition syntax
+#[derive(Debug)]
+enum Grammar {
+    Keyword(String),
+    /// `<length>`
+    Type(&'static str),
+    /// `<color>`
+    Named(String),
+    /// `<'color'>`
+    Property(String),
+    /// `<fit-content()>`
+    Function(String),
+    Delimiter(char),
+    Sequence(Vec<Grammar>),
+    AllOf(Vec<Grammar>),
+    AnyOf(Vec<Grammar>),
+    OneOf(Vec<Grammar>),
+    Repeat {
+        grammar: Box<Grammar>,
+        min: u8,
+        max: Option<u8>,
+        comma_separated: bool,
+    },
+}
+
+fn parse(syntax: &str) -> Result<Grammar> {
+    let mut parser = Parser {
+        tokens: tokenize(syntax)?,
+        position: 0,
+    };
+    let grammar = parser.parse_one_of()?;
+    if let Some(token) = parser.peek() {
+        bail!("unexpected `{token}`");
+    }
+    Ok(grammar)
+}
+
+fn tokenize(syntax: &str) -> Result<Vec<&str>> {
+    let mut tokens = Vec::new();
+    let mut rest = syntax.trim_start();
+    while !rest.is_empty() {
+        let length = if rest.starts_with("||") || rest.starts_with("&&") {
+            2
+        } else if rest.starts_with(['[', ']', '|', ',', '/', '?', '*', '+', '#']) {
+            1
+        } else if rest.starts_with('{') {
+            rest.find('}').context("unterminated `{`")? + 1
+        } else if rest.starts_with('<') {
+            rest.find('>').context("unterminated `<`")? + 1
+        } else {
+            let length = rest
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+                .unwrap_or(rest.len());
+            if length == 0 {
+                bail!("unexpected `{rest}`");
+            }
+            length
+        };
+        tokens.push(&rest[..length]);
+        rest = rest[length..].trim_start();
+    }
+    Ok(tokens)
+}
+
+/// Parses the value definition syntax. From the loosest to the tightest, the combinators
+/// are `|`, `||`, `&&` and the juxtaposition; the multipliers bind to the previous component.
+struct Parser<'a> {
+    tokens: Vec<&'a str>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn bump(&mut self) -> Result<&'a str> {
+        let token = self.peek().context("unexpected end of the grammar")?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn parse_combination(
+        &mut self,
+        separator: &str,
+        parse_item: fn(&mut Self) -> Result<Grammar>,
+        combine: fn(Vec<Grammar>) -> Grammar,
+    ) -> Result<Grammar> {
+        let mut items = vec![parse_item(self)?];
+        while self.peek() == Some(separator) {
+            self.bump()?;
+            items.push(parse_item(self)?);
+        }
+        Ok(if items.len() == 1 {
+            items.remove(0)
+        } else {
+            combine(items)
+        })
+    }
+
+    fn parse_one_of(&mut self) -> Result<Grammar> {
+        self.parse_combination("|", Self::parse_any_of, Grammar::OneOf)
+    }
+
+    fn parse_any_of(&mut self) -> Result<Grammar> {
+        self.parse_combination("||", Self::parse_all_of, Grammar::AnyOf)
+    }
+
+    fn parse_all_of(&mut self) -> Result<Grammar> {
+        self.parse_combination("&&", Self::parse_sequence, Grammar::AllOf)
+    }
+
+    fn parse_sequence(&mut self) -> Result<Grammar> {
+        let mut items = Vec::new();
+        while !matches!(self.peek(), None | Some("|" | "||" | "&&" | "]")) {
+            items.push(self.parse_component()?);
+        }
+        Ok(match items.len() {
+            0 => bail!("expected a component"),
+            1 => items.remove(0),
+            _ => Grammar::Sequence(items),
+        })
+    }
+
+    fn parse_component(&mut self) -> Result<Grammar> {
+        let grammar = self.parse_atom()?;
+        let (min, max, comma_separated) = match self.peek() {
+            Some("?") => (0, Some(1), false),
+            Some("*") => (0, None, false),
+            Some("+") => (1, None, false),
+            Some("#") => (1, None, true),
+            Some(range) if range.starts_with('{') => {
+                let range = &range[1..range.len() - 1];
+                match range.split_once(',') {
+                    Some((min, "")) => (min.parse()?, None, false),
+                    Some((min, max)) => (min.parse()?, Some(max.parse()?), false),
+                    None => (range.parse()?, Some(range.parse()?), false),
+                }
+            }
+            _ => return Ok(grammar),
+        };
+        self.bump()?;
+        Ok(Grammar::Repeat {
+            grammar: Box::new(grammar),
+            min,
+            max,
+            comma_separated,
+        })
+    }
+
+    fn parse_atom(&mut self) -> Result<Grammar> {
+        let token = self.bump()?;
+        let grammar = match token {
+            "[" => {
+                let grammar = self.parse_one_of()?;
+                if self.bump()? != "]" {
+                    bail!("expected `]`");
+                }
+                grammar
+            }
+            "," => Grammar::Delimiter(','),
+            "/" => Grammar::Delimiter('/'),
+            _ if token.starts_with("<'") => {
+                let name = token.trim_start_matches("<'").trim_end_matches("'>");
+                if !CSS_PROPERTIES.iter().any(|(property, _)| *property == name) {
+                    bail!("unknown property <'{name}'>");
+                }
+                Grammar::Property(name.to_string())
+            }
+            _ if token.starts_with('<') && token.ends_with("()>") => {
+                Grammar::Function(token[1..token.len() - 3].to_string())
+            }
+            _ if token.starts_with('<') => {
+                let name = &token[1..token.len() - 1];
+                if let Some((_, variant)) = CSS_BASIC_VALUE_TYPES.iter().find(|(n, _)| *n == name) {
+                    Grammar::Type(variant)
+                } else if CSS_VALUE_TYPES.iter().any(|(n, _)| *n == name) {
+                    Grammar::Named(name.to_string())
+                } else {
+                    bail!("unknown value type <{name}>");
+                }
+            }
+            _ => Grammar::Keyword(token.to_string()),
+        };
+        Ok(grammar)
+    }
+}
+
+fn static_name(name: &str) -> String {
+    name.to_uppercase().replace('-', "_")
+}
+
+/// Returns the static holding the grammar of the property, following the aliases
+fn property_static(name: &str) -> Result<String> {
+    let (_, syntax) = CSS_PROPERTIES
+        .iter()
+        .find(|(property, _)| *property == name)
+        .context("unknown property")?;
+    Ok(match parse(syntax)? {
+        Grammar::Property(name) => property_static(&name)?,
+        Grammar::Named(name) => static_name(&name),
+        _ => format!("{}_PROPERTY", static_name(name)),
+    })
+}
+
+/// A `&'static ValueGrammar` expression
+fn reference(grammar: &Grammar) -> Result<String> {
+    Ok(match grammar {
+        Grammar::Property(name) => format!("&{}", property_static(name)?),
+        Grammar::Named(name) => format!("&{}", static_name(name)),
+        _ => format!("&{}", value(grammar)?),
+    })
+}
+
+/// A `ValueGrammar` expression
+fn value(grammar: &Grammar) -> Result<String> {
+    Ok(match grammar {
+        Grammar::Keyword(keyword) => format!("ValueGrammar::Keyword({keyword:?})"),
+        Grammar::Type(variant) => format!("ValueGrammar::Type(ValueType::{variant})"),
+        Grammar::Function(name) => format!("ValueGrammar::Function({name:?})"),
+        Grammar::Delimiter(delimiter) => format!("ValueGrammar::Delimiter({delimiter:?})"),
+        Grammar::Sequence(items) => format!("ValueGrammar::Sequence({})", references(items)?),
+        Grammar::AllOf(items) => format!("ValueGrammar::AllOf({})", references(items)?),
+        Grammar::AnyOf(items) => format!("ValueGrammar::AnyOf({})", references(items)?),
+        Grammar::OneOf(items) => format!("ValueGrammar::OneOf({})", references(items)?),
+        Grammar::Repeat {
+            grammar,
+            min,
+            max,
+            comma_separated,
+        } => format!(
+            "ValueGrammar::Repeat {{ grammar: {}, min: {min}, max: {max:?}, comma_separated: {comma_separated} }}",
+            reference(grammar)?
+        ),
+        Grammar::Named(_) | Grammar::Property(_) => bail!("expected a grammar, found an alias"),
+    })
+}
+
+fn references(items: &[Grammar]) -> Result<String> {
+    let items = items.iter().map(reference).collect::<Result<Vec<_>>>()?;
+    Ok(format!("&[{}]", items.join(", ")))
+}
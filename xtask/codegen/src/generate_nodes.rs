@@ -977,7 +977,7 @@ pub(crate) fn token_kind_to_code(
     } else {
         // $ is valid syntax in rust and it's part of macros,
         // so we need to decorate the tokens with quotes
-        if name == "$=" {
+        if name == "$=" || name == "$" {
             let token = Literal::string(name);
             quote! { T![#token] }
         } else {
@@ -12,7 +12,7 @@ pub fn generate_syntax_kinds(grammar: KindsSrc, language_kind: LanguageKind) ->
         if "{}[]()`".contains(token) {
             let c = token.chars().next().unwrap();
             quote! { #c }
-        } else if *token == "$=" {
+        } else if *token == "$=" || *token == "$" {
             let token = Literal::string(token);
             quote! { #token }
         } else {
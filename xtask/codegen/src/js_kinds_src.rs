@@ -682,6 +682,7 @@ impl Field {
                     ("||", _) => "logical_or",
                     ("&&", _) => "logical_and",
                     ("$=", _) => "suffix",
+                    ("$", _) => "dollar",
                     ("~=", _) => "whitespace_like",
                     (",", _) => "comma",
                     _ => name,
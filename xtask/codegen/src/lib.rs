@@ -2,8 +2,10 @@
 //!
 mod ast;
 mod css_kinds_src;
+mod css_properties_src;
 mod formatter;
 mod generate_analyzer;
+mod generate_css_properties;
 mod generate_macros;
 pub mod generate_new_lintrule;
 mod generate_node_factory;
@@ -31,6 +33,7 @@ use xtask::{glue::fs2, Mode, Result};
 pub use self::ast::generate_ast;
 pub use self::formatter::generate_formatters;
 pub use self::generate_analyzer::generate_analyzer;
+pub use self::generate_css_properties::generate_css_properties;
 pub use self::parser_tests::generate_parser_tests;
 pub use self::unicode::generate_tables;
 
@@ -29,7 +29,8 @@ use crate::generate_website::generate_files;
 use crate::promote_rule::promote_rule;
 use generate_new_lintrule::*;
 use xtask_codegen::{
-    generate_analyzer, generate_ast, generate_formatters, generate_parser_tests, generate_tables,
+    generate_analyzer, generate_ast, generate_css_properties, generate_formatters,
+    generate_parser_tests, generate_tables,
 };
 
 fn main() -> Result<()> {
@@ -58,6 +59,10 @@ fn main() -> Result<()> {
             generate_analyzer()?;
             Ok(())
         }
+        "css-properties" => {
+            generate_css_properties(Mode::Overwrite)?;
+            Ok(())
+        }
         "newlintrule" => {
             let path: String = args.value_from_str("--path").unwrap();
             let rule_name: String = args.value_from_str("--name").unwrap();
@@ -101,6 +106,7 @@ fn main() -> Result<()> {
             generate_parser_tests(Mode::Overwrite)?;
             generate_formatters();
             generate_analyzer()?;
+            generate_css_properties(Mode::Overwrite)?;
             #[cfg(feature = "website")]
             generate_files()?;
             #[cfg(feature = "configuration")]
@@ -123,6 +129,7 @@ USAGE:
 SUBCOMMANDS:
 	aria            Generate aria bindings for lint rules
 	analyzer        Generate factory functions for the analyzer and the configuration of the analyzers
+	css-properties  Generate the value grammars of the known CSS properties
 	configuration    Generate the part of the configuration that depends on some metadata
 	schema          Generate the JSON schema for the Biome configuration file format
 	bindings        Generate TypeScript definitions for the JavaScript bindings to the Workspace API
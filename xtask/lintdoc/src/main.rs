@@ -15,8 +15,10 @@ use biome_diagnostics::termcolor::NoColor;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic};
 use biome_js_parser::JsParserOptions;
 use biome_js_syntax::{JsFileSource, JsLanguage, Language, ModuleKind};
+use biome_json_analyze::JsonSchemas;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
+use biome_project::ProjectImports;
 use biome_service::settings::WorkspaceSettings;
 use convert_case::{Case, Casing};
 use pulldown_cmark::{html::write_html, CodeBlockKind, Event, LinkType, Parser, Tag};
@@ -727,6 +729,8 @@ fn assert_lint(
                     &options,
                     source_type,
                     None,
+                    biome_js_analyze::ImportedCssModules::default(),
+                    &[],
                     |signal| {
                         if let Some(mut diag) = signal.diagnostic() {
                             let category = diag.category().expect("linter diagnostic has no code");
@@ -802,6 +806,8 @@ fn assert_lint(
                     &root,
                     filter,
                     &options,
+                    ProjectImports::default(),
+                    JsonSchemas::default(),
                     |signal| {
                         if let Some(mut diag) = signal.diagnostic() {
                             let category = diag.category().expect("linter diagnostic has no code");